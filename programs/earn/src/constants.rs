@@ -8,3 +8,16 @@ pub const ANCHOR_DISCRIMINATOR_SIZE: usize = 8;
 pub const PORTAL_PROGRAM: Pubkey = pubkey!("mzp1q2j5Hr1QuLC3KFBCAUz5aUckT6qyuZKZ3WJnMmY");
 
 pub const ONE_HUNDRED_PERCENT: u64 = 100_00; // 1e4
+
+pub const SECONDS_PER_YEAR: u64 = 31_536_000;
+
+// Upper limits of the index bounds, no legitimate update comes close to them
+pub const MAX_INDEX_RATE_BPS: u64 = ONE_HUNDRED_PERCENT; // 100% per year
+pub const MAX_INDEX_RATIO_BPS: u64 = 2 * ONE_HUNDRED_PERCENT; // index doubling in a single update
+
+// Bounds of the time after a cycle starts that it completes automatically
+pub const MIN_CLAIM_WINDOW: u64 = 3_600; // 1 hour
+pub const MAX_CLAIM_WINDOW: u64 = 604_800; // 1 week
+
+// Must be one less than the number of unclaimed yield buckets in the global account
+pub const MAX_UNCLAIMED_EXPIRY_CYCLES: u64 = 12;
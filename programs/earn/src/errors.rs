@@ -26,4 +26,36 @@ pub enum EarnError {
     InvalidProof,
     #[msg("Token account owner is required to be immutable.")]
     MutableOwner,
+    #[msg("Role has reached the maximum number of members.")]
+    RoleFull,
+    #[msg("Program is paused.")]
+    Paused,
+    #[msg("There is no pending index.")]
+    NoPendingIndex,
+    #[msg("There is no expired yield to sweep.")]
+    NoExpiredYield,
+    #[msg("Account is not on the expected version.")]
+    InvalidVersion,
+    #[msg("Index attestation is invalid.")]
+    InvalidAttestation,
+    #[msg("Not enough attesters signed the index attestation.")]
+    InsufficientAttestations,
+    #[msg("Committed balances exceed the max supply.")]
+    ExceedsMaxSupply,
+    #[msg("Claimed balances exceed the committed total.")]
+    ExceedsCommittedBalance,
+    #[msg("There is no completed claim cycle to record.")]
+    NoCompletedCycle,
+    #[msg("Claim window is out of bounds.")]
+    InvalidClaimWindow,
+    #[msg("Index bounds are out of range.")]
+    InvalidIndexBounds,
+    #[msg("Earner root is older than the latest root.")]
+    StaleEarnerRoot,
+    #[msg("Balance checkpoint does not cover the claim period.")]
+    InvalidCheckpoint,
+    #[msg("The last completed claim cycle must be recorded first.")]
+    UnrecordedClaimCycle,
+    #[msg("The pending index account is required to quarantine the index update.")]
+    MissingPendingIndex,
 }
@@ -0,0 +1,46 @@
+// earn/instructions/admin/accept_admin.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+
+// local dependencies
+use crate::{
+    errors::EarnError,
+    state::{Global, GLOBAL_SEED, GLOBAL_VERSION},
+};
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    pub new_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = global_account.version == GLOBAL_VERSION @ EarnError::InvalidVersion,
+        seeds = [GLOBAL_SEED],
+        bump = global_account.bump,
+        constraint = global_account.pending_admin == Some(new_admin.key()) @ EarnError::NotAuthorized,
+    )]
+    pub global_account: Account<'info, Global>,
+}
+
+pub fn handler(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let global = &mut ctx.accounts.global_account;
+
+    let old_admin = global.admin;
+
+    global.admin = ctx.accounts.new_admin.key();
+    global.pending_admin = None;
+
+    emit!(AdminTransferred {
+        old_admin,
+        new_admin: global.admin,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AdminTransferred {
+    pub old_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
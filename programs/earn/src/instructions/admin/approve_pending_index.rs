@@ -0,0 +1,85 @@
+// earn/instructions/admin/approve_pending_index.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+// local dependencies
+use crate::{
+    errors::EarnError,
+    instructions::portal::propagate_index::{queue_index_update, update_index},
+    state::{Global, PendingIndex, GLOBAL_SEED, GLOBAL_VERSION, PENDING_INDEX_SEED},
+};
+
+#[derive(Accounts)]
+pub struct ApprovePendingIndex<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin @ EarnError::NotAuthorized,
+        has_one = mint,
+        constraint = global_account.version == GLOBAL_VERSION @ EarnError::InvalidVersion,
+        seeds = [GLOBAL_SEED],
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, Global>,
+
+    #[account(
+        mut,
+        seeds = [PENDING_INDEX_SEED],
+        bump = pending_index_account.bump,
+    )]
+    pub pending_index_account: Account<'info, PendingIndex>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+}
+
+pub fn handler(ctx: Context<ApprovePendingIndex>) -> Result<()> {
+    let global = &mut ctx.accounts.global_account;
+    let pending = &mut ctx.accounts.pending_index_account;
+
+    if pending.index == 0 {
+        return err!(EarnError::NoPendingIndex);
+    }
+
+    let pending_index = pending.index;
+    let pending_earner_merkle_root = pending.earner_merkle_root;
+    let pending_earner_merkle_root_timestamp = pending.earner_merkle_root_timestamp;
+
+    pending.clear();
+
+    emit!(PendingIndexApproved {
+        index: pending_index,
+        earner_merkle_root: pending_earner_merkle_root,
+    });
+
+    // While paused, the update is queued like any other so that it is applied on unpause
+    if global.paused {
+        queue_index_update(
+            global,
+            ctx.accounts.mint.supply,
+            pending_index,
+            pending_earner_merkle_root,
+            pending_earner_merkle_root_timestamp,
+            true,
+        );
+
+        return Ok(());
+    }
+
+    // Apply the update as if it had just been propagated, skipping the bounds check
+    update_index(
+        global,
+        ctx.accounts.mint.supply,
+        pending_index,
+        pending_earner_merkle_root,
+        pending_earner_merkle_root_timestamp,
+    )
+}
+
+#[event]
+pub struct PendingIndexApproved {
+    pub index: u64,
+    pub earner_merkle_root: [u8; 32],
+}
@@ -0,0 +1,40 @@
+// earn/instructions/admin/approve_program_earner.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+
+// local dependencies
+use super::AdminAction;
+use crate::{
+    errors::EarnError,
+    state::{ProgramEarner, MAX_PROGRAM_EARNERS},
+};
+
+pub fn handler(ctx: Context<AdminAction>, program_id: Pubkey, seeds: Vec<Vec<u8>>) -> Result<()> {
+    // Derive the address from its seeds (including the bump) to prove it belongs to the program
+    let seeds: Vec<&[u8]> = seeds.iter().map(|seed| seed.as_slice()).collect();
+    let user = Pubkey::create_program_address(&seeds, &program_id)
+        .map_err(|_| EarnError::InvalidParam)?;
+
+    let global = &mut ctx.accounts.global_account;
+
+    if global.is_program_earner(&user) {
+        return err!(EarnError::InvalidParam);
+    }
+
+    if global.program_earners.len() >= MAX_PROGRAM_EARNERS {
+        return err!(EarnError::InvalidParam);
+    }
+
+    global.program_earners.push(ProgramEarner { user, program_id });
+
+    emit!(ProgramEarnerApproved { user, program_id });
+
+    Ok(())
+}
+
+#[event]
+pub struct ProgramEarnerApproved {
+    pub user: Pubkey,
+    pub program_id: Pubkey,
+}
@@ -9,7 +9,7 @@ use crate::{
     constants::ANCHOR_DISCRIMINATOR_SIZE,
     constants::PORTAL_PROGRAM,
     errors::EarnError,
-    state::{Global, GLOBAL_SEED, TOKEN_AUTHORITY_SEED},
+    state::{CompletedCycle, Global, GLOBAL_SEED, GLOBAL_VERSION, TOKEN_AUTHORITY_SEED},
 };
 
 #[derive(Accounts)]
@@ -65,6 +65,30 @@ pub fn handler(
         claim_complete: true,
         earner_merkle_root: [0; 32],
         bump: ctx.bumps.global_account,
+        previous_index: initial_index,
+        claims: 0,
+        pending_admin: None,
+        paused: false,
+        queued_index: 0,
+        queued_earner_merkle_root: [0; 32],
+        max_index_rate_bps: 0,
+        max_index_ratio_bps: 0,
+        claim_window: 0,
+        unclaimed_expiry_cycles: 0,
+        unclaimed_treasury: None,
+        unclaimed_yield: [0; 13],
+        version: GLOBAL_VERSION,
+        last_attestation_timestamp: 0,
+        attestation_threshold: 0,
+        earner_merkle_root_timestamp: 0,
+        queued_earner_merkle_root_timestamp: 0,
+        removal_bounty: 0,
+        completed_cycle: CompletedCycle::default(),
+        queued_index_approved: false,
+        completed_cycle_recorded: false,
+        reserved: [0; 20],
+        attesters: vec![],
+        program_earners: vec![],
     });
 
     Ok(())
@@ -0,0 +1,54 @@
+// earn/instructions/admin/migrate_global.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+
+// local dependencies
+use crate::{
+    errors::EarnError,
+    state::{Global, GLOBAL_SEED, GLOBAL_VERSION},
+    utils::migration::realloc_to_current,
+};
+
+#[derive(Accounts)]
+pub struct MigrateGlobal<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: The global account cannot be deserialized while it is on an older layout,
+    /// the discriminator and admin are validated in the handler
+    #[account(
+        mut,
+        seeds = [GLOBAL_SEED],
+        bump,
+    )]
+    pub global_account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateGlobal>) -> Result<()> {
+    let global_info = ctx.accounts.global_account.to_account_info();
+
+    realloc_to_current::<Global>(
+        &global_info,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    let mut global = Global::try_deserialize(&mut &global_info.try_borrow_data()?[..])?;
+
+    if global.admin != ctx.accounts.admin.key() {
+        return err!(EarnError::NotAuthorized);
+    }
+
+    if global.version >= GLOBAL_VERSION {
+        return err!(EarnError::InvalidVersion);
+    }
+
+    global.upgrade();
+
+    global.try_serialize(&mut &mut global_info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
@@ -2,21 +2,71 @@
 
 use anchor_lang::prelude::*;
 
+pub mod accept_admin;
+pub mod approve_pending_index;
+pub mod approve_program_earner;
+pub mod grant_role;
 pub mod initialize;
+pub mod migrate_global;
+pub mod reject_pending_index;
+pub mod remove_program_earner;
+pub mod revoke_role;
+pub mod set_attesters;
 pub mod set_claim_cooldown;
+pub mod set_claim_window;
 pub mod set_earn_authority;
+pub mod set_index_bounds;
+pub mod set_paused;
+pub mod set_portal_authority;
+pub mod set_removal_bounty;
+pub mod set_unclaimed_expiry;
+pub mod sweep_unclaimed;
+pub mod transfer_admin;
 
+pub use accept_admin::AcceptAdmin;
+pub(crate) use accept_admin::__client_accounts_accept_admin;
+pub use approve_pending_index::ApprovePendingIndex;
+pub(crate) use approve_pending_index::__client_accounts_approve_pending_index;
+pub use grant_role::GrantRole;
+pub(crate) use grant_role::__client_accounts_grant_role;
 pub use initialize::Initialize;
 pub(crate) use initialize::__client_accounts_initialize;
+pub use migrate_global::MigrateGlobal;
+pub(crate) use migrate_global::__client_accounts_migrate_global;
+pub use reject_pending_index::RejectPendingIndex;
+pub(crate) use reject_pending_index::__client_accounts_reject_pending_index;
+pub use remove_program_earner::RemoveProgramEarner;
+pub(crate) use remove_program_earner::__client_accounts_remove_program_earner;
+pub use revoke_role::RevokeRole;
+pub(crate) use revoke_role::__client_accounts_revoke_role;
+pub use set_index_bounds::SetIndexBounds;
+pub(crate) use set_index_bounds::__client_accounts_set_index_bounds;
+pub use set_paused::SetPaused;
+pub(crate) use set_paused::__client_accounts_set_paused;
+pub use set_unclaimed_expiry::SetUnclaimedExpiry;
+pub(crate) use set_unclaimed_expiry::__client_accounts_set_unclaimed_expiry;
+pub use sweep_unclaimed::SweepUnclaimed;
+pub(crate) use sweep_unclaimed::__client_accounts_sweep_unclaimed;
 
 use crate::{
     errors::EarnError,
-    state::{Global, GLOBAL_SEED},
+    state::{Global, GLOBAL_SEED, GLOBAL_VERSION},
 };
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "cpi")] {
+        pub(crate) use accept_admin::__cpi_client_accounts_accept_admin;
+        pub(crate) use approve_pending_index::__cpi_client_accounts_approve_pending_index;
+        pub(crate) use grant_role::__cpi_client_accounts_grant_role;
         pub(crate) use initialize::__cpi_client_accounts_initialize;
+        pub(crate) use migrate_global::__cpi_client_accounts_migrate_global;
+        pub(crate) use reject_pending_index::__cpi_client_accounts_reject_pending_index;
+        pub(crate) use remove_program_earner::__cpi_client_accounts_remove_program_earner;
+        pub(crate) use revoke_role::__cpi_client_accounts_revoke_role;
+        pub(crate) use set_index_bounds::__cpi_client_accounts_set_index_bounds;
+        pub(crate) use set_paused::__cpi_client_accounts_set_paused;
+        pub(crate) use set_unclaimed_expiry::__cpi_client_accounts_set_unclaimed_expiry;
+        pub(crate) use sweep_unclaimed::__cpi_client_accounts_sweep_unclaimed;
     }
 }
 
@@ -26,6 +76,7 @@ pub struct AdminAction<'info> {
 
     #[account(
         mut,
+        constraint = global_account.version == GLOBAL_VERSION @ EarnError::InvalidVersion,
         seeds = [GLOBAL_SEED],
         has_one = admin @ EarnError::NotAuthorized,
         bump = global_account.bump,
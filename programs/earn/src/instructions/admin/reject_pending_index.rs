@@ -0,0 +1,53 @@
+// earn/instructions/admin/reject_pending_index.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+
+// local dependencies
+use crate::{
+    errors::EarnError,
+    state::{Global, PendingIndex, GLOBAL_SEED, GLOBAL_VERSION, PENDING_INDEX_SEED},
+};
+
+#[derive(Accounts)]
+pub struct RejectPendingIndex<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        constraint = global_account.version == GLOBAL_VERSION @ EarnError::InvalidVersion,
+        seeds = [GLOBAL_SEED],
+        has_one = admin @ EarnError::NotAuthorized,
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, Global>,
+
+    #[account(
+        mut,
+        seeds = [PENDING_INDEX_SEED],
+        bump = pending_index_account.bump,
+    )]
+    pub pending_index_account: Account<'info, PendingIndex>,
+}
+
+pub fn handler(ctx: Context<RejectPendingIndex>) -> Result<()> {
+    let pending = &mut ctx.accounts.pending_index_account;
+
+    if pending.index == 0 {
+        return err!(EarnError::NoPendingIndex);
+    }
+
+    emit!(PendingIndexRejected {
+        index: pending.index,
+        earner_merkle_root: pending.earner_merkle_root,
+    });
+
+    pending.clear();
+
+    Ok(())
+}
+
+#[event]
+pub struct PendingIndexRejected {
+    pub index: u64,
+    pub earner_merkle_root: [u8; 32],
+}
@@ -0,0 +1,72 @@
+// earn/instructions/admin/remove_program_earner.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+
+// local dependencies
+use crate::{
+    errors::EarnError,
+    state::{Earner, Global, EARNER_SEED, EARNER_VERSION, GLOBAL_SEED, GLOBAL_VERSION},
+    utils::account::{close_with_refund, is_rent_recipient},
+};
+
+#[derive(Accounts)]
+pub struct RemoveProgramEarner<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin @ EarnError::NotAuthorized,
+        constraint = global_account.version == GLOBAL_VERSION @ EarnError::InvalidVersion,
+        seeds = [GLOBAL_SEED],
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, Global>,
+
+    #[account(
+        mut,
+        constraint = global_account.is_program_earner(&earner_account.user) @ EarnError::NotEarning,
+        constraint = earner_account.version == EARNER_VERSION @ EarnError::InvalidVersion,
+        seeds = [EARNER_SEED, earner_account.user_token_account.as_ref()],
+        bump = earner_account.bump,
+    )]
+    pub earner_account: Account<'info, Earner>,
+
+    /// CHECK: This account only receives the rent of the earner account
+    #[account(
+        mut,
+        constraint = is_rent_recipient(&earner_account.rent_payer, &rent_payer.key(), &admin.key()) @ EarnError::InvalidAccount,
+    )]
+    pub rent_payer: AccountInfo<'info>,
+}
+
+pub fn handler(ctx: Context<RemoveProgramEarner>) -> Result<()> {
+    let user = ctx.accounts.earner_account.user;
+
+    // Other token accounts of the same program can then be removed like any earner
+    // that is not in the earner merkle tree
+    ctx.accounts
+        .global_account
+        .program_earners
+        .retain(|earner| earner.user != user);
+
+    emit!(ProgramEarnerRemoved {
+        user,
+        token_account: ctx.accounts.earner_account.user_token_account,
+    });
+
+    // No bounty is paid since only the admin can remove program earners
+    close_with_refund(
+        &ctx.accounts.earner_account.to_account_info(),
+        &ctx.accounts.rent_payer,
+        &ctx.accounts.admin.to_account_info(),
+        0,
+    )
+}
+
+#[event]
+pub struct ProgramEarnerRemoved {
+    pub user: Pubkey,
+    pub token_account: Pubkey,
+}
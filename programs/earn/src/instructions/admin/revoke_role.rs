@@ -0,0 +1,51 @@
+// earn/instructions/admin/revoke_role.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+
+// local dependencies
+use crate::{
+    errors::EarnError,
+    state::{Global, Role, Roles, GLOBAL_SEED, GLOBAL_VERSION, ROLES_SEED},
+    utils::roles::role_members,
+};
+
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        constraint = global_account.version == GLOBAL_VERSION @ EarnError::InvalidVersion,
+        seeds = [GLOBAL_SEED],
+        has_one = admin @ EarnError::NotAuthorized,
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, Global>,
+
+    #[account(
+        mut,
+        seeds = [ROLES_SEED],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Account<'info, Roles>,
+}
+
+pub fn handler(ctx: Context<RevokeRole>, role: Role, member: Pubkey) -> Result<()> {
+    let members = role_members(&mut ctx.accounts.roles_account, role);
+
+    let Some(position) = members.iter().position(|key| *key == member) else {
+        return err!(EarnError::InvalidParam);
+    };
+
+    members.remove(position);
+
+    emit!(RoleRevoked { role, member });
+
+    Ok(())
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub role: Role,
+    pub member: Pubkey,
+}
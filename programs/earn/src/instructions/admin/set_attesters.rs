@@ -0,0 +1,36 @@
+// earn/instructions/admin/set_attesters.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+
+// local dependencies
+use super::AdminAction;
+use crate::{errors::EarnError, state::MAX_ATTESTERS};
+
+pub fn handler(ctx: Context<AdminAction>, attesters: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    if attesters.len() > MAX_ATTESTERS || threshold as usize > attesters.len() {
+        return err!(EarnError::InvalidParam);
+    }
+
+    if attesters
+        .iter()
+        .enumerate()
+        .any(|(i, attester)| attesters[..i].contains(attester))
+    {
+        return err!(EarnError::InvalidParam);
+    }
+
+    // A threshold of 0 disables index attestations
+    ctx.accounts.global_account.attesters = attesters.clone();
+    ctx.accounts.global_account.attestation_threshold = threshold;
+
+    emit!(AttestersSet { attesters, threshold });
+
+    Ok(())
+}
+
+#[event]
+pub struct AttestersSet {
+    pub attesters: Vec<Pubkey>,
+    pub threshold: u8,
+}
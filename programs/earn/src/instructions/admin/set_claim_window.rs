@@ -0,0 +1,35 @@
+// earn/instructions/admin/set_claim_window.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+
+// local dependencies
+use super::AdminAction;
+use crate::{
+    constants::{MAX_CLAIM_WINDOW, MIN_CLAIM_WINDOW},
+    errors::EarnError,
+};
+
+pub fn handler(ctx: Context<AdminAction>, claim_window: u64) -> Result<()> {
+    // The window must leave the earn authority time to process claims,
+    // and must not let a stalled cycle block new ones for too long. 0 disables it.
+    if claim_window != 0 && !(MIN_CLAIM_WINDOW..=MAX_CLAIM_WINDOW).contains(&claim_window) {
+        return err!(EarnError::InvalidClaimWindow);
+    }
+
+    let old_claim_window = ctx.accounts.global_account.claim_window;
+    ctx.accounts.global_account.claim_window = claim_window;
+
+    emit!(ClaimWindowUpdate {
+        old_claim_window,
+        new_claim_window: claim_window,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ClaimWindowUpdate {
+    pub old_claim_window: u64,
+    pub new_claim_window: u64,
+}
@@ -0,0 +1,91 @@
+// earn/instructions/admin/set_index_bounds.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+
+// local dependencies
+use crate::{
+    constants::{
+        ANCHOR_DISCRIMINATOR_SIZE, MAX_INDEX_RATE_BPS, MAX_INDEX_RATIO_BPS, ONE_HUNDRED_PERCENT,
+    },
+    errors::EarnError,
+    state::{Global, PendingIndex, GLOBAL_SEED, GLOBAL_VERSION, PENDING_INDEX_SEED},
+};
+
+#[derive(Accounts)]
+pub struct SetIndexBounds<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = global_account.version == GLOBAL_VERSION @ EarnError::InvalidVersion,
+        seeds = [GLOBAL_SEED],
+        has_one = admin @ EarnError::NotAuthorized,
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, Global>,
+
+    // Holds the updates quarantined by the bounds, so it has to exist before they are enforced
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ANCHOR_DISCRIMINATOR_SIZE + PendingIndex::INIT_SPACE,
+        seeds = [PENDING_INDEX_SEED],
+        bump,
+    )]
+    pub pending_index_account: Account<'info, PendingIndex>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SetIndexBounds>,
+    max_index_rate_bps: u64,
+    max_index_ratio_bps: u64,
+) -> Result<()> {
+    // The index can only increase, so a ratio bound of 100% or less would quarantine every update
+    if max_index_ratio_bps != 0
+        && !(ONE_HUNDRED_PERCENT + 1..=MAX_INDEX_RATIO_BPS).contains(&max_index_ratio_bps)
+    {
+        return err!(EarnError::InvalidIndexBounds);
+    }
+
+    if max_index_rate_bps > MAX_INDEX_RATE_BPS {
+        return err!(EarnError::InvalidIndexBounds);
+    }
+
+    // The rate bound allows larger updates the longer the index was not updated, the ratio bound
+    // caps them. A ratio that does not allow a single year of growth at the max rate would
+    // quarantine every update after a long gap even though its rate is within bounds.
+    if max_index_rate_bps != 0
+        && max_index_ratio_bps != 0
+        && max_index_ratio_bps < ONE_HUNDRED_PERCENT + max_index_rate_bps
+    {
+        return err!(EarnError::InvalidIndexBounds);
+    }
+
+    ctx.accounts.pending_index_account.bump = ctx.bumps.pending_index_account;
+
+    let global = &mut ctx.accounts.global_account;
+
+    emit!(IndexBoundsUpdate {
+        old_max_index_rate_bps: global.max_index_rate_bps,
+        old_max_index_ratio_bps: global.max_index_ratio_bps,
+        max_index_rate_bps,
+        max_index_ratio_bps,
+    });
+
+    global.max_index_rate_bps = max_index_rate_bps;
+    global.max_index_ratio_bps = max_index_ratio_bps;
+
+    Ok(())
+}
+
+#[event]
+pub struct IndexBoundsUpdate {
+    pub old_max_index_rate_bps: u64,
+    pub old_max_index_ratio_bps: u64,
+    pub max_index_rate_bps: u64,
+    pub max_index_ratio_bps: u64,
+}
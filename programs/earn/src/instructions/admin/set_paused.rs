@@ -0,0 +1,99 @@
+// earn/instructions/admin/set_paused.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+// local dependencies
+use crate::{
+    errors::EarnError,
+    instructions::portal::propagate_index::{apply_index_update, update_index},
+    state::{
+        Global, PendingIndex, Roles, GLOBAL_SEED, GLOBAL_VERSION, PENDING_INDEX_SEED, ROLES_SEED,
+    },
+    utils::roles::is_pauser,
+};
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = mint,
+        constraint = is_pauser(&global_account, &roles_account, &signer.key()) @ EarnError::NotAuthorized,
+        constraint = global_account.version == GLOBAL_VERSION @ EarnError::InvalidVersion,
+        seeds = [GLOBAL_SEED],
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, Global>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [ROLES_SEED],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Option<Account<'info, Roles>>,
+
+    // Only required to quarantine an update that is outside of the index bounds
+    #[account(
+        mut,
+        seeds = [PENDING_INDEX_SEED],
+        bump = pending_index_account.bump,
+    )]
+    pub pending_index_account: Option<Account<'info, PendingIndex>>,
+}
+
+pub fn handler(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+    let global = &mut ctx.accounts.global_account;
+
+    // Pausers can halt the program, but only the admin can resume it
+    if !paused && ctx.accounts.signer.key() != global.admin {
+        return err!(EarnError::NotAuthorized);
+    }
+
+    global.paused = paused;
+
+    emit!(PausedUpdate { paused });
+
+    // Apply the latest index update that was propagated while the program was paused
+    if !paused && global.queued_index != 0 {
+        let queued_index = global.queued_index;
+        let queued_earner_merkle_root = global.queued_earner_merkle_root;
+        let queued_earner_merkle_root_timestamp = global.queued_earner_merkle_root_timestamp;
+        let queued_index_approved = global.queued_index_approved;
+
+        global.queued_index = 0;
+        global.queued_earner_merkle_root = [0; 32];
+        global.queued_earner_merkle_root_timestamp = 0;
+        global.queued_index_approved = false;
+
+        // An index the admin approved while paused was already checked against the bounds
+        if queued_index_approved {
+            update_index(
+                global,
+                ctx.accounts.mint.supply,
+                queued_index,
+                queued_earner_merkle_root,
+                queued_earner_merkle_root_timestamp,
+            )?;
+        } else {
+            apply_index_update(
+                global,
+                ctx.accounts.pending_index_account.as_deref_mut(),
+                ctx.accounts.mint.supply,
+                queued_index,
+                queued_earner_merkle_root,
+                queued_earner_merkle_root_timestamp,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+#[event]
+pub struct PausedUpdate {
+    pub paused: bool,
+}
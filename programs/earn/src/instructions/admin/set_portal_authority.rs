@@ -0,0 +1,30 @@
+// earn/instructions/admin/set_portal_authority.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+
+// local dependencies
+use super::AdminAction;
+use crate::errors::EarnError;
+
+pub fn handler(ctx: Context<AdminAction>, new_portal_authority: Pubkey) -> Result<()> {
+    if new_portal_authority == Pubkey::default() {
+        return err!(EarnError::InvalidParam);
+    }
+
+    let old_portal_authority = ctx.accounts.global_account.portal_authority;
+    ctx.accounts.global_account.portal_authority = new_portal_authority;
+
+    emit!(PortalAuthorityUpdate {
+        old_portal_authority,
+        new_portal_authority,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct PortalAuthorityUpdate {
+    pub old_portal_authority: Pubkey,
+    pub new_portal_authority: Pubkey,
+}
@@ -0,0 +1,32 @@
+// earn/instructions/admin/set_removal_bounty.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+
+// local dependencies
+use super::AdminAction;
+use crate::{constants::ANCHOR_DISCRIMINATOR_SIZE, errors::EarnError, state::Earner};
+
+pub fn handler(ctx: Context<AdminAction>, removal_bounty: u64) -> Result<()> {
+    // The bounty is paid out of the rent of the removed earner account
+    let earner_rent = Rent::get()?.minimum_balance(ANCHOR_DISCRIMINATOR_SIZE + Earner::INIT_SPACE);
+
+    if removal_bounty > earner_rent {
+        return err!(EarnError::InvalidParam);
+    }
+
+    emit!(RemovalBountyUpdate {
+        old_removal_bounty: ctx.accounts.global_account.removal_bounty,
+        new_removal_bounty: removal_bounty,
+    });
+
+    ctx.accounts.global_account.removal_bounty = removal_bounty;
+
+    Ok(())
+}
+
+#[event]
+pub struct RemovalBountyUpdate {
+    pub old_removal_bounty: u64,
+    pub new_removal_bounty: u64,
+}
@@ -0,0 +1,64 @@
+// earn/instructions/admin/set_unclaimed_expiry.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+// local dependencies
+use crate::{
+    constants::MAX_UNCLAIMED_EXPIRY_CYCLES,
+    errors::EarnError,
+    state::{Global, GLOBAL_SEED, GLOBAL_VERSION},
+};
+
+#[derive(Accounts)]
+pub struct SetUnclaimedExpiry<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = global_account.version == GLOBAL_VERSION @ EarnError::InvalidVersion,
+        seeds = [GLOBAL_SEED],
+        has_one = admin @ EarnError::NotAuthorized,
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, Global>,
+
+    #[account(token::mint = global_account.mint)]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+}
+
+pub fn handler(ctx: Context<SetUnclaimedExpiry>, expiry_cycles: u64) -> Result<()> {
+    if expiry_cycles > MAX_UNCLAIMED_EXPIRY_CYCLES {
+        return err!(EarnError::InvalidParam);
+    }
+
+    let global = &mut ctx.accounts.global_account;
+
+    // Swept yield is written off from the max yield if no treasury is provided
+    let treasury = ctx
+        .accounts
+        .treasury_token_account
+        .as_ref()
+        .map(|token_account| token_account.key());
+
+    emit!(UnclaimedExpiryUpdate {
+        old_expiry_cycles: global.unclaimed_expiry_cycles,
+        new_expiry_cycles: expiry_cycles,
+        old_treasury: global.unclaimed_treasury,
+        new_treasury: treasury,
+    });
+
+    global.unclaimed_expiry_cycles = expiry_cycles;
+    global.unclaimed_treasury = treasury;
+
+    Ok(())
+}
+
+#[event]
+pub struct UnclaimedExpiryUpdate {
+    pub old_expiry_cycles: u64,
+    pub new_expiry_cycles: u64,
+    pub old_treasury: Option<Pubkey>,
+    pub new_treasury: Option<Pubkey>,
+}
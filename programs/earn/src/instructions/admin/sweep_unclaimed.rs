@@ -0,0 +1,133 @@
+// earn/instructions/admin/sweep_unclaimed.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+// local dependencies
+use crate::{
+    errors::EarnError,
+    state::{Global, GLOBAL_SEED, GLOBAL_VERSION, TOKEN_AUTHORITY_SEED},
+    utils::{rewards::expired_unclaimed_yield, token::mint_tokens},
+};
+
+// All of the expired yield is minted to the treasury when one is set and written off otherwise,
+// so the outcome of a sweep only depends on the unclaimed yield buckets and the configuration.
+#[derive(Accounts)]
+pub struct SweepUnclaimed<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin @ EarnError::NotAuthorized,
+        has_one = mint,
+        constraint = !global_account.paused @ EarnError::Paused,
+        constraint = global_account.version == GLOBAL_VERSION @ EarnError::InvalidVersion,
+        seeds = [GLOBAL_SEED],
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, Global>,
+
+    #[account(
+        mut,
+        owner = token_program.key(),
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: This account is checked in the CPI to Token2022 program
+    #[account(
+        seeds = [TOKEN_AUTHORITY_SEED],
+        bump
+    )]
+    pub token_authority_account: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = global_account.unclaimed_treasury == Some(treasury_token_account.key()) @ EarnError::InvalidAccount,
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: This account is checked in the CPI to Token2022 program
+    pub mint_multisig: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<SweepUnclaimed>) -> Result<()> {
+    let global = &mut ctx.accounts.global_account;
+
+    let amount = expired_unclaimed_yield(global);
+
+    if amount == 0 {
+        return err!(EarnError::NoExpiredYield);
+    }
+
+    let treasury_amount = if global.unclaimed_treasury.is_some() {
+        amount
+    } else {
+        0
+    };
+
+    // Remove the swept amount from the expired buckets, youngest first, so that the yield left in
+    // them matches what has been distributed this cycle and is consumed when the next cycle starts.
+    // The buckets always sum to the max yield, and the max yield remains >= the distributed amount.
+    let expiry_cycles = global.unclaimed_expiry_cycles as usize;
+    let mut remaining = amount;
+    for bucket in global.unclaimed_yield[expiry_cycles..].iter_mut() {
+        let swept = remaining.min(*bucket);
+        *bucket -= swept;
+        remaining -= swept;
+    }
+
+    global.max_yield -= amount;
+
+    // Mint the swept yield to the treasury, without one it is only written off
+    if treasury_amount > 0 {
+        let Some(treasury_token_account) = &ctx.accounts.treasury_token_account else {
+            return err!(EarnError::RequiredAccountMissing);
+        };
+
+        let token_authority_seeds: &[&[&[u8]]] =
+            &[&[TOKEN_AUTHORITY_SEED, &[ctx.bumps.token_authority_account]]];
+
+        mint_tokens(
+            treasury_token_account,                // to
+            &treasury_amount,                      // amount
+            &ctx.accounts.mint,                    // mint
+            &ctx.accounts.mint_multisig,           // multisig mint authority
+            &ctx.accounts.token_authority_account, // signer
+            token_authority_seeds,                 // signer seeds
+            &ctx.accounts.token_program,           // token program
+        )?;
+
+        ctx.accounts.mint.reload()?;
+
+        // Keep the max supply accurate since this mints M (see claim_for)
+        if ctx.accounts.mint.supply > global.max_supply {
+            global.max_supply = ctx.accounts.mint.supply;
+        }
+    }
+
+    emit!(UnclaimedYieldSweep {
+        amount,
+        treasury_amount,
+        treasury_token_account: global.unclaimed_treasury,
+        index: global.index,
+        max_yield: global.max_yield,
+        distributed: global.distributed,
+        ts: Clock::get()?.unix_timestamp.try_into().unwrap(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct UnclaimedYieldSweep {
+    pub amount: u64,
+    pub treasury_amount: u64, // amount minted to the treasury, 0 if the yield was written off
+    pub treasury_token_account: Option<Pubkey>,
+    pub index: u64,
+    pub max_yield: u64,
+    pub distributed: u64,
+    pub ts: u64,
+}
@@ -0,0 +1,30 @@
+// earn/instructions/admin/transfer_admin.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+
+// local dependencies
+use super::AdminAction;
+use crate::errors::EarnError;
+
+pub fn handler(ctx: Context<AdminAction>, new_admin: Pubkey) -> Result<()> {
+    if new_admin == Pubkey::default() {
+        return err!(EarnError::InvalidParam);
+    }
+
+    // The transfer only takes effect once the new admin accepts it
+    ctx.accounts.global_account.pending_admin = Some(new_admin);
+
+    emit!(AdminTransferStarted {
+        admin: ctx.accounts.global_account.admin,
+        pending_admin: new_admin,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AdminTransferStarted {
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+}
@@ -7,8 +7,11 @@ use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 // local dependencies
 use crate::{
     errors::EarnError,
-    state::{Earner, Global, EARNER_SEED, GLOBAL_SEED, TOKEN_AUTHORITY_SEED},
-    utils::token::mint_tokens,
+    state::{
+        Earner, Global, Roles, EARNER_SEED, EARNER_VERSION, GLOBAL_SEED, GLOBAL_VERSION,
+        ROLES_SEED, TOKEN_AUTHORITY_SEED,
+    },
+    utils::{rewards::settle_claim, roles::is_earn_authority, token::mint_tokens},
 };
 
 #[derive(Accounts)]
@@ -18,7 +21,9 @@ pub struct ClaimFor<'info> {
     #[account(
         mut,
         has_one = mint,
-        has_one = earn_authority @ EarnError::NotAuthorized,
+        constraint = is_earn_authority(&global_account, &roles_account, &earn_authority.key()) @ EarnError::NotAuthorized,
+        constraint = !global_account.paused @ EarnError::Paused,
+        constraint = global_account.version == GLOBAL_VERSION @ EarnError::InvalidVersion,
         seeds = [GLOBAL_SEED],
         bump = global_account.bump,
     )]
@@ -39,12 +44,16 @@ pub struct ClaimFor<'info> {
 
     #[account(
         mut,
-        address = earner_account.user_token_account
+        address = match earner_account.recipient_token_account {
+            Some(token_account) => token_account,
+            None => earner_account.user_token_account,
+        } @ EarnError::InvalidAccount,
     )]
     pub user_token_account: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
+        constraint = earner_account.version == EARNER_VERSION @ EarnError::InvalidVersion,
         seeds = [EARNER_SEED, earner_account.user_token_account.as_ref()],
         bump = earner_account.bump,
     )]
@@ -54,50 +63,21 @@ pub struct ClaimFor<'info> {
 
     /// CHECK: This account is checked in the CPI to Token2022 program
     pub mint_multisig: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [ROLES_SEED],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Option<Account<'info, Roles>>,
 }
 
 pub fn handler(ctx: Context<ClaimFor>, snapshot_balance: u64) -> Result<()> {
-    // Validate that the earner account has not already claimed this cycle
-    // Earner index should never be > global index, but we check to be safe against an error with index propagation
-    if ctx.accounts.earner_account.last_claim_index >= ctx.accounts.global_account.index {
-        return err!(EarnError::AlreadyClaimed);
-    }
-
-    // Validate there is an active claim cycle
-    if ctx.accounts.global_account.claim_complete {
-        return err!(EarnError::NoActiveClaim);
-    }
-
-    // Calculate the amount of tokens to send to the user
-    // Cast to u128 for multiplication to avoid overflows
-    let mut rewards: u64 = (snapshot_balance as u128)
-        .checked_mul(ctx.accounts.global_account.index.into())
-        .unwrap()
-        .checked_div(ctx.accounts.earner_account.last_claim_index.into())
-        .unwrap()
-        .try_into()
-        .unwrap();
-
-    rewards -= snapshot_balance; // can't underflow because global index > last claim index
-
-    // Validate the rewards do not cause the distributed amount to exceed the max yield
-    let distributed = ctx
-        .accounts
-        .global_account
-        .distributed
-        .checked_add(rewards)
-        .unwrap();
-
-    if distributed > ctx.accounts.global_account.max_yield {
-        return err!(EarnError::ExceedsMaxYield);
-    }
-
-    // Update the total distributed
-    ctx.accounts.global_account.distributed = distributed;
-
-    // Set the earner's last claim index to the global index and update the last claim timestamp
-    ctx.accounts.earner_account.last_claim_index = ctx.accounts.global_account.index;
-    ctx.accounts.earner_account.last_claim_timestamp = ctx.accounts.global_account.timestamp;
+    // Validate the claim and update the distributed amount and earner's last claim index
+    let rewards = settle_claim(
+        &mut ctx.accounts.global_account,
+        &mut ctx.accounts.earner_account,
+        snapshot_balance,
+    )?;
 
     // Setup the signer seeds for the mint CPI(s)
     let token_authority_seeds: &[&[&[u8]]] =
@@ -127,7 +107,7 @@ pub fn handler(ctx: Context<ClaimFor>, snapshot_balance: u64) -> Result<()> {
     }
 
     emit!(RewardsClaim {
-        token_account: ctx.accounts.user_token_account.key(),
+        token_account: ctx.accounts.earner_account.user_token_account,
         recipient_token_account: ctx.accounts.user_token_account.key(),
         amount: rewards,
         ts: ctx.accounts.earner_account.last_claim_timestamp,
@@ -0,0 +1,138 @@
+// earn/instructions/earn_authority/claim_for_batch.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+// local dependencies
+use crate::{
+    errors::EarnError,
+    instructions::claim_for::RewardsClaim,
+    state::{
+        Earner, Global, Roles, EARNER_SEED, EARNER_VERSION, GLOBAL_SEED, GLOBAL_VERSION,
+        ROLES_SEED, TOKEN_AUTHORITY_SEED,
+    },
+    utils::{rewards::settle_claim, roles::is_earn_authority, token::mint_tokens},
+};
+
+#[derive(Accounts)]
+pub struct ClaimForBatch<'info> {
+    pub earn_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = mint,
+        constraint = is_earn_authority(&global_account, &roles_account, &earn_authority.key()) @ EarnError::NotAuthorized,
+        constraint = !global_account.paused @ EarnError::Paused,
+        constraint = global_account.version == GLOBAL_VERSION @ EarnError::InvalidVersion,
+        seeds = [GLOBAL_SEED],
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, Global>,
+
+    #[account(
+        mut,
+        owner = token_program.key(),
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: This account is checked in the CPI to Token2022 program
+    #[account(
+        seeds = [TOKEN_AUTHORITY_SEED],
+        bump
+    )]
+    pub token_authority_account: AccountInfo<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: This account is checked in the CPI to Token2022 program
+    pub mint_multisig: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [ROLES_SEED],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Option<Account<'info, Roles>>,
+    // remaining accounts: (earner_account, recipient_token_account) for each snapshot balance
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimForBatch<'info>>,
+    snapshot_balances: Vec<u64>,
+) -> Result<()> {
+    // Each snapshot balance requires an earner account and its token account
+    if ctx.remaining_accounts.len() != snapshot_balances.len() * 2 {
+        return err!(EarnError::InvalidParam);
+    }
+
+    let token_authority_seeds: &[&[&[u8]]] =
+        &[&[TOKEN_AUTHORITY_SEED, &[ctx.bumps.token_authority_account]]];
+
+    for (accounts, snapshot_balance) in ctx.remaining_accounts.chunks(2).zip(snapshot_balances) {
+        let mut earner_account = Account::<Earner>::try_from(&accounts[0])?;
+        let recipient_token_account = InterfaceAccount::<TokenAccount>::try_from(&accounts[1])?;
+
+        // Validate the earner PDA and that the token account is the one receiving its yield
+        let expected_earner = Pubkey::create_program_address(
+            &[
+                EARNER_SEED,
+                earner_account.user_token_account.as_ref(),
+                &[earner_account.bump],
+            ],
+            ctx.program_id,
+        )
+        .map_err(|_| EarnError::InvalidAccount)?;
+
+        if earner_account.version != EARNER_VERSION {
+            return err!(EarnError::InvalidVersion);
+        }
+
+        if earner_account.key() != expected_earner
+            || recipient_token_account.key()
+                != earner_account
+                    .recipient_token_account
+                    .unwrap_or(earner_account.user_token_account)
+        {
+            return err!(EarnError::InvalidAccount);
+        }
+
+        // Validate the claim and update the distributed amount and earner's last claim index
+        // The max yield check is cumulative since the global account is updated on each iteration
+        let rewards = settle_claim(
+            &mut ctx.accounts.global_account,
+            &mut earner_account,
+            snapshot_balance,
+        )?;
+
+        // Persist the earner update so a duplicate entry in the batch fails as already claimed
+        earner_account.exit(ctx.program_id)?;
+
+        mint_tokens(
+            &recipient_token_account,              // to
+            &rewards,                              // amount
+            &ctx.accounts.mint,                    // mint
+            &ctx.accounts.mint_multisig,           // multisig mint authority
+            &ctx.accounts.token_authority_account, // signer
+            token_authority_seeds,                 // signer seeds
+            &ctx.accounts.token_program,           // token program
+        )?;
+
+        emit!(RewardsClaim {
+            token_account: earner_account.user_token_account,
+            recipient_token_account: recipient_token_account.key(),
+            amount: rewards,
+            ts: earner_account.last_claim_timestamp,
+            index: ctx.accounts.global_account.index,
+            fee: 0,
+        });
+    }
+
+    ctx.accounts.mint.reload()?;
+
+    // Keep the max supply accurate since this mints M (see claim_for)
+    if ctx.accounts.mint.supply > ctx.accounts.global_account.max_supply {
+        ctx.accounts.global_account.max_supply = ctx.accounts.mint.supply;
+    }
+
+    Ok(())
+}
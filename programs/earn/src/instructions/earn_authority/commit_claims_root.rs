@@ -0,0 +1,93 @@
+// earn/instructions/earn_authority/commit_claims_root.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+
+// local dependencies
+use crate::{
+    constants::ANCHOR_DISCRIMINATOR_SIZE,
+    errors::EarnError,
+    state::{
+        ClaimDistribution, Global, Roles, CLAIM_DISTRIBUTION_SEED, GLOBAL_SEED, GLOBAL_VERSION,
+        ROLES_SEED,
+    },
+    utils::roles::is_earn_authority,
+};
+
+#[derive(Accounts)]
+pub struct CommitClaimsRoot<'info> {
+    #[account(mut)]
+    pub earn_authority: Signer<'info>,
+
+    #[account(
+        constraint = is_earn_authority(&global_account, &roles_account, &earn_authority.key()) @ EarnError::NotAuthorized,
+        constraint = global_account.version == GLOBAL_VERSION @ EarnError::InvalidVersion,
+        seeds = [GLOBAL_SEED],
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, Global>,
+
+    #[account(
+        init,
+        payer = earn_authority,
+        space = ANCHOR_DISCRIMINATOR_SIZE + ClaimDistribution::INIT_SPACE,
+        seeds = [CLAIM_DISTRIBUTION_SEED, global_account.index.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub claim_distribution: Account<'info, ClaimDistribution>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        seeds = [ROLES_SEED],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Option<Account<'info, Roles>>,
+}
+
+pub fn handler(
+    ctx: Context<CommitClaimsRoot>,
+    claims_root: [u8; 32],
+    total_balance: u64,
+) -> Result<()> {
+    // Validate there is an active claim cycle
+    if ctx.accounts.global_account.claim_complete {
+        return err!(EarnError::NoActiveClaim);
+    }
+
+    if claims_root == [0u8; 32] {
+        return err!(EarnError::InvalidParam);
+    }
+
+    // Earner balances can never sum to more than the supply the max yield was sized from.
+    // The max yield itself is enforced incrementally as claims are processed.
+    if total_balance > ctx.accounts.global_account.max_supply {
+        return err!(EarnError::ExceedsMaxSupply);
+    }
+
+    ctx.accounts
+        .claim_distribution
+        .set_inner(ClaimDistribution {
+            index: ctx.accounts.global_account.index,
+            claims_root,
+            total_balance,
+            claimed_balance: 0,
+            claims: 0,
+            bump: ctx.bumps.claim_distribution,
+        });
+
+    emit!(ClaimsRootCommit {
+        index: ctx.accounts.global_account.index,
+        claims_root,
+        total_balance,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ClaimsRootCommit {
+    pub index: u64,
+    pub claims_root: [u8; 32],
+    pub total_balance: u64,
+}
@@ -5,31 +5,82 @@ use anchor_lang::prelude::*;
 
 // local dependencies
 use crate::{
+    constants::ANCHOR_DISCRIMINATOR_SIZE,
     errors::EarnError,
-    state::{Global, GLOBAL_SEED},
+    state::{ClaimCycle, Global, Roles, CLAIM_CYCLE_SEED, GLOBAL_SEED, GLOBAL_VERSION, ROLES_SEED},
+    utils::{
+        rewards::{claim_window_expired, close_claim_cycle, record_completed_cycle},
+        roles::is_earn_authority,
+    },
 };
 
 #[derive(Accounts)]
 pub struct CompleteClaims<'info> {
+    #[account(mut)]
     pub earn_authority: Signer<'info>,
 
     #[account(
         mut,
-        has_one = earn_authority @ EarnError::NotAuthorized,
+        constraint = is_earn_authority(&global_account, &roles_account, &earn_authority.key()) @ EarnError::NotAuthorized,
+        constraint = global_account.version == GLOBAL_VERSION @ EarnError::InvalidVersion,
         seeds = [GLOBAL_SEED],
         bump,
     )]
     pub global_account: Account<'info, Global>,
+
+    #[account(
+        init_if_needed,
+        payer = earn_authority,
+        space = ANCHOR_DISCRIMINATOR_SIZE + ClaimCycle::INIT_SPACE,
+        seeds = [CLAIM_CYCLE_SEED, global_account.index.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub claim_cycle_account: Account<'info, ClaimCycle>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        seeds = [ROLES_SEED],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Option<Account<'info, Roles>>,
 }
 
 pub fn handler(ctx: Context<CompleteClaims>) -> Result<()> {
-    // Validate that the latest claim cycle is not already completed
-    if ctx.accounts.global_account.claim_complete {
+    let global = &mut ctx.accounts.global_account;
+    let current_timestamp: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
+
+    if !global.claim_complete {
+        // The claim window may have passed without an index update closing the cycle.
+        // This fails if the previous cycle was not recorded, see record_claim_cycle.
+        let timed_out = claim_window_expired(global, current_timestamp);
+
+        close_claim_cycle(global, current_timestamp, timed_out)?;
+    } else if global.completed_cycle.index != global.index
+        || global.completed_cycle_recorded
+        || ctx.accounts.claim_cycle_account.completed_at != 0
+    {
+        // Validate that the latest claim cycle is not already completed and recorded
         return err!(EarnError::NoActiveClaim);
     }
 
-    // Set the claim_complete flag to true
-    ctx.accounts.global_account.claim_complete = true;
+    // Record the final state of the claim cycle, this also covers a cycle that
+    // completed automatically when its claim window passed
+    record_completed_cycle(
+        global,
+        &mut ctx.accounts.claim_cycle_account,
+        ctx.bumps.claim_cycle_account,
+    );
 
     Ok(())
 }
+
+#[event]
+pub struct ClaimCycleCompleted {
+    pub index: u64,
+    pub max_yield: u64,
+    pub distributed: u64,
+    pub claims: u64,
+    pub timed_out: bool, // true if the cycle closed because the claim window passed
+    pub ts: u64,
+}
@@ -1,16 +1,28 @@
 // earn/instructions/earn_authority/mod.rs
 
 pub mod claim_for;
+pub mod claim_for_batch;
+pub mod commit_claims_root;
 pub mod complete_claims;
+pub mod revoke_claims_root;
 
 pub use claim_for::ClaimFor;
 pub(crate) use claim_for::__client_accounts_claim_for;
+pub use claim_for_batch::ClaimForBatch;
+pub(crate) use claim_for_batch::__client_accounts_claim_for_batch;
+pub use commit_claims_root::CommitClaimsRoot;
+pub(crate) use commit_claims_root::__client_accounts_commit_claims_root;
 pub use complete_claims::CompleteClaims;
 pub(crate) use complete_claims::__client_accounts_complete_claims;
+pub use revoke_claims_root::RevokeClaimsRoot;
+pub(crate) use revoke_claims_root::__client_accounts_revoke_claims_root;
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "cpi")] {
         pub(crate) use claim_for::__cpi_client_accounts_claim_for;
+        pub(crate) use claim_for_batch::__cpi_client_accounts_claim_for_batch;
+        pub(crate) use commit_claims_root::__cpi_client_accounts_commit_claims_root;
         pub(crate) use complete_claims::__cpi_client_accounts_complete_claims;
+        pub(crate) use revoke_claims_root::__cpi_client_accounts_revoke_claims_root;
     }
 }
@@ -0,0 +1,65 @@
+// earn/instructions/earn_authority/revoke_claims_root.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+
+// local dependencies
+use crate::{
+    errors::EarnError,
+    state::{
+        ClaimDistribution, Global, Roles, CLAIM_DISTRIBUTION_SEED, GLOBAL_SEED, GLOBAL_VERSION,
+        ROLES_SEED,
+    },
+    utils::roles::is_earn_authority,
+};
+
+// Closes the claims root of the current cycle so that a wrong root can be committed again.
+// Claims already processed against the revoked root stay settled on the earner accounts.
+#[derive(Accounts)]
+pub struct RevokeClaimsRoot<'info> {
+    #[account(mut)]
+    pub earn_authority: Signer<'info>,
+
+    #[account(
+        constraint = is_earn_authority(&global_account, &roles_account, &earn_authority.key()) @ EarnError::NotAuthorized,
+        constraint = global_account.version == GLOBAL_VERSION @ EarnError::InvalidVersion,
+        seeds = [GLOBAL_SEED],
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, Global>,
+
+    #[account(
+        mut,
+        close = earn_authority,
+        seeds = [CLAIM_DISTRIBUTION_SEED, global_account.index.to_le_bytes().as_ref()],
+        bump = claim_distribution.bump,
+    )]
+    pub claim_distribution: Account<'info, ClaimDistribution>,
+
+    #[account(
+        seeds = [ROLES_SEED],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Option<Account<'info, Roles>>,
+}
+
+pub fn handler(ctx: Context<RevokeClaimsRoot>) -> Result<()> {
+    let distribution = &ctx.accounts.claim_distribution;
+
+    emit!(ClaimsRootRevoke {
+        index: distribution.index,
+        claims_root: distribution.claims_root,
+        claimed_balance: distribution.claimed_balance,
+        claims: distribution.claims,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ClaimsRootRevoke {
+    pub index: u64,
+    pub claims_root: [u8; 32],
+    pub claimed_balance: u64,
+    pub claims: u64,
+}
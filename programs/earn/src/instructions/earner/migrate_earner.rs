@@ -0,0 +1,105 @@
+// earn/instructions/earner/migrate_earner.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+// local dependencies
+use crate::{
+    constants::ANCHOR_DISCRIMINATOR_SIZE,
+    errors::EarnError,
+    state::{Earner, Global, EARNER_SEED, EARNER_VERSION, GLOBAL_SEED, GLOBAL_VERSION},
+    utils::{
+        account::{close_with_refund, is_rent_recipient},
+        token::has_immutable_owner,
+    },
+};
+
+#[derive(Accounts)]
+pub struct MigrateEarner<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        constraint = !global_account.paused @ EarnError::Paused,
+        constraint = global_account.version == GLOBAL_VERSION @ EarnError::InvalidVersion,
+        seeds = [GLOBAL_SEED],
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, Global>,
+
+    #[account(
+        mut,
+        has_one = user @ EarnError::NotAuthorized,
+        constraint = earner_account.version == EARNER_VERSION @ EarnError::InvalidVersion,
+        seeds = [EARNER_SEED, earner_account.user_token_account.as_ref()],
+        bump = earner_account.bump,
+    )]
+    pub earner_account: Account<'info, Earner>,
+
+    #[account(
+        token::mint = global_account.mint,
+        token::authority = user,
+        constraint = has_immutable_owner(&new_user_token_account) @ EarnError::MutableOwner,
+    )]
+    pub new_user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = user,
+        space = ANCHOR_DISCRIMINATOR_SIZE + Earner::INIT_SPACE,
+        seeds = [EARNER_SEED, new_user_token_account.key().as_ref()],
+        bump
+    )]
+    pub new_earner_account: Account<'info, Earner>,
+
+    /// CHECK: This account only receives the rent of the old earner account
+    #[account(
+        mut,
+        constraint = is_rent_recipient(&earner_account.rent_payer, &rent_payer.key(), &user.key()) @ EarnError::InvalidAccount,
+    )]
+    pub rent_payer: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateEarner>) -> Result<()> {
+    let earner = &ctx.accounts.earner_account;
+
+    // Carry over the accrual state and stats so no yield is forfeited
+    // The old account is closed to whoever paid for it, the user pays for the new one
+    ctx.accounts.new_earner_account.set_inner(Earner {
+        last_claim_index: earner.last_claim_index,
+        last_claim_timestamp: earner.last_claim_timestamp,
+        bump: ctx.bumps.new_earner_account,
+        user: earner.user,
+        user_token_account: ctx.accounts.new_user_token_account.key(),
+        recipient_token_account: earner.recipient_token_account,
+        total_rewards: earner.total_rewards,
+        claims: earner.claims,
+        first_earning_timestamp: earner.first_earning_timestamp,
+        version: EARNER_VERSION,
+        rent_payer: ctx.accounts.user.key(),
+        reserved: [0; 32],
+    });
+
+    emit!(EarnerMigrated {
+        user: earner.user,
+        old_user_token_account: earner.user_token_account,
+        new_user_token_account: ctx.accounts.new_user_token_account.key(),
+    });
+
+    close_with_refund(
+        &ctx.accounts.earner_account.to_account_info(),
+        &ctx.accounts.rent_payer,
+        &ctx.accounts.user.to_account_info(),
+        0,
+    )
+}
+
+#[event]
+pub struct EarnerMigrated {
+    pub user: Pubkey,
+    pub old_user_token_account: Pubkey,
+    pub new_user_token_account: Pubkey,
+}
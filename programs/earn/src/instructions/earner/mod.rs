@@ -0,0 +1,16 @@
+// earn/instructions/earner/mod.rs
+
+pub mod migrate_earner;
+pub mod set_recipient;
+
+pub use migrate_earner::MigrateEarner;
+pub(crate) use migrate_earner::__client_accounts_migrate_earner;
+pub use set_recipient::SetRecipient;
+pub(crate) use set_recipient::__client_accounts_set_recipient;
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "cpi")] {
+        pub(crate) use migrate_earner::__cpi_client_accounts_migrate_earner;
+        pub(crate) use set_recipient::__cpi_client_accounts_set_recipient;
+    }
+}
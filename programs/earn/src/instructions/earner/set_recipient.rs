@@ -0,0 +1,57 @@
+// earn/instructions/earner/set_recipient.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+// local dependencies
+use crate::{
+    errors::EarnError,
+    state::{Earner, Global, EARNER_SEED, EARNER_VERSION, GLOBAL_SEED, GLOBAL_VERSION},
+};
+
+#[derive(Accounts)]
+pub struct SetRecipient<'info> {
+    pub user: Signer<'info>,
+
+    #[account(
+        constraint = global_account.version == GLOBAL_VERSION @ EarnError::InvalidVersion,
+        seeds = [GLOBAL_SEED],
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, Global>,
+
+    #[account(
+        mut,
+        has_one = user @ EarnError::NotAuthorized,
+        constraint = earner_account.version == EARNER_VERSION @ EarnError::InvalidVersion,
+        seeds = [EARNER_SEED, earner_account.user_token_account.as_ref()],
+        bump = earner_account.bump,
+    )]
+    pub earner_account: Account<'info, Earner>,
+
+    #[account(token::mint = global_account.mint)]
+    pub recipient_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+}
+
+pub fn handler(ctx: Context<SetRecipient>) -> Result<()> {
+    // Yield is sent back to the user's token account if no recipient is provided
+    ctx.accounts.earner_account.recipient_token_account = ctx
+        .accounts
+        .recipient_token_account
+        .as_ref()
+        .map(|token_account| token_account.key());
+
+    emit!(RecipientSet {
+        user_token_account: ctx.accounts.earner_account.user_token_account,
+        recipient_token_account: ctx.accounts.earner_account.recipient_token_account,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RecipientSet {
+    pub user_token_account: Pubkey,
+    pub recipient_token_account: Option<Pubkey>, // None if yield goes to the user token account
+}
@@ -2,10 +2,12 @@
 
 pub mod admin;
 pub mod earn_authority;
+pub mod earner;
 pub mod open;
 pub mod portal;
 
 pub use admin::*;
 pub use earn_authority::*;
+pub use earner::*;
 pub use open::*;
 pub use portal::*;
@@ -0,0 +1,65 @@
+// earn/instructions/open/add_program_earner.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+// local dependencies
+use crate::{
+    constants::ANCHOR_DISCRIMINATOR_SIZE,
+    errors::EarnError,
+    state::{Earner, Global, EARNER_SEED, EARNER_VERSION, GLOBAL_SEED, GLOBAL_VERSION},
+    utils::token::has_immutable_owner,
+};
+
+#[derive(Accounts)]
+pub struct AddProgramEarner<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        constraint = !global_account.paused @ EarnError::Paused,
+        constraint = global_account.version == GLOBAL_VERSION @ EarnError::InvalidVersion,
+        seeds = [GLOBAL_SEED],
+        bump = global_account.bump
+    )]
+    pub global_account: Account<'info, Global>,
+
+    #[account(
+        token::mint = global_account.mint,
+        constraint = global_account.is_program_earner(&user_token_account.owner) @ EarnError::NotAuthorized,
+        constraint = has_immutable_owner(&user_token_account) @ EarnError::MutableOwner,
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = Earner::INIT_SPACE + ANCHOR_DISCRIMINATOR_SIZE,
+        seeds = [EARNER_SEED, user_token_account.key().as_ref()],
+        bump
+    )]
+    pub earner_account: Account<'info, Earner>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AddProgramEarner>) -> Result<()> {
+    // Approved by the admin instead of a proof against the earner merkle root
+    ctx.accounts.earner_account.set_inner(Earner {
+        last_claim_index: ctx.accounts.global_account.index,
+        last_claim_timestamp: ctx.accounts.global_account.timestamp,
+        bump: ctx.bumps.earner_account,
+        user: ctx.accounts.user_token_account.owner,
+        user_token_account: ctx.accounts.user_token_account.key(),
+        recipient_token_account: None,
+        total_rewards: 0,
+        claims: 0,
+        first_earning_timestamp: ctx.accounts.global_account.timestamp,
+        version: EARNER_VERSION,
+        rent_payer: ctx.accounts.signer.key(),
+        reserved: [0; 32],
+    });
+
+    Ok(())
+}
@@ -8,7 +8,7 @@ use anchor_spl::token_interface::TokenAccount;
 use crate::{
     constants::ANCHOR_DISCRIMINATOR_SIZE,
     errors::EarnError,
-    state::{Earner, Global, EARNER_SEED, GLOBAL_SEED},
+    state::{Earner, Global, EARNER_SEED, EARNER_VERSION, GLOBAL_SEED, GLOBAL_VERSION},
     utils::{
         merkle_proof::{verify_in_tree, ProofElement},
         token::has_immutable_owner,
@@ -22,6 +22,8 @@ pub struct AddRegistrarEarner<'info> {
     pub signer: Signer<'info>,
 
     #[account(
+        constraint = !global_account.paused @ EarnError::Paused,
+        constraint = global_account.version == GLOBAL_VERSION @ EarnError::InvalidVersion,
         seeds = [GLOBAL_SEED],
         bump = global_account.bump
     )]
@@ -69,6 +71,13 @@ pub fn handler(
         bump: ctx.bumps.earner_account,
         user,
         user_token_account: ctx.accounts.user_token_account.key(),
+        recipient_token_account: None,
+        total_rewards: 0,
+        claims: 0,
+        first_earning_timestamp: ctx.accounts.global_account.timestamp,
+        version: EARNER_VERSION,
+        rent_payer: ctx.accounts.signer.key(),
+        reserved: [0; 32],
     });
 
     Ok(())
@@ -0,0 +1,185 @@
+// earn/instructions/open/add_registrar_earners.rs
+
+// external dependencies
+use anchor_lang::{
+    prelude::*,
+    system_program::{
+        allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+    },
+};
+use anchor_spl::token_interface::TokenAccount;
+
+// local dependencies
+use crate::{
+    constants::ANCHOR_DISCRIMINATOR_SIZE,
+    errors::EarnError,
+    state::{Earner, Global, EARNER_SEED, EARNER_VERSION, GLOBAL_SEED, GLOBAL_VERSION},
+    utils::{
+        merkle_proof::{verify_multi_in_tree, ProofElement},
+        token::has_immutable_owner,
+    },
+};
+
+#[derive(Accounts)]
+pub struct AddRegistrarEarners<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        constraint = !global_account.paused @ EarnError::Paused,
+        constraint = global_account.version == GLOBAL_VERSION @ EarnError::InvalidVersion,
+        seeds = [GLOBAL_SEED],
+        bump = global_account.bump
+    )]
+    pub global_account: Account<'info, Global>,
+
+    pub system_program: Program<'info, System>,
+    // remaining accounts: (user_token_account, earner_account) for each user
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, AddRegistrarEarners<'info>>,
+    users: Vec<Pubkey>,
+    proof: Vec<ProofElement>,
+    proof_flags: Vec<bool>,
+) -> Result<()> {
+    // Each user requires a token account and the earner account to create for it
+    if ctx.remaining_accounts.len() != users.len() * 2 {
+        return err!(EarnError::InvalidParam);
+    }
+
+    // Verify the user is not the default public key (system program)
+    if users.iter().any(|user| *user == Pubkey::default()) {
+        return err!(EarnError::InvalidParam);
+    }
+
+    // Verify all users are in the approved earners list
+    // Users must be sorted in ascending order (the order of the leaves in the tree)
+    let values: Vec<[u8; 32]> = users.iter().map(|user| user.to_bytes()).collect();
+    verify_multi_in_tree(
+        ctx.accounts.global_account.earner_merkle_root,
+        &values,
+        proof,
+        proof_flags,
+    )?;
+
+    for (accounts, user) in ctx.remaining_accounts.chunks(2).zip(users) {
+        let user_token_account = InterfaceAccount::<TokenAccount>::try_from(&accounts[0])?;
+        let earner_info = &accounts[1];
+
+        // Same constraints as add_registrar_earner
+        if user_token_account.mint != ctx.accounts.global_account.mint
+            || user_token_account.owner != user
+        {
+            return err!(EarnError::InvalidAccount);
+        }
+
+        if !has_immutable_owner(&user_token_account) {
+            return err!(EarnError::MutableOwner);
+        }
+
+        let (earner_key, bump) = Pubkey::find_program_address(
+            &[EARNER_SEED, user_token_account.key().as_ref()],
+            ctx.program_id,
+        );
+
+        if earner_info.key() != earner_key {
+            return err!(EarnError::InvalidAccount);
+        }
+
+        if earner_info.owner != &System::id() || !earner_info.data_is_empty() {
+            return err!(EarnError::AlreadyEarns);
+        }
+
+        create_earner_account(
+            &ctx.accounts.signer,
+            earner_info,
+            &ctx.accounts.system_program,
+            &[&[EARNER_SEED, user_token_account.key().as_ref(), &[bump]]],
+            ctx.program_id,
+        )?;
+
+        let earner = Earner {
+            last_claim_index: ctx.accounts.global_account.index,
+            last_claim_timestamp: ctx.accounts.global_account.timestamp,
+            bump,
+            user,
+            user_token_account: user_token_account.key(),
+            recipient_token_account: None,
+            total_rewards: 0,
+            claims: 0,
+            first_earning_timestamp: ctx.accounts.global_account.timestamp,
+            version: EARNER_VERSION,
+            rent_payer: ctx.accounts.signer.key(),
+            reserved: [0; 32],
+        };
+
+        earner.try_serialize(&mut &mut earner_info.try_borrow_mut_data()?[..])?;
+    }
+
+    Ok(())
+}
+
+// Mirrors anchor's `init` constraint, which also handles accounts that have been pre-funded
+fn create_earner_account<'info>(
+    payer: &Signer<'info>,
+    earner_info: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    signer_seeds: &[&[&[u8]]],
+    program_id: &Pubkey,
+) -> Result<()> {
+    let space = ANCHOR_DISCRIMINATOR_SIZE + Earner::INIT_SPACE;
+    let rent = Rent::get()?.minimum_balance(space);
+    let current_lamports = earner_info.lamports();
+
+    if current_lamports == 0 {
+        return create_account(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                CreateAccount {
+                    from: payer.to_account_info(),
+                    to: earner_info.clone(),
+                },
+                signer_seeds,
+            ),
+            rent,
+            space as u64,
+            program_id,
+        );
+    }
+
+    if current_lamports < rent {
+        transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: payer.to_account_info(),
+                    to: earner_info.clone(),
+                },
+            ),
+            rent - current_lamports,
+        )?;
+    }
+
+    allocate(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            Allocate {
+                account_to_allocate: earner_info.clone(),
+            },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+
+    assign(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            Assign {
+                account_to_assign: earner_info.clone(),
+            },
+            signer_seeds,
+        ),
+        program_id,
+    )
+}
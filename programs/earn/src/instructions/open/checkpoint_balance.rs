@@ -0,0 +1,59 @@
+// earn/instructions/open/checkpoint_balance.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+// local dependencies
+use crate::state::{BalanceCheckpoint, BALANCE_CHECKPOINT_SEED};
+
+#[derive(Accounts)]
+pub struct CheckpointBalance<'info> {
+    #[account(
+        mut,
+        has_one = user_token_account,
+        seeds = [BALANCE_CHECKPOINT_SEED, balance_checkpoint.user_token_account.as_ref()],
+        bump = balance_checkpoint.bump,
+    )]
+    pub balance_checkpoint: Account<'info, BalanceCheckpoint>,
+
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+}
+
+pub fn handler(ctx: Context<CheckpointBalance>) -> Result<()> {
+    let current_timestamp: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
+
+    record_balance(
+        &mut ctx.accounts.balance_checkpoint,
+        ctx.accounts.user_token_account.amount,
+        current_timestamp,
+    );
+
+    Ok(())
+}
+
+/// Records an observation of the earner's balance.
+/// The minimum balance only ever decreases until the window is reset by a claim.
+pub fn record_balance(checkpoint: &mut BalanceCheckpoint, balance: u64, timestamp: u64) {
+    if balance < checkpoint.min_balance {
+        checkpoint.min_balance = balance;
+    }
+
+    checkpoint.last_balance = balance;
+    checkpoint.last_update = timestamp;
+
+    emit!(BalanceCheckpointUpdate {
+        token_account: checkpoint.user_token_account,
+        balance,
+        min_balance: checkpoint.min_balance,
+        ts: timestamp,
+    });
+}
+
+#[event]
+pub struct BalanceCheckpointUpdate {
+    pub token_account: Pubkey,
+    pub balance: u64,
+    pub min_balance: u64,
+    pub ts: u64,
+}
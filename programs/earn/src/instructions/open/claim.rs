@@ -0,0 +1,140 @@
+// earn/instructions/open/claim.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+// local dependencies
+use super::checkpoint_balance::record_balance;
+use crate::{
+    errors::EarnError,
+    instructions::claim_for::RewardsClaim,
+    state::{
+        BalanceCheckpoint, Earner, Global, BALANCE_CHECKPOINT_SEED, EARNER_SEED, EARNER_VERSION,
+        GLOBAL_SEED, GLOBAL_VERSION, TOKEN_AUTHORITY_SEED,
+    },
+    utils::{rewards::settle_claim, token::mint_tokens},
+};
+
+#[derive(Accounts)]
+pub struct Claim<'info> {
+    #[account(
+        mut,
+        has_one = mint,
+        constraint = !global_account.paused @ EarnError::Paused,
+        constraint = global_account.version == GLOBAL_VERSION @ EarnError::InvalidVersion,
+        seeds = [GLOBAL_SEED],
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, Global>,
+
+    #[account(
+        mut,
+        owner = token_program.key(),
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: This account is checked in the CPI to Token2022 program
+    #[account(
+        seeds = [TOKEN_AUTHORITY_SEED],
+        bump
+    )]
+    pub token_authority_account: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        address = earner_account.user_token_account
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        address = match earner_account.recipient_token_account {
+            Some(token_account) => token_account,
+            None => earner_account.user_token_account,
+        } @ EarnError::InvalidAccount,
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = earner_account.version == EARNER_VERSION @ EarnError::InvalidVersion,
+        seeds = [EARNER_SEED, earner_account.user_token_account.as_ref()],
+        bump = earner_account.bump,
+    )]
+    pub earner_account: Account<'info, Earner>,
+
+    #[account(
+        mut,
+        seeds = [BALANCE_CHECKPOINT_SEED, earner_account.user_token_account.as_ref()],
+        bump = balance_checkpoint.bump,
+    )]
+    pub balance_checkpoint: Account<'info, BalanceCheckpoint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: This account is checked in the CPI to Token2022 program
+    pub mint_multisig: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<Claim>) -> Result<()> {
+    // The checkpoint must have been observing the balance for the whole period being claimed
+    if ctx.accounts.balance_checkpoint.start_index > ctx.accounts.earner_account.last_claim_index {
+        return err!(EarnError::InvalidCheckpoint);
+    }
+
+    // Include the current balance in the observations before using it
+    let current_timestamp: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
+    record_balance(
+        &mut ctx.accounts.balance_checkpoint,
+        ctx.accounts.user_token_account.amount,
+        current_timestamp,
+    );
+
+    // Rewards are calculated on the lowest balance held during the window
+    let rewards = settle_claim(
+        &mut ctx.accounts.global_account,
+        &mut ctx.accounts.earner_account,
+        ctx.accounts.balance_checkpoint.min_balance,
+    )?;
+
+    let token_authority_seeds: &[&[&[u8]]] =
+        &[&[TOKEN_AUTHORITY_SEED, &[ctx.bumps.token_authority_account]]];
+
+    mint_tokens(
+        &ctx.accounts.recipient_token_account, // to
+        &rewards,                              // amount
+        &ctx.accounts.mint,                    // mint
+        &ctx.accounts.mint_multisig,           // multisig mint authority
+        &ctx.accounts.token_authority_account, // signer
+        token_authority_seeds,                 // signer seeds
+        &ctx.accounts.token_program,           // token program
+    )?;
+
+    ctx.accounts.mint.reload()?;
+    ctx.accounts.user_token_account.reload()?;
+
+    // Keep the max supply accurate since this mints M (see claim_for)
+    if ctx.accounts.mint.supply > ctx.accounts.global_account.max_supply {
+        ctx.accounts.global_account.max_supply = ctx.accounts.mint.supply;
+    }
+
+    // Open a new observation window starting from the balance after the claim
+    let balance = ctx.accounts.user_token_account.amount;
+    let checkpoint = &mut ctx.accounts.balance_checkpoint;
+    checkpoint.start_index = ctx.accounts.global_account.index;
+    checkpoint.min_balance = balance;
+    checkpoint.last_balance = balance;
+    checkpoint.last_update = current_timestamp;
+
+    emit!(RewardsClaim {
+        token_account: ctx.accounts.user_token_account.key(),
+        recipient_token_account: ctx.accounts.recipient_token_account.key(),
+        amount: rewards,
+        ts: ctx.accounts.earner_account.last_claim_timestamp,
+        index: ctx.accounts.global_account.index,
+        fee: 0,
+    });
+
+    Ok(())
+}
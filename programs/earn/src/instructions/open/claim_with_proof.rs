@@ -0,0 +1,139 @@
+// earn/instructions/open/claim_with_proof.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+// local dependencies
+use crate::{
+    errors::EarnError,
+    instructions::claim_for::RewardsClaim,
+    state::{
+        ClaimDistribution, Earner, Global, CLAIM_DISTRIBUTION_SEED, EARNER_SEED, EARNER_VERSION,
+        GLOBAL_SEED, GLOBAL_VERSION, TOKEN_AUTHORITY_SEED,
+    },
+    utils::{
+        merkle_proof::{verify_claim_in_tree, ProofElement},
+        rewards::settle_claim,
+        token::mint_tokens,
+    },
+};
+
+#[derive(Accounts)]
+pub struct ClaimWithProof<'info> {
+    #[account(
+        mut,
+        has_one = mint,
+        constraint = !global_account.paused @ EarnError::Paused,
+        constraint = global_account.version == GLOBAL_VERSION @ EarnError::InvalidVersion,
+        seeds = [GLOBAL_SEED],
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, Global>,
+
+    #[account(
+        mut,
+        seeds = [CLAIM_DISTRIBUTION_SEED, global_account.index.to_le_bytes().as_ref()],
+        bump = claim_distribution.bump,
+    )]
+    pub claim_distribution: Account<'info, ClaimDistribution>,
+
+    #[account(
+        mut,
+        owner = token_program.key(),
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: This account is checked in the CPI to Token2022 program
+    #[account(
+        seeds = [TOKEN_AUTHORITY_SEED],
+        bump
+    )]
+    pub token_authority_account: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        address = match earner_account.recipient_token_account {
+            Some(token_account) => token_account,
+            None => earner_account.user_token_account,
+        } @ EarnError::InvalidAccount,
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = earner_account.version == EARNER_VERSION @ EarnError::InvalidVersion,
+        seeds = [EARNER_SEED, earner_account.user_token_account.as_ref()],
+        bump = earner_account.bump,
+    )]
+    pub earner_account: Account<'info, Earner>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: This account is checked in the CPI to Token2022 program
+    pub mint_multisig: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<ClaimWithProof>,
+    snapshot_balance: u64,
+    proof: Vec<ProofElement>,
+) -> Result<()> {
+    // Verify the snapshot balance was committed to by the earn authority for this cycle
+    verify_claim_in_tree(
+        ctx.accounts.claim_distribution.claims_root,
+        ctx.accounts.earner_account.user_token_account,
+        snapshot_balance,
+        proof,
+    )?;
+
+    // Track the committed balances that have been claimed against the root
+    let distribution = &mut ctx.accounts.claim_distribution;
+    distribution.claimed_balance = distribution
+        .claimed_balance
+        .checked_add(snapshot_balance)
+        .unwrap();
+    distribution.claims += 1;
+
+    if distribution.claimed_balance > distribution.total_balance {
+        return err!(EarnError::ExceedsCommittedBalance);
+    }
+
+    // Validate the claim and update the distributed amount and earner's last claim index
+    let rewards = settle_claim(
+        &mut ctx.accounts.global_account,
+        &mut ctx.accounts.earner_account,
+        snapshot_balance,
+    )?;
+
+    let token_authority_seeds: &[&[&[u8]]] =
+        &[&[TOKEN_AUTHORITY_SEED, &[ctx.bumps.token_authority_account]]];
+
+    mint_tokens(
+        &ctx.accounts.user_token_account,      // to
+        &rewards,                              // amount
+        &ctx.accounts.mint,                    // mint
+        &ctx.accounts.mint_multisig,           // multisig mint authority
+        &ctx.accounts.token_authority_account, // signer
+        token_authority_seeds,                 // signer seeds
+        &ctx.accounts.token_program,           // token program
+    )?;
+
+    ctx.accounts.mint.reload()?;
+
+    // Keep the max supply accurate since this mints M (see claim_for)
+    if ctx.accounts.mint.supply > ctx.accounts.global_account.max_supply {
+        ctx.accounts.global_account.max_supply = ctx.accounts.mint.supply;
+    }
+
+    emit!(RewardsClaim {
+        token_account: ctx.accounts.earner_account.user_token_account,
+        recipient_token_account: ctx.accounts.user_token_account.key(),
+        amount: rewards,
+        ts: ctx.accounts.earner_account.last_claim_timestamp,
+        index: ctx.accounts.global_account.index,
+        fee: 0,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+// earn/instructions/open/create_balance_checkpoint.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+// local dependencies
+use crate::{
+    constants::ANCHOR_DISCRIMINATOR_SIZE,
+    errors::EarnError,
+    state::{
+        BalanceCheckpoint, Earner, Global, BALANCE_CHECKPOINT_SEED, EARNER_SEED, EARNER_VERSION,
+        GLOBAL_SEED, GLOBAL_VERSION,
+    },
+};
+
+#[derive(Accounts)]
+pub struct CreateBalanceCheckpoint<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        constraint = global_account.version == GLOBAL_VERSION @ EarnError::InvalidVersion,
+        seeds = [GLOBAL_SEED],
+        bump = global_account.bump
+    )]
+    pub global_account: Account<'info, Global>,
+
+    #[account(
+        has_one = user_token_account,
+        constraint = earner_account.version == EARNER_VERSION @ EarnError::InvalidVersion,
+        seeds = [EARNER_SEED, earner_account.user_token_account.as_ref()],
+        bump = earner_account.bump,
+    )]
+    pub earner_account: Account<'info, Earner>,
+
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = ANCHOR_DISCRIMINATOR_SIZE + BalanceCheckpoint::INIT_SPACE,
+        seeds = [BALANCE_CHECKPOINT_SEED, user_token_account.key().as_ref()],
+        bump
+    )]
+    pub balance_checkpoint: Account<'info, BalanceCheckpoint>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateBalanceCheckpoint>) -> Result<()> {
+    let current_timestamp: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
+    let balance = ctx.accounts.user_token_account.amount;
+
+    // The observation window starts at the current global index. The earner can only
+    // self-claim for periods that start at or after this index, so balances held before
+    // the checkpoint existed can never be used to inflate a claim.
+    ctx.accounts.balance_checkpoint.set_inner(BalanceCheckpoint {
+        user_token_account: ctx.accounts.user_token_account.key(),
+        start_index: ctx.accounts.global_account.index,
+        min_balance: balance,
+        last_balance: balance,
+        last_update: current_timestamp,
+        bump: ctx.bumps.balance_checkpoint,
+    });
+
+    Ok(())
+}
@@ -1,16 +1,60 @@
 // earn/instructions/open/mod.rs
 
+pub mod add_program_earner;
 pub mod add_registrar_earner;
+pub mod add_registrar_earners;
+pub mod checkpoint_balance;
+pub mod claim;
+pub mod claim_with_proof;
+pub mod create_balance_checkpoint;
+pub mod migrate_earner_account;
+pub mod preview_claim;
+pub mod propagate_attested_index;
+pub mod record_claim_cycle;
 pub mod remove_registrar_earner;
+pub mod remove_registrar_earners;
 
+pub use add_program_earner::AddProgramEarner;
+pub(crate) use add_program_earner::__client_accounts_add_program_earner;
 pub use add_registrar_earner::AddRegistrarEarner;
 pub(crate) use add_registrar_earner::__client_accounts_add_registrar_earner;
+pub use add_registrar_earners::AddRegistrarEarners;
+pub(crate) use add_registrar_earners::__client_accounts_add_registrar_earners;
+pub use checkpoint_balance::CheckpointBalance;
+pub(crate) use checkpoint_balance::__client_accounts_checkpoint_balance;
+pub use claim::Claim;
+pub(crate) use claim::__client_accounts_claim;
+pub use claim_with_proof::ClaimWithProof;
+pub(crate) use claim_with_proof::__client_accounts_claim_with_proof;
+pub use create_balance_checkpoint::CreateBalanceCheckpoint;
+pub(crate) use create_balance_checkpoint::__client_accounts_create_balance_checkpoint;
+pub use migrate_earner_account::MigrateEarnerAccount;
+pub(crate) use migrate_earner_account::__client_accounts_migrate_earner_account;
+pub use preview_claim::PreviewClaim;
+pub(crate) use preview_claim::__client_accounts_preview_claim;
+pub use propagate_attested_index::PropagateAttestedIndex;
+pub(crate) use propagate_attested_index::__client_accounts_propagate_attested_index;
+pub use record_claim_cycle::RecordClaimCycle;
+pub(crate) use record_claim_cycle::__client_accounts_record_claim_cycle;
 pub use remove_registrar_earner::RemoveRegistrarEarner;
 pub(crate) use remove_registrar_earner::__client_accounts_remove_registrar_earner;
+pub use remove_registrar_earners::RemoveRegistrarEarners;
+pub(crate) use remove_registrar_earners::__client_accounts_remove_registrar_earners;
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "cpi")] {
+        pub(crate) use add_program_earner::__cpi_client_accounts_add_program_earner;
         pub(crate) use add_registrar_earner::__cpi_client_accounts_add_registrar_earner;
+        pub(crate) use add_registrar_earners::__cpi_client_accounts_add_registrar_earners;
+        pub(crate) use checkpoint_balance::__cpi_client_accounts_checkpoint_balance;
+        pub(crate) use claim::__cpi_client_accounts_claim;
+        pub(crate) use claim_with_proof::__cpi_client_accounts_claim_with_proof;
+        pub(crate) use create_balance_checkpoint::__cpi_client_accounts_create_balance_checkpoint;
+        pub(crate) use migrate_earner_account::__cpi_client_accounts_migrate_earner_account;
+        pub(crate) use preview_claim::__cpi_client_accounts_preview_claim;
+        pub(crate) use propagate_attested_index::__cpi_client_accounts_propagate_attested_index;
+        pub(crate) use record_claim_cycle::__cpi_client_accounts_record_claim_cycle;
         pub(crate) use remove_registrar_earner::__cpi_client_accounts_remove_registrar_earner;
+        pub(crate) use remove_registrar_earners::__cpi_client_accounts_remove_registrar_earners;
     }
 }
@@ -0,0 +1,87 @@
+// earn/instructions/open/preview_claim.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+
+// local dependencies
+use crate::{
+    state::{Earner, Global, EARNER_SEED, EARNER_VERSION, GLOBAL_SEED, GLOBAL_VERSION},
+    utils::rewards::{calculate_rewards, claim_window_expired},
+};
+
+#[derive(Accounts)]
+pub struct PreviewClaim<'info> {
+    #[account(
+        seeds = [GLOBAL_SEED],
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, Global>,
+
+    #[account(
+        seeds = [EARNER_SEED, earner_account.user_token_account.as_ref()],
+        bump = earner_account.bump,
+    )]
+    pub earner_account: Account<'info, Earner>,
+}
+
+// Reasons a claim would fail, shared with the ext_earn program
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClaimFailure {
+    AlreadyClaimed,
+    ExceedsMaxYield,
+    InsufficientCollateral,
+    NoActiveClaim,
+    Paused,
+    InvalidYieldMode,
+    InvalidVersion,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ClaimPreview {
+    pub rewards: u64,                  // gross rewards for the snapshot balance
+    pub fee: u64,                      // earn manager fee taken from the rewards
+    pub amount: u64,                   // net amount minted to the recipient
+    pub failure: Option<ClaimFailure>, // reason the claim would fail, None if it would succeed
+}
+
+// Mirrors the checks in claim_for without recording the claim.
+// Checks that claim_for enforces on its accounts are reported instead of failing the preview.
+pub fn handler(ctx: Context<PreviewClaim>, snapshot_balance: u64) -> Result<ClaimPreview> {
+    let global = &ctx.accounts.global_account;
+    let earner = &ctx.accounts.earner_account;
+
+    let mut preview = ClaimPreview {
+        rewards: 0,
+        fee: 0,
+        amount: 0,
+        failure: None,
+    };
+
+    if global.paused {
+        preview.failure = Some(ClaimFailure::Paused);
+        return Ok(preview);
+    }
+
+    if global.version != GLOBAL_VERSION || earner.version != EARNER_VERSION {
+        preview.failure = Some(ClaimFailure::InvalidVersion);
+        return Ok(preview);
+    }
+
+    if earner.last_claim_index >= global.index {
+        preview.failure = Some(ClaimFailure::AlreadyClaimed);
+        return Ok(preview);
+    }
+
+    preview.rewards = calculate_rewards(snapshot_balance, earner.last_claim_index, global.index);
+    preview.amount = preview.rewards;
+
+    let current_timestamp: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
+
+    if global.claim_complete || claim_window_expired(global, current_timestamp) {
+        preview.failure = Some(ClaimFailure::NoActiveClaim);
+    } else if global.distributed.saturating_add(preview.rewards) > global.max_yield {
+        preview.failure = Some(ClaimFailure::ExceedsMaxYield);
+    }
+
+    Ok(preview)
+}
@@ -0,0 +1,98 @@
+// earn/instructions/open/propagate_attested_index.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use solana_program::sysvar;
+
+// local dependencies
+use crate::{
+    errors::EarnError,
+    instructions::propagate_index::receive_index,
+    state::{Global, PendingIndex, GLOBAL_SEED, GLOBAL_VERSION, PENDING_INDEX_SEED},
+    utils::attestation::{attestation_message, verified_signers},
+};
+
+#[derive(Accounts)]
+pub struct PropagateAttestedIndex<'info> {
+    #[account(
+        mut,
+        has_one = mint,
+        constraint = global_account.attestation_threshold != 0 @ EarnError::NotAuthorized,
+        constraint = global_account.version == GLOBAL_VERSION @ EarnError::InvalidVersion,
+        seeds = [GLOBAL_SEED],
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, Global>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: This account is validated by its address
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // Only required to quarantine an update that is outside of the index bounds
+    #[account(
+        mut,
+        seeds = [PENDING_INDEX_SEED],
+        bump = pending_index_account.bump,
+    )]
+    pub pending_index_account: Option<Account<'info, PendingIndex>>,
+}
+
+pub fn handler(
+    ctx: Context<PropagateAttestedIndex>,
+    new_index: u64,
+    earner_merkle_root: [u8; 32],
+    timestamp: u64,
+) -> Result<()> {
+    let global = &mut ctx.accounts.global_account;
+    let current_timestamp: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
+
+    // Attestations must be newer than the last accepted one so they can't be replayed
+    if timestamp <= global.last_attestation_timestamp || timestamp > current_timestamp {
+        return err!(EarnError::InvalidAttestation);
+    }
+
+    // The signatures are verified by Ed25519 program instructions earlier in the transaction
+    let message = attestation_message(&global.mint, new_index, earner_merkle_root, timestamp);
+    let signers = verified_signers(&ctx.accounts.instructions_sysvar, &message)?;
+
+    // Each attester counts once, no matter how many times it signed
+    let attestations = global
+        .attesters
+        .iter()
+        .filter(|attester| signers.contains(attester))
+        .count();
+
+    if attestations < global.attestation_threshold as usize {
+        return err!(EarnError::InsufficientAttestations);
+    }
+
+    global.last_attestation_timestamp = timestamp;
+
+    emit!(IndexAttested {
+        index: new_index,
+        earner_merkle_root,
+        timestamp,
+        attestations: attestations as u8,
+    });
+
+    // The attested timestamp also orders the root against roots received through the portal
+    receive_index(
+        global,
+        ctx.accounts.pending_index_account.as_deref_mut(),
+        ctx.accounts.mint.supply,
+        new_index,
+        earner_merkle_root,
+        timestamp,
+    )
+}
+
+#[event]
+pub struct IndexAttested {
+    pub index: u64,
+    pub earner_merkle_root: [u8; 32],
+    pub timestamp: u64,
+    pub attestations: u8,
+}
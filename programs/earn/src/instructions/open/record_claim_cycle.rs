@@ -0,0 +1,53 @@
+// earn/instructions/open/record_claim_cycle.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+
+// local dependencies
+use crate::{
+    constants::ANCHOR_DISCRIMINATOR_SIZE,
+    errors::EarnError,
+    state::{ClaimCycle, Global, CLAIM_CYCLE_SEED, GLOBAL_SEED, GLOBAL_VERSION},
+    utils::rewards::{has_unrecorded_cycle, record_completed_cycle},
+};
+
+// Persists a claim cycle that completed when its claim window passed and was followed
+// by a new cycle before the earn authority could record it with complete_claims.
+// The next cycle can only complete once this was done.
+#[derive(Accounts)]
+pub struct RecordClaimCycle<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = global_account.version == GLOBAL_VERSION @ EarnError::InvalidVersion,
+        constraint = has_unrecorded_cycle(&global_account) @ EarnError::NoCompletedCycle,
+        seeds = [GLOBAL_SEED],
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, Global>,
+
+    // The history account can already exist if the cycle was recorded before the global
+    // account tracked it, it is then written again with the same values
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = ANCHOR_DISCRIMINATOR_SIZE + ClaimCycle::INIT_SPACE,
+        seeds = [CLAIM_CYCLE_SEED, global_account.completed_cycle.index.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub claim_cycle_account: Account<'info, ClaimCycle>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RecordClaimCycle>) -> Result<()> {
+    record_completed_cycle(
+        &mut ctx.accounts.global_account,
+        &mut ctx.accounts.claim_cycle_account,
+        ctx.bumps.claim_cycle_account,
+    );
+
+    Ok(())
+}
@@ -6,8 +6,12 @@ use anchor_spl::token_interface::TokenAccount;
 
 // local dependencies
 use crate::{
-    state::{Earner, Global, EARNER_SEED, GLOBAL_SEED},
-    utils::merkle_proof::{verify_not_in_tree, ProofElement},
+    errors::EarnError,
+    state::{Earner, Global, EARNER_SEED, EARNER_VERSION, GLOBAL_SEED, GLOBAL_VERSION},
+    utils::{
+        account::{close_with_refund, is_rent_recipient},
+        merkle_proof::{verify_not_in_tree, ProofElement},
+    },
 };
 
 #[derive(Accounts)]
@@ -16,6 +20,8 @@ pub struct RemoveRegistrarEarner<'info> {
     pub signer: Signer<'info>,
 
     #[account(
+        constraint = !global_account.paused @ EarnError::Paused,
+        constraint = global_account.version == GLOBAL_VERSION @ EarnError::InvalidVersion,
         seeds = [GLOBAL_SEED],
         bump = global_account.bump
     )]
@@ -23,14 +29,22 @@ pub struct RemoveRegistrarEarner<'info> {
 
     #[account(
         mut,
-        close = signer,
         has_one = user_token_account,
+        constraint = !global_account.is_program_earner(&earner_account.user) @ EarnError::NotAuthorized,
+        constraint = earner_account.version == EARNER_VERSION @ EarnError::InvalidVersion,
         seeds = [EARNER_SEED, earner_account.user_token_account.as_ref()],
         bump = earner_account.bump,
     )]
     pub earner_account: Account<'info, Earner>,
 
     pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: This account only receives the rent of the earner account
+    #[account(
+        mut,
+        constraint = is_rent_recipient(&earner_account.rent_payer, &rent_payer.key(), &signer.key()) @ EarnError::InvalidAccount,
+    )]
+    pub rent_payer: AccountInfo<'info>,
 }
 
 pub fn handler(
@@ -51,5 +65,10 @@ pub fn handler(
         neighbors,
     )?;
 
-    Ok(())
+    close_with_refund(
+        &ctx.accounts.earner_account.to_account_info(),
+        &ctx.accounts.rent_payer,
+        &ctx.accounts.signer.to_account_info(),
+        ctx.accounts.global_account.removal_bounty,
+    )
 }
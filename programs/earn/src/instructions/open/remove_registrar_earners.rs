@@ -0,0 +1,103 @@
+// earn/instructions/open/remove_registrar_earners.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+// local dependencies
+use crate::{
+    errors::EarnError,
+    state::{Earner, Global, EARNER_SEED, EARNER_VERSION, GLOBAL_SEED, GLOBAL_VERSION},
+    utils::{
+        account::{close_with_refund, is_rent_recipient},
+        merkle_proof::{verify_not_in_tree_multi, ProofElement},
+    },
+};
+
+#[derive(Accounts)]
+pub struct RemoveRegistrarEarners<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        constraint = !global_account.paused @ EarnError::Paused,
+        constraint = global_account.version == GLOBAL_VERSION @ EarnError::InvalidVersion,
+        seeds = [GLOBAL_SEED],
+        bump = global_account.bump
+    )]
+    pub global_account: Account<'info, Global>,
+    // remaining accounts: (earner_account, user_token_account, rent_payer) for each earner to remove
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RemoveRegistrarEarners<'info>>,
+    neighbors: Vec<[u8; 32]>,
+    proof: Vec<ProofElement>,
+    proof_flags: Vec<bool>,
+) -> Result<()> {
+    if ctx.remaining_accounts.is_empty() || ctx.remaining_accounts.len() % 3 != 0 {
+        return err!(EarnError::InvalidParam);
+    }
+
+    let mut earner_accounts = Vec::with_capacity(ctx.remaining_accounts.len() / 3);
+    let mut values = Vec::with_capacity(ctx.remaining_accounts.len() / 3);
+
+    for accounts in ctx.remaining_accounts.chunks(3) {
+        let earner_account = Account::<Earner>::try_from(&accounts[0])?;
+        let user_token_account = InterfaceAccount::<TokenAccount>::try_from(&accounts[1])?;
+
+        // Validate the earner PDA and that the token account belongs to it
+        let expected_earner = Pubkey::create_program_address(
+            &[
+                EARNER_SEED,
+                earner_account.user_token_account.as_ref(),
+                &[earner_account.bump],
+            ],
+            ctx.program_id,
+        )
+        .map_err(|_| EarnError::InvalidAccount)?;
+
+        if earner_account.version != EARNER_VERSION {
+            return err!(EarnError::InvalidVersion);
+        }
+
+        // Program earners are not in the earner merkle tree, only the admin can remove them
+        if ctx.accounts.global_account.is_program_earner(&earner_account.user) {
+            return err!(EarnError::NotAuthorized);
+        }
+
+        if earner_account.key() != expected_earner
+            || user_token_account.key() != earner_account.user_token_account
+            || !is_rent_recipient(
+                &earner_account.rent_payer,
+                accounts[2].key,
+                ctx.accounts.signer.key,
+            )
+        {
+            return err!(EarnError::InvalidAccount);
+        }
+
+        values.push(user_token_account.owner.to_bytes());
+        earner_accounts.push((earner_account, &accounts[2]));
+    }
+
+    // Verify none of the token account owners are in the approved earners list
+    verify_not_in_tree_multi(
+        ctx.accounts.global_account.earner_merkle_root,
+        &values,
+        neighbors,
+        proof,
+        proof_flags,
+    )?;
+
+    for (earner_account, rent_payer) in earner_accounts {
+        close_with_refund(
+            &earner_account.to_account_info(),
+            rent_payer,
+            &ctx.accounts.signer.to_account_info(),
+            ctx.accounts.global_account.removal_bounty,
+        )?;
+    }
+
+    Ok(())
+}
@@ -6,39 +6,246 @@ use anchor_spl::token_interface::Mint;
 
 // local dependencies
 use crate::{
+    constants::{ONE_HUNDRED_PERCENT, SECONDS_PER_YEAR},
     errors::EarnError,
-    state::{Global, GLOBAL_SEED},
+    state::{Global, PendingIndex, GLOBAL_SEED, GLOBAL_VERSION, PENDING_INDEX_SEED},
+    utils::{
+        migration::realloc_prefunded,
+        rewards::{
+            age_unclaimed_yield, claim_window_expired, close_claim_cycle, has_unrecorded_cycle,
+        },
+    },
 };
 
 #[derive(Accounts)]
 pub struct PropagateIndex<'info> {
-    #[account(
-        constraint = signer.key() == global_account.portal_authority
-            || (cfg!(feature = "testing") && signer.key() == global_account.admin) @ EarnError::NotAuthorized 
-    )]
     pub signer: Signer<'info>,
 
+    /// CHECK: The global account is migrated before it is deserialized after an upgrade,
+    /// the owner, discriminator, mint and signer are validated in the handler
     #[account(
         mut,
-        has_one = mint,
         seeds = [GLOBAL_SEED],
-        bump = global_account.bump,
+        bump,
     )]
-    pub global_account: Account<'info, Global>,
+    pub global_account: UncheckedAccount<'info>,
 
     pub mint: InterfaceAccount<'info, Mint>,
+
+    // Only required to quarantine an update that is outside of the index bounds
+    #[account(
+        mut,
+        seeds = [PENDING_INDEX_SEED],
+        bump = pending_index_account.bump,
+    )]
+    pub pending_index_account: Option<Account<'info, PendingIndex>>,
 }
 
 pub fn handler(
     ctx: Context<PropagateIndex>,
     new_index: u64,
     earner_merkle_root: [u8; 32],
+    earner_root_timestamp: u64,
 ) -> Result<()> {
-    let global = &mut ctx.accounts.global_account;
+    let global_info = ctx.accounts.global_account.to_account_info();
+
+    // The portal can't wait for the admin to run migrate_global after an upgrade, so the global
+    // account is migrated by the first index update instead. There is no payer for a larger
+    // layout, its rent has to be deposited into the global account before the upgrade.
+    realloc_prefunded::<Global>(&global_info)?;
+
+    let mut global = Global::try_deserialize(&mut &global_info.try_borrow_data()?[..])?;
+
+    if global.version < GLOBAL_VERSION {
+        global.upgrade();
+    }
+
+    if global.mint != ctx.accounts.mint.key() {
+        return err!(EarnError::InvalidAccount);
+    }
+
+    let signer = ctx.accounts.signer.key();
+    if signer != global.portal_authority && !(cfg!(feature = "testing") && signer == global.admin) {
+        return err!(EarnError::NotAuthorized);
+    }
 
     // Cache the current supply of the M token
     let current_supply = ctx.accounts.mint.supply;
 
+    receive_index(
+        &mut global,
+        ctx.accounts.pending_index_account.as_deref_mut(),
+        current_supply,
+        new_index,
+        earner_merkle_root,
+        earner_root_timestamp,
+    )?;
+
+    global.try_serialize(&mut &mut global_info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
+
+/// Entry point for index updates from the portal or attesters.
+pub fn receive_index(
+    global: &mut Global,
+    pending: Option<&mut PendingIndex>,
+    current_supply: u64,
+    new_index: u64,
+    earner_merkle_root: [u8; 32],
+    earner_root_timestamp: u64,
+) -> Result<()> {
+    if is_stale_earner_root(global, new_index, earner_merkle_root, earner_root_timestamp) {
+        return err!(EarnError::StaleEarnerRoot);
+    }
+
+    // While paused, we queue the update instead of applying it so that it is not lost.
+    // The queued update is applied when the program is unpaused.
+    // Failing here would instead block the inbound message on the portal.
+    if global.paused {
+        queue_index_update(
+            global,
+            current_supply,
+            new_index,
+            earner_merkle_root,
+            earner_root_timestamp,
+            false,
+        );
+
+        return Ok(());
+    }
+
+    apply_index_update(
+        global,
+        pending,
+        current_supply,
+        new_index,
+        earner_merkle_root,
+        earner_root_timestamp,
+    )
+}
+
+/// Queues an index update while the program is paused, the latest one is applied on unpause.
+/// An update approved by the admin skips the bounds check when it is applied.
+pub fn queue_index_update(
+    global: &mut Global,
+    current_supply: u64,
+    new_index: u64,
+    earner_merkle_root: [u8; 32],
+    earner_root_timestamp: u64,
+    approved: bool,
+) {
+    if current_supply > global.max_supply {
+        global.max_supply = current_supply;
+    }
+
+    if new_index >= global.index && new_index >= global.queued_index {
+        // A higher index has to pass the bounds check on its own
+        if new_index > global.queued_index {
+            global.queued_index_approved = approved;
+        } else {
+            global.queued_index_approved |= approved;
+        }
+
+        global.queued_index = new_index;
+
+        if earner_merkle_root != [0u8; 32]
+            && is_newer_root(
+                earner_root_timestamp,
+                global.queued_earner_merkle_root_timestamp,
+            )
+        {
+            global.queued_earner_merkle_root = earner_merkle_root;
+            global.queued_earner_merkle_root_timestamp = earner_root_timestamp;
+        }
+    }
+
+    emit!(IndexQueued {
+        index: global.queued_index,
+        earner_merkle_root: global.queued_earner_merkle_root,
+    });
+}
+
+/// Applies a propagated index and earner root unless the index is outside of the configured
+/// bounds, in which case it is quarantined until the admin approves or rejects it.
+pub fn apply_index_update(
+    global: &mut Global,
+    pending: Option<&mut PendingIndex>,
+    current_supply: u64,
+    new_index: u64,
+    earner_merkle_root: [u8; 32],
+    earner_root_timestamp: u64,
+) -> Result<()> {
+    let current_timestamp: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
+
+    if new_index > global.index && !index_within_bounds(global, new_index, current_timestamp) {
+        let pending = pending.ok_or(EarnError::MissingPendingIndex)?;
+
+        if current_supply > global.max_supply {
+            global.max_supply = current_supply;
+        }
+
+        // The latest out of bounds update replaces any previously quarantined one
+        pending.index = new_index;
+        pending.earner_merkle_root = earner_merkle_root;
+        pending.earner_merkle_root_timestamp = earner_root_timestamp;
+
+        emit!(IndexQuarantined {
+            index: new_index,
+            current_index: global.index,
+            earner_merkle_root,
+            ts: current_timestamp,
+        });
+
+        return Ok(());
+    }
+
+    update_index(
+        global,
+        current_supply,
+        new_index,
+        earner_merkle_root,
+        earner_root_timestamp,
+    )
+}
+
+// Checks the ratio of the new index to the current index and the annualized rate it implies
+// over the time elapsed since the current index was set.
+// Callers must ensure new_index > global.index.
+fn index_within_bounds(global: &Global, new_index: u64, current_timestamp: u64) -> bool {
+    let index = global.index as u128;
+    let new_index = new_index as u128;
+
+    if global.max_index_ratio_bps != 0
+        && new_index * ONE_HUNDRED_PERCENT as u128 > index * global.max_index_ratio_bps as u128
+    {
+        return false;
+    }
+
+    if global.max_index_rate_bps != 0 {
+        // (new_index - index) / index * SECONDS_PER_YEAR / elapsed <= max rate
+        let elapsed = current_timestamp.saturating_sub(global.timestamp) as u128;
+
+        if (new_index - index) * ONE_HUNDRED_PERCENT as u128 * SECONDS_PER_YEAR as u128
+            > (global.max_index_rate_bps as u128)
+                .saturating_mul(index)
+                .saturating_mul(elapsed)
+        {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Applies a propagated index and earner root, starting a new claim cycle if possible.
+pub fn update_index(
+    global: &mut Global,
+    current_supply: u64,
+    new_index: u64,
+    earner_merkle_root: [u8; 32],
+    earner_root_timestamp: u64,
+) -> Result<()> {
     // Check if the new index is greater than or equal to the previously seen index.
     // If so, update the merkle roots if they are non-zero.
     // We don't necessarily need the second check if we know updates only come
@@ -46,7 +253,7 @@ pub fn handler(
     // in the event non-zero roots are sent from another chain.
     if new_index >= global.index {
         if earner_merkle_root != [0u8; 32] {
-            global.earner_merkle_root = earner_merkle_root;
+            update_earner_root(global, earner_merkle_root, earner_root_timestamp);
         }
     }
 
@@ -60,6 +267,20 @@ pub fn handler(
     let current_timestamp: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
     let cooldown_target = global.timestamp + global.claim_cooldown;
 
+    // If the claim window has passed, the cycle is considered complete so that it can't stall
+    // when the earn authority fails to complete it. Leftover yield is carried into the next cycle.
+    // The previous completed cycle has to be recorded first, until then the new index waits.
+    if !global.claim_complete && claim_window_expired(global, current_timestamp) {
+        if has_unrecorded_cycle(global) {
+            msg!(
+                "Claim cycle {} must be recorded first",
+                global.completed_cycle.index
+            );
+        } else {
+            close_claim_cycle(global, current_timestamp, true)?;
+        }
+    }
+
     if !global.claim_complete || current_timestamp < cooldown_target || new_index <= global.index {
         if current_supply > global.max_supply {
             global.max_supply = current_supply;
@@ -92,7 +313,11 @@ pub fn handler(
 
     period_max = period_max - global.max_supply - leftover; // can't underflow because new_index > ctx.accounts.global.index
 
+    // Track the age of the unclaimed yield so that it can expire
+    age_unclaimed_yield(global, period_max);
+
     // Update the global state
+    global.previous_index = global.index;
     global.index = new_index;
     global.timestamp = current_timestamp;
     global.max_supply = current_supply; // we set this to the current supply regardless of whether it is larger since we are starting a new cycle
@@ -101,6 +326,7 @@ pub fn handler(
     global.max_yield = leftover.checked_add(period_max).unwrap();
 
     global.distributed = 0;
+    global.claims = 0;
     global.claim_complete = false;
 
     emit!(IndexUpdate {
@@ -113,6 +339,69 @@ pub fn handler(
     Ok(())
 }
 
+/// Returns true if the earner root is older than the latest root received, including a queued one.
+/// The latest root can be sent again, but a different root with the same timestamp is rejected.
+/// Roots are only applied with an index >= the current index, so other roots are never stale.
+pub fn is_stale_earner_root(
+    global: &Global,
+    new_index: u64,
+    earner_merkle_root: [u8; 32],
+    timestamp: u64,
+) -> bool {
+    if earner_merkle_root == [0u8; 32] || new_index < global.index {
+        return false;
+    }
+
+    // The queued root is cleared on unpause, so it is only newer while paused
+    let (latest_root, latest_timestamp) =
+        if global.queued_earner_merkle_root_timestamp > global.earner_merkle_root_timestamp {
+            (
+                global.queued_earner_merkle_root,
+                global.queued_earner_merkle_root_timestamp,
+            )
+        } else {
+            (
+                global.earner_merkle_root,
+                global.earner_merkle_root_timestamp,
+            )
+        };
+
+    timestamp < latest_timestamp
+        || (timestamp == latest_timestamp && timestamp != 0 && earner_merkle_root != latest_root)
+}
+
+// Roots with the same index can arrive out of order, so they are ordered by their source timestamp.
+// Stale roots are rejected when they are received. A queued or approved root can still be
+// superseded before it is applied, in which case it is ignored so that the index still goes through.
+fn update_earner_root(global: &mut Global, earner_merkle_root: [u8; 32], timestamp: u64) {
+    if !is_newer_root(timestamp, global.earner_merkle_root_timestamp) {
+        emit!(EarnerRootIgnored {
+            root: earner_merkle_root,
+            timestamp,
+            current_timestamp: global.earner_merkle_root_timestamp,
+        });
+        return;
+    }
+
+    let old_root = global.earner_merkle_root;
+
+    global.earner_merkle_root = earner_merkle_root;
+    global.earner_merkle_root_timestamp = timestamp;
+
+    if old_root != earner_merkle_root {
+        emit!(EarnerRootUpdate {
+            old_root,
+            new_root: earner_merkle_root,
+            timestamp,
+        });
+    }
+}
+
+// Roots without a source timestamp are accepted until a timestamped root has been received
+fn is_newer_root(timestamp: u64, latest_timestamp: u64) -> bool {
+    timestamp > latest_timestamp || (timestamp == 0 && latest_timestamp == 0)
+}
+
 #[event]
 pub struct IndexUpdate {
     pub index: u64,
@@ -120,3 +409,31 @@ pub struct IndexUpdate {
     pub supply: u64,
     pub max_yield: u64,
 }
+
+#[event]
+pub struct IndexQuarantined {
+    pub index: u64,
+    pub current_index: u64,
+    pub earner_merkle_root: [u8; 32],
+    pub ts: u64,
+}
+
+#[event]
+pub struct IndexQueued {
+    pub index: u64,
+    pub earner_merkle_root: [u8; 32],
+}
+
+#[event]
+pub struct EarnerRootUpdate {
+    pub old_root: [u8; 32],
+    pub new_root: [u8; 32],
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct EarnerRootIgnored {
+    pub root: [u8; 32],
+    pub timestamp: u64, // source timestamp of the ignored root, 0 if it had none
+    pub current_timestamp: u64, // source timestamp of the root that was kept
+}
@@ -8,7 +8,8 @@ pub mod utils;
 
 use anchor_lang::prelude::*;
 
-use instructions::*;
+use instructions::{preview_claim::ClaimPreview, *};
+use state::Role;
 use utils::merkle_proof::ProofElement;
 
 #[cfg(not(feature = "no-entrypoint"))]
@@ -48,14 +49,110 @@ pub mod earn {
         instructions::admin::set_claim_cooldown::handler(ctx, claim_cooldown)
     }
 
+    pub fn set_claim_window(ctx: Context<AdminAction>, claim_window: u64) -> Result<()> {
+        instructions::admin::set_claim_window::handler(ctx, claim_window)
+    }
+
+    pub fn transfer_admin(ctx: Context<AdminAction>, new_admin: Pubkey) -> Result<()> {
+        instructions::admin::transfer_admin::handler(ctx, new_admin)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::admin::accept_admin::handler(ctx)
+    }
+
+    pub fn set_portal_authority(
+        ctx: Context<AdminAction>,
+        new_portal_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::admin::set_portal_authority::handler(ctx, new_portal_authority)
+    }
+
+    pub fn grant_role(ctx: Context<GrantRole>, role: Role, member: Pubkey) -> Result<()> {
+        instructions::admin::grant_role::handler(ctx, role, member)
+    }
+
+    pub fn revoke_role(ctx: Context<RevokeRole>, role: Role, member: Pubkey) -> Result<()> {
+        instructions::admin::revoke_role::handler(ctx, role, member)
+    }
+
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        instructions::admin::set_paused::handler(ctx, paused)
+    }
+
+    pub fn set_index_bounds(
+        ctx: Context<SetIndexBounds>,
+        max_index_rate_bps: u64,
+        max_index_ratio_bps: u64,
+    ) -> Result<()> {
+        instructions::admin::set_index_bounds::handler(
+            ctx,
+            max_index_rate_bps,
+            max_index_ratio_bps,
+        )
+    }
+
+    pub fn approve_pending_index(ctx: Context<ApprovePendingIndex>) -> Result<()> {
+        instructions::admin::approve_pending_index::handler(ctx)
+    }
+
+    pub fn reject_pending_index(ctx: Context<RejectPendingIndex>) -> Result<()> {
+        instructions::admin::reject_pending_index::handler(ctx)
+    }
+
+    pub fn set_unclaimed_expiry(
+        ctx: Context<SetUnclaimedExpiry>,
+        expiry_cycles: u64,
+    ) -> Result<()> {
+        instructions::admin::set_unclaimed_expiry::handler(ctx, expiry_cycles)
+    }
+
+    pub fn sweep_unclaimed(ctx: Context<SweepUnclaimed>) -> Result<()> {
+        instructions::admin::sweep_unclaimed::handler(ctx)
+    }
+
+    pub fn set_attesters(
+        ctx: Context<AdminAction>,
+        attesters: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::admin::set_attesters::handler(ctx, attesters, threshold)
+    }
+
+    pub fn approve_program_earner(
+        ctx: Context<AdminAction>,
+        program_id: Pubkey,
+        seeds: Vec<Vec<u8>>,
+    ) -> Result<()> {
+        instructions::admin::approve_program_earner::handler(ctx, program_id, seeds)
+    }
+
+    pub fn remove_program_earner(ctx: Context<RemoveProgramEarner>) -> Result<()> {
+        instructions::admin::remove_program_earner::handler(ctx)
+    }
+
+    pub fn set_removal_bounty(ctx: Context<AdminAction>, removal_bounty: u64) -> Result<()> {
+        instructions::admin::set_removal_bounty::handler(ctx, removal_bounty)
+    }
+
+    pub fn migrate_global(ctx: Context<MigrateGlobal>) -> Result<()> {
+        instructions::admin::migrate_global::handler(ctx)
+    }
+
     // Portal instrutions
 
     pub fn propagate_index(
         ctx: Context<PropagateIndex>,
         index: u64,
         earner_merkle_root: [u8; 32],
+        earner_root_timestamp: u64,
     ) -> Result<()> {
-        instructions::portal::propagate_index::handler(ctx, index, earner_merkle_root)
+        instructions::portal::propagate_index::handler(
+            ctx,
+            index,
+            earner_merkle_root,
+            earner_root_timestamp,
+        )
     }
 
     // Earn authority instructions
@@ -64,10 +161,39 @@ pub mod earn {
         instructions::earn_authority::claim_for::handler(ctx, snapshot_balance)
     }
 
+    pub fn claim_for_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimForBatch<'info>>,
+        snapshot_balances: Vec<u64>,
+    ) -> Result<()> {
+        instructions::earn_authority::claim_for_batch::handler(ctx, snapshot_balances)
+    }
+
     pub fn complete_claims(ctx: Context<CompleteClaims>) -> Result<()> {
         instructions::earn_authority::complete_claims::handler(ctx)
     }
 
+    pub fn commit_claims_root(
+        ctx: Context<CommitClaimsRoot>,
+        claims_root: [u8; 32],
+        total_balance: u64,
+    ) -> Result<()> {
+        instructions::earn_authority::commit_claims_root::handler(ctx, claims_root, total_balance)
+    }
+
+    pub fn revoke_claims_root(ctx: Context<RevokeClaimsRoot>) -> Result<()> {
+        instructions::earn_authority::revoke_claims_root::handler(ctx)
+    }
+
+    // Earner instructions
+
+    pub fn migrate_earner(ctx: Context<MigrateEarner>) -> Result<()> {
+        instructions::earner::migrate_earner::handler(ctx)
+    }
+
+    pub fn set_recipient(ctx: Context<SetRecipient>) -> Result<()> {
+        instructions::earner::set_recipient::handler(ctx)
+    }
+
     // Open instructions
 
     pub fn add_registrar_earner(
@@ -85,4 +211,75 @@ pub mod earn {
     ) -> Result<()> {
         instructions::open::remove_registrar_earner::handler(ctx, proofs, neighbors)
     }
+
+    pub fn add_registrar_earners<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AddRegistrarEarners<'info>>,
+        users: Vec<Pubkey>,
+        proof: Vec<ProofElement>,
+        proof_flags: Vec<bool>,
+    ) -> Result<()> {
+        instructions::open::add_registrar_earners::handler(ctx, users, proof, proof_flags)
+    }
+
+    pub fn remove_registrar_earners<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RemoveRegistrarEarners<'info>>,
+        neighbors: Vec<[u8; 32]>,
+        proof: Vec<ProofElement>,
+        proof_flags: Vec<bool>,
+    ) -> Result<()> {
+        instructions::open::remove_registrar_earners::handler(ctx, neighbors, proof, proof_flags)
+    }
+
+    pub fn add_program_earner(ctx: Context<AddProgramEarner>) -> Result<()> {
+        instructions::open::add_program_earner::handler(ctx)
+    }
+
+    pub fn create_balance_checkpoint(ctx: Context<CreateBalanceCheckpoint>) -> Result<()> {
+        instructions::open::create_balance_checkpoint::handler(ctx)
+    }
+
+    pub fn checkpoint_balance(ctx: Context<CheckpointBalance>) -> Result<()> {
+        instructions::open::checkpoint_balance::handler(ctx)
+    }
+
+    pub fn claim(ctx: Context<Claim>) -> Result<()> {
+        instructions::open::claim::handler(ctx)
+    }
+
+    pub fn claim_with_proof(
+        ctx: Context<ClaimWithProof>,
+        snapshot_balance: u64,
+        proof: Vec<ProofElement>,
+    ) -> Result<()> {
+        instructions::open::claim_with_proof::handler(ctx, snapshot_balance, proof)
+    }
+
+    pub fn preview_claim(
+        ctx: Context<PreviewClaim>,
+        snapshot_balance: u64,
+    ) -> Result<ClaimPreview> {
+        instructions::open::preview_claim::handler(ctx, snapshot_balance)
+    }
+
+    pub fn migrate_earner_account(ctx: Context<MigrateEarnerAccount>) -> Result<()> {
+        instructions::open::migrate_earner_account::handler(ctx)
+    }
+
+    pub fn propagate_attested_index(
+        ctx: Context<PropagateAttestedIndex>,
+        new_index: u64,
+        earner_merkle_root: [u8; 32],
+        timestamp: u64,
+    ) -> Result<()> {
+        instructions::open::propagate_attested_index::handler(
+            ctx,
+            new_index,
+            earner_merkle_root,
+            timestamp,
+        )
+    }
+
+    pub fn record_claim_cycle(ctx: Context<RecordClaimCycle>) -> Result<()> {
+        instructions::open::record_claim_cycle::handler(ctx)
+    }
 }
@@ -0,0 +1,20 @@
+// earn/state/balance_checkpoint.rs
+
+use anchor_lang::prelude::*;
+
+#[constant]
+pub const BALANCE_CHECKPOINT_SEED: &[u8] = b"balance_checkpoint";
+
+// Observed balances of an earner's token account, used to self-claim without the earn authority.
+// Transfers of M can't be observed by this program, so anyone can record the balance, e.g. the
+// yield bot after every index update, and claims are paid on the lowest balance recorded.
+#[account]
+#[derive(InitSpace)]
+pub struct BalanceCheckpoint {
+    pub user_token_account: Pubkey,
+    pub start_index: u64, // global index when the current observation window was opened
+    pub min_balance: u64, // lowest balance observed since the window was opened
+    pub last_balance: u64, // most recently observed balance
+    pub last_update: u64, // timestamp of the most recent observation
+    pub bump: u8,
+}
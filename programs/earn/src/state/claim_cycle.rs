@@ -0,0 +1,41 @@
+// earn/state/claim_cycle.rs
+
+use anchor_lang::prelude::*;
+
+#[constant]
+pub const CLAIM_CYCLE_SEED: &[u8] = b"claim_cycle";
+
+// Historical record of a claim cycle, seeded by the index the cycle was started with.
+// The portal cannot pass additional accounts, so a cycle that completes in propagate_index is
+// kept on the global account until complete_claims or record_claim_cycle persists it here.
+// Another cycle can't complete until then, so that no cycle is missing from the history.
+#[account]
+#[derive(InitSpace)]
+pub struct ClaimCycle {
+    pub index: u64,          // index the claim cycle was started with
+    pub previous_index: u64, // index of the previous claim cycle
+    pub timestamp: u64,      // timestamp of the index update that started the cycle
+    pub max_supply: u64,     // max observed supply of the token during the cycle
+    pub max_yield: u64,      // max yield that can be distributed in the cycle
+    pub distributed: u64,    // total yield distributed in the cycle
+    pub claims: u64,         // number of claims processed in the cycle
+    pub completed_at: u64,   // timestamp the cycle was completed
+    pub timed_out: bool,     // true if the cycle completed because the claim window passed
+    pub bump: u8,
+}
+
+// Final state of the last completed claim cycle, kept on the global account until it is recorded
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace,
+)]
+pub struct CompletedCycle {
+    pub index: u64,
+    pub previous_index: u64,
+    pub timestamp: u64,
+    pub max_supply: u64,
+    pub max_yield: u64,
+    pub distributed: u64,
+    pub claims: u64,
+    pub completed_at: u64, // 0 if no cycle has completed yet
+    pub timed_out: bool,
+}
@@ -0,0 +1,17 @@
+// earn/state/claim_distribution.rs
+
+use anchor_lang::prelude::*;
+
+#[constant]
+pub const CLAIM_DISTRIBUTION_SEED: &[u8] = b"claim_distribution";
+
+#[account]
+#[derive(InitSpace)]
+pub struct ClaimDistribution {
+    pub index: u64,            // global index of the claim cycle this distribution is for
+    pub claims_root: [u8; 32], // merkle root over (user_token_account, snapshot_balance) leaves
+    pub total_balance: u64,    // sum of the snapshot balances committed to in the root
+    pub claimed_balance: u64,  // sum of the snapshot balances claimed so far
+    pub claims: u64,           // number of claims processed against the root
+    pub bump: u8,
+}
@@ -5,6 +5,11 @@ use anchor_lang::prelude::*;
 #[constant]
 pub const EARNER_SEED: &[u8] = b"earner";
 
+// Current layout version, older accounts must be upgraded with migrate_earner_account.
+// Fields taken from the reserved space only need a new version if zero is not a valid default.
+#[constant]
+pub const EARNER_VERSION: u8 = 1;
+
 #[account]
 #[derive(InitSpace)]
 pub struct Earner {
@@ -13,4 +18,11 @@ pub struct Earner {
     pub bump: u8,
     pub user: Pubkey,
     pub user_token_account: Pubkey,
+    pub recipient_token_account: Option<Pubkey>, // receives yield instead of user_token_account if set
+    pub total_rewards: u64,           // lifetime rewards claimed
+    pub claims: u64,                  // lifetime number of claims
+    pub first_earning_timestamp: u64, // timestamp of the index the earner started earning from
+    pub version: u8,                  // layout version of this account
+    pub rent_payer: Pubkey, // receives the rent when the account is closed, unset on older accounts
+    pub reserved: [u8; 32], // space for new fields without a realloc
 }
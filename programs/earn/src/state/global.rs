@@ -2,9 +2,22 @@
 
 use anchor_lang::prelude::*;
 
+use super::CompletedCycle;
+
 #[constant]
 pub const GLOBAL_SEED: &[u8] = b"global";
 
+// Bumped whenever the account grows or new fields need to be initialized by migrate_global,
+// handlers refuse a global account on an older version. propagate_index migrates it instead so
+// that index updates from the portal are not blocked. Fixed size fields are taken from the
+// reserved space when zero is a valid default, which needs neither a realloc nor a new version.
+#[constant]
+pub const GLOBAL_VERSION: u8 = 2;
+
+pub const MAX_ATTESTERS: usize = 8;
+
+pub const MAX_PROGRAM_EARNERS: usize = 8;
+
 #[account]
 #[derive(InitSpace)]
 pub struct Global {
@@ -21,4 +34,58 @@ pub struct Global {
     pub earner_merkle_root: [u8; 32],
     pub portal_authority: Pubkey, // portal authority that propogates indexes and roots
     pub bump: u8,                 // bump seed on this PDA
+    pub previous_index: u64,      // index of the previous claim cycle
+    pub claims: u64,              // number of claims processed in this claim cycle
+    pub paused: bool,                  // halts claims, earner updates and index updates
+    pub queued_index: u64,             // latest index propagated while paused, 0 if there is none
+    pub queued_earner_merkle_root: [u8; 32], // latest earner root propagated while paused
+    pub max_index_rate_bps: u64,  // max annualized rate implied by an index update, 0 to disable
+    pub max_index_ratio_bps: u64, // max ratio of a new index to the current index, 0 to disable
+    pub claim_window: u64, // time after a cycle starts that it completes automatically, 0 to disable
+    pub unclaimed_expiry_cycles: u64, // cycles after which unclaimed yield can be swept, 0 to disable
+    pub unclaimed_yield: [u64; 13], // max yield by age in cycles, the last bucket holds anything older
+    pub version: u8,                // layout version of this account
+    pub last_attestation_timestamp: u64, // timestamp of the last accepted index attestation
+    pub attestation_threshold: u8, // attester signatures required to update the index, 0 to disable
+    pub earner_merkle_root_timestamp: u64, // source timestamp of the earner root, must increase
+    pub queued_earner_merkle_root_timestamp: u64, // source timestamp of the queued earner root
+    pub removal_bounty: u64, // lamports paid to whoever removes an earner, out of its rent
+    pub completed_cycle: CompletedCycle, // final state of the last completed claim cycle
+    pub queued_index_approved: bool, // the queued index was approved by the admin and skips the bounds
+    pub completed_cycle_recorded: bool, // the completed cycle was written to its history account
+    pub reserved: [u8; 20],  // space for new fixed size fields without a realloc
+    // Variable length fields are kept at the end so the offsets of the fixed size fields are stable
+    pub pending_admin: Option<Pubkey>, // admin that has been nominated but has not accepted yet
+    pub unclaimed_treasury: Option<Pubkey>, // receives swept yield, it is written off if not set
+    #[max_len(MAX_ATTESTERS)]
+    pub attesters: Vec<Pubkey>, // keys that can sign index attestations
+    #[max_len(MAX_PROGRAM_EARNERS)]
+    pub program_earners: Vec<ProgramEarner>, // program accounts the admin approved as earners
+}
+
+impl Global {
+    /// Initializes the fields that were added since the account's version, after it was grown
+    /// to the current layout. Fields added by a realloc start at zero, which disables the
+    /// features they configure, e.g. earner root timestamps start at zero so any timestamped
+    /// root is newer.
+    pub fn upgrade(&mut self) {
+        // The previous index is only tracked from the upgrade on
+        if self.version == 0 && self.previous_index == 0 {
+            self.previous_index = self.index;
+        }
+
+        self.version = GLOBAL_VERSION;
+    }
+
+    pub fn is_program_earner(&self, user: &Pubkey) -> bool {
+        self.program_earners.iter().any(|earner| earner.user == *user)
+    }
+}
+
+// Program derived address that can earn without being in the earner merkle tree,
+// e.g. the M vault of an extension
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct ProgramEarner {
+    pub user: Pubkey,       // owner of the earning token accounts
+    pub program_id: Pubkey, // program the address is derived from
 }
@@ -1,10 +1,20 @@
 // earn/state/mod.rs
 
+pub mod balance_checkpoint;
+pub mod claim_cycle;
+pub mod claim_distribution;
 pub mod earner;
 pub mod global;
+pub mod pending_index;
+pub mod roles;
 
+pub use balance_checkpoint::*;
+pub use claim_cycle::*;
+pub use claim_distribution::*;
 pub use earner::*;
 pub use global::*;
+pub use pending_index::*;
+pub use roles::*;
 
 use anchor_lang::prelude::*;
 
@@ -0,0 +1,25 @@
+// earn/state/pending_index.rs
+
+use anchor_lang::prelude::*;
+
+#[constant]
+pub const PENDING_INDEX_SEED: &[u8] = b"pending_index";
+
+// Out of bounds index update awaiting admin approval, kept out of the global account so that
+// quarantining an update does not grow it. Created when the index bounds are first set.
+#[account]
+#[derive(InitSpace)]
+pub struct PendingIndex {
+    pub index: u64,                        // quarantined index, 0 if there is none
+    pub earner_merkle_root: [u8; 32],      // earner root propagated with the pending index
+    pub earner_merkle_root_timestamp: u64, // source timestamp of the pending earner root
+    pub bump: u8,
+}
+
+impl PendingIndex {
+    pub fn clear(&mut self) {
+        self.index = 0;
+        self.earner_merkle_root = [0; 32];
+        self.earner_merkle_root_timestamp = 0;
+    }
+}
@@ -0,0 +1,25 @@
+// earn/state/roles.rs
+
+use anchor_lang::prelude::*;
+
+#[constant]
+pub const ROLES_SEED: &[u8] = b"roles";
+
+pub const MAX_ROLE_MEMBERS: usize = 8;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+    EarnAuthority, // can distribute yield alongside the global earn authority
+    Pauser,        // can pause the program
+}
+
+// Additional role members, the admin and earn authority on the global account keep their rights
+#[account]
+#[derive(InitSpace)]
+pub struct Roles {
+    #[max_len(MAX_ROLE_MEMBERS)]
+    pub earn_authorities: Vec<Pubkey>,
+    #[max_len(MAX_ROLE_MEMBERS)]
+    pub pausers: Vec<Pubkey>,
+    pub bump: u8,
+}
@@ -0,0 +1,35 @@
+// earn/utils/account.rs
+
+// external dependencies
+use anchor_lang::{prelude::*, system_program};
+
+/// Returns true if `recipient` can receive the rent of a closed earner account.
+/// Earners created before the rent payer was stored refund whoever removes them.
+pub fn is_rent_recipient(rent_payer: &Pubkey, recipient: &Pubkey, remover: &Pubkey) -> bool {
+    if *rent_payer == Pubkey::default() {
+        recipient == remover
+    } else {
+        recipient == rent_payer
+    }
+}
+
+/// Closes an account owned by the calling program, paying `bounty` lamports to the remover
+/// and refunding the rest of its rent to the rent payer.
+pub fn close_with_refund<'info>(
+    account: &AccountInfo<'info>,
+    rent_payer: &AccountInfo<'info>,
+    remover: &AccountInfo<'info>,
+    bounty: u64,
+) -> Result<()> {
+    let lamports = account.lamports();
+    let bounty = bounty.min(lamports);
+
+    **remover.try_borrow_mut_lamports()? += bounty;
+    **rent_payer.try_borrow_mut_lamports()? += lamports - bounty;
+    **account.try_borrow_mut_lamports()? = 0;
+
+    account.assign(&system_program::ID);
+    account.realloc(0, false)?;
+
+    Ok(())
+}
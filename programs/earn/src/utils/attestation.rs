@@ -0,0 +1,174 @@
+// earn/utils/attestation.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+use solana_program::{
+    ed25519_program,
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
+
+// local dependencies
+use crate::errors::EarnError;
+
+// Prefix of attestation messages so that signatures can't be reused for another purpose
+pub const ATTESTATION_DOMAIN: &[u8] = b"m0:index_attestation";
+
+// Header of the Ed25519 program instruction data: number of signatures and a padding byte
+const ED25519_HEADER_SIZE: usize = 2;
+
+// Seven u16 values describing where the signature, public key and message are located
+const ED25519_OFFSETS_SIZE: usize = 14;
+
+/// Builds the message attesters sign to attest to an index and earner root.
+/// The mint binds the attestation to a single deployment of the program.
+pub fn attestation_message(
+    mint: &Pubkey,
+    index: u64,
+    earner_merkle_root: [u8; 32],
+    timestamp: u64,
+) -> Vec<u8> {
+    [
+        ATTESTATION_DOMAIN,
+        mint.as_ref(),
+        &index.to_le_bytes(),
+        &earner_merkle_root,
+        &timestamp.to_le_bytes(),
+    ]
+    .concat()
+}
+
+/// Returns the keys that signed `message` in the Ed25519 program instructions before the
+/// current instruction. The runtime fails the transaction if any of those signatures is invalid,
+/// so a key being present in the instruction data means its signature was verified.
+pub fn verified_signers(instructions_sysvar: &AccountInfo, message: &[u8]) -> Result<Vec<Pubkey>> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    let mut signers = vec![];
+
+    for i in 0..current_index {
+        let instruction = load_instruction_at_checked(i as usize, instructions_sysvar)?;
+
+        if instruction.program_id == ed25519_program::ID {
+            signers.extend(ed25519_signers(&instruction.data, message)?);
+        }
+    }
+
+    Ok(signers)
+}
+
+// Parses the instruction data of the Ed25519 program and returns the public keys of the
+// signatures over `message`. Only signatures whose data is contained in the instruction
+// itself are considered, so the verified key and message are the ones we read here.
+fn ed25519_signers(data: &[u8], message: &[u8]) -> Result<Vec<Pubkey>> {
+    if data.len() < ED25519_HEADER_SIZE {
+        return err!(EarnError::InvalidAttestation);
+    }
+
+    let count = data[0] as usize;
+    let mut signers = Vec::with_capacity(count);
+
+    for i in 0..count {
+        let start = ED25519_HEADER_SIZE + i * ED25519_OFFSETS_SIZE;
+        let offsets = data
+            .get(start..start + ED25519_OFFSETS_SIZE)
+            .ok_or(EarnError::InvalidAttestation)?;
+
+        let read = |n: usize| u16::from_le_bytes([offsets[n * 2], offsets[n * 2 + 1]]);
+        let public_key_offset = read(2) as usize;
+        let message_offset = read(4) as usize;
+        let message_size = read(5) as usize;
+
+        // Signature, public key and message must all be in this instruction
+        if read(1) != u16::MAX || read(3) != u16::MAX || read(6) != u16::MAX {
+            continue;
+        }
+
+        let signed_message = data
+            .get(message_offset..message_offset + message_size)
+            .ok_or(EarnError::InvalidAttestation)?;
+
+        if signed_message != message {
+            continue;
+        }
+
+        let public_key = data
+            .get(public_key_offset..public_key_offset + 32)
+            .ok_or(EarnError::InvalidAttestation)?;
+
+        signers.push(Pubkey::try_from(public_key).unwrap());
+    }
+
+    Ok(signers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Builds Ed25519 program instruction data with all data in the instruction itself
+    fn ed25519_data(entries: &[(Pubkey, &[u8])]) -> Vec<u8> {
+        let mut data = vec![entries.len() as u8, 0];
+        let mut payload = vec![];
+        let payload_start = ED25519_HEADER_SIZE + entries.len() * ED25519_OFFSETS_SIZE;
+
+        for (key, message) in entries {
+            let signature_offset = payload_start + payload.len();
+            payload.extend([0u8; 64]);
+            let public_key_offset = payload_start + payload.len();
+            payload.extend(key.to_bytes());
+            let message_offset = payload_start + payload.len();
+            payload.extend(*message);
+
+            for value in [
+                signature_offset,
+                u16::MAX as usize,
+                public_key_offset,
+                u16::MAX as usize,
+                message_offset,
+                message.len(),
+                u16::MAX as usize,
+            ] {
+                data.extend((value as u16).to_le_bytes());
+            }
+        }
+
+        data.extend(payload);
+        data
+    }
+
+    #[test]
+    fn test_ed25519_signers() {
+        let mint = Pubkey::new_unique();
+        let message = attestation_message(&mint, 1_100_000_000_000, [7u8; 32], 1_700_000_000);
+        let other = attestation_message(&mint, 1_200_000_000_000, [7u8; 32], 1_700_000_000);
+
+        let signer1 = Pubkey::new_unique();
+        let signer2 = Pubkey::new_unique();
+        let signer3 = Pubkey::new_unique();
+
+        let data = ed25519_data(&[(signer1, &message), (signer2, &other), (signer3, &message)]);
+
+        assert_eq!(ed25519_signers(&data, &message).unwrap(), vec![signer1, signer3]);
+        assert_eq!(ed25519_signers(&data, &other).unwrap(), vec![signer2]);
+    }
+
+    #[test]
+    fn test_ed25519_signers_other_instruction() {
+        let message = attestation_message(&Pubkey::new_unique(), 1, [0u8; 32], 1);
+        let mut data = ed25519_data(&[(Pubkey::new_unique(), &message)]);
+
+        // Point the public key at another instruction
+        data[ED25519_HEADER_SIZE + 6..ED25519_HEADER_SIZE + 8].copy_from_slice(&0u16.to_le_bytes());
+
+        assert!(ed25519_signers(&data, &message).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_ed25519_signers_malformed() {
+        let message = attestation_message(&Pubkey::new_unique(), 1, [0u8; 32], 1);
+        let data = ed25519_data(&[(Pubkey::new_unique(), &message)]);
+
+        assert!(ed25519_signers(&[], &message).is_err());
+        assert!(ed25519_signers(&data[..ED25519_HEADER_SIZE + 4], &message).is_err());
+        assert!(ed25519_signers(&data[..data.len() - 1], &message).is_err());
+    }
+}
@@ -13,9 +13,37 @@ pub struct ProofElement {
     pub on_right: bool,
 }
 
+// Number of leaves under a node at `level`, which is the index offset of the leaves under its right sibling.
+// A tree of 2^64 leaves can't exist, deeper proofs are invalid rather than overflowing.
+fn leaves_under(level: u32) -> Result<u64> {
+    1u64.checked_shl(level).ok_or_else(|| error!(EarnError::InvalidProof))
+}
+
 pub fn verify_in_tree(root: [u8; 32], value: [u8; 32], proof: Vec<ProofElement>) -> Result<u64> {
     let leaf = solana_program::keccak::hashv(&[&[ZERO_BIT], value.as_slice()]).to_bytes();
 
+    verify_leaf_in_tree(root, leaf, proof)
+}
+
+/// Verifies that a claim for `snapshot_balance` on `token_account` is committed to in the tree.
+/// Leaves are hashed as keccak(0x00 || token_account || snapshot_balance as little-endian u64).
+pub fn verify_claim_in_tree(
+    root: [u8; 32],
+    token_account: Pubkey,
+    snapshot_balance: u64,
+    proof: Vec<ProofElement>,
+) -> Result<u64> {
+    let leaf = solana_program::keccak::hashv(&[
+        &[ZERO_BIT],
+        token_account.as_ref(),
+        &snapshot_balance.to_le_bytes(),
+    ])
+    .to_bytes();
+
+    verify_leaf_in_tree(root, leaf, proof)
+}
+
+fn verify_leaf_in_tree(root: [u8; 32], leaf: [u8; 32], proof: Vec<ProofElement>) -> Result<u64> {
     let mut computed_hash = leaf;
     let mut index: u64 = 0;
 
@@ -37,7 +65,7 @@ pub fn verify_in_tree(root: [u8; 32], value: [u8; 32], proof: Vec<ProofElement>)
             ])
             .to_bytes();
             // Since the proof element is on the left, we need to increment the index by 2^i
-            index += 2u64.pow(i as u32);
+            index += leaves_under(i as u32)?;
         }
     }
 
@@ -55,8 +83,7 @@ pub fn verify_not_in_tree(
     neighbors: Vec<[u8; 32]>,
 ) -> Result<()> {
     // The number of proofs should match the number of neighbors
-    // TODO we can make this more efficient using a multiproof, see
-    // https://github.com/OpenZeppelin/openzeppelin-contracts/blob/acd4ff74de833399287ed6b31b4debf6b2b35527/contracts/utils/cryptography/MerkleProof.sol#L290
+    // See verify_not_in_tree_multi for a more efficient version that shares nodes between the neighbor proofs
 
     let len = proofs.len();
     if neighbors.len() != len {
@@ -91,7 +118,7 @@ pub fn verify_not_in_tree(
             // @audit I believe this works because we use different bits to hash leafs vs. nodes to protect
             // against second pre-image attacks -> attacker cannot provide a proof that hashes to the root
             // using a subset of the tree (which would be shorter)
-            let expected_index = 2u64.pow(proof.len() as u32) - 1;
+            let expected_index = leaves_under(proof.len() as u32)? - 1;
 
             let neighbor_index = verify_in_tree(root, neighbor, proof.clone())?;
 
@@ -132,3 +159,475 @@ pub fn verify_not_in_tree(
     // The leaf is not in the tree
     Ok(())
 }
+
+/// Node in the multiproof processing queue. Tracks the level of the node in the tree
+/// and the (contiguous) range of leaves it covers so their indices can be recovered.
+#[derive(Clone, Copy)]
+struct MultiProofNode {
+    hash: [u8; 32],
+    level: u32,
+    first_leaf: usize,
+    last_leaf: usize,
+}
+
+/// Verifies that all `values` are leaves of the tree using a single multiproof, based on
+/// https://github.com/OpenZeppelin/openzeppelin-contracts/blob/acd4ff74de833399287ed6b31b4debf6b2b35527/contracts/utils/cryptography/MerkleProof.sol#L290
+///
+/// Our tree hashes pairs by position instead of sorting them, so `values` must be provided in tree order
+/// (ascending) and each proof element carries the side it is on. A `true` proof flag combines the next two
+/// nodes in the queue, `false` combines the next node in the queue with the next proof element.
+///
+/// Returns the index of each value in the tree and the depth of the tree.
+pub fn verify_multi_in_tree(
+    root: [u8; 32],
+    values: &[[u8; 32]],
+    proof: Vec<ProofElement>,
+    proof_flags: Vec<bool>,
+) -> Result<(Vec<u64>, u32)> {
+    let leaves_len = values.len();
+    let total_hashes = proof_flags.len();
+
+    // Values must be unique and in tree order, and every leaf or proof node
+    // except the root must be consumed by exactly one hash
+    if leaves_len == 0
+        || values.windows(2).any(|pair| pair[0] >= pair[1])
+        || leaves_len + proof.len() != total_hashes + 1
+    {
+        return err!(EarnError::InvalidProof);
+    }
+
+    // The queue starts with the leaves and computed hashes are appended to it
+    let mut queue: Vec<MultiProofNode> = Vec::with_capacity(leaves_len + total_hashes);
+    for (i, value) in values.iter().enumerate() {
+        queue.push(MultiProofNode {
+            hash: solana_program::keccak::hashv(&[&[ZERO_BIT], value.as_slice()]).to_bytes(),
+            level: 0,
+            first_leaf: i,
+            last_leaf: i,
+        });
+    }
+
+    let mut indices = vec![0u64; leaves_len];
+    let mut queue_pos = 0;
+    let mut proof_pos = 0;
+
+    for flag in proof_flags {
+        let left = *queue.get(queue_pos).ok_or(EarnError::InvalidProof)?;
+        queue_pos += 1;
+
+        let node = if flag {
+            // Both nodes come from the queue, they must be siblings on the same level
+            let right = *queue.get(queue_pos).ok_or(EarnError::InvalidProof)?;
+            queue_pos += 1;
+
+            if left.level != right.level || left.last_leaf >= right.first_leaf {
+                return err!(EarnError::InvalidProof);
+            }
+
+            // The leaves under the right node are offset by 2^level
+            for index in &mut indices[right.first_leaf..=right.last_leaf] {
+                *index += leaves_under(left.level)?;
+            }
+
+            MultiProofNode {
+                hash: solana_program::keccak::hashv(&[
+                    &[ONE_BIT],
+                    left.hash.as_slice(),
+                    right.hash.as_slice(),
+                ])
+                .to_bytes(),
+                level: left.level + 1,
+                first_leaf: left.first_leaf,
+                last_leaf: right.last_leaf,
+            }
+        } else {
+            let proof_element = proof.get(proof_pos).ok_or(EarnError::InvalidProof)?;
+            proof_pos += 1;
+
+            let hash = if proof_element.on_right {
+                solana_program::keccak::hashv(&[
+                    &[ONE_BIT],
+                    left.hash.as_slice(),
+                    proof_element.node.as_slice(),
+                ])
+                .to_bytes()
+            } else {
+                // Since the proof element is on the left, the leaves under the node are offset by 2^level
+                for index in &mut indices[left.first_leaf..=left.last_leaf] {
+                    *index += leaves_under(left.level)?;
+                }
+
+                solana_program::keccak::hashv(&[
+                    &[ONE_BIT],
+                    proof_element.node.as_slice(),
+                    left.hash.as_slice(),
+                ])
+                .to_bytes()
+            };
+
+            MultiProofNode {
+                hash,
+                level: left.level + 1,
+                first_leaf: left.first_leaf,
+                last_leaf: left.last_leaf,
+            }
+        };
+
+        queue.push(node);
+    }
+
+    // Everything must have been consumed, leaving the root as the last node in the queue
+    if proof_pos != proof.len() || queue_pos + 1 != queue.len() {
+        return err!(EarnError::InvalidProof);
+    }
+
+    let computed_root = queue[queue_pos];
+
+    if computed_root.hash != root {
+        return err!(EarnError::InvalidProof);
+    }
+
+    Ok((indices, computed_root.level))
+}
+
+/// Verifies that none of the `values` are in the tree with a single multiproof over their neighbors.
+///
+/// `neighbors` are leaves of the tree in ascending order. Each value must either be between two
+/// neighbors that are next to each other in the tree, smaller than the first leaf of the tree,
+/// or larger than the last leaf of the tree.
+pub fn verify_not_in_tree_multi(
+    root: [u8; 32],
+    values: &[[u8; 32]],
+    neighbors: Vec<[u8; 32]>,
+    proof: Vec<ProofElement>,
+    proof_flags: Vec<bool>,
+) -> Result<()> {
+    if values.is_empty() {
+        return err!(EarnError::InvalidProof);
+    }
+
+    let (indices, depth) = verify_multi_in_tree(root, &neighbors, proof, proof_flags)?;
+
+    // See verify_not_in_tree for why the proof length (depth) gives us the index of the last leaf
+    let last_index = leaves_under(depth)? - 1;
+
+    for value in values {
+        // Find the first neighbor that is larger than the value
+        let position = neighbors.partition_point(|neighbor| neighbor < value);
+
+        // Can't be the same as a neighbor
+        if position < neighbors.len() && neighbors[position] == *value {
+            return err!(EarnError::InvalidProof);
+        }
+
+        let valid = if position == 0 {
+            // Value is smaller than the smallest leaf in the tree
+            indices[0] == 0
+        } else if position == neighbors.len() {
+            // Value is larger than the largest leaf in the tree
+            indices[position - 1] == last_index
+        } else {
+            // Value is between two leaves that are next to each other
+            indices[position - 1] + 1 == indices[position]
+        };
+
+        if !valid {
+            return err!(EarnError::InvalidProof);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash_leaf(value: &[u8; 32]) -> [u8; 32] {
+        solana_program::keccak::hashv(&[&[ZERO_BIT], value.as_slice()]).to_bytes()
+    }
+
+    fn hash_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        solana_program::keccak::hashv(&[&[ONE_BIT], left.as_slice(), right.as_slice()]).to_bytes()
+    }
+
+    fn value(byte: u8) -> [u8; 32] {
+        [byte; 32]
+    }
+
+    // Mirrors the SDK tree: the last node of an odd level is hashed with itself
+    fn build_tree(values: &[[u8; 32]]) -> Vec<Vec<[u8; 32]>> {
+        let mut tree = vec![values.iter().map(hash_leaf).collect::<Vec<_>>()];
+
+        while tree.last().unwrap().len() > 1 {
+            let level = tree.last().unwrap();
+            let next = level
+                .chunks(2)
+                .map(|pair| hash_node(&pair[0], pair.get(1).unwrap_or(&pair[0])))
+                .collect();
+            tree.push(next);
+        }
+
+        tree
+    }
+
+    // Duplicated nodes are their own sibling, `duplicate_on_left` controls which side they are presented on
+    fn multi_proof(
+        tree: &[Vec<[u8; 32]>],
+        positions: &[usize],
+        duplicate_on_left: bool,
+    ) -> (Vec<ProofElement>, Vec<bool>) {
+        let mut proof = vec![];
+        let mut flags = vec![];
+        let mut known = positions.to_vec();
+
+        for nodes in &tree[..tree.len() - 1] {
+            let mut next = vec![];
+            let mut i = 0;
+
+            while i < known.len() {
+                let index = known[i];
+
+                if index % 2 == 0 && known.get(i + 1) == Some(&(index + 1)) {
+                    flags.push(true);
+                    i += 2;
+                } else {
+                    let element = match nodes.get(index ^ 1) {
+                        Some(node) => ProofElement {
+                            node: *node,
+                            on_right: index % 2 == 0,
+                        },
+                        None => ProofElement {
+                            node: nodes[index],
+                            on_right: !duplicate_on_left,
+                        },
+                    };
+                    proof.push(element);
+                    flags.push(false);
+                    i += 1;
+                }
+
+                if next.last() != Some(&(index / 2)) {
+                    next.push(index / 2);
+                }
+            }
+
+            known = next;
+        }
+
+        (proof, flags)
+    }
+
+    fn root(tree: &[Vec<[u8; 32]>]) -> [u8; 32] {
+        tree.last().unwrap()[0]
+    }
+
+    #[test]
+    fn multi_proof_verifies_leaves() {
+        let values: Vec<[u8; 32]> = (1..=7).map(|i| value(i * 10)).collect();
+        let tree = build_tree(&values);
+
+        let positions = [0, 3, 4, 6];
+        let (proof, flags) = multi_proof(&tree, &positions, false);
+        let leaves: Vec<[u8; 32]> = positions.iter().map(|i| values[*i]).collect();
+
+        let (indices, depth) = verify_multi_in_tree(root(&tree), &leaves, proof, flags).unwrap();
+
+        assert_eq!(indices, vec![0, 3, 4, 6]);
+        assert_eq!(depth, 3);
+    }
+
+    #[test]
+    fn multi_proof_matches_single_proof() {
+        let values: Vec<[u8; 32]> = (1..=5).map(|i| value(i * 10)).collect();
+        let tree = build_tree(&values);
+
+        for (position, leaf) in values.iter().enumerate() {
+            let (proof, flags) = multi_proof(&tree, &[position], false);
+            let single_index = verify_in_tree(root(&tree), *leaf, proof.clone()).unwrap();
+
+            let (indices, _) = verify_multi_in_tree(root(&tree), &[*leaf], proof, flags).unwrap();
+
+            assert_eq!(indices, vec![single_index]);
+        }
+    }
+
+    #[test]
+    fn multi_proof_single_leaf_tree() {
+        let tree = build_tree(&[value(1)]);
+
+        let (indices, depth) = verify_multi_in_tree(root(&tree), &[value(1)], vec![], vec![]).unwrap();
+
+        assert_eq!(indices, vec![0]);
+        assert_eq!(depth, 0);
+    }
+
+    #[test]
+    fn multi_proof_rejects_invalid_input() {
+        let values: Vec<[u8; 32]> = (1..=6).map(|i| value(i * 10)).collect();
+        let tree = build_tree(&values);
+        let (proof, flags) = multi_proof(&tree, &[1, 2], false);
+
+        // Out of order
+        assert!(verify_multi_in_tree(
+            root(&tree),
+            &[values[2], values[1]],
+            proof.clone(),
+            flags.clone()
+        )
+        .is_err());
+
+        // Not in the tree
+        assert!(verify_multi_in_tree(
+            root(&tree),
+            &[values[1], value(35)],
+            proof.clone(),
+            flags.clone()
+        )
+        .is_err());
+
+        // Flags that don't match the proof
+        let mut bad_flags = flags.clone();
+        bad_flags[0] = !bad_flags[0];
+        assert!(
+            verify_multi_in_tree(root(&tree), &[values[1], values[2]], proof.clone(), bad_flags)
+                .is_err()
+        );
+
+        // Missing proof element
+        let mut short_proof = proof.clone();
+        short_proof.pop();
+        assert!(
+            verify_multi_in_tree(root(&tree), &[values[1], values[2]], short_proof, flags).is_err()
+        );
+    }
+
+    #[test]
+    fn not_in_tree_multi_between_neighbors() {
+        let values: Vec<[u8; 32]> = (1..=5).map(|i| value(i * 10)).collect();
+        let tree = build_tree(&values);
+
+        // 25 is between 20 and 30, 45 is between 40 and 50
+        let (proof, flags) = multi_proof(&tree, &[1, 2, 3, 4], false);
+        let neighbors = vec![values[1], values[2], values[3], values[4]];
+
+        verify_not_in_tree_multi(
+            root(&tree),
+            &[value(25), value(45)],
+            neighbors.clone(),
+            proof.clone(),
+            flags.clone(),
+        )
+        .unwrap();
+
+        // 35 is between 30 and 40 as well
+        verify_not_in_tree_multi(
+            root(&tree),
+            &[value(35)],
+            neighbors.clone(),
+            proof.clone(),
+            flags.clone(),
+        )
+        .unwrap();
+
+        // Values in the tree can't be proven absent
+        assert!(
+            verify_not_in_tree_multi(root(&tree), &[value(30)], neighbors, proof, flags).is_err()
+        );
+    }
+
+    #[test]
+    fn not_in_tree_multi_rejects_non_adjacent_neighbors() {
+        let values: Vec<[u8; 32]> = (1..=5).map(|i| value(i * 10)).collect();
+        let tree = build_tree(&values);
+
+        // 20 and 40 are both in the tree but 30 is between them
+        let (proof, flags) = multi_proof(&tree, &[1, 3], false);
+
+        assert!(verify_not_in_tree_multi(
+            root(&tree),
+            &[value(35)],
+            vec![values[1], values[3]],
+            proof,
+            flags,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn not_in_tree_multi_outside_bounds() {
+        let values: Vec<[u8; 32]> = (1..=5).map(|i| value(i * 10)).collect();
+        let tree = build_tree(&values);
+
+        // Smaller than the first leaf
+        let (proof, flags) = multi_proof(&tree, &[0], false);
+        verify_not_in_tree_multi(root(&tree), &[value(5)], vec![values[0]], proof, flags).unwrap();
+
+        // Larger than the last leaf, which is presented as the rightmost node of the tree
+        let (proof, flags) = multi_proof(&tree, &[4], true);
+        verify_not_in_tree_multi(root(&tree), &[value(55)], vec![values[4]], proof, flags).unwrap();
+
+        // Both at once
+        let (proof, flags) = multi_proof(&tree, &[0, 4], true);
+        verify_not_in_tree_multi(
+            root(&tree),
+            &[value(5), value(55)],
+            vec![values[0], values[4]],
+            proof,
+            flags,
+        )
+        .unwrap();
+
+        // A leaf that is not the last one can't be used to prove a larger value is absent
+        let (proof, flags) = multi_proof(&tree, &[3], true);
+        assert!(verify_not_in_tree_multi(
+            root(&tree),
+            &[value(55)],
+            vec![values[3]],
+            proof,
+            flags
+        )
+        .is_err());
+    }
+
+    // Hashes `leaf` up through `depth` levels with proof elements on the right
+    fn deep_proof(leaf: &[u8; 32], depth: usize) -> ([u8; 32], Vec<ProofElement>) {
+        let mut node = hash_leaf(leaf);
+        let mut proof = vec![];
+
+        for i in 0..depth {
+            let sibling = value(i as u8);
+            node = hash_node(&node, &sibling);
+            proof.push(ProofElement {
+                node: sibling,
+                on_right: true,
+            });
+        }
+
+        (node, proof)
+    }
+
+    #[test]
+    fn proofs_deeper_than_64_levels_are_invalid() {
+        // A left proof element at level 64 would offset the index by 2^64
+        let (_, mut proof) = deep_proof(&value(1), 64);
+        proof.push(ProofElement {
+            node: value(2),
+            on_right: false,
+        });
+        let flags = vec![false; proof.len()];
+
+        assert!(verify_multi_in_tree([0u8; 32], &[value(1)], proof.clone(), flags).is_err());
+        assert!(verify_in_tree([0u8; 32], value(1), proof).is_err());
+
+        // A valid 64 level proof has no last leaf index that fits in a u64
+        let (root, proof) = deep_proof(&value(1), 64);
+        let flags = vec![false; proof.len()];
+
+        assert!(
+            verify_not_in_tree_multi(root, &[value(0)], vec![value(1)], proof.clone(), flags)
+                .is_err()
+        );
+        assert!(verify_not_in_tree(root, value(2), vec![proof], vec![value(1)]).is_err());
+    }
+}
@@ -0,0 +1,84 @@
+// earn/utils/migration.rs
+
+// external dependencies
+use anchor_lang::{
+    error::ErrorCode,
+    prelude::*,
+    system_program::{transfer, Transfer},
+    Discriminator,
+};
+
+// local dependencies
+use crate::constants::ANCHOR_DISCRIMINATOR_SIZE;
+
+/// Grows an account created with an older, smaller layout of `T` to the current size.
+/// Accounts on an old layout can't be deserialized, so the owner and discriminator are checked here.
+/// The rent exemption is topped up by `payer` and new bytes are zero initialized.
+/// Shared with the extension programs, so it only returns Anchor errors.
+pub fn realloc_to_current<'info, T: Owner + Discriminator + Space>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    check_account::<T>(account)?;
+
+    let space = ANCHOR_DISCRIMINATOR_SIZE + T::INIT_SPACE;
+
+    // Nothing to do if the account already has the current size
+    if account.data_len() >= space {
+        return Ok(());
+    }
+
+    let rent = Rent::get()?.minimum_balance(space);
+    let current_lamports = account.lamports();
+
+    if current_lamports < rent {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent - current_lamports,
+        )?;
+    }
+
+    // New fields are zero initialized, which deserializes to their default values (e.g. None)
+    account.realloc(space, true)?;
+
+    Ok(())
+}
+
+/// Same as `realloc_to_current` for instructions without a payer,
+/// the account must already hold the rent exemption for the current size.
+pub fn realloc_prefunded<T: Owner + Discriminator + Space>(account: &AccountInfo) -> Result<()> {
+    check_account::<T>(account)?;
+
+    let space = ANCHOR_DISCRIMINATOR_SIZE + T::INIT_SPACE;
+
+    if account.data_len() >= space {
+        return Ok(());
+    }
+
+    if account.lamports() < Rent::get()?.minimum_balance(space) {
+        return err!(ErrorCode::ConstraintRentExempt);
+    }
+
+    account.realloc(space, true)?;
+
+    Ok(())
+}
+
+fn check_account<T: Owner + Discriminator>(account: &AccountInfo) -> Result<()> {
+    if account.owner != &T::owner() {
+        return err!(ErrorCode::AccountOwnedByWrongProgram);
+    }
+
+    if !account.try_borrow_data()?.starts_with(&T::DISCRIMINATOR) {
+        return err!(ErrorCode::AccountDiscriminatorMismatch);
+    }
+
+    Ok(())
+}
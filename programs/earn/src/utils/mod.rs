@@ -1,4 +1,9 @@
 // earn/utils/mod.rs
 
+pub mod account;
+pub mod attestation;
 pub mod merkle_proof;
+pub mod migration;
+pub mod rewards;
+pub mod roles;
 pub mod token;
@@ -0,0 +1,182 @@
+// earn/utils/rewards.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+
+// local dependencies
+use crate::{
+    errors::EarnError,
+    instructions::complete_claims::ClaimCycleCompleted,
+    state::{ClaimCycle, CompletedCycle, Earner, Global},
+};
+
+/// Calculates the yield earned on `balance` between `last_claim_index` and `index`.
+/// Callers must ensure that `index` > `last_claim_index`.
+pub fn calculate_rewards(balance: u64, last_claim_index: u64, index: u64) -> u64 {
+    // Cast to u128 for multiplication to avoid overflows
+    let total: u64 = (balance as u128)
+        .checked_mul(index.into())
+        .unwrap()
+        .checked_div(last_claim_index.into())
+        .unwrap()
+        .try_into()
+        .unwrap();
+
+    total - balance // can't underflow because index > last claim index
+}
+
+/// Validates a claim for the current cycle and records it on the global and earner accounts.
+/// Returns the amount of rewards that must be minted to the earner.
+pub fn settle_claim(
+    global: &mut Global,
+    earner: &mut Earner,
+    snapshot_balance: u64,
+) -> Result<u64> {
+    // Validate that the earner account has not already claimed this cycle
+    // Earner index should never be > global index, but we check to be safe against an error with index propagation
+    if earner.last_claim_index >= global.index {
+        return err!(EarnError::AlreadyClaimed);
+    }
+
+    // Validate there is an active claim cycle
+    let current_timestamp: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
+    if global.claim_complete || claim_window_expired(global, current_timestamp) {
+        return err!(EarnError::NoActiveClaim);
+    }
+
+    // Calculate the amount of tokens to send to the user
+    let rewards = calculate_rewards(snapshot_balance, earner.last_claim_index, global.index);
+
+    // Validate the rewards do not cause the distributed amount to exceed the max yield
+    let distributed = global.distributed.checked_add(rewards).unwrap();
+
+    if distributed > global.max_yield {
+        return err!(EarnError::ExceedsMaxYield);
+    }
+
+    // Update the total distributed and the number of claims in the cycle
+    global.distributed = distributed;
+    global.claims += 1;
+
+    // Earners created before lifetime stats were tracked have no first earning timestamp,
+    // they started earning no later than their last claim
+    if earner.first_earning_timestamp == 0 {
+        earner.first_earning_timestamp = earner.last_claim_timestamp;
+    }
+
+    // Set the earner's last claim index to the global index and update the last claim timestamp
+    earner.last_claim_index = global.index;
+    earner.last_claim_timestamp = global.timestamp;
+
+    // Update the earner's lifetime stats
+    earner.total_rewards += rewards;
+    earner.claims += 1;
+
+    Ok(rewards)
+}
+
+/// Returns true if the claim window of the current cycle has passed.
+/// The cycle is then considered complete even if the earn authority has not completed it.
+pub fn claim_window_expired(global: &Global, current_timestamp: u64) -> bool {
+    global.claim_window != 0 && current_timestamp >= global.timestamp + global.claim_window
+}
+
+/// Returns true if the last completed cycle has not been written to its history account yet.
+pub fn has_unrecorded_cycle(global: &Global) -> bool {
+    global.completed_cycle.completed_at != 0 && !global.completed_cycle_recorded
+}
+
+/// Completes the current claim cycle, either by the earn authority or because its claim window passed.
+/// The final state is kept on the global account until it is written to its history account,
+/// which has to happen before the next cycle can complete.
+pub fn close_claim_cycle(
+    global: &mut Global,
+    current_timestamp: u64,
+    timed_out: bool,
+) -> Result<()> {
+    if has_unrecorded_cycle(global) {
+        return err!(EarnError::UnrecordedClaimCycle);
+    }
+
+    global.claim_complete = true;
+    global.completed_cycle_recorded = false;
+
+    global.completed_cycle = CompletedCycle {
+        index: global.index,
+        previous_index: global.previous_index,
+        timestamp: global.timestamp,
+        max_supply: global.max_supply,
+        max_yield: global.max_yield,
+        distributed: global.distributed,
+        claims: global.claims,
+        completed_at: current_timestamp,
+        timed_out,
+    };
+
+    emit!(ClaimCycleCompleted {
+        index: global.index,
+        max_yield: global.max_yield,
+        distributed: global.distributed,
+        claims: global.claims,
+        timed_out,
+        ts: current_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Writes the last completed claim cycle to its history account.
+pub fn record_completed_cycle(global: &mut Global, claim_cycle: &mut ClaimCycle, bump: u8) {
+    write_claim_cycle(claim_cycle, &global.completed_cycle, bump);
+    global.completed_cycle_recorded = true;
+}
+
+fn write_claim_cycle(claim_cycle: &mut ClaimCycle, cycle: &CompletedCycle, bump: u8) {
+    claim_cycle.index = cycle.index;
+    claim_cycle.previous_index = cycle.previous_index;
+    claim_cycle.timestamp = cycle.timestamp;
+    claim_cycle.max_supply = cycle.max_supply;
+    claim_cycle.max_yield = cycle.max_yield;
+    claim_cycle.distributed = cycle.distributed;
+    claim_cycle.claims = cycle.claims;
+    claim_cycle.completed_at = cycle.completed_at;
+    claim_cycle.timed_out = cycle.timed_out;
+    claim_cycle.bump = bump;
+}
+
+/// Ages the unclaimed yield buckets when a new claim cycle starts.
+/// Yield distributed in the cycle that ended is assumed to come from the oldest buckets first.
+pub fn age_unclaimed_yield(global: &mut Global, period_max: u64) {
+    let buckets = &mut global.unclaimed_yield;
+
+    // Yield from before the buckets were tracked is attributed to the cycle that ended
+    let tracked: u64 = buckets.iter().sum();
+    buckets[0] += global.max_yield.saturating_sub(tracked);
+
+    let mut distributed = global.distributed;
+    for bucket in buckets.iter_mut().rev() {
+        let consumed = distributed.min(*bucket);
+        *bucket -= consumed;
+        distributed -= consumed;
+    }
+
+    // The oldest bucket accumulates everything past the max expiry
+    let last = buckets.len() - 1;
+    buckets[last] += buckets[last - 1];
+    buckets.copy_within(0..last - 1, 1);
+    buckets[0] = period_max;
+}
+
+/// Returns the unclaimed yield that is older than the expiry and has not been claimed this cycle.
+pub fn expired_unclaimed_yield(global: &Global) -> u64 {
+    if global.unclaimed_expiry_cycles == 0 {
+        return 0;
+    }
+
+    let expired: u64 = global.unclaimed_yield[global.unclaimed_expiry_cycles as usize..]
+        .iter()
+        .sum();
+
+    // Claims in the current cycle are assumed to come from the oldest yield first
+    expired.saturating_sub(global.distributed)
+}
@@ -0,0 +1,31 @@
+// earn/utils/roles.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+
+// local dependencies
+use crate::state::{Global, Role, Roles};
+
+/// Returns the members of `role` stored on the roles account.
+pub fn role_members(roles: &mut Roles, role: Role) -> &mut Vec<Pubkey> {
+    match role {
+        Role::EarnAuthority => &mut roles.earn_authorities,
+        Role::Pauser => &mut roles.pausers,
+    }
+}
+
+/// Returns true if `key` is the global earn authority or a member of the earn authority role.
+pub fn is_earn_authority(global: &Global, roles: &Option<Account<Roles>>, key: &Pubkey) -> bool {
+    global.earn_authority == *key
+        || roles
+            .as_ref()
+            .is_some_and(|roles| roles.earn_authorities.contains(key))
+}
+
+/// Returns true if `key` is the admin or a member of the pauser role.
+pub fn is_pauser(global: &Global, roles: &Option<Account<Roles>>, key: &Pubkey) -> bool {
+    global.admin == *key
+        || roles
+            .as_ref()
+            .is_some_and(|roles| roles.pausers.contains(key))
+}
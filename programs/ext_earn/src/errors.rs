@@ -20,4 +20,14 @@ pub enum ExtError {
     InsufficientCollateral,
     #[msg("Invalid Mint.")]
     InvalidMint,
+    #[msg("Role has reached the maximum number of members.")]
+    RoleFull,
+    #[msg("Account is not on the expected version.")]
+    InvalidVersion,
+    #[msg("Not supported in the yield mode of the extension.")]
+    InvalidYieldMode,
+    #[msg("Index has not been synced with the M earn index.")]
+    IndexNotSynced,
+    #[msg("Earners have not all been claimed for the current index.")]
+    ClaimsPending,
 }
@@ -6,7 +6,11 @@ use anchor_spl::token_interface::TokenAccount;
 use crate::{
     constants::{ANCHOR_DISCRIMINATOR_SIZE, ONE_HUNDRED_PERCENT},
     errors::ExtError,
-    state::{EarnManager, ExtGlobal, EARN_MANAGER_SEED, EXT_GLOBAL_SEED},
+    state::{
+        EarnManager, ExtGlobal, Roles, EARN_MANAGER_SEED, EARN_MANAGER_VERSION, EXT_GLOBAL_SEED,
+        EXT_GLOBAL_VERSION, ROLES_SEED,
+    },
+    utils::roles::is_earn_manager_admin,
 };
 
 #[derive(Accounts)]
@@ -16,9 +20,10 @@ pub struct AddEarnManager<'info> {
     pub admin: Signer<'info>,
 
     #[account(
-        seeds = [EXT_GLOBAL_SEED],
+        seeds = [EXT_GLOBAL_SEED, global_account.ext_mint.as_ref()],
         bump = global_account.bump,
-        has_one = admin @ ExtError::NotAuthorized,
+        constraint = is_earn_manager_admin(&global_account, &roles_account, &admin.key()) @ ExtError::NotAuthorized,
+        constraint = global_account.version == EXT_GLOBAL_VERSION @ ExtError::InvalidVersion,
     )]
     pub global_account: Account<'info, ExtGlobal>,
 
@@ -26,7 +31,7 @@ pub struct AddEarnManager<'info> {
         init_if_needed,
         payer = admin,
         space = ANCHOR_DISCRIMINATOR_SIZE + EarnManager::INIT_SPACE,
-        seeds = [EARN_MANAGER_SEED, earn_manager.as_ref()],
+        seeds = [EARN_MANAGER_SEED, global_account.ext_mint.as_ref(), earn_manager.as_ref()],
         bump
     )]
     pub earn_manager_account: Account<'info, EarnManager>,
@@ -35,6 +40,12 @@ pub struct AddEarnManager<'info> {
     pub fee_token_account: InterfaceAccount<'info, TokenAccount>,
 
     pub system_program: Program<'info, System>,
+
+    #[account(
+        seeds = [ROLES_SEED, global_account.ext_mint.as_ref()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Option<Account<'info, Roles>>,
 }
 
 pub fn handler(ctx: Context<AddEarnManager>, earn_manager: Pubkey, fee_bps: u64) -> Result<()> {
@@ -42,13 +53,37 @@ pub fn handler(ctx: Context<AddEarnManager>, earn_manager: Pubkey, fee_bps: u64)
         return err!(ExtError::InvalidParam);
     }
 
+    // Keep the payer of an earn manager that is added again
+    let rent_payer = match ctx.accounts.earn_manager_account.rent_payer {
+        rent_payer if rent_payer != Pubkey::default() => rent_payer,
+        _ => ctx.accounts.admin.key(),
+    };
+
     ctx.accounts.earn_manager_account.set_inner(EarnManager {
         earn_manager,
         is_active: true,
         fee_bps,
         fee_token_account: ctx.accounts.fee_token_account.key(),
         bump: ctx.bumps.earn_manager_account,
+        version: EARN_MANAGER_VERSION,
+        rent_payer,
+        reserved: [0; 32],
+    });
+
+    emit!(EarnManagerAdded {
+        ext_mint: ctx.accounts.global_account.ext_mint,
+        earn_manager,
+        fee_bps,
+        fee_token_account: ctx.accounts.fee_token_account.key(),
     });
 
     Ok(())
 }
+
+#[event]
+pub struct EarnManagerAdded {
+    pub ext_mint: Pubkey,
+    pub earn_manager: Pubkey,
+    pub fee_bps: u64,
+    pub fee_token_account: Pubkey,
+}
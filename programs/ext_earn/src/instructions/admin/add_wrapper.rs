@@ -0,0 +1,57 @@
+// ext_earn/instructions/admin/add_wrapper.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+
+// local dependencies
+use crate::{
+    constants::ANCHOR_DISCRIMINATOR_SIZE,
+    errors::ExtError,
+    state::{ExtGlobal, Wrapper, EXT_GLOBAL_SEED, EXT_GLOBAL_VERSION, WRAPPER_SEED},
+};
+
+#[derive(Accounts)]
+#[instruction(wrapper: Pubkey)]
+pub struct AddWrapper<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        constraint = global_account.version == EXT_GLOBAL_VERSION @ ExtError::InvalidVersion,
+        seeds = [EXT_GLOBAL_SEED, global_account.ext_mint.as_ref()],
+        has_one = admin @ ExtError::NotAuthorized,
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, ExtGlobal>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = ANCHOR_DISCRIMINATOR_SIZE + Wrapper::INIT_SPACE,
+        seeds = [WRAPPER_SEED, global_account.ext_mint.as_ref(), wrapper.as_ref()],
+        bump
+    )]
+    pub wrapper_account: Account<'info, Wrapper>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AddWrapper>, wrapper: Pubkey) -> Result<()> {
+    ctx.accounts.wrapper_account.set_inner(Wrapper {
+        wrapper,
+        bump: ctx.bumps.wrapper_account,
+    });
+
+    emit!(WrapperAdded {
+        ext_mint: ctx.accounts.global_account.ext_mint,
+        wrapper,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct WrapperAdded {
+    pub ext_mint: Pubkey,
+    pub wrapper: Pubkey,
+}
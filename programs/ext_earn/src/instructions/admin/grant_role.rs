@@ -0,0 +1,65 @@
+// ext_earn/instructions/admin/grant_role.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+
+// local dependencies
+use crate::{
+    constants::ANCHOR_DISCRIMINATOR_SIZE,
+    errors::ExtError,
+    state::{
+        ExtGlobal, Role, Roles, EXT_GLOBAL_SEED, EXT_GLOBAL_VERSION, MAX_ROLE_MEMBERS, ROLES_SEED,
+    },
+    utils::roles::role_members,
+};
+
+#[derive(Accounts)]
+pub struct GrantRole<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        constraint = global_account.version == EXT_GLOBAL_VERSION @ ExtError::InvalidVersion,
+        seeds = [EXT_GLOBAL_SEED, global_account.ext_mint.as_ref()],
+        has_one = admin @ ExtError::NotAuthorized,
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, ExtGlobal>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ANCHOR_DISCRIMINATOR_SIZE + Roles::INIT_SPACE,
+        seeds = [ROLES_SEED, global_account.ext_mint.as_ref()],
+        bump,
+    )]
+    pub roles_account: Account<'info, Roles>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<GrantRole>, role: Role, member: Pubkey) -> Result<()> {
+    ctx.accounts.roles_account.bump = ctx.bumps.roles_account;
+
+    let members = role_members(&mut ctx.accounts.roles_account, role);
+
+    if members.contains(&member) {
+        return err!(ExtError::InvalidParam);
+    }
+
+    if members.len() >= MAX_ROLE_MEMBERS {
+        return err!(ExtError::RoleFull);
+    }
+
+    members.push(member);
+
+    emit!(RoleGranted { role, member });
+
+    Ok(())
+}
+
+#[event]
+pub struct RoleGranted {
+    pub role: Role,
+    pub member: Pubkey,
+}
@@ -8,7 +8,11 @@ use anchor_spl::token_interface::{Mint, Token2022};
 use crate::{
     constants::ANCHOR_DISCRIMINATOR_SIZE,
     errors::ExtError,
-    state::{ExtGlobal, EXT_GLOBAL_SEED, MINT_AUTHORITY_SEED, M_VAULT_SEED},
+    state::{
+        Allowlist, ExtGlobal, YieldMode, EXT_GLOBAL_SEED, EXT_GLOBAL_VERSION, MINT_AUTHORITY_SEED,
+        M_VAULT_SEED,
+    },
+    utils::token::{permanent_delegate, scaled_ui_amount_authority},
 };
 use earn::{
     state::{Global as EarnGlobal, GLOBAL_SEED as EARN_GLOBAL_SEED},
@@ -24,7 +28,7 @@ pub struct Initialize<'info> {
         init,
         payer = admin,
         space = ANCHOR_DISCRIMINATOR_SIZE + ExtGlobal::INIT_SPACE,
-        seeds = [EXT_GLOBAL_SEED],
+        seeds = [EXT_GLOBAL_SEED, ext_mint.key().as_ref()],
         bump
     )]
     pub global_account: Account<'info, ExtGlobal>,
@@ -54,19 +58,35 @@ pub struct Initialize<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<Initialize>, earn_authority: Pubkey) -> Result<()> {
-    let m_vault_bump = Pubkey::find_program_address(&[M_VAULT_SEED], ctx.program_id).1;
+pub fn handler(
+    ctx: Context<Initialize>,
+    earn_authority: Pubkey,
+    yield_mode: YieldMode,
+) -> Result<()> {
+    // Each extension has its own vault and mint authority
+    let ext_mint = ctx.accounts.ext_mint.key();
+    let m_vault_bump =
+        Pubkey::find_program_address(&[M_VAULT_SEED, ext_mint.as_ref()], ctx.program_id).1;
     let (ext_mint_authority, ext_mint_authority_bump) =
-        Pubkey::find_program_address(&[MINT_AUTHORITY_SEED], ctx.program_id);
+        Pubkey::find_program_address(&[MINT_AUTHORITY_SEED, ext_mint.as_ref()], ctx.program_id);
 
     if ctx.accounts.ext_mint.mint_authority.unwrap_or_default() != ext_mint_authority {
         return err!(ExtError::InvalidMint);
     }
 
+    // sync updates the multiplier of a rebasing mint, and earn manager fees are
+    // moved out of earner balances, so it needs to be both of their authorities
+    if yield_mode == YieldMode::Rebasing
+        && (scaled_ui_amount_authority(&ctx.accounts.ext_mint) != Some(ext_mint_authority)
+            || permanent_delegate(&ctx.accounts.ext_mint) != Some(ext_mint_authority))
+    {
+        return err!(ExtError::InvalidMint);
+    }
+
     ctx.accounts.global_account.set_inner(ExtGlobal {
         admin: ctx.accounts.admin.key(),
         earn_authority,
-        ext_mint: ctx.accounts.ext_mint.key(),
+        ext_mint,
         m_mint: ctx.accounts.m_mint.key(),
         m_earn_global_account: ctx.accounts.m_earn_global_account.key(),
         index: ctx.accounts.m_earn_global_account.index,
@@ -74,6 +94,24 @@ pub fn handler(ctx: Context<Initialize>, earn_authority: Pubkey) -> Result<()> {
         bump: ctx.bumps.global_account,
         m_vault_bump,
         ext_mint_authority_bump,
+        version: EXT_GLOBAL_VERSION,
+        removal_bounty: 0,
+        legacy: false,
+        yield_recipient: Pubkey::default(),
+        excess_buffer_bps: 0,
+        yield_mode,
+        base_index: ctx.accounts.m_earn_global_account.index,
+        wrap_fee_bps: 0,
+        unwrap_fee_bps: 0,
+        fee_token_account: Pubkey::default(),
+        allowlist: Allowlist::Open,
+        wrapper_merkle_root: [0; 32],
+        earners: 0,
+        claimed_earners: 0,
+        legacy_earners: 0,
+        claims_timeout: 0,
+        m_index: ctx.accounts.m_earn_global_account.index,
+        reserved: [0; 24],
     });
 
     Ok(())
@@ -0,0 +1,54 @@
+// ext_earn/instructions/admin/migrate_ext_global.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+use earn::utils::migration::realloc_to_current;
+
+// local dependencies
+use crate::{
+    errors::ExtError,
+    state::{ExtGlobal, EXT_GLOBAL_VERSION},
+};
+
+#[derive(Accounts)]
+pub struct MigrateExtGlobal<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: The global account cannot be deserialized while it is on an older layout,
+    /// the owner, discriminator and admin are validated in the handler
+    #[account(mut)]
+    pub global_account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateExtGlobal>) -> Result<()> {
+    let global_info = ctx.accounts.global_account.to_account_info();
+
+    realloc_to_current::<ExtGlobal>(
+        &global_info,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    let mut global = ExtGlobal::try_deserialize(&mut &global_info.try_borrow_data()?[..])?;
+
+    if global.admin != ctx.accounts.admin.key() {
+        return err!(ExtError::NotAuthorized);
+    }
+
+    if global.version >= EXT_GLOBAL_VERSION {
+        return err!(ExtError::InvalidVersion);
+    }
+
+    // Version 2 grows the account for the wrap fee and allowlist fields, which no longer fit
+    // in the reserved space, and starts new reserved space. All new fields start at zero,
+    // which disables fees and leaves wrapping open.
+
+    global.version = EXT_GLOBAL_VERSION;
+
+    global.try_serialize(&mut &mut global_info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
@@ -0,0 +1,234 @@
+// ext_earn/instructions/admin/migrate_legacy_global.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+use earn::{
+    state::{
+        Earner as EarnEarner, Global as EarnGlobal, EARNER_SEED as EARN_EARNER_SEED,
+        GLOBAL_SEED as EARN_GLOBAL_SEED,
+    },
+    ID as EARN_PROGRAM,
+};
+
+// local dependencies
+use crate::{
+    constants::ANCHOR_DISCRIMINATOR_SIZE,
+    errors::ExtError,
+    state::{
+        ExtGlobal, Roles, EXT_GLOBAL_SEED, EXT_GLOBAL_VERSION, MINT_AUTHORITY_SEED, M_VAULT_SEED,
+        ROLES_SEED,
+    },
+    utils::token::{set_mint_authority, transfer_tokens_from_program},
+};
+
+// Moves the extension deployed before accounts were keyed by the extension mint
+// to the keyed layout. Earn managers and earners are moved separately afterwards,
+// `legacy_earners` is the number of legacy earner accounts left to migrate.
+#[derive(Accounts)]
+pub struct MigrateLegacyGlobal<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        close = admin,
+        has_one = admin @ ExtError::NotAuthorized,
+        has_one = m_mint @ ExtError::InvalidAccount,
+        has_one = ext_mint @ ExtError::InvalidAccount,
+        has_one = m_earn_global_account @ ExtError::InvalidAccount,
+        constraint = legacy_global_account.version == EXT_GLOBAL_VERSION @ ExtError::InvalidVersion,
+        seeds = [EXT_GLOBAL_SEED],
+        bump = legacy_global_account.bump,
+    )]
+    pub legacy_global_account: Account<'info, ExtGlobal>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = ANCHOR_DISCRIMINATOR_SIZE + ExtGlobal::INIT_SPACE,
+        seeds = [EXT_GLOBAL_SEED, ext_mint.key().as_ref()],
+        bump
+    )]
+    pub global_account: Account<'info, ExtGlobal>,
+
+    pub m_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub ext_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [EARN_GLOBAL_SEED],
+        seeds::program = EARN_PROGRAM,
+        bump = m_earn_global_account.bump,
+    )]
+    pub m_earn_global_account: Account<'info, EarnGlobal>,
+
+    /// CHECK: This account is validated by the seed, it stores no data
+    #[account(
+        seeds = [M_VAULT_SEED],
+        bump = legacy_global_account.m_vault_bump,
+    )]
+    pub legacy_m_vault: AccountInfo<'info>,
+
+    /// CHECK: This account is validated by the seed, it stores no data
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump = legacy_global_account.ext_mint_authority_bump,
+    )]
+    pub legacy_ext_mint_authority: AccountInfo<'info>,
+
+    /// CHECK: This account is validated by the seed, it stores no data
+    #[account(
+        seeds = [M_VAULT_SEED, ext_mint.key().as_ref()],
+        bump,
+    )]
+    pub m_vault: AccountInfo<'info>,
+
+    /// CHECK: This account is validated by the seed, it stores no data
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED, ext_mint.key().as_ref()],
+        bump,
+    )]
+    pub ext_mint_authority: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = m_mint,
+        associated_token::authority = legacy_m_vault,
+        associated_token::token_program = token_2022,
+    )]
+    pub legacy_vault_m_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = m_mint,
+        associated_token::authority = m_vault,
+        associated_token::token_program = token_2022,
+    )]
+    pub vault_m_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    // Yield claimed to the legacy vault after the migration would be stranded,
+    // so the legacy vault must have claimed up to the current M index
+    #[account(
+        constraint = legacy_vault_earner_account.last_claim_index == m_earn_global_account.index @ ExtError::InvalidAccount,
+        seeds = [EARN_EARNER_SEED, legacy_vault_m_token_account.key().as_ref()],
+        seeds::program = EARN_PROGRAM,
+        bump = legacy_vault_earner_account.bump,
+    )]
+    pub legacy_vault_earner_account: Account<'info, EarnEarner>,
+
+    // The new vault must already be an M earner so the extension keeps earning
+    #[account(
+        seeds = [EARN_EARNER_SEED, vault_m_token_account.key().as_ref()],
+        seeds::program = EARN_PROGRAM,
+        bump = vault_earner_account.bump,
+    )]
+    pub vault_earner_account: Account<'info, EarnEarner>,
+
+    // Role members are carried over to the roles account of the new layout
+    #[account(
+        mut,
+        close = admin,
+        seeds = [ROLES_SEED],
+        bump = legacy_roles_account.bump,
+    )]
+    pub legacy_roles_account: Option<Account<'info, Roles>>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = ANCHOR_DISCRIMINATOR_SIZE + Roles::INIT_SPACE,
+        seeds = [ROLES_SEED, ext_mint.key().as_ref()],
+        bump,
+    )]
+    pub roles_account: Option<Account<'info, Roles>>,
+
+    pub token_2022: Program<'info, Token2022>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateLegacyGlobal>, legacy_earners: u64) -> Result<()> {
+    let legacy = &ctx.accounts.legacy_global_account;
+
+    ctx.accounts.global_account.set_inner(ExtGlobal {
+        admin: legacy.admin,
+        earn_authority: legacy.earn_authority,
+        ext_mint: legacy.ext_mint,
+        m_mint: legacy.m_mint,
+        m_earn_global_account: legacy.m_earn_global_account,
+        index: legacy.index,
+        timestamp: legacy.timestamp,
+        bump: ctx.bumps.global_account,
+        m_vault_bump: ctx.bumps.m_vault,
+        ext_mint_authority_bump: ctx.bumps.ext_mint_authority,
+        version: EXT_GLOBAL_VERSION,
+        removal_bounty: legacy.removal_bounty,
+        legacy: true,
+        yield_recipient: legacy.yield_recipient,
+        excess_buffer_bps: legacy.excess_buffer_bps,
+        yield_mode: legacy.yield_mode,
+        base_index: legacy.base_index,
+        wrap_fee_bps: legacy.wrap_fee_bps,
+        unwrap_fee_bps: legacy.unwrap_fee_bps,
+        fee_token_account: legacy.fee_token_account,
+        allowlist: legacy.allowlist,
+        wrapper_merkle_root: legacy.wrapper_merkle_root,
+        earners: 0,
+        claimed_earners: 0,
+        legacy_earners,
+        claims_timeout: legacy.claims_timeout,
+        m_index: legacy.m_index,
+        reserved: [0; 24],
+    });
+
+    // Carry over the role members, the legacy roles account is closed
+    match (
+        &ctx.accounts.legacy_roles_account,
+        &mut ctx.accounts.roles_account,
+    ) {
+        (Some(legacy_roles), Some(roles)) => roles.set_inner(Roles {
+            earn_authorities: legacy_roles.earn_authorities.clone(),
+            earn_manager_admins: legacy_roles.earn_manager_admins.clone(),
+            bump: ctx.bumps.roles_account,
+        }),
+        (None, None) => {}
+        _ => return err!(ExtError::InvalidAccount),
+    }
+
+    // Move all collateral to the vault of the extension
+    let amount = ctx.accounts.legacy_vault_m_token_account.amount;
+
+    transfer_tokens_from_program(
+        &ctx.accounts.legacy_vault_m_token_account, // from
+        &ctx.accounts.vault_m_token_account,        // to
+        amount,                                     // amount
+        &ctx.accounts.m_mint,                       // mint
+        &ctx.accounts.legacy_m_vault,               // authority
+        &[&[M_VAULT_SEED, &[legacy.m_vault_bump]]], // authority seeds
+        &ctx.accounts.token_2022,                   // token program
+    )?;
+
+    // Hand over minting of the extension to its own mint authority
+    set_mint_authority(
+        &ctx.accounts.ext_mint,                                       // mint
+        &ctx.accounts.ext_mint_authority,                             // new authority
+        &ctx.accounts.legacy_ext_mint_authority,                      // authority
+        &[&[MINT_AUTHORITY_SEED, &[legacy.ext_mint_authority_bump]]], // authority seeds
+        &ctx.accounts.token_2022,                                     // token program
+    )?;
+
+    emit!(LegacyGlobalMigrated {
+        ext_mint: legacy.ext_mint,
+        collateral: amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct LegacyGlobalMigrated {
+    pub ext_mint: Pubkey,
+    pub collateral: u64,
+}
@@ -1,24 +1,64 @@
 // ext_earn/instructions/admin/mod.rs
 
 pub mod add_earn_manager;
+pub mod add_wrapper;
+pub mod grant_role;
 pub mod initialize;
+pub mod migrate_ext_global;
+pub mod migrate_legacy_global;
 pub mod remove_earn_manager;
+pub mod remove_wrapper;
+pub mod revoke_role;
+pub mod set_allowlist;
 pub mod set_earn_authority;
+pub mod set_removal_bounty;
+pub mod set_wrap_fees;
+pub mod set_yield_recipient;
 
 pub use add_earn_manager::AddEarnManager;
 pub(crate) use add_earn_manager::__client_accounts_add_earn_manager;
+pub use add_wrapper::AddWrapper;
+pub(crate) use add_wrapper::__client_accounts_add_wrapper;
+pub use grant_role::GrantRole;
+pub(crate) use grant_role::__client_accounts_grant_role;
 pub use initialize::Initialize;
 pub(crate) use initialize::__client_accounts_initialize;
+pub use migrate_ext_global::MigrateExtGlobal;
+pub(crate) use migrate_ext_global::__client_accounts_migrate_ext_global;
+pub use migrate_legacy_global::MigrateLegacyGlobal;
+pub(crate) use migrate_legacy_global::__client_accounts_migrate_legacy_global;
 pub use remove_earn_manager::RemoveEarnManager;
 pub(crate) use remove_earn_manager::__client_accounts_remove_earn_manager;
+pub use remove_wrapper::RemoveWrapper;
+pub(crate) use remove_wrapper::__client_accounts_remove_wrapper;
+pub use revoke_role::RevokeRole;
+pub(crate) use revoke_role::__client_accounts_revoke_role;
+pub use set_allowlist::SetAllowlist;
+pub(crate) use set_allowlist::__client_accounts_set_allowlist;
 pub use set_earn_authority::SetEarnAuthority;
 pub(crate) use set_earn_authority::__client_accounts_set_earn_authority;
+pub use set_removal_bounty::SetRemovalBounty;
+pub(crate) use set_removal_bounty::__client_accounts_set_removal_bounty;
+pub use set_wrap_fees::SetWrapFees;
+pub(crate) use set_wrap_fees::__client_accounts_set_wrap_fees;
+pub use set_yield_recipient::SetYieldRecipient;
+pub(crate) use set_yield_recipient::__client_accounts_set_yield_recipient;
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "cpi")] {
         pub(crate) use add_earn_manager::__cpi_client_accounts_add_earn_manager;
+        pub(crate) use add_wrapper::__cpi_client_accounts_add_wrapper;
+        pub(crate) use grant_role::__cpi_client_accounts_grant_role;
         pub(crate) use initialize::__cpi_client_accounts_initialize;
+        pub(crate) use migrate_ext_global::__cpi_client_accounts_migrate_ext_global;
+        pub(crate) use migrate_legacy_global::__cpi_client_accounts_migrate_legacy_global;
         pub(crate) use remove_earn_manager::__cpi_client_accounts_remove_earn_manager;
+        pub(crate) use remove_wrapper::__cpi_client_accounts_remove_wrapper;
+        pub(crate) use revoke_role::__cpi_client_accounts_revoke_role;
+        pub(crate) use set_allowlist::__cpi_client_accounts_set_allowlist;
         pub(crate) use set_earn_authority::__cpi_client_accounts_set_earn_authority;
+        pub(crate) use set_removal_bounty::__cpi_client_accounts_set_removal_bounty;
+        pub(crate) use set_wrap_fees::__cpi_client_accounts_set_wrap_fees;
+        pub(crate) use set_yield_recipient::__cpi_client_accounts_set_yield_recipient;
     }
 }
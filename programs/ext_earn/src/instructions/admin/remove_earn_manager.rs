@@ -4,7 +4,11 @@ use anchor_lang::prelude::*;
 
 use crate::{
     errors::ExtError,
-    state::{EarnManager, ExtGlobal, EARN_MANAGER_SEED, EXT_GLOBAL_SEED},
+    state::{
+        EarnManager, ExtGlobal, Roles, EARN_MANAGER_SEED, EARN_MANAGER_VERSION, EXT_GLOBAL_SEED,
+        EXT_GLOBAL_VERSION, ROLES_SEED,
+    },
+    utils::roles::is_earn_manager_admin,
 };
 
 #[derive(Accounts)]
@@ -12,18 +16,26 @@ pub struct RemoveEarnManager<'info> {
     pub admin: Signer<'info>,
 
     #[account(
-        seeds = [EXT_GLOBAL_SEED],
+        seeds = [EXT_GLOBAL_SEED, global_account.ext_mint.as_ref()],
         bump = global_account.bump,
-        has_one = admin @ ExtError::NotAuthorized,
+        constraint = is_earn_manager_admin(&global_account, &roles_account, &admin.key()) @ ExtError::NotAuthorized,
+        constraint = global_account.version == EXT_GLOBAL_VERSION @ ExtError::InvalidVersion,
     )]
     pub global_account: Account<'info, ExtGlobal>,
 
     #[account(
         mut,
-        seeds = [EARN_MANAGER_SEED, earn_manager_account.earn_manager.as_ref()],
+        constraint = earn_manager_account.version == EARN_MANAGER_VERSION @ ExtError::InvalidVersion,
+        seeds = [EARN_MANAGER_SEED, global_account.ext_mint.as_ref(), earn_manager_account.earn_manager.as_ref()],
         bump = earn_manager_account.bump,
     )]
     pub earn_manager_account: Account<'info, EarnManager>,
+
+    #[account(
+        seeds = [ROLES_SEED, global_account.ext_mint.as_ref()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Option<Account<'info, Roles>>,
 }
 
 pub fn handler(ctx: Context<RemoveEarnManager>) -> Result<()> {
@@ -31,5 +43,16 @@ pub fn handler(ctx: Context<RemoveEarnManager>) -> Result<()> {
     // with earner instructions which require the earn manager account
     ctx.accounts.earn_manager_account.is_active = false;
 
+    emit!(EarnManagerRemoved {
+        ext_mint: ctx.accounts.global_account.ext_mint,
+        earn_manager: ctx.accounts.earn_manager_account.earn_manager,
+    });
+
     Ok(())
 }
+
+#[event]
+pub struct EarnManagerRemoved {
+    pub ext_mint: Pubkey,
+    pub earn_manager: Pubkey,
+}
@@ -0,0 +1,47 @@
+// ext_earn/instructions/admin/remove_wrapper.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+
+// local dependencies
+use crate::{
+    errors::ExtError,
+    state::{ExtGlobal, Wrapper, EXT_GLOBAL_SEED, EXT_GLOBAL_VERSION, WRAPPER_SEED},
+};
+
+#[derive(Accounts)]
+pub struct RemoveWrapper<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        constraint = global_account.version == EXT_GLOBAL_VERSION @ ExtError::InvalidVersion,
+        seeds = [EXT_GLOBAL_SEED, global_account.ext_mint.as_ref()],
+        has_one = admin @ ExtError::NotAuthorized,
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, ExtGlobal>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [WRAPPER_SEED, global_account.ext_mint.as_ref(), wrapper_account.wrapper.as_ref()],
+        bump = wrapper_account.bump,
+    )]
+    pub wrapper_account: Account<'info, Wrapper>,
+}
+
+pub fn handler(ctx: Context<RemoveWrapper>) -> Result<()> {
+    emit!(WrapperRemoved {
+        ext_mint: ctx.accounts.global_account.ext_mint,
+        wrapper: ctx.accounts.wrapper_account.wrapper,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct WrapperRemoved {
+    pub ext_mint: Pubkey,
+    pub wrapper: Pubkey,
+}
@@ -0,0 +1,51 @@
+// ext_earn/instructions/admin/revoke_role.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+
+// local dependencies
+use crate::{
+    errors::ExtError,
+    state::{ExtGlobal, Role, Roles, EXT_GLOBAL_SEED, EXT_GLOBAL_VERSION, ROLES_SEED},
+    utils::roles::role_members,
+};
+
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        constraint = global_account.version == EXT_GLOBAL_VERSION @ ExtError::InvalidVersion,
+        seeds = [EXT_GLOBAL_SEED, global_account.ext_mint.as_ref()],
+        has_one = admin @ ExtError::NotAuthorized,
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, ExtGlobal>,
+
+    #[account(
+        mut,
+        seeds = [ROLES_SEED, global_account.ext_mint.as_ref()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Account<'info, Roles>,
+}
+
+pub fn handler(ctx: Context<RevokeRole>, role: Role, member: Pubkey) -> Result<()> {
+    let members = role_members(&mut ctx.accounts.roles_account, role);
+
+    let Some(position) = members.iter().position(|key| *key == member) else {
+        return err!(ExtError::InvalidParam);
+    };
+
+    members.remove(position);
+
+    emit!(RoleRevoked { role, member });
+
+    Ok(())
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub role: Role,
+    pub member: Pubkey,
+}
@@ -0,0 +1,53 @@
+// ext_earn/instructions/admin/set_allowlist.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+
+// local dependencies
+use crate::{
+    errors::ExtError,
+    state::{Allowlist, ExtGlobal, EXT_GLOBAL_SEED, EXT_GLOBAL_VERSION},
+};
+
+#[derive(Accounts)]
+pub struct SetAllowlist<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = global_account.version == EXT_GLOBAL_VERSION @ ExtError::InvalidVersion,
+        seeds = [EXT_GLOBAL_SEED, global_account.ext_mint.as_ref()],
+        has_one = admin @ ExtError::NotAuthorized,
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, ExtGlobal>,
+}
+
+pub fn handler(
+    ctx: Context<SetAllowlist>,
+    allowlist: Allowlist,
+    wrapper_merkle_root: [u8; 32],
+) -> Result<()> {
+    // The root is only used by the merkle root allowlist
+    if (allowlist == Allowlist::MerkleRoot) != (wrapper_merkle_root != [0; 32]) {
+        return err!(ExtError::InvalidParam);
+    }
+
+    ctx.accounts.global_account.allowlist = allowlist;
+    ctx.accounts.global_account.wrapper_merkle_root = wrapper_merkle_root;
+
+    emit!(AllowlistUpdate {
+        ext_mint: ctx.accounts.global_account.ext_mint,
+        allowlist,
+        wrapper_merkle_root,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AllowlistUpdate {
+    pub ext_mint: Pubkey,
+    pub allowlist: Allowlist,
+    pub wrapper_merkle_root: [u8; 32],
+}
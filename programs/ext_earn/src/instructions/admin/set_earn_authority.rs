@@ -6,7 +6,7 @@ use anchor_lang::prelude::*;
 // local dependencies
 use crate::{
     errors::ExtError,
-    state::{ExtGlobal, EXT_GLOBAL_SEED},
+    state::{ExtGlobal, EXT_GLOBAL_SEED, EXT_GLOBAL_VERSION},
 };
 
 #[derive(Accounts)]
@@ -15,7 +15,8 @@ pub struct SetEarnAuthority<'info> {
 
     #[account(
         mut,
-        seeds = [EXT_GLOBAL_SEED],
+        constraint = global_account.version == EXT_GLOBAL_VERSION @ ExtError::InvalidVersion,
+        seeds = [EXT_GLOBAL_SEED, global_account.ext_mint.as_ref()],
         has_one = admin @ ExtError::NotAuthorized,
         bump = global_account.bump,
     )]
@@ -0,0 +1,49 @@
+// ext_earn/instructions/admin/set_removal_bounty.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+
+// local dependencies
+use crate::{
+    constants::ANCHOR_DISCRIMINATOR_SIZE,
+    errors::ExtError,
+    state::{Earner, ExtGlobal, EXT_GLOBAL_SEED, EXT_GLOBAL_VERSION},
+};
+
+#[derive(Accounts)]
+pub struct SetRemovalBounty<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = global_account.version == EXT_GLOBAL_VERSION @ ExtError::InvalidVersion,
+        seeds = [EXT_GLOBAL_SEED, global_account.ext_mint.as_ref()],
+        has_one = admin @ ExtError::NotAuthorized,
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, ExtGlobal>,
+}
+
+pub fn handler(ctx: Context<SetRemovalBounty>, removal_bounty: u64) -> Result<()> {
+    // The bounty is paid out of the rent of the removed earner account
+    let earner_rent = Rent::get()?.minimum_balance(ANCHOR_DISCRIMINATOR_SIZE + Earner::INIT_SPACE);
+
+    if removal_bounty > earner_rent {
+        return err!(ExtError::InvalidParam);
+    }
+
+    ctx.accounts.global_account.removal_bounty = removal_bounty;
+
+    emit!(RemovalBountyUpdate {
+        ext_mint: ctx.accounts.global_account.ext_mint,
+        removal_bounty,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RemovalBountyUpdate {
+    pub ext_mint: Pubkey,
+    pub removal_bounty: u64,
+}
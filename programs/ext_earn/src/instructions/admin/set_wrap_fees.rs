@@ -0,0 +1,56 @@
+// ext_earn/instructions/admin/set_wrap_fees.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+// local dependencies
+use crate::{
+    constants::ONE_HUNDRED_PERCENT,
+    errors::ExtError,
+    state::{ExtGlobal, EXT_GLOBAL_SEED, EXT_GLOBAL_VERSION},
+};
+
+#[derive(Accounts)]
+pub struct SetWrapFees<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = global_account.version == EXT_GLOBAL_VERSION @ ExtError::InvalidVersion,
+        seeds = [EXT_GLOBAL_SEED, global_account.ext_mint.as_ref()],
+        has_one = admin @ ExtError::NotAuthorized,
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, ExtGlobal>,
+
+    #[account(token::mint = global_account.m_mint)]
+    pub fee_token_account: InterfaceAccount<'info, TokenAccount>,
+}
+
+pub fn handler(ctx: Context<SetWrapFees>, wrap_fee_bps: u64, unwrap_fee_bps: u64) -> Result<()> {
+    if wrap_fee_bps > ONE_HUNDRED_PERCENT || unwrap_fee_bps > ONE_HUNDRED_PERCENT {
+        return err!(ExtError::InvalidParam);
+    }
+
+    ctx.accounts.global_account.wrap_fee_bps = wrap_fee_bps;
+    ctx.accounts.global_account.unwrap_fee_bps = unwrap_fee_bps;
+    ctx.accounts.global_account.fee_token_account = ctx.accounts.fee_token_account.key();
+
+    emit!(WrapFeesUpdate {
+        ext_mint: ctx.accounts.global_account.ext_mint,
+        wrap_fee_bps,
+        unwrap_fee_bps,
+        fee_token_account: ctx.accounts.fee_token_account.key(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct WrapFeesUpdate {
+    pub ext_mint: Pubkey,
+    pub wrap_fee_bps: u64,
+    pub unwrap_fee_bps: u64,
+    pub fee_token_account: Pubkey,
+}
@@ -0,0 +1,63 @@
+// ext_earn/instructions/admin/set_yield_recipient.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+// local dependencies
+use crate::{
+    constants::ONE_HUNDRED_PERCENT,
+    errors::ExtError,
+    state::{ExtGlobal, EXT_GLOBAL_SEED, EXT_GLOBAL_VERSION},
+};
+
+#[derive(Accounts)]
+pub struct SetYieldRecipient<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = global_account.version == EXT_GLOBAL_VERSION @ ExtError::InvalidVersion,
+        seeds = [EXT_GLOBAL_SEED, global_account.ext_mint.as_ref()],
+        has_one = admin @ ExtError::NotAuthorized,
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, ExtGlobal>,
+
+    // Receives m if it is an m token account, or newly minted ext tokens otherwise
+    #[account(
+        constraint = yield_recipient.mint == global_account.m_mint || yield_recipient.mint == global_account.ext_mint @ ExtError::InvalidMint,
+    )]
+    pub yield_recipient: InterfaceAccount<'info, TokenAccount>,
+}
+
+pub fn handler(
+    ctx: Context<SetYieldRecipient>,
+    excess_buffer_bps: u64,
+    claims_timeout: u64,
+) -> Result<()> {
+    if excess_buffer_bps > ONE_HUNDRED_PERCENT {
+        return err!(ExtError::InvalidParam);
+    }
+
+    ctx.accounts.global_account.yield_recipient = ctx.accounts.yield_recipient.key();
+    ctx.accounts.global_account.excess_buffer_bps = excess_buffer_bps;
+    ctx.accounts.global_account.claims_timeout = claims_timeout;
+
+    emit!(YieldRecipientUpdate {
+        ext_mint: ctx.accounts.global_account.ext_mint,
+        yield_recipient: ctx.accounts.yield_recipient.key(),
+        excess_buffer_bps,
+        claims_timeout,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct YieldRecipientUpdate {
+    pub ext_mint: Pubkey,
+    pub yield_recipient: Pubkey,
+    pub excess_buffer_bps: u64,
+    pub claims_timeout: u64,
+}
@@ -0,0 +1,177 @@
+// ext_earn/instructions/earn_authority/claim_excess.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+use earn::state::Global as EarnGlobal;
+
+// local dependencies
+use crate::{
+    errors::ExtError,
+    state::{
+        ExtGlobal, Roles, YieldMode, EXT_GLOBAL_SEED, EXT_GLOBAL_VERSION, MINT_AUTHORITY_SEED,
+        M_VAULT_SEED, ROLES_SEED,
+    },
+    utils::{
+        collateral::{excess_to_mint, excess_to_transfer, required_collateral},
+        roles::is_earn_authority,
+        token::{mint_tokens, transfer_tokens_from_program},
+    },
+};
+
+#[derive(Accounts)]
+pub struct ClaimExcess<'info> {
+    pub earn_authority: Signer<'info>,
+
+    #[account(
+        has_one = m_mint @ ExtError::InvalidAccount,
+        has_one = ext_mint @ ExtError::InvalidAccount,
+        has_one = yield_recipient @ ExtError::InvalidAccount,
+        has_one = m_earn_global_account @ ExtError::InvalidAccount,
+        constraint = is_earn_authority(&global_account, &roles_account, &earn_authority.key()) @ ExtError::NotAuthorized,
+        constraint = global_account.version == EXT_GLOBAL_VERSION @ ExtError::InvalidVersion,
+        seeds = [EXT_GLOBAL_SEED, global_account.ext_mint.as_ref()],
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, ExtGlobal>,
+
+    pub m_earn_global_account: Account<'info, EarnGlobal>,
+
+    pub m_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub ext_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: This account is validated by the seed, it stores no data
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED, global_account.ext_mint.as_ref()],
+        bump = global_account.ext_mint_authority_bump,
+    )]
+    pub ext_mint_authority: AccountInfo<'info>,
+
+    /// CHECK: This account is validated by the seed, it stores no data
+    #[account(
+        seeds = [M_VAULT_SEED, global_account.ext_mint.as_ref()],
+        bump = global_account.m_vault_bump,
+    )]
+    pub m_vault_account: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = m_mint,
+        associated_token::authority = m_vault_account,
+        associated_token::token_program = token_2022,
+    )]
+    pub vault_m_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub yield_recipient: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_2022: Program<'info, Token2022>,
+
+    #[account(
+        seeds = [ROLES_SEED, global_account.ext_mint.as_ref()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Option<Account<'info, Roles>>,
+}
+
+pub fn handler(ctx: Context<ClaimExcess>) -> Result<()> {
+    let global = &ctx.accounts.global_account;
+    let buffer_bps = global.excess_buffer_bps;
+
+    // Yield received by the vault for an index that has not been synced is owed to holders
+    if !global.is_synced(ctx.accounts.m_earn_global_account.index) {
+        return err!(ExtError::IndexNotSynced);
+    }
+
+    // Yield owed to earners is only minted when they are claimed for, so it would be
+    // counted as excess until all earners have been claimed for the current index.
+    // Earners of a legacy extension are only counted once they have been migrated.
+    // Once the claims timeout has passed, unclaimed yield is no longer held back.
+    let now = Clock::get()?.unix_timestamp as u64;
+
+    if global.yield_mode == YieldMode::Claim && global.claims_pending(now) {
+        return err!(ExtError::ClaimsPending);
+    }
+
+    // The excess is computed in m, a rebasing supply is worth more m than its amount
+    let collateral = ctx.accounts.vault_m_token_account.amount;
+    let supply = global.collateral_for(ctx.accounts.ext_mint.supply);
+    let mint_ext = ctx.accounts.yield_recipient.mint == global.ext_mint;
+
+    let amount = if mint_ext {
+        global.m_to_ext(excess_to_mint(collateral, supply, buffer_bps))
+    } else {
+        excess_to_transfer(collateral, supply, buffer_bps)
+    };
+
+    if amount == 0 {
+        return err!(ExtError::InsufficientCollateral);
+    }
+
+    if mint_ext {
+        mint_tokens(
+            &ctx.accounts.yield_recipient,    // to
+            amount,                           // amount
+            &ctx.accounts.ext_mint,           // mint
+            &ctx.accounts.ext_mint_authority, // authority
+            &[&[
+                MINT_AUTHORITY_SEED,
+                global.ext_mint.as_ref(),
+                &[global.ext_mint_authority_bump],
+            ]], // authority seeds
+            &ctx.accounts.token_2022,         // token program
+        )?;
+    } else {
+        transfer_tokens_from_program(
+            &ctx.accounts.vault_m_token_account, // from
+            &ctx.accounts.yield_recipient,       // to
+            amount,                              // amount
+            &ctx.accounts.m_mint,                // mint
+            &ctx.accounts.m_vault_account,       // authority
+            &[&[
+                M_VAULT_SEED,
+                global.ext_mint.as_ref(),
+                &[global.m_vault_bump],
+            ]], // authority seeds
+            &ctx.accounts.token_2022,            // token program
+        )?;
+    }
+
+    // Check the vault against the balances after the CPI, not the computed amounts
+    ctx.accounts.vault_m_token_account.reload()?;
+    ctx.accounts.ext_mint.reload()?;
+
+    let collateral = ctx.accounts.vault_m_token_account.amount;
+    let supply = ctx.accounts.ext_mint.supply;
+    let required = required_collateral(
+        ctx.accounts.global_account.collateral_for(supply),
+        buffer_bps,
+    );
+
+    if (collateral as u128) < required {
+        return err!(ExtError::InsufficientCollateral);
+    }
+
+    emit!(ExcessClaimed {
+        ext_mint: ctx.accounts.global_account.ext_mint,
+        recipient: ctx.accounts.yield_recipient.key(),
+        amount,
+        minted: mint_ext,
+        collateral,
+        supply,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ExcessClaimed {
+    pub ext_mint: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub minted: bool,    // minted as ext tokens rather than transferred as m
+    pub collateral: u64, // vault balance after the claim
+    pub supply: u64,     // ext supply after the claim
+}
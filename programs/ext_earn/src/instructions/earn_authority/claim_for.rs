@@ -10,10 +10,15 @@ use crate::{
     constants::ONE_HUNDRED_PERCENT,
     errors::ExtError,
     state::{
-        EarnManager, Earner, ExtGlobal, EARNER_SEED, EARN_MANAGER_SEED, EXT_GLOBAL_SEED,
-        MINT_AUTHORITY_SEED, M_VAULT_SEED,
+        EarnManager, Earner, ExtGlobal, Roles, YieldMode, EARNER_SEED, EARNER_VERSION,
+        EARN_MANAGER_SEED, EARN_MANAGER_VERSION, EXT_GLOBAL_SEED, EXT_GLOBAL_VERSION,
+        MINT_AUTHORITY_SEED, M_VAULT_SEED, ROLES_SEED,
+    },
+    utils::{
+        rewards::{record_claim, settle_claim},
+        roles::is_earn_authority,
+        token::mint_tokens,
     },
-    utils::token::mint_tokens,
 };
 
 #[derive(Accounts)]
@@ -23,8 +28,10 @@ pub struct ClaimFor<'info> {
     #[account(
         mut,
         has_one = ext_mint @ ExtError::InvalidAccount,
-        has_one = earn_authority @ ExtError::NotAuthorized,
-        seeds = [EXT_GLOBAL_SEED],
+        constraint = is_earn_authority(&global_account, &roles_account, &earn_authority.key()) @ ExtError::NotAuthorized,
+        constraint = global_account.version == EXT_GLOBAL_VERSION @ ExtError::InvalidVersion,
+        constraint = global_account.yield_mode == YieldMode::Claim @ ExtError::InvalidYieldMode,
+        seeds = [EXT_GLOBAL_SEED, global_account.ext_mint.as_ref()],
         bump = global_account.bump,
     )]
     pub global_account: Account<'info, ExtGlobal>,
@@ -34,14 +41,14 @@ pub struct ClaimFor<'info> {
 
     /// CHECK: This account is validated by the seed, it stores no data
     #[account(
-        seeds = [MINT_AUTHORITY_SEED],
+        seeds = [MINT_AUTHORITY_SEED, global_account.ext_mint.as_ref()],
         bump = global_account.ext_mint_authority_bump,
     )]
     pub ext_mint_authority: AccountInfo<'info>,
 
     /// CHECK: This account is validated by the seed, it stores no data
     #[account(
-        seeds = [M_VAULT_SEED],
+        seeds = [M_VAULT_SEED, global_account.ext_mint.as_ref()],
         bump = global_account.m_vault_bump,
     )]
     pub m_vault_account: AccountInfo<'info>,
@@ -64,13 +71,15 @@ pub struct ClaimFor<'info> {
 
     #[account(
         mut,
-        seeds = [EARNER_SEED, earner_account.user_token_account.as_ref()],
+        constraint = earner_account.version == EARNER_VERSION @ ExtError::InvalidVersion,
+        seeds = [EARNER_SEED, global_account.ext_mint.as_ref(), earner_account.user_token_account.as_ref()],
         bump = earner_account.bump,
     )]
     pub earner_account: Account<'info, Earner>,
 
     #[account(
-        seeds = [EARN_MANAGER_SEED, earner_account.earn_manager.as_ref()],
+        constraint = earn_manager_account.version == EARN_MANAGER_VERSION @ ExtError::InvalidVersion,
+        seeds = [EARN_MANAGER_SEED, global_account.ext_mint.as_ref(), earner_account.earn_manager.as_ref()],
         bump = earn_manager_account.bump,
     )]
     pub earn_manager_account: Account<'info, EarnManager>,
@@ -85,51 +94,48 @@ pub struct ClaimFor<'info> {
     pub earn_manager_token_account: AccountInfo<'info>,
 
     pub token_2022: Program<'info, Token2022>,
+
+    #[account(
+        seeds = [ROLES_SEED, global_account.ext_mint.as_ref()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Option<Account<'info, Roles>>,
 }
 
 pub fn handler(ctx: Context<ClaimFor>, snapshot_balance: u64) -> Result<()> {
-    // Validate that the earner account has not already claimed this cycle
-    // Earner index should never be > global index, but we check to be safe against an error with index propagation
-    if ctx.accounts.earner_account.last_claim_index >= ctx.accounts.global_account.index {
-        return err!(ExtError::AlreadyClaimed);
-    }
-
-    // Calculate the amount of tokens to send to the user
-    // Cast to u128 for multiplication to avoid overflows
-    let mut rewards: u64 = (snapshot_balance as u128)
-        .checked_mul(ctx.accounts.global_account.index.into())
-        .unwrap()
-        .checked_div(ctx.accounts.earner_account.last_claim_index.into())
-        .unwrap()
-        .try_into()
-        .unwrap();
-
-    rewards -= snapshot_balance; // can't underflow because global index > last claim index
-
-    // Validate that the newly minted rewards will not make the extension undercollateralized
-    let ext_supply = ctx.accounts.ext_mint.supply;
-    let ext_collateral = ctx.accounts.vault_m_token_account.amount;
-
-    if ext_supply + rewards > ext_collateral {
-        return err!(ExtError::InsufficientCollateral);
-    }
-
-    // Set the earner's last claim index to the global index and update the last claim timestamp
-    ctx.accounts.earner_account.last_claim_index = ctx.accounts.global_account.index;
-    ctx.accounts.earner_account.last_claim_timestamp = ctx.accounts.global_account.timestamp;
+    // Validate the claim and advance the earner to the current index
+    let mut rewards = settle_claim(
+        &mut ctx.accounts.global_account,
+        &mut ctx.accounts.earner_account,
+        snapshot_balance,
+        ctx.accounts.ext_mint.supply,
+        ctx.accounts.vault_m_token_account.amount,
+    )?;
 
     // Setup the signer seeds for the mint CPI(s)
     let mint_authority_seeds: &[&[&[u8]]] = &[&[
         MINT_AUTHORITY_SEED,
+        ctx.accounts.global_account.ext_mint.as_ref(),
         &[ctx.accounts.global_account.ext_mint_authority_bump],
     ]];
 
     // Calculate the earn manager fee if applicable and subtract from the earner's rewards
     // If the earn manager is not active, then no fee is taken
-    let fee = handle_fee(&ctx, rewards, mint_authority_seeds)?;
+    let fee = handle_fee(
+        &ctx.accounts.earn_manager_account,
+        &ctx.accounts.earn_manager_token_account,
+        &ctx.accounts.ext_mint,
+        &ctx.accounts.ext_mint_authority,
+        &ctx.accounts.token_2022,
+        rewards,
+        mint_authority_seeds,
+    )?;
 
     rewards -= fee;
 
+    // Update the earner's lifetime stats
+    record_claim(&mut ctx.accounts.earner_account, rewards, fee);
+
     // Mint the tokens to the user's token aaccount
     mint_tokens(
         &ctx.accounts.user_token_account, // to
@@ -152,30 +158,18 @@ pub fn handler(ctx: Context<ClaimFor>, snapshot_balance: u64) -> Result<()> {
     Ok(())
 }
 
-fn handle_fee(
-    ctx: &Context<ClaimFor>,
+pub(crate) fn handle_fee<'info>(
+    earn_manager_account: &EarnManager,
+    earn_manager_token_account: &AccountInfo<'info>,
+    ext_mint: &InterfaceAccount<'info, Mint>,
+    ext_mint_authority: &AccountInfo<'info>,
+    token_2022: &Program<'info, Token2022>,
     rewards: u64,
     mint_authority_seeds: &[&[&[u8]]],
 ) -> Result<u64> {
-    // Calculate the earn manager fee if applicable and subtract from the earner's rewards
-    // If the earn manager doesn't charge a fee or is not active, then no fee is taken
-    if ctx.accounts.earn_manager_account.fee_bps == 0
-        || !ctx.accounts.earn_manager_account.is_active
-    {
-        return Ok(0);
-    }
+    let fee = calculate_fee(earn_manager_account, earn_manager_token_account, rewards);
 
-    // If the earn manager token account is not initialized, then no fee is taken
-    if ctx.accounts.earn_manager_token_account.owner != &Token2022::id()
-        || ctx.accounts.earn_manager_token_account.lamports() == 0
-    {
-        return Ok(0);
-    }
-
-    // Fees are rounded down in favor of the user
-    let fee = (rewards * ctx.accounts.earn_manager_account.fee_bps) / ONE_HUNDRED_PERCENT;
-
-    // Return early if the fee rounds to zero
+    // Return early if no fee is taken
     if fee == 0 {
         return Ok(0);
     }
@@ -183,13 +177,13 @@ fn handle_fee(
     // mint tokens to the earn manager token account
     // we don't use the helper function due to lifetime issues
     let mint_options = MintTo {
-        mint: ctx.accounts.ext_mint.to_account_info(),
-        to: ctx.accounts.earn_manager_token_account.clone(),
-        authority: ctx.accounts.ext_mint_authority.clone(),
+        mint: ext_mint.to_account_info(),
+        to: earn_manager_token_account.clone(),
+        authority: ext_mint_authority.clone(),
     };
 
     let cpi_context = CpiContext::new_with_signer(
-        ctx.accounts.token_2022.to_account_info(),
+        token_2022.to_account_info(),
         mint_options,
         mint_authority_seeds,
     );
@@ -198,3 +192,25 @@ fn handle_fee(
 
     Ok(fee)
 }
+
+pub(crate) fn calculate_fee(
+    earn_manager_account: &EarnManager,
+    earn_manager_token_account: &AccountInfo,
+    rewards: u64,
+) -> u64 {
+    // Calculate the earn manager fee if applicable and subtract from the earner's rewards
+    // If the earn manager doesn't charge a fee or is not active, then no fee is taken
+    if earn_manager_account.fee_bps == 0 || !earn_manager_account.is_active {
+        return 0;
+    }
+
+    // If the earn manager token account is not initialized, then no fee is taken
+    if earn_manager_token_account.owner != &Token2022::id()
+        || earn_manager_token_account.lamports() == 0
+    {
+        return 0;
+    }
+
+    // Fees are rounded down in favor of the user
+    (rewards * earn_manager_account.fee_bps) / ONE_HUNDRED_PERCENT
+}
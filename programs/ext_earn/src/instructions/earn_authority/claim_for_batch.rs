@@ -0,0 +1,193 @@
+// ext_earn/instructions/earn_authority/claim_for_batch.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+use earn::instructions::claim_for::RewardsClaim;
+
+// local dependencies
+use super::claim_for::handle_fee;
+use crate::{
+    errors::ExtError,
+    state::{
+        EarnManager, Earner, ExtGlobal, Roles, YieldMode, EARNER_SEED, EARNER_VERSION,
+        EARN_MANAGER_SEED, EARN_MANAGER_VERSION, EXT_GLOBAL_SEED, EXT_GLOBAL_VERSION,
+        MINT_AUTHORITY_SEED, M_VAULT_SEED, ROLES_SEED,
+    },
+    utils::{
+        rewards::{record_claim, settle_claim},
+        roles::is_earn_authority,
+        token::mint_tokens,
+    },
+};
+
+#[derive(Accounts)]
+pub struct ClaimForBatch<'info> {
+    pub earn_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = ext_mint @ ExtError::InvalidAccount,
+        constraint = is_earn_authority(&global_account, &roles_account, &earn_authority.key()) @ ExtError::NotAuthorized,
+        constraint = global_account.version == EXT_GLOBAL_VERSION @ ExtError::InvalidVersion,
+        constraint = global_account.yield_mode == YieldMode::Claim @ ExtError::InvalidYieldMode,
+        seeds = [EXT_GLOBAL_SEED, global_account.ext_mint.as_ref()],
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, ExtGlobal>,
+
+    #[account(mut)]
+    pub ext_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: This account is validated by the seed, it stores no data
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED, global_account.ext_mint.as_ref()],
+        bump = global_account.ext_mint_authority_bump,
+    )]
+    pub ext_mint_authority: AccountInfo<'info>,
+
+    /// CHECK: This account is validated by the seed, it stores no data
+    #[account(
+        seeds = [M_VAULT_SEED, global_account.ext_mint.as_ref()],
+        bump = global_account.m_vault_bump,
+    )]
+    pub m_vault_account: AccountInfo<'info>,
+
+    #[account(
+        associated_token::mint = global_account.m_mint,
+        associated_token::authority = m_vault_account,
+        associated_token::token_program = token_2022,
+    )]
+    pub vault_m_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_2022: Program<'info, Token2022>,
+
+    #[account(
+        seeds = [ROLES_SEED, global_account.ext_mint.as_ref()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Option<Account<'info, Roles>>,
+    // remaining accounts: (earner_account, user_token_account, earn_manager_account, earn_manager_token_account)
+    // for each snapshot balance, earners in a batch can have different earn managers
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimForBatch<'info>>,
+    snapshot_balances: Vec<u64>,
+) -> Result<()> {
+    // Each snapshot balance requires an earner account, its (recipient) token account,
+    // and the earn manager account and fee token account of the earner
+    if ctx.remaining_accounts.len() != snapshot_balances.len() * 4 {
+        return err!(ExtError::InvalidParam);
+    }
+
+    let ext_mint = ctx.accounts.global_account.ext_mint;
+    let index = ctx.accounts.global_account.index;
+    let timestamp = ctx.accounts.global_account.timestamp;
+
+    // Track everything minted in the batch so the collateral check is cumulative
+    let ext_collateral = ctx.accounts.vault_m_token_account.amount;
+    let mut ext_supply = ctx.accounts.ext_mint.supply;
+
+    let mint_authority_seeds: &[&[&[u8]]] = &[&[
+        MINT_AUTHORITY_SEED,
+        ext_mint.as_ref(),
+        &[ctx.accounts.global_account.ext_mint_authority_bump],
+    ]];
+
+    for (accounts, snapshot_balance) in ctx.remaining_accounts.chunks(4).zip(snapshot_balances) {
+        let mut earner_account = Account::<Earner>::try_from(&accounts[0])?;
+        let user_token_account = InterfaceAccount::<TokenAccount>::try_from(&accounts[1])?;
+        let earn_manager_account = Account::<EarnManager>::try_from(&accounts[2])?;
+        let earn_manager_token_account = &accounts[3];
+
+        // Validate the earner PDA, its earn manager, and the token accounts receiving yield and fees
+        let expected_earner = Pubkey::create_program_address(
+            &[
+                EARNER_SEED,
+                ext_mint.as_ref(),
+                earner_account.user_token_account.as_ref(),
+                &[earner_account.bump],
+            ],
+            ctx.program_id,
+        )
+        .map_err(|_| ExtError::InvalidAccount)?;
+
+        let expected_earn_manager = Pubkey::create_program_address(
+            &[
+                EARN_MANAGER_SEED,
+                ext_mint.as_ref(),
+                earner_account.earn_manager.as_ref(),
+                &[earn_manager_account.bump],
+            ],
+            ctx.program_id,
+        )
+        .map_err(|_| ExtError::InvalidAccount)?;
+
+        let expected_recipient = match earner_account.recipient_token_account {
+            Some(token_account) => token_account,
+            None => earner_account.user_token_account,
+        };
+
+        if earner_account.version != EARNER_VERSION
+            || earn_manager_account.version != EARN_MANAGER_VERSION
+        {
+            return err!(ExtError::InvalidVersion);
+        }
+
+        if earner_account.key() != expected_earner
+            || earn_manager_account.key() != expected_earn_manager
+            || user_token_account.key() != expected_recipient
+            || earn_manager_token_account.key() != earn_manager_account.fee_token_account
+        {
+            return err!(ExtError::InvalidAccount);
+        }
+
+        // Validate the claim and advance the earner to the current index
+        let mut rewards = settle_claim(
+            &mut ctx.accounts.global_account,
+            &mut earner_account,
+            snapshot_balance,
+            ext_supply,
+            ext_collateral,
+        )?;
+
+        ext_supply += rewards;
+
+        let fee = handle_fee(
+            &earn_manager_account,
+            earn_manager_token_account,
+            &ctx.accounts.ext_mint,
+            &ctx.accounts.ext_mint_authority,
+            &ctx.accounts.token_2022,
+            rewards,
+            mint_authority_seeds,
+        )?;
+
+        rewards -= fee;
+
+        // Persist the updates so a duplicate entry in the batch fails as already claimed
+        record_claim(&mut earner_account, rewards, fee);
+        earner_account.exit(ctx.program_id)?;
+
+        mint_tokens(
+            &user_token_account,              // to
+            rewards,                          // amount
+            &ctx.accounts.ext_mint,           // mint
+            &ctx.accounts.ext_mint_authority, // authority
+            mint_authority_seeds,             // authority seeds
+            &ctx.accounts.token_2022,         // token program
+        )?;
+
+        emit!(RewardsClaim {
+            token_account: earner_account.user_token_account,
+            recipient_token_account: user_token_account.key(),
+            amount: rewards,
+            fee,
+            ts: timestamp,
+            index,
+        });
+    }
+
+    Ok(())
+}
@@ -1,16 +1,28 @@
 // ext_earn/instructions/earn_authority/mod.rs
 
+pub mod claim_excess;
 pub mod claim_for;
+pub mod claim_for_batch;
+pub mod settle_fee;
 pub mod sync;
 
+pub use claim_excess::ClaimExcess;
+pub(crate) use claim_excess::__client_accounts_claim_excess;
 pub use claim_for::ClaimFor;
 pub(crate) use claim_for::__client_accounts_claim_for;
+pub use claim_for_batch::ClaimForBatch;
+pub(crate) use claim_for_batch::__client_accounts_claim_for_batch;
+pub use settle_fee::SettleFee;
+pub(crate) use settle_fee::__client_accounts_settle_fee;
 pub use sync::Sync;
 pub(crate) use sync::__client_accounts_sync;
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "cpi")] {
+        pub(crate) use claim_excess::__cpi_client_accounts_claim_excess;
         pub(crate) use claim_for::__cpi_client_accounts_claim_for;
+        pub(crate) use claim_for_batch::__cpi_client_accounts_claim_for_batch;
+        pub(crate) use settle_fee::__cpi_client_accounts_settle_fee;
         pub(crate) use sync::__cpi_client_accounts_sync;
     }
 }
@@ -0,0 +1,141 @@
+// ext_earn/instructions/earn_authority/settle_fee.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, Token2022, TokenAccount, TransferChecked,
+};
+use earn::instructions::claim_for::RewardsClaim;
+
+// local dependencies
+use super::claim_for::calculate_fee;
+use crate::{
+    errors::ExtError,
+    state::{
+        EarnManager, Earner, ExtGlobal, Roles, YieldMode, EARNER_SEED, EARNER_VERSION,
+        EARN_MANAGER_SEED, EARN_MANAGER_VERSION, EXT_GLOBAL_SEED, EXT_GLOBAL_VERSION,
+        MINT_AUTHORITY_SEED, ROLES_SEED,
+    },
+    utils::{
+        rewards::{record_claim, settle_rebasing_claim},
+        roles::is_earn_authority,
+    },
+};
+
+// Earner yield accrues to every holder of a rebasing extension, so the earn manager
+// fee is moved out of the earner's balance by the mint authority as permanent delegate
+#[derive(Accounts)]
+pub struct SettleFee<'info> {
+    pub earn_authority: Signer<'info>,
+
+    #[account(
+        has_one = ext_mint @ ExtError::InvalidAccount,
+        constraint = is_earn_authority(&global_account, &roles_account, &earn_authority.key()) @ ExtError::NotAuthorized,
+        constraint = global_account.version == EXT_GLOBAL_VERSION @ ExtError::InvalidVersion,
+        constraint = global_account.yield_mode == YieldMode::Rebasing @ ExtError::InvalidYieldMode,
+        seeds = [EXT_GLOBAL_SEED, global_account.ext_mint.as_ref()],
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, ExtGlobal>,
+
+    pub ext_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: This account is validated by the seed, it stores no data
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED, global_account.ext_mint.as_ref()],
+        bump = global_account.ext_mint_authority_bump,
+    )]
+    pub ext_mint_authority: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        address = earner_account.user_token_account @ ExtError::InvalidAccount,
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = earner_account.version == EARNER_VERSION @ ExtError::InvalidVersion,
+        seeds = [EARNER_SEED, global_account.ext_mint.as_ref(), earner_account.user_token_account.as_ref()],
+        bump = earner_account.bump,
+    )]
+    pub earner_account: Account<'info, Earner>,
+
+    #[account(
+        constraint = earn_manager_account.version == EARN_MANAGER_VERSION @ ExtError::InvalidVersion,
+        seeds = [EARN_MANAGER_SEED, global_account.ext_mint.as_ref(), earner_account.earn_manager.as_ref()],
+        bump = earn_manager_account.bump,
+    )]
+    pub earn_manager_account: Account<'info, EarnManager>,
+
+    /// CHECK: we validate this manually in the handler so we can skip it
+    /// if the token account has been closed or is not initialized
+    #[account(
+        mut,
+        address = earn_manager_account.fee_token_account @ ExtError::InvalidAccount,
+    )]
+    pub earn_manager_token_account: AccountInfo<'info>,
+
+    pub token_2022: Program<'info, Token2022>,
+
+    #[account(
+        seeds = [ROLES_SEED, global_account.ext_mint.as_ref()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Option<Account<'info, Roles>>,
+}
+
+pub fn handler(ctx: Context<SettleFee>, snapshot_balance: u64) -> Result<()> {
+    // Validate the settlement and advance the earner to the current index
+    let rewards = settle_rebasing_claim(
+        &ctx.accounts.global_account,
+        &mut ctx.accounts.earner_account,
+        snapshot_balance,
+    )?;
+
+    // The fee can't be more than what is left in the earner's token account
+    let fee = calculate_fee(
+        &ctx.accounts.earn_manager_account,
+        &ctx.accounts.earn_manager_token_account,
+        rewards,
+    )
+    .min(ctx.accounts.user_token_account.amount);
+
+    // Update the earner's lifetime stats, the rest of the yield has already accrued to the earner
+    record_claim(&mut ctx.accounts.earner_account, rewards - fee, fee);
+
+    if fee > 0 {
+        let mint_authority_seeds: &[&[&[u8]]] = &[&[
+            MINT_AUTHORITY_SEED,
+            ctx.accounts.global_account.ext_mint.as_ref(),
+            &[ctx.accounts.global_account.ext_mint_authority_bump],
+        ]];
+
+        // we don't use the helper function as the fee token account is not deserialized
+        let transfer_options = TransferChecked {
+            from: ctx.accounts.user_token_account.to_account_info(),
+            mint: ctx.accounts.ext_mint.to_account_info(),
+            to: ctx.accounts.earn_manager_token_account.clone(),
+            authority: ctx.accounts.ext_mint_authority.clone(),
+        };
+
+        let cpi_context = CpiContext::new_with_signer(
+            ctx.accounts.token_2022.to_account_info(),
+            transfer_options,
+            mint_authority_seeds,
+        );
+
+        transfer_checked(cpi_context, fee, ctx.accounts.ext_mint.decimals)?;
+    }
+
+    emit!(RewardsClaim {
+        token_account: ctx.accounts.earner_account.user_token_account,
+        recipient_token_account: ctx.accounts.user_token_account.key(),
+        amount: rewards - fee,
+        fee,
+        ts: ctx.accounts.earner_account.last_claim_timestamp,
+        index: ctx.accounts.earner_account.last_claim_index,
+    });
+
+    Ok(())
+}
@@ -1,10 +1,19 @@
 // wrapped-m/instructions/earn_authority/sync.rs
 
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
 
 use crate::{
     errors::ExtError,
-    state::{ExtGlobal, EXT_GLOBAL_SEED},
+    state::{
+        ExtGlobal, Roles, YieldMode, EXT_GLOBAL_SEED, EXT_GLOBAL_VERSION, MINT_AUTHORITY_SEED,
+        M_VAULT_SEED, ROLES_SEED,
+    },
+    utils::{
+        rate::{backed_index, multiplier},
+        roles::is_earn_authority,
+        token::update_multiplier,
+    },
 };
 use earn::state::Global as EarnGlobal;
 
@@ -16,22 +25,96 @@ pub struct Sync<'info> {
 
     #[account(
         mut,
-        seeds = [EXT_GLOBAL_SEED],
+        seeds = [EXT_GLOBAL_SEED, global_account.ext_mint.as_ref()],
         bump = global_account.bump,
-        has_one = earn_authority @ ExtError::NotAuthorized,
+        constraint = is_earn_authority(&global_account, &roles_account, &earn_authority.key()) @ ExtError::NotAuthorized,
+        constraint = global_account.version == EXT_GLOBAL_VERSION @ ExtError::InvalidVersion,
         has_one = m_earn_global_account @ ExtError::InvalidAccount,
+        has_one = ext_mint @ ExtError::InvalidAccount,
     )]
     pub global_account: Account<'info, ExtGlobal>,
+
+    #[account(mut)]
+    pub ext_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: This account is validated by the seed, it stores no data
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED, global_account.ext_mint.as_ref()],
+        bump = global_account.ext_mint_authority_bump,
+    )]
+    pub ext_mint_authority: AccountInfo<'info>,
+
+    /// CHECK: This account is validated by the seed, it stores no data
+    #[account(
+        seeds = [M_VAULT_SEED, global_account.ext_mint.as_ref()],
+        bump = global_account.m_vault_bump,
+    )]
+    pub m_vault_account: AccountInfo<'info>,
+
+    #[account(
+        associated_token::mint = global_account.m_mint,
+        associated_token::authority = m_vault_account,
+        associated_token::token_program = token_2022,
+    )]
+    pub vault_m_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_2022: Program<'info, Token2022>,
+
+    #[account(
+        seeds = [ROLES_SEED, global_account.ext_mint.as_ref()],
+        bump = roles_account.bump,
+    )]
+    pub roles_account: Option<Account<'info, Roles>>,
 }
 
 pub fn handler(ctx: Context<Sync>) -> Result<()> {
+    let previous_index = ctx.accounts.global_account.index;
+    let m_index = ctx.accounts.m_earn_global_account.index;
+
+    let index = match ctx.accounts.global_account.yield_mode {
+        YieldMode::Claim => m_index,
+        // Holders can unwrap at the new index right away, so it is capped at what the vault
+        // can back. The vault earns less than the m index on m wrapped during the cycle and
+        // only receives its yield once it has been claimed for on the earn program.
+        YieldMode::Rebasing => backed_index(
+            ctx.accounts.vault_m_token_account.amount,
+            ctx.accounts.ext_mint.supply,
+            ctx.accounts.global_account.base_index,
+        )
+        .clamp(previous_index.min(m_index), m_index),
+    };
+
     // Update the local data
-    ctx.accounts.global_account.index = ctx.accounts.m_earn_global_account.index;
-    ctx.accounts.global_account.timestamp = ctx.accounts.m_earn_global_account.timestamp;
+    let global = &mut ctx.accounts.global_account;
+    global.index = index;
+    global.m_index = m_index;
+    global.timestamp = ctx.accounts.m_earn_global_account.timestamp;
+
+    // Every earner is owed yield for a new index until it is claimed for
+    if index > previous_index {
+        global.claimed_earners = 0;
+    }
+
+    let global = &ctx.accounts.global_account;
+
+    if global.yield_mode == YieldMode::Rebasing && index != previous_index {
+        update_multiplier(
+            &ctx.accounts.ext_mint,               // mint
+            multiplier(index, global.base_index), // multiplier
+            Clock::get()?.unix_timestamp,         // effective timestamp
+            &ctx.accounts.ext_mint_authority,     // authority
+            &[&[
+                MINT_AUTHORITY_SEED,
+                global.ext_mint.as_ref(),
+                &[global.ext_mint_authority_bump],
+            ]], // authority seeds
+            &ctx.accounts.token_2022,             // token program
+        )?;
+    }
 
     emit!(SyncIndexUpdate {
-        index: ctx.accounts.global_account.index,
-        ts: ctx.accounts.global_account.timestamp,
+        index: global.index,
+        ts: global.timestamp,
     });
 
     Ok(())
@@ -8,7 +8,10 @@ use anchor_spl::token_interface::TokenAccount;
 use crate::{
     constants::ANCHOR_DISCRIMINATOR_SIZE,
     errors::ExtError,
-    state::{EarnManager, Earner, ExtGlobal, EARNER_SEED, EARN_MANAGER_SEED, EXT_GLOBAL_SEED},
+    state::{
+        EarnManager, Earner, ExtGlobal, EARNER_SEED, EARNER_VERSION, EARN_MANAGER_SEED,
+        EARN_MANAGER_VERSION, EXT_GLOBAL_SEED, EXT_GLOBAL_VERSION,
+    },
 };
 
 #[derive(Accounts)]
@@ -19,13 +22,16 @@ pub struct AddEarner<'info> {
 
     #[account(
         constraint = earn_manager_account.is_active @ ExtError::NotActive,
-        seeds = [EARN_MANAGER_SEED, signer.key().as_ref()],
+        constraint = earn_manager_account.version == EARN_MANAGER_VERSION @ ExtError::InvalidVersion,
+        seeds = [EARN_MANAGER_SEED, global_account.ext_mint.as_ref(), signer.key().as_ref()],
         bump = earn_manager_account.bump
     )]
     pub earn_manager_account: Account<'info, EarnManager>,
 
     #[account(
-        seeds = [EXT_GLOBAL_SEED],
+        mut,
+        constraint = global_account.version == EXT_GLOBAL_VERSION @ ExtError::InvalidVersion,
+        seeds = [EXT_GLOBAL_SEED, global_account.ext_mint.as_ref()],
         bump = global_account.bump
     )]
     pub global_account: Account<'info, ExtGlobal>,
@@ -40,7 +46,7 @@ pub struct AddEarner<'info> {
         init,
         payer = signer,
         space = ANCHOR_DISCRIMINATOR_SIZE + Earner::INIT_SPACE,
-        seeds = [EARNER_SEED, user_token_account.key().as_ref()],
+        seeds = [EARNER_SEED, global_account.ext_mint.as_ref(), user_token_account.key().as_ref()],
         bump
     )]
     pub earner_account: Account<'info, Earner>,
@@ -57,7 +63,32 @@ pub fn handler(ctx: Context<AddEarner>, user: Pubkey) -> Result<()> {
         bump: ctx.bumps.earner_account,
         user,
         user_token_account: ctx.accounts.user_token_account.key(),
+        total_rewards: 0,
+        total_fees: 0,
+        claims: 0,
+        first_earning_timestamp: ctx.accounts.global_account.timestamp,
+        version: EARNER_VERSION,
+        rent_payer: ctx.accounts.signer.key(),
+        reserved: [0; 32],
+    });
+
+    let index = ctx.accounts.global_account.index;
+    ctx.accounts.global_account.add_earner(index);
+
+    emit!(EarnerAdded {
+        ext_mint: ctx.accounts.global_account.ext_mint,
+        earn_manager: ctx.accounts.signer.key(),
+        user,
+        user_token_account: ctx.accounts.user_token_account.key(),
     });
 
     Ok(())
 }
+
+#[event]
+pub struct EarnerAdded {
+    pub ext_mint: Pubkey,
+    pub earn_manager: Pubkey,
+    pub user: Pubkey,
+    pub user_token_account: Pubkey,
+}
@@ -8,7 +8,10 @@ use anchor_spl::token_interface::TokenAccount;
 use crate::{
     constants::ONE_HUNDRED_PERCENT,
     errors::ExtError,
-    state::{EarnManager, ExtGlobal, EARN_MANAGER_SEED, EXT_GLOBAL_SEED},
+    state::{
+        EarnManager, ExtGlobal, EARN_MANAGER_SEED, EARN_MANAGER_VERSION, EXT_GLOBAL_SEED,
+        EXT_GLOBAL_VERSION,
+    },
 };
 
 #[derive(Accounts)]
@@ -17,14 +20,16 @@ pub struct ConfigureEarnManager<'info> {
     pub signer: Signer<'info>,
 
     #[account(
-        seeds = [EXT_GLOBAL_SEED],
+        constraint = global_account.version == EXT_GLOBAL_VERSION @ ExtError::InvalidVersion,
+        seeds = [EXT_GLOBAL_SEED, global_account.ext_mint.as_ref()],
         bump = global_account.bump,
     )]
     pub global_account: Account<'info, ExtGlobal>,
 
     #[account(
         mut,
-        seeds = [EARN_MANAGER_SEED, signer.key().as_ref()],
+        constraint = earn_manager_account.version == EARN_MANAGER_VERSION @ ExtError::InvalidVersion,
+        seeds = [EARN_MANAGER_SEED, global_account.ext_mint.as_ref(), signer.key().as_ref()],
         bump = earn_manager_account.bump
     )]
     pub earn_manager_account: Account<'info, EarnManager>,
@@ -47,5 +52,20 @@ pub fn handler(ctx: Context<ConfigureEarnManager>, fee_bps: Option<u64>) -> Resu
         ctx.accounts.earn_manager_account.fee_token_account = fee_token_account.key();
     }
 
+    emit!(EarnManagerConfigured {
+        ext_mint: ctx.accounts.global_account.ext_mint,
+        earn_manager: ctx.accounts.signer.key(),
+        fee_bps: ctx.accounts.earn_manager_account.fee_bps,
+        fee_token_account: ctx.accounts.earn_manager_account.fee_token_account,
+    });
+
     Ok(())
 }
+
+#[event]
+pub struct EarnManagerConfigured {
+    pub ext_mint: Pubkey,
+    pub earn_manager: Pubkey,
+    pub fee_bps: u64,
+    pub fee_token_account: Pubkey,
+}
@@ -3,10 +3,15 @@
 // external dependencies
 use anchor_lang::prelude::*;
 
+use earn::utils::account::{close_with_refund, is_rent_recipient};
+
 // local dependencies
 use crate::{
     errors::ExtError,
-    state::{EarnManager, Earner, EARNER_SEED, EARN_MANAGER_SEED},
+    state::{
+        EarnManager, Earner, ExtGlobal, EARNER_SEED, EARNER_VERSION, EARN_MANAGER_SEED,
+        EARN_MANAGER_VERSION, EXT_GLOBAL_SEED, EXT_GLOBAL_VERSION,
+    },
 };
 
 #[derive(Accounts)]
@@ -16,23 +21,63 @@ pub struct RemoveEarner<'info> {
 
     #[account(
         mut,
-        close = signer,
+        constraint = global_account.version == EXT_GLOBAL_VERSION @ ExtError::InvalidVersion,
+        seeds = [EXT_GLOBAL_SEED, global_account.ext_mint.as_ref()],
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, ExtGlobal>,
+
+    #[account(
+        mut,
         constraint = earner_account.earn_manager == signer.key() @ ExtError::NotAuthorized,
-        seeds = [EARNER_SEED, earner_account.user_token_account.as_ref()],
+        constraint = earner_account.version == EARNER_VERSION @ ExtError::InvalidVersion,
+        seeds = [EARNER_SEED, global_account.ext_mint.as_ref(), earner_account.user_token_account.as_ref()],
         bump = earner_account.bump,
     )]
     pub earner_account: Account<'info, Earner>,
 
     #[account(
         constraint = earn_manager_account.is_active @ ExtError::NotActive,
-        seeds = [EARN_MANAGER_SEED, signer.key().as_ref()],
+        constraint = earn_manager_account.version == EARN_MANAGER_VERSION @ ExtError::InvalidVersion,
+        seeds = [EARN_MANAGER_SEED, global_account.ext_mint.as_ref(), signer.key().as_ref()],
         bump = earn_manager_account.bump
     )]
     pub earn_manager_account: Account<'info, EarnManager>,
 
+    /// CHECK: This account only receives the rent of the earner account
+    #[account(
+        mut,
+        constraint = is_rent_recipient(&earner_account.rent_payer, &rent_payer.key(), &signer.key()) @ ExtError::InvalidAccount,
+    )]
+    pub rent_payer: AccountInfo<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(_ctx: Context<RemoveEarner>) -> Result<()> {
-    Ok(())
+pub fn handler(ctx: Context<RemoveEarner>) -> Result<()> {
+    emit!(EarnerRemoved {
+        ext_mint: ctx.accounts.global_account.ext_mint,
+        earn_manager: ctx.accounts.earner_account.earn_manager,
+        user: ctx.accounts.earner_account.user,
+        user_token_account: ctx.accounts.earner_account.user_token_account,
+    });
+
+    let last_claim_index = ctx.accounts.earner_account.last_claim_index;
+    ctx.accounts.global_account.remove_earner(last_claim_index);
+
+    // The earn manager is removing its own earner, so no bounty is paid
+    close_with_refund(
+        &ctx.accounts.earner_account.to_account_info(),
+        &ctx.accounts.rent_payer,
+        &ctx.accounts.signer.to_account_info(),
+        0,
+    )
+}
+
+#[event]
+pub struct EarnerRemoved {
+    pub ext_mint: Pubkey,
+    pub earn_manager: Pubkey,
+    pub user: Pubkey,
+    pub user_token_account: Pubkey,
 }
@@ -4,7 +4,10 @@ use anchor_lang::prelude::*;
 
 use crate::{
     errors::ExtError,
-    state::{EarnManager, Earner, EARNER_SEED, EARN_MANAGER_SEED},
+    state::{
+        EarnManager, Earner, ExtGlobal, EARNER_SEED, EARNER_VERSION, EARN_MANAGER_SEED,
+        EARN_MANAGER_VERSION, EXT_GLOBAL_SEED, EXT_GLOBAL_VERSION,
+    },
 };
 
 #[derive(Accounts)]
@@ -12,24 +15,34 @@ use crate::{
 pub struct TransferEarner<'info> {
     pub signer: Signer<'info>,
 
+    #[account(
+        constraint = global_account.version == EXT_GLOBAL_VERSION @ ExtError::InvalidVersion,
+        seeds = [EXT_GLOBAL_SEED, global_account.ext_mint.as_ref()],
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, ExtGlobal>,
+
     #[account(
         mut,
         constraint = earner_account.earn_manager == signer.key() @ ExtError::NotAuthorized,
-        seeds = [EARNER_SEED, earner_account.user_token_account.as_ref()],
+        constraint = earner_account.version == EARNER_VERSION @ ExtError::InvalidVersion,
+        seeds = [EARNER_SEED, global_account.ext_mint.as_ref(), earner_account.user_token_account.as_ref()],
         bump = earner_account.bump,
     )]
     pub earner_account: Account<'info, Earner>,
 
     #[account(
         constraint = from_earn_manager_account.is_active @ ExtError::NotActive,
-        seeds = [EARN_MANAGER_SEED, signer.key().as_ref()],
+        constraint = from_earn_manager_account.version == EARN_MANAGER_VERSION @ ExtError::InvalidVersion,
+        seeds = [EARN_MANAGER_SEED, global_account.ext_mint.as_ref(), signer.key().as_ref()],
         bump = from_earn_manager_account.bump,
     )]
     pub from_earn_manager_account: Account<'info, EarnManager>,
 
     #[account(
         constraint = to_earn_manager_account.is_active @ ExtError::NotActive,
-        seeds = [EARN_MANAGER_SEED, to_earn_manager.as_ref()],
+        constraint = to_earn_manager_account.version == EARN_MANAGER_VERSION @ ExtError::InvalidVersion,
+        seeds = [EARN_MANAGER_SEED, global_account.ext_mint.as_ref(), to_earn_manager.as_ref()],
         bump = to_earn_manager_account.bump,
     )]
     pub to_earn_manager_account: Account<'info, EarnManager>,
@@ -38,5 +51,20 @@ pub struct TransferEarner<'info> {
 pub fn handler(ctx: Context<TransferEarner>, to_earn_manager: Pubkey) -> Result<()> {
     ctx.accounts.earner_account.earn_manager = to_earn_manager;
 
+    emit!(EarnerTransferred {
+        ext_mint: ctx.accounts.global_account.ext_mint,
+        user_token_account: ctx.accounts.earner_account.user_token_account,
+        from_earn_manager: ctx.accounts.signer.key(),
+        to_earn_manager,
+    });
+
     Ok(())
 }
+
+#[event]
+pub struct EarnerTransferred {
+    pub ext_mint: Pubkey,
+    pub user_token_account: Pubkey,
+    pub from_earn_manager: Pubkey,
+    pub to_earn_manager: Pubkey,
+}
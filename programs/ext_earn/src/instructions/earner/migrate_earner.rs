@@ -0,0 +1,106 @@
+// ext_earn/instructions/earner/migrate_earner.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use earn::utils::{
+    account::{close_with_refund, is_rent_recipient},
+    token::has_immutable_owner,
+};
+
+// local dependencies
+use crate::{
+    constants::ANCHOR_DISCRIMINATOR_SIZE,
+    errors::ExtError,
+    state::{Earner, ExtGlobal, EARNER_SEED, EARNER_VERSION, EXT_GLOBAL_SEED, EXT_GLOBAL_VERSION},
+};
+
+#[derive(Accounts)]
+pub struct MigrateEarner<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        constraint = global_account.version == EXT_GLOBAL_VERSION @ ExtError::InvalidVersion,
+        seeds = [EXT_GLOBAL_SEED, global_account.ext_mint.as_ref()],
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, ExtGlobal>,
+
+    #[account(
+        mut,
+        has_one = user @ ExtError::NotAuthorized,
+        constraint = earner_account.version == EARNER_VERSION @ ExtError::InvalidVersion,
+        seeds = [EARNER_SEED, global_account.ext_mint.as_ref(), earner_account.user_token_account.as_ref()],
+        bump = earner_account.bump,
+    )]
+    pub earner_account: Account<'info, Earner>,
+
+    #[account(
+        token::mint = global_account.ext_mint,
+        token::authority = user,
+        constraint = has_immutable_owner(&new_user_token_account) @ ExtError::MutableOwner,
+    )]
+    pub new_user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = user,
+        space = ANCHOR_DISCRIMINATOR_SIZE + Earner::INIT_SPACE,
+        seeds = [EARNER_SEED, global_account.ext_mint.as_ref(), new_user_token_account.key().as_ref()],
+        bump
+    )]
+    pub new_earner_account: Account<'info, Earner>,
+
+    /// CHECK: This account only receives the rent of the old earner account
+    #[account(
+        mut,
+        constraint = is_rent_recipient(&earner_account.rent_payer, &rent_payer.key(), &user.key()) @ ExtError::InvalidAccount,
+    )]
+    pub rent_payer: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateEarner>) -> Result<()> {
+    let earner = &ctx.accounts.earner_account;
+
+    // Carry over the accrual state, stats and earn manager
+    // The old account is closed to whoever paid for it, the user pays for the new one
+    ctx.accounts.new_earner_account.set_inner(Earner {
+        last_claim_index: earner.last_claim_index,
+        last_claim_timestamp: earner.last_claim_timestamp,
+        bump: ctx.bumps.new_earner_account,
+        user: earner.user,
+        user_token_account: ctx.accounts.new_user_token_account.key(),
+        earn_manager: earner.earn_manager,
+        recipient_token_account: earner.recipient_token_account,
+        total_rewards: earner.total_rewards,
+        total_fees: earner.total_fees,
+        claims: earner.claims,
+        first_earning_timestamp: earner.first_earning_timestamp,
+        version: EARNER_VERSION,
+        rent_payer: ctx.accounts.user.key(),
+        reserved: [0; 32],
+    });
+
+    emit!(EarnerMigrated {
+        user: earner.user,
+        old_user_token_account: earner.user_token_account,
+        new_user_token_account: ctx.accounts.new_user_token_account.key(),
+    });
+
+    close_with_refund(
+        &ctx.accounts.earner_account.to_account_info(),
+        &ctx.accounts.rent_payer,
+        &ctx.accounts.user.to_account_info(),
+        0,
+    )
+}
+
+#[event]
+pub struct EarnerMigrated {
+    pub user: Pubkey,
+    pub old_user_token_account: Pubkey,
+    pub new_user_token_account: Pubkey,
+}
@@ -5,7 +5,7 @@ use anchor_spl::token_interface::TokenAccount;
 
 use crate::{
     errors::ExtError,
-    state::{Earner, ExtGlobal, EARNER_SEED, EXT_GLOBAL_SEED},
+    state::{Earner, ExtGlobal, EARNER_SEED, EARNER_VERSION, EXT_GLOBAL_SEED, EXT_GLOBAL_VERSION},
 };
 
 #[derive(Accounts)]
@@ -19,14 +19,16 @@ pub struct SetRecipient<'info> {
     pub signer: Signer<'info>,
 
     #[account(
-        seeds = [EXT_GLOBAL_SEED],
+        constraint = global_account.version == EXT_GLOBAL_VERSION @ ExtError::InvalidVersion,
+        seeds = [EXT_GLOBAL_SEED, global_account.ext_mint.as_ref()],
         bump = global_account.bump,
     )]
     pub global_account: Account<'info, ExtGlobal>,
 
     #[account(
         mut,
-        seeds = [EARNER_SEED, &earner_account.user_token_account.as_ref()],
+        constraint = earner_account.version == EARNER_VERSION @ ExtError::InvalidVersion,
+        seeds = [EARNER_SEED, global_account.ext_mint.as_ref(), &earner_account.user_token_account.as_ref()],
         bump = earner_account.bump,
     )]
     pub earner_account: Account<'info, Earner>,
@@ -43,5 +45,18 @@ pub fn handler(ctx: Context<SetRecipient>) -> Result<()> {
             None
         };
 
+    emit!(RecipientSet {
+        ext_mint: ctx.accounts.global_account.ext_mint,
+        user_token_account: ctx.accounts.earner_account.user_token_account,
+        recipient_token_account: ctx.accounts.earner_account.recipient_token_account,
+    });
+
     Ok(())
 }
+
+#[event]
+pub struct RecipientSet {
+    pub ext_mint: Pubkey,
+    pub user_token_account: Pubkey,
+    pub recipient_token_account: Option<Pubkey>, // None if yield goes to the user token account
+}
@@ -0,0 +1,46 @@
+// ext_earn/instructions/open/migrate_earn_manager.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+use earn::utils::migration::realloc_to_current;
+
+// local dependencies
+use crate::{
+    errors::ExtError,
+    state::{EarnManager, EARN_MANAGER_VERSION},
+};
+
+#[derive(Accounts)]
+pub struct MigrateEarnManager<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Earn manager accounts on an older layout cannot be deserialized,
+    /// so the owner and discriminator are validated in the handler
+    #[account(mut)]
+    pub earn_manager_account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateEarnManager>) -> Result<()> {
+    let earn_manager_info = ctx.accounts.earn_manager_account.to_account_info();
+
+    realloc_to_current::<EarnManager>(
+        &earn_manager_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    let mut earn_manager = EarnManager::try_deserialize(&mut &earn_manager_info.try_borrow_data()?[..])?;
+
+    if earn_manager.version >= EARN_MANAGER_VERSION {
+        return err!(ExtError::InvalidVersion);
+    }
+
+    earn_manager.version = EARN_MANAGER_VERSION;
+
+    earn_manager.try_serialize(&mut &mut earn_manager_info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+// ext_earn/instructions/open/migrate_earner_account.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+use earn::utils::migration::realloc_to_current;
+
+// local dependencies
+use crate::{
+    errors::ExtError,
+    state::{Earner, EARNER_VERSION},
+};
+
+#[derive(Accounts)]
+pub struct MigrateEarnerAccount<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Earner accounts on an older layout cannot be deserialized,
+    /// so the owner and discriminator are validated in the handler
+    #[account(mut)]
+    pub earner_account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateEarnerAccount>) -> Result<()> {
+    let earner_info = ctx.accounts.earner_account.to_account_info();
+
+    realloc_to_current::<Earner>(
+        &earner_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    let mut earner = Earner::try_deserialize(&mut &earner_info.try_borrow_data()?[..])?;
+
+    if earner.version >= EARNER_VERSION {
+        return err!(ExtError::InvalidVersion);
+    }
+
+    // Fields added before versioning default to zero, see the earn program
+    earner.version = EARNER_VERSION;
+
+    earner.try_serialize(&mut &mut earner_info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
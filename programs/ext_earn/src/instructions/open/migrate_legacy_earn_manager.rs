@@ -0,0 +1,83 @@
+// ext_earn/instructions/open/migrate_legacy_earn_manager.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+use earn::utils::account::{close_with_refund, is_rent_recipient};
+
+// local dependencies
+use crate::{
+    constants::ANCHOR_DISCRIMINATOR_SIZE,
+    errors::ExtError,
+    state::{
+        EarnManager, ExtGlobal, EARN_MANAGER_SEED, EARN_MANAGER_VERSION, EXT_GLOBAL_SEED,
+        EXT_GLOBAL_VERSION,
+    },
+};
+
+// Moves an earn manager of the legacy extension to the layout keyed by the extension mint
+#[derive(Accounts)]
+pub struct MigrateLegacyEarnManager<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        constraint = global_account.legacy @ ExtError::InvalidAccount,
+        constraint = global_account.version == EXT_GLOBAL_VERSION @ ExtError::InvalidVersion,
+        seeds = [EXT_GLOBAL_SEED, global_account.ext_mint.as_ref()],
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, ExtGlobal>,
+
+    #[account(
+        mut,
+        constraint = legacy_earn_manager_account.version == EARN_MANAGER_VERSION @ ExtError::InvalidVersion,
+        seeds = [EARN_MANAGER_SEED, legacy_earn_manager_account.earn_manager.as_ref()],
+        bump = legacy_earn_manager_account.bump,
+    )]
+    pub legacy_earn_manager_account: Account<'info, EarnManager>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = ANCHOR_DISCRIMINATOR_SIZE + EarnManager::INIT_SPACE,
+        seeds = [
+            EARN_MANAGER_SEED,
+            global_account.ext_mint.as_ref(),
+            legacy_earn_manager_account.earn_manager.as_ref(),
+        ],
+        bump
+    )]
+    pub earn_manager_account: Account<'info, EarnManager>,
+
+    // Legacy earn managers without a rent payer were paid for by the admin
+    /// CHECK: This account only receives the rent of the legacy earn manager account
+    #[account(
+        mut,
+        constraint = is_rent_recipient(&legacy_earn_manager_account.rent_payer, &rent_payer.key(), &global_account.admin) @ ExtError::InvalidAccount,
+    )]
+    pub rent_payer: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateLegacyEarnManager>) -> Result<()> {
+    let legacy = &ctx.accounts.legacy_earn_manager_account;
+
+    ctx.accounts.earn_manager_account.set_inner(EarnManager {
+        earn_manager: legacy.earn_manager,
+        is_active: legacy.is_active,
+        fee_bps: legacy.fee_bps,
+        fee_token_account: legacy.fee_token_account,
+        bump: ctx.bumps.earn_manager_account,
+        version: EARN_MANAGER_VERSION,
+        rent_payer: ctx.accounts.signer.key(),
+        reserved: [0; 32],
+    });
+
+    close_with_refund(
+        &ctx.accounts.legacy_earn_manager_account.to_account_info(),
+        &ctx.accounts.rent_payer,
+        &ctx.accounts.signer.to_account_info(),
+        0,
+    )
+}
@@ -0,0 +1,93 @@
+// ext_earn/instructions/open/migrate_legacy_earner.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+use earn::utils::account::{close_with_refund, is_rent_recipient};
+
+// local dependencies
+use crate::{
+    constants::ANCHOR_DISCRIMINATOR_SIZE,
+    errors::ExtError,
+    state::{Earner, ExtGlobal, EARNER_SEED, EARNER_VERSION, EXT_GLOBAL_SEED, EXT_GLOBAL_VERSION},
+};
+
+// Moves an earner of the legacy extension to the layout keyed by the extension mint
+#[derive(Accounts)]
+pub struct MigrateLegacyEarner<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = global_account.legacy @ ExtError::InvalidAccount,
+        constraint = global_account.version == EXT_GLOBAL_VERSION @ ExtError::InvalidVersion,
+        seeds = [EXT_GLOBAL_SEED, global_account.ext_mint.as_ref()],
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, ExtGlobal>,
+
+    #[account(
+        mut,
+        constraint = legacy_earner_account.version == EARNER_VERSION @ ExtError::InvalidVersion,
+        seeds = [EARNER_SEED, legacy_earner_account.user_token_account.as_ref()],
+        bump = legacy_earner_account.bump,
+    )]
+    pub legacy_earner_account: Account<'info, Earner>,
+
+    #[account(
+        init,
+        payer = signer,
+        space = ANCHOR_DISCRIMINATOR_SIZE + Earner::INIT_SPACE,
+        seeds = [
+            EARNER_SEED,
+            global_account.ext_mint.as_ref(),
+            legacy_earner_account.user_token_account.as_ref(),
+        ],
+        bump
+    )]
+    pub earner_account: Account<'info, Earner>,
+
+    /// CHECK: This account only receives the rent of the legacy earner account
+    #[account(
+        mut,
+        constraint = is_rent_recipient(&legacy_earner_account.rent_payer, &rent_payer.key(), &signer.key()) @ ExtError::InvalidAccount,
+    )]
+    pub rent_payer: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateLegacyEarner>) -> Result<()> {
+    let legacy = &ctx.accounts.legacy_earner_account;
+
+    // The signer pays the rent of the new account and gets it back when it is closed
+    ctx.accounts.earner_account.set_inner(Earner {
+        last_claim_index: legacy.last_claim_index,
+        last_claim_timestamp: legacy.last_claim_timestamp,
+        bump: ctx.bumps.earner_account,
+        user: legacy.user,
+        user_token_account: legacy.user_token_account,
+        earn_manager: legacy.earn_manager,
+        recipient_token_account: legacy.recipient_token_account,
+        total_rewards: legacy.total_rewards,
+        total_fees: legacy.total_fees,
+        claims: legacy.claims,
+        first_earning_timestamp: legacy.first_earning_timestamp,
+        version: EARNER_VERSION,
+        rent_payer: ctx.accounts.signer.key(),
+        reserved: [0; 32],
+    });
+
+    // Legacy earners are only counted once they are migrated
+    let last_claim_index = legacy.last_claim_index;
+    let global = &mut ctx.accounts.global_account;
+    global.add_earner(last_claim_index);
+    global.legacy_earners = global.legacy_earners.saturating_sub(1);
+
+    close_with_refund(
+        &ctx.accounts.legacy_earner_account.to_account_info(),
+        &ctx.accounts.rent_payer,
+        &ctx.accounts.signer.to_account_info(),
+        0,
+    )
+}
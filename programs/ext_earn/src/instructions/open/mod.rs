@@ -1,9 +1,24 @@
 // ext_earn/instructions/open/mod.rs
 
+pub mod migrate_earn_manager;
+pub mod migrate_earner_account;
+pub mod migrate_legacy_earn_manager;
+pub mod migrate_legacy_earner;
+pub mod preview_claim;
 pub mod remove_orphaned_earner;
 pub mod unwrap;
 pub mod wrap;
 
+pub use migrate_earn_manager::MigrateEarnManager;
+pub(crate) use migrate_earn_manager::__client_accounts_migrate_earn_manager;
+pub use migrate_earner_account::MigrateEarnerAccount;
+pub(crate) use migrate_earner_account::__client_accounts_migrate_earner_account;
+pub use migrate_legacy_earn_manager::MigrateLegacyEarnManager;
+pub(crate) use migrate_legacy_earn_manager::__client_accounts_migrate_legacy_earn_manager;
+pub use migrate_legacy_earner::MigrateLegacyEarner;
+pub(crate) use migrate_legacy_earner::__client_accounts_migrate_legacy_earner;
+pub use preview_claim::PreviewClaim;
+pub(crate) use preview_claim::__client_accounts_preview_claim;
 pub use remove_orphaned_earner::RemoveOrphanedEarner;
 pub(crate) use remove_orphaned_earner::__client_accounts_remove_orphaned_earner;
 pub use unwrap::Unwrap;
@@ -16,5 +31,10 @@ cfg_if::cfg_if! {
         pub(crate) use wrap::__cpi_client_accounts_wrap;
         pub(crate) use unwrap::__cpi_client_accounts_unwrap;
         pub(crate) use remove_orphaned_earner::__cpi_client_accounts_remove_orphaned_earner;
+        pub(crate) use preview_claim::__cpi_client_accounts_preview_claim;
+        pub(crate) use migrate_earner_account::__cpi_client_accounts_migrate_earner_account;
+        pub(crate) use migrate_earn_manager::__cpi_client_accounts_migrate_earn_manager;
+        pub(crate) use migrate_legacy_earn_manager::__cpi_client_accounts_migrate_legacy_earn_manager;
+        pub(crate) use migrate_legacy_earner::__cpi_client_accounts_migrate_legacy_earner;
     }
 }
@@ -0,0 +1,115 @@
+// ext_earn/instructions/open/preview_claim.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+use earn::instructions::preview_claim::{ClaimFailure, ClaimPreview};
+
+// local dependencies
+use crate::{
+    errors::ExtError,
+    instructions::claim_for::calculate_fee,
+    state::{
+        EarnManager, Earner, ExtGlobal, YieldMode, EARNER_SEED, EARNER_VERSION, EARN_MANAGER_SEED,
+        EARN_MANAGER_VERSION, EXT_GLOBAL_SEED, EXT_GLOBAL_VERSION, M_VAULT_SEED,
+    },
+};
+
+#[derive(Accounts)]
+pub struct PreviewClaim<'info> {
+    #[account(
+        has_one = ext_mint @ ExtError::InvalidAccount,
+        seeds = [EXT_GLOBAL_SEED, global_account.ext_mint.as_ref()],
+        bump = global_account.bump,
+    )]
+    pub global_account: Account<'info, ExtGlobal>,
+
+    pub ext_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: This account is validated by the seed, it stores no data
+    #[account(
+        seeds = [M_VAULT_SEED, global_account.ext_mint.as_ref()],
+        bump = global_account.m_vault_bump,
+    )]
+    pub m_vault_account: AccountInfo<'info>,
+
+    #[account(
+        associated_token::mint = global_account.m_mint,
+        associated_token::authority = m_vault_account,
+        associated_token::token_program = token_2022,
+    )]
+    pub vault_m_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [EARNER_SEED, global_account.ext_mint.as_ref(), earner_account.user_token_account.as_ref()],
+        bump = earner_account.bump,
+    )]
+    pub earner_account: Account<'info, Earner>,
+
+    #[account(
+        seeds = [EARN_MANAGER_SEED, global_account.ext_mint.as_ref(), earner_account.earn_manager.as_ref()],
+        bump = earn_manager_account.bump,
+    )]
+    pub earn_manager_account: Account<'info, EarnManager>,
+
+    /// CHECK: the fee is skipped if the token account has been closed or is not initialized
+    #[account(
+        address = earn_manager_account.fee_token_account @ ExtError::InvalidAccount,
+    )]
+    pub earn_manager_token_account: AccountInfo<'info>,
+
+    pub token_2022: Program<'info, Token2022>,
+}
+
+// Mirrors the checks in claim_for without minting.
+// Checks that claim_for enforces on its accounts are reported instead of failing the preview.
+pub fn handler(ctx: Context<PreviewClaim>, snapshot_balance: u64) -> Result<ClaimPreview> {
+    let global = &ctx.accounts.global_account;
+    let earner = &ctx.accounts.earner_account;
+
+    let mut preview = ClaimPreview {
+        rewards: 0,
+        fee: 0,
+        amount: 0,
+        failure: None,
+    };
+
+    if global.version != EXT_GLOBAL_VERSION
+        || earner.version != EARNER_VERSION
+        || ctx.accounts.earn_manager_account.version != EARN_MANAGER_VERSION
+    {
+        preview.failure = Some(ClaimFailure::InvalidVersion);
+        return Ok(preview);
+    }
+
+    // Holders of a rebasing extension earn through the index, nothing is claimed
+    if global.yield_mode != YieldMode::Claim {
+        preview.failure = Some(ClaimFailure::InvalidYieldMode);
+        return Ok(preview);
+    }
+
+    if earner.last_claim_index >= global.index {
+        preview.failure = Some(ClaimFailure::AlreadyClaimed);
+        return Ok(preview);
+    }
+
+    preview.rewards = earn::utils::rewards::calculate_rewards(
+        snapshot_balance,
+        earner.last_claim_index,
+        global.index,
+    );
+
+    preview.fee = calculate_fee(
+        &ctx.accounts.earn_manager_account,
+        &ctx.accounts.earn_manager_token_account,
+        preview.rewards,
+    );
+    preview.amount = preview.rewards - preview.fee;
+
+    // The newly minted rewards must not make the extension undercollateralized
+    if ctx.accounts.ext_mint.supply + preview.rewards > ctx.accounts.vault_m_token_account.amount {
+        preview.failure = Some(ClaimFailure::InsufficientCollateral);
+    }
+
+    Ok(preview)
+}
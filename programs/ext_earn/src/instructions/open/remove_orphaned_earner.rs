@@ -1,10 +1,15 @@
 // ext_earn/instructions/open/remove_orphaned_earner.rs
 
 use anchor_lang::prelude::*;
+use earn::utils::account::{close_with_refund, is_rent_recipient};
 
 use crate::{
     errors::ExtError,
-    state::{EarnManager, Earner, ExtGlobal, EARNER_SEED, EARN_MANAGER_SEED, EXT_GLOBAL_SEED},
+    instructions::earn_manager::remove_earner::EarnerRemoved,
+    state::{
+        EarnManager, Earner, ExtGlobal, EARNER_SEED, EARNER_VERSION, EARN_MANAGER_SEED,
+        EARN_MANAGER_VERSION, EXT_GLOBAL_SEED, EXT_GLOBAL_VERSION,
+    },
 };
 
 #[derive(Accounts)]
@@ -13,29 +18,54 @@ pub struct RemoveOrphanedEarner<'info> {
     pub signer: Signer<'info>,
 
     #[account(
-        seeds = [EXT_GLOBAL_SEED],
+        mut,
+        constraint = global_account.version == EXT_GLOBAL_VERSION @ ExtError::InvalidVersion,
+        seeds = [EXT_GLOBAL_SEED, global_account.ext_mint.as_ref()],
         bump = global_account.bump,
     )]
     pub global_account: Account<'info, ExtGlobal>,
 
     #[account(
         mut,
-        close = signer,
-        seeds = [EARNER_SEED, earner_account.user_token_account.as_ref()],
+        constraint = earner_account.version == EARNER_VERSION @ ExtError::InvalidVersion,
+        seeds = [EARNER_SEED, global_account.ext_mint.as_ref(), earner_account.user_token_account.as_ref()],
         bump = earner_account.bump,
     )]
     pub earner_account: Account<'info, Earner>,
 
     #[account(
         constraint = !earn_manager_account.is_active @ ExtError::Active,
-        seeds = [EARN_MANAGER_SEED, earner_account.earn_manager.as_ref()],
+        constraint = earn_manager_account.version == EARN_MANAGER_VERSION @ ExtError::InvalidVersion,
+        seeds = [EARN_MANAGER_SEED, global_account.ext_mint.as_ref(), earner_account.earn_manager.as_ref()],
         bump = earn_manager_account.bump,
     )]
     pub earn_manager_account: Account<'info, EarnManager>,
 
+    /// CHECK: This account only receives the rent of the earner account
+    #[account(
+        mut,
+        constraint = is_rent_recipient(&earner_account.rent_payer, &rent_payer.key(), &signer.key()) @ ExtError::InvalidAccount,
+    )]
+    pub rent_payer: AccountInfo<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(_ctx: Context<RemoveOrphanedEarner>) -> Result<()> {
-    Ok(())
+pub fn handler(ctx: Context<RemoveOrphanedEarner>) -> Result<()> {
+    emit!(EarnerRemoved {
+        ext_mint: ctx.accounts.global_account.ext_mint,
+        earn_manager: ctx.accounts.earner_account.earn_manager,
+        user: ctx.accounts.earner_account.user,
+        user_token_account: ctx.accounts.earner_account.user_token_account,
+    });
+
+    let last_claim_index = ctx.accounts.earner_account.last_claim_index;
+    ctx.accounts.global_account.remove_earner(last_claim_index);
+
+    close_with_refund(
+        &ctx.accounts.earner_account.to_account_info(),
+        &ctx.accounts.rent_payer,
+        &ctx.accounts.signer.to_account_info(),
+        ctx.accounts.global_account.removal_bounty,
+    )
 }
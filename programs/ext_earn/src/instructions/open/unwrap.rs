@@ -2,14 +2,18 @@
 
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+use earn::{state::Global as EarnGlobal, utils::merkle_proof::ProofElement};
 
 use crate::{
     errors::ExtError,
     state::{
-        global::{ExtGlobal, EXT_GLOBAL_SEED},
-        M_VAULT_SEED,
+        global::{ExtGlobal, EXT_GLOBAL_SEED, EXT_GLOBAL_VERSION},
+        Wrapper, YieldMode, M_VAULT_SEED, WRAPPER_SEED,
+    },
+    utils::{
+        token::{burn_tokens, transfer_tokens_from_program},
+        wrapping::{check_wrapper, wrap_fee},
     },
-    utils::token::{burn_tokens, transfer_tokens_from_program},
 };
 
 #[derive(Accounts)]
@@ -22,16 +26,20 @@ pub struct Unwrap<'info> {
     pub ext_mint: InterfaceAccount<'info, Mint>,
 
     #[account(
-        seeds = [EXT_GLOBAL_SEED],
+        constraint = global_account.version == EXT_GLOBAL_VERSION @ ExtError::InvalidVersion,
+        seeds = [EXT_GLOBAL_SEED, global_account.ext_mint.as_ref()],
         bump = global_account.bump,
         has_one = m_mint @ ExtError::InvalidAccount,
         has_one = ext_mint @ ExtError::InvalidAccount,
+        has_one = m_earn_global_account @ ExtError::InvalidAccount,
     )]
     pub global_account: Account<'info, ExtGlobal>,
 
+    pub m_earn_global_account: Account<'info, EarnGlobal>,
+
     /// CHECK: This account is validated by the seed, it stores no data
     #[account(
-        seeds = [M_VAULT_SEED],
+        seeds = [M_VAULT_SEED, global_account.ext_mint.as_ref()],
         bump = global_account.m_vault_bump,
     )]
     pub m_vault: AccountInfo<'info>,
@@ -57,10 +65,38 @@ pub struct Unwrap<'info> {
     )]
     pub from_ext_token_account: InterfaceAccount<'info, TokenAccount>,
 
+    #[account(
+        seeds = [WRAPPER_SEED, global_account.ext_mint.as_ref(), signer.key().as_ref()],
+        bump = wrapper_account.bump,
+    )]
+    pub wrapper_account: Option<Account<'info, Wrapper>>,
+
+    #[account(
+        mut,
+        address = global_account.fee_token_account @ ExtError::InvalidAccount,
+    )]
+    pub fee_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
     pub token_2022: Program<'info, Token2022>,
 }
 
-pub fn handler(ctx: Context<Unwrap>, amount: u64) -> Result<()> {
+pub fn handler(ctx: Context<Unwrap>, amount: u64, proof: Option<Vec<ProofElement>>) -> Result<()> {
+    // When rebasing, amounts are converted at the ext index, which has to be synced
+    // with the m earn index first so that they are not priced at a stale index
+    let global = &ctx.accounts.global_account;
+    if global.yield_mode == YieldMode::Rebasing
+        && !global.is_synced(ctx.accounts.m_earn_global_account.index)
+    {
+        return err!(ExtError::IndexNotSynced);
+    }
+
+    check_wrapper(
+        &ctx.accounts.global_account,
+        &ctx.accounts.signer.key(),
+        &ctx.accounts.wrapper_account,
+        proof,
+    )?;
+
     // Burn the amount of ext tokens from the user
     burn_tokens(
         &ctx.accounts.from_ext_token_account,   // from
@@ -70,16 +106,79 @@ pub fn handler(ctx: Context<Unwrap>, amount: u64) -> Result<()> {
         &ctx.accounts.token_2022,               // token program
     )?;
 
-    // Transfer the amount of m tokens from the m vault to the user
+    // A rebasing ext token is worth more m as the index grows
+    let m_amount = ctx.accounts.global_account.ext_to_m(amount);
+    let vault_seeds: &[&[&[u8]]] = &[&[
+        M_VAULT_SEED,
+        ctx.accounts.global_account.ext_mint.as_ref(),
+        &[ctx.accounts.global_account.m_vault_bump],
+    ]];
+
+    // The fee is taken in m from the unwrapped amount
+    let fee = wrap_fee(m_amount, ctx.accounts.global_account.unwrap_fee_bps);
+
+    if fee > 0 {
+        let fee_token_account = ctx
+            .accounts
+            .fee_token_account
+            .as_ref()
+            .ok_or(ExtError::InvalidAccount)?;
+
+        transfer_tokens_from_program(
+            &ctx.accounts.vault_m_token_account, // from
+            fee_token_account,                   // to
+            fee,                                 // amount
+            &ctx.accounts.m_mint,                // mint
+            &ctx.accounts.m_vault,               // authority
+            vault_seeds,                         // authority seeds
+            &ctx.accounts.token_2022,            // token program
+        )?;
+    }
+
+    // Transfer the rest of the m tokens from the m vault to the user
     transfer_tokens_from_program(
         &ctx.accounts.vault_m_token_account, // from
         &ctx.accounts.to_m_token_account,    // to
-        amount,                              // amount
+        m_amount - fee,                      // amount
         &ctx.accounts.m_mint,                // mint
         &ctx.accounts.m_vault,               // authority
-        &[&[M_VAULT_SEED, &[ctx.accounts.global_account.m_vault_bump]]], // authority seeds
+        vault_seeds,                         // authority seeds
         &ctx.accounts.token_2022,            // token program
     )?;
 
+    // Report the supply and collateral after the CPIs
+    ctx.accounts.ext_mint.reload()?;
+    ctx.accounts.vault_m_token_account.reload()?;
+
+    emit!(events::Unwrap {
+        ext_mint: ctx.accounts.global_account.ext_mint,
+        signer: ctx.accounts.signer.key(),
+        source: ctx.accounts.from_ext_token_account.key(),
+        destination: ctx.accounts.to_m_token_account.key(),
+        amount,
+        fee,
+        m_amount: m_amount - fee,
+        ext_supply: ctx.accounts.ext_mint.supply,
+        collateral: ctx.accounts.vault_m_token_account.amount,
+    });
+
     Ok(())
 }
+
+// The event shares its name with the accounts struct, so it is kept in its own module
+pub mod events {
+    use super::*;
+
+    #[event]
+    pub struct Unwrap {
+        pub ext_mint: Pubkey,
+        pub signer: Pubkey,
+        pub source: Pubkey,      // ext token account the tokens are burned from
+        pub destination: Pubkey, // m token account receiving the unwrapped m
+        pub amount: u64,         // ext burned from the signer
+        pub fee: u64,            // m paid to the fee token account
+        pub m_amount: u64,       // m sent to the destination after the fee
+        pub ext_supply: u64,     // ext supply after the unwrap
+        pub collateral: u64,     // m held by the vault after the unwrap
+    }
+}
@@ -2,14 +2,18 @@
 
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, Token2022, TokenAccount};
+use earn::{state::Global as EarnGlobal, utils::merkle_proof::ProofElement};
 
 use crate::{
     errors::ExtError,
     state::{
-        global::{ExtGlobal, EXT_GLOBAL_SEED},
-        MINT_AUTHORITY_SEED, M_VAULT_SEED,
+        global::{ExtGlobal, EXT_GLOBAL_SEED, EXT_GLOBAL_VERSION},
+        Wrapper, YieldMode, MINT_AUTHORITY_SEED, M_VAULT_SEED, WRAPPER_SEED,
+    },
+    utils::{
+        token::{mint_tokens, transfer_tokens},
+        wrapping::{check_wrapper, wrap_fee},
     },
-    utils::token::{mint_tokens, transfer_tokens},
 };
 
 #[derive(Accounts)]
@@ -22,23 +26,27 @@ pub struct Wrap<'info> {
     pub ext_mint: InterfaceAccount<'info, Mint>,
 
     #[account(
-        seeds = [EXT_GLOBAL_SEED],
+        constraint = global_account.version == EXT_GLOBAL_VERSION @ ExtError::InvalidVersion,
+        seeds = [EXT_GLOBAL_SEED, global_account.ext_mint.as_ref()],
         bump = global_account.bump,
         has_one = m_mint @ ExtError::InvalidAccount,
         has_one = ext_mint @ ExtError::InvalidAccount,
+        has_one = m_earn_global_account @ ExtError::InvalidAccount,
     )]
     pub global_account: Account<'info, ExtGlobal>,
 
+    pub m_earn_global_account: Account<'info, EarnGlobal>,
+
     /// CHECK: This account is validated by the seed, it stores no data
     #[account(
-        seeds = [M_VAULT_SEED],
+        seeds = [M_VAULT_SEED, global_account.ext_mint.as_ref()],
         bump = global_account.m_vault_bump
     )]
     pub m_vault: AccountInfo<'info>,
 
     /// CHECK: This account is validated by the seed, it stores no data
     #[account(
-        seeds = [MINT_AUTHORITY_SEED],
+        seeds = [MINT_AUTHORITY_SEED, global_account.ext_mint.as_ref()],
         bump = global_account.ext_mint_authority_bump,
     )]
     pub ext_mint_authority: AccountInfo<'info>,
@@ -64,32 +72,117 @@ pub struct Wrap<'info> {
     )]
     pub to_ext_token_account: InterfaceAccount<'info, TokenAccount>,
 
+    #[account(
+        seeds = [WRAPPER_SEED, global_account.ext_mint.as_ref(), signer.key().as_ref()],
+        bump = wrapper_account.bump,
+    )]
+    pub wrapper_account: Option<Account<'info, Wrapper>>,
+
+    #[account(
+        mut,
+        address = global_account.fee_token_account @ ExtError::InvalidAccount,
+    )]
+    pub fee_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
     pub token_2022: Program<'info, Token2022>,
 }
 
-pub fn handler(ctx: Context<Wrap>, amount: u64) -> Result<()> {
-    // Transfer the amount of m tokens from the user to the m vault
+pub fn handler(ctx: Context<Wrap>, amount: u64, proof: Option<Vec<ProofElement>>) -> Result<()> {
+    // When rebasing, amounts are converted at the ext index, which has to be synced
+    // with the m earn index first so that they are not priced at a stale index
+    let global = &ctx.accounts.global_account;
+    if global.yield_mode == YieldMode::Rebasing
+        && !global.is_synced(ctx.accounts.m_earn_global_account.index)
+    {
+        return err!(ExtError::IndexNotSynced);
+    }
+
+    check_wrapper(
+        &ctx.accounts.global_account,
+        &ctx.accounts.signer.key(),
+        &ctx.accounts.wrapper_account,
+        proof,
+    )?;
+
+    // The fee is taken in m before wrapping
+    let fee = wrap_fee(amount, ctx.accounts.global_account.wrap_fee_bps);
+
+    if fee > 0 {
+        let fee_token_account = ctx
+            .accounts
+            .fee_token_account
+            .as_ref()
+            .ok_or(ExtError::InvalidAccount)?;
+
+        transfer_tokens(
+            &ctx.accounts.from_m_token_account,     // from
+            fee_token_account,                      // to
+            fee,                                    // amount
+            &ctx.accounts.m_mint,                   // mint
+            &ctx.accounts.signer.to_account_info(), // authority
+            &ctx.accounts.token_2022,               // token program
+        )?;
+    }
+
+    // Transfer the rest of the m tokens from the user to the m vault
     transfer_tokens(
         &ctx.accounts.from_m_token_account,     // from
         &ctx.accounts.vault_m_token_account,    // to
-        amount,                                 // amount
+        amount - fee,                           // amount
         &ctx.accounts.m_mint,                   // mint
         &ctx.accounts.signer.to_account_info(), // authority
         &ctx.accounts.token_2022,               // token program
     )?;
 
-    // Mint the amount of ext tokens to the user
+    // Mint the amount of ext tokens to the user, scaled down by the index if rebasing
+    let ext_amount = ctx.accounts.global_account.m_to_ext(amount - fee);
+
     mint_tokens(
         &ctx.accounts.to_ext_token_account, // to
-        amount,                             // amount
+        ext_amount,                         // amount
         &ctx.accounts.ext_mint,             // mint
         &ctx.accounts.ext_mint_authority,   // authority
         &[&[
             MINT_AUTHORITY_SEED,
+            ctx.accounts.global_account.ext_mint.as_ref(),
             &[ctx.accounts.global_account.ext_mint_authority_bump],
         ]], // authority seeds
         &ctx.accounts.token_2022,           // token program
     )?;
 
+    // Report the supply and collateral after the CPIs
+    ctx.accounts.ext_mint.reload()?;
+    ctx.accounts.vault_m_token_account.reload()?;
+
+    emit!(events::Wrap {
+        ext_mint: ctx.accounts.global_account.ext_mint,
+        signer: ctx.accounts.signer.key(),
+        source: ctx.accounts.from_m_token_account.key(),
+        destination: ctx.accounts.to_ext_token_account.key(),
+        amount,
+        fee,
+        ext_amount,
+        ext_supply: ctx.accounts.ext_mint.supply,
+        collateral: ctx.accounts.vault_m_token_account.amount,
+    });
+
     Ok(())
 }
+
+// The event shares its name with the accounts struct, so it is kept in its own module
+pub mod events {
+    use super::*;
+
+    #[event]
+    pub struct Wrap {
+        pub ext_mint: Pubkey,
+        pub signer: Pubkey,
+        pub source: Pubkey,      // m token account of the signer
+        pub destination: Pubkey, // ext token account receiving the minted tokens
+        pub amount: u64,         // m taken from the signer, including the fee
+        pub fee: u64,            // m paid to the fee token account
+        pub ext_amount: u64,     // ext minted
+        pub ext_supply: u64,     // ext supply after the wrap
+        pub collateral: u64,     // m held by the vault after the wrap
+    }
+}
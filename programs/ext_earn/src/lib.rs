@@ -8,7 +8,9 @@ pub mod utils;
 
 use anchor_lang::prelude::*;
 
+use earn::{instructions::preview_claim::ClaimPreview, utils::merkle_proof::ProofElement};
 use instructions::*;
+use state::{Allowlist, Role, YieldMode};
 
 #[cfg(not(feature = "no-entrypoint"))]
 solana_security_txt::security_txt! {
@@ -30,8 +32,12 @@ pub mod ext_earn {
 
     // Admin instructions
 
-    pub fn initialize(ctx: Context<Initialize>, earn_authority: Pubkey) -> Result<()> {
-        instructions::admin::initialize::handler(ctx, earn_authority)
+    pub fn initialize(
+        ctx: Context<Initialize>,
+        earn_authority: Pubkey,
+        yield_mode: YieldMode,
+    ) -> Result<()> {
+        instructions::admin::initialize::handler(ctx, earn_authority, yield_mode)
     }
 
     pub fn set_earn_authority(
@@ -53,16 +59,89 @@ pub mod ext_earn {
         instructions::admin::remove_earn_manager::handler(ctx)
     }
 
+    pub fn grant_role(ctx: Context<GrantRole>, role: Role, member: Pubkey) -> Result<()> {
+        instructions::admin::grant_role::handler(ctx, role, member)
+    }
+
+    pub fn revoke_role(ctx: Context<RevokeRole>, role: Role, member: Pubkey) -> Result<()> {
+        instructions::admin::revoke_role::handler(ctx, role, member)
+    }
+
+    pub fn set_removal_bounty(
+        ctx: Context<SetRemovalBounty>,
+        removal_bounty: u64,
+    ) -> Result<()> {
+        instructions::admin::set_removal_bounty::handler(ctx, removal_bounty)
+    }
+
+    pub fn set_yield_recipient(
+        ctx: Context<SetYieldRecipient>,
+        excess_buffer_bps: u64,
+        claims_timeout: u64,
+    ) -> Result<()> {
+        instructions::admin::set_yield_recipient::handler(ctx, excess_buffer_bps, claims_timeout)
+    }
+
+    pub fn set_wrap_fees(
+        ctx: Context<SetWrapFees>,
+        wrap_fee_bps: u64,
+        unwrap_fee_bps: u64,
+    ) -> Result<()> {
+        instructions::admin::set_wrap_fees::handler(ctx, wrap_fee_bps, unwrap_fee_bps)
+    }
+
+    pub fn set_allowlist(
+        ctx: Context<SetAllowlist>,
+        allowlist: Allowlist,
+        wrapper_merkle_root: [u8; 32],
+    ) -> Result<()> {
+        instructions::admin::set_allowlist::handler(ctx, allowlist, wrapper_merkle_root)
+    }
+
+    pub fn add_wrapper(ctx: Context<AddWrapper>, wrapper: Pubkey) -> Result<()> {
+        instructions::admin::add_wrapper::handler(ctx, wrapper)
+    }
+
+    pub fn remove_wrapper(ctx: Context<RemoveWrapper>) -> Result<()> {
+        instructions::admin::remove_wrapper::handler(ctx)
+    }
+
+    pub fn migrate_ext_global(ctx: Context<MigrateExtGlobal>) -> Result<()> {
+        instructions::admin::migrate_ext_global::handler(ctx)
+    }
+
+    pub fn migrate_legacy_global(
+        ctx: Context<MigrateLegacyGlobal>,
+        legacy_earners: u64,
+    ) -> Result<()> {
+        instructions::admin::migrate_legacy_global::handler(ctx, legacy_earners)
+    }
+
     // Earn authority instructions
 
     pub fn claim_for(ctx: Context<ClaimFor>, snapshot_balance: u64) -> Result<()> {
         instructions::earn_authority::claim_for::handler(ctx, snapshot_balance)
     }
 
+    pub fn claim_for_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimForBatch<'info>>,
+        snapshot_balances: Vec<u64>,
+    ) -> Result<()> {
+        instructions::earn_authority::claim_for_batch::handler(ctx, snapshot_balances)
+    }
+
+    pub fn claim_excess(ctx: Context<ClaimExcess>) -> Result<()> {
+        instructions::earn_authority::claim_excess::handler(ctx)
+    }
+
     pub fn sync(ctx: Context<Sync>) -> Result<()> {
         instructions::earn_authority::sync::handler(ctx)
     }
 
+    pub fn settle_fee(ctx: Context<SettleFee>, snapshot_balance: u64) -> Result<()> {
+        instructions::earn_authority::settle_fee::handler(ctx, snapshot_balance)
+    }
+
     // Earn manager instructions
 
     pub fn add_earner(ctx: Context<AddEarner>, user: Pubkey) -> Result<()> {
@@ -90,17 +169,52 @@ pub mod ext_earn {
         instructions::earner::set_recipient::handler(ctx)
     }
 
+    pub fn migrate_earner(ctx: Context<MigrateEarner>) -> Result<()> {
+        instructions::earner::migrate_earner::handler(ctx)
+    }
+
     // Open instructions
 
-    pub fn wrap(ctx: Context<Wrap>, amount: u64) -> Result<()> {
-        instructions::open::wrap::handler(ctx, amount)
+    pub fn wrap(
+        ctx: Context<Wrap>,
+        amount: u64,
+        proof: Option<Vec<ProofElement>>,
+    ) -> Result<()> {
+        instructions::open::wrap::handler(ctx, amount, proof)
     }
 
-    pub fn unwrap(ctx: Context<Unwrap>, amount: u64) -> Result<()> {
-        instructions::open::unwrap::handler(ctx, amount)
+    pub fn unwrap(
+        ctx: Context<Unwrap>,
+        amount: u64,
+        proof: Option<Vec<ProofElement>>,
+    ) -> Result<()> {
+        instructions::open::unwrap::handler(ctx, amount, proof)
     }
 
     pub fn remove_orphaned_earner(ctx: Context<RemoveOrphanedEarner>) -> Result<()> {
         instructions::open::remove_orphaned_earner::handler(ctx)
     }
+
+    pub fn preview_claim(
+        ctx: Context<PreviewClaim>,
+        snapshot_balance: u64,
+    ) -> Result<ClaimPreview> {
+        instructions::open::preview_claim::handler(ctx, snapshot_balance)
+    }
+
+    pub fn migrate_earner_account(ctx: Context<MigrateEarnerAccount>) -> Result<()> {
+        instructions::open::migrate_earner_account::handler(ctx)
+    }
+
+    pub fn migrate_earn_manager(ctx: Context<MigrateEarnManager>) -> Result<()> {
+        instructions::open::migrate_earn_manager::handler(ctx)
+    }
+
+    pub fn migrate_legacy_earn_manager(ctx: Context<MigrateLegacyEarnManager>) -> Result<()> {
+        instructions::open::migrate_legacy_earn_manager::handler(ctx)
+    }
+
+    pub fn migrate_legacy_earner(ctx: Context<MigrateLegacyEarner>) -> Result<()> {
+        instructions::open::migrate_legacy_earner::handler(ctx)
+    }
 }
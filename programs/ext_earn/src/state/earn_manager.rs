@@ -5,6 +5,10 @@ use anchor_lang::prelude::*;
 #[constant]
 pub const EARN_MANAGER_SEED: &[u8] = b"earn_manager";
 
+// Current layout version, older accounts must be upgraded with migrate_earn_manager
+#[constant]
+pub const EARN_MANAGER_VERSION: u8 = 1;
+
 #[account]
 #[derive(InitSpace)]
 pub struct EarnManager {
@@ -13,4 +17,7 @@ pub struct EarnManager {
     pub fee_bps: u64,
     pub fee_token_account: Pubkey,
     pub bump: u8,
+    pub version: u8,        // layout version of this account
+    pub rent_payer: Pubkey, // receives the rent when the account is closed, unset on older accounts
+    pub reserved: [u8; 32], // space for new fields without a realloc
 }
@@ -5,6 +5,11 @@ use anchor_lang::prelude::*;
 #[constant]
 pub const EARNER_SEED: &[u8] = b"earner";
 
+// Current layout version, older accounts must be upgraded with migrate_earner_account
+// Fields taken from the reserved space only need a new version if zero is not a valid default
+#[constant]
+pub const EARNER_VERSION: u8 = 1;
+
 #[account]
 #[derive(InitSpace)]
 pub struct Earner {
@@ -15,4 +20,11 @@ pub struct Earner {
     pub user_token_account: Pubkey,
     pub earn_manager: Pubkey,
     pub recipient_token_account: Option<Pubkey>,
+    pub total_rewards: u64,           // lifetime rewards claimed, net of fees
+    pub total_fees: u64,              // lifetime fees paid to the earn manager
+    pub claims: u64,                  // lifetime number of claims
+    pub first_earning_timestamp: u64, // timestamp of the index the earner started earning from
+    pub version: u8,                  // layout version of this account
+    pub rent_payer: Pubkey, // receives the rent when the account is closed, unset on older accounts
+    pub reserved: [u8; 32], // space for new fields without a realloc
 }
@@ -5,6 +5,11 @@ use anchor_lang::prelude::*;
 #[constant]
 pub const EXT_GLOBAL_SEED: &[u8] = b"global";
 
+// Bumped whenever the account grows or new fields need to be initialized by migrate_ext_global.
+// Fixed size fields are taken from the reserved space when zero is a valid default.
+#[constant]
+pub const EXT_GLOBAL_VERSION: u8 = 2;
+
 #[account]
 #[derive(InitSpace)]
 pub struct ExtGlobal {
@@ -18,4 +23,103 @@ pub struct ExtGlobal {
     pub bump: u8,
     pub m_vault_bump: u8,
     pub ext_mint_authority_bump: u8,
+    pub version: u8,                   // layout version of this account
+    pub removal_bounty: u64,           // lamports paid to whoever removes an orphaned earner
+    pub legacy: bool,                  // took over the accounts of the single extension layout
+    pub yield_recipient: Pubkey,       // m or ext token account receiving the excess collateral
+    pub excess_buffer_bps: u64, // share of the ext supply kept in the vault above full collateral
+    pub yield_mode: YieldMode,  // how yield reaches ext holders, fixed at initialize
+    pub base_index: u64,        // m index when a rebasing extension was initialized
+    pub wrap_fee_bps: u64,      // fee on wrapped m, paid to the fee token account
+    pub unwrap_fee_bps: u64,    // fee on unwrapped m, paid to the fee token account
+    pub fee_token_account: Pubkey, // m token account receiving wrap and unwrap fees
+    pub allowlist: Allowlist,   // who can wrap and unwrap
+    pub wrapper_merkle_root: [u8; 32], // permitted wrappers when the allowlist is a merkle root
+    pub earners: u64,           // number of earner accounts
+    pub claimed_earners: u64,   // earners that have claimed up to the current index
+    pub legacy_earners: u64,    // earners of the legacy extension that have not been migrated
+    pub claims_timeout: u64,    // seconds after an index update that claims block claim_excess
+    pub m_index: u64,           // m earn index the extension was last synced to
+    pub reserved: [u8; 24],     // space for new fields without a realloc
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum YieldMode {
+    Claim,    // yield is minted to earners by the earn authority
+    Rebasing, // every holder earns through the scaled ui amount extension of the ext mint
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum Allowlist {
+    Open,       // anyone can wrap and unwrap
+    Pda,        // wrappers need a wrapper account added by the admin
+    MerkleRoot, // wrappers need a proof against the wrapper merkle root
+}
+
+impl ExtGlobal {
+    /// Returns the ext amount minted when wrapping `amount` of m.
+    /// A rebasing ext token is worth index / base_index m.
+    pub fn m_to_ext(&self, amount: u64) -> u64 {
+        match self.yield_mode {
+            YieldMode::Claim => amount,
+            YieldMode::Rebasing => (amount as u128 * self.base_index as u128 / self.index as u128)
+                .try_into()
+                .unwrap(),
+        }
+    }
+
+    /// Returns the m paid out when unwrapping `amount` of ext, rounded down.
+    pub fn ext_to_m(&self, amount: u64) -> u64 {
+        match self.yield_mode {
+            YieldMode::Claim => amount,
+            YieldMode::Rebasing => (amount as u128 * self.index as u128 / self.base_index as u128)
+                .try_into()
+                .unwrap(),
+        }
+    }
+
+    /// Returns true if the extension has been synced to the m earn index.
+    /// A rebasing index can stay below the m earn index when the vault did not receive all of the yield.
+    pub fn is_synced(&self, m_earn_index: u64) -> bool {
+        self.index.max(self.m_index) >= m_earn_index
+    }
+
+    /// Counts a new earner account, it has nothing left to claim if it starts at the current index.
+    pub fn add_earner(&mut self, last_claim_index: u64) {
+        self.earners += 1;
+
+        if last_claim_index >= self.index {
+            self.claimed_earners += 1;
+        }
+    }
+
+    /// Stops counting a closed earner account.
+    pub fn remove_earner(&mut self, last_claim_index: u64) {
+        self.earners = self.earners.saturating_sub(1);
+
+        if last_claim_index >= self.index {
+            self.claimed_earners = self.claimed_earners.saturating_sub(1);
+        }
+    }
+
+    /// Returns true while some earners have not claimed the yield owed up to the current index,
+    /// or have not been migrated from the legacy extension, until the claims timeout has passed.
+    pub fn claims_pending(&self, now: u64) -> bool {
+        let pending = self.legacy_earners > 0 || self.claimed_earners < self.earners;
+
+        pending
+            && (self.claims_timeout == 0
+                || now < self.timestamp.saturating_add(self.claims_timeout))
+    }
+
+    /// Returns the m required to back an ext `supply`, rounded up.
+    pub fn collateral_for(&self, supply: u64) -> u64 {
+        match self.yield_mode {
+            YieldMode::Claim => supply,
+            YieldMode::Rebasing => (supply as u128 * self.index as u128)
+                .div_ceil(self.base_index as u128)
+                .try_into()
+                .unwrap(),
+        }
+    }
 }
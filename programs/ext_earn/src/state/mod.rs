@@ -3,10 +3,14 @@
 pub mod earn_manager;
 pub mod earner;
 pub mod global;
+pub mod roles;
+pub mod wrapper;
 
 pub use earn_manager::*;
 pub use earner::*;
 pub use global::*;
+pub use roles::*;
+pub use wrapper::*;
 
 use anchor_lang::prelude::*;
 
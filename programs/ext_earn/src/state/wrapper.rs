@@ -0,0 +1,14 @@
+// ext_earn/state/wrapper.rs
+
+use anchor_lang::prelude::*;
+
+#[constant]
+pub const WRAPPER_SEED: &[u8] = b"wrapper";
+
+// Permits a wrapper to wrap and unwrap when the allowlist uses accounts
+#[account]
+#[derive(InitSpace)]
+pub struct Wrapper {
+    pub wrapper: Pubkey,
+    pub bump: u8,
+}
@@ -0,0 +1,70 @@
+// ext_earn/utils/collateral.rs
+
+// local dependencies
+use crate::constants::ONE_HUNDRED_PERCENT;
+
+/// Returns the collateral required to back `supply` with `buffer_bps` on top.
+/// Supplies are valued in m, see ExtGlobal::collateral_for.
+pub fn required_collateral(supply: u64, buffer_bps: u64) -> u128 {
+    let supply = supply as u128;
+
+    supply + supply * buffer_bps as u128 / ONE_HUNDRED_PERCENT as u128
+}
+
+/// Returns the amount of m that can leave the vault while keeping the buffer.
+pub fn excess_to_transfer(collateral: u64, supply: u64, buffer_bps: u64) -> u64 {
+    (collateral as u128)
+        .saturating_sub(required_collateral(supply, buffer_bps))
+        .try_into()
+        .unwrap()
+}
+
+/// Returns the amount of ext tokens that can be minted against the vault while keeping the buffer.
+/// The buffer also applies to the newly minted tokens.
+pub fn excess_to_mint(collateral: u64, supply: u64, buffer_bps: u64) -> u64 {
+    let max_supply = (collateral as u128) * ONE_HUNDRED_PERCENT as u128
+        / (ONE_HUNDRED_PERCENT + buffer_bps) as u128;
+
+    // The buffer is rounded down, so the division can be off by a unit in either direction
+    let mut excess = max_supply.saturating_sub(supply as u128) as u64;
+
+    while excess > 0 && required_collateral(supply + excess, buffer_bps) > collateral as u128 {
+        excess -= 1;
+    }
+
+    while supply + excess < u64::MAX
+        && required_collateral(supply + excess + 1, buffer_bps) <= collateral as u128
+    {
+        excess += 1;
+    }
+
+    excess
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_excess_to_transfer() {
+        assert_eq!(excess_to_transfer(1_100, 1_000, 0), 100);
+        assert_eq!(excess_to_transfer(1_100, 1_000, 500), 50);
+        assert_eq!(excess_to_transfer(1_100, 1_000, 1_000), 0);
+        assert_eq!(excess_to_transfer(1_000, 1_100, 0), 0);
+    }
+
+    #[test]
+    fn test_excess_to_mint() {
+        assert_eq!(excess_to_mint(1_100, 1_000, 0), 100);
+        assert_eq!(excess_to_mint(1_050, 1_000, 500), 0);
+        assert_eq!(excess_to_mint(1_000, 1_100, 500), 0);
+        assert_eq!(excess_to_mint(u64::MAX, 0, 0), u64::MAX);
+
+        for (collateral, supply, buffer_bps) in [(1_234_567, 1_000_000, 333), (u64::MAX, 0, 1)] {
+            let excess = excess_to_mint(collateral, supply, buffer_bps);
+
+            assert!(required_collateral(supply + excess, buffer_bps) <= collateral as u128);
+            assert!(required_collateral(supply + excess + 1, buffer_bps) > collateral as u128);
+        }
+    }
+}
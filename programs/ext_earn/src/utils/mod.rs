@@ -1,3 +1,8 @@
 // ext_earn/utils/mod.rs
 
+pub mod collateral;
+pub mod rate;
+pub mod rewards;
+pub mod roles;
 pub mod token;
+pub mod wrapping;
@@ -0,0 +1,51 @@
+// ext_earn/utils/rate.rs
+
+/// Returns the multiplier of the scaled ui amount extension at `index`.
+/// A rebasing ext token is worth index / base_index m, so balances are displayed at that scale.
+pub fn multiplier(index: u64, base_index: u64) -> f64 {
+    index as f64 / base_index as f64
+}
+
+/// Returns the highest index, rounded down, at which `collateral` m backs an ext `supply`.
+/// The vault does not earn a full cycle of yield on m wrapped during the cycle, so it can
+/// back less than the m earn index.
+pub fn backed_index(collateral: u64, supply: u64, base_index: u64) -> u64 {
+    if supply == 0 {
+        return u64::MAX;
+    }
+
+    (collateral as u128 * base_index as u128 / supply as u128)
+        .try_into()
+        .unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multiplier() {
+        let base_index = 1_000_000_000_000;
+
+        assert_eq!(multiplier(base_index, base_index), 1.0);
+        assert_eq!(multiplier(1_100_000_000_000, base_index), 1.1);
+        assert_eq!(multiplier(1_250_000_000_000, base_index), 1.25);
+    }
+
+    #[test]
+    fn test_backed_index() {
+        let base_index = 1_000_000_000_000;
+
+        // The vault received the full yield on the supply
+        assert_eq!(backed_index(110, 100, base_index), 1_100_000_000_000);
+
+        // Part of the supply was wrapped during the cycle
+        assert_eq!(backed_index(105, 100, base_index), 1_050_000_000_000);
+
+        // Rounded down so the supply stays fully collateralized
+        assert_eq!(backed_index(100, 3, base_index), 33_333_333_333_333);
+
+        assert_eq!(backed_index(0, 0, base_index), u64::MAX);
+        assert_eq!(backed_index(u64::MAX, 1, base_index), u64::MAX);
+    }
+}
@@ -0,0 +1,81 @@
+// ext_earn/utils/rewards.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+use earn::utils::rewards::calculate_rewards;
+
+// local dependencies
+use crate::{
+    errors::ExtError,
+    state::{Earner, ExtGlobal},
+};
+
+/// Validates a claim for the current index and advances the earner to it, counting it as claimed.
+/// `ext_supply` must include anything already minted by the instruction so the collateral check is cumulative.
+/// Returns the rewards, before the earn manager fee, that must be minted.
+pub fn settle_claim(
+    global: &mut ExtGlobal,
+    earner: &mut Earner,
+    snapshot_balance: u64,
+    ext_supply: u64,
+    ext_collateral: u64,
+) -> Result<u64> {
+    // Validate that the earner account has not already claimed this cycle
+    // Earner index should never be > global index, but we check to be safe against an error with index propagation
+    if earner.last_claim_index >= global.index {
+        return err!(ExtError::AlreadyClaimed);
+    }
+
+    // Calculate the amount of tokens to send to the user
+    let rewards = calculate_rewards(snapshot_balance, earner.last_claim_index, global.index);
+
+    // Validate that the newly minted rewards will not make the extension undercollateralized
+    if ext_supply.checked_add(rewards).unwrap() > ext_collateral {
+        return err!(ExtError::InsufficientCollateral);
+    }
+
+    advance_earner(global, earner);
+    global.claimed_earners += 1;
+
+    Ok(rewards)
+}
+
+/// Validates a fee settlement of a rebasing extension for the current index and advances the earner to it.
+/// Returns the yield earned by the snapshot balance since the last settlement, in ext tokens at the current index.
+pub fn settle_rebasing_claim(
+    global: &ExtGlobal,
+    earner: &mut Earner,
+    snapshot_balance: u64,
+) -> Result<u64> {
+    if earner.last_claim_index >= global.index {
+        return err!(ExtError::AlreadyClaimed);
+    }
+
+    // The balance grew by index / last claim index in m, which is worth
+    // (index - last claim index) / index of it in ext tokens at the current index
+    let rewards = (snapshot_balance as u128 * (global.index - earner.last_claim_index) as u128
+        / global.index as u128) as u64;
+
+    advance_earner(global, earner);
+
+    Ok(rewards)
+}
+
+// Sets the earner's last claim index and timestamp to the ones of the extension.
+fn advance_earner(global: &ExtGlobal, earner: &mut Earner) {
+    // Earners created before lifetime stats were tracked have no first earning timestamp,
+    // they started earning no later than their last claim
+    if earner.first_earning_timestamp == 0 {
+        earner.first_earning_timestamp = earner.last_claim_timestamp;
+    }
+
+    earner.last_claim_index = global.index;
+    earner.last_claim_timestamp = global.timestamp;
+}
+
+/// Updates the earner's lifetime stats with the amounts minted for a claim.
+pub fn record_claim(earner: &mut Earner, rewards: u64, fee: u64) {
+    earner.total_rewards += rewards;
+    earner.total_fees += fee;
+    earner.claims += 1;
+}
@@ -0,0 +1,39 @@
+// ext_earn/utils/roles.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+
+// local dependencies
+use crate::state::{ExtGlobal, Role, Roles};
+
+/// Returns the members of `role` stored on the roles account.
+pub fn role_members(roles: &mut Roles, role: Role) -> &mut Vec<Pubkey> {
+    match role {
+        Role::EarnAuthority => &mut roles.earn_authorities,
+        Role::EarnManagerAdmin => &mut roles.earn_manager_admins,
+    }
+}
+
+/// Returns true if `key` is the global earn authority or a member of the earn authority role.
+pub fn is_earn_authority(
+    global: &ExtGlobal,
+    roles: &Option<Account<Roles>>,
+    key: &Pubkey,
+) -> bool {
+    global.earn_authority == *key
+        || roles
+            .as_ref()
+            .is_some_and(|roles| roles.earn_authorities.contains(key))
+}
+
+/// Returns true if `key` is the admin or a member of the earn manager admin role.
+pub fn is_earn_manager_admin(
+    global: &ExtGlobal,
+    roles: &Option<Account<Roles>>,
+    key: &Pubkey,
+) -> bool {
+    global.admin == *key
+        || roles
+            .as_ref()
+            .is_some_and(|roles| roles.earn_manager_admins.contains(key))
+}
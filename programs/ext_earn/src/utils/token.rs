@@ -2,8 +2,17 @@
 
 // external dependencies
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{
-    burn, mint_to, transfer_checked, Burn, Mint, MintTo, Token2022, TokenAccount, TransferChecked,
+use anchor_lang::solana_program::{instruction::Instruction, program::invoke_signed};
+use anchor_spl::{
+    token_2022::spl_token_2022::{
+        extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+        instruction::AuthorityType,
+        state::Mint as MintState,
+    },
+    token_interface::{
+        burn, mint_to, set_authority, transfer_checked, Burn, Mint, MintTo, SetAuthority,
+        Token2022, TokenAccount, TransferChecked,
+    },
 };
 
 pub fn transfer_tokens_from_program<'info>(
@@ -107,3 +116,105 @@ pub fn burn_tokens<'info>(
 
     Ok(())
 }
+
+pub fn set_mint_authority<'info>(
+    mint: &InterfaceAccount<'info, Mint>,
+    new_authority: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    authority_seeds: &[&[&[u8]]],
+    token_program: &Program<'info, Token2022>,
+) -> Result<()> {
+    // Build the arguments for the set authority instruction
+    let set_authority_options = SetAuthority {
+        current_authority: authority.clone(),
+        account_or_mint: mint.to_account_info(),
+    };
+
+    let cpi_context = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        set_authority_options,
+        authority_seeds,
+    );
+
+    // Call the set authority instruction
+    set_authority(
+        cpi_context,
+        AuthorityType::MintTokens,
+        Some(new_authority.key()),
+    )?;
+
+    Ok(())
+}
+
+pub fn update_multiplier<'info>(
+    mint: &InterfaceAccount<'info, Mint>,
+    multiplier: f64,
+    effective_timestamp: i64,
+    authority: &AccountInfo<'info>,
+    authority_seeds: &[&[&[u8]]],
+    token_program: &Program<'info, Token2022>,
+) -> Result<()> {
+    // The scaled ui amount extension is newer than the spl_token_2022 version used by anchor_spl,
+    // so the instruction is built by hand: extension instruction, update multiplier, multiplier, timestamp
+    let mut data = vec![SCALED_UI_AMOUNT_INSTRUCTION, UPDATE_MULTIPLIER_INSTRUCTION];
+    data.extend_from_slice(&multiplier.to_le_bytes());
+    data.extend_from_slice(&effective_timestamp.to_le_bytes());
+
+    let instruction = Instruction {
+        program_id: token_program.key(),
+        accounts: vec![
+            AccountMeta::new(mint.key(), false),
+            AccountMeta::new_readonly(authority.key(), true),
+        ],
+        data,
+    };
+
+    invoke_signed(
+        &instruction,
+        &[mint.to_account_info(), authority.clone()],
+        authority_seeds,
+    )?;
+
+    Ok(())
+}
+
+/// Returns the multiplier authority of the scaled ui amount extension, if the mint has one.
+pub fn scaled_ui_amount_authority(mint: &InterfaceAccount<Mint>) -> Option<Pubkey> {
+    mint_extension_authority(mint, SCALED_UI_AMOUNT_EXTENSION)
+}
+
+/// Returns the permanent delegate of the mint, if it has one.
+pub fn permanent_delegate(mint: &InterfaceAccount<Mint>) -> Option<Pubkey> {
+    mint_extension_authority(mint, ExtensionType::PermanentDelegate as u16)
+}
+
+// Token-2022 discriminators of the scaled ui amount extension
+const SCALED_UI_AMOUNT_EXTENSION: u16 = 25;
+const SCALED_UI_AMOUNT_INSTRUCTION: u8 = 43;
+const UPDATE_MULTIPLIER_INSTRUCTION: u8 = 1;
+
+// Returns the authority stored at the start of a mint extension, zero meaning there is none.
+// The extensions are walked by hand because spl_token_2022 fails on extension types it doesn't know.
+fn mint_extension_authority(mint: &InterfaceAccount<Mint>, extension_type: u16) -> Option<Pubkey> {
+    let account_info = mint.to_account_info();
+    let data = account_info.data.borrow();
+
+    let mint = StateWithExtensions::<MintState>::unpack(&data).ok()?;
+    let mut tlv_data = mint.get_tlv_data();
+
+    // Each extension is its type and length as u16s followed by its value
+    while tlv_data.len() >= 4 {
+        let value_type = u16::from_le_bytes([tlv_data[0], tlv_data[1]]);
+        let length = u16::from_le_bytes([tlv_data[2], tlv_data[3]]) as usize;
+        let value = tlv_data.get(4..4 + length)?;
+
+        if value_type == extension_type {
+            let authority = Pubkey::try_from(value.get(..32)?).ok()?;
+            return (authority != Pubkey::default()).then_some(authority);
+        }
+
+        tlv_data = &tlv_data[4 + length..];
+    }
+
+    None
+}
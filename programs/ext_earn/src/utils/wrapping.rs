@@ -0,0 +1,61 @@
+// ext_earn/utils/wrapping.rs
+
+// external dependencies
+use anchor_lang::prelude::*;
+use earn::utils::merkle_proof::{verify_in_tree, ProofElement};
+
+// local dependencies
+use crate::{
+    constants::ONE_HUNDRED_PERCENT,
+    errors::ExtError,
+    state::{Allowlist, ExtGlobal, Wrapper},
+};
+
+/// Validates that `wrapper` can wrap and unwrap under the allowlist of the extension.
+/// The wrapper account is expected to be validated by its seeds.
+pub fn check_wrapper(
+    global: &ExtGlobal,
+    wrapper: &Pubkey,
+    wrapper_account: &Option<Account<Wrapper>>,
+    proof: Option<Vec<ProofElement>>,
+) -> Result<()> {
+    match global.allowlist {
+        Allowlist::Open => Ok(()),
+        Allowlist::Pda => match wrapper_account {
+            Some(_) => Ok(()),
+            None => err!(ExtError::NotAuthorized),
+        },
+        Allowlist::MerkleRoot => {
+            let proof = proof.ok_or(ExtError::NotAuthorized)?;
+            verify_in_tree(global.wrapper_merkle_root, wrapper.to_bytes(), proof)?;
+
+            Ok(())
+        }
+    }
+}
+
+/// Returns the fee charged on `amount` of m, rounded down.
+pub fn wrap_fee(amount: u64, fee_bps: u64) -> u64 {
+    (amount as u128 * fee_bps as u128 / ONE_HUNDRED_PERCENT as u128) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_fee() {
+        assert_eq!(wrap_fee(1_000_000, 0), 0);
+        assert_eq!(wrap_fee(1_000_000, 25), 2_500);
+        assert_eq!(wrap_fee(1_000_000, ONE_HUNDRED_PERCENT), 1_000_000);
+
+        // Rounded down in favor of the user
+        assert_eq!(wrap_fee(399, 25), 0);
+        assert_eq!(wrap_fee(400, 25), 1);
+        assert_eq!(wrap_fee(12_345, 100), 123);
+
+        // No overflow on large amounts
+        assert_eq!(wrap_fee(u64::MAX, ONE_HUNDRED_PERCENT), u64::MAX);
+        assert_eq!(wrap_fee(u64::MAX, 5_000), u64::MAX / 2);
+    }
+}
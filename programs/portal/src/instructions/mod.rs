@@ -21,3 +21,12 @@ pub struct BridgeEvent {
     pub to: [u8; 32],
     pub wormhole_chain_id: u16,
 }
+
+// Earner root that was older than the root of the earn program, e.g. one without a source timestamp
+// sent after a timestamped root. The index is still propagated without it.
+#[event]
+pub struct EarnerRootDropped {
+    pub index: u64,
+    pub earner_root: [u8; 32],
+    pub timestamp: u64,
+}
@@ -10,7 +10,7 @@ use crate::{
     payloads::Payload,
     peer::NttManagerPeer,
     queue::{
-        inbox::{InboxItem, InboxRateLimit, ReleaseStatus, Source, TokenTransfer},
+        inbox::{InboxItem, InboxRateLimit, ReleaseStatus, RootTimestamp, Source, TokenTransfer},
         outbox::OutboxRateLimit,
         rate_limit::RateLimitResult,
     },
@@ -128,6 +128,7 @@ pub fn redeem(ctx: Context<Redeem>, _args: RedeemArgs) -> Result<()> {
                 chain: transceiver_message.from_chain,
                 from: message.sender,
             },
+            earners_root_timestamp: RootTimestamp::default(),
         };
 
         match &message.payload {
@@ -151,6 +152,8 @@ pub fn redeem(ctx: Context<Redeem>, _args: RedeemArgs) -> Result<()> {
                 // payloads from mainnet might have merkle root updates
                 if payload.earner_root.is_some() {
                     inbox_item.earners_root_update = Some(payload.earner_root.unwrap());
+                    inbox_item.earners_root_timestamp =
+                        RootTimestamp(payload.earner_root_timestamp.unwrap_or_default());
                 }
             }
         };
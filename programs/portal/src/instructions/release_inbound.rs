@@ -1,6 +1,10 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{associated_token::get_associated_token_address_with_program_id, token_interface};
-use earn::cpi::accounts::PropagateIndex;
+use earn::{
+    cpi::accounts::PropagateIndex,
+    instructions::propagate_index::is_stale_earner_root,
+    state::{Global as EarnGlobal, PENDING_INDEX_SEED as EARN_PENDING_INDEX_SEED},
+};
 use ntt_messages::mode::Mode;
 use solana_program::program::invoke_signed;
 use spl_token_2022::onchain;
@@ -8,7 +12,7 @@ use spl_token_2022::onchain;
 use crate::{
     config::*,
     error::NTTError,
-    instructions::BridgeEvent,
+    instructions::{BridgeEvent, EarnerRootDropped},
     queue::inbox::{InboxItem, ReleaseStatus},
     spl_multisig::SplMultisig,
 };
@@ -164,23 +168,63 @@ pub fn release_inbound_mint_multisig<'info>(
             }
         }
 
+        let mut earner_root = inbox_item.earners_root_update.unwrap_or_default();
+        let earner_root_timestamp = inbox_item.earners_root_timestamp.0;
+
+        // The earn program rejects stale roots, drop them so that the transfer is still released.
+        // After an upgrade the earn global account can't be deserialized until the earn program
+        // migrates it, it has no root timestamps at that point so no root is stale.
+        if let Ok(earn_global) =
+            EarnGlobal::try_deserialize(&mut &ctx.remaining_accounts[1].try_borrow_data()?[..])
+        {
+            if is_stale_earner_root(
+                &earn_global,
+                inbox_item.index_update,
+                earner_root,
+                earner_root_timestamp,
+            ) {
+                msg!("Dropping stale earner root");
+                emit!(EarnerRootDropped {
+                    index: inbox_item.index_update,
+                    earner_root,
+                    timestamp: earner_root_timestamp,
+                });
+                earner_root = [0u8; 32];
+            }
+        }
+
+        // Out of bounds updates are quarantined in the pending index account of the earn program,
+        // it is passed after the expected accounts once the index bounds are set
+        let pending_index_address =
+            Pubkey::find_program_address(&[EARN_PENDING_INDEX_SEED], &earn::ID).0;
+        let pending_index_account = ctx
+            .remaining_accounts
+            .get(expected_accounts.len())
+            .filter(|account| account.key() == pending_index_address)
+            .cloned();
+
         let ctx = CpiContext::new_with_signer(
             ctx.remaining_accounts[0].clone(),
             PropagateIndex {
                 signer: ctx.accounts.common.token_authority.to_account_info(),
                 global_account: ctx.remaining_accounts[1].clone(),
                 mint: ctx.accounts.common.mint.to_account_info(),
+                pending_index_account,
             },
             token_authority_sig,
         );
 
-        let earner_root = inbox_item.earners_root_update.unwrap_or_default();
-        earn::cpi::propagate_index(ctx, inbox_item.index_update, earner_root)?;
+        earn::cpi::propagate_index(
+            ctx,
+            inbox_item.index_update,
+            earner_root,
+            earner_root_timestamp,
+        )?;
 
         msg!(
             "Index update: {} | root update: {}",
             inbox_item.index_update,
-            inbox_item.earners_root_update.is_some()
+            earner_root != [0u8; 32]
         );
     }
 
@@ -47,6 +47,11 @@ impl Readable for NativeTokenTransfer {
             additional_payload.earner_root = Some(Readable::read(reader)?);
         }
 
+        // Source timestamp of the earner root, used to order roots on the earn program
+        if payload_len >= 80 {
+            additional_payload.earner_root_timestamp = Some(Readable::read(reader)?);
+        }
+
         Ok(Self {
             amount,
             source_token,
@@ -99,6 +104,7 @@ pub struct AdditionalPayload {
     pub index: u64,
     pub destination_token: [u8; 32], // address of the token (M or Wrapped M) on the destination chain
     pub earner_root: Option<[u8; 32]>,
+    pub earner_root_timestamp: Option<u64>,
 }
 
 impl AdditionalPayload {
@@ -107,6 +113,7 @@ impl AdditionalPayload {
             index: 0,
             destination_token,
             earner_root: None,
+            earner_root_timestamp: None,
         }
     }
 }
@@ -117,6 +124,10 @@ impl Writeable for AdditionalPayload {
 
         if self.earner_root.is_some() {
             size += self.earner_root.unwrap().len();
+
+            if self.earner_root_timestamp.is_some() {
+                size += u64::SIZE.unwrap();
+            }
         }
 
         size
@@ -131,8 +142,99 @@ impl Writeable for AdditionalPayload {
 
         if self.earner_root.is_some() {
             self.earner_root.unwrap().write(writer)?;
+
+            if self.earner_root_timestamp.is_some() {
+                self.earner_root_timestamp.unwrap().write(writer)?;
+            }
         }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transfer(additional_payload: AdditionalPayload) -> NativeTokenTransfer {
+        NativeTokenTransfer {
+            amount: TrimmedAmount::new(10_000, 8),
+            source_token: [1; 32],
+            to: [2; 32],
+            to_chain: ChainId { id: 1 },
+            additional_payload,
+        }
+    }
+
+    // Writes the transfer and reads it back without the prefix, like Payload does
+    fn roundtrip(ntt: &NativeTokenTransfer) -> (u16, NativeTokenTransfer) {
+        let mut bytes = Vec::new();
+        ntt.write(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), ntt.written_size());
+
+        let payload_len_offset = 4 + TrimmedAmount::SIZE.unwrap() + 64 + ChainId::SIZE.unwrap();
+        let payload_len = u16::from_be_bytes([
+            bytes[payload_len_offset],
+            bytes[payload_len_offset + 1],
+        ]);
+
+        let read = NativeTokenTransfer::read(&mut &bytes[4..]).unwrap();
+        (payload_len, read)
+    }
+
+    #[test]
+    fn test_payload_without_earner_root() {
+        let ntt = transfer(AdditionalPayload {
+            index: 1_000_000_000_001,
+            destination_token: [3; 32],
+            earner_root: None,
+            earner_root_timestamp: None,
+        });
+
+        let (payload_len, read) = roundtrip(&ntt);
+        assert_eq!(payload_len, 40);
+        assert_eq!(read, ntt);
+    }
+
+    #[test]
+    fn test_payload_with_earner_root() {
+        let ntt = transfer(AdditionalPayload {
+            index: 1_000_000_000_001,
+            destination_token: [3; 32],
+            earner_root: Some([4; 32]),
+            earner_root_timestamp: None,
+        });
+
+        let (payload_len, read) = roundtrip(&ntt);
+        assert_eq!(payload_len, 72);
+        assert_eq!(read, ntt);
+    }
+
+    #[test]
+    fn test_payload_with_earner_root_timestamp() {
+        let ntt = transfer(AdditionalPayload {
+            index: 1_000_000_000_001,
+            destination_token: [3; 32],
+            earner_root: Some([4; 32]),
+            earner_root_timestamp: Some(1_700_000_000),
+        });
+
+        let (payload_len, read) = roundtrip(&ntt);
+        assert_eq!(payload_len, 80);
+        assert_eq!(read, ntt);
+    }
+
+    #[test]
+    fn test_payload_timestamp_requires_earner_root() {
+        let ntt = transfer(AdditionalPayload {
+            index: 1_000_000_000_001,
+            destination_token: [3; 32],
+            earner_root: None,
+            earner_root_timestamp: Some(1_700_000_000),
+        });
+
+        let (payload_len, read) = roundtrip(&ntt);
+        assert_eq!(payload_len, 40);
+        assert_eq!(read.additional_payload.earner_root_timestamp, None);
+    }
+}
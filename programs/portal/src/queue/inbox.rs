@@ -1,4 +1,7 @@
-use std::ops::{Deref, DerefMut};
+use std::{
+    io,
+    ops::{Deref, DerefMut},
+};
 
 use anchor_lang::prelude::*;
 use ntt_messages::chain_id::ChainId;
@@ -20,6 +23,36 @@ pub struct InboxItem {
     pub index_update: u64,
     pub earners_root_update: Option<[u8; 32]>,
     pub source: Source,
+    pub earners_root_timestamp: RootTimestamp,
+}
+
+/// Source timestamp of the earner root. Items redeemed before it was added have no room
+/// for it, so it is decoded as 0 when the account ends early and only written when set.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RootTimestamp(pub u64);
+
+impl Space for RootTimestamp {
+    const INIT_SPACE: usize = 8;
+}
+
+impl AnchorSerialize for RootTimestamp {
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        if self.0 != 0 {
+            self.0.serialize(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl AnchorDeserialize for RootTimestamp {
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        let mut bytes = [0u8; 8];
+        match reader.read_exact(&mut bytes) {
+            Ok(()) => Ok(Self(u64::from_le_bytes(bytes))),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => Ok(Self(0)),
+            Err(e) => Err(e),
+        }
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, Default)]
@@ -100,3 +133,51 @@ impl DerefMut for InboxRateLimit {
         &mut self.rate_limit
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Layout of the fields that can precede the timestamp with variable length
+    #[derive(AnchorSerialize, AnchorDeserialize)]
+    struct Tail {
+        earners_root_update: Option<[u8; 32]>,
+        earners_root_timestamp: RootTimestamp,
+    }
+
+    #[test]
+    fn test_root_timestamp_missing() {
+        // Items redeemed before the timestamp can end right after the previous field
+        let legacy = Some([1u8; 32]).try_to_vec().unwrap();
+        let tail = Tail::deserialize(&mut &legacy[..]).unwrap();
+        assert_eq!(tail.earners_root_timestamp, RootTimestamp(0));
+
+        // and must still fit in their account when written back
+        let mut data = vec![0u8; legacy.len()];
+        tail.serialize(&mut &mut data[..]).unwrap();
+        assert_eq!(data, legacy);
+    }
+
+    #[test]
+    fn test_root_timestamp_padding() {
+        // Shorter variants leave zeroed space at the end of the account
+        let mut legacy = None::<[u8; 32]>.try_to_vec().unwrap();
+        legacy.resize(33, 0);
+        let tail = Tail::deserialize(&mut &legacy[..]).unwrap();
+        assert_eq!(tail.earners_root_timestamp, RootTimestamp(0));
+    }
+
+    #[test]
+    fn test_root_timestamp_roundtrip() {
+        let tail = Tail {
+            earners_root_update: Some([1u8; 32]),
+            earners_root_timestamp: RootTimestamp(1_700_000_000),
+        };
+
+        let mut data = vec![0u8; 33 + RootTimestamp::INIT_SPACE];
+        tail.serialize(&mut &mut data[..]).unwrap();
+
+        let read = Tail::deserialize(&mut &data[..]).unwrap();
+        assert_eq!(read.earners_root_timestamp, RootTimestamp(1_700_000_000));
+    }
+}
@@ -1,6 +1,6 @@
 import { Connection, PublicKey } from '@solana/web3.js';
 import BN from 'bn.js';
-import { PROGRAM_ID } from '.';
+import { EXT_MINT, getExtGlobalAccount, PROGRAM_ID } from '.';
 import { getProgram, getExtProgram } from './idl';
 
 export interface EarnManagerData {
@@ -22,6 +22,8 @@ export interface GlobalAccountData {
   maxYield?: BN;
   distributed?: BN;
   claimComplete?: boolean;
+  completedCycle?: { index: BN; completedAt: BN };
+  completedCycleRecorded?: boolean;
 }
 
 export interface EarnerData {
@@ -34,13 +36,16 @@ export interface EarnerData {
   recipientTokenAccount?: PublicKey | null;
 }
 
-export async function loadGlobal(connection: Connection, program = PROGRAM_ID): Promise<GlobalAccountData> {
-  const [globalAccount] = PublicKey.findProgramAddressSync([Buffer.from('global')], program);
-
+export async function loadGlobal(
+  connection: Connection,
+  program = PROGRAM_ID,
+  extMint = EXT_MINT,
+): Promise<GlobalAccountData> {
   if (program.equals(PROGRAM_ID)) {
+    const [globalAccount] = PublicKey.findProgramAddressSync([Buffer.from('global')], program);
     return await getProgram(connection).account.global.fetch(globalAccount);
   } else {
-    const extGlobal = await getExtProgram(connection).account.extGlobal.fetch(globalAccount);
+    const extGlobal = await getExtProgram(connection).account.extGlobal.fetch(getExtGlobalAccount(extMint));
     return { ...extGlobal, mint: extGlobal.extMint, underlyingMint: extGlobal.mMint };
   }
 }
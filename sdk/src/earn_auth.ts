@@ -1,6 +1,17 @@
-import { Connection, TransactionInstruction, PublicKey, VersionedTransaction } from '@solana/web3.js';
+import { Connection, TransactionInstruction, PublicKey, SystemProgram, VersionedTransaction } from '@solana/web3.js';
 import { PublicClient } from 'viem';
-import { getApiClient, EXT_GLOBAL_ACCOUNT, EXT_PROGRAM_ID, GLOBAL_ACCOUNT, PROGRAM_ID, TransactionBuilder } from '.';
+import {
+  getApiClient,
+  getExtEarnerAccount,
+  getExtEarnManagerAccount,
+  getExtGlobalAccount,
+  getExtMVault,
+  EXT_MINT,
+  EXT_PROGRAM_ID,
+  GLOBAL_ACCOUNT,
+  PROGRAM_ID,
+  TransactionBuilder,
+} from '.';
 import { Earner } from './earner';
 import { EarnManager } from './earn_manager';
 import { GlobalAccountData, loadGlobal } from './accounts';
@@ -48,8 +59,9 @@ export class EarnAuthority {
     evmClient: PublicClient,
     program = PROGRAM_ID,
     logger: Logger = new MockLogger(),
+    extMint = EXT_MINT,
   ): Promise<EarnAuthority> {
-    let global = await loadGlobal(connection, program);
+    let global = await loadGlobal(connection, program, extMint);
 
     // get mint multisig
     const mint = await spl.getMint(connection, global.mint, connection.commitment, spl.TOKEN_2022_PROGRAM_ID);
@@ -58,7 +70,7 @@ export class EarnAuthority {
   }
 
   async refresh(): Promise<void> {
-    this.global = await loadGlobal(this.connection, this.programID);
+    this.global = await loadGlobal(this.connection, this.programID, this.global.mint);
   }
 
   public get latestIndex(): BN {
@@ -84,11 +96,46 @@ export class EarnAuthority {
       return null;
     }
 
+    // The final state of the cycle is recorded in an account seeded by its index
+    const [claimCycleAccount] = PublicKey.findProgramAddressSync(
+      [Buffer.from('claim_cycle'), this.global.index.toArrayLike(Buffer, 'le', 8)],
+      PROGRAM_ID,
+    );
+
     return await (this.program as Program<Earn>).methods
       .completeClaims()
       .accounts({
         earnAuthority: new PublicKey(this.global.earnAuthority),
         globalAccount: PublicKey.findProgramAddressSync([Buffer.from('global')], PROGRAM_ID)[0],
+        claimCycleAccount,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction();
+  }
+
+  async buildRecordClaimCycleInstruction(): Promise<TransactionInstruction | null> {
+    if (!this.programID.equals(PROGRAM_ID)) {
+      return null;
+    }
+
+    // A cycle that timed out has to be recorded before the next cycle can complete
+    const cycle = this.global.completedCycle;
+    if (!cycle || cycle.completedAt.isZero() || this.global.completedCycleRecorded) {
+      return null;
+    }
+
+    const [claimCycleAccount] = PublicKey.findProgramAddressSync(
+      [Buffer.from('claim_cycle'), cycle.index.toArrayLike(Buffer, 'le', 8)],
+      PROGRAM_ID,
+    );
+
+    return await (this.program as Program<Earn>).methods
+      .recordClaimCycle()
+      .accounts({
+        signer: new PublicKey(this.global.earnAuthority),
+        globalAccount: PublicKey.findProgramAddressSync([Buffer.from('global')], PROGRAM_ID)[0],
+        claimCycleAccount,
+        systemProgram: SystemProgram.programId,
       })
       .instruction();
   }
@@ -139,28 +186,27 @@ export class EarnAuthority {
       .mul(earner.data.lastClaimIndex)
       .div(this.global.index.sub(earner.data.lastClaimIndex));
 
-    // PDAs
-    const [earnerAccount] = PublicKey.findProgramAddressSync(
-      [Buffer.from('earner'), earner.data.userTokenAccount.toBuffer()],
-      this.programID,
-    );
-
     if (this.programID.equals(EXT_PROGRAM_ID)) {
+      // PDAs, keyed by the extension mint
+      const earnerAccount = getExtEarnerAccount(earner.data.userTokenAccount, this.global.mint);
+
       // get manager (manager fee token account)
       let manager = this.managerCache.get(earner.data.earnManager!);
       if (!manager) {
-        manager = await EarnManager.fromManagerAddress(this.connection, this.evmClient, earner.data.earnManager!);
+        manager = await EarnManager.fromManagerAddress(
+          this.connection,
+          this.evmClient,
+          earner.data.earnManager!,
+          this.global.mint,
+        );
         this.managerCache.set(earner.data.earnManager!, manager);
       }
 
       const earnManagerTokenAccount = manager.data.feeTokenAccount;
-      const earnManagerAccount = PublicKey.findProgramAddressSync(
-        [Buffer.from('earn_manager'), earner.data.earnManager!.toBytes()],
-        this.programID,
-      )[0];
+      const earnManagerAccount = getExtEarnManagerAccount(earner.data.earnManager!, this.global.mint);
 
       // vault PDAs
-      const [mVaultAccount] = PublicKey.findProgramAddressSync([Buffer.from('m_vault')], this.programID);
+      const mVaultAccount = getExtMVault(this.global.mint);
       const vaultMTokenAccount = spl.getAssociatedTokenAddressSync(
         this.global.underlyingMint!,
         mVaultAccount,
@@ -172,7 +218,7 @@ export class EarnAuthority {
         .claimFor(claimBalance)
         .accounts({
           earnAuthority: this.global.earnAuthority,
-          globalAccount: EXT_GLOBAL_ACCOUNT,
+          globalAccount: getExtGlobalAccount(this.global.mint),
           extMint: this.global.mint,
           extMintAuthority: this.mintAuth,
           mVaultAccount,
@@ -185,6 +231,10 @@ export class EarnAuthority {
         })
         .instruction();
     } else {
+      const [earnerAccount] = PublicKey.findProgramAddressSync(
+        [Buffer.from('earner'), earner.data.userTokenAccount.toBuffer()],
+        PROGRAM_ID,
+      );
       const [tokenAuthorityAccount] = PublicKey.findProgramAddressSync([Buffer.from('token_authority')], PROGRAM_ID);
 
       return (this.program as Program<Earn>).methods
@@ -194,7 +244,7 @@ export class EarnAuthority {
           globalAccount: GLOBAL_ACCOUNT,
           mint: new PublicKey(this.global.mint),
           tokenAuthorityAccount,
-          userTokenAccount: earner.data.userTokenAccount,
+          userTokenAccount: earner.data.recipientTokenAccount ?? earner.data.userTokenAccount,
           earnerAccount,
           tokenProgram: spl.TOKEN_2022_PROGRAM_ID,
           mintMultisig: this.mintAuth,
@@ -271,7 +321,7 @@ export class EarnAuthority {
       // vault balance
       const vaultMTokenAccount = spl.getAssociatedTokenAddressSync(
         this.global.underlyingMint!,
-        PublicKey.findProgramAddressSync([Buffer.from('m_vault')], this.programID)[0],
+        getExtMVault(this.global.mint),
         true,
         spl.TOKEN_2022_PROGRAM_ID,
       );
@@ -306,7 +356,7 @@ export class EarnAuthority {
       .sync()
       .accounts({
         earnAuthority: this.global.earnAuthority,
-        globalAccount: EXT_GLOBAL_ACCOUNT,
+        globalAccount: getExtGlobalAccount(this.global.mint),
         mEarnGlobalAccount: GLOBAL_ACCOUNT,
       })
       .instruction();
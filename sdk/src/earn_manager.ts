@@ -3,7 +3,7 @@ import BN from 'bn.js';
 import * as spl from '@solana/spl-token';
 import { PublicClient } from 'viem';
 
-import { EXT_GLOBAL_ACCOUNT, EXT_MINT, EXT_PROGRAM_ID } from '.';
+import { EXT_MINT, getExtEarnerAccount, getExtEarnManagerAccount, getExtGlobalAccount } from '.';
 import { Earner } from './earner';
 import { Program } from '@coral-xyz/anchor';
 import { getExtProgram } from './idl';
@@ -18,6 +18,7 @@ export class EarnManager {
   manager: PublicKey;
   pubkey: PublicKey;
   data: EarnManagerData;
+  extMint: PublicKey;
 
   constructor(
     connection: Connection,
@@ -25,6 +26,7 @@ export class EarnManager {
     manager: PublicKey,
     pubkey: PublicKey,
     data: EarnManagerData,
+    extMint: PublicKey = EXT_MINT,
   ) {
     this.connection = connection;
     this.program = getExtProgram(connection);
@@ -32,39 +34,35 @@ export class EarnManager {
     this.manager = manager;
     this.pubkey = pubkey;
     this.data = data;
+    this.extMint = extMint;
   }
 
   static async fromManagerAddress(
     connection: Connection,
     evmClient: PublicClient,
     manager: PublicKey,
+    extMint: PublicKey = EXT_MINT,
   ): Promise<EarnManager> {
-    const [earnManagerAccount] = PublicKey.findProgramAddressSync(
-      [Buffer.from('earn_manager'), manager.toBytes()],
-      EXT_PROGRAM_ID,
-    );
+    const earnManagerAccount = getExtEarnManagerAccount(manager, extMint);
 
     const data = await getExtProgram(connection).account.earnManager.fetch(earnManagerAccount);
 
-    return new EarnManager(connection, evmClient, manager, earnManagerAccount, data);
+    return new EarnManager(connection, evmClient, manager, earnManagerAccount, data, extMint);
   }
 
   async refresh() {
-    const updated = await EarnManager.fromManagerAddress(this.connection, this.evmClient, this.manager);
+    const updated = await EarnManager.fromManagerAddress(this.connection, this.evmClient, this.manager, this.extMint);
     Object.assign(this, updated);
   }
 
   async buildConfigureInstruction(feeBPS: number, feeTokenAccount: PublicKey): Promise<TransactionInstruction> {
-    const [earnManagerAccount] = PublicKey.findProgramAddressSync(
-      [Buffer.from('earn_manager'), this.manager.toBytes()],
-      EXT_PROGRAM_ID,
-    );
+    const earnManagerAccount = getExtEarnManagerAccount(this.manager, this.extMint);
 
     return this.program.methods
       .configureEarnManager(new BN(feeBPS))
       .accounts({
         signer: this.manager,
-        globalAccount: EXT_GLOBAL_ACCOUNT,
+        globalAccount: getExtGlobalAccount(this.extMint),
         earnManagerAccount,
         feeTokenAccount,
       })
@@ -76,7 +74,7 @@ export class EarnManager {
 
     // derive ata if token account not provided
     if (!userTokenAccount) {
-      userTokenAccount = spl.getAssociatedTokenAddressSync(EXT_MINT, user, true, spl.TOKEN_2022_PROGRAM_ID);
+      userTokenAccount = spl.getAssociatedTokenAddressSync(this.extMint, user, true, spl.TOKEN_2022_PROGRAM_ID);
 
       // check if ata exists
       try {
@@ -87,7 +85,7 @@ export class EarnManager {
             this.manager,
             userTokenAccount,
             user,
-            EXT_MINT,
+            this.extMint,
             spl.TOKEN_2022_PROGRAM_ID,
           ),
         );
@@ -95,21 +93,15 @@ export class EarnManager {
     }
 
     // PDAs
-    const [earnManagerAccount] = PublicKey.findProgramAddressSync(
-      [Buffer.from('earn_manager'), this.manager.toBytes()],
-      EXT_PROGRAM_ID,
-    );
-    const [earnerAccount] = PublicKey.findProgramAddressSync(
-      [Buffer.from('earner'), userTokenAccount.toBytes()],
-      EXT_PROGRAM_ID,
-    );
+    const earnManagerAccount = getExtEarnManagerAccount(this.manager, this.extMint);
+    const earnerAccount = getExtEarnerAccount(userTokenAccount, this.extMint);
 
     ixs.push(
       await this.program.methods
         .addEarner(user)
         .accounts({
           signer: this.manager,
-          globalAccount: EXT_GLOBAL_ACCOUNT,
+          globalAccount: getExtGlobalAccount(this.extMint),
           earnManagerAccount,
           userTokenAccount,
           earnerAccount,
@@ -122,6 +114,6 @@ export class EarnManager {
 
   async getEarners(): Promise<Earner[]> {
     const accounts = await getExtProgram(this.connection).account.earner.all();
-    return accounts.map((a) => new Earner(this.connection, this.evmClient, a.publicKey, a.account, EXT_MINT));
+    return accounts.map((a) => new Earner(this.connection, this.evmClient, a.publicKey, a.account, this.extMint));
   }
 }
@@ -1,7 +1,7 @@
 import { Connection, PublicKey } from '@solana/web3.js';
 import { PublicClient } from 'viem';
 import BN from 'bn.js';
-import { EXT_MINT, EXT_PROGRAM_ID, getApiClient, MINT } from '.';
+import { EXT_MINT, EXT_PROGRAM_ID, getApiClient, getExtEarnerAccount, MINT } from '.';
 import { EarnerData } from './accounts';
 import { getExtProgram, getProgram } from './idl';
 import { EarnManager } from './earn_manager';
@@ -29,13 +29,17 @@ export class Earner {
     evmClient: PublicClient,
     tokenAccount: PublicKey,
     program = EXT_PROGRAM_ID,
+    extMint = EXT_MINT,
   ): Promise<Earner> {
-    const [earnerAccount] = PublicKey.findProgramAddressSync([Buffer.from('earner'), tokenAccount.toBytes()], program);
-
     if (program.equals(EXT_PROGRAM_ID)) {
+      const earnerAccount = getExtEarnerAccount(tokenAccount, extMint);
       const data = await getExtProgram(connection).account.earner.fetch(earnerAccount);
-      return new Earner(connection, evmClient, earnerAccount, data, EXT_MINT);
+      return new Earner(connection, evmClient, earnerAccount, data, extMint);
     } else {
+      const [earnerAccount] = PublicKey.findProgramAddressSync(
+        [Buffer.from('earner'), tokenAccount.toBytes()],
+        program,
+      );
       const data = await getProgram(connection).account.earner.fetch(earnerAccount);
       return new Earner(
         connection,
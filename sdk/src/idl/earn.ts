@@ -2,16 +2,51 @@ export type Earn = {
   version: '0.1.0';
   name: 'earn';
   constants: [
+    {
+      name: 'BALANCE_CHECKPOINT_SEED';
+      type: 'bytes';
+      value: '[98, 97, 108, 97, 110, 99, 101, 95, 99, 104, 101, 99, 107, 112, 111, 105, 110, 116]';
+    },
+    {
+      name: 'CLAIM_CYCLE_SEED';
+      type: 'bytes';
+      value: '[99, 108, 97, 105, 109, 95, 99, 121, 99, 108, 101]';
+    },
+    {
+      name: 'CLAIM_DISTRIBUTION_SEED';
+      type: 'bytes';
+      value: '[99, 108, 97, 105, 109, 95, 100, 105, 115, 116, 114, 105, 98, 117, 116, 105, 111, 110]';
+    },
     {
       name: 'EARNER_SEED';
       type: 'bytes';
       value: '[101, 97, 114, 110, 101, 114]';
     },
+    {
+      name: 'EARNER_VERSION';
+      type: 'u8';
+      value: '1';
+    },
     {
       name: 'GLOBAL_SEED';
       type: 'bytes';
       value: '[103, 108, 111, 98, 97, 108]';
     },
+    {
+      name: 'GLOBAL_VERSION';
+      type: 'u8';
+      value: '2';
+    },
+    {
+      name: 'PENDING_INDEX_SEED';
+      type: 'bytes';
+      value: '[112, 101, 110, 100, 105, 110, 103, 95, 105, 110, 100, 101, 120]';
+    },
+    {
+      name: 'ROLES_SEED';
+      type: 'bytes';
+      value: '[114, 111, 108, 101, 115]';
+    },
     {
       name: 'TOKEN_AUTHORITY_SEED';
       type: 'bytes';
@@ -101,10 +136,10 @@ export type Earn = {
       ];
     },
     {
-      name: 'propagateIndex';
+      name: 'setClaimWindow';
       accounts: [
         {
-          name: 'signer';
+          name: 'admin';
           isMut: false;
           isSigner: true;
         },
@@ -113,81 +148,219 @@ export type Earn = {
           isMut: true;
           isSigner: false;
         },
+      ];
+      args: [
+        {
+          name: 'claimWindow';
+          type: 'u64';
+        },
+      ];
+    },
+    {
+      name: 'transferAdmin';
+      accounts: [
         {
-          name: 'mint';
+          name: 'admin';
           isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: true;
           isSigner: false;
         },
       ];
       args: [
         {
-          name: 'index';
-          type: 'u64';
+          name: 'newAdmin';
+          type: 'publicKey';
         },
+      ];
+    },
+    {
+      name: 'acceptAdmin';
+      accounts: [
         {
-          name: 'earnerMerkleRoot';
+          name: 'newAdmin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'setPortalAuthority';
+      accounts: [
+        {
+          name: 'admin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'newPortalAuthority';
+          type: 'publicKey';
+        },
+      ];
+    },
+    {
+      name: 'grantRole';
+      accounts: [
+        {
+          name: 'admin';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'rolesAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'role';
           type: {
-            array: ['u8', 32];
+            defined: 'Role';
           };
         },
+        {
+          name: 'member';
+          type: 'publicKey';
+        },
       ];
     },
     {
-      name: 'claimFor';
+      name: 'revokeRole';
       accounts: [
         {
-          name: 'earnAuthority';
+          name: 'admin';
           isMut: false;
           isSigner: true;
         },
         {
           name: 'globalAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'rolesAccount';
           isMut: true;
           isSigner: false;
         },
+      ];
+      args: [
+        {
+          name: 'role';
+          type: {
+            defined: 'Role';
+          };
+        },
         {
-          name: 'mint';
+          name: 'member';
+          type: 'publicKey';
+        },
+      ];
+    },
+    {
+      name: 'setPaused';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'tokenAuthorityAccount';
+          name: 'mint';
           isMut: false;
           isSigner: false;
         },
         {
-          name: 'userTokenAccount';
+          name: 'rolesAccount';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'pendingIndexAccount';
           isMut: true;
           isSigner: false;
+          isOptional: true;
         },
+      ];
+      args: [
         {
-          name: 'earnerAccount';
+          name: 'paused';
+          type: 'bool';
+        },
+      ];
+    },
+    {
+      name: 'setIndexBounds';
+      accounts: [
+        {
+          name: 'admin';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'tokenProgram';
-          isMut: false;
+          name: 'pendingIndexAccount';
+          isMut: true;
           isSigner: false;
         },
         {
-          name: 'mintMultisig';
+          name: 'systemProgram';
           isMut: false;
           isSigner: false;
         },
       ];
       args: [
         {
-          name: 'snapshotBalance';
+          name: 'maxIndexRateBps';
+          type: 'u64';
+        },
+        {
+          name: 'maxIndexRatioBps';
           type: 'u64';
         },
       ];
     },
     {
-      name: 'completeClaims';
+      name: 'approvePendingIndex';
       accounts: [
         {
-          name: 'earnAuthority';
+          name: 'admin';
           isMut: false;
           isSigner: true;
         },
@@ -196,15 +369,25 @@ export type Earn = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: 'pendingIndexAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'mint';
+          isMut: false;
+          isSigner: false;
+        },
       ];
       args: [];
     },
     {
-      name: 'addRegistrarEarner';
+      name: 'rejectPendingIndex';
       accounts: [
         {
-          name: 'signer';
-          isMut: true;
+          name: 'admin';
+          isMut: false;
           isSigner: true;
         },
         {
@@ -213,740 +396,4825 @@ export type Earn = {
           isSigner: false;
         },
         {
-          name: 'userTokenAccount';
-          isMut: false;
+          name: 'pendingIndexAccount';
+          isMut: true;
           isSigner: false;
         },
+      ];
+      args: [];
+    },
+    {
+      name: 'setUnclaimedExpiry';
+      accounts: [
         {
-          name: 'earnerAccount';
+          name: 'admin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'systemProgram';
+          name: 'treasuryTokenAccount';
           isMut: false;
           isSigner: false;
+          isOptional: true;
         },
       ];
       args: [
         {
-          name: 'user';
-          type: 'publicKey';
-        },
-        {
-          name: 'proof';
-          type: {
-            vec: {
-              defined: 'ProofElement';
-            };
-          };
+          name: 'expiryCycles';
+          type: 'u64';
         },
       ];
     },
     {
-      name: 'removeRegistrarEarner';
+      name: 'sweepUnclaimed';
       accounts: [
         {
-          name: 'signer';
-          isMut: true;
+          name: 'admin';
+          isMut: false;
           isSigner: true;
         },
         {
           name: 'globalAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'mint';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'tokenAuthorityAccount';
           isMut: false;
           isSigner: false;
         },
         {
-          name: 'earnerAccount';
+          name: 'treasuryTokenAccount';
           isMut: true;
           isSigner: false;
+          isOptional: true;
         },
         {
-          name: 'userTokenAccount';
+          name: 'tokenProgram';
           isMut: false;
           isSigner: false;
         },
+        {
+          name: 'mintMultisig';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'setAttesters';
+      accounts: [
+        {
+          name: 'admin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: true;
+          isSigner: false;
+        },
       ];
       args: [
         {
-          name: 'proofs';
+          name: 'attesters';
           type: {
-            vec: {
-              vec: {
-                defined: 'ProofElement';
-              };
-            };
+            vec: 'publicKey';
           };
         },
         {
-          name: 'neighbors';
-          type: {
-            vec: {
-              array: ['u8', 32];
-            };
-          };
+          name: 'threshold';
+          type: 'u8';
         },
       ];
     },
+    {
+      name: 'approveProgramEarner';
+      accounts: [
+        {
+          name: 'admin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'programId';
+          type: 'publicKey';
+        },
+        {
+          name: 'seeds';
+          type: {
+            vec: 'bytes';
+          };
+        },
+      ];
+    },
+    {
+      name: 'removeProgramEarner';
+      accounts: [
+        {
+          name: 'admin';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'earnerAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'rentPayer';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'setRemovalBounty';
+      accounts: [
+        {
+          name: 'admin';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'removalBounty';
+          type: 'u64';
+        },
+      ];
+    },
+    {
+      name: 'migrateGlobal';
+      accounts: [
+        {
+          name: 'admin';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: true;
+          isSigner: false;
+          docs: ['the discriminator and admin are validated in the handler'];
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'propagateIndex';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: true;
+          isSigner: false;
+          docs: ['the owner, discriminator, mint and signer are validated in the handler'];
+        },
+        {
+          name: 'mint';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'pendingIndexAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+      ];
+      args: [
+        {
+          name: 'index';
+          type: 'u64';
+        },
+        {
+          name: 'earnerMerkleRoot';
+          type: {
+            array: ['u8', 32];
+          };
+        },
+        {
+          name: 'earnerRootTimestamp';
+          type: 'u64';
+        },
+      ];
+    },
+    {
+      name: 'claimFor';
+      accounts: [
+        {
+          name: 'earnAuthority';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'mint';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'tokenAuthorityAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'userTokenAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'earnerAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'mintMultisig';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'rolesAccount';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+      ];
+      args: [
+        {
+          name: 'snapshotBalance';
+          type: 'u64';
+        },
+      ];
+    },
+    {
+      name: 'claimForBatch';
+      accounts: [
+        {
+          name: 'earnAuthority';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'mint';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'tokenAuthorityAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'mintMultisig';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'rolesAccount';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+      ];
+      args: [
+        {
+          name: 'snapshotBalances';
+          type: {
+            vec: 'u64';
+          };
+        },
+      ];
+    },
+    {
+      name: 'completeClaims';
+      accounts: [
+        {
+          name: 'earnAuthority';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'claimCycleAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'rolesAccount';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'commitClaimsRoot';
+      accounts: [
+        {
+          name: 'earnAuthority';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'claimDistribution';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'rolesAccount';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+      ];
+      args: [
+        {
+          name: 'claimsRoot';
+          type: {
+            array: ['u8', 32];
+          };
+        },
+        {
+          name: 'totalBalance';
+          type: 'u64';
+        },
+      ];
+    },
+    {
+      name: 'revokeClaimsRoot';
+      accounts: [
+        {
+          name: 'earnAuthority';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'claimDistribution';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'rolesAccount';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'migrateEarner';
+      accounts: [
+        {
+          name: 'user';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'earnerAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'newUserTokenAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'newEarnerAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'rentPayer';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'setRecipient';
+      accounts: [
+        {
+          name: 'user';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'earnerAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'recipientTokenAccount';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'addRegistrarEarner';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'userTokenAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'earnerAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'user';
+          type: 'publicKey';
+        },
+        {
+          name: 'proof';
+          type: {
+            vec: {
+              defined: 'ProofElement';
+            };
+          };
+        },
+      ];
+    },
+    {
+      name: 'removeRegistrarEarner';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'earnerAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'userTokenAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'rentPayer';
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'proofs';
+          type: {
+            vec: {
+              vec: {
+                defined: 'ProofElement';
+              };
+            };
+          };
+        },
+        {
+          name: 'neighbors';
+          type: {
+            vec: {
+              array: ['u8', 32];
+            };
+          };
+        },
+      ];
+    },
+    {
+      name: 'addRegistrarEarners';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'users';
+          type: {
+            vec: 'publicKey';
+          };
+        },
+        {
+          name: 'proof';
+          type: {
+            vec: {
+              defined: 'ProofElement';
+            };
+          };
+        },
+        {
+          name: 'proofFlags';
+          type: {
+            vec: 'bool';
+          };
+        },
+      ];
+    },
+    {
+      name: 'removeRegistrarEarners';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'neighbors';
+          type: {
+            vec: {
+              array: ['u8', 32];
+            };
+          };
+        },
+        {
+          name: 'proof';
+          type: {
+            vec: {
+              defined: 'ProofElement';
+            };
+          };
+        },
+        {
+          name: 'proofFlags';
+          type: {
+            vec: 'bool';
+          };
+        },
+      ];
+    },
+    {
+      name: 'addProgramEarner';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'userTokenAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'earnerAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'createBalanceCheckpoint';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'earnerAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'userTokenAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'balanceCheckpoint';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'checkpointBalance';
+      accounts: [
+        {
+          name: 'balanceCheckpoint';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'userTokenAccount';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'claim';
+      accounts: [
+        {
+          name: 'globalAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'mint';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'tokenAuthorityAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'userTokenAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'recipientTokenAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'earnerAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'balanceCheckpoint';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'mintMultisig';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'claimWithProof';
+      accounts: [
+        {
+          name: 'globalAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'claimDistribution';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'mint';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'tokenAuthorityAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'userTokenAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'earnerAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'tokenProgram';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'mintMultisig';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'snapshotBalance';
+          type: 'u64';
+        },
+        {
+          name: 'proof';
+          type: {
+            vec: {
+              defined: 'ProofElement';
+            };
+          };
+        },
+      ];
+    },
+    {
+      name: 'previewClaim';
+      accounts: [
+        {
+          name: 'globalAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'earnerAccount';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'snapshotBalance';
+          type: 'u64';
+        },
+      ];
+      returns: {
+        defined: 'ClaimPreview';
+      };
+    },
+    {
+      name: 'migrateEarnerAccount';
+      accounts: [
+        {
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'earnerAccount';
+          isMut: true;
+          isSigner: false;
+          docs: ['so the owner and discriminator are validated in the handler'];
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'propagateAttestedIndex';
+      accounts: [
+        {
+          name: 'globalAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'mint';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'instructionsSysvar';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'pendingIndexAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+      ];
+      args: [
+        {
+          name: 'newIndex';
+          type: 'u64';
+        },
+        {
+          name: 'earnerMerkleRoot';
+          type: {
+            array: ['u8', 32];
+          };
+        },
+        {
+          name: 'timestamp';
+          type: 'u64';
+        },
+      ];
+    },
+    {
+      name: 'recordClaimCycle';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'claimCycleAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+  ];
+  accounts: [
+    {
+      name: 'balanceCheckpoint';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'userTokenAccount';
+            type: 'publicKey';
+          },
+          {
+            name: 'startIndex';
+            type: 'u64';
+          },
+          {
+            name: 'minBalance';
+            type: 'u64';
+          },
+          {
+            name: 'lastBalance';
+            type: 'u64';
+          },
+          {
+            name: 'lastUpdate';
+            type: 'u64';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+        ];
+      };
+    },
+    {
+      name: 'claimCycle';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'index';
+            type: 'u64';
+          },
+          {
+            name: 'previousIndex';
+            type: 'u64';
+          },
+          {
+            name: 'timestamp';
+            type: 'u64';
+          },
+          {
+            name: 'maxSupply';
+            type: 'u64';
+          },
+          {
+            name: 'maxYield';
+            type: 'u64';
+          },
+          {
+            name: 'distributed';
+            type: 'u64';
+          },
+          {
+            name: 'claims';
+            type: 'u64';
+          },
+          {
+            name: 'completedAt';
+            type: 'u64';
+          },
+          {
+            name: 'timedOut';
+            type: 'bool';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+        ];
+      };
+    },
+    {
+      name: 'claimDistribution';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'index';
+            type: 'u64';
+          },
+          {
+            name: 'claimsRoot';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'totalBalance';
+            type: 'u64';
+          },
+          {
+            name: 'claimedBalance';
+            type: 'u64';
+          },
+          {
+            name: 'claims';
+            type: 'u64';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+        ];
+      };
+    },
+    {
+      name: 'earner';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'lastClaimIndex';
+            type: 'u64';
+          },
+          {
+            name: 'lastClaimTimestamp';
+            type: 'u64';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'user';
+            type: 'publicKey';
+          },
+          {
+            name: 'userTokenAccount';
+            type: 'publicKey';
+          },
+          {
+            name: 'recipientTokenAccount';
+            type: {
+              option: 'publicKey';
+            };
+          },
+          {
+            name: 'totalRewards';
+            type: 'u64';
+          },
+          {
+            name: 'claims';
+            type: 'u64';
+          },
+          {
+            name: 'firstEarningTimestamp';
+            type: 'u64';
+          },
+          {
+            name: 'version';
+            type: 'u8';
+          },
+          {
+            name: 'rentPayer';
+            type: 'publicKey';
+          },
+          {
+            name: 'reserved';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'global';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'admin';
+            type: 'publicKey';
+          },
+          {
+            name: 'earnAuthority';
+            type: 'publicKey';
+          },
+          {
+            name: 'mint';
+            type: 'publicKey';
+          },
+          {
+            name: 'index';
+            type: 'u64';
+          },
+          {
+            name: 'timestamp';
+            type: 'u64';
+          },
+          {
+            name: 'claimCooldown';
+            type: 'u64';
+          },
+          {
+            name: 'maxSupply';
+            type: 'u64';
+          },
+          {
+            name: 'maxYield';
+            type: 'u64';
+          },
+          {
+            name: 'distributed';
+            type: 'u64';
+          },
+          {
+            name: 'claimComplete';
+            type: 'bool';
+          },
+          {
+            name: 'earnerMerkleRoot';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'portalAuthority';
+            type: 'publicKey';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'previousIndex';
+            type: 'u64';
+          },
+          {
+            name: 'claims';
+            type: 'u64';
+          },
+          {
+            name: 'paused';
+            type: 'bool';
+          },
+          {
+            name: 'queuedIndex';
+            type: 'u64';
+          },
+          {
+            name: 'queuedEarnerMerkleRoot';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'maxIndexRateBps';
+            type: 'u64';
+          },
+          {
+            name: 'maxIndexRatioBps';
+            type: 'u64';
+          },
+          {
+            name: 'claimWindow';
+            type: 'u64';
+          },
+          {
+            name: 'unclaimedExpiryCycles';
+            type: 'u64';
+          },
+          {
+            name: 'unclaimedYield';
+            type: {
+              array: ['u64', 13];
+            };
+          },
+          {
+            name: 'version';
+            type: 'u8';
+          },
+          {
+            name: 'lastAttestationTimestamp';
+            type: 'u64';
+          },
+          {
+            name: 'attestationThreshold';
+            type: 'u8';
+          },
+          {
+            name: 'earnerMerkleRootTimestamp';
+            type: 'u64';
+          },
+          {
+            name: 'queuedEarnerMerkleRootTimestamp';
+            type: 'u64';
+          },
+          {
+            name: 'removalBounty';
+            type: 'u64';
+          },
+          {
+            name: 'completedCycle';
+            type: {
+              defined: 'CompletedCycle';
+            };
+          },
+          {
+            name: 'queuedIndexApproved';
+            type: 'bool';
+          },
+          {
+            name: 'completedCycleRecorded';
+            type: 'bool';
+          },
+          {
+            name: 'reserved';
+            type: {
+              array: ['u8', 20];
+            };
+          },
+          {
+            name: 'pendingAdmin';
+            type: {
+              option: 'publicKey';
+            };
+          },
+          {
+            name: 'unclaimedTreasury';
+            type: {
+              option: 'publicKey';
+            };
+          },
+          {
+            name: 'attesters';
+            type: {
+              vec: 'publicKey';
+            };
+          },
+          {
+            name: 'programEarners';
+            type: {
+              vec: {
+                defined: 'ProgramEarner';
+              };
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'pendingIndex';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'index';
+            type: 'u64';
+          },
+          {
+            name: 'earnerMerkleRoot';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'earnerMerkleRootTimestamp';
+            type: 'u64';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+        ];
+      };
+    },
+    {
+      name: 'roles';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'earnAuthorities';
+            type: {
+              vec: 'publicKey';
+            };
+          },
+          {
+            name: 'pausers';
+            type: {
+              vec: 'publicKey';
+            };
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+        ];
+      };
+    },
+  ];
+  types: [
+    {
+      name: 'ClaimPreview';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'rewards';
+            type: 'u64';
+          },
+          {
+            name: 'fee';
+            type: 'u64';
+          },
+          {
+            name: 'amount';
+            type: 'u64';
+          },
+          {
+            name: 'failure';
+            type: {
+              option: {
+                defined: 'ClaimFailure';
+              };
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'CompletedCycle';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'index';
+            type: 'u64';
+          },
+          {
+            name: 'previousIndex';
+            type: 'u64';
+          },
+          {
+            name: 'timestamp';
+            type: 'u64';
+          },
+          {
+            name: 'maxSupply';
+            type: 'u64';
+          },
+          {
+            name: 'maxYield';
+            type: 'u64';
+          },
+          {
+            name: 'distributed';
+            type: 'u64';
+          },
+          {
+            name: 'claims';
+            type: 'u64';
+          },
+          {
+            name: 'completedAt';
+            type: 'u64';
+          },
+          {
+            name: 'timedOut';
+            type: 'bool';
+          },
+        ];
+      };
+    },
+    {
+      name: 'ProgramEarner';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'user';
+            type: 'publicKey';
+          },
+          {
+            name: 'programId';
+            type: 'publicKey';
+          },
+        ];
+      };
+    },
+    {
+      name: 'ProofElement';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'node';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'onRight';
+            type: 'bool';
+          },
+        ];
+      };
+    },
+    {
+      name: 'ClaimFailure';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'AlreadyClaimed';
+          },
+          {
+            name: 'ExceedsMaxYield';
+          },
+          {
+            name: 'InsufficientCollateral';
+          },
+          {
+            name: 'NoActiveClaim';
+          },
+          {
+            name: 'Paused';
+          },
+          {
+            name: 'InvalidYieldMode';
+          },
+          {
+            name: 'InvalidVersion';
+          },
+        ];
+      };
+    },
+    {
+      name: 'Role';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'EarnAuthority';
+          },
+          {
+            name: 'Pauser';
+          },
+        ];
+      };
+    },
+  ];
+  events: [
+    {
+      name: 'AdminTransferred';
+      fields: [
+        {
+          name: 'oldAdmin';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'newAdmin';
+          type: 'publicKey';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'PendingIndexApproved';
+      fields: [
+        {
+          name: 'index';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'earnerMerkleRoot';
+          type: {
+            array: ['u8', 32];
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'ProgramEarnerApproved';
+      fields: [
+        {
+          name: 'user';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'programId';
+          type: 'publicKey';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'RoleGranted';
+      fields: [
+        {
+          name: 'role';
+          type: {
+            defined: 'Role';
+          };
+          index: false;
+        },
+        {
+          name: 'member';
+          type: 'publicKey';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'PendingIndexRejected';
+      fields: [
+        {
+          name: 'index';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'earnerMerkleRoot';
+          type: {
+            array: ['u8', 32];
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'ProgramEarnerRemoved';
+      fields: [
+        {
+          name: 'user';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'tokenAccount';
+          type: 'publicKey';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'RoleRevoked';
+      fields: [
+        {
+          name: 'role';
+          type: {
+            defined: 'Role';
+          };
+          index: false;
+        },
+        {
+          name: 'member';
+          type: 'publicKey';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'AttestersSet';
+      fields: [
+        {
+          name: 'attesters';
+          type: {
+            vec: 'publicKey';
+          };
+          index: false;
+        },
+        {
+          name: 'threshold';
+          type: 'u8';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'ClaimWindowUpdate';
+      fields: [
+        {
+          name: 'oldClaimWindow';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'newClaimWindow';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'IndexBoundsUpdate';
+      fields: [
+        {
+          name: 'oldMaxIndexRateBps';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'oldMaxIndexRatioBps';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'maxIndexRateBps';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'maxIndexRatioBps';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'PausedUpdate';
+      fields: [
+        {
+          name: 'paused';
+          type: 'bool';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'PortalAuthorityUpdate';
+      fields: [
+        {
+          name: 'oldPortalAuthority';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'newPortalAuthority';
+          type: 'publicKey';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'RemovalBountyUpdate';
+      fields: [
+        {
+          name: 'oldRemovalBounty';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'newRemovalBounty';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'UnclaimedExpiryUpdate';
+      fields: [
+        {
+          name: 'oldExpiryCycles';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'newExpiryCycles';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'oldTreasury';
+          type: {
+            option: 'publicKey';
+          };
+          index: false;
+        },
+        {
+          name: 'newTreasury';
+          type: {
+            option: 'publicKey';
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'UnclaimedYieldSweep';
+      fields: [
+        {
+          name: 'amount';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'treasuryAmount';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'treasuryTokenAccount';
+          type: {
+            option: 'publicKey';
+          };
+          index: false;
+        },
+        {
+          name: 'index';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'maxYield';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'distributed';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'ts';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'AdminTransferStarted';
+      fields: [
+        {
+          name: 'admin';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'pendingAdmin';
+          type: 'publicKey';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'RewardsClaim';
+      fields: [
+        {
+          name: 'tokenAccount';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'recipientTokenAccount';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'amount';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'ts';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'index';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'fee';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'ClaimsRootCommit';
+      fields: [
+        {
+          name: 'index';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'claimsRoot';
+          type: {
+            array: ['u8', 32];
+          };
+          index: false;
+        },
+        {
+          name: 'totalBalance';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'ClaimCycleCompleted';
+      fields: [
+        {
+          name: 'index';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'maxYield';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'distributed';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'claims';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'timedOut';
+          type: 'bool';
+          index: false;
+        },
+        {
+          name: 'ts';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'ClaimsRootRevoke';
+      fields: [
+        {
+          name: 'index';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'claimsRoot';
+          type: {
+            array: ['u8', 32];
+          };
+          index: false;
+        },
+        {
+          name: 'claimedBalance';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'claims';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'EarnerMigrated';
+      fields: [
+        {
+          name: 'user';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'oldUserTokenAccount';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'newUserTokenAccount';
+          type: 'publicKey';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'RecipientSet';
+      fields: [
+        {
+          name: 'userTokenAccount';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'recipientTokenAccount';
+          type: {
+            option: 'publicKey';
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'BalanceCheckpointUpdate';
+      fields: [
+        {
+          name: 'tokenAccount';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'balance';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'minBalance';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'ts';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'IndexAttested';
+      fields: [
+        {
+          name: 'index';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'earnerMerkleRoot';
+          type: {
+            array: ['u8', 32];
+          };
+          index: false;
+        },
+        {
+          name: 'timestamp';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'attestations';
+          type: 'u8';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'IndexUpdate';
+      fields: [
+        {
+          name: 'index';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'ts';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'supply';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'maxYield';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'IndexQuarantined';
+      fields: [
+        {
+          name: 'index';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'currentIndex';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'earnerMerkleRoot';
+          type: {
+            array: ['u8', 32];
+          };
+          index: false;
+        },
+        {
+          name: 'ts';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'IndexQueued';
+      fields: [
+        {
+          name: 'index';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'earnerMerkleRoot';
+          type: {
+            array: ['u8', 32];
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'EarnerRootUpdate';
+      fields: [
+        {
+          name: 'oldRoot';
+          type: {
+            array: ['u8', 32];
+          };
+          index: false;
+        },
+        {
+          name: 'newRoot';
+          type: {
+            array: ['u8', 32];
+          };
+          index: false;
+        },
+        {
+          name: 'timestamp';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'EarnerRootIgnored';
+      fields: [
+        {
+          name: 'root';
+          type: {
+            array: ['u8', 32];
+          };
+          index: false;
+        },
+        {
+          name: 'timestamp';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'currentTimestamp';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: 'AlreadyClaimed';
+      msg: 'Already claimed for user.';
+    },
+    {
+      code: 6001;
+      name: 'ExceedsMaxYield';
+      msg: 'Rewards exceed max yield.';
+    },
+    {
+      code: 6002;
+      name: 'NotAuthorized';
+      msg: 'Invalid signer.';
+    },
+    {
+      code: 6003;
+      name: 'InvalidParam';
+      msg: 'Invalid parameter.';
+    },
+    {
+      code: 6004;
+      name: 'AlreadyEarns';
+      msg: 'User is already an earner.';
+    },
+    {
+      code: 6005;
+      name: 'NoActiveClaim';
+      msg: 'There is no active claim to complete.';
+    },
+    {
+      code: 6006;
+      name: 'NotEarning';
+      msg: 'User is not earning.';
+    },
+    {
+      code: 6007;
+      name: 'RequiredAccountMissing';
+      msg: 'An optional account is required in this case, but not provided.';
+    },
+    {
+      code: 6008;
+      name: 'InvalidAccount';
+      msg: 'Account does not match the expected key.';
+    },
+    {
+      code: 6009;
+      name: 'NotActive';
+      msg: 'Account is not currently active.';
+    },
+    {
+      code: 6010;
+      name: 'InvalidProof';
+      msg: 'Merkle proof verification failed.';
+    },
+    {
+      code: 6011;
+      name: 'MutableOwner';
+      msg: 'Token account owner is required to be immutable.';
+    },
+    {
+      code: 6012;
+      name: 'RoleFull';
+      msg: 'Role has reached the maximum number of members.';
+    },
+    {
+      code: 6013;
+      name: 'Paused';
+      msg: 'Program is paused.';
+    },
+    {
+      code: 6014;
+      name: 'NoPendingIndex';
+      msg: 'There is no pending index.';
+    },
+    {
+      code: 6015;
+      name: 'NoExpiredYield';
+      msg: 'There is no expired yield to sweep.';
+    },
+    {
+      code: 6016;
+      name: 'InvalidVersion';
+      msg: 'Account is not on the expected version.';
+    },
+    {
+      code: 6017;
+      name: 'InvalidAttestation';
+      msg: 'Index attestation is invalid.';
+    },
+    {
+      code: 6018;
+      name: 'InsufficientAttestations';
+      msg: 'Not enough attesters signed the index attestation.';
+    },
+    {
+      code: 6019;
+      name: 'ExceedsMaxSupply';
+      msg: 'Committed balances exceed the max supply.';
+    },
+    {
+      code: 6020;
+      name: 'ExceedsCommittedBalance';
+      msg: 'Claimed balances exceed the committed total.';
+    },
+    {
+      code: 6021;
+      name: 'NoCompletedCycle';
+      msg: 'There is no completed claim cycle to record.';
+    },
+    {
+      code: 6022;
+      name: 'InvalidClaimWindow';
+      msg: 'Claim window is out of bounds.';
+    },
+    {
+      code: 6023;
+      name: 'InvalidIndexBounds';
+      msg: 'Index bounds are out of range.';
+    },
+    {
+      code: 6024;
+      name: 'StaleEarnerRoot';
+      msg: 'Earner root is older than the latest root.';
+    },
+    {
+      code: 6025;
+      name: 'InvalidCheckpoint';
+      msg: 'Balance checkpoint does not cover the claim period.';
+    },
+    {
+      code: 6026;
+      name: 'UnrecordedClaimCycle';
+      msg: 'The last completed claim cycle must be recorded first.';
+    },
+    {
+      code: 6027;
+      name: 'MissingPendingIndex';
+      msg: 'The pending index account is required to quarantine the index update.';
+    },
   ];
+};
+
+export const IDL: Earn = {
+  version: '0.1.0',
+  name: 'earn',
+  constants: [
+    {
+      name: 'BALANCE_CHECKPOINT_SEED',
+      type: 'bytes',
+      value: '[98, 97, 108, 97, 110, 99, 101, 95, 99, 104, 101, 99, 107, 112, 111, 105, 110, 116]',
+    },
+    {
+      name: 'CLAIM_CYCLE_SEED',
+      type: 'bytes',
+      value: '[99, 108, 97, 105, 109, 95, 99, 121, 99, 108, 101]',
+    },
+    {
+      name: 'CLAIM_DISTRIBUTION_SEED',
+      type: 'bytes',
+      value: '[99, 108, 97, 105, 109, 95, 100, 105, 115, 116, 114, 105, 98, 117, 116, 105, 111, 110]',
+    },
+    {
+      name: 'EARNER_SEED',
+      type: 'bytes',
+      value: '[101, 97, 114, 110, 101, 114]',
+    },
+    {
+      name: 'EARNER_VERSION',
+      type: 'u8',
+      value: '1',
+    },
+    {
+      name: 'GLOBAL_SEED',
+      type: 'bytes',
+      value: '[103, 108, 111, 98, 97, 108]',
+    },
+    {
+      name: 'GLOBAL_VERSION',
+      type: 'u8',
+      value: '2',
+    },
+    {
+      name: 'PENDING_INDEX_SEED',
+      type: 'bytes',
+      value: '[112, 101, 110, 100, 105, 110, 103, 95, 105, 110, 100, 101, 120]',
+    },
+    {
+      name: 'ROLES_SEED',
+      type: 'bytes',
+      value: '[114, 111, 108, 101, 115]',
+    },
+    {
+      name: 'TOKEN_AUTHORITY_SEED',
+      type: 'bytes',
+      value: '[116, 111, 107, 101, 110, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]',
+    },
+  ],
+  instructions: [
+    {
+      name: 'initialize',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'mint',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'earnAuthority',
+          type: 'publicKey',
+        },
+        {
+          name: 'initialIndex',
+          type: 'u64',
+        },
+        {
+          name: 'claimCooldown',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'setEarnAuthority',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'newEarnAuthority',
+          type: 'publicKey',
+        },
+      ],
+    },
+    {
+      name: 'setClaimCooldown',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'claimCooldown',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'setClaimWindow',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'claimWindow',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'transferAdmin',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'newAdmin',
+          type: 'publicKey',
+        },
+      ],
+    },
+    {
+      name: 'acceptAdmin',
+      accounts: [
+        {
+          name: 'newAdmin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'setPortalAuthority',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'newPortalAuthority',
+          type: 'publicKey',
+        },
+      ],
+    },
+    {
+      name: 'grantRole',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'rolesAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'role',
+          type: {
+            defined: 'Role',
+          },
+        },
+        {
+          name: 'member',
+          type: 'publicKey',
+        },
+      ],
+    },
+    {
+      name: 'revokeRole',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'rolesAccount',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'role',
+          type: {
+            defined: 'Role',
+          },
+        },
+        {
+          name: 'member',
+          type: 'publicKey',
+        },
+      ],
+    },
+    {
+      name: 'setPaused',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'mint',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'rolesAccount',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'pendingIndexAccount',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: 'paused',
+          type: 'bool',
+        },
+      ],
+    },
+    {
+      name: 'setIndexBounds',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'pendingIndexAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'maxIndexRateBps',
+          type: 'u64',
+        },
+        {
+          name: 'maxIndexRatioBps',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'approvePendingIndex',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'pendingIndexAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'mint',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'rejectPendingIndex',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'pendingIndexAccount',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'setUnclaimedExpiry',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'treasuryTokenAccount',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: 'expiryCycles',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'sweepUnclaimed',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'mint',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'tokenAuthorityAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'treasuryTokenAccount',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'mintMultisig',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'setAttesters',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'attesters',
+          type: {
+            vec: 'publicKey',
+          },
+        },
+        {
+          name: 'threshold',
+          type: 'u8',
+        },
+      ],
+    },
+    {
+      name: 'approveProgramEarner',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'programId',
+          type: 'publicKey',
+        },
+        {
+          name: 'seeds',
+          type: {
+            vec: 'bytes',
+          },
+        },
+      ],
+    },
+    {
+      name: 'removeProgramEarner',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'earnerAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'rentPayer',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'setRemovalBounty',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'removalBounty',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'migrateGlobal',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+          docs: ['the discriminator and admin are validated in the handler'],
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'propagateIndex',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+          docs: ['the owner, discriminator, mint and signer are validated in the handler'],
+        },
+        {
+          name: 'mint',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'pendingIndexAccount',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: 'index',
+          type: 'u64',
+        },
+        {
+          name: 'earnerMerkleRoot',
+          type: {
+            array: ['u8', 32],
+          },
+        },
+        {
+          name: 'earnerRootTimestamp',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'claimFor',
+      accounts: [
+        {
+          name: 'earnAuthority',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'mint',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'tokenAuthorityAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'userTokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'earnerAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'mintMultisig',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'rolesAccount',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: 'snapshotBalance',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'claimForBatch',
+      accounts: [
+        {
+          name: 'earnAuthority',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'mint',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'tokenAuthorityAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'mintMultisig',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'rolesAccount',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: 'snapshotBalances',
+          type: {
+            vec: 'u64',
+          },
+        },
+      ],
+    },
+    {
+      name: 'completeClaims',
+      accounts: [
+        {
+          name: 'earnAuthority',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'claimCycleAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'rolesAccount',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'commitClaimsRoot',
+      accounts: [
+        {
+          name: 'earnAuthority',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'claimDistribution',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'rolesAccount',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: 'claimsRoot',
+          type: {
+            array: ['u8', 32],
+          },
+        },
+        {
+          name: 'totalBalance',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'revokeClaimsRoot',
+      accounts: [
+        {
+          name: 'earnAuthority',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'claimDistribution',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'rolesAccount',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'migrateEarner',
+      accounts: [
+        {
+          name: 'user',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'earnerAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'newUserTokenAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'newEarnerAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'rentPayer',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'setRecipient',
+      accounts: [
+        {
+          name: 'user',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'earnerAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'recipientTokenAccount',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'addRegistrarEarner',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'userTokenAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'earnerAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'user',
+          type: 'publicKey',
+        },
+        {
+          name: 'proof',
+          type: {
+            vec: {
+              defined: 'ProofElement',
+            },
+          },
+        },
+      ],
+    },
+    {
+      name: 'removeRegistrarEarner',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'earnerAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'userTokenAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'rentPayer',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'proofs',
+          type: {
+            vec: {
+              vec: {
+                defined: 'ProofElement',
+              },
+            },
+          },
+        },
+        {
+          name: 'neighbors',
+          type: {
+            vec: {
+              array: ['u8', 32],
+            },
+          },
+        },
+      ],
+    },
+    {
+      name: 'addRegistrarEarners',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'users',
+          type: {
+            vec: 'publicKey',
+          },
+        },
+        {
+          name: 'proof',
+          type: {
+            vec: {
+              defined: 'ProofElement',
+            },
+          },
+        },
+        {
+          name: 'proofFlags',
+          type: {
+            vec: 'bool',
+          },
+        },
+      ],
+    },
+    {
+      name: 'removeRegistrarEarners',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'neighbors',
+          type: {
+            vec: {
+              array: ['u8', 32],
+            },
+          },
+        },
+        {
+          name: 'proof',
+          type: {
+            vec: {
+              defined: 'ProofElement',
+            },
+          },
+        },
+        {
+          name: 'proofFlags',
+          type: {
+            vec: 'bool',
+          },
+        },
+      ],
+    },
+    {
+      name: 'addProgramEarner',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'userTokenAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'earnerAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'createBalanceCheckpoint',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'earnerAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'userTokenAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'balanceCheckpoint',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'checkpointBalance',
+      accounts: [
+        {
+          name: 'balanceCheckpoint',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'userTokenAccount',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'claim',
+      accounts: [
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'mint',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'tokenAuthorityAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'userTokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'recipientTokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'earnerAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'balanceCheckpoint',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'mintMultisig',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'claimWithProof',
+      accounts: [
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'claimDistribution',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'mint',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'tokenAuthorityAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'userTokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'earnerAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'mintMultisig',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'snapshotBalance',
+          type: 'u64',
+        },
+        {
+          name: 'proof',
+          type: {
+            vec: {
+              defined: 'ProofElement',
+            },
+          },
+        },
+      ],
+    },
+    {
+      name: 'previewClaim',
+      accounts: [
+        {
+          name: 'globalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'earnerAccount',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'snapshotBalance',
+          type: 'u64',
+        },
+      ],
+      returns: {
+        defined: 'ClaimPreview',
+      },
+    },
+    {
+      name: 'migrateEarnerAccount',
+      accounts: [
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'earnerAccount',
+          isMut: true,
+          isSigner: false,
+          docs: ['so the owner and discriminator are validated in the handler'],
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'propagateAttestedIndex',
+      accounts: [
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'mint',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'instructionsSysvar',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'pendingIndexAccount',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: 'newIndex',
+          type: 'u64',
+        },
+        {
+          name: 'earnerMerkleRoot',
+          type: {
+            array: ['u8', 32],
+          },
+        },
+        {
+          name: 'timestamp',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'recordClaimCycle',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'claimCycleAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+  ],
   accounts: [
     {
-      name: 'earner';
+      name: 'balanceCheckpoint',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'userTokenAccount',
+            type: 'publicKey',
+          },
+          {
+            name: 'startIndex',
+            type: 'u64',
+          },
+          {
+            name: 'minBalance',
+            type: 'u64',
+          },
+          {
+            name: 'lastBalance',
+            type: 'u64',
+          },
+          {
+            name: 'lastUpdate',
+            type: 'u64',
+          },
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+        ],
+      },
+    },
+    {
+      name: 'claimCycle',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'index',
+            type: 'u64',
+          },
+          {
+            name: 'previousIndex',
+            type: 'u64',
+          },
+          {
+            name: 'timestamp',
+            type: 'u64',
+          },
+          {
+            name: 'maxSupply',
+            type: 'u64',
+          },
+          {
+            name: 'maxYield',
+            type: 'u64',
+          },
+          {
+            name: 'distributed',
+            type: 'u64',
+          },
+          {
+            name: 'claims',
+            type: 'u64',
+          },
+          {
+            name: 'completedAt',
+            type: 'u64',
+          },
+          {
+            name: 'timedOut',
+            type: 'bool',
+          },
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+        ],
+      },
+    },
+    {
+      name: 'claimDistribution',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'index',
+            type: 'u64',
+          },
+          {
+            name: 'claimsRoot',
+            type: {
+              array: ['u8', 32],
+            },
+          },
+          {
+            name: 'totalBalance',
+            type: 'u64',
+          },
+          {
+            name: 'claimedBalance',
+            type: 'u64',
+          },
+          {
+            name: 'claims',
+            type: 'u64',
+          },
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+        ],
+      },
+    },
+    {
+      name: 'earner',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'lastClaimIndex',
+            type: 'u64',
+          },
+          {
+            name: 'lastClaimTimestamp',
+            type: 'u64',
+          },
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'user',
+            type: 'publicKey',
+          },
+          {
+            name: 'userTokenAccount',
+            type: 'publicKey',
+          },
+          {
+            name: 'recipientTokenAccount',
+            type: {
+              option: 'publicKey',
+            },
+          },
+          {
+            name: 'totalRewards',
+            type: 'u64',
+          },
+          {
+            name: 'claims',
+            type: 'u64',
+          },
+          {
+            name: 'firstEarningTimestamp',
+            type: 'u64',
+          },
+          {
+            name: 'version',
+            type: 'u8',
+          },
+          {
+            name: 'rentPayer',
+            type: 'publicKey',
+          },
+          {
+            name: 'reserved',
+            type: {
+              array: ['u8', 32],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'global',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'admin',
+            type: 'publicKey',
+          },
+          {
+            name: 'earnAuthority',
+            type: 'publicKey',
+          },
+          {
+            name: 'mint',
+            type: 'publicKey',
+          },
+          {
+            name: 'index',
+            type: 'u64',
+          },
+          {
+            name: 'timestamp',
+            type: 'u64',
+          },
+          {
+            name: 'claimCooldown',
+            type: 'u64',
+          },
+          {
+            name: 'maxSupply',
+            type: 'u64',
+          },
+          {
+            name: 'maxYield',
+            type: 'u64',
+          },
+          {
+            name: 'distributed',
+            type: 'u64',
+          },
+          {
+            name: 'claimComplete',
+            type: 'bool',
+          },
+          {
+            name: 'earnerMerkleRoot',
+            type: {
+              array: ['u8', 32],
+            },
+          },
+          {
+            name: 'portalAuthority',
+            type: 'publicKey',
+          },
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'previousIndex',
+            type: 'u64',
+          },
+          {
+            name: 'claims',
+            type: 'u64',
+          },
+          {
+            name: 'paused',
+            type: 'bool',
+          },
+          {
+            name: 'queuedIndex',
+            type: 'u64',
+          },
+          {
+            name: 'queuedEarnerMerkleRoot',
+            type: {
+              array: ['u8', 32],
+            },
+          },
+          {
+            name: 'maxIndexRateBps',
+            type: 'u64',
+          },
+          {
+            name: 'maxIndexRatioBps',
+            type: 'u64',
+          },
+          {
+            name: 'claimWindow',
+            type: 'u64',
+          },
+          {
+            name: 'unclaimedExpiryCycles',
+            type: 'u64',
+          },
+          {
+            name: 'unclaimedYield',
+            type: {
+              array: ['u64', 13],
+            },
+          },
+          {
+            name: 'version',
+            type: 'u8',
+          },
+          {
+            name: 'lastAttestationTimestamp',
+            type: 'u64',
+          },
+          {
+            name: 'attestationThreshold',
+            type: 'u8',
+          },
+          {
+            name: 'earnerMerkleRootTimestamp',
+            type: 'u64',
+          },
+          {
+            name: 'queuedEarnerMerkleRootTimestamp',
+            type: 'u64',
+          },
+          {
+            name: 'removalBounty',
+            type: 'u64',
+          },
+          {
+            name: 'completedCycle',
+            type: {
+              defined: 'CompletedCycle',
+            },
+          },
+          {
+            name: 'queuedIndexApproved',
+            type: 'bool',
+          },
+          {
+            name: 'completedCycleRecorded',
+            type: 'bool',
+          },
+          {
+            name: 'reserved',
+            type: {
+              array: ['u8', 20],
+            },
+          },
+          {
+            name: 'pendingAdmin',
+            type: {
+              option: 'publicKey',
+            },
+          },
+          {
+            name: 'unclaimedTreasury',
+            type: {
+              option: 'publicKey',
+            },
+          },
+          {
+            name: 'attesters',
+            type: {
+              vec: 'publicKey',
+            },
+          },
+          {
+            name: 'programEarners',
+            type: {
+              vec: {
+                defined: 'ProgramEarner',
+              },
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'pendingIndex',
       type: {
-        kind: 'struct';
+        kind: 'struct',
         fields: [
           {
-            name: 'lastClaimIndex';
-            type: 'u64';
+            name: 'index',
+            type: 'u64',
           },
           {
-            name: 'lastClaimTimestamp';
-            type: 'u64';
+            name: 'earnerMerkleRoot',
+            type: {
+              array: ['u8', 32],
+            },
           },
           {
-            name: 'bump';
-            type: 'u8';
+            name: 'earnerMerkleRootTimestamp',
+            type: 'u64',
           },
           {
-            name: 'user';
-            type: 'publicKey';
+            name: 'bump',
+            type: 'u8',
+          },
+        ],
+      },
+    },
+    {
+      name: 'roles',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'earnAuthorities',
+            type: {
+              vec: 'publicKey',
+            },
           },
           {
-            name: 'userTokenAccount';
-            type: 'publicKey';
+            name: 'pausers',
+            type: {
+              vec: 'publicKey',
+            },
           },
-        ];
-      };
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+        ],
+      },
     },
+  ],
+  types: [
     {
-      name: 'global';
+      name: 'ClaimPreview',
       type: {
-        kind: 'struct';
+        kind: 'struct',
         fields: [
           {
-            name: 'admin';
-            type: 'publicKey';
+            name: 'rewards',
+            type: 'u64',
           },
           {
-            name: 'earnAuthority';
-            type: 'publicKey';
+            name: 'fee',
+            type: 'u64',
           },
           {
-            name: 'mint';
-            type: 'publicKey';
+            name: 'amount',
+            type: 'u64',
           },
           {
-            name: 'index';
-            type: 'u64';
+            name: 'failure',
+            type: {
+              option: {
+                defined: 'ClaimFailure',
+              },
+            },
           },
+        ],
+      },
+    },
+    {
+      name: 'CompletedCycle',
+      type: {
+        kind: 'struct',
+        fields: [
           {
-            name: 'timestamp';
-            type: 'u64';
+            name: 'index',
+            type: 'u64',
           },
           {
-            name: 'claimCooldown';
-            type: 'u64';
+            name: 'previousIndex',
+            type: 'u64',
           },
           {
-            name: 'maxSupply';
-            type: 'u64';
+            name: 'timestamp',
+            type: 'u64',
           },
           {
-            name: 'maxYield';
-            type: 'u64';
+            name: 'maxSupply',
+            type: 'u64',
           },
           {
-            name: 'distributed';
-            type: 'u64';
+            name: 'maxYield',
+            type: 'u64',
           },
           {
-            name: 'claimComplete';
-            type: 'bool';
+            name: 'distributed',
+            type: 'u64',
           },
           {
-            name: 'earnerMerkleRoot';
+            name: 'claims',
+            type: 'u64',
+          },
+          {
+            name: 'completedAt',
+            type: 'u64',
+          },
+          {
+            name: 'timedOut',
+            type: 'bool',
+          },
+        ],
+      },
+    },
+    {
+      name: 'ProgramEarner',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'user',
+            type: 'publicKey',
+          },
+          {
+            name: 'programId',
+            type: 'publicKey',
+          },
+        ],
+      },
+    },
+    {
+      name: 'ProofElement',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'node',
             type: {
-              array: ['u8', 32];
-            };
+              array: ['u8', 32],
+            },
           },
           {
-            name: 'portalAuthority';
-            type: 'publicKey';
+            name: 'onRight',
+            type: 'bool',
+          },
+        ],
+      },
+    },
+    {
+      name: 'ClaimFailure',
+      type: {
+        kind: 'enum',
+        variants: [
+          {
+            name: 'AlreadyClaimed',
           },
           {
-            name: 'bump';
-            type: 'u8';
+            name: 'ExceedsMaxYield',
+          },
+          {
+            name: 'InsufficientCollateral',
+          },
+          {
+            name: 'NoActiveClaim',
+          },
+          {
+            name: 'Paused',
+          },
+          {
+            name: 'InvalidYieldMode',
+          },
+          {
+            name: 'InvalidVersion',
           },
-        ];
-      };
+        ],
+      },
     },
-  ];
-  types: [
     {
-      name: 'ProofElement';
+      name: 'Role',
       type: {
-        kind: 'struct';
-        fields: [
+        kind: 'enum',
+        variants: [
           {
-            name: 'node';
-            type: {
-              array: ['u8', 32];
-            };
+            name: 'EarnAuthority',
           },
           {
-            name: 'onRight';
-            type: 'bool';
+            name: 'Pauser',
           },
-        ];
-      };
+        ],
+      },
     },
-  ];
+  ],
   events: [
     {
-      name: 'RewardsClaim';
+      name: 'AdminTransferred',
       fields: [
         {
-          name: 'tokenAccount';
-          type: 'publicKey';
-          index: false;
+          name: 'oldAdmin',
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'recipientTokenAccount';
-          type: 'publicKey';
-          index: false;
+          name: 'newAdmin',
+          type: 'publicKey',
+          index: false,
         },
+      ],
+    },
+    {
+      name: 'PendingIndexApproved',
+      fields: [
         {
-          name: 'amount';
-          type: 'u64';
-          index: false;
+          name: 'index',
+          type: 'u64',
+          index: false,
         },
         {
-          name: 'ts';
-          type: 'u64';
-          index: false;
+          name: 'earnerMerkleRoot',
+          type: {
+            array: ['u8', 32],
+          },
+          index: false,
         },
+      ],
+    },
+    {
+      name: 'ProgramEarnerApproved',
+      fields: [
         {
-          name: 'index';
-          type: 'u64';
-          index: false;
+          name: 'user',
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'fee';
-          type: 'u64';
-          index: false;
+          name: 'programId',
+          type: 'publicKey',
+          index: false,
         },
-      ];
+      ],
     },
     {
-      name: 'IndexUpdate';
+      name: 'RoleGranted',
       fields: [
         {
-          name: 'index';
-          type: 'u64';
-          index: false;
+          name: 'role',
+          type: {
+            defined: 'Role',
+          },
+          index: false,
         },
         {
-          name: 'ts';
-          type: 'u64';
-          index: false;
+          name: 'member',
+          type: 'publicKey',
+          index: false,
         },
+      ],
+    },
+    {
+      name: 'PendingIndexRejected',
+      fields: [
         {
-          name: 'supply';
-          type: 'u64';
-          index: false;
+          name: 'index',
+          type: 'u64',
+          index: false,
         },
         {
-          name: 'maxYield';
-          type: 'u64';
-          index: false;
+          name: 'earnerMerkleRoot',
+          type: {
+            array: ['u8', 32],
+          },
+          index: false,
         },
-      ];
-    },
-  ];
-  errors: [
-    {
-      code: 6000;
-      name: 'AlreadyClaimed';
-      msg: 'Already claimed for user.';
-    },
-    {
-      code: 6001;
-      name: 'ExceedsMaxYield';
-      msg: 'Rewards exceed max yield.';
-    },
-    {
-      code: 6002;
-      name: 'NotAuthorized';
-      msg: 'Invalid signer.';
-    },
-    {
-      code: 6003;
-      name: 'InvalidParam';
-      msg: 'Invalid parameter.';
-    },
-    {
-      code: 6004;
-      name: 'AlreadyEarns';
-      msg: 'User is already an earner.';
-    },
-    {
-      code: 6005;
-      name: 'NoActiveClaim';
-      msg: 'There is no active claim to complete.';
+      ],
     },
     {
-      code: 6006;
-      name: 'NotEarning';
-      msg: 'User is not earning.';
+      name: 'ProgramEarnerRemoved',
+      fields: [
+        {
+          name: 'user',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'tokenAccount',
+          type: 'publicKey',
+          index: false,
+        },
+      ],
     },
     {
-      code: 6007;
-      name: 'RequiredAccountMissing';
-      msg: 'An optional account is required in this case, but not provided.';
+      name: 'RoleRevoked',
+      fields: [
+        {
+          name: 'role',
+          type: {
+            defined: 'Role',
+          },
+          index: false,
+        },
+        {
+          name: 'member',
+          type: 'publicKey',
+          index: false,
+        },
+      ],
     },
     {
-      code: 6008;
-      name: 'InvalidAccount';
-      msg: 'Account does not match the expected key.';
+      name: 'AttestersSet',
+      fields: [
+        {
+          name: 'attesters',
+          type: {
+            vec: 'publicKey',
+          },
+          index: false,
+        },
+        {
+          name: 'threshold',
+          type: 'u8',
+          index: false,
+        },
+      ],
     },
     {
-      code: 6009;
-      name: 'NotActive';
-      msg: 'Account is not currently active.';
+      name: 'ClaimWindowUpdate',
+      fields: [
+        {
+          name: 'oldClaimWindow',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'newClaimWindow',
+          type: 'u64',
+          index: false,
+        },
+      ],
     },
     {
-      code: 6010;
-      name: 'InvalidProof';
-      msg: 'Merkle proof verification failed.';
+      name: 'IndexBoundsUpdate',
+      fields: [
+        {
+          name: 'oldMaxIndexRateBps',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'oldMaxIndexRatioBps',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'maxIndexRateBps',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'maxIndexRatioBps',
+          type: 'u64',
+          index: false,
+        },
+      ],
     },
     {
-      code: 6011;
-      name: 'MutableOwner';
-      msg: 'Token account owner is required to be immutable.';
+      name: 'PausedUpdate',
+      fields: [
+        {
+          name: 'paused',
+          type: 'bool',
+          index: false,
+        },
+      ],
     },
-  ];
-};
-
-export const IDL: Earn = {
-  version: '0.1.0',
-  name: 'earn',
-  constants: [
     {
-      name: 'EARNER_SEED',
-      type: 'bytes',
-      value: '[101, 97, 114, 110, 101, 114]',
+      name: 'PortalAuthorityUpdate',
+      fields: [
+        {
+          name: 'oldPortalAuthority',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'newPortalAuthority',
+          type: 'publicKey',
+          index: false,
+        },
+      ],
     },
     {
-      name: 'GLOBAL_SEED',
-      type: 'bytes',
-      value: '[103, 108, 111, 98, 97, 108]',
+      name: 'RemovalBountyUpdate',
+      fields: [
+        {
+          name: 'oldRemovalBounty',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'newRemovalBounty',
+          type: 'u64',
+          index: false,
+        },
+      ],
     },
     {
-      name: 'TOKEN_AUTHORITY_SEED',
-      type: 'bytes',
-      value: '[116, 111, 107, 101, 110, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]',
+      name: 'UnclaimedExpiryUpdate',
+      fields: [
+        {
+          name: 'oldExpiryCycles',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'newExpiryCycles',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'oldTreasury',
+          type: {
+            option: 'publicKey',
+          },
+          index: false,
+        },
+        {
+          name: 'newTreasury',
+          type: {
+            option: 'publicKey',
+          },
+          index: false,
+        },
+      ],
     },
-  ],
-  instructions: [
     {
-      name: 'initialize',
-      accounts: [
+      name: 'UnclaimedYieldSweep',
+      fields: [
         {
-          name: 'admin',
-          isMut: true,
-          isSigner: true,
+          name: 'amount',
+          type: 'u64',
+          index: false,
         },
         {
-          name: 'globalAccount',
-          isMut: true,
-          isSigner: false,
+          name: 'treasuryAmount',
+          type: 'u64',
+          index: false,
         },
         {
-          name: 'mint',
-          isMut: false,
-          isSigner: false,
+          name: 'treasuryTokenAccount',
+          type: {
+            option: 'publicKey',
+          },
+          index: false,
         },
         {
-          name: 'systemProgram',
-          isMut: false,
-          isSigner: false,
+          name: 'index',
+          type: 'u64',
+          index: false,
         },
-      ],
-      args: [
         {
-          name: 'earnAuthority',
-          type: 'publicKey',
+          name: 'maxYield',
+          type: 'u64',
+          index: false,
         },
         {
-          name: 'initialIndex',
+          name: 'distributed',
           type: 'u64',
+          index: false,
         },
         {
-          name: 'claimCooldown',
+          name: 'ts',
           type: 'u64',
+          index: false,
         },
       ],
     },
     {
-      name: 'setEarnAuthority',
-      accounts: [
+      name: 'AdminTransferStarted',
+      fields: [
         {
           name: 'admin',
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: 'globalAccount',
-          isMut: true,
-          isSigner: false,
+          type: 'publicKey',
+          index: false,
         },
-      ],
-      args: [
         {
-          name: 'newEarnAuthority',
+          name: 'pendingAdmin',
           type: 'publicKey',
+          index: false,
         },
       ],
     },
     {
-      name: 'setClaimCooldown',
-      accounts: [
+      name: 'RewardsClaim',
+      fields: [
         {
-          name: 'admin',
-          isMut: false,
-          isSigner: true,
+          name: 'tokenAccount',
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'globalAccount',
-          isMut: true,
-          isSigner: false,
+          name: 'recipientTokenAccount',
+          type: 'publicKey',
+          index: false,
         },
-      ],
-      args: [
         {
-          name: 'claimCooldown',
+          name: 'amount',
           type: 'u64',
+          index: false,
         },
-      ],
-    },
-    {
-      name: 'propagateIndex',
-      accounts: [
         {
-          name: 'signer',
-          isMut: false,
-          isSigner: true,
+          name: 'ts',
+          type: 'u64',
+          index: false,
         },
         {
-          name: 'globalAccount',
-          isMut: true,
-          isSigner: false,
+          name: 'index',
+          type: 'u64',
+          index: false,
         },
         {
-          name: 'mint',
-          isMut: false,
-          isSigner: false,
+          name: 'fee',
+          type: 'u64',
+          index: false,
         },
       ],
-      args: [
+    },
+    {
+      name: 'ClaimsRootCommit',
+      fields: [
         {
           name: 'index',
           type: 'u64',
+          index: false,
         },
         {
-          name: 'earnerMerkleRoot',
+          name: 'claimsRoot',
           type: {
             array: ['u8', 32],
           },
+          index: false,
+        },
+        {
+          name: 'totalBalance',
+          type: 'u64',
+          index: false,
         },
       ],
     },
     {
-      name: 'claimFor',
-      accounts: [
+      name: 'ClaimCycleCompleted',
+      fields: [
         {
-          name: 'earnAuthority',
-          isMut: false,
-          isSigner: true,
+          name: 'index',
+          type: 'u64',
+          index: false,
         },
         {
-          name: 'globalAccount',
-          isMut: true,
-          isSigner: false,
+          name: 'maxYield',
+          type: 'u64',
+          index: false,
         },
         {
-          name: 'mint',
-          isMut: true,
-          isSigner: false,
+          name: 'distributed',
+          type: 'u64',
+          index: false,
         },
         {
-          name: 'tokenAuthorityAccount',
-          isMut: false,
-          isSigner: false,
+          name: 'claims',
+          type: 'u64',
+          index: false,
         },
         {
-          name: 'userTokenAccount',
-          isMut: true,
-          isSigner: false,
+          name: 'timedOut',
+          type: 'bool',
+          index: false,
         },
         {
-          name: 'earnerAccount',
-          isMut: true,
-          isSigner: false,
+          name: 'ts',
+          type: 'u64',
+          index: false,
         },
+      ],
+    },
+    {
+      name: 'ClaimsRootRevoke',
+      fields: [
         {
-          name: 'tokenProgram',
-          isMut: false,
-          isSigner: false,
+          name: 'index',
+          type: 'u64',
+          index: false,
         },
         {
-          name: 'mintMultisig',
-          isMut: false,
-          isSigner: false,
+          name: 'claimsRoot',
+          type: {
+            array: ['u8', 32],
+          },
+          index: false,
         },
-      ],
-      args: [
         {
-          name: 'snapshotBalance',
+          name: 'claimedBalance',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'claims',
           type: 'u64',
+          index: false,
         },
       ],
     },
     {
-      name: 'completeClaims',
-      accounts: [
+      name: 'EarnerMigrated',
+      fields: [
         {
-          name: 'earnAuthority',
-          isMut: false,
-          isSigner: true,
+          name: 'user',
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'globalAccount',
-          isMut: true,
-          isSigner: false,
+          name: 'oldUserTokenAccount',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'newUserTokenAccount',
+          type: 'publicKey',
+          index: false,
         },
       ],
-      args: [],
     },
     {
-      name: 'addRegistrarEarner',
-      accounts: [
-        {
-          name: 'signer',
-          isMut: true,
-          isSigner: true,
-        },
+      name: 'RecipientSet',
+      fields: [
         {
-          name: 'globalAccount',
-          isMut: false,
-          isSigner: false,
+          name: 'userTokenAccount',
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'userTokenAccount',
-          isMut: false,
-          isSigner: false,
+          name: 'recipientTokenAccount',
+          type: {
+            option: 'publicKey',
+          },
+          index: false,
         },
+      ],
+    },
+    {
+      name: 'BalanceCheckpointUpdate',
+      fields: [
         {
-          name: 'earnerAccount',
-          isMut: true,
-          isSigner: false,
+          name: 'tokenAccount',
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'systemProgram',
-          isMut: false,
-          isSigner: false,
+          name: 'balance',
+          type: 'u64',
+          index: false,
         },
-      ],
-      args: [
         {
-          name: 'user',
-          type: 'publicKey',
+          name: 'minBalance',
+          type: 'u64',
+          index: false,
         },
         {
-          name: 'proof',
-          type: {
-            vec: {
-              defined: 'ProofElement',
-            },
-          },
+          name: 'ts',
+          type: 'u64',
+          index: false,
         },
       ],
     },
     {
-      name: 'removeRegistrarEarner',
-      accounts: [
+      name: 'IndexAttested',
+      fields: [
         {
-          name: 'signer',
-          isMut: true,
-          isSigner: true,
+          name: 'index',
+          type: 'u64',
+          index: false,
         },
         {
-          name: 'globalAccount',
-          isMut: false,
-          isSigner: false,
+          name: 'earnerMerkleRoot',
+          type: {
+            array: ['u8', 32],
+          },
+          index: false,
         },
         {
-          name: 'earnerAccount',
-          isMut: true,
-          isSigner: false,
+          name: 'timestamp',
+          type: 'u64',
+          index: false,
         },
         {
-          name: 'userTokenAccount',
-          isMut: false,
-          isSigner: false,
+          name: 'attestations',
+          type: 'u8',
+          index: false,
         },
       ],
-      args: [
+    },
+    {
+      name: 'IndexUpdate',
+      fields: [
         {
-          name: 'proofs',
-          type: {
-            vec: {
-              vec: {
-                defined: 'ProofElement',
-              },
-            },
-          },
+          name: 'index',
+          type: 'u64',
+          index: false,
         },
         {
-          name: 'neighbors',
-          type: {
-            vec: {
-              array: ['u8', 32],
-            },
-          },
+          name: 'ts',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'supply',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'maxYield',
+          type: 'u64',
+          index: false,
         },
       ],
     },
-  ],
-  accounts: [
-    {
-      name: 'earner',
-      type: {
-        kind: 'struct',
-        fields: [
-          {
-            name: 'lastClaimIndex',
-            type: 'u64',
-          },
-          {
-            name: 'lastClaimTimestamp',
-            type: 'u64',
-          },
-          {
-            name: 'bump',
-            type: 'u8',
-          },
-          {
-            name: 'user',
-            type: 'publicKey',
-          },
-          {
-            name: 'userTokenAccount',
-            type: 'publicKey',
-          },
-        ],
-      },
-    },
-    {
-      name: 'global',
-      type: {
-        kind: 'struct',
-        fields: [
-          {
-            name: 'admin',
-            type: 'publicKey',
-          },
-          {
-            name: 'earnAuthority',
-            type: 'publicKey',
-          },
-          {
-            name: 'mint',
-            type: 'publicKey',
-          },
-          {
-            name: 'index',
-            type: 'u64',
-          },
-          {
-            name: 'timestamp',
-            type: 'u64',
-          },
-          {
-            name: 'claimCooldown',
-            type: 'u64',
-          },
-          {
-            name: 'maxSupply',
-            type: 'u64',
-          },
-          {
-            name: 'maxYield',
-            type: 'u64',
-          },
-          {
-            name: 'distributed',
-            type: 'u64',
-          },
-          {
-            name: 'claimComplete',
-            type: 'bool',
-          },
-          {
-            name: 'earnerMerkleRoot',
-            type: {
-              array: ['u8', 32],
-            },
-          },
-          {
-            name: 'portalAuthority',
-            type: 'publicKey',
-          },
-          {
-            name: 'bump',
-            type: 'u8',
-          },
-        ],
-      },
-    },
-  ],
-  types: [
-    {
-      name: 'ProofElement',
-      type: {
-        kind: 'struct',
-        fields: [
-          {
-            name: 'node',
-            type: {
-              array: ['u8', 32],
-            },
-          },
-          {
-            name: 'onRight',
-            type: 'bool',
-          },
-        ],
-      },
-    },
-  ],
-  events: [
     {
-      name: 'RewardsClaim',
+      name: 'IndexQuarantined',
       fields: [
         {
-          name: 'tokenAccount',
-          type: 'publicKey',
+          name: 'index',
+          type: 'u64',
           index: false,
         },
         {
-          name: 'recipientTokenAccount',
-          type: 'publicKey',
+          name: 'currentIndex',
+          type: 'u64',
           index: false,
         },
         {
-          name: 'amount',
-          type: 'u64',
+          name: 'earnerMerkleRoot',
+          type: {
+            array: ['u8', 32],
+          },
           index: false,
         },
         {
@@ -954,38 +5222,66 @@ export const IDL: Earn = {
           type: 'u64',
           index: false,
         },
+      ],
+    },
+    {
+      name: 'IndexQueued',
+      fields: [
         {
           name: 'index',
           type: 'u64',
           index: false,
         },
         {
-          name: 'fee',
-          type: 'u64',
+          name: 'earnerMerkleRoot',
+          type: {
+            array: ['u8', 32],
+          },
           index: false,
         },
       ],
     },
     {
-      name: 'IndexUpdate',
+      name: 'EarnerRootUpdate',
       fields: [
         {
-          name: 'index',
-          type: 'u64',
+          name: 'oldRoot',
+          type: {
+            array: ['u8', 32],
+          },
           index: false,
         },
         {
-          name: 'ts',
+          name: 'newRoot',
+          type: {
+            array: ['u8', 32],
+          },
+          index: false,
+        },
+        {
+          name: 'timestamp',
           type: 'u64',
           index: false,
         },
+      ],
+    },
+    {
+      name: 'EarnerRootIgnored',
+      fields: [
+        {
+          name: 'root',
+          type: {
+            array: ['u8', 32],
+          },
+          index: false,
+        },
         {
-          name: 'supply',
+          name: 'timestamp',
           type: 'u64',
           index: false,
         },
         {
-          name: 'maxYield',
+          name: 'currentTimestamp',
           type: 'u64',
           index: false,
         },
@@ -1053,5 +5349,85 @@ export const IDL: Earn = {
       name: 'MutableOwner',
       msg: 'Token account owner is required to be immutable.',
     },
+    {
+      code: 6012,
+      name: 'RoleFull',
+      msg: 'Role has reached the maximum number of members.',
+    },
+    {
+      code: 6013,
+      name: 'Paused',
+      msg: 'Program is paused.',
+    },
+    {
+      code: 6014,
+      name: 'NoPendingIndex',
+      msg: 'There is no pending index.',
+    },
+    {
+      code: 6015,
+      name: 'NoExpiredYield',
+      msg: 'There is no expired yield to sweep.',
+    },
+    {
+      code: 6016,
+      name: 'InvalidVersion',
+      msg: 'Account is not on the expected version.',
+    },
+    {
+      code: 6017,
+      name: 'InvalidAttestation',
+      msg: 'Index attestation is invalid.',
+    },
+    {
+      code: 6018,
+      name: 'InsufficientAttestations',
+      msg: 'Not enough attesters signed the index attestation.',
+    },
+    {
+      code: 6019,
+      name: 'ExceedsMaxSupply',
+      msg: 'Committed balances exceed the max supply.',
+    },
+    {
+      code: 6020,
+      name: 'ExceedsCommittedBalance',
+      msg: 'Claimed balances exceed the committed total.',
+    },
+    {
+      code: 6021,
+      name: 'NoCompletedCycle',
+      msg: 'There is no completed claim cycle to record.',
+    },
+    {
+      code: 6022,
+      name: 'InvalidClaimWindow',
+      msg: 'Claim window is out of bounds.',
+    },
+    {
+      code: 6023,
+      name: 'InvalidIndexBounds',
+      msg: 'Index bounds are out of range.',
+    },
+    {
+      code: 6024,
+      name: 'StaleEarnerRoot',
+      msg: 'Earner root is older than the latest root.',
+    },
+    {
+      code: 6025,
+      name: 'InvalidCheckpoint',
+      msg: 'Balance checkpoint does not cover the claim period.',
+    },
+    {
+      code: 6026,
+      name: 'UnrecordedClaimCycle',
+      msg: 'The last completed claim cycle must be recorded first.',
+    },
+    {
+      code: 6027,
+      name: 'MissingPendingIndex',
+      msg: 'The pending index account is required to quarantine the index update.',
+    },
   ],
 };
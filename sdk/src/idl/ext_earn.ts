@@ -7,16 +7,41 @@ export type ExtEarn = {
       type: 'bytes';
       value: '[101, 97, 114, 110, 95, 109, 97, 110, 97, 103, 101, 114]';
     },
+    {
+      name: 'EARN_MANAGER_VERSION';
+      type: 'u8';
+      value: '1';
+    },
     {
       name: 'EARNER_SEED';
       type: 'bytes';
       value: '[101, 97, 114, 110, 101, 114]';
     },
+    {
+      name: 'EARNER_VERSION';
+      type: 'u8';
+      value: '1';
+    },
     {
       name: 'EXT_GLOBAL_SEED';
       type: 'bytes';
       value: '[103, 108, 111, 98, 97, 108]';
     },
+    {
+      name: 'EXT_GLOBAL_VERSION';
+      type: 'u8';
+      value: '2';
+    },
+    {
+      name: 'ROLES_SEED';
+      type: 'bytes';
+      value: '[114, 111, 108, 101, 115]';
+    },
+    {
+      name: 'WRAPPER_SEED';
+      type: 'bytes';
+      value: '[119, 114, 97, 112, 112, 101, 114]';
+    },
     {
       name: 'M_VAULT_SEED';
       type: 'bytes';
@@ -73,6 +98,12 @@ export type ExtEarn = {
           name: 'earnAuthority';
           type: 'publicKey';
         },
+        {
+          name: 'yieldMode';
+          type: {
+            defined: 'YieldMode';
+          };
+        },
       ];
     },
     {
@@ -124,6 +155,12 @@ export type ExtEarn = {
           isMut: false;
           isSigner: false;
         },
+        {
+          name: 'rolesAccount';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
       ];
       args: [
         {
@@ -154,172 +191,199 @@ export type ExtEarn = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: 'rolesAccount';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
       ];
       args: [];
     },
     {
-      name: 'claimFor';
+      name: 'grantRole';
       accounts: [
         {
-          name: 'earnAuthority';
-          isMut: false;
+          name: 'admin';
+          isMut: true;
           isSigner: true;
         },
         {
           name: 'globalAccount';
-          isMut: true;
+          isMut: false;
           isSigner: false;
         },
         {
-          name: 'extMint';
+          name: 'rolesAccount';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'extMintAuthority';
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: 'mVaultAccount';
+          name: 'systemProgram';
           isMut: false;
           isSigner: false;
         },
+      ];
+      args: [
         {
-          name: 'vaultMTokenAccount';
-          isMut: false;
-          isSigner: false;
+          name: 'role';
+          type: {
+            defined: 'Role';
+          };
         },
         {
-          name: 'userTokenAccount';
-          isMut: true;
-          isSigner: false;
+          name: 'member';
+          type: 'publicKey';
         },
+      ];
+    },
+    {
+      name: 'revokeRole';
+      accounts: [
         {
-          name: 'earnerAccount';
-          isMut: true;
-          isSigner: false;
+          name: 'admin';
+          isMut: false;
+          isSigner: true;
         },
         {
-          name: 'earnManagerAccount';
+          name: 'globalAccount';
           isMut: false;
           isSigner: false;
         },
         {
-          name: 'earnManagerTokenAccount';
+          name: 'rolesAccount';
           isMut: true;
           isSigner: false;
-          docs: [
-            'if the token account has been closed or is not initialized',
-            'This prevents DoSing earner yield by closing this account',
-          ];
-        },
-        {
-          name: 'token2022';
-          isMut: false;
-          isSigner: false;
         },
       ];
       args: [
         {
-          name: 'snapshotBalance';
-          type: 'u64';
+          name: 'role';
+          type: {
+            defined: 'Role';
+          };
+        },
+        {
+          name: 'member';
+          type: 'publicKey';
         },
       ];
     },
     {
-      name: 'sync';
+      name: 'setRemovalBounty';
       accounts: [
         {
-          name: 'earnAuthority';
+          name: 'admin';
           isMut: false;
           isSigner: true;
         },
-        {
-          name: 'mEarnGlobalAccount';
-          isMut: false;
-          isSigner: false;
-        },
         {
           name: 'globalAccount';
           isMut: true;
           isSigner: false;
         },
       ];
-      args: [];
+      args: [
+        {
+          name: 'removalBounty';
+          type: 'u64';
+        },
+      ];
     },
     {
-      name: 'addEarner';
+      name: 'setYieldRecipient';
       accounts: [
         {
-          name: 'signer';
-          isMut: true;
+          name: 'admin';
+          isMut: false;
           isSigner: true;
         },
         {
-          name: 'earnManagerAccount';
-          isMut: false;
+          name: 'globalAccount';
+          isMut: true;
           isSigner: false;
         },
         {
-          name: 'globalAccount';
+          name: 'yieldRecipient';
           isMut: false;
           isSigner: false;
         },
+      ];
+      args: [
         {
-          name: 'userTokenAccount';
+          name: 'excessBufferBps';
+          type: 'u64';
+        },
+        {
+          name: 'claimsTimeout';
+          type: 'u64';
+        },
+      ];
+    },
+    {
+      name: 'setWrapFees';
+      accounts: [
+        {
+          name: 'admin';
           isMut: false;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: 'earnerAccount';
+          name: 'globalAccount';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'systemProgram';
+          name: 'feeTokenAccount';
           isMut: false;
           isSigner: false;
         },
       ];
       args: [
         {
-          name: 'user';
-          type: 'publicKey';
+          name: 'wrapFeeBps';
+          type: 'u64';
+        },
+        {
+          name: 'unwrapFeeBps';
+          type: 'u64';
         },
       ];
     },
     {
-      name: 'removeEarner';
+      name: 'setAllowlist';
       accounts: [
         {
-          name: 'signer';
-          isMut: true;
+          name: 'admin';
+          isMut: false;
           isSigner: true;
         },
         {
-          name: 'earnerAccount';
+          name: 'globalAccount';
           isMut: true;
           isSigner: false;
         },
+      ];
+      args: [
         {
-          name: 'earnManagerAccount';
-          isMut: false;
-          isSigner: false;
+          name: 'allowlist';
+          type: {
+            defined: 'Allowlist';
+          };
         },
         {
-          name: 'systemProgram';
-          isMut: false;
-          isSigner: false;
+          name: 'wrapperMerkleRoot';
+          type: {
+            array: ['u8', 32];
+          };
         },
       ];
-      args: [];
     },
     {
-      name: 'configureEarnManager';
+      name: 'addWrapper';
       accounts: [
         {
-          name: 'signer';
+          name: 'admin';
           isMut: true;
           isSigner: true;
         },
@@ -329,92 +393,84 @@ export type ExtEarn = {
           isSigner: false;
         },
         {
-          name: 'earnManagerAccount';
+          name: 'wrapperAccount';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'feeTokenAccount';
+          name: 'systemProgram';
           isMut: false;
           isSigner: false;
-          isOptional: true;
         },
       ];
       args: [
         {
-          name: 'feeBps';
-          type: {
-            option: 'u64';
-          };
+          name: 'wrapper';
+          type: 'publicKey';
         },
       ];
     },
     {
-      name: 'transferEarner';
+      name: 'removeWrapper';
       accounts: [
         {
-          name: 'signer';
-          isMut: false;
-          isSigner: true;
-        },
-        {
-          name: 'earnerAccount';
+          name: 'admin';
           isMut: true;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: 'fromEarnManagerAccount';
+          name: 'globalAccount';
           isMut: false;
           isSigner: false;
         },
         {
-          name: 'toEarnManagerAccount';
-          isMut: false;
+          name: 'wrapperAccount';
+          isMut: true;
           isSigner: false;
         },
       ];
-      args: [
-        {
-          name: 'toEarnManager';
-          type: 'publicKey';
-        },
-      ];
+      args: [];
     },
     {
-      name: 'setRecipient';
+      name: 'migrateExtGlobal';
       accounts: [
         {
-          name: 'signer';
-          isMut: false;
+          name: 'admin';
+          isMut: true;
           isSigner: true;
         },
         {
           name: 'globalAccount';
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: 'earnerAccount';
           isMut: true;
           isSigner: false;
+          docs: ['the owner, discriminator and admin are validated in the handler'];
         },
         {
-          name: 'recipientTokenAccount';
+          name: 'systemProgram';
           isMut: false;
           isSigner: false;
-          isOptional: true;
         },
       ];
       args: [];
     },
     {
-      name: 'wrap';
+      name: 'migrateLegacyGlobal';
       accounts: [
         {
-          name: 'signer';
-          isMut: false;
+          name: 'admin';
+          isMut: true;
           isSigner: true;
         },
+        {
+          name: 'legacyGlobalAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'globalAccount';
+          isMut: true;
+          isSigner: false;
+        },
         {
           name: 'mMint';
           isMut: false;
@@ -426,7 +482,17 @@ export type ExtEarn = {
           isSigner: false;
         },
         {
-          name: 'globalAccount';
+          name: 'mEarnGlobalAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'legacyMVault';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'legacyExtMintAuthority';
           isMut: false;
           isSigner: false;
         },
@@ -441,7 +507,7 @@ export type ExtEarn = {
           isSigner: false;
         },
         {
-          name: 'fromMTokenAccount';
+          name: 'legacyVaultMTokenAccount';
           isMut: true;
           isSigner: false;
         },
@@ -451,34 +517,56 @@ export type ExtEarn = {
           isSigner: false;
         },
         {
-          name: 'toExtTokenAccount';
+          name: 'legacyVaultEarnerAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'vaultEarnerAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'legacyRolesAccount';
           isMut: true;
           isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'rolesAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
         },
         {
           name: 'token2022';
           isMut: false;
           isSigner: false;
         },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
       ];
       args: [
         {
-          name: 'amount';
+          name: 'legacyEarners';
           type: 'u64';
         },
       ];
     },
     {
-      name: 'unwrap';
+      name: 'claimFor';
       accounts: [
         {
-          name: 'signer';
+          name: 'earnAuthority';
           isMut: false;
           isSigner: true;
         },
         {
-          name: 'mMint';
-          isMut: false;
+          name: 'globalAccount';
+          isMut: true;
           isSigner: false;
         },
         {
@@ -487,49 +575,237 @@ export type ExtEarn = {
           isSigner: false;
         },
         {
-          name: 'globalAccount';
+          name: 'extMintAuthority';
           isMut: false;
           isSigner: false;
         },
         {
-          name: 'mVault';
+          name: 'mVaultAccount';
           isMut: false;
           isSigner: false;
         },
         {
-          name: 'toMTokenAccount';
+          name: 'vaultMTokenAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'userTokenAccount';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'vaultMTokenAccount';
+          name: 'earnerAccount';
           isMut: true;
           isSigner: false;
         },
         {
-          name: 'fromExtTokenAccount';
+          name: 'earnManagerAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'earnManagerTokenAccount';
           isMut: true;
           isSigner: false;
+          docs: [
+            'if the token account has been closed or is not initialized',
+            'This prevents DoSing earner yield by closing this account',
+          ];
         },
         {
           name: 'token2022';
           isMut: false;
           isSigner: false;
         },
+        {
+          name: 'rolesAccount';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
       ];
       args: [
         {
-          name: 'amount';
+          name: 'snapshotBalance';
           type: 'u64';
         },
       ];
     },
     {
-      name: 'removeOrphanedEarner';
+      name: 'claimForBatch';
       accounts: [
         {
-          name: 'signer';
+          name: 'earnAuthority';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'extMint';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'extMintAuthority';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'mVaultAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'vaultMTokenAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'token2022';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'rolesAccount';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+      ];
+      args: [
+        {
+          name: 'snapshotBalances';
+          type: {
+            vec: 'u64';
+          };
+        },
+      ];
+    },
+    {
+      name: 'claimExcess';
+      accounts: [
+        {
+          name: 'earnAuthority';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'mEarnGlobalAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'mMint';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'extMint';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'extMintAuthority';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'mVaultAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'vaultMTokenAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'yieldRecipient';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'token2022';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'rolesAccount';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'sync';
+      accounts: [
+        {
+          name: 'earnAuthority';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'mEarnGlobalAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'globalAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'extMint';
           isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'extMintAuthority';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'mVaultAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'vaultMTokenAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'token2022';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'rolesAccount';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'settleFee';
+      accounts: [
+        {
+          name: 'earnAuthority';
+          isMut: false;
           isSigner: true;
         },
         {
@@ -537,6 +813,21 @@ export type ExtEarn = {
           isMut: false;
           isSigner: false;
         },
+        {
+          name: 'extMint';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'extMintAuthority';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'userTokenAccount';
+          isMut: true;
+          isSigner: false;
+        },
         {
           name: 'earnerAccount';
           isMut: true;
@@ -548,881 +839,4077 @@ export type ExtEarn = {
           isSigner: false;
         },
         {
-          name: 'systemProgram';
+          name: 'earnManagerTokenAccount';
+          isMut: true;
+          isSigner: false;
+          docs: ['if the token account has been closed or is not initialized'];
+        },
+        {
+          name: 'token2022';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'rolesAccount';
           isMut: false;
           isSigner: false;
+          isOptional: true;
+        },
+      ];
+      args: [
+        {
+          name: 'snapshotBalance';
+          type: 'u64';
         },
       ];
-      args: [];
     },
-  ];
-  accounts: [
     {
-      name: 'earnManager';
-      type: {
-        kind: 'struct';
-        fields: [
+      name: 'addEarner';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'earnManagerAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'globalAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'userTokenAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'earnerAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'user';
+          type: 'publicKey';
+        },
+      ];
+    },
+    {
+      name: 'removeEarner';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'earnerAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'earnManagerAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'rentPayer';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'configureEarnManager';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'earnManagerAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'feeTokenAccount';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+      ];
+      args: [
+        {
+          name: 'feeBps';
+          type: {
+            option: 'u64';
+          };
+        },
+      ];
+    },
+    {
+      name: 'transferEarner';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'earnerAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'fromEarnManagerAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'toEarnManagerAccount';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'toEarnManager';
+          type: 'publicKey';
+        },
+      ];
+    },
+    {
+      name: 'setRecipient';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'earnerAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'recipientTokenAccount';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'migrateEarner';
+      accounts: [
+        {
+          name: 'user';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'earnerAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'newUserTokenAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'newEarnerAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'rentPayer';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'wrap';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'mMint';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'extMint';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'globalAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'mEarnGlobalAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'mVault';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'extMintAuthority';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'fromMTokenAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'vaultMTokenAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'toExtTokenAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'wrapperAccount';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'feeTokenAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'token2022';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'amount';
+          type: 'u64';
+        },
+        {
+          name: 'proof';
+          type: {
+            option: {
+              vec: {
+                defined: 'ProofElement';
+              };
+            };
+          };
+        },
+      ];
+    },
+    {
+      name: 'unwrap';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: 'mMint';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'extMint';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'globalAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'mEarnGlobalAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'mVault';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'toMTokenAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'vaultMTokenAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'fromExtTokenAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'wrapperAccount';
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'feeTokenAccount';
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: 'token2022';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'amount';
+          type: 'u64';
+        },
+        {
+          name: 'proof';
+          type: {
+            option: {
+              vec: {
+                defined: 'ProofElement';
+              };
+            };
+          };
+        },
+      ];
+    },
+    {
+      name: 'removeOrphanedEarner';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'earnerAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'earnManagerAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'rentPayer';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'previewClaim';
+      accounts: [
+        {
+          name: 'globalAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'extMint';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'mVaultAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'vaultMTokenAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'earnerAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'earnManagerAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'earnManagerTokenAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'token2022';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: 'snapshotBalance';
+          type: 'u64';
+        },
+      ];
+      returns: {
+        defined: 'ClaimPreview';
+      };
+    },
+    {
+      name: 'migrateEarnerAccount';
+      accounts: [
+        {
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'earnerAccount';
+          isMut: true;
+          isSigner: false;
+          docs: ['so the owner and discriminator are validated in the handler'];
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'migrateEarnManager';
+      accounts: [
+        {
+          name: 'payer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'earnManagerAccount';
+          isMut: true;
+          isSigner: false;
+          docs: ['so the owner and discriminator are validated in the handler'];
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'migrateLegacyEarnManager';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: 'legacyEarnManagerAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'earnManagerAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'rentPayer';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: 'migrateLegacyEarner';
+      accounts: [
+        {
+          name: 'signer';
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: 'globalAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'legacyEarnerAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'earnerAccount';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'rentPayer';
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: 'systemProgram';
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+  ];
+  accounts: [
+    {
+      name: 'earnManager';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'earnManager';
+            type: 'publicKey';
+          },
+          {
+            name: 'isActive';
+            type: 'bool';
+          },
+          {
+            name: 'feeBps';
+            type: 'u64';
+          },
+          {
+            name: 'feeTokenAccount';
+            type: 'publicKey';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'version';
+            type: 'u8';
+          },
+          {
+            name: 'rentPayer';
+            type: 'publicKey';
+          },
+          {
+            name: 'reserved';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'earner';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'lastClaimIndex';
+            type: 'u64';
+          },
+          {
+            name: 'lastClaimTimestamp';
+            type: 'u64';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'user';
+            type: 'publicKey';
+          },
+          {
+            name: 'userTokenAccount';
+            type: 'publicKey';
+          },
+          {
+            name: 'earnManager';
+            type: 'publicKey';
+          },
+          {
+            name: 'recipientTokenAccount';
+            type: {
+              option: 'publicKey';
+            };
+          },
+          {
+            name: 'totalRewards';
+            type: 'u64';
+          },
+          {
+            name: 'totalFees';
+            type: 'u64';
+          },
+          {
+            name: 'claims';
+            type: 'u64';
+          },
+          {
+            name: 'firstEarningTimestamp';
+            type: 'u64';
+          },
+          {
+            name: 'version';
+            type: 'u8';
+          },
+          {
+            name: 'rentPayer';
+            type: 'publicKey';
+          },
+          {
+            name: 'reserved';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'extGlobal';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'admin';
+            type: 'publicKey';
+          },
+          {
+            name: 'earnAuthority';
+            type: 'publicKey';
+          },
+          {
+            name: 'extMint';
+            type: 'publicKey';
+          },
+          {
+            name: 'mMint';
+            type: 'publicKey';
+          },
+          {
+            name: 'mEarnGlobalAccount';
+            type: 'publicKey';
+          },
+          {
+            name: 'index';
+            type: 'u64';
+          },
+          {
+            name: 'timestamp';
+            type: 'u64';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+          {
+            name: 'mVaultBump';
+            type: 'u8';
+          },
+          {
+            name: 'extMintAuthorityBump';
+            type: 'u8';
+          },
+          {
+            name: 'version';
+            type: 'u8';
+          },
+          {
+            name: 'removalBounty';
+            type: 'u64';
+          },
+          {
+            name: 'legacy';
+            type: 'bool';
+          },
+          {
+            name: 'yieldRecipient';
+            type: 'publicKey';
+          },
+          {
+            name: 'excessBufferBps';
+            type: 'u64';
+          },
+          {
+            name: 'yieldMode';
+            type: {
+              defined: 'YieldMode';
+            };
+          },
+          {
+            name: 'baseIndex';
+            type: 'u64';
+          },
+          {
+            name: 'wrapFeeBps';
+            type: 'u64';
+          },
+          {
+            name: 'unwrapFeeBps';
+            type: 'u64';
+          },
+          {
+            name: 'feeTokenAccount';
+            type: 'publicKey';
+          },
+          {
+            name: 'allowlist';
+            type: {
+              defined: 'Allowlist';
+            };
+          },
+          {
+            name: 'wrapperMerkleRoot';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'earners';
+            type: 'u64';
+          },
+          {
+            name: 'claimedEarners';
+            type: 'u64';
+          },
+          {
+            name: 'legacyEarners';
+            type: 'u64';
+          },
+          {
+            name: 'claimsTimeout';
+            type: 'u64';
+          },
+          {
+            name: 'mIndex';
+            type: 'u64';
+          },
+          {
+            name: 'reserved';
+            type: {
+              array: ['u8', 24];
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'roles';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'earnAuthorities';
+            type: {
+              vec: 'publicKey';
+            };
+          },
+          {
+            name: 'earnManagerAdmins';
+            type: {
+              vec: 'publicKey';
+            };
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+        ];
+      };
+    },
+    {
+      name: 'wrapper';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'wrapper';
+            type: 'publicKey';
+          },
+          {
+            name: 'bump';
+            type: 'u8';
+          },
+        ];
+      };
+    },
+  ];
+  types: [
+    {
+      name: 'ClaimPreview';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'rewards';
+            type: 'u64';
+          },
+          {
+            name: 'fee';
+            type: 'u64';
+          },
+          {
+            name: 'amount';
+            type: 'u64';
+          },
+          {
+            name: 'failure';
+            type: {
+              option: {
+                defined: 'ClaimFailure';
+              };
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: 'CompletedCycle';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'index';
+            type: 'u64';
+          },
+          {
+            name: 'previousIndex';
+            type: 'u64';
+          },
+          {
+            name: 'timestamp';
+            type: 'u64';
+          },
+          {
+            name: 'maxSupply';
+            type: 'u64';
+          },
+          {
+            name: 'maxYield';
+            type: 'u64';
+          },
+          {
+            name: 'distributed';
+            type: 'u64';
+          },
+          {
+            name: 'claims';
+            type: 'u64';
+          },
+          {
+            name: 'completedAt';
+            type: 'u64';
+          },
+          {
+            name: 'timedOut';
+            type: 'bool';
+          },
+        ];
+      };
+    },
+    {
+      name: 'ProgramEarner';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'user';
+            type: 'publicKey';
+          },
+          {
+            name: 'programId';
+            type: 'publicKey';
+          },
+        ];
+      };
+    },
+    {
+      name: 'ProofElement';
+      type: {
+        kind: 'struct';
+        fields: [
+          {
+            name: 'node';
+            type: {
+              array: ['u8', 32];
+            };
+          },
+          {
+            name: 'onRight';
+            type: 'bool';
+          },
+        ];
+      };
+    },
+    {
+      name: 'ClaimFailure';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'AlreadyClaimed';
+          },
+          {
+            name: 'ExceedsMaxYield';
+          },
+          {
+            name: 'InsufficientCollateral';
+          },
+          {
+            name: 'NoActiveClaim';
+          },
+          {
+            name: 'Paused';
+          },
+          {
+            name: 'InvalidYieldMode';
+          },
+          {
+            name: 'InvalidVersion';
+          },
+        ];
+      };
+    },
+    {
+      name: 'YieldMode';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'Claim';
+          },
+          {
+            name: 'Rebasing';
+          },
+        ];
+      };
+    },
+    {
+      name: 'Allowlist';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'Open';
+          },
+          {
+            name: 'Pda';
+          },
+          {
+            name: 'MerkleRoot';
+          },
+        ];
+      };
+    },
+    {
+      name: 'Role';
+      type: {
+        kind: 'enum';
+        variants: [
+          {
+            name: 'EarnAuthority';
+          },
+          {
+            name: 'EarnManagerAdmin';
+          },
+        ];
+      };
+    },
+  ];
+  events: [
+    {
+      name: 'EarnManagerAdded';
+      fields: [
+        {
+          name: 'extMint';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'earnManager';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'feeBps';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'feeTokenAccount';
+          type: 'publicKey';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'WrapperAdded';
+      fields: [
+        {
+          name: 'extMint';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'wrapper';
+          type: 'publicKey';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'RoleGranted';
+      fields: [
+        {
+          name: 'role';
+          type: {
+            defined: 'Role';
+          };
+          index: false;
+        },
+        {
+          name: 'member';
+          type: 'publicKey';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'LegacyGlobalMigrated';
+      fields: [
+        {
+          name: 'extMint';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'collateral';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'EarnManagerRemoved';
+      fields: [
+        {
+          name: 'extMint';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'earnManager';
+          type: 'publicKey';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'WrapperRemoved';
+      fields: [
+        {
+          name: 'extMint';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'wrapper';
+          type: 'publicKey';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'RoleRevoked';
+      fields: [
+        {
+          name: 'role';
+          type: {
+            defined: 'Role';
+          };
+          index: false;
+        },
+        {
+          name: 'member';
+          type: 'publicKey';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'AllowlistUpdate';
+      fields: [
+        {
+          name: 'extMint';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'allowlist';
+          type: {
+            defined: 'Allowlist';
+          };
+          index: false;
+        },
+        {
+          name: 'wrapperMerkleRoot';
+          type: {
+            array: ['u8', 32];
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'RemovalBountyUpdate';
+      fields: [
+        {
+          name: 'extMint';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'removalBounty';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'WrapFeesUpdate';
+      fields: [
+        {
+          name: 'extMint';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'wrapFeeBps';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'unwrapFeeBps';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'feeTokenAccount';
+          type: 'publicKey';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'YieldRecipientUpdate';
+      fields: [
+        {
+          name: 'extMint';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'yieldRecipient';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'excessBufferBps';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'claimsTimeout';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'ExcessClaimed';
+      fields: [
+        {
+          name: 'extMint';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'recipient';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'amount';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'minted';
+          type: 'bool';
+          index: false;
+        },
+        {
+          name: 'collateral';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'supply';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'SyncIndexUpdate';
+      fields: [
+        {
+          name: 'index';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'ts';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'EarnerAdded';
+      fields: [
+        {
+          name: 'extMint';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'earnManager';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'user';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'userTokenAccount';
+          type: 'publicKey';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'EarnManagerConfigured';
+      fields: [
+        {
+          name: 'extMint';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'earnManager';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'feeBps';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'feeTokenAccount';
+          type: 'publicKey';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'EarnerRemoved';
+      fields: [
+        {
+          name: 'extMint';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'earnManager';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'user';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'userTokenAccount';
+          type: 'publicKey';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'EarnerTransferred';
+      fields: [
+        {
+          name: 'extMint';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'userTokenAccount';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'fromEarnManager';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'toEarnManager';
+          type: 'publicKey';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'EarnerMigrated';
+      fields: [
+        {
+          name: 'user';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'oldUserTokenAccount';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'newUserTokenAccount';
+          type: 'publicKey';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'RecipientSet';
+      fields: [
+        {
+          name: 'extMint';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'userTokenAccount';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'recipientTokenAccount';
+          type: {
+            option: 'publicKey';
+          };
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'Unwrap';
+      fields: [
+        {
+          name: 'extMint';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'signer';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'source';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'destination';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'amount';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'fee';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'mAmount';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'extSupply';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'collateral';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+    {
+      name: 'Wrap';
+      fields: [
+        {
+          name: 'extMint';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'signer';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'source';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'destination';
+          type: 'publicKey';
+          index: false;
+        },
+        {
+          name: 'amount';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'fee';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'extAmount';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'extSupply';
+          type: 'u64';
+          index: false;
+        },
+        {
+          name: 'collateral';
+          type: 'u64';
+          index: false;
+        },
+      ];
+    },
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: 'AlreadyClaimed';
+      msg: 'Already claimed for user.';
+    },
+    {
+      code: 6001;
+      name: 'NotAuthorized';
+      msg: 'Invalid signer.';
+    },
+    {
+      code: 6002;
+      name: 'InvalidParam';
+      msg: 'Invalid parameter.';
+    },
+    {
+      code: 6003;
+      name: 'InvalidAccount';
+      msg: 'Account does not match the expected key.';
+    },
+    {
+      code: 6004;
+      name: 'Active';
+      msg: 'Account is currently active.';
+    },
+    {
+      code: 6005;
+      name: 'NotActive';
+      msg: 'Account is not currently active.';
+    },
+    {
+      code: 6006;
+      name: 'MutableOwner';
+      msg: 'Token account owner is required to be immutable.';
+    },
+    {
+      code: 6007;
+      name: 'InsufficientCollateral';
+      msg: 'Not enough M.';
+    },
+    {
+      code: 6008;
+      name: 'InvalidMint';
+      msg: 'Invalid Mint.';
+    },
+    {
+      code: 6009;
+      name: 'RoleFull';
+      msg: 'Role has reached the maximum number of members.';
+    },
+    {
+      code: 6010;
+      name: 'InvalidVersion';
+      msg: 'Account is not on the expected version.';
+    },
+    {
+      code: 6011;
+      name: 'InvalidYieldMode';
+      msg: 'Not supported in the yield mode of the extension.';
+    },
+    {
+      code: 6012;
+      name: 'IndexNotSynced';
+      msg: 'Index has not been synced with the M earn index.';
+    },
+    {
+      code: 6013;
+      name: 'ClaimsPending';
+      msg: 'Earners have not all been claimed for the current index.';
+    },
+  ];
+};
+
+export const IDL: ExtEarn = {
+  version: '0.1.0',
+  name: 'ext_earn',
+  constants: [
+    {
+      name: 'EARN_MANAGER_SEED',
+      type: 'bytes',
+      value: '[101, 97, 114, 110, 95, 109, 97, 110, 97, 103, 101, 114]',
+    },
+    {
+      name: 'EARN_MANAGER_VERSION',
+      type: 'u8',
+      value: '1',
+    },
+    {
+      name: 'EARNER_SEED',
+      type: 'bytes',
+      value: '[101, 97, 114, 110, 101, 114]',
+    },
+    {
+      name: 'EARNER_VERSION',
+      type: 'u8',
+      value: '1',
+    },
+    {
+      name: 'EXT_GLOBAL_SEED',
+      type: 'bytes',
+      value: '[103, 108, 111, 98, 97, 108]',
+    },
+    {
+      name: 'EXT_GLOBAL_VERSION',
+      type: 'u8',
+      value: '2',
+    },
+    {
+      name: 'ROLES_SEED',
+      type: 'bytes',
+      value: '[114, 111, 108, 101, 115]',
+    },
+    {
+      name: 'WRAPPER_SEED',
+      type: 'bytes',
+      value: '[119, 114, 97, 112, 112, 101, 114]',
+    },
+    {
+      name: 'M_VAULT_SEED',
+      type: 'bytes',
+      value: '[109, 95, 118, 97, 117, 108, 116]',
+    },
+    {
+      name: 'MINT_AUTHORITY_SEED',
+      type: 'bytes',
+      value: '[109, 105, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]',
+    },
+  ],
+  instructions: [
+    {
+      name: 'initialize',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'mMint',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'extMint',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'mEarnGlobalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'token2022',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'earnAuthority',
+          type: 'publicKey',
+        },
+        {
+          name: 'yieldMode',
+          type: {
+            defined: 'YieldMode',
+          },
+        },
+      ],
+    },
+    {
+      name: 'setEarnAuthority',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'newEarnAuthority',
+          type: 'publicKey',
+        },
+      ],
+    },
+    {
+      name: 'addEarnManager',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'earnManagerAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'feeTokenAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'rolesAccount',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: 'earnManager',
+          type: 'publicKey',
+        },
+        {
+          name: 'feeBps',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'removeEarnManager',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'earnManagerAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'rolesAccount',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'grantRole',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'rolesAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'role',
+          type: {
+            defined: 'Role',
+          },
+        },
+        {
+          name: 'member',
+          type: 'publicKey',
+        },
+      ],
+    },
+    {
+      name: 'revokeRole',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'rolesAccount',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'role',
+          type: {
+            defined: 'Role',
+          },
+        },
+        {
+          name: 'member',
+          type: 'publicKey',
+        },
+      ],
+    },
+    {
+      name: 'setRemovalBounty',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'removalBounty',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'setYieldRecipient',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'yieldRecipient',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'excessBufferBps',
+          type: 'u64',
+        },
+        {
+          name: 'claimsTimeout',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'setWrapFees',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'feeTokenAccount',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'wrapFeeBps',
+          type: 'u64',
+        },
+        {
+          name: 'unwrapFeeBps',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'setAllowlist',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'allowlist',
+          type: {
+            defined: 'Allowlist',
+          },
+        },
+        {
+          name: 'wrapperMerkleRoot',
+          type: {
+            array: ['u8', 32],
+          },
+        },
+      ],
+    },
+    {
+      name: 'addWrapper',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'wrapperAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'wrapper',
+          type: 'publicKey',
+        },
+      ],
+    },
+    {
+      name: 'removeWrapper',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'wrapperAccount',
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'migrateExtGlobal',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+          docs: ['the owner, discriminator and admin are validated in the handler'],
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'migrateLegacyGlobal',
+      accounts: [
+        {
+          name: 'admin',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'legacyGlobalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'mMint',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'extMint',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'mEarnGlobalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'legacyMVault',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'legacyExtMintAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'mVault',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'extMintAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'legacyVaultMTokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'vaultMTokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'legacyVaultEarnerAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'vaultEarnerAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'legacyRolesAccount',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'rolesAccount',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'token2022',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'legacyEarners',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'claimFor',
+      accounts: [
+        {
+          name: 'earnAuthority',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'extMint',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'extMintAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'mVaultAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'vaultMTokenAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'userTokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'earnerAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'earnManagerAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'earnManagerTokenAccount',
+          isMut: true,
+          isSigner: false,
+          docs: [
+            'if the token account has been closed or is not initialized',
+            'This prevents DoSing earner yield by closing this account',
+          ],
+        },
+        {
+          name: 'token2022',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'rolesAccount',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: 'snapshotBalance',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'claimForBatch',
+      accounts: [
+        {
+          name: 'earnAuthority',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'extMint',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'extMintAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'mVaultAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'vaultMTokenAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'token2022',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'rolesAccount',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: 'snapshotBalances',
+          type: {
+            vec: 'u64',
+          },
+        },
+      ],
+    },
+    {
+      name: 'claimExcess',
+      accounts: [
+        {
+          name: 'earnAuthority',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'mEarnGlobalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'mMint',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'extMint',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'extMintAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'mVaultAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'vaultMTokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'yieldRecipient',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'token2022',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'rolesAccount',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'sync',
+      accounts: [
+        {
+          name: 'earnAuthority',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'mEarnGlobalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'extMint',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'extMintAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'mVaultAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'vaultMTokenAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'token2022',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'rolesAccount',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'settleFee',
+      accounts: [
+        {
+          name: 'earnAuthority',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'extMint',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'extMintAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'userTokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'earnerAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'earnManagerAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'earnManagerTokenAccount',
+          isMut: true,
+          isSigner: false,
+          docs: ['if the token account has been closed or is not initialized'],
+        },
+        {
+          name: 'token2022',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'rolesAccount',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: 'snapshotBalance',
+          type: 'u64',
+        },
+      ],
+    },
+    {
+      name: 'addEarner',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'earnManagerAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'userTokenAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'earnerAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'user',
+          type: 'publicKey',
+        },
+      ],
+    },
+    {
+      name: 'removeEarner',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'earnerAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'earnManagerAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'rentPayer',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'configureEarnManager',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'earnManagerAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'feeTokenAccount',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [
+        {
+          name: 'feeBps',
+          type: {
+            option: 'u64',
+          },
+        },
+      ],
+    },
+    {
+      name: 'transferEarner',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'earnerAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'fromEarnManagerAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'toEarnManagerAccount',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'toEarnManager',
+          type: 'publicKey',
+        },
+      ],
+    },
+    {
+      name: 'setRecipient',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'earnerAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'recipientTokenAccount',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'migrateEarner',
+      accounts: [
+        {
+          name: 'user',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'earnerAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'newUserTokenAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'newEarnerAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'rentPayer',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'wrap',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'mMint',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'extMint',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'globalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'mEarnGlobalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'mVault',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'extMintAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'fromMTokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'vaultMTokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'toExtTokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'wrapperAccount',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'feeTokenAccount',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'token2022',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'amount',
+          type: 'u64',
+        },
+        {
+          name: 'proof',
+          type: {
+            option: {
+              vec: {
+                defined: 'ProofElement',
+              },
+            },
+          },
+        },
+      ],
+    },
+    {
+      name: 'unwrap',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'mMint',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'extMint',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'globalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'mEarnGlobalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'mVault',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'toMTokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'vaultMTokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'fromExtTokenAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'wrapperAccount',
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'feeTokenAccount',
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: 'token2022',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'amount',
+          type: 'u64',
+        },
+        {
+          name: 'proof',
+          type: {
+            option: {
+              vec: {
+                defined: 'ProofElement',
+              },
+            },
+          },
+        },
+      ],
+    },
+    {
+      name: 'removeOrphanedEarner',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'earnerAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'earnManagerAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'rentPayer',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'previewClaim',
+      accounts: [
+        {
+          name: 'globalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'extMint',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'mVaultAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'vaultMTokenAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'earnerAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'earnManagerAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'earnManagerTokenAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'token2022',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'snapshotBalance',
+          type: 'u64',
+        },
+      ],
+      returns: {
+        defined: 'ClaimPreview',
+      },
+    },
+    {
+      name: 'migrateEarnerAccount',
+      accounts: [
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'earnerAccount',
+          isMut: true,
+          isSigner: false,
+          docs: ['so the owner and discriminator are validated in the handler'],
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'migrateEarnManager',
+      accounts: [
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'earnManagerAccount',
+          isMut: true,
+          isSigner: false,
+          docs: ['so the owner and discriminator are validated in the handler'],
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'migrateLegacyEarnManager',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'legacyEarnManagerAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'earnManagerAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'rentPayer',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: 'migrateLegacyEarner',
+      accounts: [
+        {
+          name: 'signer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'globalAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'legacyEarnerAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'earnerAccount',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'rentPayer',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+  ],
+  accounts: [
+    {
+      name: 'earnManager',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'earnManager',
+            type: 'publicKey',
+          },
+          {
+            name: 'isActive',
+            type: 'bool',
+          },
+          {
+            name: 'feeBps',
+            type: 'u64',
+          },
+          {
+            name: 'feeTokenAccount',
+            type: 'publicKey',
+          },
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'version',
+            type: 'u8',
+          },
+          {
+            name: 'rentPayer',
+            type: 'publicKey',
+          },
+          {
+            name: 'reserved',
+            type: {
+              array: ['u8', 32],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'earner',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'lastClaimIndex',
+            type: 'u64',
+          },
+          {
+            name: 'lastClaimTimestamp',
+            type: 'u64',
+          },
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'user',
+            type: 'publicKey',
+          },
+          {
+            name: 'userTokenAccount',
+            type: 'publicKey',
+          },
+          {
+            name: 'earnManager',
+            type: 'publicKey',
+          },
+          {
+            name: 'recipientTokenAccount',
+            type: {
+              option: 'publicKey',
+            },
+          },
+          {
+            name: 'totalRewards',
+            type: 'u64',
+          },
+          {
+            name: 'totalFees',
+            type: 'u64',
+          },
+          {
+            name: 'claims',
+            type: 'u64',
+          },
+          {
+            name: 'firstEarningTimestamp',
+            type: 'u64',
+          },
+          {
+            name: 'version',
+            type: 'u8',
+          },
+          {
+            name: 'rentPayer',
+            type: 'publicKey',
+          },
+          {
+            name: 'reserved',
+            type: {
+              array: ['u8', 32],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'extGlobal',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'admin',
+            type: 'publicKey',
+          },
+          {
+            name: 'earnAuthority',
+            type: 'publicKey',
+          },
+          {
+            name: 'extMint',
+            type: 'publicKey',
+          },
+          {
+            name: 'mMint',
+            type: 'publicKey',
+          },
+          {
+            name: 'mEarnGlobalAccount',
+            type: 'publicKey',
+          },
+          {
+            name: 'index',
+            type: 'u64',
+          },
+          {
+            name: 'timestamp',
+            type: 'u64',
+          },
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'mVaultBump',
+            type: 'u8',
+          },
           {
-            name: 'earnManager';
-            type: 'publicKey';
+            name: 'extMintAuthorityBump',
+            type: 'u8',
           },
           {
-            name: 'isActive';
-            type: 'bool';
+            name: 'version',
+            type: 'u8',
           },
           {
-            name: 'feeBps';
-            type: 'u64';
+            name: 'removalBounty',
+            type: 'u64',
           },
           {
-            name: 'feeTokenAccount';
-            type: 'publicKey';
+            name: 'legacy',
+            type: 'bool',
           },
           {
-            name: 'bump';
-            type: 'u8';
+            name: 'yieldRecipient',
+            type: 'publicKey',
           },
-        ];
-      };
-    },
-    {
-      name: 'earner';
-      type: {
-        kind: 'struct';
-        fields: [
           {
-            name: 'lastClaimIndex';
-            type: 'u64';
+            name: 'excessBufferBps',
+            type: 'u64',
           },
           {
-            name: 'lastClaimTimestamp';
-            type: 'u64';
+            name: 'yieldMode',
+            type: {
+              defined: 'YieldMode',
+            },
           },
           {
-            name: 'bump';
-            type: 'u8';
+            name: 'baseIndex',
+            type: 'u64',
           },
           {
-            name: 'user';
-            type: 'publicKey';
+            name: 'wrapFeeBps',
+            type: 'u64',
           },
           {
-            name: 'userTokenAccount';
-            type: 'publicKey';
+            name: 'unwrapFeeBps',
+            type: 'u64',
           },
           {
-            name: 'earnManager';
-            type: 'publicKey';
+            name: 'feeTokenAccount',
+            type: 'publicKey',
           },
           {
-            name: 'recipientTokenAccount';
+            name: 'allowlist',
             type: {
-              option: 'publicKey';
-            };
+              defined: 'Allowlist',
+            },
           },
-        ];
-      };
-    },
-    {
-      name: 'extGlobal';
-      type: {
-        kind: 'struct';
-        fields: [
           {
-            name: 'admin';
-            type: 'publicKey';
+            name: 'wrapperMerkleRoot',
+            type: {
+              array: ['u8', 32],
+            },
           },
           {
-            name: 'earnAuthority';
-            type: 'publicKey';
+            name: 'earners',
+            type: 'u64',
           },
           {
-            name: 'extMint';
-            type: 'publicKey';
+            name: 'claimedEarners',
+            type: 'u64',
           },
           {
-            name: 'mMint';
-            type: 'publicKey';
+            name: 'legacyEarners',
+            type: 'u64',
           },
           {
-            name: 'mEarnGlobalAccount';
-            type: 'publicKey';
+            name: 'claimsTimeout',
+            type: 'u64',
           },
           {
-            name: 'index';
-            type: 'u64';
+            name: 'mIndex',
+            type: 'u64',
           },
           {
-            name: 'timestamp';
-            type: 'u64';
+            name: 'reserved',
+            type: {
+              array: ['u8', 24],
+            },
           },
+        ],
+      },
+    },
+    {
+      name: 'roles',
+      type: {
+        kind: 'struct',
+        fields: [
           {
-            name: 'bump';
-            type: 'u8';
+            name: 'earnAuthorities',
+            type: {
+              vec: 'publicKey',
+            },
           },
           {
-            name: 'mVaultBump';
-            type: 'u8';
+            name: 'earnManagerAdmins',
+            type: {
+              vec: 'publicKey',
+            },
           },
           {
-            name: 'extMintAuthorityBump';
-            type: 'u8';
+            name: 'bump',
+            type: 'u8',
           },
-        ];
-      };
-    },
-  ];
-  events: [
-    {
-      name: 'SyncIndexUpdate';
-      fields: [
-        {
-          name: 'index';
-          type: 'u64';
-          index: false;
-        },
-        {
-          name: 'ts';
-          type: 'u64';
-          index: false;
-        },
-      ];
-    },
-  ];
-  errors: [
-    {
-      code: 6000;
-      name: 'AlreadyClaimed';
-      msg: 'Already claimed for user.';
-    },
-    {
-      code: 6001;
-      name: 'NotAuthorized';
-      msg: 'Invalid signer.';
-    },
-    {
-      code: 6002;
-      name: 'InvalidParam';
-      msg: 'Invalid parameter.';
-    },
-    {
-      code: 6003;
-      name: 'InvalidAccount';
-      msg: 'Account does not match the expected key.';
-    },
-    {
-      code: 6004;
-      name: 'Active';
-      msg: 'Account is currently active.';
+        ],
+      },
     },
     {
-      code: 6005;
-      name: 'NotActive';
-      msg: 'Account is not currently active.';
+      name: 'wrapper',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'wrapper',
+            type: 'publicKey',
+          },
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+        ],
+      },
     },
+  ],
+  types: [
     {
-      code: 6006;
-      name: 'MutableOwner';
-      msg: 'Token account owner is required to be immutable.';
+      name: 'ClaimPreview',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'rewards',
+            type: 'u64',
+          },
+          {
+            name: 'fee',
+            type: 'u64',
+          },
+          {
+            name: 'amount',
+            type: 'u64',
+          },
+          {
+            name: 'failure',
+            type: {
+              option: {
+                defined: 'ClaimFailure',
+              },
+            },
+          },
+        ],
+      },
     },
     {
-      code: 6007;
-      name: 'InsufficientCollateral';
-      msg: 'Not enough M.';
+      name: 'CompletedCycle',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'index',
+            type: 'u64',
+          },
+          {
+            name: 'previousIndex',
+            type: 'u64',
+          },
+          {
+            name: 'timestamp',
+            type: 'u64',
+          },
+          {
+            name: 'maxSupply',
+            type: 'u64',
+          },
+          {
+            name: 'maxYield',
+            type: 'u64',
+          },
+          {
+            name: 'distributed',
+            type: 'u64',
+          },
+          {
+            name: 'claims',
+            type: 'u64',
+          },
+          {
+            name: 'completedAt',
+            type: 'u64',
+          },
+          {
+            name: 'timedOut',
+            type: 'bool',
+          },
+        ],
+      },
     },
     {
-      code: 6008;
-      name: 'InvalidMint';
-      msg: 'Invalid Mint.';
+      name: 'ProgramEarner',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'user',
+            type: 'publicKey',
+          },
+          {
+            name: 'programId',
+            type: 'publicKey',
+          },
+        ],
+      },
     },
-  ];
-};
-
-export const IDL: ExtEarn = {
-  version: '0.1.0',
-  name: 'ext_earn',
-  constants: [
     {
-      name: 'EARN_MANAGER_SEED',
-      type: 'bytes',
-      value: '[101, 97, 114, 110, 95, 109, 97, 110, 97, 103, 101, 114]',
+      name: 'ProofElement',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'node',
+            type: {
+              array: ['u8', 32],
+            },
+          },
+          {
+            name: 'onRight',
+            type: 'bool',
+          },
+        ],
+      },
     },
     {
-      name: 'EARNER_SEED',
-      type: 'bytes',
-      value: '[101, 97, 114, 110, 101, 114]',
+      name: 'ClaimFailure',
+      type: {
+        kind: 'enum',
+        variants: [
+          {
+            name: 'AlreadyClaimed',
+          },
+          {
+            name: 'ExceedsMaxYield',
+          },
+          {
+            name: 'InsufficientCollateral',
+          },
+          {
+            name: 'NoActiveClaim',
+          },
+          {
+            name: 'Paused',
+          },
+          {
+            name: 'InvalidYieldMode',
+          },
+          {
+            name: 'InvalidVersion',
+          },
+        ],
+      },
     },
     {
-      name: 'EXT_GLOBAL_SEED',
-      type: 'bytes',
-      value: '[103, 108, 111, 98, 97, 108]',
+      name: 'YieldMode',
+      type: {
+        kind: 'enum',
+        variants: [
+          {
+            name: 'Claim',
+          },
+          {
+            name: 'Rebasing',
+          },
+        ],
+      },
     },
     {
-      name: 'M_VAULT_SEED',
-      type: 'bytes',
-      value: '[109, 95, 118, 97, 117, 108, 116]',
+      name: 'Allowlist',
+      type: {
+        kind: 'enum',
+        variants: [
+          {
+            name: 'Open',
+          },
+          {
+            name: 'Pda',
+          },
+          {
+            name: 'MerkleRoot',
+          },
+        ],
+      },
     },
     {
-      name: 'MINT_AUTHORITY_SEED',
-      type: 'bytes',
-      value: '[109, 105, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]',
+      name: 'Role',
+      type: {
+        kind: 'enum',
+        variants: [
+          {
+            name: 'EarnAuthority',
+          },
+          {
+            name: 'EarnManagerAdmin',
+          },
+        ],
+      },
     },
   ],
-  instructions: [
+  events: [
     {
-      name: 'initialize',
-      accounts: [
-        {
-          name: 'admin',
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: 'globalAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'mMint',
-          isMut: false,
-          isSigner: false,
-        },
+      name: 'EarnManagerAdded',
+      fields: [
         {
           name: 'extMint',
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: 'mEarnGlobalAccount',
-          isMut: false,
-          isSigner: false,
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'token2022',
-          isMut: false,
-          isSigner: false,
+          name: 'earnManager',
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'systemProgram',
-          isMut: false,
-          isSigner: false,
+          name: 'feeBps',
+          type: 'u64',
+          index: false,
         },
-      ],
-      args: [
         {
-          name: 'earnAuthority',
+          name: 'feeTokenAccount',
           type: 'publicKey',
+          index: false,
         },
       ],
     },
     {
-      name: 'setEarnAuthority',
-      accounts: [
-        {
-          name: 'admin',
-          isMut: false,
-          isSigner: true,
-        },
+      name: 'WrapperAdded',
+      fields: [
         {
-          name: 'globalAccount',
-          isMut: true,
-          isSigner: false,
+          name: 'extMint',
+          type: 'publicKey',
+          index: false,
         },
-      ],
-      args: [
         {
-          name: 'newEarnAuthority',
+          name: 'wrapper',
           type: 'publicKey',
+          index: false,
         },
       ],
     },
     {
-      name: 'addEarnManager',
-      accounts: [
-        {
-          name: 'admin',
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: 'globalAccount',
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: 'earnManagerAccount',
-          isMut: true,
-          isSigner: false,
-        },
+      name: 'RoleGranted',
+      fields: [
         {
-          name: 'feeTokenAccount',
-          isMut: false,
-          isSigner: false,
+          name: 'role',
+          type: {
+            defined: 'Role',
+          },
+          index: false,
         },
         {
-          name: 'systemProgram',
-          isMut: false,
-          isSigner: false,
+          name: 'member',
+          type: 'publicKey',
+          index: false,
         },
       ],
-      args: [
+    },
+    {
+      name: 'LegacyGlobalMigrated',
+      fields: [
         {
-          name: 'earnManager',
+          name: 'extMint',
           type: 'publicKey',
+          index: false,
         },
         {
-          name: 'feeBps',
+          name: 'collateral',
           type: 'u64',
+          index: false,
         },
       ],
     },
     {
-      name: 'removeEarnManager',
-      accounts: [
-        {
-          name: 'admin',
-          isMut: false,
-          isSigner: true,
-        },
+      name: 'EarnManagerRemoved',
+      fields: [
         {
-          name: 'globalAccount',
-          isMut: false,
-          isSigner: false,
+          name: 'extMint',
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'earnManagerAccount',
-          isMut: true,
-          isSigner: false,
+          name: 'earnManager',
+          type: 'publicKey',
+          index: false,
         },
       ],
-      args: [],
     },
     {
-      name: 'claimFor',
-      accounts: [
-        {
-          name: 'earnAuthority',
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: 'globalAccount',
-          isMut: true,
-          isSigner: false,
-        },
+      name: 'WrapperRemoved',
+      fields: [
         {
           name: 'extMint',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'extMintAuthority',
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: 'mVaultAccount',
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: 'vaultMTokenAccount',
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: 'userTokenAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'earnerAccount',
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: 'earnManagerAccount',
-          isMut: false,
-          isSigner: false,
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'earnManagerTokenAccount',
-          isMut: true,
-          isSigner: false,
-          docs: [
-            'if the token account has been closed or is not initialized',
-            'This prevents DoSing earner yield by closing this account',
-          ],
+          name: 'wrapper',
+          type: 'publicKey',
+          index: false,
         },
+      ],
+    },
+    {
+      name: 'RoleRevoked',
+      fields: [
         {
-          name: 'token2022',
-          isMut: false,
-          isSigner: false,
+          name: 'role',
+          type: {
+            defined: 'Role',
+          },
+          index: false,
         },
-      ],
-      args: [
         {
-          name: 'snapshotBalance',
-          type: 'u64',
+          name: 'member',
+          type: 'publicKey',
+          index: false,
         },
       ],
     },
     {
-      name: 'sync',
-      accounts: [
-        {
-          name: 'earnAuthority',
-          isMut: false,
-          isSigner: true,
+      name: 'AllowlistUpdate',
+      fields: [
+        {
+          name: 'extMint',
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'mEarnGlobalAccount',
-          isMut: false,
-          isSigner: false,
+          name: 'allowlist',
+          type: {
+            defined: 'Allowlist',
+          },
+          index: false,
         },
         {
-          name: 'globalAccount',
-          isMut: true,
-          isSigner: false,
+          name: 'wrapperMerkleRoot',
+          type: {
+            array: ['u8', 32],
+          },
+          index: false,
         },
       ],
-      args: [],
     },
     {
-      name: 'addEarner',
-      accounts: [
-        {
-          name: 'signer',
-          isMut: true,
-          isSigner: true,
-        },
+      name: 'RemovalBountyUpdate',
+      fields: [
         {
-          name: 'earnManagerAccount',
-          isMut: false,
-          isSigner: false,
+          name: 'extMint',
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'globalAccount',
-          isMut: false,
-          isSigner: false,
+          name: 'removalBounty',
+          type: 'u64',
+          index: false,
         },
+      ],
+    },
+    {
+      name: 'WrapFeesUpdate',
+      fields: [
         {
-          name: 'userTokenAccount',
-          isMut: false,
-          isSigner: false,
+          name: 'extMint',
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'earnerAccount',
-          isMut: true,
-          isSigner: false,
+          name: 'wrapFeeBps',
+          type: 'u64',
+          index: false,
         },
         {
-          name: 'systemProgram',
-          isMut: false,
-          isSigner: false,
+          name: 'unwrapFeeBps',
+          type: 'u64',
+          index: false,
         },
-      ],
-      args: [
         {
-          name: 'user',
+          name: 'feeTokenAccount',
           type: 'publicKey',
+          index: false,
         },
       ],
     },
     {
-      name: 'removeEarner',
-      accounts: [
+      name: 'YieldRecipientUpdate',
+      fields: [
         {
-          name: 'signer',
-          isMut: true,
-          isSigner: true,
+          name: 'extMint',
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'earnerAccount',
-          isMut: true,
-          isSigner: false,
+          name: 'yieldRecipient',
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'earnManagerAccount',
-          isMut: false,
-          isSigner: false,
+          name: 'excessBufferBps',
+          type: 'u64',
+          index: false,
         },
         {
-          name: 'systemProgram',
-          isMut: false,
-          isSigner: false,
+          name: 'claimsTimeout',
+          type: 'u64',
+          index: false,
         },
       ],
-      args: [],
     },
     {
-      name: 'configureEarnManager',
-      accounts: [
+      name: 'ExcessClaimed',
+      fields: [
         {
-          name: 'signer',
-          isMut: true,
-          isSigner: true,
+          name: 'extMint',
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'globalAccount',
-          isMut: false,
-          isSigner: false,
+          name: 'recipient',
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'earnManagerAccount',
-          isMut: true,
-          isSigner: false,
+          name: 'amount',
+          type: 'u64',
+          index: false,
         },
         {
-          name: 'feeTokenAccount',
-          isMut: false,
-          isSigner: false,
-          isOptional: true,
+          name: 'minted',
+          type: 'bool',
+          index: false,
         },
-      ],
-      args: [
         {
-          name: 'feeBps',
-          type: {
-            option: 'u64',
-          },
+          name: 'collateral',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'supply',
+          type: 'u64',
+          index: false,
         },
       ],
     },
     {
-      name: 'transferEarner',
-      accounts: [
+      name: 'SyncIndexUpdate',
+      fields: [
         {
-          name: 'signer',
-          isMut: false,
-          isSigner: true,
+          name: 'index',
+          type: 'u64',
+          index: false,
         },
         {
-          name: 'earnerAccount',
-          isMut: true,
-          isSigner: false,
+          name: 'ts',
+          type: 'u64',
+          index: false,
         },
+      ],
+    },
+    {
+      name: 'EarnerAdded',
+      fields: [
         {
-          name: 'fromEarnManagerAccount',
-          isMut: false,
-          isSigner: false,
+          name: 'extMint',
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'toEarnManagerAccount',
-          isMut: false,
-          isSigner: false,
+          name: 'earnManager',
+          type: 'publicKey',
+          index: false,
         },
-      ],
-      args: [
         {
-          name: 'toEarnManager',
+          name: 'user',
           type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'userTokenAccount',
+          type: 'publicKey',
+          index: false,
         },
       ],
     },
     {
-      name: 'setRecipient',
-      accounts: [
+      name: 'EarnManagerConfigured',
+      fields: [
         {
-          name: 'signer',
-          isMut: false,
-          isSigner: true,
+          name: 'extMint',
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'globalAccount',
-          isMut: false,
-          isSigner: false,
+          name: 'earnManager',
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'earnerAccount',
-          isMut: true,
-          isSigner: false,
+          name: 'feeBps',
+          type: 'u64',
+          index: false,
         },
         {
-          name: 'recipientTokenAccount',
-          isMut: false,
-          isSigner: false,
-          isOptional: true,
+          name: 'feeTokenAccount',
+          type: 'publicKey',
+          index: false,
         },
       ],
-      args: [],
     },
     {
-      name: 'wrap',
-      accounts: [
+      name: 'EarnerRemoved',
+      fields: [
         {
-          name: 'signer',
-          isMut: false,
-          isSigner: true,
+          name: 'extMint',
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'mMint',
-          isMut: false,
-          isSigner: false,
+          name: 'earnManager',
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'extMint',
-          isMut: true,
-          isSigner: false,
+          name: 'user',
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'globalAccount',
-          isMut: false,
-          isSigner: false,
+          name: 'userTokenAccount',
+          type: 'publicKey',
+          index: false,
         },
+      ],
+    },
+    {
+      name: 'EarnerTransferred',
+      fields: [
         {
-          name: 'mVault',
-          isMut: false,
-          isSigner: false,
+          name: 'extMint',
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'extMintAuthority',
-          isMut: false,
-          isSigner: false,
+          name: 'userTokenAccount',
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'fromMTokenAccount',
-          isMut: true,
-          isSigner: false,
+          name: 'fromEarnManager',
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'vaultMTokenAccount',
-          isMut: true,
-          isSigner: false,
+          name: 'toEarnManager',
+          type: 'publicKey',
+          index: false,
         },
+      ],
+    },
+    {
+      name: 'EarnerMigrated',
+      fields: [
         {
-          name: 'toExtTokenAccount',
-          isMut: true,
-          isSigner: false,
+          name: 'user',
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'token2022',
-          isMut: false,
-          isSigner: false,
+          name: 'oldUserTokenAccount',
+          type: 'publicKey',
+          index: false,
         },
-      ],
-      args: [
         {
-          name: 'amount',
-          type: 'u64',
+          name: 'newUserTokenAccount',
+          type: 'publicKey',
+          index: false,
         },
       ],
     },
     {
-      name: 'unwrap',
-      accounts: [
+      name: 'RecipientSet',
+      fields: [
         {
-          name: 'signer',
-          isMut: false,
-          isSigner: true,
+          name: 'extMint',
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'mMint',
-          isMut: false,
-          isSigner: false,
+          name: 'userTokenAccount',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'recipientTokenAccount',
+          type: {
+            option: 'publicKey',
+          },
+          index: false,
         },
+      ],
+    },
+    {
+      name: 'Unwrap',
+      fields: [
         {
           name: 'extMint',
-          isMut: true,
-          isSigner: false,
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'globalAccount',
-          isMut: false,
-          isSigner: false,
+          name: 'signer',
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'mVault',
-          isMut: false,
-          isSigner: false,
+          name: 'source',
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'toMTokenAccount',
-          isMut: true,
-          isSigner: false,
+          name: 'destination',
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'vaultMTokenAccount',
-          isMut: true,
-          isSigner: false,
+          name: 'amount',
+          type: 'u64',
+          index: false,
         },
         {
-          name: 'fromExtTokenAccount',
-          isMut: true,
-          isSigner: false,
+          name: 'fee',
+          type: 'u64',
+          index: false,
         },
         {
-          name: 'token2022',
-          isMut: false,
-          isSigner: false,
+          name: 'mAmount',
+          type: 'u64',
+          index: false,
         },
-      ],
-      args: [
         {
-          name: 'amount',
+          name: 'extSupply',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'collateral',
           type: 'u64',
+          index: false,
         },
       ],
     },
     {
-      name: 'removeOrphanedEarner',
-      accounts: [
+      name: 'Wrap',
+      fields: [
+        {
+          name: 'extMint',
+          type: 'publicKey',
+          index: false,
+        },
         {
           name: 'signer',
-          isMut: true,
-          isSigner: true,
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'globalAccount',
-          isMut: false,
-          isSigner: false,
+          name: 'source',
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'earnerAccount',
-          isMut: true,
-          isSigner: false,
+          name: 'destination',
+          type: 'publicKey',
+          index: false,
         },
         {
-          name: 'earnManagerAccount',
-          isMut: false,
-          isSigner: false,
+          name: 'amount',
+          type: 'u64',
+          index: false,
         },
         {
-          name: 'systemProgram',
-          isMut: false,
-          isSigner: false,
+          name: 'fee',
+          type: 'u64',
+          index: false,
         },
-      ],
-      args: [],
-    },
-  ],
-  accounts: [
-    {
-      name: 'earnManager',
-      type: {
-        kind: 'struct',
-        fields: [
-          {
-            name: 'earnManager',
-            type: 'publicKey',
-          },
-          {
-            name: 'isActive',
-            type: 'bool',
-          },
-          {
-            name: 'feeBps',
-            type: 'u64',
-          },
-          {
-            name: 'feeTokenAccount',
-            type: 'publicKey',
-          },
-          {
-            name: 'bump',
-            type: 'u8',
-          },
-        ],
-      },
-    },
-    {
-      name: 'earner',
-      type: {
-        kind: 'struct',
-        fields: [
-          {
-            name: 'lastClaimIndex',
-            type: 'u64',
-          },
-          {
-            name: 'lastClaimTimestamp',
-            type: 'u64',
-          },
-          {
-            name: 'bump',
-            type: 'u8',
-          },
-          {
-            name: 'user',
-            type: 'publicKey',
-          },
-          {
-            name: 'userTokenAccount',
-            type: 'publicKey',
-          },
-          {
-            name: 'earnManager',
-            type: 'publicKey',
-          },
-          {
-            name: 'recipientTokenAccount',
-            type: {
-              option: 'publicKey',
-            },
-          },
-        ],
-      },
-    },
-    {
-      name: 'extGlobal',
-      type: {
-        kind: 'struct',
-        fields: [
-          {
-            name: 'admin',
-            type: 'publicKey',
-          },
-          {
-            name: 'earnAuthority',
-            type: 'publicKey',
-          },
-          {
-            name: 'extMint',
-            type: 'publicKey',
-          },
-          {
-            name: 'mMint',
-            type: 'publicKey',
-          },
-          {
-            name: 'mEarnGlobalAccount',
-            type: 'publicKey',
-          },
-          {
-            name: 'index',
-            type: 'u64',
-          },
-          {
-            name: 'timestamp',
-            type: 'u64',
-          },
-          {
-            name: 'bump',
-            type: 'u8',
-          },
-          {
-            name: 'mVaultBump',
-            type: 'u8',
-          },
-          {
-            name: 'extMintAuthorityBump',
-            type: 'u8',
-          },
-        ],
-      },
-    },
-  ],
-  events: [
-    {
-      name: 'SyncIndexUpdate',
-      fields: [
         {
-          name: 'index',
+          name: 'extAmount',
           type: 'u64',
           index: false,
         },
         {
-          name: 'ts',
+          name: 'extSupply',
+          type: 'u64',
+          index: false,
+        },
+        {
+          name: 'collateral',
           type: 'u64',
           index: false,
         },
@@ -1475,5 +4962,30 @@ export const IDL: ExtEarn = {
       name: 'InvalidMint',
       msg: 'Invalid Mint.',
     },
+    {
+      code: 6009,
+      name: 'RoleFull',
+      msg: 'Role has reached the maximum number of members.',
+    },
+    {
+      code: 6010,
+      name: 'InvalidVersion',
+      msg: 'Account is not on the expected version.',
+    },
+    {
+      code: 6011,
+      name: 'InvalidYieldMode',
+      msg: 'Not supported in the yield mode of the extension.',
+    },
+    {
+      code: 6012,
+      name: 'IndexNotSynced',
+      msg: 'Index has not been synced with the M earn index.',
+    },
+    {
+      code: 6013,
+      name: 'ClaimsPending',
+      msg: 'Earners have not all been claimed for the current index.',
+    },
   ],
 };
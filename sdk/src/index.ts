@@ -9,7 +9,28 @@ export const MINT = new PublicKey('mzerokyEX9TNDoK4o2YZQBDmMzjokAeN6M2g2S3pLJo')
 export const EXT_MINT = new PublicKey('mzeroXDoBpRVhnEXBra27qzAMdxgpWVY3DzQW7xMVJp');
 export const MINT_MULTISIG = new PublicKey('ms2SCrTYioPuumF6oBvReXoVRizEW5qYkiVuUEak7Th');
 export const GLOBAL_ACCOUNT = PublicKey.findProgramAddressSync([Buffer.from('global')], PROGRAM_ID)[0];
-export const EXT_GLOBAL_ACCOUNT = PublicKey.findProgramAddressSync([Buffer.from('global')], EXT_PROGRAM_ID)[0];
+
+// Accounts of the extension program are keyed by the extension mint
+export const getExtGlobalAccount = (extMint: PublicKey = EXT_MINT) =>
+  PublicKey.findProgramAddressSync([Buffer.from('global'), extMint.toBuffer()], EXT_PROGRAM_ID)[0];
+export const getExtMVault = (extMint: PublicKey = EXT_MINT) =>
+  PublicKey.findProgramAddressSync([Buffer.from('m_vault'), extMint.toBuffer()], EXT_PROGRAM_ID)[0];
+export const getExtMintAuthority = (extMint: PublicKey = EXT_MINT) =>
+  PublicKey.findProgramAddressSync([Buffer.from('mint_authority'), extMint.toBuffer()], EXT_PROGRAM_ID)[0];
+export const getExtEarnManagerAccount = (manager: PublicKey, extMint: PublicKey = EXT_MINT) =>
+  PublicKey.findProgramAddressSync(
+    [Buffer.from('earn_manager'), extMint.toBuffer(), manager.toBuffer()],
+    EXT_PROGRAM_ID,
+  )[0];
+export const getExtEarnerAccount = (tokenAccount: PublicKey, extMint: PublicKey = EXT_MINT) =>
+  PublicKey.findProgramAddressSync(
+    [Buffer.from('earner'), extMint.toBuffer(), tokenAccount.toBuffer()],
+    EXT_PROGRAM_ID,
+  )[0];
+export const getExtRolesAccount = (extMint: PublicKey = EXT_MINT) =>
+  PublicKey.findProgramAddressSync([Buffer.from('roles'), extMint.toBuffer()], EXT_PROGRAM_ID)[0];
+
+export const EXT_GLOBAL_ACCOUNT = getExtGlobalAccount();
 export const EARN_ADDRESS_TABLE = new PublicKey('Aq87DiRe8thyDfPhkpe92umFj9VU6bt8o9S9MTAhNC6c');
 export const EARN_ADDRESS_TABLE_DEVNET = new PublicKey('HtKQ9sHyMhun73asZsARkGCc1fDz2dQH7QhGfFJcQo7S');
 
@@ -383,3 +383,16 @@ export class MerkleTree {
     return { proofs, neighbors };
   }
 }
+
+// Leaf of a claims root committed by the earn authority, a single leaf is its own root
+export const hashClaimLeaf = (tokenAccount: PublicKey, snapshotBalance: bigint): number[] => {
+  const balance = Buffer.alloc(8);
+  balance.writeBigUInt64LE(snapshotBalance);
+
+  const hasher = new Keccak(256);
+  hasher.update(Buffer.from([0x00]));
+  hasher.update(tokenAccount.toBuffer());
+  hasher.update(balance);
+
+  return Array.from(hasher.digest());
+};
@@ -7,7 +7,14 @@ import {
 } from '@solana/spl-token';
 
 import { Fireblocks, FeeLevel, TransactionOperation, TransactionRequest } from '@fireblocks/ts-sdk';
-import { EXT_PROGRAM_ID, PROGRAM_ID } from '../../sdk/src';
+import {
+  EXT_PROGRAM_ID,
+  getExtGlobalAccount,
+  getExtMintAuthority,
+  getExtMVault,
+  GLOBAL_ACCOUNT,
+  PROGRAM_ID,
+} from '../../sdk/src';
 
 import { Program, BN } from '@coral-xyz/anchor';
 import { ExtEarn } from '../../target/types/ext_earn';
@@ -131,26 +138,29 @@ async function main() {
       const [owner, mMint, wmMint] = keysFromEnv(['PAYER_KEYPAIR', 'M_MINT_KEYPAIR', 'WM_MINT_KEYPAIR']);
       const extEarn = new Program<ExtEarn>(EXT_EARN_IDL, PROGRAMS.extEarn, anchorProvider(connection, owner));
 
-      const [extGlobalAccount] = PublicKey.findProgramAddressSync([Buffer.from('global')], PROGRAMS.extEarn);
-      const [mVault] = PublicKey.findProgramAddressSync([Buffer.from('m_vault')], PROGRAMS.extEarn);
-      const [extMintAuthority] = PublicKey.findProgramAddressSync([Buffer.from('mint_authority')], PROGRAMS.extEarn);
+      const extGlobalAccount = getExtGlobalAccount(wmMint.publicKey);
+      const mVault = getExtMVault(wmMint.publicKey);
+      const extMintAuthority = getExtMintAuthority(wmMint.publicKey);
 
       const vaultMTokenAccount = getAssociatedTokenAddressSync(mMint.publicKey, mVault, true, TOKEN_2022_PROGRAM_ID);
       const fbMTokenAccount = getAssociatedTokenAddressSync(mMint.publicKey, fbAddress, true, TOKEN_2022_PROGRAM_ID);
       const fbExtTokenAccount = getAssociatedTokenAddressSync(wmMint.publicKey, fbAddress, true, TOKEN_2022_PROGRAM_ID);
 
       const txn = await extEarn.methods
-        .wrap(new BN(amount))
+        .wrap(new BN(amount), null)
         .accounts({
           signer: new PublicKey(fbAddress ?? ''),
           mMint: mMint.publicKey,
           extMint: wmMint.publicKey,
           globalAccount: extGlobalAccount,
+          mEarnGlobalAccount: GLOBAL_ACCOUNT,
           mVault,
           extMintAuthority,
           fromMTokenAccount: fbMTokenAccount,
           vaultMTokenAccount: vaultMTokenAccount,
           toExtTokenAccount: fbExtTokenAccount,
+          wrapperAccount: null,
+          feeTokenAccount: null,
           token2022: TOKEN_2022_PROGRAM_ID,
         })
         .transaction();
@@ -173,23 +183,26 @@ async function main() {
 
       const [owner, mMint, wmMint] = keysFromEnv(['PAYER_KEYPAIR', 'M_MINT_KEYPAIR', 'WM_MINT_KEYPAIR']);
       const extEarn = new Program<ExtEarn>(EXT_EARN_IDL, PROGRAMS.extEarn, anchorProvider(connection, owner));
-      const [extGlobalAccount] = PublicKey.findProgramAddressSync([Buffer.from('global')], PROGRAMS.extEarn);
-      const [mVault] = PublicKey.findProgramAddressSync([Buffer.from('m_vault')], PROGRAMS.extEarn);
+      const extGlobalAccount = getExtGlobalAccount(wmMint.publicKey);
+      const mVault = getExtMVault(wmMint.publicKey);
       const vaultMTokenAccount = getAssociatedTokenAddressSync(mMint.publicKey, mVault, true, TOKEN_2022_PROGRAM_ID);
       const fbMTokenAccount = getAssociatedTokenAddressSync(mMint.publicKey, fbAddress, true, TOKEN_2022_PROGRAM_ID);
       const fbExtTokenAccount = getAssociatedTokenAddressSync(wmMint.publicKey, fbAddress, true, TOKEN_2022_PROGRAM_ID);
 
       const txn = await extEarn.methods
-        .unwrap(new BN(amount))
+        .unwrap(new BN(amount), null)
         .accounts({
           signer: new PublicKey(fbAddress ?? ''),
           mMint: mMint.publicKey,
           extMint: wmMint.publicKey,
           globalAccount: extGlobalAccount,
+          mEarnGlobalAccount: GLOBAL_ACCOUNT,
           mVault,
           fromExtTokenAccount: fbExtTokenAccount,
           vaultMTokenAccount: vaultMTokenAccount,
           toMTokenAccount: fbMTokenAccount,
+          wrapperAccount: null,
+          feeTokenAccount: null,
           token2022: TOKEN_2022_PROGRAM_ID,
         })
         .transaction();
@@ -35,7 +35,17 @@ import {
   TokenMetadata,
 } from '@solana/spl-token-metadata';
 import { Chain, ChainAddress, UniversalAddress, assertChain, signSendWait } from '@wormhole-foundation/sdk';
-import { createPublicClient, EXT_GLOBAL_ACCOUNT, EXT_MINT, http, EarnAuthority } from '../../sdk/src';
+import {
+  createPublicClient,
+  EXT_GLOBAL_ACCOUNT,
+  EXT_MINT,
+  getExtEarnManagerAccount,
+  getExtGlobalAccount,
+  getExtMintAuthority,
+  getExtMVault,
+  http,
+  EarnAuthority,
+} from '../../sdk/src';
 
 import { createSetEvmAddresses } from '../../tests/test-utils';
 import { createInitializeConfidentialTransferMintInstruction } from './confidential-transfers';
@@ -82,8 +92,8 @@ async function main() {
       const [mMint, wmMint, multisig] = keysFromEnv(['M_MINT_KEYPAIR', 'WM_MINT_KEYPAIR', 'M_MINT_MULTISIG_KEYPAIR']);
       const [portalTokenAuthPda] = PublicKey.findProgramAddressSync([Buffer.from('token_authority')], PROGRAMS.portal);
       const [earnTokenAuthPda] = PublicKey.findProgramAddressSync([Buffer.from('token_authority')], PROGRAMS.earn);
-      const [mVaultPda] = PublicKey.findProgramAddressSync([Buffer.from('m_vault')], PROGRAMS.extEarn);
-      const [mintAuthPda] = PublicKey.findProgramAddressSync([Buffer.from('mint_authority')], PROGRAMS.extEarn);
+      const mVaultPda = getExtMVault(wmMint.publicKey);
+      const mintAuthPda = getExtMintAuthority(wmMint.publicKey);
       const [portalEmitter] = PublicKey.findProgramAddressSync([Buffer.from('emitter')], PROGRAMS.portal);
       const nttQuoter = new PublicKey('Nqd6XqA8LbsCuG8MLWWuP865NV6jR1MbXeKxD4HLKDJ');
       const [quoterRegisteredNtt] = PublicKey.findProgramAddressSync(
@@ -133,7 +143,7 @@ async function main() {
     .action(async () => {
       const [owner] = keysFromEnv(['PAYER_KEYPAIR']);
       const extEarn = new Program<ExtEarn>(EXT_EARN_IDL, PROGRAMS.extEarn, anchorProvider(connection, owner));
-      const [globalAccount] = PublicKey.findProgramAddressSync([Buffer.from('global')], PROGRAMS.extEarn);
+      const globalAccount = getExtGlobalAccount();
       const global = await extEarn.account.extGlobal.fetch(globalAccount);
       console.log('ExtEarn Global State:', global);
     });
@@ -201,7 +211,7 @@ async function main() {
         authority = new PublicKey(owner);
       }
 
-      const mintAuthority = PublicKey.findProgramAddressSync([Buffer.from('mint_authority')], PROGRAMS.extEarn)[0];
+      const mintAuthority = getExtMintAuthority(mint.publicKey);
       const freezeAuthority = new PublicKey(freezeAuth);
 
       await createToken2022Mint(
@@ -313,7 +323,7 @@ async function main() {
 
       const extEarn = new Program<ExtEarn>(EXT_EARN_IDL, PROGRAMS.extEarn, anchorProvider(connection, owner));
       const [earnGlobalAccount] = PublicKey.findProgramAddressSync([Buffer.from('global')], PROGRAMS.earn);
-      const [extGlobalAccount] = PublicKey.findProgramAddressSync([Buffer.from('global')], PROGRAMS.extEarn);
+      const extGlobalAccount = getExtGlobalAccount(wmMint.publicKey);
 
       let earnAuth = owner.publicKey;
       let admin = owner.publicKey;
@@ -501,10 +511,7 @@ async function main() {
       const [owner] = keysFromEnv(['PAYER_KEYPAIR']);
       const extEarn = new Program<ExtEarn>(EXT_EARN_IDL, PROGRAMS.extEarn, anchorProvider(connection, owner));
 
-      const [earnManagerAccount] = PublicKey.findProgramAddressSync(
-        [Buffer.from('earn_manager'), owner.publicKey.toBuffer()],
-        PROGRAMS.extEarn,
-      );
+      const earnManagerAccount = getExtEarnManagerAccount(owner.publicKey);
 
       const managerATA = await getOrCreateAssociatedTokenAccount(
         connection,
@@ -598,10 +605,10 @@ async function main() {
       const [mMint, wmMint, multisig] = keysFromEnv(['M_MINT_KEYPAIR', 'WM_MINT_KEYPAIR', 'M_MINT_MULTISIG_KEYPAIR']);
       const [portalTokenAuthPda] = PublicKey.findProgramAddressSync([Buffer.from('token_authority')], PROGRAMS.portal);
       const [earnTokenAuthPda] = PublicKey.findProgramAddressSync([Buffer.from('token_authority')], PROGRAMS.earn);
-      const [mVaultPda] = PublicKey.findProgramAddressSync([Buffer.from('m_vault')], PROGRAMS.extEarn);
-      const [mintAuthPda] = PublicKey.findProgramAddressSync([Buffer.from('mint_authority')], PROGRAMS.extEarn);
+      const mVaultPda = getExtMVault(wmMint.publicKey);
+      const mintAuthPda = getExtMintAuthority(wmMint.publicKey);
       const [global] = PublicKey.findProgramAddressSync([Buffer.from('global')], PROGRAM_ID);
-      const [extGlobal] = PublicKey.findProgramAddressSync([Buffer.from('global')], EXT_PROGRAM_ID);
+      const extGlobal = getExtGlobalAccount(wmMint.publicKey);
 
       const globalAccount = await getProgram(connection).account.global.fetch(global);
       const extGlobalAccount = await getExtProgram(connection).account.extGlobal.fetch(extGlobal);
@@ -3,7 +3,15 @@ import { signSendWait, UniversalAddress } from '@wormhole-foundation/sdk';
 import { Command } from 'commander';
 import * as multisig from '@sqds/multisig';
 import { anchorProvider, keysFromEnv, NttManager } from './utils';
-import { EXT_GLOBAL_ACCOUNT, EXT_PROGRAM_ID } from '../../sdk/src';
+import {
+  EXT_PROGRAM_ID,
+  getExtEarnerAccount,
+  getExtEarnManagerAccount,
+  getExtGlobalAccount,
+  getExtMintAuthority,
+  getExtMVault,
+  GLOBAL_ACCOUNT,
+} from '../../sdk/src';
 import {
   createAssociatedTokenAccountInstruction,
   createTransferCheckedInstruction,
@@ -27,8 +35,8 @@ async function main() {
       const [sender, m, wM] = keysFromEnv(['PAYER_KEYPAIR', 'M_MINT_KEYPAIR', 'WM_MINT_KEYPAIR']);
       const program = new Program<ExtEarn>(EXT_EARN_IDL, EXT_PROGRAM_ID, anchorProvider(connection, sender));
 
-      const mVault = PublicKey.findProgramAddressSync([Buffer.from('m_vault')], EXT_PROGRAM_ID)[0];
-      const extMintAuthority = PublicKey.findProgramAddressSync([Buffer.from('mint_authority')], EXT_PROGRAM_ID)[0];
+      const mVault = getExtMVault(wM.publicKey);
+      const extMintAuthority = getExtMintAuthority(wM.publicKey);
 
       const atas: PublicKey[] = [];
       for (const [mint, owner] of [
@@ -53,17 +61,20 @@ async function main() {
       await new Promise((resolve) => setTimeout(resolve, 2500));
 
       const sig = await program.methods
-        .wrap(amount)
+        .wrap(amount, null)
         .accounts({
           signer: sender.publicKey,
           mMint: m.publicKey,
           extMint: wM.publicKey,
-          globalAccount: EXT_GLOBAL_ACCOUNT,
+          globalAccount: getExtGlobalAccount(wM.publicKey),
+          mEarnGlobalAccount: GLOBAL_ACCOUNT,
           mVault,
           extMintAuthority,
           fromMTokenAccount,
           vaultMTokenAccount,
           toExtTokenAccount,
+          wrapperAccount: null,
+          feeTokenAccount: null,
           token2022: TOKEN_2022_PROGRAM_ID,
         })
         .signers([sender])
@@ -183,21 +194,15 @@ async function main() {
           ),
         );
 
-        const [earnManagerAccount] = PublicKey.findProgramAddressSync(
-          [Buffer.from('earn_manager'), owner.publicKey.toBytes()],
-          EXT_PROGRAM_ID,
-        );
-        const [earnerAccount] = PublicKey.findProgramAddressSync(
-          [Buffer.from('earner'), associatedToken.toBytes()],
-          EXT_PROGRAM_ID,
-        );
+        const earnManagerAccount = getExtEarnManagerAccount(owner.publicKey, mint.publicKey);
+        const earnerAccount = getExtEarnerAccount(associatedToken, mint.publicKey);
 
         // register them as earners
         ixs.push(
           await program.methods
             .addEarner(user.publicKey)
             .accounts({
-              globalAccount: EXT_GLOBAL_ACCOUNT,
+              globalAccount: getExtGlobalAccount(mint.publicKey),
               earnManagerAccount,
               userTokenAccount: associatedToken,
               earnerAccount,
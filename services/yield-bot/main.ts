@@ -127,6 +127,13 @@ async function validation(opt: ParsedOptions) {
 async function distributeYield(opt: ParsedOptions) {
   const auth = await EarnAuthority.load(opt.connection, opt.evmClient, opt.programID, logger);
 
+  // record a cycle that timed out so that the next one can complete
+  const recordCycleIx = await auth.buildRecordClaimCycleInstruction();
+  if (recordCycleIx) {
+    const signature = await buildAndSendTransaction(opt, [recordCycleIx]);
+    logger.info('claim cycle recorded', { signature });
+  }
+
   if (auth['global'].claimComplete) {
     logger.info('claim cycle already complete');
     return true;
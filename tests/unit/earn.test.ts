@@ -1,7 +1,15 @@
 import { Program, AnchorError, BN } from '@coral-xyz/anchor';
 import { LiteSVM } from 'litesvm';
 import { fromWorkspace, LiteSVMProvider } from 'anchor-litesvm';
-import { PublicKey, Keypair, LAMPORTS_PER_SOL, SystemProgram, Transaction } from '@solana/web3.js';
+import {
+  PublicKey,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  SystemProgram,
+  Transaction,
+  Ed25519Program,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+} from '@solana/web3.js';
 import {
   ACCOUNT_SIZE,
   ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -15,11 +23,14 @@ import {
   createInitializeAccountInstruction,
   createInitializeMultisigInstruction,
   createMintToCheckedInstruction,
+  createBurnCheckedInstruction,
   getAccountLen,
+  createInitializeImmutableOwnerInstruction,
+  ExtensionType,
 } from '@solana/spl-token';
 import { randomInt } from 'crypto';
 
-import { MerkleTree, ProofElement } from '../../sdk/src/merkle';
+import { MerkleTree, ProofElement, hashClaimLeaf } from '../../sdk/src/merkle';
 import { loadKeypair } from '../test-utils';
 import { Earn } from '../../target/types/earn';
 
@@ -96,6 +107,15 @@ const getEarnTokenAuthority = () => {
   return earnTokenAuthority;
 };
 
+const getClaimDistributionAccount = (index: BN) => {
+  const [claimDistribution] = PublicKey.findProgramAddressSync(
+    [Buffer.from('claim_distribution'), index.toArrayLike(Buffer, 'le', 8)],
+    earn.programId,
+  );
+
+  return claimDistribution;
+};
+
 const getEarnerAccount = (tokenAccount: PublicKey) => {
   const [earnerAccount] = PublicKey.findProgramAddressSync(
     [Buffer.from('earner'), tokenAccount.toBuffer()],
@@ -105,6 +125,28 @@ const getEarnerAccount = (tokenAccount: PublicKey) => {
   return earnerAccount;
 };
 
+const getPendingIndexAccount = () => {
+  const [pendingIndexAccount] = PublicKey.findProgramAddressSync([Buffer.from('pending_index')], earn.programId);
+
+  return pendingIndexAccount;
+};
+
+// The pending index account is only created when the index bounds are set
+const getOptionalPendingIndexAccount = () => {
+  const pendingIndexAccount = getPendingIndexAccount();
+
+  return svm.getAccount(pendingIndexAccount) ? pendingIndexAccount : null;
+};
+
+const getBalanceCheckpointAccount = (tokenAccount: PublicKey) => {
+  const [balanceCheckpoint] = PublicKey.findProgramAddressSync(
+    [Buffer.from('balance_checkpoint'), tokenAccount.toBuffer()],
+    earn.programId,
+  );
+
+  return balanceCheckpoint;
+};
+
 // Utility functions for the tests
 const expectAccountEmpty = (account: PublicKey) => {
   const accountInfo = svm.getAccount(account);
@@ -350,6 +392,24 @@ const mintM = async (to: PublicKey, amount: BN) => {
   await provider.sendAndConfirm!(tx, [portal]);
 };
 
+const burnM = async (from: Keypair, amount: BN) => {
+  const fromATA: PublicKey = await getATA(mint.publicKey, from.publicKey);
+
+  const burnInstruction = createBurnCheckedInstruction(
+    fromATA,
+    mint.publicKey,
+    from.publicKey,
+    BigInt(amount.toString()),
+    6,
+    [],
+    TOKEN_2022_PROGRAM_ID,
+  );
+
+  let tx = new Transaction();
+  tx.add(burnInstruction);
+  await provider.sendAndConfirm!(tx, [from]);
+};
+
 const warp = (seconds: BN, increment: boolean) => {
   const clock = svm.getClock();
   clock.unixTimestamp = increment ? clock.unixTimestamp + BigInt(seconds.toString()) : BigInt(seconds.toString());
@@ -419,6 +479,80 @@ const prepSetClaimCooldown = (signer: Keypair) => {
   return { globalAccount };
 };
 
+const prepSetClaimWindow = (signer: Keypair) => {
+  // Get the global PDA
+  const globalAccount = getGlobalAccount();
+
+  // Populate accounts for the instruction
+  accounts = {};
+  accounts.admin = signer.publicKey;
+  accounts.globalAccount = globalAccount;
+
+  return { globalAccount };
+};
+
+const prepSetIndexBounds = (signer: Keypair) => {
+  // Get the global and pending index PDAs
+  const globalAccount = getGlobalAccount();
+  const pendingIndexAccount = getPendingIndexAccount();
+
+  // Populate accounts for the instruction
+  accounts = {};
+  accounts.admin = signer.publicKey;
+  accounts.globalAccount = globalAccount;
+  accounts.pendingIndexAccount = pendingIndexAccount;
+  accounts.systemProgram = SystemProgram.programId;
+
+  return { globalAccount, pendingIndexAccount };
+};
+
+const prepSetPaused = (signer: Keypair) => {
+  // Get the global PDA
+  const globalAccount = getGlobalAccount();
+
+  // Populate accounts for the instruction
+  accounts = {};
+  accounts.signer = signer.publicKey;
+  accounts.globalAccount = globalAccount;
+  accounts.mint = mint.publicKey;
+  accounts.pendingIndexAccount = getOptionalPendingIndexAccount();
+
+  return { globalAccount };
+};
+
+const prepApprovePendingIndex = (signer: Keypair) => {
+  // Get the global and pending index PDAs
+  const globalAccount = getGlobalAccount();
+  const pendingIndexAccount = getPendingIndexAccount();
+
+  // Populate accounts for the instruction
+  accounts = {};
+  accounts.admin = signer.publicKey;
+  accounts.globalAccount = globalAccount;
+  accounts.pendingIndexAccount = pendingIndexAccount;
+  accounts.mint = mint.publicKey;
+
+  return { globalAccount, pendingIndexAccount };
+};
+
+const prepSweepUnclaimed = (signer: Keypair) => {
+  // Get the global and token authority PDAs
+  const globalAccount = getGlobalAccount();
+  const earnTokenAuthority = getEarnTokenAuthority();
+
+  // Populate accounts for the instruction
+  accounts = {};
+  accounts.admin = signer.publicKey;
+  accounts.globalAccount = globalAccount;
+  accounts.mint = mint.publicKey;
+  accounts.tokenAuthorityAccount = earnTokenAuthority;
+  accounts.treasuryTokenAccount = null;
+  accounts.tokenProgram = TOKEN_2022_PROGRAM_ID;
+  accounts.mintMultisig = mintAuthority.publicKey;
+
+  return { globalAccount };
+};
+
 const prepPropagateIndex = (signer: Keypair) => {
   // Get the global PDA
   const globalAccount = getGlobalAccount();
@@ -428,17 +562,22 @@ const prepPropagateIndex = (signer: Keypair) => {
   accounts.signer = signer.publicKey;
   accounts.globalAccount = globalAccount;
   accounts.mint = mint.publicKey;
+  accounts.pendingIndexAccount = getOptionalPendingIndexAccount();
 
   return { globalAccount };
 };
 
-const propagateIndex = async (newIndex: BN, earnerMerkleRoot: number[] = ZERO_WORD) => {
+const propagateIndex = async (
+  newIndex: BN,
+  earnerMerkleRoot: number[] = ZERO_WORD,
+  earnerRootTimestamp: BN = new BN(0),
+) => {
   // Setup the instruction
   const { globalAccount } = prepPropagateIndex(portal);
 
   // Send the instruction
   await earn.methods
-    .propagateIndex(newIndex, earnerMerkleRoot)
+    .propagateIndex(newIndex, earnerMerkleRoot, earnerRootTimestamp)
     .accounts({ ...accounts })
     .signers([portal])
     .rpc();
@@ -473,21 +612,30 @@ const prepClaimFor = async (signer: Keypair, mint: PublicKey, earner: PublicKey)
   return { globalAccount, earnerAccount, earnerATA };
 };
 
-const prepCompleteClaims = (signer: Keypair) => {
+const prepCompleteClaims = async (signer: Keypair) => {
   // Get the global PDA
   const globalAccount = getGlobalAccount();
 
+  // Get the claim cycle PDA of the current index
+  const { index } = await earn.account.global.fetch(globalAccount);
+  const [claimCycleAccount] = PublicKey.findProgramAddressSync(
+    [Buffer.from('claim_cycle'), index.toArrayLike(Buffer, 'le', 8)],
+    earn.programId,
+  );
+
   // Populate accounts
   accounts = {};
   accounts.earnAuthority = signer.publicKey;
   accounts.globalAccount = globalAccount;
+  accounts.claimCycleAccount = claimCycleAccount;
+  accounts.systemProgram = SystemProgram.programId;
 
-  return { globalAccount };
+  return { globalAccount, claimCycleAccount };
 };
 
 const completeClaims = async () => {
   // Setup the instruction
-  prepCompleteClaims(earnAuthority);
+  await prepCompleteClaims(earnAuthority);
 
   // Send the instruction
   await earn.methods
@@ -497,6 +645,191 @@ const completeClaims = async () => {
     .rpc();
 };
 
+const prepRecordClaimCycle = (signer: Keypair, index: BN) => {
+  // Get the global and claim cycle PDAs
+  const globalAccount = getGlobalAccount();
+  const [claimCycleAccount] = PublicKey.findProgramAddressSync(
+    [Buffer.from('claim_cycle'), index.toArrayLike(Buffer, 'le', 8)],
+    earn.programId,
+  );
+
+  // Populate accounts
+  accounts = {};
+  accounts.signer = signer.publicKey;
+  accounts.globalAccount = globalAccount;
+  accounts.claimCycleAccount = claimCycleAccount;
+  accounts.systemProgram = SystemProgram.programId;
+
+  return { globalAccount, claimCycleAccount };
+};
+
+const prepCommitClaimsRoot = (signer: Keypair, index: BN) => {
+  // Get the global and claim distribution PDAs
+  const globalAccount = getGlobalAccount();
+  const claimDistribution = getClaimDistributionAccount(index);
+
+  // Populate accounts
+  accounts = {};
+  accounts.earnAuthority = signer.publicKey;
+  accounts.globalAccount = globalAccount;
+  accounts.claimDistribution = claimDistribution;
+  accounts.systemProgram = SystemProgram.programId;
+
+  return { globalAccount, claimDistribution };
+};
+
+const commitClaimsRoot = async (index: BN, claimsRoot: number[], totalBalance: BN) => {
+  // Setup the instruction
+  const { claimDistribution } = prepCommitClaimsRoot(earnAuthority, index);
+
+  // Send the instruction
+  await earn.methods
+    .commitClaimsRoot(claimsRoot, totalBalance)
+    .accounts({ ...accounts })
+    .signers([earnAuthority])
+    .rpc();
+
+  return { claimDistribution };
+};
+
+const prepRevokeClaimsRoot = (signer: Keypair, index: BN) => {
+  // Get the global and claim distribution PDAs
+  const globalAccount = getGlobalAccount();
+  const claimDistribution = getClaimDistributionAccount(index);
+
+  // Populate accounts
+  accounts = {};
+  accounts.earnAuthority = signer.publicKey;
+  accounts.globalAccount = globalAccount;
+  accounts.claimDistribution = claimDistribution;
+
+  return { globalAccount, claimDistribution };
+};
+
+const prepClaimWithProof = async (index: BN, earner: PublicKey) => {
+  // Get the global, claim distribution and token authority PDAs
+  const globalAccount = getGlobalAccount();
+  const claimDistribution = getClaimDistributionAccount(index);
+  const earnTokenAuthority = getEarnTokenAuthority();
+
+  // Get the earner ATA and earner account
+  const earnerATA = await getATA(mint.publicKey, earner);
+  const earnerAccount = getEarnerAccount(earnerATA);
+
+  // Populate accounts
+  accounts = {};
+  accounts.globalAccount = globalAccount;
+  accounts.claimDistribution = claimDistribution;
+  accounts.mint = mint.publicKey;
+  accounts.tokenAuthorityAccount = earnTokenAuthority;
+  accounts.userTokenAccount = earnerATA;
+  accounts.earnerAccount = earnerAccount;
+  accounts.tokenProgram = TOKEN_2022_PROGRAM_ID;
+  accounts.mintMultisig = mintAuthority.publicKey;
+
+  return { globalAccount, claimDistribution, earnerAccount, earnerATA };
+};
+
+const prepCreateBalanceCheckpoint = async (signer: Keypair, earner: PublicKey) => {
+  // Get the global PDA
+  const globalAccount = getGlobalAccount();
+
+  // Get the earner ATA, earner account and balance checkpoint
+  const earnerATA = await getATA(mint.publicKey, earner);
+  const earnerAccount = getEarnerAccount(earnerATA);
+  const balanceCheckpoint = getBalanceCheckpointAccount(earnerATA);
+
+  // Populate accounts
+  accounts = {};
+  accounts.signer = signer.publicKey;
+  accounts.globalAccount = globalAccount;
+  accounts.earnerAccount = earnerAccount;
+  accounts.userTokenAccount = earnerATA;
+  accounts.balanceCheckpoint = balanceCheckpoint;
+  accounts.systemProgram = SystemProgram.programId;
+
+  return { globalAccount, earnerAccount, earnerATA, balanceCheckpoint };
+};
+
+const createBalanceCheckpoint = async (earner: PublicKey) => {
+  // Setup the instruction
+  const { balanceCheckpoint } = await prepCreateBalanceCheckpoint(nonAdmin, earner);
+
+  // Send the instruction
+  await earn.methods
+    .createBalanceCheckpoint()
+    .accounts({ ...accounts })
+    .signers([nonAdmin])
+    .rpc();
+
+  return { balanceCheckpoint };
+};
+
+const prepCheckpointBalance = async (earner: PublicKey) => {
+  // Get the earner ATA and balance checkpoint
+  const earnerATA = await getATA(mint.publicKey, earner);
+  const balanceCheckpoint = getBalanceCheckpointAccount(earnerATA);
+
+  // Populate accounts
+  accounts = {};
+  accounts.balanceCheckpoint = balanceCheckpoint;
+  accounts.userTokenAccount = earnerATA;
+
+  return { earnerATA, balanceCheckpoint };
+};
+
+const checkpointBalance = async (earner: PublicKey) => {
+  // Setup the instruction
+  await prepCheckpointBalance(earner);
+
+  // Send the instruction
+  await earn.methods
+    .checkpointBalance()
+    .accounts({ ...accounts })
+    .rpc();
+};
+
+const prepClaim = async (earner: PublicKey) => {
+  // Get the global and token authority PDAs
+  const globalAccount = getGlobalAccount();
+  const earnTokenAuthority = getEarnTokenAuthority();
+
+  // Get the earner ATA, earner account and balance checkpoint
+  const earnerATA = await getATA(mint.publicKey, earner);
+  const earnerAccount = getEarnerAccount(earnerATA);
+  const balanceCheckpoint = getBalanceCheckpointAccount(earnerATA);
+
+  // Populate accounts
+  accounts = {};
+  accounts.globalAccount = globalAccount;
+  accounts.mint = mint.publicKey;
+  accounts.tokenAuthorityAccount = earnTokenAuthority;
+  accounts.userTokenAccount = earnerATA;
+  accounts.recipientTokenAccount = earnerATA;
+  accounts.earnerAccount = earnerAccount;
+  accounts.balanceCheckpoint = balanceCheckpoint;
+  accounts.tokenProgram = TOKEN_2022_PROGRAM_ID;
+  accounts.mintMultisig = mintAuthority.publicKey;
+
+  return { globalAccount, earnerAccount, earnerATA, balanceCheckpoint };
+};
+
+const prepPreviewClaim = async (earner: PublicKey) => {
+  // Get the global PDA
+  const globalAccount = getGlobalAccount();
+
+  // Get the earner ATA and earner account
+  const earnerATA = await getATA(mint.publicKey, earner);
+  const earnerAccount = getEarnerAccount(earnerATA);
+
+  // Populate accounts
+  accounts = {};
+  accounts.globalAccount = globalAccount;
+  accounts.earnerAccount = earnerAccount;
+
+  return { globalAccount, earnerAccount };
+};
+
 const prepAddRegistrarEarner = (signer: Keypair, earnerATA: PublicKey) => {
   // Get the global PDA
   const globalAccount = getGlobalAccount();
@@ -543,253 +876,4108 @@ const prepRemoveRegistrarEarner = (signer: Keypair, earnerATA: PublicKey) => {
   accounts.globalAccount = globalAccount;
   accounts.userTokenAccount = earnerATA;
   accounts.earnerAccount = earnerAccount;
+  accounts.rentPayer = signer.publicKey;
 
   return { globalAccount, earnerAccount };
 };
 
-describe('Earn unit tests', () => {
-  beforeEach(async () => {
-    // Initialize the SVM instance with all necessary configurations
-    svm = fromWorkspace('../')
-      .withSplPrograms() // Add SPL programs (including token programs)
-      .withBuiltins() // Add builtin programs
-      .withSysvars() // Setup standard sysvars
-      .withPrecompiles() // Add standard precompiles
-      .withBlockhashCheck(true); // Optional: disable blockhash checking for tests
+const getRolesAccount = () => {
+  const [rolesAccount] = PublicKey.findProgramAddressSync([Buffer.from('roles')], earn.programId);
 
-    // Create an anchor provider from the liteSVM instance
-    provider = new LiteSVMProvider(svm);
+  return rolesAccount;
+};
 
-    // Create program instances
-    earn = new Program<Earn>(EARN_IDL, EARN_PROGRAM_ID, provider);
+const prepTransferAdmin = (signer: Keypair) => {
+  // Get the global PDA
+  const globalAccount = getGlobalAccount();
 
-    // Fund the wallets
-    svm.airdrop(admin.publicKey, BigInt(10 * LAMPORTS_PER_SOL));
-    svm.airdrop(portal.publicKey, BigInt(10 * LAMPORTS_PER_SOL));
-    svm.airdrop(earnAuthority.publicKey, BigInt(10 * LAMPORTS_PER_SOL));
-    svm.airdrop(nonAdmin.publicKey, BigInt(10 * LAMPORTS_PER_SOL));
+  // Populate accounts for the instruction
+  accounts = {};
+  accounts.admin = signer.publicKey;
+  accounts.globalAccount = globalAccount;
 
-    // Create the token mint
-    await createMintWithMultisig(mint, mintAuthority);
+  return { globalAccount };
+};
 
-    // Mint some tokens to have a non-zero supply
-    await mintM(admin.publicKey, initialSupply);
+const prepAcceptAdmin = (signer: Keypair) => {
+  // Get the global PDA
+  const globalAccount = getGlobalAccount();
+
+  // Populate accounts for the instruction
+  accounts = {};
+  accounts.newAdmin = signer.publicKey;
+  accounts.globalAccount = globalAccount;
+
+  return { globalAccount };
+};
+
+const prepSetPortalAuthority = (signer: Keypair) => {
+  // Get the global PDA
+  const globalAccount = getGlobalAccount();
+
+  // Populate accounts for the instruction
+  accounts = {};
+  accounts.admin = signer.publicKey;
+  accounts.globalAccount = globalAccount;
+
+  return { globalAccount };
+};
+
+const prepGrantRole = (signer: Keypair) => {
+  // Get the global and roles PDAs
+  const globalAccount = getGlobalAccount();
+  const rolesAccount = getRolesAccount();
+
+  // Populate accounts for the instruction
+  accounts = {};
+  accounts.admin = signer.publicKey;
+  accounts.globalAccount = globalAccount;
+  accounts.rolesAccount = rolesAccount;
+  accounts.systemProgram = SystemProgram.programId;
+
+  return { globalAccount, rolesAccount };
+};
+
+const grantRole = async (role: any, member: PublicKey) => {
+  // Setup the instruction
+  prepGrantRole(admin);
+
+  // Send the instruction
+  await earn.methods
+    .grantRole(role, member)
+    .accounts({ ...accounts })
+    .signers([admin])
+    .rpc();
+};
+
+const prepRevokeRole = (signer: Keypair) => {
+  // Get the global and roles PDAs
+  const globalAccount = getGlobalAccount();
+  const rolesAccount = getRolesAccount();
+
+  // Populate accounts for the instruction
+  accounts = {};
+  accounts.admin = signer.publicKey;
+  accounts.globalAccount = globalAccount;
+  accounts.rolesAccount = rolesAccount;
+
+  return { globalAccount, rolesAccount };
+};
+
+const prepRejectPendingIndex = (signer: Keypair) => {
+  // Get the global and pending index PDAs
+  const globalAccount = getGlobalAccount();
+  const pendingIndexAccount = getPendingIndexAccount();
+
+  // Populate accounts for the instruction
+  accounts = {};
+  accounts.admin = signer.publicKey;
+  accounts.globalAccount = globalAccount;
+  accounts.pendingIndexAccount = pendingIndexAccount;
+
+  return { globalAccount, pendingIndexAccount };
+};
+
+const prepSetUnclaimedExpiry = (signer: Keypair, treasuryTokenAccount: PublicKey | null) => {
+  // Get the global PDA
+  const globalAccount = getGlobalAccount();
+
+  // Populate accounts for the instruction
+  accounts = {};
+  accounts.admin = signer.publicKey;
+  accounts.globalAccount = globalAccount;
+  accounts.treasuryTokenAccount = treasuryTokenAccount;
+
+  return { globalAccount };
+};
+
+const prepSetAttesters = (signer: Keypair) => {
+  // Get the global PDA
+  const globalAccount = getGlobalAccount();
+
+  // Populate accounts for the instruction
+  accounts = {};
+  accounts.admin = signer.publicKey;
+  accounts.globalAccount = globalAccount;
+
+  return { globalAccount };
+};
+
+const setAttesters = async (attesters: PublicKey[], threshold: number) => {
+  // Setup the instruction
+  prepSetAttesters(admin);
+
+  // Send the instruction
+  await earn.methods
+    .setAttesters(attesters, threshold)
+    .accounts({ ...accounts })
+    .signers([admin])
+    .rpc();
+};
+
+const prepSetRemovalBounty = (signer: Keypair) => {
+  // Get the global PDA
+  const globalAccount = getGlobalAccount();
+
+  // Populate accounts for the instruction
+  accounts = {};
+  accounts.admin = signer.publicKey;
+  accounts.globalAccount = globalAccount;
+
+  return { globalAccount };
+};
+
+const prepApproveProgramEarner = (signer: Keypair) => {
+  // Get the global PDA
+  const globalAccount = getGlobalAccount();
+
+  // Populate accounts for the instruction
+  accounts = {};
+  accounts.admin = signer.publicKey;
+  accounts.globalAccount = globalAccount;
+
+  return { globalAccount };
+};
+
+const approveProgramEarner = async (programId: PublicKey, seeds: Buffer[]) => {
+  // Setup the instruction
+  prepApproveProgramEarner(admin);
+
+  // Send the instruction
+  await earn.methods
+    .approveProgramEarner(programId, seeds)
+    .accounts({ ...accounts })
+    .signers([admin])
+    .rpc();
+};
+
+const prepRemoveProgramEarner = (signer: Keypair, earnerATA: PublicKey, rentPayer: PublicKey) => {
+  // Get the global PDA
+  const globalAccount = getGlobalAccount();
+
+  // Get the earner account
+  const earnerAccount = getEarnerAccount(earnerATA);
+
+  // Populate accounts for the instruction
+  accounts = {};
+  accounts.admin = signer.publicKey;
+  accounts.globalAccount = globalAccount;
+  accounts.earnerAccount = earnerAccount;
+  accounts.rentPayer = rentPayer;
+
+  return { globalAccount, earnerAccount };
+};
+
+const prepMigrateGlobal = (signer: Keypair) => {
+  // Get the global PDA
+  const globalAccount = getGlobalAccount();
+
+  // Populate accounts for the instruction
+  accounts = {};
+  accounts.admin = signer.publicKey;
+  accounts.globalAccount = globalAccount;
+  accounts.systemProgram = SystemProgram.programId;
+
+  return { globalAccount };
+};
+
+// Rewrites an account with an older layout version to test the migrate instructions
+const setAccountVersion = async (account: PublicKey, accountName: 'Global' | 'Earner', version: number) => {
+  const accountInfo = svm.getAccount(account)!;
+  const state = earn.coder.accounts.decode(accountName, Buffer.from(accountInfo.data));
+  const data = await earn.coder.accounts.encode(accountName, { ...state, version });
+
+  accountInfo.data.set(data);
+  svm.setAccount(account, accountInfo);
+};
+
+// Size of the global account before versioning, the fields after the bump were added since
+const LEGACY_GLOBAL_SIZE = 218;
+
+// Shrinks the global account to its layout from before versioning
+const setLegacyGlobalLayout = (lamports?: number) => {
+  const globalAccount = getGlobalAccount();
+  const accountInfo = svm.getAccount(globalAccount)!;
+
+  svm.setAccount(globalAccount, {
+    ...accountInfo,
+    data: accountInfo.data.slice(0, LEGACY_GLOBAL_SIZE),
+    lamports: lamports ?? accountInfo.lamports,
+  });
+};
+
+const prepPropagateAttestedIndex = () => {
+  // Get the global PDA
+  const globalAccount = getGlobalAccount();
+
+  // Populate accounts
+  accounts = {};
+  accounts.globalAccount = globalAccount;
+  accounts.mint = mint.publicKey;
+  accounts.instructionsSysvar = SYSVAR_INSTRUCTIONS_PUBKEY;
+  accounts.pendingIndexAccount = getOptionalPendingIndexAccount();
+
+  return { globalAccount };
+};
+
+// Builds the Ed25519 program instructions that verify the attesters' signatures
+const signAttestation = (attesters: Keypair[], index: BN, earnerMerkleRoot: number[], timestamp: BN) => {
+  const message = Buffer.concat([
+    Buffer.from('m0:index_attestation'),
+    mint.publicKey.toBuffer(),
+    index.toArrayLike(Buffer, 'le', 8),
+    Buffer.from(earnerMerkleRoot),
+    timestamp.toArrayLike(Buffer, 'le', 8),
+  ]);
+
+  return attesters.map((attester) =>
+    Ed25519Program.createInstructionWithPrivateKey({ privateKey: attester.secretKey, message }),
+  );
+};
+
+const prepClaimForBatch = (signer: Keypair) => {
+  // Get the global and token authority PDAs
+  const globalAccount = getGlobalAccount();
+  const earnTokenAuthority = getEarnTokenAuthority();
+
+  // Populate accounts
+  accounts = {};
+  accounts.earnAuthority = signer.publicKey;
+  accounts.globalAccount = globalAccount;
+  accounts.mint = mint.publicKey;
+  accounts.tokenAuthorityAccount = earnTokenAuthority;
+  accounts.tokenProgram = TOKEN_2022_PROGRAM_ID;
+  accounts.mintMultisig = mintAuthority.publicKey;
+  accounts.rolesAccount = null;
+
+  return { globalAccount };
+};
+
+const prepAddRegistrarEarners = (signer: Keypair) => {
+  // Get the global PDA
+  const globalAccount = getGlobalAccount();
+
+  // Populate accounts
+  accounts = {};
+  accounts.signer = signer.publicKey;
+  accounts.globalAccount = globalAccount;
+  accounts.systemProgram = SystemProgram.programId;
+
+  return { globalAccount };
+};
+
+const prepRemoveRegistrarEarners = (signer: Keypair) => {
+  // Get the global PDA
+  const globalAccount = getGlobalAccount();
+
+  // Populate accounts
+  accounts = {};
+  accounts.signer = signer.publicKey;
+  accounts.globalAccount = globalAccount;
+
+  return { globalAccount };
+};
+
+// Users must be passed to the batch instructions in the order of the leaves in the tree
+const sortKeys = (keys: PublicKey[]) => [...keys].sort((a, b) => Buffer.compare(a.toBuffer(), b.toBuffer()));
+
+const asRemainingAccounts = (keys: PublicKey[]) =>
+  keys.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true }));
+
+const prepAddProgramEarner = (signer: Keypair, userTokenAccount: PublicKey) => {
+  // Get the global PDA
+  const globalAccount = getGlobalAccount();
+
+  // Get the earner account
+  const earnerAccount = getEarnerAccount(userTokenAccount);
+
+  // Populate accounts
+  accounts = {};
+  accounts.signer = signer.publicKey;
+  accounts.globalAccount = globalAccount;
+  accounts.userTokenAccount = userTokenAccount;
+  accounts.earnerAccount = earnerAccount;
+  accounts.systemProgram = SystemProgram.programId;
+
+  return { globalAccount, earnerAccount };
+};
+
+const prepMigrateEarner = (signer: Keypair, earnerATA: PublicKey, newTokenAccount: PublicKey, rentPayer: PublicKey) => {
+  // Get the global PDA
+  const globalAccount = getGlobalAccount();
+
+  // Get the old and new earner accounts
+  const earnerAccount = getEarnerAccount(earnerATA);
+  const newEarnerAccount = getEarnerAccount(newTokenAccount);
+
+  // Populate accounts
+  accounts = {};
+  accounts.user = signer.publicKey;
+  accounts.globalAccount = globalAccount;
+  accounts.earnerAccount = earnerAccount;
+  accounts.newUserTokenAccount = newTokenAccount;
+  accounts.newEarnerAccount = newEarnerAccount;
+  accounts.rentPayer = rentPayer;
+  accounts.systemProgram = SystemProgram.programId;
+
+  return { globalAccount, earnerAccount, newEarnerAccount };
+};
+
+const createImmutableTokenAccount = async (mint: PublicKey, owner: PublicKey) => {
+  const tokenAccountKeypair = Keypair.generate();
+  const tokenAccountLen = getAccountLen([ExtensionType.ImmutableOwner]);
+  const lamports = await provider.connection.getMinimumBalanceForRentExemption(tokenAccountLen);
+
+  // Create a token account that is not the ATA but has the immutable owner extension
+  const transaction = new Transaction().add(
+    SystemProgram.createAccount({
+      fromPubkey: admin.publicKey,
+      newAccountPubkey: tokenAccountKeypair.publicKey,
+      space: tokenAccountLen,
+      lamports,
+      programId: TOKEN_2022_PROGRAM_ID,
+    }),
+    createInitializeImmutableOwnerInstruction(tokenAccountKeypair.publicKey, TOKEN_2022_PROGRAM_ID),
+    createInitializeAccountInstruction(tokenAccountKeypair.publicKey, mint, owner, TOKEN_2022_PROGRAM_ID),
+  );
+
+  await provider.send!(transaction, [admin, tokenAccountKeypair]);
+
+  return { tokenAccount: tokenAccountKeypair.publicKey };
+};
+
+const prepSetRecipient = async (signer: Keypair, earner: PublicKey, recipientTokenAccount: PublicKey | null) => {
+  // Get the global PDA
+  const globalAccount = getGlobalAccount();
+
+  // Get the earner ATA and earner account
+  const earnerATA = await getATA(mint.publicKey, earner);
+  const earnerAccount = getEarnerAccount(earnerATA);
+
+  // Populate accounts
+  accounts = {};
+  accounts.user = signer.publicKey;
+  accounts.globalAccount = globalAccount;
+  accounts.earnerAccount = earnerAccount;
+  accounts.recipientTokenAccount = recipientTokenAccount;
+
+  return { globalAccount, earnerAccount };
+};
+
+const prepMigrateEarnerAccount = (signer: Keypair, earnerAccount: PublicKey) => {
+  // Populate accounts
+  accounts = {};
+  accounts.payer = signer.publicKey;
+  accounts.earnerAccount = earnerAccount;
+  accounts.systemProgram = SystemProgram.programId;
+
+  return { earnerAccount };
+};
+
+describe('Earn unit tests', () => {
+  beforeEach(async () => {
+    // Initialize the SVM instance with all necessary configurations
+    svm = fromWorkspace('../')
+      .withSplPrograms() // Add SPL programs (including token programs)
+      .withBuiltins() // Add builtin programs
+      .withSysvars() // Setup standard sysvars
+      .withPrecompiles() // Add standard precompiles
+      .withBlockhashCheck(true); // Optional: disable blockhash checking for tests
+
+    // Create an anchor provider from the liteSVM instance
+    provider = new LiteSVMProvider(svm);
+
+    // Create program instances
+    earn = new Program<Earn>(EARN_IDL, EARN_PROGRAM_ID, provider);
+
+    // Fund the wallets
+    svm.airdrop(admin.publicKey, BigInt(10 * LAMPORTS_PER_SOL));
+    svm.airdrop(portal.publicKey, BigInt(10 * LAMPORTS_PER_SOL));
+    svm.airdrop(earnAuthority.publicKey, BigInt(10 * LAMPORTS_PER_SOL));
+    svm.airdrop(nonAdmin.publicKey, BigInt(10 * LAMPORTS_PER_SOL));
+
+    // Create the token mint
+    await createMintWithMultisig(mint, mintAuthority);
+
+    // Mint some tokens to have a non-zero supply
+    await mintM(admin.publicKey, initialSupply);
+  });
+
+  describe('initialize unit tests', () => {
+    // test cases
+    //   [X] given the admin signs the transaction
+    //      [X] the global account is created
+    //      [X] the admin is set to the signer
+    //      [X] the mint is set correctly
+    //      [X] the earn authority is set correctly
+    //      [X] the initial index is set correctly
+    //      [X] the claim cooldown is set correctly
+
+    // given the admin signs the transaction
+    // the global account is created and configured correctly
+    test('Admin can initialize earn program', async () => {
+      // Setup the instruction call
+      const { globalAccount } = prepInitialize(admin, mint.publicKey);
+
+      // Create and send the transaction
+      await earn.methods
+        .initialize(earnAuthority.publicKey, initialIndex, claimCooldown)
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      // Verify the global state including zero-initialized Merkle roots
+      await expectGlobalState(globalAccount, {
+        admin: admin.publicKey,
+        mint: mint.publicKey,
+        earnAuthority: earnAuthority.publicKey,
+        index: initialIndex,
+        claimCooldown,
+        claimComplete: true,
+        earnerMerkleRoot: ZERO_WORD,
+      });
+    });
+  });
+
+  describe('set_earn_authority unit tests', () => {
+    // test cases
+    //   [X] given the admin signs the transaction
+    //      [X] the earn authority is updated
+    //   [X] given a non-admin signs the transaction
+    //      [X] the transaction reverts with a not authorized error
+
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+    });
+
+    test('Admin can set new earn authority', async () => {
+      // Setup new earn authority
+      const newEarnAuthority = new Keypair();
+
+      // Setup the instruction
+      const { globalAccount } = prepSetEarnAuthority(admin);
+
+      // Send the transaction
+      await earn.methods
+        .setEarnAuthority(newEarnAuthority.publicKey)
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      // Verify the global state was updated
+      await expectGlobalState(globalAccount, {
+        earnAuthority: newEarnAuthority.publicKey,
+      });
+    });
+
+    test('Non-admin cannot set earn authority', async () => {
+      // Attempt to set new earn authority with non-admin
+      const newEarnAuthority = new Keypair();
+
+      prepSetEarnAuthority(nonAdmin);
+
+      await expectAnchorError(
+        earn.methods
+          .setEarnAuthority(newEarnAuthority.publicKey)
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'NotAuthorized',
+      );
+    });
+  });
+
+  describe('set_claim_cooldown unit tests', () => {
+    // test cases
+    // [X] given the admin does not sign the transaction
+    //   [X] it reverts with a NotAuthorized error
+    // [X] given the admin does sign then transaction
+    //   [X] given the new cooldown is greater than 1 week
+    //     [X] it reverts with an InvalidParam error
+    //   [X] given the new cooldown is less than or equal to 1 week
+    //     [X] the claim cooldown is updated
+
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+    });
+
+    test('Admin does not sign transaction - reverts', async () => {
+      // Attempt to set the claim cooldown without the admin signing
+      prepSetClaimCooldown(nonAdmin);
+
+      await expectAnchorError(
+        earn.methods
+          .setClaimCooldown(new BN(1))
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'NotAuthorized',
+      );
+    });
+
+    test('Admin tries to set cooldown to more than  1 week - reverts', async () => {
+      // Attempt to set the claim cooldown to more than 1 week
+
+      const randomCooldown = randomInt(604_801, 2 ** 32);
+
+      prepSetClaimCooldown(admin);
+
+      await expectAnchorError(
+        earn.methods
+          .setClaimCooldown(new BN(randomCooldown))
+          .accounts({ ...accounts })
+          .signers([admin])
+          .rpc(),
+        'InvalidParam',
+      );
+    });
+
+    test('Admin sets cooldown to less than or equal to 1 week - success', async () => {
+      // Attempt to set the claim cooldown to less than or equal to 1 week
+      const newCooldown = new BN(randomInt(0, 604_800));
+
+      const { globalAccount } = prepSetClaimCooldown(admin);
+
+      await earn.methods
+        .setClaimCooldown(newCooldown)
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      // Verify the global state was updated
+      await expectGlobalState(globalAccount, {
+        claimCooldown: newCooldown,
+      });
+    });
+  });
+
+  describe('set_claim_window unit tests', () => {
+    // test cases
+    // [X] given the admin does not sign the transaction
+    //   [X] it reverts with a NotAuthorized error
+    // [X] given the admin does sign the transaction
+    //   [X] given the new window is less than 1 hour
+    //     [X] it reverts with an InvalidClaimWindow error
+    //   [X] given the new window is greater than 1 week
+    //     [X] it reverts with an InvalidClaimWindow error
+    //   [X] given the new window is within the bounds
+    //     [X] the claim window is updated
+    //   [X] given the new window is zero
+    //     [X] the claim window is disabled
+
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+    });
+
+    test('Admin does not sign transaction - reverts', async () => {
+      // Attempt to set the claim window without the admin signing
+      prepSetClaimWindow(nonAdmin);
+
+      await expectAnchorError(
+        earn.methods
+          .setClaimWindow(new BN(86_400))
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'NotAuthorized',
+      );
+    });
+
+    test('Admin tries to set window to less than 1 hour - reverts', async () => {
+      // Attempt to set the claim window too short
+      const randomWindow = randomInt(1, 3_600);
+
+      prepSetClaimWindow(admin);
+
+      await expectAnchorError(
+        earn.methods
+          .setClaimWindow(new BN(randomWindow))
+          .accounts({ ...accounts })
+          .signers([admin])
+          .rpc(),
+        'InvalidClaimWindow',
+      );
+    });
+
+    test('Admin tries to set window to more than 1 week - reverts', async () => {
+      // Attempt to set the claim window to more than 1 week
+      const randomWindow = randomInt(604_801, 2 ** 32);
+
+      prepSetClaimWindow(admin);
+
+      await expectAnchorError(
+        earn.methods
+          .setClaimWindow(new BN(randomWindow))
+          .accounts({ ...accounts })
+          .signers([admin])
+          .rpc(),
+        'InvalidClaimWindow',
+      );
+    });
+
+    test('Admin sets window within the bounds - success', async () => {
+      const newWindow = new BN(randomInt(3_600, 604_801));
+
+      const { globalAccount } = prepSetClaimWindow(admin);
+
+      await earn.methods
+        .setClaimWindow(newWindow)
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      // Verify the global state was updated
+      const state = await earn.account.global.fetch(globalAccount);
+      expect(state.claimWindow.toString()).toEqual(newWindow.toString());
+    });
+
+    test('Admin sets window to zero - success', async () => {
+      prepSetClaimWindow(admin);
+
+      await earn.methods
+        .setClaimWindow(new BN(86_400))
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      const { globalAccount } = prepSetClaimWindow(admin);
+
+      await earn.methods
+        .setClaimWindow(new BN(0))
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      // Verify the claim window was disabled
+      const state = await earn.account.global.fetch(globalAccount);
+      expect(state.claimWindow.toString()).toEqual('0');
+    });
+  });
+
+  describe('set_index_bounds unit tests', () => {
+    // test cases
+    // [X] given the admin does not sign the transaction
+    //   [X] it reverts with a NotAuthorized error
+    // [X] given the admin does sign the transaction
+    //   [X] given the ratio bound is not above 100%
+    //     [X] it reverts with an InvalidIndexBounds error
+    //   [X] given the ratio bound is above 200%
+    //     [X] it reverts with an InvalidIndexBounds error
+    //   [X] given the rate bound is above 100% per year
+    //     [X] it reverts with an InvalidIndexBounds error
+    //   [X] given the ratio bound does not allow a year of growth at the max rate
+    //     [X] it reverts with an InvalidIndexBounds error
+    //   [X] given consistent bounds
+    //     [X] the bounds are updated
+    //     [X] the pending index account is created
+    //   [X] given both bounds are zero
+    //     [X] the bounds are disabled
+
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+    });
+
+    test('Admin does not sign transaction - reverts', async () => {
+      prepSetIndexBounds(nonAdmin);
+
+      await expectAnchorError(
+        earn.methods
+          .setIndexBounds(new BN(1_000), new BN(11_000))
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'NotAuthorized',
+      );
+    });
+
+    test('Ratio bound not above 100% - reverts', async () => {
+      const randomRatio = randomInt(1, 10_001);
+
+      prepSetIndexBounds(admin);
+
+      await expectAnchorError(
+        earn.methods
+          .setIndexBounds(new BN(0), new BN(randomRatio))
+          .accounts({ ...accounts })
+          .signers([admin])
+          .rpc(),
+        'InvalidIndexBounds',
+      );
+    });
+
+    test('Ratio bound above 200% - reverts', async () => {
+      const randomRatio = randomInt(20_001, 2 ** 32);
+
+      prepSetIndexBounds(admin);
+
+      await expectAnchorError(
+        earn.methods
+          .setIndexBounds(new BN(0), new BN(randomRatio))
+          .accounts({ ...accounts })
+          .signers([admin])
+          .rpc(),
+        'InvalidIndexBounds',
+      );
+    });
+
+    test('Rate bound above 100% per year - reverts', async () => {
+      const randomRate = randomInt(10_001, 2 ** 32);
+
+      prepSetIndexBounds(admin);
+
+      await expectAnchorError(
+        earn.methods
+          .setIndexBounds(new BN(randomRate), new BN(0))
+          .accounts({ ...accounts })
+          .signers([admin])
+          .rpc(),
+        'InvalidIndexBounds',
+      );
+    });
+
+    test('Ratio bound below a year of growth at the max rate - reverts', async () => {
+      // A 10% rate needs a ratio of at least 110%
+      prepSetIndexBounds(admin);
+
+      await expectAnchorError(
+        earn.methods
+          .setIndexBounds(new BN(1_000), new BN(10_999))
+          .accounts({ ...accounts })
+          .signers([admin])
+          .rpc(),
+        'InvalidIndexBounds',
+      );
+    });
+
+    test('Consistent bounds - success', async () => {
+      const { globalAccount, pendingIndexAccount } = prepSetIndexBounds(admin);
+
+      await earn.methods
+        .setIndexBounds(new BN(1_000), new BN(11_000))
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      // Verify the global state was updated
+      const state = await earn.account.global.fetch(globalAccount);
+      expect(state.maxIndexRateBps.toString()).toEqual('1000');
+      expect(state.maxIndexRatioBps.toString()).toEqual('11000');
+
+      // Verify the pending index account was created without a pending index
+      const pending = await earn.account.pendingIndex.fetch(pendingIndexAccount);
+      expect(pending.index.toString()).toEqual('0');
+    });
+
+    test('Both bounds zero - success', async () => {
+      prepSetIndexBounds(admin);
+
+      await earn.methods
+        .setIndexBounds(new BN(1_000), new BN(11_000))
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      svm.expireBlockhash();
+
+      const { globalAccount } = prepSetIndexBounds(admin);
+
+      await earn.methods
+        .setIndexBounds(new BN(0), new BN(0))
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      // Verify the bounds are disabled
+      const state = await earn.account.global.fetch(globalAccount);
+      expect(state.maxIndexRateBps.toString()).toEqual('0');
+      expect(state.maxIndexRatioBps.toString()).toEqual('0');
+    });
+  });
+
+  describe('approve_pending_index unit tests', () => {
+    // test cases
+    // [X] given there is no pending index
+    //   [X] it reverts with a NoPendingIndex error
+    // [X] given an out of bounds update is propagated without the pending index account
+    //   [X] it reverts with a MissingPendingIndex error
+    // [X] given the program is not paused
+    //   [X] the pending index is applied
+    // [X] given the program is paused
+    //   [X] the pending index is queued as approved
+    //   [X] it is applied on unpause even though it is out of bounds
+    // [X] given a newer earner root was applied after the pending index
+    //   [X] the pending root is ignored
+    //   [X] it emits an EarnerRootIgnored event
+
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+
+      // Only allow the index to grow by 1% in a single update
+      prepSetIndexBounds(admin);
+
+      await earn.methods
+        .setIndexBounds(new BN(0), new BN(10_100))
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      // Warp past the initial cooldown period
+      warp(claimCooldown, true);
+    });
+
+    test('No pending index - reverts', async () => {
+      prepApprovePendingIndex(admin);
+
+      await expectAnchorError(
+        earn.methods
+          .approvePendingIndex()
+          .accounts({ ...accounts })
+          .signers([admin])
+          .rpc(),
+        'NoPendingIndex',
+      );
+    });
+
+    // given an out of bounds update is propagated without the pending index account
+    // it reverts with a MissingPendingIndex error
+    test('Pending index account not provided - reverts', async () => {
+      prepPropagateIndex(portal);
+      accounts.pendingIndexAccount = null;
+
+      await expectAnchorError(
+        earn.methods
+          .propagateIndex(new BN(1_100_000_000_000), ZERO_WORD, new BN(0))
+          .accounts({ ...accounts })
+          .signers([portal])
+          .rpc(),
+        'MissingPendingIndex',
+      );
+    });
+
+    test('Not paused - pending index applied', async () => {
+      const newIndex = new BN(1_100_000_000_000);
+
+      // The update is out of bounds and quarantined
+      const { globalAccount } = await propagateIndex(newIndex);
+
+      const pendingIndexAccount = getPendingIndexAccount();
+      let pending = await earn.account.pendingIndex.fetch(pendingIndexAccount);
+      expect(pending.index.toString()).toEqual(newIndex.toString());
+
+      let state = await earn.account.global.fetch(globalAccount);
+      expect(state.index.toString()).toEqual(initialIndex.toString());
+
+      prepApprovePendingIndex(admin);
+
+      await earn.methods
+        .approvePendingIndex()
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      // Verify the index was applied
+      pending = await earn.account.pendingIndex.fetch(pendingIndexAccount);
+      expect(pending.index.toString()).toEqual('0');
+
+      state = await earn.account.global.fetch(globalAccount);
+      expect(state.index.toString()).toEqual(newIndex.toString());
+    });
+
+    test('Paused - pending index queued and applied on unpause', async () => {
+      const newIndex = new BN(1_100_000_000_000);
+
+      // The update is out of bounds and quarantined
+      const { globalAccount } = await propagateIndex(newIndex);
+
+      // Pause the program
+      prepSetPaused(admin);
+
+      await earn.methods
+        .setPaused(true)
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      prepApprovePendingIndex(admin);
+
+      await earn.methods
+        .approvePendingIndex()
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      // Verify the index was queued instead of applied
+      const pending = await earn.account.pendingIndex.fetch(getPendingIndexAccount());
+      expect(pending.index.toString()).toEqual('0');
+
+      let state = await earn.account.global.fetch(globalAccount);
+      expect(state.queuedIndex.toString()).toEqual(newIndex.toString());
+      expect(state.queuedIndexApproved).toBe(true);
+      expect(state.index.toString()).toEqual(initialIndex.toString());
+
+      // Unpause the program
+      prepSetPaused(admin);
+
+      await earn.methods
+        .setPaused(false)
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      // Verify the approved index was applied without being quarantined again
+      state = await earn.account.global.fetch(globalAccount);
+      expect(state.index.toString()).toEqual(newIndex.toString());
+      expect(state.queuedIndex.toString()).toEqual('0');
+      expect(state.queuedIndexApproved).toBe(false);
+    });
+
+    // given a newer earner root was applied after the pending index
+    // the pending root is ignored and an EarnerRootIgnored event is emitted
+    test('Superseded pending root - ignored', async () => {
+      const pendingRoot = Array(32).fill(1);
+      const newerRoot = Array(32).fill(2);
+
+      // The update is out of bounds and quarantined with its root
+      await propagateIndex(new BN(1_100_000_000_000), pendingRoot, new BN(10));
+
+      // A newer root is applied with the current index
+      const { globalAccount } = await propagateIndex(initialIndex, newerRoot, new BN(20));
+
+      prepApprovePendingIndex(admin);
+
+      const { events } = await earn.methods
+        .approvePendingIndex()
+        .accounts({ ...accounts })
+        .signers([admin])
+        .simulate();
+
+      // Verify the pending root is reported as ignored
+      const ignored = events.find((event) => event.name === 'EarnerRootIgnored');
+      expect(ignored).toBeDefined();
+      expect(ignored!.data.root).toEqual(pendingRoot);
+      expect(ignored!.data.timestamp.toString()).toEqual('10');
+      expect(ignored!.data.currentTimestamp.toString()).toEqual('20');
+
+      await earn.methods
+        .approvePendingIndex()
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      // Verify the index was applied and the newer root kept
+      await expectGlobalState(globalAccount, {
+        index: new BN(1_100_000_000_000),
+        earnerMerkleRoot: newerRoot,
+      });
+    });
+  });
+
+  describe('sweep_unclaimed unit tests', () => {
+    // test cases
+    // [X] given the admin does not sign the transaction
+    //   [X] it reverts with a NotAuthorized error
+    // [X] given the admin does sign the transaction
+    //   [X] given no unclaimed yield has expired
+    //     [X] it reverts with a NoExpiredYield error
+    //   [X] given a treasury is set
+    //     [X] all of the expired yield is minted to the treasury
+    //     [X] it is removed from the max yield
+
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+    });
+
+    test('Admin does not sign transaction - reverts', async () => {
+      prepSweepUnclaimed(nonAdmin);
+
+      await expectAnchorError(
+        earn.methods
+          .sweepUnclaimed()
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'NotAuthorized',
+      );
+    });
+
+    test('No expired yield - reverts', async () => {
+      prepSweepUnclaimed(admin);
+
+      await expectAnchorError(
+        earn.methods
+          .sweepUnclaimed()
+          .accounts({ ...accounts })
+          .signers([admin])
+          .rpc(),
+        'NoExpiredYield',
+      );
+    });
+
+    // given a treasury is set
+    // all of the expired yield is minted to the treasury and removed from the max yield
+    test('Treasury set - expired yield minted to the treasury', async () => {
+      const treasuryATA = await getATA(mint.publicKey, nonAdmin.publicKey);
+
+      // Yield expires once it is older than a cycle
+      prepSetUnclaimedExpiry(admin, treasuryATA);
+      await earn.methods
+        .setUnclaimedExpiry(new BN(1))
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      // Run a cycle without any claims, then start the next one
+      warp(claimCooldown, true);
+      await propagateIndex(new BN(1_100_000_000_000));
+      await completeClaims();
+
+      warp(claimCooldown, true);
+      const { globalAccount } = await propagateIndex(new BN(1_200_000_000_000));
+
+      // The yield of the first cycle has expired
+      const before = await earn.account.global.fetch(globalAccount);
+      const expired = before.unclaimedYield.slice(1).reduce((sum, bucket) => sum.add(bucket), new BN(0));
+      expect(expired.gtn(0)).toBe(true);
+
+      prepSweepUnclaimed(admin);
+      accounts.treasuryTokenAccount = treasuryATA;
+
+      await earn.methods
+        .sweepUnclaimed()
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      // Verify the expired yield was minted to the treasury and removed from the max yield
+      await expectTokenBalance(treasuryATA, expired);
+      await expectGlobalState(globalAccount, {
+        maxYield: before.maxYield.sub(expired),
+      });
+    });
+  });
+
+  describe('transfer_admin unit tests', () => {
+    // test cases
+    // [X] given the admin does not sign the transaction
+    //   [X] it reverts with a NotAuthorized error
+    // [X] given the admin signs the transaction
+    //   [X] given the new admin is the default public key
+    //     [X] it reverts with an InvalidParam error
+    //   [X] given the new admin is valid
+    //     [X] the new admin is set as the pending admin
+    //     [X] the admin is not changed until the transfer is accepted
+
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+    });
+
+    // given the admin does not sign the transaction
+    // it reverts with a NotAuthorized error
+    test('Admin does not sign transaction - reverts', async () => {
+      prepTransferAdmin(nonAdmin);
+
+      await expectAnchorError(
+        earn.methods
+          .transferAdmin(nonAdmin.publicKey)
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'NotAuthorized',
+      );
+    });
+
+    // given the admin signs the transaction
+    // given the new admin is the default public key
+    // it reverts with an InvalidParam error
+    test('New admin is the default public key - reverts', async () => {
+      prepTransferAdmin(admin);
+
+      await expectAnchorError(
+        earn.methods
+          .transferAdmin(PublicKey.default)
+          .accounts({ ...accounts })
+          .signers([admin])
+          .rpc(),
+        'InvalidParam',
+      );
+    });
+
+    // given the admin signs the transaction
+    // given the new admin is valid
+    // the new admin is set as the pending admin
+    test('Transfer admin - success', async () => {
+      const { globalAccount } = prepTransferAdmin(admin);
+
+      await earn.methods
+        .transferAdmin(nonAdmin.publicKey)
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      // Verify the transfer is pending and the admin is unchanged
+      const state = await earn.account.global.fetch(globalAccount);
+      expect(state.pendingAdmin).toEqual(nonAdmin.publicKey);
+      expect(state.admin).toEqual(admin.publicKey);
+    });
+  });
+
+  describe('accept_admin unit tests', () => {
+    // test cases
+    // [X] given there is no pending admin
+    //   [X] it reverts with a NotAuthorized error
+    // [X] given the signer is not the pending admin
+    //   [X] it reverts with a NotAuthorized error
+    // [X] given the pending admin signs the transaction
+    //   [X] the admin is set to the pending admin
+    //   [X] the pending admin is cleared
+    //   [X] the old admin can no longer act as admin
+
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+    });
+
+    // given there is no pending admin
+    // it reverts with a NotAuthorized error
+    test('No pending admin - reverts', async () => {
+      prepAcceptAdmin(nonAdmin);
+
+      await expectAnchorError(
+        earn.methods
+          .acceptAdmin()
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'NotAuthorized',
+      );
+    });
+
+    // given the signer is not the pending admin
+    // it reverts with a NotAuthorized error
+    test('Signer is not the pending admin - reverts', async () => {
+      // Nominate the earn authority as the new admin
+      prepTransferAdmin(admin);
+
+      await earn.methods
+        .transferAdmin(earnAuthority.publicKey)
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      prepAcceptAdmin(nonAdmin);
+
+      await expectAnchorError(
+        earn.methods
+          .acceptAdmin()
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'NotAuthorized',
+      );
+    });
+
+    // given the pending admin signs the transaction
+    // the admin is transferred and the pending admin is cleared
+    test('Accept admin - success', async () => {
+      prepTransferAdmin(admin);
+
+      await earn.methods
+        .transferAdmin(nonAdmin.publicKey)
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      const { globalAccount } = prepAcceptAdmin(nonAdmin);
+
+      await earn.methods
+        .acceptAdmin()
+        .accounts({ ...accounts })
+        .signers([nonAdmin])
+        .rpc();
+
+      // Verify the admin was transferred
+      const state = await earn.account.global.fetch(globalAccount);
+      expect(state.admin).toEqual(nonAdmin.publicKey);
+      expect(state.pendingAdmin).toBeNull();
+
+      // The old admin can no longer update the config
+      prepSetClaimCooldown(admin);
+
+      await expectAnchorError(
+        earn.methods
+          .setClaimCooldown(new BN(0))
+          .accounts({ ...accounts })
+          .signers([admin])
+          .rpc(),
+        'NotAuthorized',
+      );
+    });
+  });
+
+  describe('set_portal_authority unit tests', () => {
+    // test cases
+    // [X] given the admin does not sign the transaction
+    //   [X] it reverts with a NotAuthorized error
+    // [X] given the admin signs the transaction
+    //   [X] given the new portal authority is the default public key
+    //     [X] it reverts with an InvalidParam error
+    //   [X] given the new portal authority is valid
+    //     [X] the portal authority is updated
+
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+    });
+
+    // given the admin does not sign the transaction
+    // it reverts with a NotAuthorized error
+    test('Admin does not sign transaction - reverts', async () => {
+      prepSetPortalAuthority(nonAdmin);
+
+      await expectAnchorError(
+        earn.methods
+          .setPortalAuthority(nonAdmin.publicKey)
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'NotAuthorized',
+      );
+    });
+
+    // given the admin signs the transaction
+    // given the new portal authority is the default public key
+    // it reverts with an InvalidParam error
+    test('New portal authority is the default public key - reverts', async () => {
+      prepSetPortalAuthority(admin);
+
+      await expectAnchorError(
+        earn.methods
+          .setPortalAuthority(PublicKey.default)
+          .accounts({ ...accounts })
+          .signers([admin])
+          .rpc(),
+        'InvalidParam',
+      );
+    });
+
+    // given the admin signs the transaction
+    // given the new portal authority is valid
+    // the portal authority is updated
+    test('Set portal authority - success', async () => {
+      const newPortalAuthority = new Keypair().publicKey;
+
+      const { globalAccount } = prepSetPortalAuthority(admin);
+
+      await earn.methods
+        .setPortalAuthority(newPortalAuthority)
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      // Verify the portal authority was updated
+      const state = await earn.account.global.fetch(globalAccount);
+      expect(state.portalAuthority).toEqual(newPortalAuthority);
+    });
+  });
+
+  describe('grant_role unit tests', () => {
+    // test cases
+    // [X] given the admin does not sign the transaction
+    //   [X] it reverts with a NotAuthorized error
+    // [X] given the admin signs the transaction
+    //   [X] given the member already has the role
+    //     [X] it reverts with an InvalidParam error
+    //   [X] given the role already has the max number of members
+    //     [X] it reverts with a RoleFull error
+    //   [X] given the member does not have the role
+    //     [X] the roles account is created if needed
+    //     [X] the member is added to the role
+    //     [X] the member can act with the role
+
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+    });
+
+    // given the admin does not sign the transaction
+    // it reverts with a NotAuthorized error
+    test('Admin does not sign transaction - reverts', async () => {
+      prepGrantRole(nonAdmin);
+
+      await expectAnchorError(
+        earn.methods
+          .grantRole({ pauser: {} }, nonAdmin.publicKey)
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'NotAuthorized',
+      );
+    });
+
+    // given the admin signs the transaction
+    // given the member already has the role
+    // it reverts with an InvalidParam error
+    test('Member already has the role - reverts', async () => {
+      await grantRole({ pauser: {} }, nonAdmin.publicKey);
+
+      // Send the same instruction again in a new transaction
+      svm.expireBlockhash();
+
+      prepGrantRole(admin);
+
+      await expectAnchorError(
+        earn.methods
+          .grantRole({ pauser: {} }, nonAdmin.publicKey)
+          .accounts({ ...accounts })
+          .signers([admin])
+          .rpc(),
+        'InvalidParam',
+      );
+    });
+
+    // given the admin signs the transaction
+    // given the role already has the max number of members
+    // it reverts with a RoleFull error
+    test('Role is full - reverts', async () => {
+      for (let i = 0; i < 8; i++) {
+        await grantRole({ earnAuthority: {} }, new Keypair().publicKey);
+      }
+
+      prepGrantRole(admin);
+
+      await expectAnchorError(
+        earn.methods
+          .grantRole({ earnAuthority: {} }, nonAdmin.publicKey)
+          .accounts({ ...accounts })
+          .signers([admin])
+          .rpc(),
+        'RoleFull',
+      );
+    });
+
+    // given the admin signs the transaction
+    // given the member does not have the role
+    // the member is added to the role and can act with it
+    test('Grant role - success', async () => {
+      const { rolesAccount } = prepGrantRole(admin);
+
+      await earn.methods
+        .grantRole({ pauser: {} }, nonAdmin.publicKey)
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      // Verify the member was added to the role
+      const state = await earn.account.roles.fetch(rolesAccount);
+      expect(state.pausers).toEqual([nonAdmin.publicKey]);
+      expect(state.earnAuthorities).toEqual([]);
+
+      // The pauser can pause the program
+      const { globalAccount } = prepSetPaused(nonAdmin);
+      accounts.rolesAccount = rolesAccount;
+
+      await earn.methods
+        .setPaused(true)
+        .accounts({ ...accounts })
+        .signers([nonAdmin])
+        .rpc();
+
+      const global = await earn.account.global.fetch(globalAccount);
+      expect(global.paused).toBe(true);
+    });
+  });
+
+  describe('revoke_role unit tests', () => {
+    // test cases
+    // [X] given the admin does not sign the transaction
+    //   [X] it reverts with a NotAuthorized error
+    // [X] given the admin signs the transaction
+    //   [X] given the member does not have the role
+    //     [X] it reverts with an InvalidParam error
+    //   [X] given the member has the role
+    //     [X] the member is removed from the role
+
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+
+      // Grant the pauser role to the non admin
+      await grantRole({ pauser: {} }, nonAdmin.publicKey);
+    });
+
+    // given the admin does not sign the transaction
+    // it reverts with a NotAuthorized error
+    test('Admin does not sign transaction - reverts', async () => {
+      prepRevokeRole(nonAdmin);
+
+      await expectAnchorError(
+        earn.methods
+          .revokeRole({ pauser: {} }, nonAdmin.publicKey)
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'NotAuthorized',
+      );
+    });
+
+    // given the admin signs the transaction
+    // given the member does not have the role
+    // it reverts with an InvalidParam error
+    test('Member does not have the role - reverts', async () => {
+      prepRevokeRole(admin);
+
+      await expectAnchorError(
+        earn.methods
+          .revokeRole({ earnAuthority: {} }, nonAdmin.publicKey)
+          .accounts({ ...accounts })
+          .signers([admin])
+          .rpc(),
+        'InvalidParam',
+      );
+    });
+
+    // given the admin signs the transaction
+    // given the member has the role
+    // the member is removed from the role
+    test('Revoke role - success', async () => {
+      const { rolesAccount } = prepRevokeRole(admin);
+
+      await earn.methods
+        .revokeRole({ pauser: {} }, nonAdmin.publicKey)
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      // Verify the member was removed from the role
+      const state = await earn.account.roles.fetch(rolesAccount);
+      expect(state.pausers).toEqual([]);
+    });
+  });
+
+  describe('reject_pending_index unit tests', () => {
+    // test cases
+    // [X] given the admin does not sign the transaction
+    //   [X] it reverts with a NotAuthorized error
+    // [X] given there is no pending index
+    //   [X] it reverts with a NoPendingIndex error
+    // [X] given there is a pending index
+    //   [X] the pending index is discarded
+    //   [X] the current index is unchanged
+
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+
+      // Only allow the index to grow by 1% in a single update
+      prepSetIndexBounds(admin);
+
+      await earn.methods
+        .setIndexBounds(new BN(0), new BN(10_100))
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      // Warp past the initial cooldown period
+      warp(claimCooldown, true);
+    });
+
+    // given the admin does not sign the transaction
+    // it reverts with a NotAuthorized error
+    test('Admin does not sign transaction - reverts', async () => {
+      prepRejectPendingIndex(nonAdmin);
+
+      await expectAnchorError(
+        earn.methods
+          .rejectPendingIndex()
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'NotAuthorized',
+      );
+    });
+
+    // given there is no pending index
+    // it reverts with a NoPendingIndex error
+    test('No pending index - reverts', async () => {
+      prepRejectPendingIndex(admin);
+
+      await expectAnchorError(
+        earn.methods
+          .rejectPendingIndex()
+          .accounts({ ...accounts })
+          .signers([admin])
+          .rpc(),
+        'NoPendingIndex',
+      );
+    });
+
+    // given there is a pending index
+    // the pending index is discarded and the current index is unchanged
+    test('Reject pending index - success', async () => {
+      const newIndex = new BN(1_100_000_000_000);
+
+      // The update is out of bounds and quarantined
+      const { globalAccount } = await propagateIndex(newIndex);
+
+      const { pendingIndexAccount } = prepRejectPendingIndex(admin);
+
+      let pending = await earn.account.pendingIndex.fetch(pendingIndexAccount);
+      expect(pending.index.toString()).toEqual(newIndex.toString());
+
+      await earn.methods
+        .rejectPendingIndex()
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      // Verify the pending index was discarded
+      pending = await earn.account.pendingIndex.fetch(pendingIndexAccount);
+      expect(pending.index.toString()).toEqual('0');
+      expect(pending.earnerMerkleRoot).toEqual(ZERO_WORD);
+
+      const state = await earn.account.global.fetch(globalAccount);
+      expect(state.index.toString()).toEqual(initialIndex.toString());
+    });
+  });
+
+  describe('set_unclaimed_expiry unit tests', () => {
+    // test cases
+    // [X] given the admin does not sign the transaction
+    //   [X] it reverts with a NotAuthorized error
+    // [X] given the admin signs the transaction
+    //   [X] given the expiry is more than the max number of cycles
+    //     [X] it reverts with an InvalidParam error
+    //   [X] given a treasury token account is provided
+    //     [X] the expiry and treasury are set
+    //     [X] it emits an UnclaimedExpiryUpdate event
+    //   [X] given no treasury token account is provided
+    //     [X] the treasury is cleared
+
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+    });
+
+    // given the admin does not sign the transaction
+    // it reverts with a NotAuthorized error
+    test('Admin does not sign transaction - reverts', async () => {
+      prepSetUnclaimedExpiry(nonAdmin, null);
+
+      await expectAnchorError(
+        earn.methods
+          .setUnclaimedExpiry(new BN(4))
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'NotAuthorized',
+      );
+    });
+
+    // given the admin signs the transaction
+    // given the expiry is more than the max number of cycles
+    // it reverts with an InvalidParam error
+    test('Expiry above the max - reverts', async () => {
+      prepSetUnclaimedExpiry(admin, null);
+
+      await expectAnchorError(
+        earn.methods
+          .setUnclaimedExpiry(new BN(13))
+          .accounts({ ...accounts })
+          .signers([admin])
+          .rpc(),
+        'InvalidParam',
+      );
+    });
+
+    // given the admin signs the transaction
+    // the expiry and treasury are set, and the treasury is cleared when none is provided
+    test('Set unclaimed expiry - success', async () => {
+      const treasuryATA = await getATA(mint.publicKey, admin.publicKey);
+
+      const { globalAccount } = prepSetUnclaimedExpiry(admin, treasuryATA);
+
+      await earn.methods
+        .setUnclaimedExpiry(new BN(12))
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      // Verify the expiry and treasury were set
+      let state = await earn.account.global.fetch(globalAccount);
+      expect(state.unclaimedExpiryCycles.toString()).toEqual('12');
+      expect(state.unclaimedTreasury).toEqual(treasuryATA);
+
+      prepSetUnclaimedExpiry(admin, null);
+
+      await earn.methods
+        .setUnclaimedExpiry(new BN(12))
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      // Verify the treasury was cleared
+      state = await earn.account.global.fetch(globalAccount);
+      expect(state.unclaimedTreasury).toBeNull();
+    });
+
+    // given a treasury token account is provided
+    // it emits an UnclaimedExpiryUpdate event
+    test('Set unclaimed expiry - emits event', async () => {
+      const treasuryATA = await getATA(mint.publicKey, admin.publicKey);
+
+      prepSetUnclaimedExpiry(admin, treasuryATA);
+
+      const { events } = await earn.methods
+        .setUnclaimedExpiry(new BN(4))
+        .accounts({ ...accounts })
+        .signers([admin])
+        .simulate();
+
+      // Verify the event has the old and new configuration
+      expect(events.length).toEqual(1);
+      expect(events[0].name).toEqual('UnclaimedExpiryUpdate');
+      expect(events[0].data.oldExpiryCycles.toString()).toEqual('0');
+      expect(events[0].data.newExpiryCycles.toString()).toEqual('4');
+      expect(events[0].data.oldTreasury).toBeNull();
+      expect(events[0].data.newTreasury).toEqual(treasuryATA);
+    });
+  });
+
+  describe('set_attesters unit tests', () => {
+    // test cases
+    // [X] given the admin does not sign the transaction
+    //   [X] it reverts with a NotAuthorized error
+    // [X] given the admin signs the transaction
+    //   [X] given the threshold is larger than the number of attesters
+    //     [X] it reverts with an InvalidParam error
+    //   [X] given there are more than the max number of attesters
+    //     [X] it reverts with an InvalidParam error
+    //   [X] given an attester is listed twice
+    //     [X] it reverts with an InvalidParam error
+    //   [X] given the attesters and threshold are valid
+    //     [X] the attesters and threshold are set
+
+    const attesters = [new Keypair().publicKey, new Keypair().publicKey, new Keypair().publicKey];
+
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+    });
+
+    // given the admin does not sign the transaction
+    // it reverts with a NotAuthorized error
+    test('Admin does not sign transaction - reverts', async () => {
+      prepSetAttesters(nonAdmin);
+
+      await expectAnchorError(
+        earn.methods
+          .setAttesters(attesters, 2)
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'NotAuthorized',
+      );
+    });
+
+    // given the admin signs the transaction
+    // given the threshold is larger than the number of attesters
+    // it reverts with an InvalidParam error
+    test('Threshold above the number of attesters - reverts', async () => {
+      prepSetAttesters(admin);
+
+      await expectAnchorError(
+        earn.methods
+          .setAttesters(attesters, 4)
+          .accounts({ ...accounts })
+          .signers([admin])
+          .rpc(),
+        'InvalidParam',
+      );
+    });
+
+    // given the admin signs the transaction
+    // given there are more than the max number of attesters
+    // it reverts with an InvalidParam error
+    test('Too many attesters - reverts', async () => {
+      const tooMany = Array.from({ length: 9 }, () => new Keypair().publicKey);
+
+      prepSetAttesters(admin);
+
+      await expectAnchorError(
+        earn.methods
+          .setAttesters(tooMany, 2)
+          .accounts({ ...accounts })
+          .signers([admin])
+          .rpc(),
+        'InvalidParam',
+      );
+    });
+
+    // given the admin signs the transaction
+    // given an attester is listed twice
+    // it reverts with an InvalidParam error
+    test('Duplicate attester - reverts', async () => {
+      prepSetAttesters(admin);
+
+      await expectAnchorError(
+        earn.methods
+          .setAttesters([attesters[0], attesters[1], attesters[0]], 2)
+          .accounts({ ...accounts })
+          .signers([admin])
+          .rpc(),
+        'InvalidParam',
+      );
+    });
+
+    // given the admin signs the transaction
+    // given the attesters and threshold are valid
+    // the attesters and threshold are set
+    test('Set attesters - success', async () => {
+      const { globalAccount } = prepSetAttesters(admin);
+
+      await earn.methods
+        .setAttesters(attesters, 2)
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      // Verify the attesters and threshold were set
+      const state = await earn.account.global.fetch(globalAccount);
+      expect(state.attesters).toEqual(attesters);
+      expect(state.attestationThreshold).toEqual(2);
+    });
+  });
+
+  describe('set_removal_bounty unit tests', () => {
+    // test cases
+    // [X] given the admin does not sign the transaction
+    //   [X] it reverts with a NotAuthorized error
+    // [X] given the admin signs the transaction
+    //   [X] given the bounty is more than the rent of an earner account
+    //     [X] it reverts with an InvalidParam error
+    //   [X] given the bounty is at most the rent of an earner account
+    //     [X] the removal bounty is set
+    //     [X] it emits a RemovalBountyUpdate event
+
+    let earnerRent: BN;
+
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+
+      // The bounty is paid out of the rent of the removed earner account
+      earnerRent = new BN(await provider.connection.getMinimumBalanceForRentExemption(earn.account.earner.size));
+    });
+
+    // given the admin does not sign the transaction
+    // it reverts with a NotAuthorized error
+    test('Admin does not sign transaction - reverts', async () => {
+      prepSetRemovalBounty(nonAdmin);
+
+      await expectAnchorError(
+        earn.methods
+          .setRemovalBounty(new BN(1_000))
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'NotAuthorized',
+      );
+    });
+
+    // given the admin signs the transaction
+    // given the bounty is more than the rent of an earner account
+    // it reverts with an InvalidParam error
+    test('Bounty above the earner rent - reverts', async () => {
+      prepSetRemovalBounty(admin);
+
+      await expectAnchorError(
+        earn.methods
+          .setRemovalBounty(earnerRent.addn(1))
+          .accounts({ ...accounts })
+          .signers([admin])
+          .rpc(),
+        'InvalidParam',
+      );
+    });
+
+    // given the admin signs the transaction
+    // given the bounty is at most the rent of an earner account
+    // the removal bounty is set
+    test('Set removal bounty - success', async () => {
+      const { globalAccount } = prepSetRemovalBounty(admin);
+
+      await earn.methods
+        .setRemovalBounty(earnerRent)
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      // Verify the removal bounty was set
+      const state = await earn.account.global.fetch(globalAccount);
+      expect(state.removalBounty.toString()).toEqual(earnerRent.toString());
+    });
+
+    // given the admin signs the transaction
+    // given the bounty is at most the rent of an earner account
+    // it emits a RemovalBountyUpdate event
+    test('Set removal bounty - emits event', async () => {
+      prepSetRemovalBounty(admin);
+
+      const { events } = await earn.methods
+        .setRemovalBounty(earnerRent)
+        .accounts({ ...accounts })
+        .signers([admin])
+        .simulate();
+
+      expect(events.length).toEqual(1);
+      expect(events[0].name).toEqual('RemovalBountyUpdate');
+      expect(events[0].data.oldRemovalBounty.toString()).toEqual('0');
+      expect(events[0].data.newRemovalBounty.toString()).toEqual(earnerRent.toString());
+    });
+  });
+
+  describe('approve_program_earner unit tests', () => {
+    // test cases
+    // [X] given the admin does not sign the transaction
+    //   [X] it reverts with a NotAuthorized error
+    // [X] given the admin signs the transaction
+    //   [X] given the seeds do not derive a valid address
+    //     [X] it reverts with an InvalidParam error
+    //   [X] given the address is already approved
+    //     [X] it reverts with an InvalidParam error
+    //   [X] given the seeds derive a new address
+    //     [X] the address is added to the program earners
+
+    const programId = new Keypair().publicKey;
+    const [vault, bump] = PublicKey.findProgramAddressSync([Buffer.from('vault')], programId);
+    const seeds = [Buffer.from('vault'), Buffer.from([bump])];
+
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+    });
+
+    // given the admin does not sign the transaction
+    // it reverts with a NotAuthorized error
+    test('Admin does not sign transaction - reverts', async () => {
+      prepApproveProgramEarner(nonAdmin);
+
+      await expectAnchorError(
+        earn.methods
+          .approveProgramEarner(programId, seeds)
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'NotAuthorized',
+      );
+    });
+
+    // given the admin signs the transaction
+    // given the seeds do not derive a valid address
+    // it reverts with an InvalidParam error
+    test('Invalid seeds - reverts', async () => {
+      prepApproveProgramEarner(admin);
+
+      // Seeds are limited to 32 bytes
+      await expectAnchorError(
+        earn.methods
+          .approveProgramEarner(programId, [Buffer.alloc(33), Buffer.from([bump])])
+          .accounts({ ...accounts })
+          .signers([admin])
+          .rpc(),
+        'InvalidParam',
+      );
+    });
+
+    // given the admin signs the transaction
+    // given the address is already approved
+    // it reverts with an InvalidParam error
+    test('Already approved - reverts', async () => {
+      await approveProgramEarner(programId, seeds);
+
+      // Send the same instruction again in a new transaction
+      svm.expireBlockhash();
+
+      prepApproveProgramEarner(admin);
+
+      await expectAnchorError(
+        earn.methods
+          .approveProgramEarner(programId, seeds)
+          .accounts({ ...accounts })
+          .signers([admin])
+          .rpc(),
+        'InvalidParam',
+      );
+    });
+
+    // given the admin signs the transaction
+    // given the seeds derive a new address
+    // the address is added to the program earners
+    test('Approve program earner - success', async () => {
+      const { globalAccount } = prepApproveProgramEarner(admin);
+
+      await earn.methods
+        .approveProgramEarner(programId, seeds)
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      // Verify the program earner was approved
+      const state = await earn.account.global.fetch(globalAccount);
+      expect(state.programEarners).toEqual([{ user: vault, programId }]);
+    });
+  });
+
+  describe('remove_program_earner unit tests', () => {
+    // test cases
+    // [X] given the admin does not sign the transaction
+    //   [X] it reverts with a NotAuthorized error
+    // [X] given the admin signs the transaction
+    //   [X] given the earner is not a program earner
+    //     [X] it reverts with a NotEarning error
+    //   [X] given the earner is a program earner
+    //     [X] the program earner approval is removed
+    //     [X] the earner account is closed and the rent is refunded to its payer
+
+    const programId = new Keypair().publicKey;
+    const [vault, bump] = PublicKey.findProgramAddressSync([Buffer.from('vault')], programId);
+
+    let vaultATA: PublicKey;
+
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+
+      // Approve the vault and add an earner account for its token account
+      await approveProgramEarner(programId, [Buffer.from('vault'), Buffer.from([bump])]);
+
+      vaultATA = await getATA(mint.publicKey, vault);
+      prepAddProgramEarner(nonAdmin, vaultATA);
+
+      await earn.methods
+        .addProgramEarner()
+        .accounts({ ...accounts })
+        .signers([nonAdmin])
+        .rpc();
+    });
+
+    // given the admin does not sign the transaction
+    // it reverts with a NotAuthorized error
+    test('Admin does not sign transaction - reverts', async () => {
+      prepRemoveProgramEarner(nonAdmin, vaultATA, nonAdmin.publicKey);
+
+      await expectAnchorError(
+        earn.methods
+          .removeProgramEarner()
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'NotAuthorized',
+      );
+    });
+
+    // given the admin signs the transaction
+    // given the earner is not a program earner
+    // it reverts with a NotEarning error
+    test('Not a program earner - reverts', async () => {
+      // Add earner one as a registrar earner
+      earnerMerkleTree = new MerkleTree([earnerOne.publicKey]);
+      warp(claimCooldown, true);
+      await propagateIndex(initialIndex, earnerMerkleTree.getRoot());
+
+      const { proof } = earnerMerkleTree.getInclusionProof(earnerOne.publicKey);
+      await addRegistrarEarner(earnerOne.publicKey, proof);
+
+      const earnerOneATA = await getATA(mint.publicKey, earnerOne.publicKey);
+      prepRemoveProgramEarner(admin, earnerOneATA, nonAdmin.publicKey);
+
+      await expectAnchorError(
+        earn.methods
+          .removeProgramEarner()
+          .accounts({ ...accounts })
+          .signers([admin])
+          .rpc(),
+        'NotEarning',
+      );
+    });
+
+    // given the admin signs the transaction
+    // given the earner is a program earner
+    // the approval is removed and the earner account is closed
+    test('Remove program earner - success', async () => {
+      const { globalAccount, earnerAccount } = prepRemoveProgramEarner(admin, vaultATA, nonAdmin.publicKey);
+
+      const rent = svm.getAccount(earnerAccount)!.lamports;
+      const payerBalance = svm.getBalance(nonAdmin.publicKey)!;
+
+      await earn.methods
+        .removeProgramEarner()
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      // Verify the approval was removed and the rent refunded to the payer
+      const state = await earn.account.global.fetch(globalAccount);
+      expect(state.programEarners).toEqual([]);
+      expectAccountEmpty(earnerAccount);
+      expect(svm.getBalance(nonAdmin.publicKey)).toEqual(payerBalance + BigInt(rent));
+    });
+  });
+
+  describe('migrate_global unit tests', () => {
+    // test cases
+    // [X] given the admin does not sign the transaction
+    //   [X] it reverts with a NotAuthorized error
+    // [X] given the global account is on the current version
+    //   [X] it reverts with an InvalidVersion error
+    // [X] given the global account is on an older version
+    //   [X] the global account is upgraded to the current version
+
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+    });
+
+    // given the admin does not sign the transaction
+    // it reverts with a NotAuthorized error
+    test('Admin does not sign transaction - reverts', async () => {
+      const { globalAccount } = prepMigrateGlobal(nonAdmin);
+      await setAccountVersion(globalAccount, 'Global', 1);
+
+      await expectAnchorError(
+        earn.methods
+          .migrateGlobal()
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'NotAuthorized',
+      );
+    });
+
+    // given the global account is on the current version
+    // it reverts with an InvalidVersion error
+    test('Already on the current version - reverts', async () => {
+      prepMigrateGlobal(admin);
+
+      await expectAnchorError(
+        earn.methods
+          .migrateGlobal()
+          .accounts({ ...accounts })
+          .signers([admin])
+          .rpc(),
+        'InvalidVersion',
+      );
+    });
+
+    // given the global account is on an older version
+    // the global account is upgraded to the current version
+    test('Migrate global - success', async () => {
+      const { globalAccount } = prepMigrateGlobal(admin);
+      await setAccountVersion(globalAccount, 'Global', 1);
+
+      // Handlers refuse the account until it is migrated
+      prepSetClaimCooldown(admin);
+
+      await expectAnchorError(
+        earn.methods
+          .setClaimCooldown(new BN(0))
+          .accounts({ ...accounts })
+          .signers([admin])
+          .rpc(),
+        'InvalidVersion',
+      );
+
+      prepMigrateGlobal(admin);
+
+      await earn.methods
+        .migrateGlobal()
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      // Verify the account was upgraded and its state kept
+      const state = await earn.account.global.fetch(globalAccount);
+      expect(state.version).toEqual(2);
+      expect(state.index.toString()).toEqual(initialIndex.toString());
+      expect(state.admin).toEqual(admin.publicKey);
+    });
+  });
+
+  describe('propagate_index unit tests', () => {
+    // test cases
+    // [X] given the portal does not sign the transaction
+    //   [X] the transaction fails with a not authorized error
+    // [X] given the portal does sign the transaction
+    //   [X] given the new index is less than the existing index
+    //     [X] given the new earner merkle root is empty
+    //       [X] it is not updated
+    //     [X] given the new earner merkle is not empty
+    //       [X] it is not updated
+    //   [X] given the new index is greater than or eqal to the existing index
+    //     [X] given the new earner merkle root is empty
+    //       [X] it is not updated
+    //     [X] given the new earner merkle is not empty
+    //       [X] it is updated
+    //   [X] given the new earner root is older than the current root
+    //     [X] it reverts with a StaleEarnerRoot error
+    //   [X] given the new earner root differs from the current root with the same timestamp
+    //     [X] it reverts with a StaleEarnerRoot error
+    //   [X] given the current earner root is sent again
+    //     [X] it is accepted
+    //   [X] given the new earner root is newer than the current root
+    //     [X] it is updated
+    //   [X] given the last claim hasn't been completed
+    //     [X] given the time is within the cooldown period
+    //       [X] given the new index is less than or equal to the existing index
+    //         [X] given current supply is less than or equal to max supply
+    //           [X] nothing is updated
+    //         [X] given current supply is greater than max supply
+    //           [X] max supply is updated to the current supply
+    //       [X] given the new index is greater the existing index
+    //         [X] given current supply is less than or equal to max supply
+    //           [X] nothing is updated
+    //         [X] given current supply is greater than max supply
+    //           [X] max supply is updated to the current supply
+    //     [X] given the time is past the cooldown period
+    //       [X] given the new index is less than or equal to the existing index
+    //         [X] given the current supply is less than or equal to max supply
+    //           [X] nothing is updated
+    //         [X] given the current supply is greater than max supply
+    //           [X] max supply is updated to the current supply
+    //       [X] given the new index is greater the existing index
+    //         [X] given current supply is less than or equal to max supply
+    //           [X] nothing is updated
+    //         [X] given current supply is greater than max supply
+    //           [X] max supply is updated to the current supply
+    //   [X] given the last claim has been completed
+    //     [X] given the time is within the cooldown period
+    //       [X] given the new index is less than or equal to the existing index
+    //         [X] given current supply is greater than max supply
+    //           [X] max supply is updated to the current supply
+    //         [X] given current supply is less than or equal to max supply
+    //           [X] nothing is updated
+    //       [X] given the new index is greater the existing index
+    //         [X] given current supply is less than or equal to max supply
+    //           [X] nothing is updated
+    //         [X] given current supply is greater than max supply
+    //           [X] max supply is updated to the current supply
+    //     [X] given the time is past the cooldown period
+    //       [X] given the new index is less than or equal to the existing index
+    //         [X] given current supply is less than or equal to max supply
+    //           [X] nothing is updated
+    //         [X] given current supply is greater than max supply
+    //           [X] max supply is updated to the current supply
+    //       [X] given the new index is greater the existing index
+    //         [X] a new claim cycle starts:
+    //           [X] index is updated to the provided value
+    //           [X] timestamp is updated to the current timestamp
+    //           [X] max supply is set to the current supply
+    //           [X] distributed is set to 0
+    //           [X] max yield is updated
+    //           [X] claim complete is set to false
+    //   [X] given the global account is on an older version
+    //     [X] it is migrated and the index is applied
+    //   [X] given the global account is on the layout from before versioning
+    //     [X] given it holds the rent for the current layout
+    //       [X] it is grown to the current layout and the index is applied
+    //     [X] given it does not hold the rent for the current layout
+    //       [X] it reverts with a ConstraintRentExempt error
+
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+
+      // Populate the earner merkle tree with the initial earners
+      earnerMerkleTree = new MerkleTree([admin.publicKey, earnerOne.publicKey, earnerTwo.publicKey]);
+
+      // Propagate the earner and earn manager merkle roots so they are set to non-zero values
+      await propagateIndex(initialIndex, earnerMerkleTree.getRoot());
+
+      // Warp past the initial cooldown period
+      warp(claimCooldown, true);
+    });
+
+    // given the portal does not sign the transaction
+    // the transaction fails with an address constraint error
+    test('Non-portal cannot update index - reverts', async () => {
+      const newIndex = new BN(1_100_000_000_000);
+      const newEarnerRoot = Array(32).fill(1);
+
+      prepPropagateIndex(nonAdmin);
+
+      await expectAnchorError(
+        earn.methods
+          .propagateIndex(newIndex, newEarnerRoot, new BN(0))
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'NotAuthorized',
+      );
+    });
+
+    // given the global account is on an older version
+    // it is migrated and the index is applied
+    test('Global on an older version - migrated', async () => {
+      const newIndex = new BN(1_100_000_000_000);
+      const globalAccount = getGlobalAccount();
+      await setAccountVersion(globalAccount, 'Global', 1);
+
+      await propagateIndex(newIndex);
+
+      const state = await earn.account.global.fetch(globalAccount);
+      expect(state.version).toEqual(2);
+      expect(state.index.toString()).toEqual(newIndex.toString());
+    });
+
+    // given the global account is on the layout from before versioning
+    // given it holds the rent for the current layout
+    // it is grown to the current layout and the index is applied
+    test('Global on the legacy layout - migrated', async () => {
+      const newIndex = new BN(1_100_000_000_000);
+      const globalAccount = getGlobalAccount();
+      const currentSize = svm.getAccount(globalAccount)!.data.length;
+
+      // The account keeps the lamports of the current layout
+      setLegacyGlobalLayout();
+
+      await propagateIndex(newIndex);
+
+      // Verify the account was grown and upgraded before the new cycle started
+      expect(svm.getAccount(globalAccount)!.data.length).toEqual(currentSize);
+
+      const state = await earn.account.global.fetch(globalAccount);
+      expect(state.version).toEqual(2);
+      expect(state.admin).toEqual(admin.publicKey);
+      expect(state.previousIndex.toString()).toEqual(initialIndex.toString());
+      expect(state.index.toString()).toEqual(newIndex.toString());
+      expect(state.pendingAdmin).toBeNull();
+    });
+
+    // given the global account is on the layout from before versioning
+    // given it does not hold the rent for the current layout
+    // it reverts with a ConstraintRentExempt error
+    test('Global on the legacy layout without rent - reverts', async () => {
+      setLegacyGlobalLayout(Number(svm.minimumBalanceForRentExemption(BigInt(LEGACY_GLOBAL_SIZE))));
+
+      prepPropagateIndex(portal);
+
+      await expectAnchorError(
+        earn.methods
+          .propagateIndex(new BN(1_100_000_000_000), ZERO_WORD, new BN(0))
+          .accounts({ ...accounts })
+          .signers([portal])
+          .rpc(),
+        'ConstraintRentExempt',
+      );
+    });
+
+    // given new index is less than the existing index
+    // given new earner merkle root is empty
+    // nothing is updated
+    test('new index < existing index, new earner root empty', async () => {
+      // Try to propagate a new index with a lower value
+      const lowerIndex = new BN(999_999_999_999);
+      const emptyEarnerRoot = ZERO_WORD;
+      const emptyEarnManagerRoot = ZERO_WORD;
+
+      const { globalAccount } = await propagateIndex(lowerIndex, emptyEarnerRoot);
+
+      // Check the state
+      await expectGlobalState(globalAccount, {
+        earnerMerkleRoot: earnerMerkleTree.getRoot(),
+      });
+    });
+
+    // given new index is less than the existing index
+    // given new earner merkle root is not empty
+    // nothing is updated
+    test('new index < existing index, new earner root not empty', async () => {
+      // Try to propagate a new index with a lower value
+      const lowerIndex = new BN(999_999_999_999);
+      const newEarnerRoot = new Array(32).fill(1);
+
+      const { globalAccount } = await propagateIndex(lowerIndex, newEarnerRoot);
+
+      // Check the state
+      await expectGlobalState(globalAccount, {
+        earnerMerkleRoot: earnerMerkleTree.getRoot(),
+      });
+    });
+
+    // given new index is greater than or equal to the existing index
+    // given new earner merkle root is empty
+    // nothing is updated
+    test('new index >= existing index, new earner root empty', async () => {
+      // Try to propagate a new index with a higher value
+      const randomIncrement = randomInt(0, 2 ** 32);
+      const higherIndex = initialIndex.add(new BN(randomIncrement));
+      const emptyEarnerRoot = ZERO_WORD;
+
+      const { globalAccount } = await propagateIndex(higherIndex, emptyEarnerRoot);
+
+      // Check the state
+      await expectGlobalState(globalAccount, {
+        earnerMerkleRoot: earnerMerkleTree.getRoot(),
+      });
+    });
+
+    // given new index is greater than or equal to the existing index
+    // given new earner merkle root is not empty
+    // earner merkle root is updated
+    test('new index >= existing index, new earner root not empty', async () => {
+      // Try to propagate a new index with a higher value
+      const randomIncrement = randomInt(0, 2 ** 32);
+      const higherIndex = initialIndex.add(new BN(randomIncrement));
+      const newEarnerRoot = new Array(32).fill(1);
+
+      const { globalAccount } = await propagateIndex(higherIndex, newEarnerRoot);
+
+      // Check the state
+      await expectGlobalState(globalAccount, {
+        earnerMerkleRoot: newEarnerRoot,
+      });
+    });
+
+    // given the new earner root is older than the current earner root
+    // it reverts with a StaleEarnerRoot error
+    test('new earner root older than the current root - reverts', async () => {
+      const newerRoot = new Array(32).fill(1);
+      const olderRoot = new Array(32).fill(2);
+
+      await propagateIndex(initialIndex, newerRoot, new BN(1_700_000_000));
+
+      svm.expireBlockhash();
+      prepPropagateIndex(portal);
+
+      await expectAnchorError(
+        earn.methods
+          .propagateIndex(initialIndex, olderRoot, new BN(1_699_999_999))
+          .accounts({ ...accounts })
+          .signers([portal])
+          .rpc(),
+        'StaleEarnerRoot',
+      );
+    });
+
+    // given the new earner root has the same timestamp as the current root but differs
+    // it reverts with a StaleEarnerRoot error
+    test('different earner root with the current timestamp - reverts', async () => {
+      const currentRoot = new Array(32).fill(1);
+      const otherRoot = new Array(32).fill(2);
+
+      await propagateIndex(initialIndex, currentRoot, new BN(1_700_000_000));
+
+      svm.expireBlockhash();
+      prepPropagateIndex(portal);
+
+      await expectAnchorError(
+        earn.methods
+          .propagateIndex(initialIndex, otherRoot, new BN(1_700_000_000))
+          .accounts({ ...accounts })
+          .signers([portal])
+          .rpc(),
+        'StaleEarnerRoot',
+      );
+    });
+
+    // given the current earner root is sent again with its timestamp
+    // the update goes through and the root is unchanged
+    test('current earner root sent again - success', async () => {
+      const currentRoot = new Array(32).fill(1);
+
+      await propagateIndex(initialIndex, currentRoot, new BN(1_700_000_000));
+
+      svm.expireBlockhash();
+
+      const { globalAccount } = await propagateIndex(initialIndex, currentRoot, new BN(1_700_000_000));
+
+      // Check the state
+      await expectGlobalState(globalAccount, {
+        earnerMerkleRoot: currentRoot,
+      });
+    });
+
+    // given the new earner root is newer than the current earner root
+    // the earner root is updated
+    test('new earner root newer than the current root - success', async () => {
+      const olderRoot = new Array(32).fill(1);
+      const newerRoot = new Array(32).fill(2);
+
+      await propagateIndex(initialIndex, olderRoot, new BN(1_700_000_000));
+
+      svm.expireBlockhash();
+
+      const { globalAccount } = await propagateIndex(initialIndex, newerRoot, new BN(1_700_000_001));
+
+      // Check the state
+      await expectGlobalState(globalAccount, {
+        earnerMerkleRoot: newerRoot,
+      });
+    });
+
+    // given new index <= existing index
+    // given the last claim hasn't been completed
+    // given the time is within the cooldown period
+    // given current supply is less than or equal to max supply
+    // nothing is updated
+    test('new index <= existing index, claim not complete, within cooldown period, supply <= max supply', async () => {
+      // Update the index initially so the claim is not complete and the time is within the cooldown period
+      const startIndex = new BN(1_100_000_000_000);
+      const startTimestamp = new BN(svm.getClock().unixTimestamp.toString());
+      const { globalAccount } = await propagateIndex(startIndex);
+
+      // Expire the blockhash
+      svm.expireBlockhash();
+
+      // Update the index again with the same or lower value
+      const randomDecrement = randomInt(0, startIndex.toNumber());
+      const newIndex = startIndex.sub(new BN(randomDecrement));
+
+      await propagateIndex(newIndex);
+
+      // Confirm that the index, timestamp, and Merkle roots are updated
+      await expectGlobalState(globalAccount, {
+        index: startIndex,
+        timestamp: startTimestamp,
+        maxSupply: initialSupply,
+      });
+    });
+
+    // given new index <= existing index
+    // given the last claim hasn't been completed
+    // given the time is within the cooldown period
+    // given current supply is greater than max supply
+    // max supply is updated to the current supply
+    test('new index <= existing index, claim not complete, within cooldown period, supply > max supply', async () => {
+      // Update the index initially so the claim is not complete and the time is within the cooldown period
+      const startIndex = new BN(1_100_000_000_000);
+      const startTimestamp = new BN(svm.getClock().unixTimestamp.toString());
+      const { globalAccount } = await propagateIndex(startIndex);
+
+      // Mint more tokens to increase supply
+      const additionalSupply = new BN(50_000_000);
+      await mintM(admin.publicKey, additionalSupply);
+      const newSupply = initialSupply.add(additionalSupply);
+
+      // Warp forward in time slightly
+      warp(new BN(1), true);
+
+      // Update the index again with the same or lower value
+      const randomDecrement = randomInt(0, startIndex.toNumber());
+      const newIndex = startIndex.sub(new BN(randomDecrement));
+      await propagateIndex(newIndex);
+
+      // Check that only max supply was updated
+      await expectGlobalState(globalAccount, {
+        index: startIndex,
+        timestamp: startTimestamp,
+        maxSupply: newSupply,
+      });
+    });
+
+    // given new index <= existing index
+    // given the last claim has been completed
+    // given the time is within the cooldown period
+    // given current supply is greater than max supply
+    // max supply is updated to the current supply
+    test('new index <= existing index, claim complete, within cooldown period, supply > max supply', async () => {
+      // Update the index initially so the claim is not complete and the time is within the cooldown period
+      const startIndex = new BN(1_100_000_000_000);
+      const startTimestamp = new BN(svm.getClock().unixTimestamp.toString());
+      const { globalAccount } = await propagateIndex(startIndex);
+
+      // Set claim complete
+      await completeClaims();
+
+      // Mint more tokens
+      const additionalSupply = new BN(50_000_000);
+      await mintM(admin.publicKey, additionalSupply);
+      const newSupply = initialSupply.add(additionalSupply);
+
+      // Warp forward in time slightly
+      warp(new BN(1), true);
+
+      // Update the index again with the same or lower value
+      const randomDecrement = randomInt(0, startIndex.toNumber());
+      const newIndex = startIndex.sub(new BN(randomDecrement));
+      await propagateIndex(newIndex);
+
+      // Check that only max supply was updated
+      await expectGlobalState(globalAccount, {
+        index: startIndex,
+        timestamp: startTimestamp,
+        maxSupply: newSupply,
+      });
+    });
+
+    // given new index <= existing index
+    // given the last claim has been completed
+    // given the time is within the cooldown period
+    // given current supply is less than or equal to max supply
+    // nothing is updated
+    test('new index <= existing index, claim complete, within cooldown period, supply <= max supply', async () => {
+      // Update the index initially so the claim is not complete and the time is within the cooldown period
+      const startIndex = new BN(1_100_000_000_000);
+      const startTimestamp = new BN(svm.getClock().unixTimestamp.toString());
+      const { globalAccount } = await propagateIndex(startIndex);
+
+      // Set claim complete
+      await completeClaims();
+
+      // Warp forward in time slightly
+      warp(new BN(1), true);
+
+      // Update the index again with the same or lower value
+      const randomDecrement = randomInt(0, startIndex.toNumber());
+      const newIndex = startIndex.sub(new BN(randomDecrement));
+      await propagateIndex(newIndex);
+
+      // Check that nothing was updated
+      await expectGlobalState(globalAccount, {
+        index: startIndex,
+        timestamp: startTimestamp,
+        maxSupply: initialSupply,
+      });
+    });
+
+    // given new index <= existing index
+    // given the last claim hasn't been completed
+    // given the time is past the cooldown period
+    // given the current supply is greater than max supply
+    // max supply is updated to the current supply
+    test('new index <= existing index, claim not complete, past cooldown period, supply > max supply', async () => {
+      // Update the index initially so the claim is not complete and the time is within the cooldown period
+      const startIndex = new BN(1_100_000_000_000);
+      const startTimestamp = new BN(svm.getClock().unixTimestamp.toString());
+      const { globalAccount } = await propagateIndex(startIndex);
+
+      // Mint more tokens
+      const additionalSupply = new BN(50_000_000);
+      await mintM(admin.publicKey, additionalSupply);
+      const newSupply = initialSupply.add(additionalSupply);
+
+      // Expire the blockhash
+      svm.expireBlockhash();
+
+      // Warp forward past the cooldown period
+      warp(claimCooldown.add(new BN(1)), true);
+
+      // Update the index again with the same or lower value
+      const randomDecrement = randomInt(0, startIndex.toNumber());
+      const newIndex = startIndex.sub(new BN(randomDecrement));
+      await propagateIndex(newIndex);
+
+      // Check that only max supply was updated
+      await expectGlobalState(globalAccount, {
+        index: startIndex,
+        timestamp: startTimestamp,
+        maxSupply: newSupply,
+      });
+    });
+
+    // given new index <= existing index
+    // given the last claim hasn't been completed
+    // given the time is past the cooldown period
+    // given the current supply is less than or equal to max supply
+    // nothing is updated
+    test('new index <= existing index, claim not complete, past cooldown period, supply <= max supply', async () => {
+      // Update the index initially so the claim is not complete and the time is within the cooldown period
+      const startIndex = new BN(1_100_000_000_000);
+      const startTimestamp = new BN(svm.getClock().unixTimestamp.toString());
+      const { globalAccount } = await propagateIndex(startIndex);
+
+      // Expire the blockhash
+      svm.expireBlockhash();
+
+      // Warp forward past the cooldown period
+      warp(claimCooldown.add(new BN(1)), true);
+
+      // Update the index again with the same or lower value
+      const randomDecrement = randomInt(0, startIndex.toNumber());
+      const newIndex = startIndex.sub(new BN(randomDecrement));
+      await propagateIndex(newIndex);
+
+      // Check that nothing was updated
+      await expectGlobalState(globalAccount, {
+        index: startIndex,
+        timestamp: startTimestamp,
+        maxSupply: initialSupply,
+      });
+    });
+
+    // given new index > existing index
+    // given the last claim hasn't been completed
+    // given the time is within the cooldown period
+    // given current supply is less than or equal to max supply
+    // nothing is updated
+    test('new index > existing index, claim not complete, within cooldown period, supply <= max supply', async () => {
+      // Update the index initially so the claim is not complete and the time is within the cooldown period
+      const startIndex = new BN(1_100_000_000_000);
+      const startTimestamp = new BN(svm.getClock().unixTimestamp.toString());
+      const { globalAccount } = await propagateIndex(startIndex);
+
+      // Expire the blockhash
+      svm.expireBlockhash();
+
+      // Warp forward slightly
+      warp(new BN(1), true);
+
+      // Update the index again with a higher value
+      const randomIncrement = randomInt(1, 2 ** 32);
+      const newIndex = startIndex.add(new BN(randomIncrement));
+      await propagateIndex(newIndex);
+
+      // Check that nothing was updated
+      await expectGlobalState(globalAccount, {
+        index: startIndex,
+        timestamp: startTimestamp,
+        maxSupply: initialSupply,
+      });
+    });
+
+    // given new index > existing index
+    // given the last claim hasn't been completed
+    // given the time is within the cooldown period
+    // given current supply is greater than max supply
+    // max supply is updated to the current supply
+    test('new index > existing index, claim not complete, within cooldown period, supply > max supply', async () => {
+      // Update the index initially so the claim is not complete and the time is within the cooldown period
+      const startIndex = new BN(1_100_000_000_000);
+      const startTimestamp = new BN(svm.getClock().unixTimestamp.toString());
+      const { globalAccount } = await propagateIndex(startIndex);
+
+      // Mint more tokens
+      const additionalSupply = new BN(50_000_000);
+      await mintM(admin.publicKey, additionalSupply);
+      const newSupply = initialSupply.add(additionalSupply);
+
+      // Warp forward slightly
+      warp(new BN(1), true);
+
+      // Update the index again with a higher value
+      const randomIncrement = randomInt(1, 2 ** 32);
+      const newIndex = startIndex.add(new BN(randomIncrement));
+      await propagateIndex(newIndex);
+
+      // Check that only max supply was updated
+      await expectGlobalState(globalAccount, {
+        index: startIndex,
+        timestamp: startTimestamp,
+        maxSupply: newSupply,
+      });
+    });
+
+    // given new index > existing index
+    // given the last claim has been completed
+    // given the time is within the cooldown period
+    // given current supply is less than or equal to max supply
+    // nothing is updated
+    test('new index > existing index, claim complete, within cooldown period, supply <= max supply', async () => {
+      // Update the index initially so the claim is not complete and the time is within the cooldown period
+      const startIndex = new BN(1_100_000_000_000);
+      const startTimestamp = new BN(svm.getClock().unixTimestamp.toString());
+      const { globalAccount } = await propagateIndex(startIndex);
+
+      // Set claim complete
+      await completeClaims();
+
+      // Warp forward slightly
+      warp(new BN(1), true);
+
+      // Update the index again with a higher value
+      const randomIncrement = randomInt(1, 2 ** 32);
+      const newIndex = startIndex.add(new BN(randomIncrement));
+      await propagateIndex(newIndex);
+
+      // Check that nothing was updated
+      await expectGlobalState(globalAccount, {
+        index: startIndex,
+        timestamp: startTimestamp,
+        maxSupply: initialSupply,
+      });
+    });
+
+    // given new index > existing index
+    // given the last claim has been completed
+    // given the time is within the cooldown period
+    // given current supply is greater than max supply
+    // max supply is updated to the current supply
+    test('new index > existing index, claim complete, within cooldown period, supply > max supply', async () => {
+      // Update the index initially so the claim is not complete and the time is within the cooldown period
+      const startIndex = new BN(1_100_000_000_000);
+      const startTimestamp = new BN(svm.getClock().unixTimestamp.toString());
+      const { globalAccount } = await propagateIndex(startIndex);
+
+      // Set claim complete
+      await completeClaims();
+
+      // Mint more tokens
+      const additionalSupply = new BN(50_000_000);
+      await mintM(admin.publicKey, additionalSupply);
+      const newSupply = initialSupply.add(additionalSupply);
+
+      // Warp forward slightly
+      warp(new BN(1), true);
+
+      // Update the index again with a higher value
+      const randomIncrement = randomInt(1, 2 ** 32);
+      const newIndex = startIndex.add(new BN(randomIncrement));
+      await propagateIndex(newIndex);
+
+      // Check that only max supply was updated
+      await expectGlobalState(globalAccount, {
+        index: startIndex,
+        timestamp: startTimestamp,
+        maxSupply: newSupply,
+      });
+    });
+
+    // given new index > existing index
+    // given the last claim hasn't been completed
+    // given the time is past the cooldown period
+    // given current supply is less than or equal to max supply
+    // nothing is updated
+    test('new index > existing index, claim not complete, past cooldown period, supply <= max supply', async () => {
+      // Update the index initially so the claim is not complete and the time is within the cooldown period
+      const startIndex = new BN(1_100_000_000_000);
+      const startTimestamp = new BN(svm.getClock().unixTimestamp.toString());
+      const { globalAccount } = await propagateIndex(startIndex);
+
+      // Expire the blockhash
+      svm.expireBlockhash();
+
+      // Warp forward past the cooldown period
+      warp(claimCooldown.add(new BN(1)), true);
+
+      // Update the index again with a higher value
+      const randomIncrement = randomInt(1, 2 ** 32);
+      const newIndex = startIndex.add(new BN(randomIncrement));
+      await propagateIndex(newIndex);
+
+      // Check that nothing was updated
+      await expectGlobalState(globalAccount, {
+        index: startIndex,
+        timestamp: startTimestamp,
+        maxSupply: initialSupply,
+      });
+    });
+
+    // given new index > existing index
+    // given the last claim hasn't been completed
+    // given the time is past the cooldown period
+    // given current supply is greater than max supply
+    // max supply is updated to the current supply
+    test('new index > existing index, claim not complete, past cooldown period, supply > max supply', async () => {
+      // Update the index initially so the claim is not complete and the time is within the cooldown period
+      const startIndex = new BN(1_100_000_000_000);
+      const startTimestamp = new BN(svm.getClock().unixTimestamp.toString());
+      const { globalAccount } = await propagateIndex(startIndex);
+
+      // Mint more tokens
+      const additionalSupply = new BN(50_000_000);
+      await mintM(admin.publicKey, additionalSupply);
+      const newSupply = initialSupply.add(additionalSupply);
+
+      // Expire the blockhash
+      svm.expireBlockhash();
+
+      // Warp forward past the cooldown period
+      warp(claimCooldown.add(new BN(1)), true);
+
+      // Update the index again with a higher value
+      const randomIncrement = randomInt(1, 2 ** 32);
+      const newIndex = startIndex.add(new BN(randomIncrement));
+      await propagateIndex(newIndex);
+
+      // Check that only max supply was updated
+      await expectGlobalState(globalAccount, {
+        index: startIndex,
+        timestamp: startTimestamp,
+        maxSupply: newSupply,
+      });
+    });
+
+    // given new index > existing index
+    // given the last claim has been completed
+    // given the time is past the cooldown period
+    // the index is updated to the provided value
+    // max supply is set to the current supply
+    // distributed is set to 0
+    // max yield is updated
+    // claim complete is set to false
+    test('new index > existing index, claim complete, past cooldown period, new cycle starts', async () => {
+      // Update the index initially so the claim is not complete and the time is within the cooldown period
+      const startIndex = new BN(1_100_000_000_000);
+      const { globalAccount } = await propagateIndex(startIndex);
+      const startGlobalState = await earn.account.global.fetch(globalAccount);
+
+      // Set claim complete
+      await completeClaims();
+
+      // Mint more tokens
+      const additionalSupply = new BN(50_000_000);
+      await mintM(admin.publicKey, additionalSupply);
+      const newSupply = initialSupply.add(additionalSupply);
+
+      // Warp forward past the cooldown period
+      warp(claimCooldown.add(new BN(1)), true);
+
+      // Update the index again with a higher value
+      const randomIncrement = randomInt(1, 2 ** 32);
+      const newIndex = startIndex.add(new BN(randomIncrement));
+      await propagateIndex(newIndex);
+
+      // Calculate expected rewards per token and max yield
+      const supplyPlusLeftover = initialSupply.add(startGlobalState.maxYield).sub(startGlobalState.distributed);
+
+      const maxYield = supplyPlusLeftover
+        .mul(newIndex)
+        .div(startIndex)
+        .sub(supplyPlusLeftover)
+        .add(startGlobalState.maxYield);
+
+      // Check that a new cycle started with all updates
+      const clock = svm.getClock();
+      await expectGlobalState(globalAccount, {
+        index: newIndex,
+        timestamp: new BN(clock.unixTimestamp.toString()),
+        maxSupply: newSupply,
+        maxYield,
+        distributed: new BN(0),
+        claimComplete: false,
+      });
+    });
+  });
+
+  describe('claim_for unit tests', () => {
+    // test cases
+    // [X] given the earn authority does not sign the transaction
+    //   [X] it reverts with an address constraint error
+    // [X] given the earn authority signs the transaction
+    //   [X] given the user token account's earner account is not initialized
+    //     [X] it reverts with an account not initialized error
+    //   [X] given the earner's last claim index is the current index
+    //     [X] it reverts with an AlreadyClaimed error
+    //   [X] given the amonut to be minted causes the total distributed to exceed the max yield
+    //     [X] it reverts with am ExceedsMaxYield error
+    //   [X] otherwise
+    //     [X] the correct amount is minted to the earner's token account
+
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+
+      // Populate the earner merkle tree with the initial earners
+      earnerMerkleTree = new MerkleTree([admin.publicKey, earnerOne.publicKey, earnerTwo.publicKey]);
+
+      // Warp past the initial cooldown period
+      warp(claimCooldown, true);
+
+      // Propagate the earner and earn manager merkle roots so we can add earners
+      await propagateIndex(initialIndex, earnerMerkleTree.getRoot());
+
+      // Add earner one as a registrar earner
+      const { proof: earnerOneProof } = earnerMerkleTree.getInclusionProof(earnerOne.publicKey);
+      await addRegistrarEarner(earnerOne.publicKey, earnerOneProof);
+
+      // Send earner one 10 tokens so they have a positive balance
+      await mintM(earnerOne.publicKey, new BN(10_000_000));
+    });
+
+    // given the earn authority doesn't sign the transaction
+    // it reverts with an address constraint error
+    test('Non-earn authority cannot claim - reverts', async () => {
+      // Setup the instruction
+      await prepClaimFor(nonAdmin, mint.publicKey, earnerOne.publicKey);
+
+      // Attempt to claim with non-earn authority
+      await expectAnchorError(
+        earn.methods
+          .claimFor(new BN(100_000_000))
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'NotAuthorized',
+      );
+    });
+
+    // given the earn authority signs the transaction
+    // given the user token account's earner account is not initialized
+    // it reverts with an account not initialized error
+    test('Earner account not initialized - reverts', async () => {
+      // Setup the instruction
+      await prepClaimFor(earnAuthority, mint.publicKey, earnerTwo.publicKey);
+
+      // Attempt to claim for non-initialized earner
+      await expectAnchorError(
+        earn.methods
+          .claimFor(new BN(10_000_000))
+          .accounts({ ...accounts })
+          .signers([earnAuthority])
+          .rpc(),
+        'AccountNotInitialized',
+      );
+    });
+
+    // given the earn authority signs the transaction
+    // given the earner's last claim index is the current index
+    // it reverts with an AlreadyClaimed error
+    test('Earner already claimed - reverts', async () => {
+      // Setup the instruction
+      await prepClaimFor(earnAuthority, mint.publicKey, earnerOne.publicKey);
+
+      // Attempt to claim, but the earner is already up to date
+      await expectAnchorError(
+        earn.methods
+          .claimFor(new BN(10_000_000))
+          .accounts({ ...accounts })
+          .signers([earnAuthority])
+          .rpc(),
+        'AlreadyClaimed',
+      );
+    });
+
+    // given the earn authority signs the transaction
+    // given the amount to be minted causes the total distributed to exceed the max yield
+    // it reverts with an ExceedsMaxYield error
+    test('Exceeds max yield - reverts', async () => {
+      // Update the index so there is outstanding yield
+      await propagateIndex(new BN(1_100_000_000_000));
+
+      // Setup the instruction
+      await prepClaimFor(earnAuthority, mint.publicKey, earnerOne.publicKey);
+
+      // Attempt to claim an amount that exceeds the max yield
+      await expectAnchorError(
+        earn.methods
+          .claimFor(new BN(120_000_001))
+          .accounts({ ...accounts })
+          .signers([earnAuthority])
+          .rpc(),
+        'ExceedsMaxYield',
+      );
+    });
+
+    // given the earn authority signs the transaction
+    // given the inputs are correct
+    // the correct amount is minted to the earner's token account
+    test('Claim for - success', async () => {
+      // Update the index so there is outstanding yield
+      await propagateIndex(new BN(1_100_000_000_000));
+
+      // Setup the instruction
+      const { earnerAccount, earnerATA } = await prepClaimFor(earnAuthority, mint.publicKey, earnerOne.publicKey);
+
+      // Verify the starting values
+      await expectTokenBalance(earnerATA, new BN(10_000_000));
+      expectEarnerState(earnerAccount, {
+        lastClaimIndex: initialIndex,
+      });
+
+      // Claim for the earner
+      await earn.methods
+        .claimFor(new BN(10_000_000))
+        .accounts({ ...accounts })
+        .signers([earnAuthority])
+        .rpc();
+
+      const currentTime = new BN(svm.getClock().unixTimestamp.toString());
+
+      // Verify the user token account was minted the correct amount
+      // and the last claim index was updated
+      await expectTokenBalance(earnerATA, new BN(11_000_000));
+      expectEarnerState(earnerAccount, {
+        lastClaimIndex: new BN(1_100_000_000_000),
+        lastClaimTimestamp: currentTime,
+      });
+    });
+  });
+
+  describe('preview_claim unit tests', () => {
+    // test cases
+    // [X] given the program is paused
+    //   [X] it reports a Paused failure
+    // [X] given the earner has already claimed for the current index
+    //   [X] it reports an AlreadyClaimed failure
+    // [X] given a claim cycle is active
+    //   [X] it reports the rewards without a failure
+
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+
+      // Populate the earner merkle tree with the initial earners
+      earnerMerkleTree = new MerkleTree([admin.publicKey, earnerOne.publicKey, earnerTwo.publicKey]);
+
+      // Warp past the initial cooldown period
+      warp(claimCooldown, true);
+
+      // Propagate the earner merkle root so we can add earners
+      await propagateIndex(initialIndex, earnerMerkleTree.getRoot());
+
+      // Add earner one as a registrar earner
+      const { proof: earnerOneProof } = earnerMerkleTree.getInclusionProof(earnerOne.publicKey);
+      await addRegistrarEarner(earnerOne.publicKey, earnerOneProof);
+
+      // Warp past the cooldown and start a new claim cycle
+      warp(claimCooldown, true);
+      await propagateIndex(new BN(1_100_000_000_000));
+    });
+
+    test('Program paused - reports Paused', async () => {
+      // Pause the program
+      prepSetPaused(admin);
+
+      await earn.methods
+        .setPaused(true)
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      await prepPreviewClaim(earnerOne.publicKey);
+
+      const preview = await earn.methods
+        .previewClaim(new BN(10_000_000))
+        .accounts({ ...accounts })
+        .view();
+
+      expect(preview.failure).toEqual({ paused: {} });
+      expect(preview.amount.toString()).toEqual('0');
+    });
+
+    test('Already claimed - reports AlreadyClaimed', async () => {
+      // Claim for earner one
+      await prepClaimFor(earnAuthority, mint.publicKey, earnerOne.publicKey);
+
+      await earn.methods
+        .claimFor(new BN(10_000_000))
+        .accounts({ ...accounts })
+        .signers([earnAuthority])
+        .rpc();
+
+      await prepPreviewClaim(earnerOne.publicKey);
+
+      const preview = await earn.methods
+        .previewClaim(new BN(10_000_000))
+        .accounts({ ...accounts })
+        .view();
+
+      expect(preview.failure).toEqual({ alreadyClaimed: {} });
+    });
+
+    test('Active claim cycle - reports rewards', async () => {
+      await prepPreviewClaim(earnerOne.publicKey);
+
+      const preview = await earn.methods
+        .previewClaim(new BN(10_000_000))
+        .accounts({ ...accounts })
+        .view();
+
+      // 10% of the snapshot balance
+      expect(preview.failure).toBeNull();
+      expect(preview.rewards.toString()).toEqual('1000000');
+      expect(preview.amount.toString()).toEqual('1000000');
+    });
+  });
+
+  describe('complete_claims unit tests', () => {
+    // test cases
+    // [X] given the earn authority does not sign the transaction
+    //   [X] it reverts with an address constraint error
+    // [X] given the earn authority signs the transaction
+    //   [X] given the most recent claim is complete
+    //     [X] it reverts with a NoActiveClaim error
+    //   [X] given the most recent claim is not complete
+    //     [X] it sets the claim complete flag to true in the global account
+    //     [X] it records the final state of the cycle
+    //   [X] given the most recent claim completed because the claim window passed
+    //     [X] it records the final state of the cycle as timed out
+    //   [X] given the previous cycle timed out and was not recorded
+    //     [X] it reverts with an UnrecordedClaimCycle error
+
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+
+      // Warp past the initial cooldown period
+      warp(claimCooldown, true);
+
+      // Propagate a new index to start a new claim cycle
+      await propagateIndex(new BN(1_100_000_000_000));
+    });
+
+    // given the earn authority does not sign the transaction
+    // it reverts with an address constraint error
+    test('Earn authority does not sign - reverts', async () => {
+      // Setup the instruction
+      await prepCompleteClaims(nonAdmin);
+
+      // Attempt to complete claim with non-earn authority
+      await expectAnchorError(
+        earn.methods
+          .completeClaims()
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'NotAuthorized',
+      );
+    });
+
+    // given the earn authority signs the transaction
+    // given the most recent claim is complete
+    // it reverts with a NoActiveClaim error
+    test('Claim already complete - reverts', async () => {
+      // Complete the active claim
+      await completeClaims();
+
+      // Expire the blockhash so the same txn can be sent again (in a new block)
+      svm.expireBlockhash();
+
+      // Setup the instruction
+      await prepCompleteClaims(earnAuthority);
+
+      // Attempt to complete claim when already complete
+      await expectAnchorError(
+        earn.methods
+          .completeClaims()
+          .accounts({ ...accounts })
+          .signers([earnAuthority])
+          .rpc(),
+        'NoActiveClaim',
+      );
+    });
+
+    // given the earn authority signs the transaction
+    // given the most recent claim is not complete
+    // it sets the claim complete flag to true in the global account
+    test('Complete claims - success', async () => {
+      // Setup the instruction
+      const { globalAccount, claimCycleAccount } = await prepCompleteClaims(earnAuthority);
+
+      // Complete the claim
+      await earn.methods
+        .completeClaims()
+        .accounts({ ...accounts })
+        .signers([earnAuthority])
+        .rpc();
+
+      // Verify the global state was updated
+      await expectGlobalState(globalAccount, {
+        claimComplete: true,
+      });
+
+      // Verify the final state of the cycle was recorded
+      const cycle = await earn.account.claimCycle.fetch(claimCycleAccount);
+      expect(cycle.index.toString()).toEqual('1100000000000');
+      expect(cycle.previousIndex.toString()).toEqual(initialIndex.toString());
+      expect(cycle.completedAt.toString()).toEqual(svm.getClock().unixTimestamp.toString());
+      expect(cycle.timedOut).toBe(false);
+    });
+
+    // given the most recent claim completed because the claim window passed
+    // it records the final state of the cycle as timed out
+    test('Complete claims after the claim window passed - success', async () => {
+      // Set a claim window and warp past it
+      prepSetClaimWindow(admin);
+      await earn.methods
+        .setClaimWindow(new BN(3_600))
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+      warp(new BN(3_600), true);
+
+      // An index update closes the cycle without starting a new one
+      await propagateIndex(new BN(1_100_000_000_000));
+      const { globalAccount, claimCycleAccount } = await prepCompleteClaims(earnAuthority);
+      await expectGlobalState(globalAccount, {
+        claimComplete: true,
+      });
+
+      // The earn authority can still record the cycle
+      await earn.methods
+        .completeClaims()
+        .accounts({ ...accounts })
+        .signers([earnAuthority])
+        .rpc();
+
+      // Verify the final state of the cycle was recorded as timed out
+      const cycle = await earn.account.claimCycle.fetch(claimCycleAccount);
+      expect(cycle.index.toString()).toEqual('1100000000000');
+      expect(cycle.timedOut).toBe(true);
+    });
+
+    // given the previous cycle timed out and was not recorded
+    // it reverts with an UnrecordedClaimCycle error
+    test('Previous cycle not recorded - reverts', async () => {
+      // Set a claim window
+      prepSetClaimWindow(admin);
+      await earn.methods
+        .setClaimWindow(new BN(claimCooldown.toString()))
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      // Let the active cycle time out and start the next one in the same update
+      warp(claimCooldown, true);
+      await propagateIndex(new BN(1_200_000_000_000));
+
+      // Setup the instruction
+      await prepCompleteClaims(earnAuthority);
+
+      // Attempt to complete the new cycle before the previous one was recorded
+      await expectAnchorError(
+        earn.methods
+          .completeClaims()
+          .accounts({ ...accounts })
+          .signers([earnAuthority])
+          .rpc(),
+        'UnrecordedClaimCycle',
+      );
+    });
+  });
+
+  describe('record_claim_cycle unit tests', () => {
+    // test cases
+    // [X] given no claim cycle has completed
+    //   [X] it reverts with a NoCompletedCycle error
+    // [X] given a cycle completed and a new cycle started before it was recorded
+    //   [X] anyone can record the completed cycle
+    // [X] given the completed cycle was already recorded
+    //   [X] it reverts with a NoCompletedCycle error
+    // [X] given the next cycle times out before the previous one was recorded
+    //   [X] the next cycle stays open until the previous one is recorded
+
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+
+      // Warp past the initial cooldown period
+      warp(claimCooldown, true);
+    });
+
+    // given no claim cycle has completed
+    // it reverts with a NoCompletedCycle error
+    test('No completed cycle - reverts', async () => {
+      // Setup the instruction
+      prepRecordClaimCycle(nonAdmin, new BN(0));
+
+      // Attempt to record a cycle
+      await expectAnchorError(
+        earn.methods
+          .recordClaimCycle()
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'NoCompletedCycle',
+      );
+    });
+
+    // given a cycle completed and a new cycle started before it was recorded
+    // anyone can record the completed cycle
+    test('Record claim cycle - success', async () => {
+      const firstIndex = new BN(1_100_000_000_000);
+      const secondIndex = new BN(1_200_000_000_000);
+
+      // Set a claim window
+      prepSetClaimWindow(admin);
+      await earn.methods
+        .setClaimWindow(new BN(claimCooldown.toString()))
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      // Start a cycle, then let it time out and start the next one in the same update
+      await propagateIndex(firstIndex);
+      warp(claimCooldown, true);
+      await propagateIndex(secondIndex);
+      await expectGlobalState(getGlobalAccount(), {
+        index: secondIndex,
+        claimComplete: false,
+      });
+
+      // Record the cycle that timed out
+      const { claimCycleAccount } = prepRecordClaimCycle(nonAdmin, firstIndex);
+      await earn.methods
+        .recordClaimCycle()
+        .accounts({ ...accounts })
+        .signers([nonAdmin])
+        .rpc();
+
+      // Verify the final state of the cycle was recorded
+      const cycle = await earn.account.claimCycle.fetch(claimCycleAccount);
+      expect(cycle.index.toString()).toEqual(firstIndex.toString());
+      expect(cycle.timedOut).toBe(true);
+    });
+
+    // given the completed cycle was already recorded
+    // it reverts with a NoCompletedCycle error
+    test('Cycle already recorded - reverts', async () => {
+      // Start a cycle and complete it, which records it
+      await propagateIndex(new BN(1_100_000_000_000));
+      await completeClaims();
+
+      // Setup the instruction
+      prepRecordClaimCycle(nonAdmin, new BN(1_100_000_000_000));
+
+      // Attempt to record the cycle again
+      await expectAnchorError(
+        earn.methods
+          .recordClaimCycle()
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'NoCompletedCycle',
+      );
+    });
+
+    // given the next cycle times out before the previous one was recorded
+    // the next cycle stays open until the previous one is recorded
+    test('Unrecorded cycle - holds back the next cycle', async () => {
+      const firstIndex = new BN(1_100_000_000_000);
+      const secondIndex = new BN(1_200_000_000_000);
+      const thirdIndex = new BN(1_300_000_000_000);
+
+      // Set a claim window
+      prepSetClaimWindow(admin);
+      await earn.methods
+        .setClaimWindow(new BN(claimCooldown.toString()))
+        .accounts({ ...accounts })
+        .signers([admin])
+        .rpc();
+
+      // Start a cycle, then let it time out and start the next one in the same update
+      await propagateIndex(firstIndex);
+      warp(claimCooldown, true);
+      await propagateIndex(secondIndex);
+
+      // Let the second cycle time out without recording the first one
+      warp(claimCooldown, true);
+      await propagateIndex(thirdIndex);
+
+      // The second cycle is kept open so that the first one is not overwritten
+      await expectGlobalState(getGlobalAccount(), {
+        index: secondIndex,
+        claimComplete: false,
+      });
+
+      // Record the first cycle
+      prepRecordClaimCycle(nonAdmin, firstIndex);
+      await earn.methods
+        .recordClaimCycle()
+        .accounts({ ...accounts })
+        .signers([nonAdmin])
+        .rpc();
+
+      // Expire the blockhash so the same txn can be sent again (in a new block)
+      svm.expireBlockhash();
+
+      // The next index update closes the second cycle and starts the third one
+      await propagateIndex(thirdIndex);
+      await expectGlobalState(getGlobalAccount(), {
+        index: thirdIndex,
+        claimComplete: false,
+      });
+
+      // The second cycle can now be recorded
+      const { claimCycleAccount } = prepRecordClaimCycle(nonAdmin, secondIndex);
+      await earn.methods
+        .recordClaimCycle()
+        .accounts({ ...accounts })
+        .signers([nonAdmin])
+        .rpc();
+
+      const cycle = await earn.account.claimCycle.fetch(claimCycleAccount);
+      expect(cycle.index.toString()).toEqual(secondIndex.toString());
+      expect(cycle.timedOut).toBe(true);
+    });
+  });
+
+  describe('commit_claims_root unit tests', () => {
+    // test cases
+    // [X] given the earn authority does not sign the transaction
+    //   [X] it reverts with a NotAuthorized error
+    // [X] given the earn authority signs the transaction
+    //   [X] given there is no active claim cycle
+    //     [X] it reverts with a NoActiveClaim error
+    //   [X] given the claims root is empty
+    //     [X] it reverts with an InvalidParam error
+    //   [X] given the total balance exceeds the max supply
+    //     [X] it reverts with an ExceedsMaxSupply error
+    //   [X] given a root was already committed for the cycle
+    //     [X] it reverts
+    //   [X] otherwise
+    //     [X] the claim distribution is created for the current index
+
+    const newIndex = new BN(1_100_000_000_000);
+
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+
+      // Warp past the initial cooldown period
+      warp(claimCooldown, true);
+
+      // Propagate a new index to start a new claim cycle
+      await propagateIndex(newIndex);
+    });
+
+    // given the earn authority does not sign the transaction
+    // it reverts with a NotAuthorized error
+    test('Earn authority does not sign - reverts', async () => {
+      // Setup the instruction
+      prepCommitClaimsRoot(nonAdmin, newIndex);
+
+      // Attempt to commit a root with a non-earn authority
+      await expectAnchorError(
+        earn.methods
+          .commitClaimsRoot(Array(32).fill(1), new BN(10_000_000))
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'NotAuthorized',
+      );
+    });
+
+    // given there is no active claim cycle
+    // it reverts with a NoActiveClaim error
+    test('No active claim cycle - reverts', async () => {
+      // Complete the active claim cycle
+      await completeClaims();
+
+      // Setup the instruction
+      prepCommitClaimsRoot(earnAuthority, newIndex);
+
+      // Attempt to commit a root when the cycle is complete
+      await expectAnchorError(
+        earn.methods
+          .commitClaimsRoot(Array(32).fill(1), new BN(10_000_000))
+          .accounts({ ...accounts })
+          .signers([earnAuthority])
+          .rpc(),
+        'NoActiveClaim',
+      );
+    });
+
+    // given the claims root is empty
+    // it reverts with an InvalidParam error
+    test('Empty claims root - reverts', async () => {
+      // Setup the instruction
+      prepCommitClaimsRoot(earnAuthority, newIndex);
+
+      // Attempt to commit the zero root
+      await expectAnchorError(
+        earn.methods
+          .commitClaimsRoot(ZERO_WORD, new BN(10_000_000))
+          .accounts({ ...accounts })
+          .signers([earnAuthority])
+          .rpc(),
+        'InvalidParam',
+      );
+    });
+
+    // given the total balance exceeds the max supply
+    // it reverts with an ExceedsMaxSupply error
+    test('Total balance exceeds max supply - reverts', async () => {
+      // Setup the instruction
+      prepCommitClaimsRoot(earnAuthority, newIndex);
+
+      // Attempt to commit more balances than the supply
+      await expectAnchorError(
+        earn.methods
+          .commitClaimsRoot(Array(32).fill(1), initialSupply.add(new BN(1)))
+          .accounts({ ...accounts })
+          .signers([earnAuthority])
+          .rpc(),
+        'ExceedsMaxSupply',
+      );
+    });
+
+    // given a root was already committed for the cycle
+    // it reverts
+    test('Root already committed - reverts', async () => {
+      // Commit a root for the cycle
+      await commitClaimsRoot(newIndex, Array(32).fill(1), new BN(10_000_000));
+
+      // Expire the blockhash so a new transaction can be sent
+      svm.expireBlockhash();
+
+      // Setup the instruction
+      prepCommitClaimsRoot(earnAuthority, newIndex);
+
+      // Attempt to commit another root without revoking the first one
+      await expectSystemError(
+        earn.methods
+          .commitClaimsRoot(Array(32).fill(2), new BN(10_000_000))
+          .accounts({ ...accounts })
+          .signers([earnAuthority])
+          .rpc(),
+      );
+    });
+
+    // given the inputs are correct
+    // the claim distribution is created for the current index
+    test('Commit claims root - success', async () => {
+      const claimsRoot = Array(32).fill(1);
+
+      // Commit the root
+      const { claimDistribution } = await commitClaimsRoot(newIndex, claimsRoot, new BN(10_000_000));
+
+      // Verify the distribution state
+      const state = await earn.account.claimDistribution.fetch(claimDistribution);
+      expect(state.index.toString()).toEqual(newIndex.toString());
+      expect(state.claimsRoot).toEqual(claimsRoot);
+      expect(state.totalBalance.toString()).toEqual('10000000');
+      expect(state.claimedBalance.toString()).toEqual('0');
+      expect(state.claims.toString()).toEqual('0');
+    });
   });
 
-  describe('initialize unit tests', () => {
+  describe('revoke_claims_root unit tests', () => {
     // test cases
-    //   [X] given the admin signs the transaction
-    //      [X] the global account is created
-    //      [X] the admin is set to the signer
-    //      [X] the mint is set correctly
-    //      [X] the earn authority is set correctly
-    //      [X] the initial index is set correctly
-    //      [X] the claim cooldown is set correctly
+    // [X] given the earn authority does not sign the transaction
+    //   [X] it reverts with a NotAuthorized error
+    // [X] given the earn authority signs the transaction
+    //   [X] the claim distribution is closed
+    //   [X] a new root can be committed for the cycle
 
-    // given the admin signs the transaction
-    // the global account is created and configured correctly
-    test('Admin can initialize earn program', async () => {
-      // Setup the instruction call
-      const { globalAccount } = prepInitialize(admin, mint.publicKey);
+    const newIndex = new BN(1_100_000_000_000);
 
-      // Create and send the transaction
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+
+      // Warp past the initial cooldown period
+      warp(claimCooldown, true);
+
+      // Propagate a new index to start a new claim cycle
+      await propagateIndex(newIndex);
+
+      // Commit a root for the cycle
+      await commitClaimsRoot(newIndex, Array(32).fill(1), new BN(10_000_000));
+    });
+
+    // given the earn authority does not sign the transaction
+    // it reverts with a NotAuthorized error
+    test('Earn authority does not sign - reverts', async () => {
+      // Setup the instruction
+      prepRevokeClaimsRoot(nonAdmin, newIndex);
+
+      // Attempt to revoke the root with a non-earn authority
+      await expectAnchorError(
+        earn.methods
+          .revokeClaimsRoot()
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'NotAuthorized',
+      );
+    });
+
+    // given the earn authority signs the transaction
+    // the claim distribution is closed and a new root can be committed
+    test('Revoke claims root - success', async () => {
+      // Setup the instruction
+      const { claimDistribution } = prepRevokeClaimsRoot(earnAuthority, newIndex);
+
+      // Revoke the root
       await earn.methods
-        .initialize(earnAuthority.publicKey, initialIndex, claimCooldown)
+        .revokeClaimsRoot()
         .accounts({ ...accounts })
-        .signers([admin])
+        .signers([earnAuthority])
         .rpc();
 
-      // Verify the global state including zero-initialized Merkle roots
-      await expectGlobalState(globalAccount, {
-        admin: admin.publicKey,
-        mint: mint.publicKey,
-        earnAuthority: earnAuthority.publicKey,
-        index: initialIndex,
-        claimCooldown,
-        claimComplete: true,
-        earnerMerkleRoot: ZERO_WORD,
-      });
+      // Verify the distribution was closed
+      expectAccountEmpty(claimDistribution);
+
+      // Commit the corrected root
+      const claimsRoot = Array(32).fill(2);
+      await commitClaimsRoot(newIndex, claimsRoot, new BN(20_000_000));
+
+      // Verify the new distribution state
+      const state = await earn.account.claimDistribution.fetch(claimDistribution);
+      expect(state.claimsRoot).toEqual(claimsRoot);
+      expect(state.totalBalance.toString()).toEqual('20000000');
     });
   });
 
-  describe('set_earn_authority unit tests', () => {
+  describe('claim_with_proof unit tests', () => {
     // test cases
-    //   [X] given the admin signs the transaction
-    //      [X] the earn authority is updated
-    //   [X] given a non-admin signs the transaction
-    //      [X] the transaction reverts with a not authorized error
+    // [X] given the proof does not match the committed root
+    //   [X] it reverts with an InvalidProof error
+    // [X] given the claimed balances exceed the committed total
+    //   [X] it reverts with an ExceedsCommittedBalance error
+    // [X] given the earner already claimed for the cycle
+    //   [X] it reverts with an AlreadyClaimed error
+    // [X] otherwise
+    //   [X] the correct amount is minted to the earner's token account
+    //   [X] the claimed balance is tracked on the distribution
+
+    const newIndex = new BN(1_100_000_000_000);
+    const snapshotBalance = new BN(10_000_000);
 
     beforeEach(async () => {
       // Initialize the program
       await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+
+      // Populate the earner merkle tree with the initial earners
+      earnerMerkleTree = new MerkleTree([admin.publicKey, earnerOne.publicKey, earnerTwo.publicKey]);
+
+      // Warp past the initial cooldown period
+      warp(claimCooldown, true);
+
+      // Propagate the earner merkle root so we can add earners
+      await propagateIndex(initialIndex, earnerMerkleTree.getRoot());
+
+      // Add earner one as a registrar earner
+      const { proof: earnerOneProof } = earnerMerkleTree.getInclusionProof(earnerOne.publicKey);
+      await addRegistrarEarner(earnerOne.publicKey, earnerOneProof);
+
+      // Send earner one 10 tokens so they have a positive balance
+      await mintM(earnerOne.publicKey, snapshotBalance);
+
+      // Update the index so there is outstanding yield
+      await propagateIndex(newIndex);
     });
 
-    test('Admin can set new earn authority', async () => {
-      // Setup new earn authority
-      const newEarnAuthority = new Keypair();
+    // given the proof does not match the committed root
+    // it reverts with an InvalidProof error
+    test('Invalid proof - reverts', async () => {
+      // Commit a root over a different balance
+      const earnerATA = await getATA(mint.publicKey, earnerOne.publicKey);
+      await commitClaimsRoot(newIndex, hashClaimLeaf(earnerATA, BigInt(5_000_000)), snapshotBalance);
 
       // Setup the instruction
-      const { globalAccount } = prepSetEarnAuthority(admin);
+      await prepClaimWithProof(newIndex, earnerOne.publicKey);
 
-      // Send the transaction
+      // Attempt to claim with a balance that is not in the root
+      await expectAnchorError(
+        earn.methods
+          .claimWithProof(snapshotBalance, [])
+          .accounts({ ...accounts })
+          .rpc(),
+        'InvalidProof',
+      );
+    });
+
+    // given the claimed balances exceed the committed total
+    // it reverts with an ExceedsCommittedBalance error
+    test('Claimed balance exceeds committed total - reverts', async () => {
+      // Commit a root whose total is lower than the balance in its leaf
+      const earnerATA = await getATA(mint.publicKey, earnerOne.publicKey);
+      await commitClaimsRoot(newIndex, hashClaimLeaf(earnerATA, BigInt(snapshotBalance.toString())), new BN(1));
+
+      // Setup the instruction
+      await prepClaimWithProof(newIndex, earnerOne.publicKey);
+
+      // Attempt to claim more than was committed
+      await expectAnchorError(
+        earn.methods
+          .claimWithProof(snapshotBalance, [])
+          .accounts({ ...accounts })
+          .rpc(),
+        'ExceedsCommittedBalance',
+      );
+    });
+
+    // given the earner already claimed for the cycle
+    // it reverts with an AlreadyClaimed error
+    test('Earner already claimed - reverts', async () => {
+      // Commit a root that covers the balance twice
+      const earnerATA = await getATA(mint.publicKey, earnerOne.publicKey);
+      const claimsRoot = hashClaimLeaf(earnerATA, BigInt(snapshotBalance.toString()));
+      await commitClaimsRoot(newIndex, claimsRoot, snapshotBalance.mul(new BN(2)));
+
+      // Claim once
+      await prepClaimWithProof(newIndex, earnerOne.publicKey);
       await earn.methods
-        .setEarnAuthority(newEarnAuthority.publicKey)
+        .claimWithProof(snapshotBalance, [])
         .accounts({ ...accounts })
-        .signers([admin])
         .rpc();
 
-      // Verify the global state was updated
-      await expectGlobalState(globalAccount, {
-        earnAuthority: newEarnAuthority.publicKey,
+      // Expire the blockhash so the same txn can be sent again (in a new block)
+      svm.expireBlockhash();
+
+      // Attempt to claim again
+      await prepClaimWithProof(newIndex, earnerOne.publicKey);
+      await expectAnchorError(
+        earn.methods
+          .claimWithProof(snapshotBalance, [])
+          .accounts({ ...accounts })
+          .rpc(),
+        'AlreadyClaimed',
+      );
+    });
+
+    // given the inputs are correct
+    // the correct amount is minted to the earner's token account
+    test('Claim with proof - success', async () => {
+      // Commit a root over the earner's balance
+      const earnerATA = await getATA(mint.publicKey, earnerOne.publicKey);
+      await commitClaimsRoot(newIndex, hashClaimLeaf(earnerATA, BigInt(snapshotBalance.toString())), snapshotBalance);
+
+      // Setup the instruction
+      const { claimDistribution, earnerAccount } = await prepClaimWithProof(newIndex, earnerOne.publicKey);
+
+      // Anyone can send the claim
+      await earn.methods
+        .claimWithProof(snapshotBalance, [])
+        .accounts({ ...accounts })
+        .rpc();
+
+      // Verify the rewards were minted and the earner was updated
+      await expectTokenBalance(earnerATA, new BN(11_000_000));
+      await expectEarnerState(earnerAccount, {
+        lastClaimIndex: newIndex,
       });
+
+      // Verify the claim was tracked against the root
+      const state = await earn.account.claimDistribution.fetch(claimDistribution);
+      expect(state.claimedBalance.toString()).toEqual(snapshotBalance.toString());
+      expect(state.claims.toString()).toEqual('1');
     });
+  });
 
-    test('Non-admin cannot set earn authority', async () => {
-      // Attempt to set new earn authority with non-admin
-      const newEarnAuthority = new Keypair();
+  describe('create_balance_checkpoint unit tests', () => {
+    // test cases
+    // [X] given the token account is not an earner
+    //   [X] it reverts with an AccountNotInitialized error
+    // [X] given the checkpoint already exists
+    //   [X] it reverts
+    // [X] given the token account is an earner
+    //   [X] it creates the balance checkpoint at the current index
+    //   [X] it records the current balance as the min and last balance
 
-      prepSetEarnAuthority(nonAdmin);
+    const balance = new BN(10_000_000);
+
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+
+      // Populate the earner merkle tree with the initial earners
+      earnerMerkleTree = new MerkleTree([admin.publicKey, earnerOne.publicKey, earnerTwo.publicKey]);
+
+      // Warp past the initial cooldown period
+      warp(claimCooldown, true);
+
+      // Propagate the earner merkle root so we can add earners
+      await propagateIndex(initialIndex, earnerMerkleTree.getRoot());
+
+      // Add earner one as a registrar earner
+      const { proof: earnerOneProof } = earnerMerkleTree.getInclusionProof(earnerOne.publicKey);
+      await addRegistrarEarner(earnerOne.publicKey, earnerOneProof);
+
+      // Send earner one 10 tokens so they have a positive balance
+      await mintM(earnerOne.publicKey, balance);
+    });
 
+    // given the token account is not an earner
+    // it reverts with an AccountNotInitialized error
+    test('Token account is not an earner - reverts', async () => {
+      // Setup the instruction for a user that was never added as an earner
+      await prepCreateBalanceCheckpoint(nonAdmin, earnerTwo.publicKey);
+
+      // Attempt to create the checkpoint
       await expectAnchorError(
         earn.methods
-          .setEarnAuthority(newEarnAuthority.publicKey)
+          .createBalanceCheckpoint()
           .accounts({ ...accounts })
           .signers([nonAdmin])
           .rpc(),
-        'NotAuthorized',
+        'AccountNotInitialized',
+      );
+    });
+
+    // given the checkpoint already exists
+    // it reverts
+    test('Checkpoint already exists - reverts', async () => {
+      // Create the checkpoint
+      await createBalanceCheckpoint(earnerOne.publicKey);
+
+      // Expire the blockhash so the same txn can be sent again (in a new block)
+      svm.expireBlockhash();
+
+      // Attempt to create it again, which would reset the observed minimum
+      await prepCreateBalanceCheckpoint(nonAdmin, earnerOne.publicKey);
+      await expectSystemError(
+        earn.methods
+          .createBalanceCheckpoint()
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+      );
+    });
+
+    // given the token account is an earner
+    // it creates the balance checkpoint at the current index
+    // it records the current balance as the min and last balance
+    test('Create balance checkpoint - success', async () => {
+      // Create the checkpoint
+      const { balanceCheckpoint } = await createBalanceCheckpoint(earnerOne.publicKey);
+
+      // Verify the checkpoint state
+      const earnerATA = await getATA(mint.publicKey, earnerOne.publicKey);
+      const state = await earn.account.balanceCheckpoint.fetch(balanceCheckpoint);
+      expect(state.userTokenAccount).toEqual(earnerATA);
+      expect(state.startIndex.toString()).toEqual(initialIndex.toString());
+      expect(state.minBalance.toString()).toEqual(balance.toString());
+      expect(state.lastBalance.toString()).toEqual(balance.toString());
+    });
+  });
+
+  describe('checkpoint_balance unit tests', () => {
+    // test cases
+    // [X] given the token account does not match the checkpoint
+    //   [X] it reverts with a ConstraintHasOne error
+    // [X] given the balance decreased since the last observation
+    //   [X] it lowers the min balance
+    // [X] given the balance increased since the last observation
+    //   [X] it keeps the min balance
+    //   [X] it updates the last balance
+
+    const balance = new BN(10_000_000);
+
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+
+      // Populate the earner merkle tree with the initial earners
+      earnerMerkleTree = new MerkleTree([admin.publicKey, earnerOne.publicKey, earnerTwo.publicKey]);
+
+      // Warp past the initial cooldown period
+      warp(claimCooldown, true);
+
+      // Propagate the earner merkle root so we can add earners
+      await propagateIndex(initialIndex, earnerMerkleTree.getRoot());
+
+      // Add earner one as a registrar earner with a checkpoint over 10 tokens
+      const { proof: earnerOneProof } = earnerMerkleTree.getInclusionProof(earnerOne.publicKey);
+      await addRegistrarEarner(earnerOne.publicKey, earnerOneProof);
+      await mintM(earnerOne.publicKey, balance);
+      await createBalanceCheckpoint(earnerOne.publicKey);
+    });
+
+    // given the token account does not match the checkpoint
+    // it reverts with a ConstraintHasOne error
+    test('Token account does not match - reverts', async () => {
+      // Setup the instruction with another token account
+      await prepCheckpointBalance(earnerOne.publicKey);
+      accounts.userTokenAccount = await getATA(mint.publicKey, earnerTwo.publicKey);
+
+      // Attempt to record the balance of the other token account
+      await expectAnchorError(
+        earn.methods
+          .checkpointBalance()
+          .accounts({ ...accounts })
+          .rpc(),
+        'ConstraintHasOne',
       );
     });
+
+    // given the balance decreased since the last observation
+    // it lowers the min balance
+    test('Balance decreased - lowers the min balance', async () => {
+      // Reduce the balance
+      await burnM(earnerOne, new BN(4_000_000));
+
+      // Record the balance
+      await checkpointBalance(earnerOne.publicKey);
+
+      // Verify the checkpoint state
+      const { balanceCheckpoint } = await prepCheckpointBalance(earnerOne.publicKey);
+      const state = await earn.account.balanceCheckpoint.fetch(balanceCheckpoint);
+      expect(state.minBalance.toString()).toEqual('6000000');
+      expect(state.lastBalance.toString()).toEqual('6000000');
+    });
+
+    // given the balance increased since the last observation
+    // it keeps the min balance
+    // it updates the last balance
+    test('Balance increased - keeps the min balance', async () => {
+      // Increase the balance
+      await mintM(earnerOne.publicKey, new BN(5_000_000));
+
+      // Record the balance
+      await checkpointBalance(earnerOne.publicKey);
+
+      // Verify the checkpoint state
+      const { balanceCheckpoint } = await prepCheckpointBalance(earnerOne.publicKey);
+      const state = await earn.account.balanceCheckpoint.fetch(balanceCheckpoint);
+      expect(state.minBalance.toString()).toEqual(balance.toString());
+      expect(state.lastBalance.toString()).toEqual('15000000');
+    });
   });
 
-  describe('set_claim_cooldown unit tests', () => {
+  describe('claim unit tests', () => {
     // test cases
-    // [X] given the admin does not sign the transaction
-    //   [X] it reverts with a NotAuthorized error
-    // [X] given the admin does sign then transaction
-    //   [X] given the new cooldown is greater than 1 week
-    //     [X] it reverts with an InvalidParam error
-    //   [X] given the new cooldown is less than or equal to 1 week
-    //     [X] the claim cooldown is updated
+    // [X] given the checkpoint was created after the earner's last claim
+    //   [X] it reverts with an InvalidCheckpoint error
+    // [X] given the earner already claimed for the cycle
+    //   [X] it reverts with an AlreadyClaimed error
+    // [X] given a lower balance was observed during the period
+    //   [X] the rewards are calculated on the lowest observed balance
+    // [X] otherwise
+    //   [X] the correct amount is minted to the earner's token account
+    //   [X] the checkpoint window is reset to the current index and balance
+
+    const newIndex = new BN(1_100_000_000_000);
+    const balance = new BN(10_000_000);
 
     beforeEach(async () => {
       // Initialize the program
       await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+
+      // Populate the earner merkle tree with the initial earners
+      earnerMerkleTree = new MerkleTree([admin.publicKey, earnerOne.publicKey, earnerTwo.publicKey]);
+
+      // Warp past the initial cooldown period
+      warp(claimCooldown, true);
+
+      // Propagate the earner merkle root so we can add earners
+      await propagateIndex(initialIndex, earnerMerkleTree.getRoot());
+
+      // Add earner one as a registrar earner and send them 10 tokens
+      const { proof: earnerOneProof } = earnerMerkleTree.getInclusionProof(earnerOne.publicKey);
+      await addRegistrarEarner(earnerOne.publicKey, earnerOneProof);
+      await mintM(earnerOne.publicKey, balance);
     });
 
-    test('Admin does not sign transaction - reverts', async () => {
-      // Attempt to set the claim cooldown without the admin signing
-      prepSetClaimCooldown(nonAdmin);
+    // given the checkpoint was created after the earner's last claim
+    // it reverts with an InvalidCheckpoint error
+    test('Checkpoint does not cover the claim period - reverts', async () => {
+      // Update the index before the checkpoint exists
+      await propagateIndex(newIndex);
+
+      // Create the checkpoint at the new index
+      await createBalanceCheckpoint(earnerOne.publicKey);
+
+      // Setup the instruction
+      await prepClaim(earnerOne.publicKey);
 
+      // Attempt to claim for a period the checkpoint did not observe
       await expectAnchorError(
         earn.methods
-          .setClaimCooldown(new BN(1))
+          .claim()
           .accounts({ ...accounts })
-          .signers([nonAdmin])
           .rpc(),
-        'NotAuthorized',
+        'InvalidCheckpoint',
       );
     });
 
-    test('Admin tries to set cooldown to more than  1 week - reverts', async () => {
-      // Attempt to set the claim cooldown to more than 1 week
+    // given the earner already claimed for the cycle
+    // it reverts with an AlreadyClaimed error
+    test('Earner already claimed - reverts', async () => {
+      // Create the checkpoint and update the index
+      await createBalanceCheckpoint(earnerOne.publicKey);
+      await propagateIndex(newIndex);
 
-      const randomCooldown = randomInt(604_801, 2 ** 32);
+      // Claim once
+      await prepClaim(earnerOne.publicKey);
+      await earn.methods
+        .claim()
+        .accounts({ ...accounts })
+        .rpc();
 
-      prepSetClaimCooldown(admin);
+      // Expire the blockhash so the same txn can be sent again (in a new block)
+      svm.expireBlockhash();
 
+      // Attempt to claim again
+      await prepClaim(earnerOne.publicKey);
       await expectAnchorError(
         earn.methods
-          .setClaimCooldown(new BN(randomCooldown))
+          .claim()
           .accounts({ ...accounts })
-          .signers([admin])
           .rpc(),
-        'InvalidParam',
+        'AlreadyClaimed',
       );
     });
 
-    test('Admin sets cooldown to less than or equal to 1 week - success', async () => {
-      // Attempt to set the claim cooldown to less than or equal to 1 week
-      const newCooldown = new BN(randomInt(0, 604_800));
+    // given a lower balance was observed during the period
+    // the rewards are calculated on the lowest observed balance
+    test('Lower balance observed - claims on the min balance', async () => {
+      // Create the checkpoint
+      await createBalanceCheckpoint(earnerOne.publicKey);
 
-      const { globalAccount } = prepSetClaimCooldown(admin);
+      // Move half of the balance out and record it
+      await burnM(earnerOne, new BN(5_000_000));
+      await checkpointBalance(earnerOne.publicKey);
+
+      // Restore the balance before the claim
+      await mintM(earnerOne.publicKey, new BN(5_000_000));
 
+      // Update the index
+      await propagateIndex(newIndex);
+
+      // Claim
+      const { earnerATA } = await prepClaim(earnerOne.publicKey);
       await earn.methods
-        .setClaimCooldown(newCooldown)
+        .claim()
         .accounts({ ...accounts })
-        .signers([admin])
         .rpc();
 
-      // Verify the global state was updated
-      await expectGlobalState(globalAccount, {
-        claimCooldown: newCooldown,
+      // Only the 5 tokens held for the whole period earned yield
+      await expectTokenBalance(earnerATA, new BN(10_500_000));
+    });
+
+    // given the inputs are correct
+    // the correct amount is minted to the earner's token account
+    // the checkpoint window is reset to the current index and balance
+    test('Claim - success', async () => {
+      // Create the checkpoint and update the index
+      await createBalanceCheckpoint(earnerOne.publicKey);
+      await propagateIndex(newIndex);
+
+      // Setup the instruction
+      const { earnerATA, earnerAccount, balanceCheckpoint } = await prepClaim(earnerOne.publicKey);
+
+      // Anyone can send the claim
+      await earn.methods
+        .claim()
+        .accounts({ ...accounts })
+        .rpc();
+
+      // Verify the rewards were minted and the earner was updated
+      await expectTokenBalance(earnerATA, new BN(11_000_000));
+      await expectEarnerState(earnerAccount, {
+        lastClaimIndex: newIndex,
       });
+
+      // Verify a new observation window was opened
+      const state = await earn.account.balanceCheckpoint.fetch(balanceCheckpoint);
+      expect(state.startIndex.toString()).toEqual(newIndex.toString());
+      expect(state.minBalance.toString()).toEqual('11000000');
+      expect(state.lastBalance.toString()).toEqual('11000000');
     });
   });
 
-  describe('propagate_index unit tests', () => {
+  describe('add_registrar_earner unit tests', () => {
     // test cases
-    // [X] given the portal does not sign the transaction
-    //   [X] the transaction fails with a not authorized error
-    // [X] given the portal does sign the transaction
-    //   [X] given the new index is less than the existing index
-    //     [X] given the new earner merkle root is empty
-    //       [X] it is not updated
-    //     [X] given the new earner merkle is not empty
-    //       [X] it is not updated
-    //   [X] given the new index is greater than or eqal to the existing index
-    //     [X] given the new earner merkle root is empty
-    //       [X] it is not updated
-    //     [X] given the new earner merkle is not empty
-    //       [X] it is updated
-    //   [X] given the last claim hasn't been completed
-    //     [X] given the time is within the cooldown period
-    //       [X] given the new index is less than or equal to the existing index
-    //         [X] given current supply is less than or equal to max supply
-    //           [X] nothing is updated
-    //         [X] given current supply is greater than max supply
-    //           [X] max supply is updated to the current supply
-    //       [X] given the new index is greater the existing index
-    //         [X] given current supply is less than or equal to max supply
-    //           [X] nothing is updated
-    //         [X] given current supply is greater than max supply
-    //           [X] max supply is updated to the current supply
-    //     [X] given the time is past the cooldown period
-    //       [X] given the new index is less than or equal to the existing index
-    //         [X] given the current supply is less than or equal to max supply
-    //           [X] nothing is updated
-    //         [X] given the current supply is greater than max supply
-    //           [X] max supply is updated to the current supply
-    //       [X] given the new index is greater the existing index
-    //         [X] given current supply is less than or equal to max supply
-    //           [X] nothing is updated
-    //         [X] given current supply is greater than max supply
-    //           [X] max supply is updated to the current supply
-    //   [X] given the last claim has been completed
-    //     [X] given the time is within the cooldown period
-    //       [X] given the new index is less than or equal to the existing index
-    //         [X] given current supply is greater than max supply
-    //           [X] max supply is updated to the current supply
-    //         [X] given current supply is less than or equal to max supply
-    //           [X] nothing is updated
-    //       [X] given the new index is greater the existing index
-    //         [X] given current supply is less than or equal to max supply
-    //           [X] nothing is updated
-    //         [X] given current supply is greater than max supply
-    //           [X] max supply is updated to the current supply
-    //     [X] given the time is past the cooldown period
-    //       [X] given the new index is less than or equal to the existing index
-    //         [X] given current supply is less than or equal to max supply
-    //           [X] nothing is updated
-    //         [X] given current supply is greater than max supply
-    //           [X] max supply is updated to the current supply
-    //       [X] given the new index is greater the existing index
-    //         [X] a new claim cycle starts:
-    //           [X] index is updated to the provided value
-    //           [X] timestamp is updated to the current timestamp
-    //           [X] max supply is set to the current supply
-    //           [X] distributed is set to 0
-    //           [X] max yield is updated
-    //           [X] claim complete is set to false
+    // [X] given the earner tree is empty and the user is the zero value pubkey
+    //   [X] it reverts with an InvalidParam error
+    // [X] given the user token account is for the wrong token mint
+    //   [X] it reverts with a constraint token mint error
+    // [X] given the user token account is not for the user pubkey
+    //   [X] it reverts with a constraint token owner error
+    // [X] given the user token account is not initialized
+    //   [X] it reverts with an account not initialized error
+    // [X] given the user token account has a mutable owner
+    //   [X] it reverts with an immutable owner error
+    // [X] given the earner account is already initialized
+    //   [X] it reverts with an account already initialized error
+    // [X] given all the accounts are valid
+    //   [X] given the merkle proof for the user in the earner list is invalid
+    //     [X] it reverts with an InvalidProof error
+    //   [X] given the merkle proof for the user in the earner list is valid
+    //     [X] it creates the earner account
+    //     [X] it sets the earner account's user to the provided pubkey
+    //     [X] it sets the earner account's user_token_account to the provided token account
+    //     [X] it sets the earner account's last_claim_index to the current index
 
     beforeEach(async () => {
       // Initialize the program
@@ -798,708 +4986,638 @@ describe('Earn unit tests', () => {
       // Populate the earner merkle tree with the initial earners
       earnerMerkleTree = new MerkleTree([admin.publicKey, earnerOne.publicKey, earnerTwo.publicKey]);
 
-      // Propagate the earner and earn manager merkle roots so they are set to non-zero values
-      await propagateIndex(initialIndex, earnerMerkleTree.getRoot());
-
-      // Warp past the initial cooldown period
+      // Warp time forward past the initial cooldown period
       warp(claimCooldown, true);
-    });
-
-    // given the portal does not sign the transaction
-    // the transaction fails with an address constraint error
-    test('Non-portal cannot update index - reverts', async () => {
-      const newIndex = new BN(1_100_000_000_000);
-      const newEarnerRoot = Array(32).fill(1);
-
-      prepPropagateIndex(nonAdmin);
-
-      await expectAnchorError(
-        earn.methods
-          .propagateIndex(newIndex, newEarnerRoot)
-          .accounts({ ...accounts })
-          .signers([nonAdmin])
-          .rpc(),
-        'NotAuthorized',
-      );
-    });
-
-    // given new index is less than the existing index
-    // given new earner merkle root is empty
-    // nothing is updated
-    test('new index < existing index, new earner root empty', async () => {
-      // Try to propagate a new index with a lower value
-      const lowerIndex = new BN(999_999_999_999);
-      const emptyEarnerRoot = ZERO_WORD;
-      const emptyEarnManagerRoot = ZERO_WORD;
-
-      const { globalAccount } = await propagateIndex(lowerIndex, emptyEarnerRoot);
 
-      // Check the state
-      await expectGlobalState(globalAccount, {
-        earnerMerkleRoot: earnerMerkleTree.getRoot(),
-      });
-    });
-
-    // given new index is less than the existing index
-    // given new earner merkle root is not empty
-    // nothing is updated
-    test('new index < existing index, new earner root not empty', async () => {
-      // Try to propagate a new index with a lower value
-      const lowerIndex = new BN(999_999_999_999);
-      const newEarnerRoot = new Array(32).fill(1);
-
-      const { globalAccount } = await propagateIndex(lowerIndex, newEarnerRoot);
-
-      // Check the state
-      await expectGlobalState(globalAccount, {
-        earnerMerkleRoot: earnerMerkleTree.getRoot(),
-      });
+      // Propagate a new index to start a new claim cycle and set the merkle roots
+      await propagateIndex(new BN(1_100_000_000_000), earnerMerkleTree.getRoot());
     });
 
-    // given new index is greater than or equal to the existing index
-    // given new earner merkle root is empty
-    // nothing is updated
-    test('new index >= existing index, new earner root empty', async () => {
-      // Try to propagate a new index with a higher value
-      const randomIncrement = randomInt(0, 2 ** 32);
-      const higherIndex = initialIndex.add(new BN(randomIncrement));
-      const emptyEarnerRoot = ZERO_WORD;
+    test('Earner tree is empty and user is zero value - reverts', async () => {
+      // Remove all earners from the merkle tree
+      earnerMerkleTree = new MerkleTree([]);
 
-      const { globalAccount } = await propagateIndex(higherIndex, emptyEarnerRoot);
+      // Propagate the new merkle root
+      await propagateIndex(new BN(1_100_000_000_000), earnerMerkleTree.getRoot());
 
-      // Check the state
-      await expectGlobalState(globalAccount, {
-        earnerMerkleRoot: earnerMerkleTree.getRoot(),
-      });
-    });
+      // Get the ATA for the zero value pubkey
+      const zeroATA = await getATA(mint.publicKey, PublicKey.default);
 
-    // given new index is greater than or equal to the existing index
-    // given new earner merkle root is not empty
-    // earner merkle root is updated
-    test('new index >= existing index, new earner root not empty', async () => {
-      // Try to propagate a new index with a higher value
-      const randomIncrement = randomInt(0, 2 ** 32);
-      const higherIndex = initialIndex.add(new BN(randomIncrement));
-      const newEarnerRoot = new Array(32).fill(1);
+      // Get the inclusion proof for the zero value pubkey in the earner merkle tree
+      const { proof } = earnerMerkleTree.getInclusionProof(PublicKey.default);
 
-      const { globalAccount } = await propagateIndex(higherIndex, newEarnerRoot);
+      // Setup the instruction
+      prepAddRegistrarEarner(nonAdmin, zeroATA);
 
-      // Check the state
-      await expectGlobalState(globalAccount, {
-        earnerMerkleRoot: newEarnerRoot,
-      });
+      // Attempt to add earner with empty tree and zero value pubkey
+      await expectAnchorError(
+        earn.methods
+          .addRegistrarEarner(PublicKey.default, proof)
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'InvalidParam',
+      );
     });
 
-    // given new index <= existing index
-    // given the last claim hasn't been completed
-    // given the time is within the cooldown period
-    // given current supply is less than or equal to max supply
-    // nothing is updated
-    test('new index <= existing index, claim not complete, within cooldown period, supply <= max supply', async () => {
-      // Update the index initially so the claim is not complete and the time is within the cooldown period
-      const startIndex = new BN(1_100_000_000_000);
-      const startTimestamp = new BN(svm.getClock().unixTimestamp.toString());
-      const { globalAccount } = await propagateIndex(startIndex);
+    test('Earner tree is empty and user is zero value - reverts', async () => {
+      // Remove all earners from the merkle tree
+      earnerMerkleTree = new MerkleTree([]);
 
-      // Expire the blockhash
-      svm.expireBlockhash();
+      // Propagate the new merkle root
+      await propagateIndex(new BN(1_100_000_000_000), earnerMerkleTree.getRoot());
 
-      // Update the index again with the same or lower value
-      const randomDecrement = randomInt(0, startIndex.toNumber());
-      const newIndex = startIndex.sub(new BN(randomDecrement));
+      // Get the ATA for the zero value pubkey
+      const zeroATA = await getATA(mint.publicKey, PublicKey.default);
 
-      await propagateIndex(newIndex);
+      // Get the inclusion proof for the zero value pubkey in the earner merkle tree
+      const { proof } = earnerMerkleTree.getInclusionProof(PublicKey.default);
 
-      // Confirm that the index, timestamp, and Merkle roots are updated
-      await expectGlobalState(globalAccount, {
-        index: startIndex,
-        timestamp: startTimestamp,
-        maxSupply: initialSupply,
-      });
+      // Setup the instruction
+      prepAddRegistrarEarner(nonAdmin, zeroATA);
+
+      // Attempt to add earner with empty tree and zero value pubkey
+      await expectAnchorError(
+        earn.methods
+          .addRegistrarEarner(PublicKey.default, proof)
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'InvalidParam',
+      );
     });
 
-    // given new index <= existing index
-    // given the last claim hasn't been completed
-    // given the time is within the cooldown period
-    // given current supply is greater than max supply
-    // max supply is updated to the current supply
-    test('new index <= existing index, claim not complete, within cooldown period, supply > max supply', async () => {
-      // Update the index initially so the claim is not complete and the time is within the cooldown period
-      const startIndex = new BN(1_100_000_000_000);
-      const startTimestamp = new BN(svm.getClock().unixTimestamp.toString());
-      const { globalAccount } = await propagateIndex(startIndex);
+    // given the user token account is for the wrong token mint
+    // it reverts with a constraint token mint error
+    test('User token account is for the wrong token mint - reverts', async () => {
+      // Create a new token mint
+      const wrongMint = new Keypair();
+      await createMint(wrongMint, nonAdmin);
 
-      // Mint more tokens to increase supply
-      const additionalSupply = new BN(50_000_000);
-      await mintM(admin.publicKey, additionalSupply);
-      const newSupply = initialSupply.add(additionalSupply);
+      // Get earner one ATA for the wrong mint
+      const wrongATA = await getATA(wrongMint.publicKey, earnerOne.publicKey);
 
-      // Warp forward in time slightly
-      warp(new BN(1), true);
+      // Get the inclusion proof for earner one in the earner merkle tree
+      const { proof } = earnerMerkleTree.getInclusionProof(earnerOne.publicKey);
 
-      // Update the index again with the same or lower value
-      const randomDecrement = randomInt(0, startIndex.toNumber());
-      const newIndex = startIndex.sub(new BN(randomDecrement));
-      await propagateIndex(newIndex);
+      // Setup the instruction
+      prepAddRegistrarEarner(nonAdmin, wrongATA);
 
-      // Check that only max supply was updated
-      await expectGlobalState(globalAccount, {
-        index: startIndex,
-        timestamp: startTimestamp,
-        maxSupply: newSupply,
-      });
+      // Attempt to add earner with wrong token mint
+      await expectAnchorError(
+        earn.methods
+          .addRegistrarEarner(earnerOne.publicKey, proof)
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'ConstraintTokenMint',
+      );
     });
 
-    // given new index <= existing index
-    // given the last claim has been completed
-    // given the time is within the cooldown period
-    // given current supply is greater than max supply
-    // max supply is updated to the current supply
-    test('new index <= existing index, claim complete, within cooldown period, supply > max supply', async () => {
-      // Update the index initially so the claim is not complete and the time is within the cooldown period
-      const startIndex = new BN(1_100_000_000_000);
-      const startTimestamp = new BN(svm.getClock().unixTimestamp.toString());
-      const { globalAccount } = await propagateIndex(startIndex);
+    // given the user token account is not owned by the user pubkey
+    // it reverts with a constraint token owner error
+    test('User token account authority does not match user pubkey - reverts', async () => {
+      // Get the ATA for a random user
+      const randomATA = await getATA(mint.publicKey, nonAdmin.publicKey);
 
-      // Set claim complete
-      await completeClaims();
+      // Get the inclusion proof for earner one in the earner merkle tree
+      const { proof } = earnerMerkleTree.getInclusionProof(earnerOne.publicKey);
 
-      // Mint more tokens
-      const additionalSupply = new BN(50_000_000);
-      await mintM(admin.publicKey, additionalSupply);
-      const newSupply = initialSupply.add(additionalSupply);
+      // Setup the instruction
+      prepAddRegistrarEarner(nonAdmin, randomATA);
 
-      // Warp forward in time slightly
-      warp(new BN(1), true);
+      // Attempt to add earner with wrong token owner
+      await expectAnchorError(
+        earn.methods
+          .addRegistrarEarner(earnerOne.publicKey, proof)
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'ConstraintTokenOwner',
+      );
+    });
 
-      // Update the index again with the same or lower value
-      const randomDecrement = randomInt(0, startIndex.toNumber());
-      const newIndex = startIndex.sub(new BN(randomDecrement));
-      await propagateIndex(newIndex);
+    // given the user token account is not initialized
+    // it reverts with an account not initialized error
+    test('User token account is not initialized - reverts', async () => {
+      // Calculate the ATA for earner one, but don't create it
+      const nonInitATA = getAssociatedTokenAddressSync(
+        mint.publicKey,
+        earnerOne.publicKey,
+        true,
+        TOKEN_2022_PROGRAM_ID,
+        ASSOCIATED_TOKEN_PROGRAM_ID,
+      );
 
-      // Check that only max supply was updated
-      await expectGlobalState(globalAccount, {
-        index: startIndex,
-        timestamp: startTimestamp,
-        maxSupply: newSupply,
-      });
+      // Get the inclusion proof for earner one in the earner merkle tree
+      const { proof } = earnerMerkleTree.getInclusionProof(earnerOne.publicKey);
+
+      // Setup the instruction
+      prepAddRegistrarEarner(nonAdmin, nonInitATA);
+
+      // Attempt to add earner with uninitialized token account
+      await expectAnchorError(
+        earn.methods
+          .addRegistrarEarner(earnerOne.publicKey, proof)
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'AccountNotInitialized',
+      );
     });
 
-    // given new index <= existing index
-    // given the last claim has been completed
-    // given the time is within the cooldown period
-    // given current supply is less than or equal to max supply
-    // nothing is updated
-    test('new index <= existing index, claim complete, within cooldown period, supply <= max supply', async () => {
-      // Update the index initially so the claim is not complete and the time is within the cooldown period
-      const startIndex = new BN(1_100_000_000_000);
-      const startTimestamp = new BN(svm.getClock().unixTimestamp.toString());
-      const { globalAccount } = await propagateIndex(startIndex);
+    // given the earner account is already initialized
+    // it reverts with an account already initialized error
+    test('Earner account already initialized - reverts', async () => {
+      // Get the ATA for earner one
+      const earnerOneATA = await getATA(mint.publicKey, earnerOne.publicKey);
 
-      // Set claim complete
-      await completeClaims();
+      // Get the inclusion proof for earner one in the earner merkle tree
+      const { proof } = earnerMerkleTree.getInclusionProof(earnerOne.publicKey);
 
-      // Warp forward in time slightly
-      warp(new BN(1), true);
+      // Add earner one to the earn manager's list
+      await addRegistrarEarner(earnerOne.publicKey, proof);
 
-      // Update the index again with the same or lower value
-      const randomDecrement = randomInt(0, startIndex.toNumber());
-      const newIndex = startIndex.sub(new BN(randomDecrement));
-      await propagateIndex(newIndex);
+      // Setup the instruction
+      prepAddRegistrarEarner(nonAdmin, earnerOneATA);
 
-      // Check that nothing was updated
-      await expectGlobalState(globalAccount, {
-        index: startIndex,
-        timestamp: startTimestamp,
-        maxSupply: initialSupply,
-      });
+      // Attempt to add earner with already initialized account
+      await expectSystemError(
+        earn.methods
+          .addRegistrarEarner(earnerOne.publicKey, proof)
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+      );
     });
 
-    // given new index <= existing index
-    // given the last claim hasn't been completed
-    // given the time is past the cooldown period
-    // given the current supply is greater than max supply
-    // max supply is updated to the current supply
-    test('new index <= existing index, claim not complete, past cooldown period, supply > max supply', async () => {
-      // Update the index initially so the claim is not complete and the time is within the cooldown period
-      const startIndex = new BN(1_100_000_000_000);
-      const startTimestamp = new BN(svm.getClock().unixTimestamp.toString());
-      const { globalAccount } = await propagateIndex(startIndex);
+    test('User token account has mutable owner - reverts', async () => {
+      const tokenAccountKeypair = Keypair.generate();
+      const tokenAccountLen = getAccountLen([]);
+      const lamports = await provider.connection.getMinimumBalanceForRentExemption(tokenAccountLen);
 
-      // Mint more tokens
-      const additionalSupply = new BN(50_000_000);
-      await mintM(admin.publicKey, additionalSupply);
-      const newSupply = initialSupply.add(additionalSupply);
+      // Create token account without the immutable owner extension
+      const transaction = new Transaction().add(
+        SystemProgram.createAccount({
+          fromPubkey: nonAdmin.publicKey,
+          newAccountPubkey: tokenAccountKeypair.publicKey,
+          space: tokenAccountLen,
+          lamports,
+          programId: TOKEN_2022_PROGRAM_ID,
+        }),
+        createInitializeAccountInstruction(
+          tokenAccountKeypair.publicKey,
+          mint.publicKey,
+          earnerOne.publicKey,
+          TOKEN_2022_PROGRAM_ID,
+        ),
+      );
 
-      // Expire the blockhash
-      svm.expireBlockhash();
+      await provider.send!(transaction, [nonAdmin, tokenAccountKeypair]);
 
-      // Warp forward past the cooldown period
-      warp(claimCooldown.add(new BN(1)), true);
+      // Get the inclusion proof for the earner against the earner merkle tree
+      const { proof } = earnerMerkleTree.getInclusionProof(earnerOne.publicKey);
 
-      // Update the index again with the same or lower value
-      const randomDecrement = randomInt(0, startIndex.toNumber());
-      const newIndex = startIndex.sub(new BN(randomDecrement));
-      await propagateIndex(newIndex);
+      // Setup the instruction
+      prepAddRegistrarEarner(nonAdmin, tokenAccountKeypair.publicKey);
 
-      // Check that only max supply was updated
-      await expectGlobalState(globalAccount, {
-        index: startIndex,
-        timestamp: startTimestamp,
-        maxSupply: newSupply,
-      });
+      await expectAnchorError(
+        earn.methods
+          .addRegistrarEarner(nonEarnerOne.publicKey, proof)
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'MutableOwner',
+      );
     });
 
-    // given new index <= existing index
-    // given the last claim hasn't been completed
-    // given the time is past the cooldown period
-    // given the current supply is less than or equal to max supply
-    // nothing is updated
-    test('new index <= existing index, claim not complete, past cooldown period, supply <= max supply', async () => {
-      // Update the index initially so the claim is not complete and the time is within the cooldown period
-      const startIndex = new BN(1_100_000_000_000);
-      const startTimestamp = new BN(svm.getClock().unixTimestamp.toString());
-      const { globalAccount } = await propagateIndex(startIndex);
-
-      // Expire the blockhash
-      svm.expireBlockhash();
+    // given all the accounts are valid
+    // given the merkle proof for the user in the earner list is invalid
+    // it reverts with an InvalidProof error
+    test('Invalid merkle proof for user inclusion - reverts', async () => {
+      // Get the ATA for non earner one
+      const nonEarnerOneATA = await getATA(mint.publicKey, nonEarnerOne.publicKey);
 
-      // Warp forward past the cooldown period
-      warp(claimCooldown.add(new BN(1)), true);
+      // Get the inclusion proof for earner one in the earner merkle tree
+      const { proof } = earnerMerkleTree.getInclusionProof(earnerOne.publicKey);
 
-      // Update the index again with the same or lower value
-      const randomDecrement = randomInt(0, startIndex.toNumber());
-      const newIndex = startIndex.sub(new BN(randomDecrement));
-      await propagateIndex(newIndex);
+      // Setup the instruction
+      prepAddRegistrarEarner(nonAdmin, nonEarnerOneATA);
 
-      // Check that nothing was updated
-      await expectGlobalState(globalAccount, {
-        index: startIndex,
-        timestamp: startTimestamp,
-        maxSupply: initialSupply,
-      });
+      // Attempt to add earner with invalid merkle proof
+      await expectAnchorError(
+        earn.methods
+          .addRegistrarEarner(nonEarnerOne.publicKey, proof)
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'InvalidProof',
+      );
     });
 
-    // given new index > existing index
-    // given the last claim hasn't been completed
-    // given the time is within the cooldown period
-    // given current supply is less than or equal to max supply
-    // nothing is updated
-    test('new index > existing index, claim not complete, within cooldown period, supply <= max supply', async () => {
-      // Update the index initially so the claim is not complete and the time is within the cooldown period
-      const startIndex = new BN(1_100_000_000_000);
-      const startTimestamp = new BN(svm.getClock().unixTimestamp.toString());
-      const { globalAccount } = await propagateIndex(startIndex);
+    // given all the accounts are valid
+    // given the merkle proof for the user in the earner list is valid
+    // it creates the earner account
+    // it sets the earner account's earn_manager to None
+    // it sets the earner account's last_claim_index to the current index
+    test('Add registrar earner - success', async () => {
+      // Get the ATA for earner one
+      const earnerOneATA = await getATA(mint.publicKey, earnerOne.publicKey);
 
-      // Expire the blockhash
-      svm.expireBlockhash();
+      // Get the inclusion proof for earner one in the earner merkle tree
+      const { proof } = earnerMerkleTree.getInclusionProof(earnerOne.publicKey);
 
-      // Warp forward slightly
-      warp(new BN(1), true);
+      // Setup the instruction
+      const { earnerAccount } = prepAddRegistrarEarner(nonAdmin, earnerOneATA);
 
-      // Update the index again with a higher value
-      const randomIncrement = randomInt(1, 2 ** 32);
-      const newIndex = startIndex.add(new BN(randomIncrement));
-      await propagateIndex(newIndex);
+      // Add earner one to the earn manager's list
+      await earn.methods
+        .addRegistrarEarner(earnerOne.publicKey, proof)
+        .accounts({ ...accounts })
+        .signers([nonAdmin])
+        .rpc();
 
-      // Check that nothing was updated
-      await expectGlobalState(globalAccount, {
-        index: startIndex,
-        timestamp: startTimestamp,
-        maxSupply: initialSupply,
+      const currentTime = new BN(svm.getClock().unixTimestamp.toString());
+
+      // Verify the earner account was initialized correctly
+      await expectEarnerState(earnerAccount, {
+        lastClaimIndex: new BN(1_100_000_000_000),
+        lastClaimTimestamp: currentTime,
+        user: earnerOne.publicKey,
+        userTokenAccount: earnerOneATA,
       });
     });
+  });
 
-    // given new index > existing index
-    // given the last claim hasn't been completed
-    // given the time is within the cooldown period
-    // given current supply is greater than max supply
-    // max supply is updated to the current supply
-    test('new index > existing index, claim not complete, within cooldown period, supply > max supply', async () => {
-      // Update the index initially so the claim is not complete and the time is within the cooldown period
-      const startIndex = new BN(1_100_000_000_000);
-      const startTimestamp = new BN(svm.getClock().unixTimestamp.toString());
-      const { globalAccount } = await propagateIndex(startIndex);
+  describe('remove_registrar_earner unit tests', () => {
+    // test cases
+    // [X] given the earner account is not initialized
+    //   [X] it reverts with an account not initialized error
+    // [X] given all the accounts are valid
+    //   [X] given empty merkle proof for user exclusion
+    //     [X] it reverts with an InvalidProof error
+    //   [X] given the merkle proof for user's exclusion from the earner list is invalid
+    //     [X] it reverts with an InvalidProof error
+    //   [X] given the merkle proof for user's exclusion from the earner list is valid
+    //     [X] it closes the earner account and refunds the rent to the signer
 
-      // Mint more tokens
-      const additionalSupply = new BN(50_000_000);
-      await mintM(admin.publicKey, additionalSupply);
-      const newSupply = initialSupply.add(additionalSupply);
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
 
-      // Warp forward slightly
-      warp(new BN(1), true);
+      // Populate the earner merkle tree with the initial earners
+      earnerMerkleTree = new MerkleTree([admin.publicKey, earnerOne.publicKey, earnerTwo.publicKey]);
 
-      // Update the index again with a higher value
-      const randomIncrement = randomInt(1, 2 ** 32);
-      const newIndex = startIndex.add(new BN(randomIncrement));
-      await propagateIndex(newIndex);
+      // Warp time forward past the initial cooldown period
+      warp(claimCooldown, true);
 
-      // Check that only max supply was updated
-      await expectGlobalState(globalAccount, {
-        index: startIndex,
-        timestamp: startTimestamp,
-        maxSupply: newSupply,
-      });
-    });
+      // Propagate a new index to start a new claim cycle and set the merkle roots
+      await propagateIndex(new BN(1_100_000_000_000), earnerMerkleTree.getRoot());
 
-    // given new index > existing index
-    // given the last claim has been completed
-    // given the time is within the cooldown period
-    // given current supply is less than or equal to max supply
-    // nothing is updated
-    test('new index > existing index, claim complete, within cooldown period, supply <= max supply', async () => {
-      // Update the index initially so the claim is not complete and the time is within the cooldown period
-      const startIndex = new BN(1_100_000_000_000);
-      const startTimestamp = new BN(svm.getClock().unixTimestamp.toString());
-      const { globalAccount } = await propagateIndex(startIndex);
+      // Create an earner account for earner one
+      const { proof } = earnerMerkleTree.getInclusionProof(earnerOne.publicKey);
+      await addRegistrarEarner(earnerOne.publicKey, proof);
 
-      // Set claim complete
-      await completeClaims();
+      // Create an earner account for earner two
+      const { proof: proofTwo } = earnerMerkleTree.getInclusionProof(earnerTwo.publicKey);
+      await addRegistrarEarner(earnerTwo.publicKey, proofTwo);
 
-      // Warp forward slightly
-      warp(new BN(1), true);
+      // Remove earner one from the earner merkle tree
+      earnerMerkleTree.removeLeaf(earnerOne.publicKey);
 
-      // Update the index again with a higher value
-      const randomIncrement = randomInt(1, 2 ** 32);
-      const newIndex = startIndex.add(new BN(randomIncrement));
-      await propagateIndex(newIndex);
+      // Update the earner merkle root on the global account
+      const { globalAccount } = await propagateIndex(new BN(1_100_000_000_000), earnerMerkleTree.getRoot());
 
-      // Check that nothing was updated
-      await expectGlobalState(globalAccount, {
-        index: startIndex,
-        timestamp: startTimestamp,
-        maxSupply: initialSupply,
+      // Confirm the global account is updated
+      expectGlobalState(globalAccount, {
+        index: new BN(1_100_000_000_000),
+        earnerMerkleRoot: earnerMerkleTree.getRoot(),
       });
     });
 
-    // given new index > existing index
-    // given the last claim has been completed
-    // given the time is within the cooldown period
-    // given current supply is greater than max supply
-    // max supply is updated to the current supply
-    test('new index > existing index, claim complete, within cooldown period, supply > max supply', async () => {
-      // Update the index initially so the claim is not complete and the time is within the cooldown period
-      const startIndex = new BN(1_100_000_000_000);
-      const startTimestamp = new BN(svm.getClock().unixTimestamp.toString());
-      const { globalAccount } = await propagateIndex(startIndex);
+    // given the earner account is not initialized
+    // it reverts with an account not initialized error
+    test('Earner account is not initialized - reverts', async () => {
+      // Get the ATA for non earner one
+      const nonEarnerOneATA = await getATA(mint.publicKey, nonEarnerOne.publicKey);
 
-      // Set claim complete
-      await completeClaims();
+      // Get the exclusion proof for non earner one against the earner merkle tree
+      const { proofs, neighbors } = earnerMerkleTree.getExclusionProof(nonEarnerOne.publicKey);
 
-      // Mint more tokens
-      const additionalSupply = new BN(50_000_000);
-      await mintM(admin.publicKey, additionalSupply);
-      const newSupply = initialSupply.add(additionalSupply);
+      // Setup the instruction
+      prepRemoveRegistrarEarner(nonAdmin, nonEarnerOneATA);
 
-      // Warp forward slightly
-      warp(new BN(1), true);
+      // Attempt to remove earner with uninitialized account
+      await expectAnchorError(
+        earn.methods
+          .removeRegistrarEarner(proofs, neighbors)
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'AccountNotInitialized',
+      );
+    });
 
-      // Update the index again with a higher value
-      const randomIncrement = randomInt(1, 2 ** 32);
-      const newIndex = startIndex.add(new BN(randomIncrement));
-      await propagateIndex(newIndex);
+    // given all the accounts are valid
+    // given no proofs or neighbors are provided
+    // it reverts with an InvalidProof error
+    test('Empty merkle proof for user exclusion - reverts', async () => {
+      // Get the ATA for earner one
+      const earnerOneATA = await getATA(mint.publicKey, earnerOne.publicKey);
 
-      // Check that only max supply was updated
-      await expectGlobalState(globalAccount, {
-        index: startIndex,
-        timestamp: startTimestamp,
-        maxSupply: newSupply,
-      });
+      // Setup the instruction
+      prepRemoveRegistrarEarner(nonAdmin, earnerOneATA);
+
+      // Attempt to remove earner with invalid merkle proof
+      await expectAnchorError(
+        earn.methods
+          .removeRegistrarEarner([], [])
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'InvalidProof',
+      );
     });
 
-    // given new index > existing index
-    // given the last claim hasn't been completed
-    // given the time is past the cooldown period
-    // given current supply is less than or equal to max supply
-    // nothing is updated
-    test('new index > existing index, claim not complete, past cooldown period, supply <= max supply', async () => {
-      // Update the index initially so the claim is not complete and the time is within the cooldown period
-      const startIndex = new BN(1_100_000_000_000);
-      const startTimestamp = new BN(svm.getClock().unixTimestamp.toString());
-      const { globalAccount } = await propagateIndex(startIndex);
-
-      // Expire the blockhash
-      svm.expireBlockhash();
+    // given all the accounts are valid
+    // given the merkle proof for user's exclusion from the earner list is invalid
+    // it reverts with an InvalidProof error
+    test('Invalid merkle proof for user exclusion - reverts', async () => {
+      // Get the ATA for earner two
+      const earnerTwoATA = await getATA(mint.publicKey, earnerTwo.publicKey);
 
-      // Warp forward past the cooldown period
-      warp(claimCooldown.add(new BN(1)), true);
+      // Get the exclusion proof for earner one against the earner merkle tree
+      const { proofs, neighbors } = earnerMerkleTree.getExclusionProof(earnerOne.publicKey);
 
-      // Update the index again with a higher value
-      const randomIncrement = randomInt(1, 2 ** 32);
-      const newIndex = startIndex.add(new BN(randomIncrement));
-      await propagateIndex(newIndex);
+      // Setup the instruction
+      prepRemoveRegistrarEarner(nonAdmin, earnerTwoATA);
 
-      // Check that nothing was updated
-      await expectGlobalState(globalAccount, {
-        index: startIndex,
-        timestamp: startTimestamp,
-        maxSupply: initialSupply,
-      });
+      // Attempt to remove earner with invalid merkle proof
+      await expectAnchorError(
+        earn.methods
+          .removeRegistrarEarner(proofs, neighbors)
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'InvalidProof',
+      );
     });
 
-    // given new index > existing index
-    // given the last claim hasn't been completed
-    // given the time is past the cooldown period
-    // given current supply is greater than max supply
-    // max supply is updated to the current supply
-    test('new index > existing index, claim not complete, past cooldown period, supply > max supply', async () => {
-      // Update the index initially so the claim is not complete and the time is within the cooldown period
-      const startIndex = new BN(1_100_000_000_000);
-      const startTimestamp = new BN(svm.getClock().unixTimestamp.toString());
-      const { globalAccount } = await propagateIndex(startIndex);
-
-      // Mint more tokens
-      const additionalSupply = new BN(50_000_000);
-      await mintM(admin.publicKey, additionalSupply);
-      const newSupply = initialSupply.add(additionalSupply);
+    // given all the accounts are valid
+    // given the merkle proof for user's exclusion from the earner list is valid
+    // it closes the earner account and refunds the rent to the signer
+    test('Remove registrar earner - success', async () => {
+      // Get the ATA for earner one
+      const earnerOneATA = await getATA(mint.publicKey, earnerOne.publicKey);
 
-      // Expire the blockhash
-      svm.expireBlockhash();
+      // Get the exclusion proof for earner one against the earner merkle tree
+      const { proofs, neighbors } = earnerMerkleTree.getExclusionProof(earnerOne.publicKey);
 
-      // Warp forward past the cooldown period
-      warp(claimCooldown.add(new BN(1)), true);
+      // Setup the instruction
+      const { earnerAccount } = prepRemoveRegistrarEarner(nonAdmin, earnerOneATA);
 
-      // Update the index again with a higher value
-      const randomIncrement = randomInt(1, 2 ** 32);
-      const newIndex = startIndex.add(new BN(randomIncrement));
-      await propagateIndex(newIndex);
+      // Remove earner one from the earn manager's list
+      await earn.methods
+        .removeRegistrarEarner(proofs, neighbors)
+        .accounts({ ...accounts })
+        .signers([nonAdmin])
+        .rpc();
 
-      // Check that only max supply was updated
-      await expectGlobalState(globalAccount, {
-        index: startIndex,
-        timestamp: startTimestamp,
-        maxSupply: newSupply,
-      });
+      // Verify the earner account was closed correctly
+      expectAccountEmpty(earnerAccount);
     });
 
-    // given new index > existing index
-    // given the last claim has been completed
-    // given the time is past the cooldown period
-    // the index is updated to the provided value
-    // max supply is set to the current supply
-    // distributed is set to 0
-    // max yield is updated
-    // claim complete is set to false
-    test('new index > existing index, claim complete, past cooldown period, new cycle starts', async () => {
-      // Update the index initially so the claim is not complete and the time is within the cooldown period
-      const startIndex = new BN(1_100_000_000_000);
-      const { globalAccount } = await propagateIndex(startIndex);
-      const startGlobalState = await earn.account.global.fetch(globalAccount);
-
-      // Set claim complete
-      await completeClaims();
+    test('Remove registrar earner ownership transfered - success', async () => {
+      // Get the ATA for earner two
+      const earnerTwoATA = await getATA(mint.publicKey, earnerTwo.publicKey);
 
-      // Mint more tokens
-      const additionalSupply = new BN(50_000_000);
-      await mintM(admin.publicKey, additionalSupply);
-      const newSupply = initialSupply.add(additionalSupply);
+      // Setup the instruction
+      const { earnerAccount } = prepRemoveRegistrarEarner(nonAdmin, earnerTwoATA);
 
-      // Warp forward past the cooldown period
-      warp(claimCooldown.add(new BN(1)), true);
+      // Modify owner on token account
+      const accountInfo = svm.getAccount(earnerTwoATA)!;
+      accountInfo.data[32] = 0x1;
+      svm.setAccount(earnerTwoATA, accountInfo);
 
-      // Update the index again with a higher value
-      const randomIncrement = randomInt(1, 2 ** 32);
-      const newIndex = startIndex.add(new BN(randomIncrement));
-      await propagateIndex(newIndex);
+      // Token account
+      const account = await getAccount(provider.connection, earnerTwoATA, undefined, TOKEN_2022_PROGRAM_ID);
 
-      // Calculate expected rewards per token and max yield
-      const supplyPlusLeftover = initialSupply.add(startGlobalState.maxYield).sub(startGlobalState.distributed);
+      // Get the exclusion proof for earner two against the earner merkle tree
+      const { proofs, neighbors } = earnerMerkleTree.getExclusionProof(account.owner);
 
-      const maxYield = supplyPlusLeftover
-        .mul(newIndex)
-        .div(startIndex)
-        .sub(supplyPlusLeftover)
-        .add(startGlobalState.maxYield);
+      // Remove earner one from the earn manager's list
+      await earn.methods
+        .removeRegistrarEarner(proofs, neighbors)
+        .accounts({ ...accounts })
+        .signers([nonAdmin])
+        .rpc();
 
-      // Check that a new cycle started with all updates
-      const clock = svm.getClock();
-      await expectGlobalState(globalAccount, {
-        index: newIndex,
-        timestamp: new BN(clock.unixTimestamp.toString()),
-        maxSupply: newSupply,
-        maxYield,
-        distributed: new BN(0),
-        claimComplete: false,
-      });
+      // Verify the earner account was closed correctly
+      expectAccountEmpty(earnerAccount);
     });
   });
 
-  describe('claim_for unit tests', () => {
+  describe('propagate_attested_index unit tests', () => {
     // test cases
-    // [X] given the earn authority does not sign the transaction
-    //   [X] it reverts with an address constraint error
-    // [X] given the earn authority signs the transaction
-    //   [X] given the user token account's earner account is not initialized
-    //     [X] it reverts with an account not initialized error
-    //   [X] given the earner's last claim index is the current index
-    //     [X] it reverts with an AlreadyClaimed error
-    //   [X] given the amonut to be minted causes the total distributed to exceed the max yield
-    //     [X] it reverts with am ExceedsMaxYield error
-    //   [X] otherwise
-    //     [X] the correct amount is minted to the earner's token account
+    // [X] given index attestations are disabled
+    //   [X] it reverts with a NotAuthorized error
+    // [X] given index attestations are enabled
+    //   [X] given the attestation timestamp is in the future
+    //     [X] it reverts with an InvalidAttestation error
+    //   [X] given fewer attesters signed than the threshold
+    //     [X] it reverts with an InsufficientAttestations error
+    //   [X] given a signature is from a key that is not an attester
+    //     [X] it is not counted towards the threshold
+    //   [X] given enough attesters signed
+    //     [X] the index is updated
+    //     [X] the attestation timestamp is recorded
+    //     [X] the same attestation cannot be replayed
+
+    const attesterOne = new Keypair();
+    const attesterTwo = new Keypair();
+    const newIndex = new BN(1_100_000_000_000);
 
     beforeEach(async () => {
       // Initialize the program
       await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
 
-      // Populate the earner merkle tree with the initial earners
-      earnerMerkleTree = new MerkleTree([admin.publicKey, earnerOne.publicKey, earnerTwo.publicKey]);
-
       // Warp past the initial cooldown period
       warp(claimCooldown, true);
-
-      // Propagate the earner and earn manager merkle roots so we can add earners
-      await propagateIndex(initialIndex, earnerMerkleTree.getRoot());
-
-      // Add earner one as a registrar earner
-      const { proof: earnerOneProof } = earnerMerkleTree.getInclusionProof(earnerOne.publicKey);
-      await addRegistrarEarner(earnerOne.publicKey, earnerOneProof);
-
-      // Send earner one 10 tokens so they have a positive balance
-      await mintM(earnerOne.publicKey, new BN(10_000_000));
     });
 
-    // given the earn authority doesn't sign the transaction
-    // it reverts with an address constraint error
-    test('Non-earn authority cannot claim - reverts', async () => {
-      // Setup the instruction
-      await prepClaimFor(nonAdmin, mint.publicKey, earnerOne.publicKey);
+    // given index attestations are disabled
+    // it reverts with a NotAuthorized error
+    test('Attestations disabled - reverts', async () => {
+      const timestamp = new BN(svm.getClock().unixTimestamp.toString());
+
+      prepPropagateAttestedIndex();
 
-      // Attempt to claim with non-earn authority
       await expectAnchorError(
         earn.methods
-          .claimFor(new BN(100_000_000))
+          .propagateAttestedIndex(newIndex, ZERO_WORD, timestamp)
           .accounts({ ...accounts })
-          .signers([nonAdmin])
+          .preInstructions(signAttestation([attesterOne], newIndex, ZERO_WORD, timestamp))
           .rpc(),
         'NotAuthorized',
       );
     });
 
-    // given the earn authority signs the transaction
-    // given the user token account's earner account is not initialized
-    // it reverts with an account not initialized error
-    test('Earner account not initialized - reverts', async () => {
-      // Setup the instruction
-      await prepClaimFor(earnAuthority, mint.publicKey, earnerTwo.publicKey);
+    // given index attestations are enabled
+    // given the attestation timestamp is in the future
+    // it reverts with an InvalidAttestation error
+    test('Attestation from the future - reverts', async () => {
+      await setAttesters([attesterOne.publicKey, attesterTwo.publicKey], 2);
+
+      const timestamp = new BN(svm.getClock().unixTimestamp.toString()).addn(1);
+
+      prepPropagateAttestedIndex();
 
-      // Attempt to claim for non-initialized earner
       await expectAnchorError(
         earn.methods
-          .claimFor(new BN(10_000_000))
+          .propagateAttestedIndex(newIndex, ZERO_WORD, timestamp)
           .accounts({ ...accounts })
-          .signers([earnAuthority])
+          .preInstructions(signAttestation([attesterOne, attesterTwo], newIndex, ZERO_WORD, timestamp))
           .rpc(),
-        'AccountNotInitialized',
+        'InvalidAttestation',
       );
     });
 
-    // given the earn authority signs the transaction
-    // given the earner's last claim index is the current index
-    // it reverts with an AlreadyClaimed error
-    test('Earner already claimed - reverts', async () => {
-      // Setup the instruction
-      await prepClaimFor(earnAuthority, mint.publicKey, earnerOne.publicKey);
+    // given index attestations are enabled
+    // given fewer attesters signed than the threshold
+    // it reverts with an InsufficientAttestations error
+    test('Not enough attestations - reverts', async () => {
+      await setAttesters([attesterOne.publicKey, attesterTwo.publicKey], 2);
+
+      const timestamp = new BN(svm.getClock().unixTimestamp.toString());
+
+      prepPropagateAttestedIndex();
 
-      // Attempt to claim, but the earner is already up to date
       await expectAnchorError(
         earn.methods
-          .claimFor(new BN(10_000_000))
+          .propagateAttestedIndex(newIndex, ZERO_WORD, timestamp)
           .accounts({ ...accounts })
-          .signers([earnAuthority])
+          .preInstructions(signAttestation([attesterOne, attesterOne], newIndex, ZERO_WORD, timestamp))
           .rpc(),
-        'AlreadyClaimed',
+        'InsufficientAttestations',
       );
     });
 
-    // given the earn authority signs the transaction
-    // given the amount to be minted causes the total distributed to exceed the max yield
-    // it reverts with an ExceedsMaxYield error
-    test('Exceeds max yield - reverts', async () => {
-      // Update the index so there is outstanding yield
-      await propagateIndex(new BN(1_100_000_000_000));
+    // given index attestations are enabled
+    // given a signature is from a key that is not an attester
+    // it is not counted towards the threshold
+    test('Signature from a non-attester - reverts', async () => {
+      await setAttesters([attesterOne.publicKey, attesterTwo.publicKey], 2);
 
-      // Setup the instruction
-      await prepClaimFor(earnAuthority, mint.publicKey, earnerOne.publicKey);
+      const timestamp = new BN(svm.getClock().unixTimestamp.toString());
+
+      prepPropagateAttestedIndex();
 
-      // Attempt to claim an amount that exceeds the max yield
       await expectAnchorError(
         earn.methods
-          .claimFor(new BN(120_000_001))
+          .propagateAttestedIndex(newIndex, ZERO_WORD, timestamp)
           .accounts({ ...accounts })
-          .signers([earnAuthority])
+          .preInstructions(signAttestation([attesterOne, nonAdmin], newIndex, ZERO_WORD, timestamp))
           .rpc(),
-        'ExceedsMaxYield',
+        'InsufficientAttestations',
       );
     });
 
-    // given the earn authority signs the transaction
-    // given the inputs are correct
-    // the correct amount is minted to the earner's token account
-    test('Claim for - success', async () => {
-      // Update the index so there is outstanding yield
-      await propagateIndex(new BN(1_100_000_000_000));
+    // given index attestations are enabled
+    // given enough attesters signed
+    // the index is updated and the attestation cannot be replayed
+    test('Propagate attested index - success', async () => {
+      await setAttesters([attesterOne.publicKey, attesterTwo.publicKey], 2);
 
-      // Setup the instruction
-      const { earnerAccount, earnerATA } = await prepClaimFor(earnAuthority, mint.publicKey, earnerOne.publicKey);
+      const timestamp = new BN(svm.getClock().unixTimestamp.toString());
+      const signatures = signAttestation([attesterOne, attesterTwo], newIndex, ZERO_WORD, timestamp);
 
-      // Verify the starting values
-      await expectTokenBalance(earnerATA, new BN(10_000_000));
-      expectEarnerState(earnerAccount, {
-        lastClaimIndex: initialIndex,
-      });
+      const { globalAccount } = prepPropagateAttestedIndex();
 
-      // Claim for the earner
       await earn.methods
-        .claimFor(new BN(10_000_000))
+        .propagateAttestedIndex(newIndex, ZERO_WORD, timestamp)
         .accounts({ ...accounts })
-        .signers([earnAuthority])
+        .preInstructions(signatures)
         .rpc();
 
-      const currentTime = new BN(svm.getClock().unixTimestamp.toString());
+      // Verify the index was updated and the attestation recorded
+      const state = await earn.account.global.fetch(globalAccount);
+      expect(state.index.toString()).toEqual(newIndex.toString());
+      expect(state.lastAttestationTimestamp.toString()).toEqual(timestamp.toString());
 
-      // Verify the user token account was minted the correct amount
-      // and the last claim index was updated
-      await expectTokenBalance(earnerATA, new BN(11_000_000));
-      expectEarnerState(earnerAccount, {
-        lastClaimIndex: new BN(1_100_000_000_000),
-        lastClaimTimestamp: currentTime,
-      });
+      // Replaying the attestation reverts
+      warp(new BN(1), true);
+      svm.expireBlockhash();
+
+      await expectAnchorError(
+        earn.methods
+          .propagateAttestedIndex(newIndex, ZERO_WORD, timestamp)
+          .accounts({ ...accounts })
+          .preInstructions(signatures)
+          .rpc(),
+        'InvalidAttestation',
+      );
     });
   });
 
-  describe('complete_claims unit tests', () => {
+  describe('claim_for_batch unit tests', () => {
     // test cases
     // [X] given the earn authority does not sign the transaction
-    //   [X] it reverts with an address constraint error
+    //   [X] it reverts with a NotAuthorized error
     // [X] given the earn authority signs the transaction
-    //   [X] given the most recent claim is complete
-    //     [X] it reverts with a NoActiveClaim error
-    //   [X] given the most recent claim is not complete
-    //     [X] it sets the claim complete flag to true in the global account
+    //   [X] given the remaining accounts do not match the snapshot balances
+    //     [X] it reverts with an InvalidParam error
+    //   [X] given a token account is not the one receiving the earner's yield
+    //     [X] it reverts with an InvalidAccount error
+    //   [X] given an earner is included twice
+    //     [X] it reverts with an AlreadyClaimed error
+    //   [X] given the inputs are correct
+    //     [X] the correct amounts are minted to each earner's token account
+    //     [X] each earner's last claim index is updated
+
+    let earnerOneATA: PublicKey;
+    let earnerTwoATA: PublicKey;
 
     beforeEach(async () => {
       // Initialize the program
       await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
 
+      // Populate the earner merkle tree with the initial earners
+      earnerMerkleTree = new MerkleTree([admin.publicKey, earnerOne.publicKey, earnerTwo.publicKey]);
+
       // Warp past the initial cooldown period
       warp(claimCooldown, true);
 
-      // Propagate a new index to start a new claim cycle
+      // Propagate the earner merkle root so we can add earners
+      await propagateIndex(initialIndex, earnerMerkleTree.getRoot());
+
+      // Add earner one and earner two as registrar earners
+      const { proof: earnerOneProof } = earnerMerkleTree.getInclusionProof(earnerOne.publicKey);
+      await addRegistrarEarner(earnerOne.publicKey, earnerOneProof);
+
+      const { proof: earnerTwoProof } = earnerMerkleTree.getInclusionProof(earnerTwo.publicKey);
+      await addRegistrarEarner(earnerTwo.publicKey, earnerTwoProof);
+
+      // Send both earners 10 tokens so they have a positive balance
+      await mintM(earnerOne.publicKey, new BN(10_000_000));
+      await mintM(earnerTwo.publicKey, new BN(10_000_000));
+
+      earnerOneATA = await getATA(mint.publicKey, earnerOne.publicKey);
+      earnerTwoATA = await getATA(mint.publicKey, earnerTwo.publicKey);
+
+      // Update the index so there is outstanding yield
       await propagateIndex(new BN(1_100_000_000_000));
     });
 
     // given the earn authority does not sign the transaction
-    // it reverts with an address constraint error
-    test('Earn authority does not sign - reverts', async () => {
-      // Setup the instruction
-      prepCompleteClaims(nonAdmin);
+    // it reverts with a NotAuthorized error
+    test('Non-earn authority cannot claim - reverts', async () => {
+      prepClaimForBatch(nonAdmin);
 
-      // Attempt to complete claim with non-earn authority
       await expectAnchorError(
         earn.methods
-          .completeClaims()
+          .claimForBatch([new BN(10_000_000)])
           .accounts({ ...accounts })
+          .remainingAccounts(asRemainingAccounts([getEarnerAccount(earnerOneATA), earnerOneATA]))
           .signers([nonAdmin])
           .rpc(),
         'NotAuthorized',
@@ -1507,508 +5625,655 @@ describe('Earn unit tests', () => {
     });
 
     // given the earn authority signs the transaction
-    // given the most recent claim is complete
-    // it reverts with a NoActiveClaim error
-    test('Claim already complete - reverts', async () => {
-      // Complete the active claim
-      await completeClaims();
+    // given the remaining accounts do not match the snapshot balances
+    // it reverts with an InvalidParam error
+    test('Remaining accounts do not match the balances - reverts', async () => {
+      prepClaimForBatch(earnAuthority);
 
-      // Expire the blockhash so the same txn can be sent again (in a new block)
-      svm.expireBlockhash();
+      await expectAnchorError(
+        earn.methods
+          .claimForBatch([new BN(10_000_000), new BN(10_000_000)])
+          .accounts({ ...accounts })
+          .remainingAccounts(asRemainingAccounts([getEarnerAccount(earnerOneATA), earnerOneATA]))
+          .signers([earnAuthority])
+          .rpc(),
+        'InvalidParam',
+      );
+    });
 
-      // Setup the instruction
-      prepCompleteClaims(earnAuthority);
+    // given the earn authority signs the transaction
+    // given a token account is not the one receiving the earner's yield
+    // it reverts with an InvalidAccount error
+    test('Wrong recipient token account - reverts', async () => {
+      prepClaimForBatch(earnAuthority);
 
-      // Attempt to complete claim when already complete
       await expectAnchorError(
         earn.methods
-          .completeClaims()
+          .claimForBatch([new BN(10_000_000)])
           .accounts({ ...accounts })
+          .remainingAccounts(asRemainingAccounts([getEarnerAccount(earnerOneATA), earnerTwoATA]))
           .signers([earnAuthority])
           .rpc(),
-        'NoActiveClaim',
+        'InvalidAccount',
       );
     });
 
     // given the earn authority signs the transaction
-    // given the most recent claim is not complete
-    // it sets the claim complete flag to true in the global account
-    test('Complete claims - success', async () => {
-      // Setup the instruction
-      const { globalAccount } = prepCompleteClaims(earnAuthority);
+    // given an earner is included twice
+    // it reverts with an AlreadyClaimed error
+    test('Duplicate earner - reverts', async () => {
+      prepClaimForBatch(earnAuthority);
+
+      const earnerOneAccount = getEarnerAccount(earnerOneATA);
+
+      await expectAnchorError(
+        earn.methods
+          .claimForBatch([new BN(10_000_000), new BN(10_000_000)])
+          .accounts({ ...accounts })
+          .remainingAccounts(asRemainingAccounts([earnerOneAccount, earnerOneATA, earnerOneAccount, earnerOneATA]))
+          .signers([earnAuthority])
+          .rpc(),
+        'AlreadyClaimed',
+      );
+    });
+
+    // given the earn authority signs the transaction
+    // given the inputs are correct
+    // the correct amounts are minted to each earner's token account
+    test('Claim for batch - success', async () => {
+      prepClaimForBatch(earnAuthority);
+
+      const earnerOneAccount = getEarnerAccount(earnerOneATA);
+      const earnerTwoAccount = getEarnerAccount(earnerTwoATA);
 
-      // Complete the claim
       await earn.methods
-        .completeClaims()
+        .claimForBatch([new BN(10_000_000), new BN(10_000_000)])
         .accounts({ ...accounts })
+        .remainingAccounts(asRemainingAccounts([earnerOneAccount, earnerOneATA, earnerTwoAccount, earnerTwoATA]))
         .signers([earnAuthority])
         .rpc();
 
-      // Verify the global state was updated
-      await expectGlobalState(globalAccount, {
-        claimComplete: true,
-      });
+      // Verify both earners were minted their yield and are up to date
+      await expectTokenBalance(earnerOneATA, new BN(11_000_000));
+      await expectTokenBalance(earnerTwoATA, new BN(11_000_000));
+      await expectEarnerState(earnerOneAccount, { lastClaimIndex: new BN(1_100_000_000_000) });
+      await expectEarnerState(earnerTwoAccount, { lastClaimIndex: new BN(1_100_000_000_000) });
     });
   });
 
-  describe('add_registrar_earner unit tests', () => {
+  describe('add_registrar_earners unit tests', () => {
     // test cases
-    // [X] given the earner tree is empty and the user is the zero value pubkey
+    // [X] given the remaining accounts do not match the users
     //   [X] it reverts with an InvalidParam error
-    // [X] given the user token account is for the wrong token mint
-    //   [X] it reverts with a constraint token mint error
-    // [X] given the user token account is not for the user pubkey
-    //   [X] it reverts with a constraint token owner error
-    // [X] given the user token account is not initialized
-    //   [X] it reverts with an account not initialized error
-    // [X] given the user token account has a mutable owner
-    //   [X] it reverts with an immutable owner error
-    // [X] given the earner account is already initialized
-    //   [X] it reverts with an account already initialized error
-    // [X] given all the accounts are valid
-    //   [X] given the merkle proof for the user in the earner list is invalid
-    //     [X] it reverts with an InvalidProof error
-    //   [X] given the merkle proof for the user in the earner list is valid
-    //     [X] it creates the earner account
-    //     [X] it sets the earner account's user to the provided pubkey
-    //     [X] it sets the earner account's user_token_account to the provided token account
-    //     [X] it sets the earner account's last_claim_index to the current index
+    // [X] given the users are not sorted
+    //   [X] it reverts with an InvalidProof error
+    // [X] given a user is not in the earner merkle tree
+    //   [X] it reverts with an InvalidProof error
+    // [X] given a token account is not owned by its user
+    //   [X] it reverts with an InvalidAccount error
+    // [X] given all users are in the earner merkle tree
+    //   [X] an earner account is created for each user
+
+    let users: PublicKey[];
+    let userATAs: PublicKey[];
 
     beforeEach(async () => {
       // Initialize the program
       await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
 
-      // Populate the earner merkle tree with the initial earners
-      earnerMerkleTree = new MerkleTree([admin.publicKey, earnerOne.publicKey, earnerTwo.publicKey]);
+      // With two leaves, the multiproof of both is a single hash of the two leaves
+      earnerMerkleTree = new MerkleTree([earnerOne.publicKey, earnerTwo.publicKey]);
 
-      // Warp time forward past the initial cooldown period
+      // Warp past the initial cooldown period and propagate the earner merkle root
       warp(claimCooldown, true);
+      await propagateIndex(initialIndex, earnerMerkleTree.getRoot());
 
-      // Propagate a new index to start a new claim cycle and set the merkle roots
-      await propagateIndex(new BN(1_100_000_000_000), earnerMerkleTree.getRoot());
+      users = sortKeys([earnerOne.publicKey, earnerTwo.publicKey]);
+      userATAs = [await getATA(mint.publicKey, users[0]), await getATA(mint.publicKey, users[1])];
     });
 
-    test('Earner tree is empty and user is zero value - reverts', async () => {
-      // Remove all earners from the merkle tree
-      earnerMerkleTree = new MerkleTree([]);
-
-      // Propagate the new merkle root
-      await propagateIndex(new BN(1_100_000_000_000), earnerMerkleTree.getRoot());
-
-      // Get the ATA for the zero value pubkey
-      const zeroATA = await getATA(mint.publicKey, PublicKey.default);
-
-      // Get the inclusion proof for the zero value pubkey in the earner merkle tree
-      const { proof } = earnerMerkleTree.getInclusionProof(PublicKey.default);
-
-      // Setup the instruction
-      prepAddRegistrarEarner(nonAdmin, zeroATA);
+    // given the remaining accounts do not match the users
+    // it reverts with an InvalidParam error
+    test('Remaining accounts do not match the users - reverts', async () => {
+      prepAddRegistrarEarners(nonAdmin);
 
-      // Attempt to add earner with empty tree and zero value pubkey
       await expectAnchorError(
         earn.methods
-          .addRegistrarEarner(PublicKey.default, proof)
+          .addRegistrarEarners(users, [], [true])
           .accounts({ ...accounts })
+          .remainingAccounts(asRemainingAccounts([userATAs[0], getEarnerAccount(userATAs[0])]))
           .signers([nonAdmin])
           .rpc(),
         'InvalidParam',
       );
     });
 
-    test('Earner tree is empty and user is zero value - reverts', async () => {
-      // Remove all earners from the merkle tree
-      earnerMerkleTree = new MerkleTree([]);
-
-      // Propagate the new merkle root
-      await propagateIndex(new BN(1_100_000_000_000), earnerMerkleTree.getRoot());
-
-      // Get the ATA for the zero value pubkey
-      const zeroATA = await getATA(mint.publicKey, PublicKey.default);
-
-      // Get the inclusion proof for the zero value pubkey in the earner merkle tree
-      const { proof } = earnerMerkleTree.getInclusionProof(PublicKey.default);
-
-      // Setup the instruction
-      prepAddRegistrarEarner(nonAdmin, zeroATA);
+    // given the users are not sorted
+    // it reverts with an InvalidProof error
+    test('Users not sorted - reverts', async () => {
+      prepAddRegistrarEarners(nonAdmin);
 
-      // Attempt to add earner with empty tree and zero value pubkey
       await expectAnchorError(
         earn.methods
-          .addRegistrarEarner(PublicKey.default, proof)
+          .addRegistrarEarners([users[1], users[0]], [], [true])
           .accounts({ ...accounts })
+          .remainingAccounts(
+            asRemainingAccounts([
+              userATAs[1],
+              getEarnerAccount(userATAs[1]),
+              userATAs[0],
+              getEarnerAccount(userATAs[0]),
+            ]),
+          )
           .signers([nonAdmin])
           .rpc(),
-        'InvalidParam',
+        'InvalidProof',
       );
     });
 
-    // given the user token account is for the wrong token mint
-    // it reverts with a constraint token mint error
-    test('User token account is for the wrong token mint - reverts', async () => {
-      // Create a new token mint
-      const wrongMint = new Keypair();
-      await createMint(wrongMint, nonAdmin);
-
-      // Get earner one ATA for the wrong mint
-      const wrongATA = await getATA(wrongMint.publicKey, earnerOne.publicKey);
+    // given a user is not in the earner merkle tree
+    // it reverts with an InvalidProof error
+    test('User not in the earner merkle tree - reverts', async () => {
+      const nonEarnerATA = await getATA(mint.publicKey, nonEarnerOne.publicKey);
 
-      // Get the inclusion proof for earner one in the earner merkle tree
+      // Prove earner one, but pass non earner one in its place
       const { proof } = earnerMerkleTree.getInclusionProof(earnerOne.publicKey);
 
-      // Setup the instruction
-      prepAddRegistrarEarner(nonAdmin, wrongATA);
+      prepAddRegistrarEarners(nonAdmin);
 
-      // Attempt to add earner with wrong token mint
       await expectAnchorError(
         earn.methods
-          .addRegistrarEarner(earnerOne.publicKey, proof)
+          .addRegistrarEarners([nonEarnerOne.publicKey], proof, proof.map(() => false))
           .accounts({ ...accounts })
+          .remainingAccounts(asRemainingAccounts([nonEarnerATA, getEarnerAccount(nonEarnerATA)]))
           .signers([nonAdmin])
           .rpc(),
-        'ConstraintTokenMint',
+        'InvalidProof',
       );
     });
 
-    // given the user token account is not owned by the user pubkey
-    // it reverts with a constraint token owner error
-    test('User token account authority does not match user pubkey - reverts', async () => {
-      // Get the ATA for a random user
-      const randomATA = await getATA(mint.publicKey, nonAdmin.publicKey);
-
-      // Get the inclusion proof for earner one in the earner merkle tree
-      const { proof } = earnerMerkleTree.getInclusionProof(earnerOne.publicKey);
-
-      // Setup the instruction
-      prepAddRegistrarEarner(nonAdmin, randomATA);
+    // given a token account is not owned by its user
+    // it reverts with an InvalidAccount error
+    test('Token account not owned by the user - reverts', async () => {
+      prepAddRegistrarEarners(nonAdmin);
 
-      // Attempt to add earner with wrong token owner
       await expectAnchorError(
         earn.methods
-          .addRegistrarEarner(earnerOne.publicKey, proof)
+          .addRegistrarEarners(users, [], [true])
           .accounts({ ...accounts })
+          .remainingAccounts(
+            asRemainingAccounts([
+              userATAs[1],
+              getEarnerAccount(userATAs[1]),
+              userATAs[0],
+              getEarnerAccount(userATAs[0]),
+            ]),
+          )
           .signers([nonAdmin])
           .rpc(),
-        'ConstraintTokenOwner',
+        'InvalidAccount',
       );
     });
 
-    // given the user token account is not initialized
-    // it reverts with an account not initialized error
-    test('User token account is not initialized - reverts', async () => {
-      // Calculate the ATA for earner one, but don't create it
-      const nonInitATA = getAssociatedTokenAddressSync(
-        mint.publicKey,
-        earnerOne.publicKey,
-        true,
-        TOKEN_2022_PROGRAM_ID,
-        ASSOCIATED_TOKEN_PROGRAM_ID,
-      );
-
-      // Get the inclusion proof for earner one in the earner merkle tree
-      const { proof } = earnerMerkleTree.getInclusionProof(earnerOne.publicKey);
+    // given all users are in the earner merkle tree
+    // an earner account is created for each user
+    test('Add registrar earners - success', async () => {
+      prepAddRegistrarEarners(nonAdmin);
 
-      // Setup the instruction
-      prepAddRegistrarEarner(nonAdmin, nonInitATA);
+      await earn.methods
+        .addRegistrarEarners(users, [], [true])
+        .accounts({ ...accounts })
+        .remainingAccounts(
+          asRemainingAccounts([userATAs[0], getEarnerAccount(userATAs[0]), userATAs[1], getEarnerAccount(userATAs[1])]),
+        )
+        .signers([nonAdmin])
+        .rpc();
 
-      // Attempt to add earner with uninitialized token account
-      await expectAnchorError(
-        earn.methods
-          .addRegistrarEarner(earnerOne.publicKey, proof)
-          .accounts({ ...accounts })
-          .signers([nonAdmin])
-          .rpc(),
-        'AccountNotInitialized',
-      );
+      // Verify the earner accounts were created
+      for (let i = 0; i < users.length; i++) {
+        await expectEarnerState(getEarnerAccount(userATAs[i]), {
+          user: users[i],
+          userTokenAccount: userATAs[i],
+          lastClaimIndex: initialIndex,
+        });
+      }
     });
+  });
 
-    // given the earner account is already initialized
-    // it reverts with an account already initialized error
-    test('Earner account already initialized - reverts', async () => {
-      // Get the ATA for earner one
-      const earnerOneATA = await getATA(mint.publicKey, earnerOne.publicKey);
+  describe('remove_registrar_earners unit tests', () => {
+    // test cases
+    // [X] given no earners are provided
+    //   [X] it reverts with an InvalidParam error
+    // [X] given the rent payer is not the one that paid for an earner account
+    //   [X] it reverts with an InvalidAccount error
+    // [X] given an earner is still in the earner merkle tree
+    //   [X] it reverts with an InvalidProof error
+    // [X] given no earners are in the earner merkle tree
+    //   [X] the earner accounts are closed and the rent is refunded to the payer
 
-      // Get the inclusion proof for earner one in the earner merkle tree
-      const { proof } = earnerMerkleTree.getInclusionProof(earnerOne.publicKey);
+    let earnerOneATA: PublicKey;
+    let earnerTwoATA: PublicKey;
 
-      // Add earner one to the earn manager's list
-      await addRegistrarEarner(earnerOne.publicKey, proof);
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
 
-      // Setup the instruction
-      prepAddRegistrarEarner(nonAdmin, earnerOneATA);
+      // Populate the earner merkle tree with the initial earners
+      earnerMerkleTree = new MerkleTree([earnerOne.publicKey, earnerTwo.publicKey]);
 
-      // Attempt to add earner with already initialized account
-      await expectSystemError(
-        earn.methods
-          .addRegistrarEarner(earnerOne.publicKey, proof)
-          .accounts({ ...accounts })
-          .signers([nonAdmin])
-          .rpc(),
-      );
-    });
+      // Warp past the initial cooldown period and propagate the earner merkle root
+      warp(claimCooldown, true);
+      await propagateIndex(initialIndex, earnerMerkleTree.getRoot());
 
-    test('User token account has mutable owner - reverts', async () => {
-      const tokenAccountKeypair = Keypair.generate();
-      const tokenAccountLen = getAccountLen([]);
-      const lamports = await provider.connection.getMinimumBalanceForRentExemption(tokenAccountLen);
+      // Add earner one and earner two as registrar earners
+      const { proof: earnerOneProof } = earnerMerkleTree.getInclusionProof(earnerOne.publicKey);
+      await addRegistrarEarner(earnerOne.publicKey, earnerOneProof);
 
-      // Create token account without the immutable owner extension
-      const transaction = new Transaction().add(
-        SystemProgram.createAccount({
-          fromPubkey: nonAdmin.publicKey,
-          newAccountPubkey: tokenAccountKeypair.publicKey,
-          space: tokenAccountLen,
-          lamports,
-          programId: TOKEN_2022_PROGRAM_ID,
-        }),
-        createInitializeAccountInstruction(
-          tokenAccountKeypair.publicKey,
-          mint.publicKey,
-          earnerOne.publicKey,
-          TOKEN_2022_PROGRAM_ID,
-        ),
-      );
+      const { proof: earnerTwoProof } = earnerMerkleTree.getInclusionProof(earnerTwo.publicKey);
+      await addRegistrarEarner(earnerTwo.publicKey, earnerTwoProof);
+
+      earnerOneATA = await getATA(mint.publicKey, earnerOne.publicKey);
+      earnerTwoATA = await getATA(mint.publicKey, earnerTwo.publicKey);
+    });
 
-      await provider.send!(transaction, [nonAdmin, tokenAccountKeypair]);
+    // given no earners are provided
+    // it reverts with an InvalidParam error
+    test('No earners - reverts', async () => {
+      prepRemoveRegistrarEarners(nonAdmin);
 
-      // Get the inclusion proof for the earner against the earner merkle tree
-      const { proof } = earnerMerkleTree.getInclusionProof(earnerOne.publicKey);
+      await expectAnchorError(
+        earn.methods
+          .removeRegistrarEarners([], [], [])
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'InvalidParam',
+      );
+    });
 
-      // Setup the instruction
-      prepAddRegistrarEarner(nonAdmin, tokenAccountKeypair.publicKey);
+    // given the rent payer is not the one that paid for an earner account
+    // it reverts with an InvalidAccount error
+    test('Wrong rent payer - reverts', async () => {
+      // Remove both earners from the earner merkle tree
+      earnerMerkleTree = new MerkleTree([]);
+      await propagateIndex(initialIndex, earnerMerkleTree.getRoot());
+
+      prepRemoveRegistrarEarners(nonAdmin);
 
       await expectAnchorError(
         earn.methods
-          .addRegistrarEarner(nonEarnerOne.publicKey, proof)
+          .removeRegistrarEarners([ZERO_WORD], [], [])
           .accounts({ ...accounts })
+          .remainingAccounts(asRemainingAccounts([getEarnerAccount(earnerOneATA), earnerOneATA, admin.publicKey]))
           .signers([nonAdmin])
           .rpc(),
-        'MutableOwner',
+        'InvalidAccount',
       );
     });
 
-    // given all the accounts are valid
-    // given the merkle proof for the user in the earner list is invalid
+    // given an earner is still in the earner merkle tree
     // it reverts with an InvalidProof error
-    test('Invalid merkle proof for user inclusion - reverts', async () => {
-      // Get the ATA for non earner one
-      const nonEarnerOneATA = await getATA(mint.publicKey, nonEarnerOne.publicKey);
-
-      // Get the inclusion proof for earner one in the earner merkle tree
-      const { proof } = earnerMerkleTree.getInclusionProof(earnerOne.publicKey);
+    test('Earner still in the earner merkle tree - reverts', async () => {
+      // Remove earner one from the earner merkle tree, leaving earner two as the only leaf
+      earnerMerkleTree = new MerkleTree([earnerTwo.publicKey]);
+      await propagateIndex(initialIndex, earnerMerkleTree.getRoot());
 
-      // Setup the instruction
-      prepAddRegistrarEarner(nonAdmin, nonEarnerOneATA);
+      prepRemoveRegistrarEarners(nonAdmin);
 
-      // Attempt to add earner with invalid merkle proof
+      // Earner two is the only leaf, so it can't be proven to be excluded
       await expectAnchorError(
         earn.methods
-          .addRegistrarEarner(nonEarnerOne.publicKey, proof)
+          .removeRegistrarEarners([Array.from(earnerTwo.publicKey.toBuffer())], [], [])
           .accounts({ ...accounts })
+          .remainingAccounts(asRemainingAccounts([getEarnerAccount(earnerTwoATA), earnerTwoATA, nonAdmin.publicKey]))
           .signers([nonAdmin])
           .rpc(),
         'InvalidProof',
       );
     });
 
-    // given all the accounts are valid
-    // given the merkle proof for the user in the earner list is valid
-    // it creates the earner account
-    // it sets the earner account's earn_manager to None
-    // it sets the earner account's last_claim_index to the current index
-    test('Add registrar earner - success', async () => {
-      // Get the ATA for earner one
-      const earnerOneATA = await getATA(mint.publicKey, earnerOne.publicKey);
+    // given no earners are in the earner merkle tree
+    // the earner accounts are closed and the rent is refunded to the payer
+    test('Remove registrar earners - success', async () => {
+      // Remove both earners from the earner merkle tree
+      // The only leaf of an empty tree is the zero key, which neighbors any earner
+      earnerMerkleTree = new MerkleTree([]);
+      await propagateIndex(initialIndex, earnerMerkleTree.getRoot());
 
-      // Get the inclusion proof for earner one in the earner merkle tree
-      const { proof } = earnerMerkleTree.getInclusionProof(earnerOne.publicKey);
+      prepRemoveRegistrarEarners(nonAdmin);
 
-      // Setup the instruction
-      const { earnerAccount } = prepAddRegistrarEarner(nonAdmin, earnerOneATA);
+      const earnerOneAccount = getEarnerAccount(earnerOneATA);
+      const earnerTwoAccount = getEarnerAccount(earnerTwoATA);
 
-      // Add earner one to the earn manager's list
       await earn.methods
-        .addRegistrarEarner(earnerOne.publicKey, proof)
+        .removeRegistrarEarners([ZERO_WORD], [], [])
         .accounts({ ...accounts })
+        .remainingAccounts(
+          asRemainingAccounts([
+            earnerOneAccount,
+            earnerOneATA,
+            nonAdmin.publicKey,
+            earnerTwoAccount,
+            earnerTwoATA,
+            nonAdmin.publicKey,
+          ]),
+        )
         .signers([nonAdmin])
         .rpc();
 
-      const currentTime = new BN(svm.getClock().unixTimestamp.toString());
+      // Verify the earner accounts were closed
+      expectAccountEmpty(earnerOneAccount);
+      expectAccountEmpty(earnerTwoAccount);
+    });
+  });
 
-      // Verify the earner account was initialized correctly
+  describe('add_program_earner unit tests', () => {
+    // test cases
+    // [X] given the token account owner is not an approved program earner
+    //   [X] it reverts with a NotAuthorized error
+    // [X] given the token account owner is an approved program earner
+    //   [X] an earner account is created for the token account
+    //   [X] the rent payer is the signer
+
+    const programId = new Keypair().publicKey;
+    const [vault, bump] = PublicKey.findProgramAddressSync([Buffer.from('vault')], programId);
+
+    let vaultATA: PublicKey;
+
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+
+      vaultATA = await getATA(mint.publicKey, vault);
+    });
+
+    // given the token account owner is not an approved program earner
+    // it reverts with a NotAuthorized error
+    test('Owner not approved - reverts', async () => {
+      prepAddProgramEarner(nonAdmin, vaultATA);
+
+      await expectAnchorError(
+        earn.methods
+          .addProgramEarner()
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'NotAuthorized',
+      );
+    });
+
+    // given the token account owner is an approved program earner
+    // an earner account is created for the token account
+    test('Add program earner - success', async () => {
+      await approveProgramEarner(programId, [Buffer.from('vault'), Buffer.from([bump])]);
+
+      const { earnerAccount } = prepAddProgramEarner(nonAdmin, vaultATA);
+
+      await earn.methods
+        .addProgramEarner()
+        .accounts({ ...accounts })
+        .signers([nonAdmin])
+        .rpc();
+
+      // Verify the earner account was created
       await expectEarnerState(earnerAccount, {
-        lastClaimIndex: new BN(1_100_000_000_000),
-        lastClaimTimestamp: currentTime,
-        user: earnerOne.publicKey,
-        userTokenAccount: earnerOneATA,
+        user: vault,
+        userTokenAccount: vaultATA,
+        lastClaimIndex: initialIndex,
       });
+
+      const state = await earn.account.earner.fetch(earnerAccount);
+      expect(state.rentPayer).toEqual(nonAdmin.publicKey);
     });
   });
 
-  describe('remove_registrar_earner unit tests', () => {
+  describe('migrate_earner unit tests', () => {
     // test cases
-    // [X] given the earner account is not initialized
-    //   [X] it reverts with an account not initialized error
-    // [X] given all the accounts are valid
-    //   [X] given empty merkle proof for user exclusion
-    //     [X] it reverts with an InvalidProof error
-    //   [X] given the merkle proof for user's exclusion from the earner list is invalid
-    //     [X] it reverts with an InvalidProof error
-    //   [X] given the merkle proof for user's exclusion from the earner list is valid
-    //     [X] it closes the earner account and refunds the rent to the signer
+    // [X] given the new token account has a mutable owner
+    //   [X] it reverts with a MutableOwner error
+    // [X] given the rent payer is not the one that paid for the earner account
+    //   [X] it reverts with an InvalidAccount error
+    // [X] given the earner signs the transaction with a valid new token account
+    //   [X] a new earner account is created with the accrual state of the old one
+    //   [X] the old earner account is closed and the rent is refunded to its payer
+
+    let earnerOneATA: PublicKey;
 
     beforeEach(async () => {
       // Initialize the program
       await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
 
-      // Populate the earner merkle tree with the initial earners
-      earnerMerkleTree = new MerkleTree([admin.publicKey, earnerOne.publicKey, earnerTwo.publicKey]);
+      // The earner pays for the new earner account
+      svm.airdrop(earnerOne.publicKey, BigInt(LAMPORTS_PER_SOL));
 
-      // Warp time forward past the initial cooldown period
+      // Add earner one as a registrar earner
+      earnerMerkleTree = new MerkleTree([earnerOne.publicKey, earnerTwo.publicKey]);
       warp(claimCooldown, true);
+      await propagateIndex(initialIndex, earnerMerkleTree.getRoot());
 
-      // Propagate a new index to start a new claim cycle and set the merkle roots
-      await propagateIndex(new BN(1_100_000_000_000), earnerMerkleTree.getRoot());
-
-      // Create an earner account for earner one
       const { proof } = earnerMerkleTree.getInclusionProof(earnerOne.publicKey);
       await addRegistrarEarner(earnerOne.publicKey, proof);
 
-      // Create an earner account for earner two
-      const { proof: proofTwo } = earnerMerkleTree.getInclusionProof(earnerTwo.publicKey);
-      await addRegistrarEarner(earnerTwo.publicKey, proofTwo);
-
-      // Remove earner one from the earner merkle tree
-      earnerMerkleTree.removeLeaf(earnerOne.publicKey);
-
-      // Update the earner merkle root on the global account
-      const { globalAccount } = await propagateIndex(new BN(1_100_000_000_000), earnerMerkleTree.getRoot());
-
-      // Confirm the global account is updated
-      expectGlobalState(globalAccount, {
-        index: new BN(1_100_000_000_000),
-        earnerMerkleRoot: earnerMerkleTree.getRoot(),
-      });
+      earnerOneATA = await getATA(mint.publicKey, earnerOne.publicKey);
     });
 
-    // given the earner account is not initialized
-    // it reverts with an account not initialized error
-    test('Earner account is not initialized - reverts', async () => {
-      // Get the ATA for non earner one
-      const nonEarnerOneATA = await getATA(mint.publicKey, nonEarnerOne.publicKey);
-
-      // Get the exclusion proof for non earner one against the earner merkle tree
-      const { proofs, neighbors } = earnerMerkleTree.getExclusionProof(nonEarnerOne.publicKey);
+    // given the new token account has a mutable owner
+    // it reverts with a MutableOwner error
+    test('New token account has a mutable owner - reverts', async () => {
+      const { tokenAccount } = await createTokenAccount(mint.publicKey, earnerOne.publicKey);
 
-      // Setup the instruction
-      prepRemoveRegistrarEarner(nonAdmin, nonEarnerOneATA);
+      prepMigrateEarner(earnerOne, earnerOneATA, tokenAccount, nonAdmin.publicKey);
 
-      // Attempt to remove earner with uninitialized account
       await expectAnchorError(
         earn.methods
-          .removeRegistrarEarner(proofs, neighbors)
+          .migrateEarner()
           .accounts({ ...accounts })
-          .signers([nonAdmin])
+          .signers([earnerOne])
           .rpc(),
-        'AccountNotInitialized',
+        'MutableOwner',
       );
     });
 
-    // given all the accounts are valid
-    // given no proofs or neighbors are provided
-    // it reverts with an InvalidProof error
-    test('Empty merkle proof for user exclusion - reverts', async () => {
-      // Get the ATA for earner one
-      const earnerOneATA = await getATA(mint.publicKey, earnerOne.publicKey);
+    // given the rent payer is not the one that paid for the earner account
+    // it reverts with an InvalidAccount error
+    test('Wrong rent payer - reverts', async () => {
+      const { tokenAccount } = await createImmutableTokenAccount(mint.publicKey, earnerOne.publicKey);
 
-      // Setup the instruction
-      prepRemoveRegistrarEarner(nonAdmin, earnerOneATA);
+      prepMigrateEarner(earnerOne, earnerOneATA, tokenAccount, earnerOne.publicKey);
 
-      // Attempt to remove earner with invalid merkle proof
       await expectAnchorError(
         earn.methods
-          .removeRegistrarEarner([], [])
+          .migrateEarner()
           .accounts({ ...accounts })
-          .signers([nonAdmin])
+          .signers([earnerOne])
           .rpc(),
-        'InvalidProof',
+        'InvalidAccount',
       );
     });
 
-    // given all the accounts are valid
-    // given the merkle proof for user's exclusion from the earner list is invalid
-    // it reverts with an InvalidProof error
-    test('Invalid merkle proof for user exclusion - reverts', async () => {
-      // Get the ATA for earner two
-      const earnerTwoATA = await getATA(mint.publicKey, earnerTwo.publicKey);
+    // given the earner signs the transaction with a valid new token account
+    // the earner account is moved to the new token account
+    test('Migrate earner - success', async () => {
+      const { tokenAccount } = await createImmutableTokenAccount(mint.publicKey, earnerOne.publicKey);
 
-      // Get the exclusion proof for earner one against the earner merkle tree
-      const { proofs, neighbors } = earnerMerkleTree.getExclusionProof(earnerOne.publicKey);
+      const { earnerAccount, newEarnerAccount } = prepMigrateEarner(
+        earnerOne,
+        earnerOneATA,
+        tokenAccount,
+        nonAdmin.publicKey,
+      );
 
-      // Setup the instruction
-      prepRemoveRegistrarEarner(nonAdmin, earnerTwoATA);
+      const old = await earn.account.earner.fetch(earnerAccount);
+
+      await earn.methods
+        .migrateEarner()
+        .accounts({ ...accounts })
+        .signers([earnerOne])
+        .rpc();
+
+      // Verify the new earner account carries over the accrual state
+      await expectEarnerState(newEarnerAccount, {
+        user: earnerOne.publicKey,
+        userTokenAccount: tokenAccount,
+        lastClaimIndex: old.lastClaimIndex,
+        lastClaimTimestamp: old.lastClaimTimestamp,
+      });
+
+      const state = await earn.account.earner.fetch(newEarnerAccount);
+      expect(state.rentPayer).toEqual(earnerOne.publicKey);
+
+      // Verify the old earner account was closed
+      expectAccountEmpty(earnerAccount);
+    });
+  });
+
+  describe('set_recipient unit tests', () => {
+    // test cases
+    // [X] given the earner does not sign the transaction
+    //   [X] it reverts with a NotAuthorized error
+    // [X] given the earner signs the transaction
+    //   [X] given a recipient token account is provided
+    //     [X] the recipient is set
+    //     [X] it emits a RecipientSet event
+    //   [X] given no recipient token account is provided
+    //     [X] the recipient is cleared
+
+    let recipientATA: PublicKey;
+
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+
+      // Add earner one as a registrar earner
+      earnerMerkleTree = new MerkleTree([earnerOne.publicKey, earnerTwo.publicKey]);
+      warp(claimCooldown, true);
+      await propagateIndex(initialIndex, earnerMerkleTree.getRoot());
+
+      const { proof } = earnerMerkleTree.getInclusionProof(earnerOne.publicKey);
+      await addRegistrarEarner(earnerOne.publicKey, proof);
+
+      recipientATA = await getATA(mint.publicKey, yieldRecipient.publicKey);
+    });
+
+    // given the earner does not sign the transaction
+    // it reverts with a NotAuthorized error
+    test('Earner does not sign transaction - reverts', async () => {
+      await prepSetRecipient(nonAdmin, earnerOne.publicKey, recipientATA);
 
-      // Attempt to remove earner with invalid merkle proof
       await expectAnchorError(
         earn.methods
-          .removeRegistrarEarner(proofs, neighbors)
+          .setRecipient()
           .accounts({ ...accounts })
           .signers([nonAdmin])
           .rpc(),
-        'InvalidProof',
+        'NotAuthorized',
       );
     });
 
-    // given all the accounts are valid
-    // given the merkle proof for user's exclusion from the earner list is valid
-    // it closes the earner account and refunds the rent to the signer
-    test('Remove registrar earner - success', async () => {
-      // Get the ATA for earner one
-      const earnerOneATA = await getATA(mint.publicKey, earnerOne.publicKey);
+    // given the earner signs the transaction
+    // the recipient is set, and cleared when no recipient is provided
+    test('Set recipient - success', async () => {
+      const { earnerAccount } = await prepSetRecipient(earnerOne, earnerOne.publicKey, recipientATA);
 
-      // Get the exclusion proof for earner one against the earner merkle tree
-      const { proofs, neighbors } = earnerMerkleTree.getExclusionProof(earnerOne.publicKey);
+      await earn.methods
+        .setRecipient()
+        .accounts({ ...accounts })
+        .signers([earnerOne])
+        .rpc();
 
-      // Setup the instruction
-      const { earnerAccount } = prepRemoveRegistrarEarner(nonAdmin, earnerOneATA);
+      // Verify the recipient was set
+      let state = await earn.account.earner.fetch(earnerAccount);
+      expect(state.recipientTokenAccount).toEqual(recipientATA);
+
+      await prepSetRecipient(earnerOne, earnerOne.publicKey, null);
 
-      // Remove earner one from the earn manager's list
       await earn.methods
-        .removeRegistrarEarner(proofs, neighbors)
+        .setRecipient()
         .accounts({ ...accounts })
-        .signers([nonAdmin])
+        .signers([earnerOne])
         .rpc();
 
-      // Verify the earner account was closed correctly
-      expectAccountEmpty(earnerAccount);
+      // Verify the recipient was cleared
+      state = await earn.account.earner.fetch(earnerAccount);
+      expect(state.recipientTokenAccount).toBeNull();
     });
 
-    test('Remove registrar earner ownership transfered - success', async () => {
-      // Get the ATA for earner two
-      const earnerTwoATA = await getATA(mint.publicKey, earnerTwo.publicKey);
+    // given a recipient token account is provided
+    // it emits a RecipientSet event
+    test('Set recipient - emits event', async () => {
+      await prepSetRecipient(earnerOne, earnerOne.publicKey, recipientATA);
 
-      // Setup the instruction
-      const { earnerAccount } = prepRemoveRegistrarEarner(nonAdmin, earnerTwoATA);
+      const { events } = await earn.methods
+        .setRecipient()
+        .accounts({ ...accounts })
+        .signers([earnerOne])
+        .simulate();
+
+      // Verify the event was emitted with the new recipient
+      const earnerATA = await getATA(mint.publicKey, earnerOne.publicKey);
+      expect(events.length).toEqual(1);
+      expect(events[0].name).toEqual('RecipientSet');
+      expect(events[0].data.userTokenAccount).toEqual(earnerATA);
+      expect(events[0].data.recipientTokenAccount).toEqual(recipientATA);
+    });
+  });
 
-      // Modify owner on token account
-      const accountInfo = svm.getAccount(earnerTwoATA)!;
-      accountInfo.data[32] = 0x1;
-      svm.setAccount(earnerTwoATA, accountInfo);
+  describe('migrate_earner_account unit tests', () => {
+    // test cases
+    // [X] given the earner account is on the current version
+    //   [X] it reverts with an InvalidVersion error
+    // [X] given the earner account is on an older version
+    //   [X] the earner account is upgraded to the current version
 
-      // Token account
-      const account = await getAccount(provider.connection, earnerTwoATA, undefined, TOKEN_2022_PROGRAM_ID);
+    let earnerAccount: PublicKey;
 
-      // Get the exclusion proof for earner two against the earner merkle tree
-      const { proofs, neighbors } = earnerMerkleTree.getExclusionProof(account.owner);
+    beforeEach(async () => {
+      // Initialize the program
+      await initialize(mint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+
+      // Add earner one as a registrar earner
+      earnerMerkleTree = new MerkleTree([earnerOne.publicKey, earnerTwo.publicKey]);
+      warp(claimCooldown, true);
+      await propagateIndex(initialIndex, earnerMerkleTree.getRoot());
+
+      const { proof } = earnerMerkleTree.getInclusionProof(earnerOne.publicKey);
+      await addRegistrarEarner(earnerOne.publicKey, proof);
+
+      earnerAccount = getEarnerAccount(await getATA(mint.publicKey, earnerOne.publicKey));
+    });
+
+    // given the earner account is on the current version
+    // it reverts with an InvalidVersion error
+    test('Already on the current version - reverts', async () => {
+      prepMigrateEarnerAccount(nonAdmin, earnerAccount);
+
+      await expectAnchorError(
+        earn.methods
+          .migrateEarnerAccount()
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc(),
+        'InvalidVersion',
+      );
+    });
+
+    // given the earner account is on an older version
+    // the earner account is upgraded to the current version
+    test('Migrate earner account - success', async () => {
+      await setAccountVersion(earnerAccount, 'Earner', 0);
+
+      prepMigrateEarnerAccount(nonAdmin, earnerAccount);
 
-      // Remove earner one from the earn manager's list
       await earn.methods
-        .removeRegistrarEarner(proofs, neighbors)
+        .migrateEarnerAccount()
         .accounts({ ...accounts })
         .signers([nonAdmin])
         .rpc();
 
-      // Verify the earner account was closed correctly
-      expectAccountEmpty(earnerAccount);
+      // Verify the account was upgraded and its state kept
+      const state = await earn.account.earner.fetch(earnerAccount);
+      expect(state.version).toEqual(1);
+      expect(state.user).toEqual(earnerOne.publicKey);
     });
   });
 });
@@ -11,6 +11,7 @@ import {
   createAssociatedTokenAccountInstruction,
   createCloseAccountInstruction,
   getAccount,
+  getMint,
   getMintLen,
   getMinimumBalanceForRentExemptMultisig,
   getAssociatedTokenAddressSync,
@@ -19,6 +20,9 @@ import {
   createMintToCheckedInstruction,
   getAccountLen,
   createInitializeImmutableOwnerInstruction,
+  createInitializePermanentDelegateInstruction,
+  createInitializeScaledUiAmountConfigInstruction,
+  getScaledUiAmountConfig,
   ExtensionType,
 } from '@solana/spl-token';
 import { randomInt } from 'crypto';
@@ -93,6 +97,12 @@ interface ExtGlobal {
   bump?: number;
   mVaultBump?: number;
   extMintAuthorityBump?: number;
+  yieldRecipient?: PublicKey;
+  excessBufferBps?: BN;
+  earners?: BN;
+  claimedEarners?: BN;
+  legacyEarners?: BN;
+  claimsTimeout?: BN;
 }
 
 interface Earner {
@@ -126,19 +136,28 @@ const getEarnTokenAuthority = () => {
 };
 
 const getExtGlobalAccount = () => {
-  const [globalAccount] = PublicKey.findProgramAddressSync([Buffer.from('global')], extEarn.programId);
+  const [globalAccount] = PublicKey.findProgramAddressSync(
+    [Buffer.from('global'), extMint.publicKey.toBuffer()],
+    extEarn.programId,
+  );
 
   return globalAccount;
 };
 
 const getExtMintAuthority = () => {
-  const [extMintAuthority] = PublicKey.findProgramAddressSync([Buffer.from('mint_authority')], extEarn.programId);
+  const [extMintAuthority] = PublicKey.findProgramAddressSync(
+    [Buffer.from('mint_authority'), extMint.publicKey.toBuffer()],
+    extEarn.programId,
+  );
 
   return extMintAuthority;
 };
 
 const getMVault = () => {
-  const [mVault] = PublicKey.findProgramAddressSync([Buffer.from('m_vault')], extEarn.programId);
+  const [mVault] = PublicKey.findProgramAddressSync(
+    [Buffer.from('m_vault'), extMint.publicKey.toBuffer()],
+    extEarn.programId,
+  );
 
   return mVault;
 };
@@ -154,7 +173,7 @@ const getMEarnerAccount = (tokenAccount: PublicKey) => {
 
 const getExtEarnerAccount = (tokenAccount: PublicKey) => {
   const [earnerAccount] = PublicKey.findProgramAddressSync(
-    [Buffer.from('earner'), tokenAccount.toBuffer()],
+    [Buffer.from('earner'), extMint.publicKey.toBuffer(), tokenAccount.toBuffer()],
     extEarn.programId,
   );
 
@@ -163,7 +182,7 @@ const getExtEarnerAccount = (tokenAccount: PublicKey) => {
 
 const getEarnManagerAccount = (earnManager: PublicKey) => {
   const [earnManagerAccount] = PublicKey.findProgramAddressSync(
-    [Buffer.from('earn_manager'), earnManager.toBuffer()],
+    [Buffer.from('earn_manager'), extMint.publicKey.toBuffer(), earnManager.toBuffer()],
     extEarn.programId,
   );
 
@@ -232,6 +251,12 @@ const expectExtGlobalState = async (globalAccount: PublicKey, expected: ExtGloba
   if (expected.bump) expect(state.bump).toEqual(expected.bump);
   if (expected.mVaultBump) expect(state.mVaultBump).toEqual(expected.mVaultBump);
   if (expected.extMintAuthorityBump) expect(state.extMintAuthorityBump).toEqual(expected.extMintAuthorityBump);
+  if (expected.yieldRecipient) expect(state.yieldRecipient).toEqual(expected.yieldRecipient);
+  if (expected.excessBufferBps) expect(state.excessBufferBps.toString()).toEqual(expected.excessBufferBps.toString());
+  if (expected.earners) expect(state.earners.toString()).toEqual(expected.earners.toString());
+  if (expected.claimedEarners) expect(state.claimedEarners.toString()).toEqual(expected.claimedEarners.toString());
+  if (expected.legacyEarners) expect(state.legacyEarners.toString()).toEqual(expected.legacyEarners.toString());
+  if (expected.claimsTimeout) expect(state.claimsTimeout.toString()).toEqual(expected.claimsTimeout.toString());
 };
 
 const expectEarnerState = async (earnerAccount: PublicKey, expected: Earner) => {
@@ -377,6 +402,49 @@ const createMint = async (mint: Keypair, mintAuthority: PublicKey, use2022: bool
   return mint.publicKey;
 };
 
+const createScaledUiAmountMint = async (mint: Keypair, mintAuthority: PublicKey, decimals = 6) => {
+  // Create and initialize a token2022 mint with the scaled ui amount and permanent delegate extensions
+  // The mint authority is also the multiplier authority and the permanent delegate so that the ext program
+  // can update the multiplier and settle earn manager fees
+  const mintLen = getMintLen([ExtensionType.ScaledUiAmountConfig, ExtensionType.PermanentDelegate]);
+  const mintLamports = await provider.connection.getMinimumBalanceForRentExemption(mintLen);
+  const createMintAccount = SystemProgram.createAccount({
+    fromPubkey: admin.publicKey,
+    newAccountPubkey: mint.publicKey,
+    space: mintLen,
+    lamports: mintLamports,
+    programId: TOKEN_2022_PROGRAM_ID,
+  });
+
+  const initializeScaledUiAmount = createInitializeScaledUiAmountConfigInstruction(
+    mint.publicKey,
+    mintAuthority, // multiplier authority
+    1, // multiplier
+    TOKEN_2022_PROGRAM_ID,
+  );
+
+  const initializePermanentDelegate = createInitializePermanentDelegateInstruction(
+    mint.publicKey,
+    mintAuthority, // permanent delegate
+    TOKEN_2022_PROGRAM_ID,
+  );
+
+  const initializeMint = createInitializeMintInstruction(
+    mint.publicKey,
+    decimals, // decimals
+    mintAuthority, // mint authority
+    mintAuthority, // freeze authority
+    TOKEN_2022_PROGRAM_ID,
+  );
+
+  let tx = new Transaction();
+  tx.add(createMintAccount, initializeScaledUiAmount, initializePermanentDelegate, initializeMint);
+
+  await provider.sendAndConfirm!(tx, [admin, mint]);
+
+  return mint.publicKey;
+};
+
 const createMintWithMultisig = async (mint: Keypair, mintAuthority: Keypair) => {
   // Create and initialize multisig mint authority on the token program
   const multisigLen = 355;
@@ -514,13 +582,17 @@ const prepPropagateIndex = (signer: Keypair) => {
   return { globalAccount };
 };
 
-const propagateIndex = async (newIndex: BN, earnerMerkleRoot: number[] = ZERO_WORD) => {
+const propagateIndex = async (
+  newIndex: BN,
+  earnerMerkleRoot: number[] = ZERO_WORD,
+  earnerRootTimestamp: BN = new BN(0),
+) => {
   // Setup the instruction
   const { globalAccount } = prepPropagateIndex(portal);
 
   // Send the instruction
   await earn.methods
-    .propagateIndex(newIndex, earnerMerkleRoot)
+    .propagateIndex(newIndex, earnerMerkleRoot, earnerRootTimestamp)
     .accounts({ ...accounts })
     .signers([portal])
     .rpc();
@@ -571,21 +643,30 @@ const mClaimFor = async (earner: PublicKey, balance?: BN) => {
   return { globalAccount, earnerAccount, earnerATA };
 };
 
-const prepCompleteClaims = (signer: Keypair) => {
+const prepCompleteClaims = async (signer: Keypair) => {
   // Get the global PDA
   const globalAccount = getEarnGlobalAccount();
 
+  // Get the claim cycle PDA of the current index
+  const { index } = await earn.account.global.fetch(globalAccount);
+  const [claimCycleAccount] = PublicKey.findProgramAddressSync(
+    [Buffer.from('claim_cycle'), index.toArrayLike(Buffer, 'le', 8)],
+    earn.programId,
+  );
+
   // Populate accounts
   accounts = {};
   accounts.earnAuthority = signer.publicKey;
   accounts.globalAccount = globalAccount;
+  accounts.claimCycleAccount = claimCycleAccount;
+  accounts.systemProgram = SystemProgram.programId;
 
-  return { globalAccount };
+  return { globalAccount, claimCycleAccount };
 };
 
 const completeClaims = async () => {
   // Setup the instruction
-  prepCompleteClaims(earnAuthority);
+  await prepCompleteClaims(earnAuthority);
 
   // Send the instruction
   await earn.methods
@@ -664,13 +745,13 @@ const prepExtInitialize = (signer: Keypair) => {
   return { globalAccount };
 };
 
-const initializeExt = async (earnAuthority: PublicKey) => {
+const initializeExt = async (earnAuthority: PublicKey, yieldMode: any = { claim: {} }) => {
   // Setup the instruction
   const { globalAccount } = prepExtInitialize(admin);
 
   // Send the transaction
   await extEarn.methods
-    .initialize(earnAuthority)
+    .initialize(earnAuthority, yieldMode)
     .accounts({ ...accounts })
     .signers([admin])
     .rpc();
@@ -756,6 +837,17 @@ const prepSync = (signer: Keypair) => {
   accounts.earnAuthority = signer.publicKey;
   accounts.mEarnGlobalAccount = getEarnGlobalAccount();
   accounts.globalAccount = globalAccount;
+  accounts.extMint = extMint.publicKey;
+  accounts.extMintAuthority = getExtMintAuthority();
+  accounts.mVaultAccount = getMVault();
+  accounts.vaultMTokenAccount = getAssociatedTokenAddressSync(
+    mMint.publicKey,
+    getMVault(),
+    true,
+    TOKEN_2022_PROGRAM_ID,
+    ASSOCIATED_TOKEN_PROGRAM_ID,
+  );
+  accounts.token2022 = TOKEN_2022_PROGRAM_ID;
 
   return { globalAccount };
 };
@@ -772,6 +864,124 @@ const sync = async () => {
     .rpc();
 };
 
+const prepSetYieldRecipient = async (signer: Keypair, yieldRecipientTokenAccount?: PublicKey) => {
+  // Populate the accounts
+  // Use the M token account of the yield recipient by default
+  accounts = {};
+  accounts.admin = signer.publicKey;
+  accounts.globalAccount = getExtGlobalAccount();
+  accounts.yieldRecipient = yieldRecipientTokenAccount ?? (await getATA(mMint.publicKey, yieldRecipient.publicKey));
+
+  return { yieldRecipientTokenAccount: accounts.yieldRecipient };
+};
+
+const setYieldRecipient = async (
+  excessBufferBps: BN,
+  yieldRecipientTokenAccount?: PublicKey,
+  claimsTimeout: BN = new BN(0),
+) => {
+  // Setup the instruction
+  const result = await prepSetYieldRecipient(admin, yieldRecipientTokenAccount);
+
+  // Send the instruction
+  await extEarn.methods
+    .setYieldRecipient(excessBufferBps, claimsTimeout)
+    .accounts({ ...accounts })
+    .signers([admin])
+    .rpc();
+
+  return result;
+};
+
+const getWrapperAccount = (wrapper: PublicKey) => {
+  const [wrapperAccount] = PublicKey.findProgramAddressSync(
+    [Buffer.from('wrapper'), extMint.publicKey.toBuffer(), wrapper.toBuffer()],
+    extEarn.programId,
+  );
+
+  return wrapperAccount;
+};
+
+const prepSetWrapFees = async (signer: Keypair, feeTokenAccount?: PublicKey) => {
+  // Populate the accounts
+  // Collect the fees in the M token account of the yield recipient by default
+  accounts = {};
+  accounts.admin = signer.publicKey;
+  accounts.globalAccount = getExtGlobalAccount();
+  accounts.feeTokenAccount = feeTokenAccount ?? (await getATA(mMint.publicKey, yieldRecipient.publicKey));
+
+  return { feeTokenAccount: accounts.feeTokenAccount };
+};
+
+const setWrapFees = async (wrapFeeBps: BN, unwrapFeeBps: BN, feeTokenAccount?: PublicKey) => {
+  // Setup the instruction
+  const result = await prepSetWrapFees(admin, feeTokenAccount);
+
+  // Send the instruction
+  await extEarn.methods
+    .setWrapFees(wrapFeeBps, unwrapFeeBps)
+    .accounts({ ...accounts })
+    .signers([admin])
+    .rpc();
+
+  return result;
+};
+
+const setAllowlist = async (allowlist: any, wrapperMerkleRoot: number[] = ZERO_WORD) => {
+  // Populate the accounts
+  accounts = {};
+  accounts.admin = admin.publicKey;
+  accounts.globalAccount = getExtGlobalAccount();
+
+  // Send the instruction
+  await extEarn.methods
+    .setAllowlist(allowlist, wrapperMerkleRoot)
+    .accounts({ ...accounts })
+    .signers([admin])
+    .rpc();
+};
+
+const addWrapper = async (wrapper: PublicKey) => {
+  // Populate the accounts
+  const wrapperAccount = getWrapperAccount(wrapper);
+
+  accounts = {};
+  accounts.admin = admin.publicKey;
+  accounts.globalAccount = getExtGlobalAccount();
+  accounts.wrapperAccount = wrapperAccount;
+  accounts.systemProgram = SystemProgram.programId;
+
+  // Send the instruction
+  await extEarn.methods
+    .addWrapper(wrapper)
+    .accounts({ ...accounts })
+    .signers([admin])
+    .rpc();
+
+  return { wrapperAccount };
+};
+
+const prepClaimExcess = async (signer: Keypair) => {
+  const mVault = getMVault();
+  const globalAccount = getExtGlobalAccount();
+  const { yieldRecipient } = await extEarn.account.extGlobal.fetch(globalAccount);
+
+  // Populate accounts
+  accounts = {};
+  accounts.earnAuthority = signer.publicKey;
+  accounts.globalAccount = globalAccount;
+  accounts.mEarnGlobalAccount = getEarnGlobalAccount();
+  accounts.mMint = mMint.publicKey;
+  accounts.extMint = extMint.publicKey;
+  accounts.extMintAuthority = getExtMintAuthority();
+  accounts.mVaultAccount = mVault;
+  accounts.vaultMTokenAccount = await getATA(mMint.publicKey, mVault);
+  accounts.yieldRecipient = yieldRecipient;
+  accounts.token2022 = TOKEN_2022_PROGRAM_ID;
+
+  return { globalAccount, vaultMTokenAccount: accounts.vaultMTokenAccount, yieldRecipientTokenAccount: yieldRecipient };
+};
+
 const prepClaimFor = async (
   signer: Keypair,
   earner: PublicKey,
@@ -829,6 +1039,55 @@ const claimFor = async (earner: PublicKey, earnManager: PublicKey, balance?: BN)
     .rpc();
 };
 
+const prepSettleFee = async (signer: Keypair, earner: PublicKey, earnManager: PublicKey) => {
+  const userTokenAccount = await getATA(extMint.publicKey, earner);
+  const earnManagerTokenAccount = await getATA(extMint.publicKey, earnManager);
+  const earnerAccount = getExtEarnerAccount(userTokenAccount);
+
+  // Populate accounts
+  accounts = {};
+  accounts.earnAuthority = signer.publicKey;
+  accounts.globalAccount = getExtGlobalAccount();
+  accounts.extMint = extMint.publicKey;
+  accounts.extMintAuthority = getExtMintAuthority();
+  accounts.userTokenAccount = userTokenAccount;
+  accounts.earnerAccount = earnerAccount;
+  accounts.earnManagerAccount = getEarnManagerAccount(earnManager);
+  accounts.earnManagerTokenAccount = earnManagerTokenAccount;
+  accounts.token2022 = TOKEN_2022_PROGRAM_ID;
+
+  return { earnerAccount, userTokenAccount, earnManagerTokenAccount };
+};
+
+const prepClaimForBatch = async (signer: Keypair, claims: { earner: PublicKey; earnManager: PublicKey }[]) => {
+  const mVault = getMVault();
+
+  // Populate accounts
+  accounts = {};
+  accounts.earnAuthority = signer.publicKey;
+  accounts.globalAccount = getExtGlobalAccount();
+  accounts.extMint = extMint.publicKey;
+  accounts.extMintAuthority = getExtMintAuthority();
+  accounts.mVaultAccount = mVault;
+  accounts.vaultMTokenAccount = await getATA(mMint.publicKey, mVault);
+  accounts.token2022 = TOKEN_2022_PROGRAM_ID;
+
+  // Each claim takes the earner, its token account, and its earn manager and fee token account
+  const remainingAccounts = [];
+  for (const { earner, earnManager } of claims) {
+    const earnerATA = await getATA(extMint.publicKey, earner);
+
+    remainingAccounts.push(
+      { pubkey: getExtEarnerAccount(earnerATA), isSigner: false, isWritable: true },
+      { pubkey: earnerATA, isSigner: false, isWritable: true },
+      { pubkey: getEarnManagerAccount(earnManager), isSigner: false, isWritable: false },
+      { pubkey: await getATA(extMint.publicKey, earnManager), isSigner: false, isWritable: true },
+    );
+  }
+
+  return { remainingAccounts };
+};
+
 const prepConfigureEarnManager = async (signer: Keypair, earnManager: PublicKey, feeTokenAccount?: PublicKey) => {
   // Get the global PDA
   const globalAccount = getExtGlobalAccount();
@@ -912,6 +1171,7 @@ const prepRemoveEarner = (signer: Keypair, earnManager: PublicKey, earnerATA: Pu
   accounts.userTokenAccount = earnerATA;
   accounts.earnManagerAccount = earnManagerAccount;
   accounts.earnerAccount = earnerAccount;
+  accounts.rentPayer = earnManager;
 
   return { earnManagerAccount, earnerAccount };
 };
@@ -1009,11 +1269,14 @@ const prepWrap = async (
   accounts.mMint = mMint.publicKey;
   accounts.extMint = extMint.publicKey;
   accounts.globalAccount = getExtGlobalAccount();
+  accounts.mEarnGlobalAccount = getEarnGlobalAccount();
   accounts.mVault = mVault;
   accounts.extMintAuthority = getExtMintAuthority();
   accounts.fromMTokenAccount = fromMTokenAccount ?? (await getATA(mMint.publicKey, signer.publicKey));
   accounts.toExtTokenAccount = toExtTokenAccount ?? (await getATA(extMint.publicKey, signer.publicKey));
   accounts.vaultMTokenAccount = vaultMTokenAccount ?? (await getATA(mMint.publicKey, mVault));
+  accounts.wrapperAccount = null;
+  accounts.feeTokenAccount = null;
   accounts.token2022 = TOKEN_2022_PROGRAM_ID;
 
   return {
@@ -1027,9 +1290,9 @@ const wrap = async (user: Keypair, amount: BN) => {
   // Setup the instruction
   const { vaultMTokenAccount, fromMTokenAccount, toExtTokenAccount } = await prepWrap(user);
 
-  // Send the instruction
+  // Send the instruction without a wrapper proof
   await extEarn.methods
-    .wrap(amount)
+    .wrap(amount, null)
     .accounts({ ...accounts })
     .signers([user])
     .rpc();
@@ -1052,11 +1315,14 @@ const prepUnwrap = async (
   accounts.mMint = mMint.publicKey;
   accounts.extMint = extMint.publicKey;
   accounts.globalAccount = getExtGlobalAccount();
+  accounts.mEarnGlobalAccount = getEarnGlobalAccount();
   accounts.mVault = mVault;
   accounts.extMintAuthority = getExtMintAuthority();
   accounts.toMTokenAccount = toMTokenAccount ?? (await getATA(mMint.publicKey, signer.publicKey));
   accounts.fromExtTokenAccount = fromExtTokenAccount ?? (await getATA(extMint.publicKey, signer.publicKey));
   accounts.vaultMTokenAccount = vaultMTokenAccount ?? (await getATA(mMint.publicKey, mVault));
+  accounts.wrapperAccount = null;
+  accounts.feeTokenAccount = null;
   accounts.token2022 = TOKEN_2022_PROGRAM_ID;
 
   return {
@@ -1070,9 +1336,9 @@ const unwrap = async (user: Keypair, amount: BN) => {
   // Setup the instruction
   const { vaultMTokenAccount, toMTokenAccount, fromExtTokenAccount } = await prepUnwrap(user);
 
-  // Send the instruction
+  // Send the instruction without a wrapper proof
   await extEarn.methods
-    .unwrap(amount)
+    .unwrap(amount, null)
     .accounts({ ...accounts })
     .signers([user])
     .rpc();
@@ -1093,84 +1359,359 @@ const prepRemoveOrphanedEarner = (signer: Keypair, earnerATA: PublicKey, earnMan
   // Get the earn manager account
   const earnManagerAccount = getEarnManagerAccount(earnManager);
   accounts.earnManagerAccount = earnManagerAccount;
+  accounts.rentPayer = earnManager;
   accounts.systemProgram = SystemProgram.programId;
 
   return { earnerAccount, earnManagerAccount };
 };
 
-describe('ExtEarn unit tests', () => {
-  let currentTime: () => BN;
+const getExtRolesAccount = () => {
+  const [rolesAccount] = PublicKey.findProgramAddressSync(
+    [Buffer.from('roles'), extMint.publicKey.toBuffer()],
+    extEarn.programId,
+  );
 
-  beforeEach(async () => {
-    // Initialize the SVM instance with all necessary configurations
-    svm = fromWorkspace('../')
-      .withSplPrograms() // Add SPL programs (including token programs)
-      .withBuiltins() // Add builtin programs
-      .withSysvars() // Setup standard sysvars
-      .withPrecompiles() // Add standard precompiles
-      .withBlockhashCheck(true); // Optional: disable blockhash checking for tests
+  return rolesAccount;
+};
 
-    // Create an anchor provider from the liteSVM instance
-    provider = new LiteSVMProvider(svm);
+const prepGrantRole = (signer: Keypair) => {
+  // Cache the roles account
+  const rolesAccount = getExtRolesAccount();
 
-    // Create program instances
-    earn = new Program<Earn>(EARN_IDL, EARN_PROGRAM_ID, provider);
-    extEarn = new Program<ExtEarn>(EXT_EARN_IDL, EXT_EARN_PROGRAM_ID, provider);
+  // Populate accounts for the instruction
+  accounts = {};
+  accounts.admin = signer.publicKey;
+  accounts.globalAccount = getExtGlobalAccount();
+  accounts.rolesAccount = rolesAccount;
+  accounts.systemProgram = SystemProgram.programId;
 
-    // Fund the wallets
-    svm.airdrop(admin.publicKey, BigInt(10 * LAMPORTS_PER_SOL));
-    svm.airdrop(portal.publicKey, BigInt(10 * LAMPORTS_PER_SOL));
-    svm.airdrop(earnAuthority.publicKey, BigInt(10 * LAMPORTS_PER_SOL));
-    svm.airdrop(nonAdmin.publicKey, BigInt(10 * LAMPORTS_PER_SOL));
-    svm.airdrop(earnManagerOne.publicKey, BigInt(10 * LAMPORTS_PER_SOL));
-    svm.airdrop(earnManagerTwo.publicKey, BigInt(10 * LAMPORTS_PER_SOL));
-    svm.airdrop(nonEarnManagerOne.publicKey, BigInt(10 * LAMPORTS_PER_SOL));
+  return { rolesAccount };
+};
 
-    currentTime = () => {
-      return new BN(svm.getClock().unixTimestamp.toString());
-    };
+const grantRole = async (role: any, member: PublicKey) => {
+  // Setup the instruction
+  const { rolesAccount } = prepGrantRole(admin);
 
-    // Create the M token mint
-    await createMintWithMultisig(mMint, mMintAuthority);
+  // Send the instruction
+  await extEarn.methods
+    .grantRole(role, member)
+    .accounts({ ...accounts })
+    .signers([admin])
+    .rpc();
 
-    // Create the Ext token mint
-    await createMint(extMint, getExtMintAuthority());
+  return { rolesAccount };
+};
 
-    // Mint some m tokens to have a non-zero supply
-    await mintM(admin.publicKey, initialSupply);
+const prepRevokeRole = (signer: Keypair) => {
+  // Cache the roles account
+  const rolesAccount = getExtRolesAccount();
 
-    // Initialize the earn program
-    await initializeEarn(mMint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+  // Populate accounts for the instruction
+  accounts = {};
+  accounts.admin = signer.publicKey;
+  accounts.globalAccount = getExtGlobalAccount();
+  accounts.rolesAccount = rolesAccount;
 
-    // Add the m vault as an M earner
-    const mVault = getMVault();
-    const earnerMerkleTree = new MerkleTree([admin.publicKey, mVault]);
+  return { rolesAccount };
+};
 
-    // Propagate the merkle root
-    await propagateIndex(initialIndex, earnerMerkleTree.getRoot());
+const prepSetRemovalBounty = (signer: Keypair) => {
+  // Populate accounts for the instruction
+  accounts = {};
+  accounts.admin = signer.publicKey;
+  accounts.globalAccount = getExtGlobalAccount();
+};
 
-    // Add the earner account for the vault
-    const { proof } = earnerMerkleTree.getInclusionProof(mVault);
-    await addRegistrarEarner(mVault, proof);
-  });
+const prepRemoveWrapper = (signer: Keypair, wrapper: PublicKey) => {
+  // Cache the wrapper account
+  const wrapperAccount = getWrapperAccount(wrapper);
 
-  describe('admin instruction unit tests', () => {
-    describe('initialize unit tests', () => {
-      // test cases
-      // [X] given the m_mint is not owned by the token2022 program
-      //   [X] it reverts with a TokenProgram error
-      // [X] given the ext_mint is not owned by the token2022 program
-      //   [X] it reverts with a TokenProgram error
-      // [X] given the M earn global account does not match the PDA on the earn program
-      //   [X] it reverts with a SeedsConstraint error
-      // [X] given all accounts are correct
-      //   [X] the global account is created
-      //   [X] the admin is set to the signer
-      //   [X] the m_mint is set correctly
-      //   [X] the ext_mint is set correctly
-      //   [X] the m_earn_global_account is set correctly
-      //   [X] the earn authority is set correctly
-      //   [X] the initial index is set correctly
+  // Populate accounts for the instruction
+  accounts = {};
+  accounts.admin = signer.publicKey;
+  accounts.globalAccount = getExtGlobalAccount();
+  accounts.wrapperAccount = wrapperAccount;
+
+  return { wrapperAccount };
+};
+
+const prepPreviewClaim = async (earner: PublicKey) => {
+  const earnerAccount = getExtEarnerAccount(await getATA(extMint.publicKey, earner));
+  const { earnManager } = await extEarn.account.earner.fetch(earnerAccount);
+
+  const earnManagerAccount = getEarnManagerAccount(earnManager);
+  const { feeTokenAccount } = await extEarn.account.earnManager.fetch(earnManagerAccount);
+  const mVault = getMVault();
+
+  // Populate accounts
+  accounts = {};
+  accounts.globalAccount = getExtGlobalAccount();
+  accounts.extMint = extMint.publicKey;
+  accounts.mVaultAccount = mVault;
+  accounts.vaultMTokenAccount = await getATA(mMint.publicKey, mVault);
+  accounts.earnerAccount = earnerAccount;
+  accounts.earnManagerAccount = earnManagerAccount;
+  accounts.earnManagerTokenAccount = feeTokenAccount;
+  accounts.token2022 = TOKEN_2022_PROGRAM_ID;
+
+  return { earnerAccount };
+};
+
+const createImmutableTokenAccount = async (mint: PublicKey, owner: PublicKey) => {
+  const tokenAccount = new Keypair();
+  const tokenAccountLen = getAccountLen([ExtensionType.ImmutableOwner]);
+  const lamports = await provider.connection.getMinimumBalanceForRentExemption(tokenAccountLen);
+
+  // Create a token account that is not the ATA with the immutable owner extension
+  const tx = new Transaction().add(
+    SystemProgram.createAccount({
+      fromPubkey: admin.publicKey,
+      newAccountPubkey: tokenAccount.publicKey,
+      space: tokenAccountLen,
+      lamports,
+      programId: TOKEN_2022_PROGRAM_ID,
+    }),
+    createInitializeImmutableOwnerInstruction(tokenAccount.publicKey, TOKEN_2022_PROGRAM_ID),
+    createInitializeAccountInstruction(tokenAccount.publicKey, mint, owner, TOKEN_2022_PROGRAM_ID),
+  );
+
+  await provider.sendAndConfirm!(tx, [admin, tokenAccount]);
+
+  return { tokenAccount: tokenAccount.publicKey };
+};
+
+const prepMigrateEarner = (signer: Keypair, earnerATA: PublicKey, newTokenAccount: PublicKey, rentPayer: PublicKey) => {
+  // Cache the new earner account
+  const newEarnerAccount = getExtEarnerAccount(newTokenAccount);
+
+  // Populate accounts
+  accounts = {};
+  accounts.user = signer.publicKey;
+  accounts.globalAccount = getExtGlobalAccount();
+  accounts.earnerAccount = getExtEarnerAccount(earnerATA);
+  accounts.newUserTokenAccount = newTokenAccount;
+  accounts.newEarnerAccount = newEarnerAccount;
+  accounts.rentPayer = rentPayer;
+  accounts.systemProgram = SystemProgram.programId;
+
+  return { newEarnerAccount };
+};
+
+// Overwrites fields of an ext account in place, e.g. to emulate an older version
+const setExtAccountState = async (
+  account: PublicKey,
+  accountName: 'ExtGlobal' | 'Earner' | 'EarnManager',
+  fields: Record<string, any>,
+) => {
+  const accountInfo = svm.getAccount(account)!;
+  const state = extEarn.coder.accounts.decode(accountName, Buffer.from(accountInfo.data));
+  const data = await extEarn.coder.accounts.encode(accountName, { ...state, ...fields });
+
+  accountInfo.data.set(data);
+  svm.setAccount(account, accountInfo);
+};
+
+// Moves an ext account to the address it had before accounts were keyed by the ext mint
+const moveToLegacyAddress = async (
+  account: PublicKey,
+  accountName: 'ExtGlobal' | 'Earner' | 'EarnManager',
+  legacySeeds: Buffer[],
+  fields: Record<string, any> = {},
+) => {
+  const [legacyAccount, bump] = PublicKey.findProgramAddressSync(legacySeeds, extEarn.programId);
+
+  const accountInfo = svm.getAccount(account)!;
+  const state = extEarn.coder.accounts.decode(accountName, Buffer.from(accountInfo.data));
+  const data = await extEarn.coder.accounts.encode(accountName, { ...state, bump, ...fields });
+
+  // The encoded data can be shorter than the account, the rest stays zeroed
+  const legacyData = new Uint8Array(accountInfo.data.length);
+  legacyData.set(data);
+
+  svm.setAccount(legacyAccount, { ...accountInfo, data: legacyData });
+  svm.setAccount(account, { ...accountInfo, lamports: 0, data: new Uint8Array(), owner: SystemProgram.programId });
+
+  return { legacyAccount };
+};
+
+const prepMigrateExtGlobal = (signer: Keypair) => {
+  // Populate accounts for the instruction
+  accounts = {};
+  accounts.admin = signer.publicKey;
+  accounts.globalAccount = getExtGlobalAccount();
+  accounts.systemProgram = SystemProgram.programId;
+};
+
+const prepMigrateLegacyGlobal = (signer: Keypair) => {
+  const [legacyGlobalAccount] = PublicKey.findProgramAddressSync([Buffer.from('global')], extEarn.programId);
+  const [legacyMVault] = PublicKey.findProgramAddressSync([Buffer.from('m_vault')], extEarn.programId);
+  const [legacyExtMintAuthority] = PublicKey.findProgramAddressSync([Buffer.from('mint_authority')], extEarn.programId);
+  const legacyVaultMTokenAccount = getAssociatedTokenAddressSync(
+    mMint.publicKey,
+    legacyMVault,
+    true,
+    TOKEN_2022_PROGRAM_ID,
+  );
+  const vaultMTokenAccount = getAssociatedTokenAddressSync(mMint.publicKey, getMVault(), true, TOKEN_2022_PROGRAM_ID);
+
+  // Populate accounts for the instruction
+  accounts = {};
+  accounts.admin = signer.publicKey;
+  accounts.legacyGlobalAccount = legacyGlobalAccount;
+  accounts.globalAccount = getExtGlobalAccount();
+  accounts.mMint = mMint.publicKey;
+  accounts.extMint = extMint.publicKey;
+  accounts.mEarnGlobalAccount = getEarnGlobalAccount();
+  accounts.legacyMVault = legacyMVault;
+  accounts.legacyExtMintAuthority = legacyExtMintAuthority;
+  accounts.mVault = getMVault();
+  accounts.extMintAuthority = getExtMintAuthority();
+  accounts.legacyVaultMTokenAccount = legacyVaultMTokenAccount;
+  accounts.vaultMTokenAccount = vaultMTokenAccount;
+  accounts.legacyVaultEarnerAccount = getMEarnerAccount(legacyVaultMTokenAccount);
+  accounts.vaultEarnerAccount = getMEarnerAccount(vaultMTokenAccount);
+  accounts.legacyRolesAccount = null;
+  accounts.rolesAccount = null;
+  accounts.token2022 = TOKEN_2022_PROGRAM_ID;
+  accounts.systemProgram = SystemProgram.programId;
+
+  return { legacyGlobalAccount, legacyMVault, legacyExtMintAuthority, legacyVaultMTokenAccount, vaultMTokenAccount };
+};
+
+const prepMigrateEarnerAccount = (signer: Keypair, earnerAccount: PublicKey) => {
+  // Populate accounts
+  accounts = {};
+  accounts.payer = signer.publicKey;
+  accounts.earnerAccount = earnerAccount;
+  accounts.systemProgram = SystemProgram.programId;
+};
+
+const prepMigrateEarnManager = (signer: Keypair, earnManagerAccount: PublicKey) => {
+  // Populate accounts
+  accounts = {};
+  accounts.payer = signer.publicKey;
+  accounts.earnManagerAccount = earnManagerAccount;
+  accounts.systemProgram = SystemProgram.programId;
+};
+
+const prepMigrateLegacyEarner = (
+  signer: Keypair,
+  legacyEarnerAccount: PublicKey,
+  earnerATA: PublicKey,
+  rentPayer: PublicKey,
+) => {
+  // Cache the earner account
+  const earnerAccount = getExtEarnerAccount(earnerATA);
+
+  // Populate accounts
+  accounts = {};
+  accounts.signer = signer.publicKey;
+  accounts.globalAccount = getExtGlobalAccount();
+  accounts.legacyEarnerAccount = legacyEarnerAccount;
+  accounts.earnerAccount = earnerAccount;
+  accounts.rentPayer = rentPayer;
+  accounts.systemProgram = SystemProgram.programId;
+
+  return { earnerAccount };
+};
+
+const prepMigrateLegacyEarnManager = (
+  signer: Keypair,
+  legacyEarnManagerAccount: PublicKey,
+  earnManager: PublicKey,
+  rentPayer: PublicKey,
+) => {
+  // Cache the earn manager account
+  const earnManagerAccount = getEarnManagerAccount(earnManager);
+
+  // Populate accounts
+  accounts = {};
+  accounts.signer = signer.publicKey;
+  accounts.globalAccount = getExtGlobalAccount();
+  accounts.legacyEarnManagerAccount = legacyEarnManagerAccount;
+  accounts.earnManagerAccount = earnManagerAccount;
+  accounts.rentPayer = rentPayer;
+  accounts.systemProgram = SystemProgram.programId;
+
+  return { earnManagerAccount };
+};
+
+describe('ExtEarn unit tests', () => {
+  let currentTime: () => BN;
+
+  beforeEach(async () => {
+    // Initialize the SVM instance with all necessary configurations
+    svm = fromWorkspace('../')
+      .withSplPrograms() // Add SPL programs (including token programs)
+      .withBuiltins() // Add builtin programs
+      .withSysvars() // Setup standard sysvars
+      .withPrecompiles() // Add standard precompiles
+      .withBlockhashCheck(true); // Optional: disable blockhash checking for tests
+
+    // Create an anchor provider from the liteSVM instance
+    provider = new LiteSVMProvider(svm);
+
+    // Create program instances
+    earn = new Program<Earn>(EARN_IDL, EARN_PROGRAM_ID, provider);
+    extEarn = new Program<ExtEarn>(EXT_EARN_IDL, EXT_EARN_PROGRAM_ID, provider);
+
+    // Fund the wallets
+    svm.airdrop(admin.publicKey, BigInt(10 * LAMPORTS_PER_SOL));
+    svm.airdrop(portal.publicKey, BigInt(10 * LAMPORTS_PER_SOL));
+    svm.airdrop(earnAuthority.publicKey, BigInt(10 * LAMPORTS_PER_SOL));
+    svm.airdrop(nonAdmin.publicKey, BigInt(10 * LAMPORTS_PER_SOL));
+    svm.airdrop(earnManagerOne.publicKey, BigInt(10 * LAMPORTS_PER_SOL));
+    svm.airdrop(earnManagerTwo.publicKey, BigInt(10 * LAMPORTS_PER_SOL));
+    svm.airdrop(nonEarnManagerOne.publicKey, BigInt(10 * LAMPORTS_PER_SOL));
+
+    currentTime = () => {
+      return new BN(svm.getClock().unixTimestamp.toString());
+    };
+
+    // Create the M token mint
+    await createMintWithMultisig(mMint, mMintAuthority);
+
+    // Create the Ext token mint
+    // It has the scaled ui amount and permanent delegate extensions so that it can also be used in rebasing mode
+    await createScaledUiAmountMint(extMint, getExtMintAuthority());
+
+    // Mint some m tokens to have a non-zero supply
+    await mintM(admin.publicKey, initialSupply);
+
+    // Initialize the earn program
+    await initializeEarn(mMint.publicKey, earnAuthority.publicKey, initialIndex, claimCooldown);
+
+    // Add the m vault as an M earner
+    const mVault = getMVault();
+    const earnerMerkleTree = new MerkleTree([admin.publicKey, mVault]);
+
+    // Propagate the merkle root
+    await propagateIndex(initialIndex, earnerMerkleTree.getRoot());
+
+    // Add the earner account for the vault
+    const { proof } = earnerMerkleTree.getInclusionProof(mVault);
+    await addRegistrarEarner(mVault, proof);
+  });
+
+  describe('admin instruction unit tests', () => {
+    describe('initialize unit tests', () => {
+      // test cases
+      // [X] given the m_mint is not owned by the token2022 program
+      //   [X] it reverts with a TokenProgram error
+      // [X] given the ext_mint is not owned by the token2022 program
+      //   [X] it reverts with a TokenProgram error
+      // [X] given the M earn global account does not match the PDA on the earn program
+      //   [X] it reverts with a SeedsConstraint error
+      // [X] given the yield mode is rebasing and the ext_mint has no scaled ui amount extension
+      //   [X] it reverts with an InvalidMint error
+      // [X] given all accounts are correct
+      //   [X] the global account is created
+      //   [X] the admin is set to the signer
+      //   [X] the m_mint is set correctly
+      //   [X] the ext_mint is set correctly
+      //   [X] the m_earn_global_account is set correctly
+      //   [X] the earn authority is set correctly
+      //   [X] the initial index is set correctly
       //   [X] the bumps are set correctly
 
       // given the m_mint is not owned by the token2022 program
@@ -1189,7 +1730,7 @@ describe('ExtEarn unit tests', () => {
         // Attempt to send the transaction
         await expectAnchorError(
           extEarn.methods
-            .initialize(earnAuthority.publicKey)
+            .initialize(earnAuthority.publicKey, { claim: {} })
             .accounts({ ...accounts })
             .signers([nonAdmin])
             .rpc(),
@@ -1213,7 +1754,7 @@ describe('ExtEarn unit tests', () => {
         // Attempt to send the transaction
         await expectAnchorError(
           extEarn.methods
-            .initialize(earnAuthority.publicKey)
+            .initialize(earnAuthority.publicKey, { claim: {} })
             .accounts({ ...accounts })
             .signers([nonAdmin])
             .rpc(),
@@ -1237,7 +1778,7 @@ describe('ExtEarn unit tests', () => {
         // Attempt to send the transaction
         await expectAnchorError(
           extEarn.methods
-            .initialize(earnAuthority.publicKey)
+            .initialize(earnAuthority.publicKey, { claim: {} })
             .accounts({ ...accounts })
             .signers([nonAdmin])
             .rpc(),
@@ -1259,13 +1800,46 @@ describe('ExtEarn unit tests', () => {
         // Expect error (could be one of several "SeedsConstraint", "AccountOwnedByWrongProgram", "AccountNotInitialized")
         await expectSystemError(
           extEarn.methods
-            .initialize(earnAuthority.publicKey)
+            .initialize(earnAuthority.publicKey, { claim: {} })
             .accounts({ ...accounts })
             .signers([nonAdmin])
             .rpc(),
         );
       });
 
+      // given the yield mode is rebasing and the ext_mint has no scaled ui amount extension
+      // it reverts with an InvalidMint error
+      test('rebasing ext_mint without scaled ui amount - reverts', async () => {
+        // Create a mint without extensions, minted by its ext mint authority
+        const plainMint = new Keypair();
+        const [plainMintAuthority] = PublicKey.findProgramAddressSync(
+          [Buffer.from('mint_authority'), plainMint.publicKey.toBuffer()],
+          extEarn.programId,
+        );
+        await createMint(plainMint, plainMintAuthority);
+
+        // Setup the instruction call
+        prepExtInitialize(admin);
+
+        // Change the Ext Mint and its global account
+        accounts.extMint = plainMint.publicKey;
+        [accounts.globalAccount] = PublicKey.findProgramAddressSync(
+          [Buffer.from('global'), plainMint.publicKey.toBuffer()],
+          extEarn.programId,
+        );
+
+        // Attempt to send the transaction
+        // Expect an InvalidMint error
+        await expectAnchorError(
+          extEarn.methods
+            .initialize(earnAuthority.publicKey, { rebasing: {} })
+            .accounts({ ...accounts })
+            .signers([admin])
+            .rpc(),
+          'InvalidMint',
+        );
+      });
+
       // given all the accounts are correct
       // the global account is created and configured correctly
       test('initialize - success', async () => {
@@ -1274,16 +1848,22 @@ describe('ExtEarn unit tests', () => {
 
         // Create and send the transaction
         await extEarn.methods
-          .initialize(earnAuthority.publicKey)
+          .initialize(earnAuthority.publicKey, { claim: {} })
           .accounts({ ...accounts })
           .signers([admin])
           .rpc();
 
         // Calculate the expected bumps
-        const [, globalBump] = PublicKey.findProgramAddressSync([Buffer.from('global')], EXT_EARN_PROGRAM_ID);
-        const [, mVaultBump] = PublicKey.findProgramAddressSync([Buffer.from('m_vault')], EXT_EARN_PROGRAM_ID);
+        const [, globalBump] = PublicKey.findProgramAddressSync(
+          [Buffer.from('global'), extMint.publicKey.toBuffer()],
+          EXT_EARN_PROGRAM_ID,
+        );
+        const [, mVaultBump] = PublicKey.findProgramAddressSync(
+          [Buffer.from('m_vault'), extMint.publicKey.toBuffer()],
+          EXT_EARN_PROGRAM_ID,
+        );
         const [, extMintAuthorityBump] = PublicKey.findProgramAddressSync(
-          [Buffer.from('mint_authority')],
+          [Buffer.from('mint_authority'), extMint.publicKey.toBuffer()],
           EXT_EARN_PROGRAM_ID,
         );
 
@@ -1451,7 +2031,7 @@ describe('ExtEarn unit tests', () => {
           .rpc();
 
         const [, bump] = PublicKey.findProgramAddressSync(
-          [Buffer.from('earn_manager'), earnManagerOne.publicKey.toBuffer()],
+          [Buffer.from('earn_manager'), extMint.publicKey.toBuffer(), earnManagerOne.publicKey.toBuffer()],
           EXT_EARN_PROGRAM_ID,
         );
 
@@ -1562,67 +2142,35 @@ describe('ExtEarn unit tests', () => {
         });
       });
     });
-  });
-
-  describe('earn_authority instruction tests', () => {
-    const newIndex = new BN(1_100_000_000_000); // 1.1
-    let startTime: BN;
-    const mintAmount = new BN(100_000_000);
-
-    beforeEach(async () => {
-      // Initialize the program
-      await initializeExt(earnAuthority.publicKey);
-
-      // Add an earn manager to create earner accounts
-      // Set the fee to zero initially
-      await addEarnManager(earnManagerOne.publicKey, new BN(0));
 
-      // Mint M tokens to the earners and then wrap it to Ext tokens
-      await mintM(earnerOne.publicKey, mintAmount);
-      await mintM(earnerTwo.publicKey, mintAmount);
+    describe('set_yield_recipient unit tests', () => {
+      // test cases
+      // [X] given the admin does not sign the transaction
+      //   [X] it reverts with a NotAuthorized error
+      // [X] given the admin does sign the transaction
+      //   [X] given the excess buffer is more than 100%
+      //     [X] it reverts with an InvalidParam error
+      //   [X] given the yield recipient is not an M or ext token account
+      //     [X] it reverts with an InvalidMint error
+      //   [X] given all the inputs are valid
+      //     [X] it sets the yield recipient, the excess buffer and the claims timeout
 
-      // Propagate the initial index again to update the max M supply during the interval so max yield is sufficient
-      // Under normal operation, this happens on any bridge transaction and when yield is distributed
-      // However, we are minting tokens here for testing so it is not reflected, therefore, we have to pretend this is a bridge.
-      await propagateIndex(initialIndex);
+      beforeEach(async () => {
+        // Initialize the program
+        await initializeExt(earnAuthority.publicKey);
+      });
 
-      // Add earner one as an ext earner so there is outstanding yield once it is synced
-      await addEarner(earnManagerOne, earnerOne.publicKey);
-
-      // Wrap the M tokens to Ext tokens to deposit them in the M vault
-      await wrap(earnerOne, mintAmount);
-      await wrap(earnerTwo, mintAmount);
-
-      startTime = currentTime();
-
-      // Warp time forward an hour
-      warp(new BN(3600), true);
-
-      // Update the index on the Earn program to start a claim cycle
-      await propagateIndex(newIndex);
-    });
-
-    describe('sync unit tests', () => {
-      // test cases
-      // [X] given the earn authority does not sign the transaction
-      //   [X] it reverts with a NotAuthorized error
-      // [X] given the earn authority does sign the transaction
-      //   [X] given the m_earn_global_account does not match the stored key
-      //     [X] it reverts with an InvalidAccount error
-      //   [X] given all accounts are correct
-      //     [X] it updates the ExtGlobal index and timestamp to the current index and timestamp on the M Earn Global account
-
-      // given the earn authority does not sign the transaction
-      // it reverts with a NotAuthorized error
-      test('earn_authority does not sign - reverts', async () => {
-        // Setup the instruction
-        prepSync(nonAdmin);
+      // given the admin does not sign the transaction
+      // it reverts with a NotAuthorized error
+      test('admin does not sign the transaction - reverts', async () => {
+        // Setup the instruction
+        await prepSetYieldRecipient(nonAdmin);
 
         // Attempt to send the transaction
-        // Expect it to revert with a NotAuthorized error
+        // Expect a NotAuthorized error
         await expectAnchorError(
           extEarn.methods
-            .sync()
+            .setYieldRecipient(new BN(0), new BN(0))
             .accounts({ ...accounts })
             .signers([nonAdmin])
             .rpc(),
@@ -1630,129 +2178,103 @@ describe('ExtEarn unit tests', () => {
         );
       });
 
-      // given the earn authority does sign the transaction
-      // given the m_earn_global_account does not match the stored key
-      // it reverts with a variety of errors (AccountNotInitialized, AccountOwnedByWrongProgram, InvalidAccount)
-      test('m_earn_global_account is invalid - reverts', async () => {
+      // given the admin does sign the transaction
+      // given the excess buffer is more than 100%
+      // it reverts with an InvalidParam error
+      test('excess buffer above 100% - reverts', async () => {
         // Setup the instruction
-        prepSync(earnAuthority);
-
-        // Use an incorrect account
-        const wrongAccount = PublicKey.unique();
-        if (accounts.mEarnGlobalAccount == wrongAccount) return;
-        accounts.mEarnGlobalAccount = wrongAccount;
+        await prepSetYieldRecipient(admin);
 
         // Attempt to send the transaction
-        // Expect it to revert with an error
-        await expectSystemError(
+        // Expect an InvalidParam error
+        await expectAnchorError(
           extEarn.methods
-            .sync()
+            .setYieldRecipient(new BN(10_001), new BN(0))
             .accounts({ ...accounts })
-            .signers([earnAuthority])
+            .signers([admin])
             .rpc(),
+          'InvalidParam',
         );
       });
 
-      // given the earn authority does sign the transaction
-      // given all the accounts are correct
-      // it updates the index and timestamp of the ExtGlobal account
-      test('sync - success', async () => {
+      // given the admin does sign the transaction
+      // given the yield recipient is not an M or ext token account
+      // it reverts with an InvalidMint error
+      test('yield recipient of another mint - reverts', async () => {
+        // Create a token account for another mint
+        const otherMint = new Keypair();
+        await createMint(otherMint, admin.publicKey);
+        const otherTokenAccount = await getATA(otherMint.publicKey, yieldRecipient.publicKey);
+
         // Setup the instruction
-        const { globalAccount } = prepSync(earnAuthority);
+        await prepSetYieldRecipient(admin, otherTokenAccount);
 
-        // Confirm the state of the ExtGlobal account before the sync
-        await expectExtGlobalState(globalAccount, {
-          index: initialIndex,
-          timestamp: startTime,
-        });
+        // Attempt to send the transaction
+        // Expect an InvalidMint error
+        await expectAnchorError(
+          extEarn.methods
+            .setYieldRecipient(new BN(0), new BN(0))
+            .accounts({ ...accounts })
+            .signers([admin])
+            .rpc(),
+          'InvalidMint',
+        );
+      });
 
-        // Confirm the state of the EarnGlobal account before the sync
-        await expectEarnGlobalState(getEarnGlobalAccount(), {
-          index: newIndex,
-          timestamp: currentTime(),
-        });
+      // given the admin does sign the transaction
+      // given all the inputs are valid
+      // it sets the yield recipient, the excess buffer and the claims timeout
+      test('set_yield_recipient - success', async () => {
+        // Setup the instruction
+        const { yieldRecipientTokenAccount } = await prepSetYieldRecipient(admin);
+        const excessBufferBps = new BN(50);
+        const claimsTimeout = new BN(86_400);
 
         // Send the transaction
         await extEarn.methods
-          .sync()
+          .setYieldRecipient(excessBufferBps, claimsTimeout)
           .accounts({ ...accounts })
-          .signers([earnAuthority])
+          .signers([admin])
           .rpc();
 
-        // Expect the ExtGlobal state to be updated
-        await expectExtGlobalState(globalAccount, {
-          index: newIndex,
-          timestamp: currentTime(),
+        // Check the global state
+        await expectExtGlobalState(getExtGlobalAccount(), {
+          yieldRecipient: yieldRecipientTokenAccount!,
+          excessBufferBps,
+          claimsTimeout,
         });
       });
     });
 
-    describe('claim_for unit tests', () => {
+    describe('grant_role unit tests', () => {
       // test cases
-      // [X] given the earn authority does not sign the transaction
+      // [X] given the admin does not sign the transaction
       //   [X] it reverts with a NotAuthorized error
-      // [X] given the earn authority does sign the transaction
-      //   [X] given the wrong ext_mint account is provided
-      //     [X] it reverts with an error (could be various depending on the account)
-      //   [X] given the wrong earn manager account is provided for an earner
-      //     [X] it reverts with a ConstraintSeeds error
-      //   [X] given the wrong token account is provided for the M vault
-      //     [X] it reverts with a ConstraintAssociated error
-      //   [X] given the earn manager token account does not match the token account stored on the earn manager account
-      //     [X] it reverts with an InvalidAccount error
-      //   [X] given the earner does not have a recipient token account defined
-      //     [X] given the user token account does not match the one defined on the earner account
-      //       [X] it reverts with an InvalidAccount error
-      //     [X] given the user token account matches the one defined on the earner account
-      //       [X] it mints yield to the user token account
-      //   [X] given the earner does have a recipient token account defined
-      //     [X] given the user token account does not match the recipient token account
-      //       [X] it reverts with an InvalidAccount error
-      //     [X] given the user token account matches the recipient token account
-      //       [X] it mints yield to the recipient token account
-      //   [X] given the accounts are all correct
-      //     [X] given the earner's last claim index is greater than or equal to the current global index
-      //       [X] it reverts with an AlreadyClaimed error
-      //     [X] given the M vault does not have enough M to mint the rewards against
-      //        (e.g. the yield for the vault hasn't been claimed yet or the provided balance is too high)
-      //       [X] it reverts with an InsufficientCollateral error
-      //     [X] given the earn manager has zero fee
-      //       [X] it mints all of the rewards to the earner's token account
-      //     [X] given the earn manager is not active and has a non-zero fee
-      //       [X] it mints all of the rewards to the earner's token account
-      //     [X] given the earn manager is active and has a non-zero fee
-      //       [ ] given the earn manager's fee token account is closed
-      //         [X] it mints all of the rewards to the earner's token account
-      //       [X] given the fee on the current yield rounds to zero
-      //         [X] it mints all of the rewards to the earner's token account
-      //       [X] given the fee does not round to zero
-      //         [X] it mints the fee to the earn manager's token account and the remaining rewards
-      //             to the earner's token account
+      // [X] given the admin does sign the transaction
+      //   [X] given the member already has the role
+      //     [X] it reverts with an InvalidParam error
+      //   [X] given the role already has the maximum number of members
+      //     [X] it reverts with a RoleFull error
+      //   [X] given the member does not have the role
+      //     [X] it creates the roles account and adds the member
+      //     [X] the member can use the role
 
       beforeEach(async () => {
-        // Push the M yield to the M vault ATA
-        await mClaimFor(getMVault());
-
-        // Sync the latest index from the M earn program to have yield to claim
-        await sync();
-
-        // Add earner two as an earner after the sync so it does not have any yield to claim
-        await addEarner(earnManagerOne, earnerTwo.publicKey);
+        // Initialize the program
+        await initializeExt(earnAuthority.publicKey);
       });
 
-      // given the earn authority does not sign the transaction
+      // given the admin does not sign the transaction
       // it reverts with a NotAuthorized error
-      test('Earn authority does not sign the transaction - reverts', async () => {
-        // Setup the instruction to claim for earner one
-        await prepClaimFor(nonAdmin, earnerOne.publicKey, earnManagerOne.publicKey);
-
-        const balance = await getTokenBalance(await getATA(extMint.publicKey, earnerOne.publicKey));
+      test('admin does not sign the transaction - reverts', async () => {
+        // Setup the instruction
+        prepGrantRole(nonAdmin);
 
         // Attempt to send the transaction
         // Expect a NotAuthorized error
         await expectAnchorError(
           extEarn.methods
-            .claimFor(balance)
+            .grantRole({ earnManagerAdmin: {} }, nonAdmin.publicKey)
             .accounts({ ...accounts })
             .signers([nonAdmin])
             .rpc(),
@@ -1760,564 +2282,2498 @@ describe('ExtEarn unit tests', () => {
         );
       });
 
-      // given the wrong ext mint account is provided
-      // it reverts with a InvalidAccount error
-      test('Ext mint account is invalid - reverts', async () => {
+      // given the admin does sign the transaction
+      // given the member already has the role
+      // it reverts with an InvalidParam error
+      test('member already has the role - reverts', async () => {
+        // Grant the role
+        await grantRole({ earnManagerAdmin: {} }, nonAdmin.publicKey);
+
         // Setup the instruction
-        await prepClaimFor(earnAuthority, earnerOne.publicKey, earnManagerOne.publicKey);
+        prepGrantRole(admin);
 
-        // Change the mint account
-        accounts.extMint = mMint.publicKey;
+        // Expire the blockhash so the same transaction can be sent again
+        svm.expireBlockhash();
 
-        // Attempt to send the transaction
-        // Expect revert
+        // Attempt to grant the role again
+        // Expect an InvalidParam error
         await expectAnchorError(
           extEarn.methods
-            .claimFor(await getTokenBalance(await getATA(extMint.publicKey, earnerOne.publicKey)))
+            .grantRole({ earnManagerAdmin: {} }, nonAdmin.publicKey)
             .accounts({ ...accounts })
-            .signers([earnAuthority])
+            .signers([admin])
             .rpc(),
-          'InvalidAccount',
+          'InvalidParam',
         );
       });
 
-      // given the wrong earn manager account is provided for an earner
-      // it reverts with a ConstraintSeeds error
-      test('Earn manager account is invalid - reverts', async () => {
-        // Add another earn manager
-        await addEarnManager(earnManagerTwo.publicKey, new BN(0));
+      // given the admin does sign the transaction
+      // given the role already has the maximum number of members
+      // it reverts with a RoleFull error
+      test('role is full - reverts', async () => {
+        // Fill the role
+        for (let i = 0; i < 8; i++) {
+          await grantRole({ earnAuthority: {} }, new Keypair().publicKey);
+        }
 
         // Setup the instruction
-        await prepClaimFor(earnAuthority, earnerOne.publicKey, earnManagerTwo.publicKey);
+        prepGrantRole(admin);
 
-        // Attempt send the transaction
-        // Expect revert
+        // Attempt to grant the role to another member
+        // Expect a RoleFull error
         await expectAnchorError(
           extEarn.methods
-            .claimFor(await getTokenBalance(await getATA(extMint.publicKey, earnerOne.publicKey)))
+            .grantRole({ earnAuthority: {} }, nonAdmin.publicKey)
             .accounts({ ...accounts })
-            .signers([earnAuthority])
+            .signers([admin])
             .rpc(),
-          'ConstraintSeeds',
+          'RoleFull',
         );
       });
 
-      // given the M vault token account is not the M vault's ATA
-      // it reverts with a ConstraintAssociated error
-      test('M Vault token account is invalid - reverts', async () => {
+      // given the admin does sign the transaction
+      // given the member does not have the role
+      // it creates the roles account and adds the member
+      // the member can use the role
+      test('grant_role - success', async () => {
         // Setup the instruction
-        await prepClaimFor(earnAuthority, earnerOne.publicKey, earnManagerOne.publicKey);
+        const { rolesAccount } = prepGrantRole(admin);
 
-        // Create a non-ATA token account for the M vault
-        const { tokenAccount: invalidMVaultTokenAccount } = await createTokenAccount(mMint.publicKey, getMVault());
+        // Send the transaction
+        await extEarn.methods
+          .grantRole({ earnManagerAdmin: {} }, nonAdmin.publicKey)
+          .accounts({ ...accounts })
+          .signers([admin])
+          .rpc();
 
-        // Replace the M vault token account with the invalid one
-        accounts.vaultMTokenAccount = invalidMVaultTokenAccount;
+        // Check the roles account
+        const roles = await extEarn.account.roles.fetch(rolesAccount);
+        expect(roles.earnManagerAdmins).toEqual([nonAdmin.publicKey]);
+        expect(roles.earnAuthorities).toEqual([]);
+
+        // The earn manager admin adds an earn manager
+        const { earnManagerAccount } = await prepAddEarnManager(nonAdmin, earnManagerOne.publicKey);
+        accounts.rolesAccount = rolesAccount;
+
+        await extEarn.methods
+          .addEarnManager(earnManagerOne.publicKey, new BN(0))
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc();
+
+        await expectEarnManagerState(earnManagerAccount, {
+          earnManager: earnManagerOne.publicKey,
+          isActive: true,
+        });
+      });
+    });
+
+    describe('revoke_role unit tests', () => {
+      // test cases
+      // [X] given the admin does not sign the transaction
+      //   [X] it reverts with a NotAuthorized error
+      // [X] given the admin does sign the transaction
+      //   [X] given the member does not have the role
+      //     [X] it reverts with an InvalidParam error
+      //   [X] given the member has the role
+      //     [X] it removes the member
+      //     [X] the member can no longer use the role
+
+      beforeEach(async () => {
+        // Initialize the program
+        await initializeExt(earnAuthority.publicKey);
+
+        // Make the non admin an earn manager admin
+        await grantRole({ earnManagerAdmin: {} }, nonAdmin.publicKey);
+      });
+
+      // given the admin does not sign the transaction
+      // it reverts with a NotAuthorized error
+      test('admin does not sign the transaction - reverts', async () => {
+        // Setup the instruction
+        prepRevokeRole(nonAdmin);
 
         // Attempt to send the transaction
-        // Expect revert with a ConstraintAssociated error
+        // Expect a NotAuthorized error
         await expectAnchorError(
           extEarn.methods
-            .claimFor(await getTokenBalance(await getATA(extMint.publicKey, earnerOne.publicKey)))
+            .revokeRole({ earnManagerAdmin: {} }, nonAdmin.publicKey)
             .accounts({ ...accounts })
-            .signers([earnAuthority])
+            .signers([nonAdmin])
             .rpc(),
-          'ConstraintAssociated',
+          'NotAuthorized',
         );
       });
 
-      // given the earn manager token account does not match token account stored on the earn manager account
-      // it reverts with an InvalidAccount error
-      test('Earn manager token account is invalid - reverts', async () => {
-        // Create a new token account for the earn manager that doesn't match the one stored
-        const { tokenAccount: invalidEarnManagerTokenAccount } = await createTokenAccount(
-          extMint.publicKey,
-          earnManagerOne.publicKey,
-        );
-
+      // given the admin does sign the transaction
+      // given the member does not have the role
+      // it reverts with an InvalidParam error
+      test('member does not have the role - reverts', async () => {
         // Setup the instruction
-        await prepClaimFor(
-          earnAuthority,
-          earnerOne.publicKey,
-          earnManagerOne.publicKey,
-          undefined,
-          invalidEarnManagerTokenAccount,
-        );
+        prepRevokeRole(admin);
 
-        // Attempt to send the transaction
-        // Expect revert with an InvalidAccount error
+        // Attempt to revoke a role the member does not have
+        // Expect an InvalidParam error
         await expectAnchorError(
           extEarn.methods
-            .claimFor(await getTokenBalance(await getATA(extMint.publicKey, earnerOne.publicKey)))
+            .revokeRole({ earnAuthority: {} }, nonAdmin.publicKey)
             .accounts({ ...accounts })
-            .signers([earnAuthority])
+            .signers([admin])
             .rpc(),
-          'InvalidAccount',
+          'InvalidParam',
         );
       });
 
-      // given the earner does not have a recipient token account defined
-      // given the user token account does not match the user token account on the earner account
-      // it reverts with an InvalidAccount error
-      test("Earner has no recipient account, token account doesn't match - reverts", async () => {
-        // Create a new token account for the earner that doesn't match the one stored
-        const { tokenAccount: invalidUserTokenAccount } = await createTokenAccount(
-          extMint.publicKey,
-          earnerOne.publicKey,
+      // given the admin does sign the transaction
+      // given the member has the role
+      // it removes the member
+      // the member can no longer use the role
+      test('revoke_role - success', async () => {
+        // Setup the instruction
+        const { rolesAccount } = prepRevokeRole(admin);
+
+        // Send the transaction
+        await extEarn.methods
+          .revokeRole({ earnManagerAdmin: {} }, nonAdmin.publicKey)
+          .accounts({ ...accounts })
+          .signers([admin])
+          .rpc();
+
+        // Check the roles account
+        const roles = await extEarn.account.roles.fetch(rolesAccount);
+        expect(roles.earnManagerAdmins).toEqual([]);
+
+        // The former earn manager admin cannot add an earn manager
+        await prepAddEarnManager(nonAdmin, earnManagerOne.publicKey);
+        accounts.rolesAccount = rolesAccount;
+
+        await expectAnchorError(
+          extEarn.methods
+            .addEarnManager(earnManagerOne.publicKey, new BN(0))
+            .accounts({ ...accounts })
+            .signers([nonAdmin])
+            .rpc(),
+          'NotAuthorized',
         );
+      });
+    });
 
-        // Setup the instruction with the invalid user token account
-        await prepClaimFor(earnAuthority, earnerOne.publicKey, earnManagerOne.publicKey, invalidUserTokenAccount);
+    describe('set_removal_bounty unit tests', () => {
+      // test cases
+      // [X] given the admin does not sign the transaction
+      //   [X] it reverts with a NotAuthorized error
+      // [X] given the admin does sign the transaction
+      //   [X] given the bounty is more than the rent of an earner account
+      //     [X] it reverts with an InvalidParam error
+      //   [X] given the bounty is at most the rent of an earner account
+      //     [X] it sets the removal bounty
+      //     [X] it emits a RemovalBountyUpdate event
+
+      let earnerRent: BN;
+
+      beforeEach(async () => {
+        // Initialize the program
+        await initializeExt(earnAuthority.publicKey);
+
+        // The bounty is paid out of the rent of the earner account
+        const rent = await provider.connection.getMinimumBalanceForRentExemption(extEarn.account.earner.size);
+        earnerRent = new BN(rent);
+      });
+
+      // given the admin does not sign the transaction
+      // it reverts with a NotAuthorized error
+      test('admin does not sign the transaction - reverts', async () => {
+        // Setup the instruction
+        prepSetRemovalBounty(nonAdmin);
 
         // Attempt to send the transaction
-        // Expect revert with an InvalidAccount error
+        // Expect a NotAuthorized error
         await expectAnchorError(
           extEarn.methods
-            .claimFor(await getTokenBalance(invalidUserTokenAccount))
+            .setRemovalBounty(new BN(0))
             .accounts({ ...accounts })
-            .signers([earnAuthority])
+            .signers([nonAdmin])
             .rpc(),
-          'InvalidAccount',
+          'NotAuthorized',
         );
       });
 
-      // given the earner does not have a recipient token account defined
-      // given the user token account matches
-      // it mints the yield to the user token account
-      test('Earner has no recipient account, token account matches - success', async () => {
+      // given the admin does sign the transaction
+      // given the bounty is more than the rent of an earner account
+      // it reverts with an InvalidParam error
+      test('bounty above the earner rent - reverts', async () => {
         // Setup the instruction
-        const { earnerAccount } = await prepClaimFor(earnAuthority, earnerOne.publicKey, earnManagerOne.publicKey);
-        const earnerATA = await getATA(extMint.publicKey, earnerOne.publicKey);
-
-        // Check that the last claim index and the last claim timestamp are the initial values
-        await expectEarnerState(earnerAccount, {
-          lastClaimIndex: initialIndex,
-          lastClaimTimestamp: startTime,
-        });
+        prepSetRemovalBounty(admin);
 
-        // Get the initial balance for the earner ata
-        const initialBalance = await getTokenBalance(earnerATA);
+        // Attempt to send the transaction
+        // Expect an InvalidParam error
+        await expectAnchorError(
+          extEarn.methods
+            .setRemovalBounty(earnerRent.addn(1))
+            .accounts({ ...accounts })
+            .signers([admin])
+            .rpc(),
+          'InvalidParam',
+        );
+      });
 
-        // Calculate the expected new balance
-        // Note: earn manager fee is 0, so it all goes to the earner
-        const expectedBalance = initialBalance.mul(newIndex).div(initialIndex);
+      // given the admin does sign the transaction
+      // given the bounty is at most the rent of an earner account
+      // it sets the removal bounty
+      test('set_removal_bounty - success', async () => {
+        // Setup the instruction
+        prepSetRemovalBounty(admin);
 
-        // Send the instruction
+        // Send the transaction
         await extEarn.methods
-          .claimFor(initialBalance)
+          .setRemovalBounty(earnerRent)
           .accounts({ ...accounts })
-          .signers([earnAuthority])
+          .signers([admin])
           .rpc();
 
-        // Check the new balance matches the expected balance
-        await expectTokenBalance(earnerATA, expectedBalance);
+        // Check the global state
+        const global = await extEarn.account.extGlobal.fetch(getExtGlobalAccount());
+        expect(global.removalBounty.toString()).toEqual(earnerRent.toString());
+      });
 
-        // Check the earner account is updated
-        await expectEarnerState(earnerAccount, {
-          lastClaimIndex: newIndex,
-          lastClaimTimestamp: currentTime(),
-        });
+      // given the admin does sign the transaction
+      // given the bounty is at most the rent of an earner account
+      // it emits a RemovalBountyUpdate event
+      test('set_removal_bounty - emits event', async () => {
+        // Setup the instruction
+        prepSetRemovalBounty(admin);
+
+        // Simulate the transaction
+        const { events } = await extEarn.methods
+          .setRemovalBounty(earnerRent)
+          .accounts({ ...accounts })
+          .signers([admin])
+          .simulate();
+
+        // Check the emitted event
+        expect(events.length).toEqual(1);
+        expect(events[0].name).toEqual('RemovalBountyUpdate');
+        expect(events[0].data.extMint.toBase58()).toEqual(extMint.publicKey.toBase58());
+        expect(events[0].data.removalBounty.toString()).toEqual(earnerRent.toString());
       });
+    });
 
-      test('Earner has recipient account, token account does not match - reverts', async () => {
-        // Set the earner's recipient account to the yield recipients ATA
-        const yieldRecipientATA = await getATA(extMint.publicKey, yieldRecipient.publicKey);
-        await setRecipient(earnerOne, yieldRecipientATA);
+    describe('remove_wrapper unit tests', () => {
+      // test cases
+      // [X] given the admin does not sign the transaction
+      //   [X] it reverts with a NotAuthorized error
+      // [X] given the admin does sign the transaction
+      //   [X] it closes the wrapper account to the admin
 
-        // Setup the instruction with the earner's ATA as the user token account
-        const earnerATA = await getATA(extMint.publicKey, earnerOne.publicKey);
-        await prepClaimFor(earnAuthority, earnerOne.publicKey, earnManagerOne.publicKey, earnerATA);
+      beforeEach(async () => {
+        // Initialize the program
+        await initializeExt(earnAuthority.publicKey);
+
+        // Add earner one as a wrapper
+        await addWrapper(earnerOne.publicKey);
+      });
+
+      // given the admin does not sign the transaction
+      // it reverts with a NotAuthorized error
+      test('admin does not sign the transaction - reverts', async () => {
+        // Setup the instruction
+        prepRemoveWrapper(nonAdmin, earnerOne.publicKey);
 
         // Attempt to send the transaction
-        // Expect revert with an InvalidAccount error
+        // Expect a NotAuthorized error
         await expectAnchorError(
           extEarn.methods
-            .claimFor(await getTokenBalance(earnerATA))
+            .removeWrapper()
             .accounts({ ...accounts })
-            .signers([earnAuthority])
+            .signers([nonAdmin])
             .rpc(),
-          'InvalidAccount',
+          'NotAuthorized',
         );
       });
 
-      test('Earner has recipient account, token account matches - success', async () => {
-        // Set the earner's recipient account to the yield recipients ATA
-        const yieldRecipientATA = await getATA(extMint.publicKey, yieldRecipient.publicKey);
-        await setRecipient(earnerOne, yieldRecipientATA);
-
+      // given the admin does sign the transaction
+      // it closes the wrapper account to the admin
+      test('remove_wrapper - success', async () => {
         // Setup the instruction
-        const { earnerAccount } = await prepClaimFor(
-          earnAuthority,
-          earnerOne.publicKey,
-          earnManagerOne.publicKey,
-          yieldRecipientATA,
-        );
-        const earnerATA = await getATA(extMint.publicKey, earnerOne.publicKey);
-
-        // Check that the last claim index and the last claim timestamp are the initial values
-        await expectEarnerState(earnerAccount, {
-          lastClaimIndex: initialIndex,
-          lastClaimTimestamp: startTime,
-        });
-
-        // Get the initial balance for the earner ata
-        const initialBalance = await getTokenBalance(earnerATA);
-
-        // Calculate the expected yield
-        // Note: earn manager fee is 0, so it all goes to the yield recipient
-        const expectedYield = initialBalance.mul(newIndex).div(initialIndex).sub(initialBalance);
+        const { wrapperAccount } = prepRemoveWrapper(admin, earnerOne.publicKey);
 
-        // Send the instruction
+        // Send the transaction
         await extEarn.methods
-          .claimFor(initialBalance)
+          .removeWrapper()
           .accounts({ ...accounts })
-          .signers([earnAuthority])
+          .signers([admin])
           .rpc();
 
-        // Check the ata balance didn't change but the yield recipient received the yield
-        await expectTokenBalance(earnerATA, initialBalance);
-        await expectTokenBalance(yieldRecipientATA, expectedYield);
-
-        // Check the earner account is updated
-        await expectEarnerState(earnerAccount, {
-          lastClaimIndex: newIndex,
-          lastClaimTimestamp: currentTime(),
-        });
+        // Check the wrapper account is closed
+        expectAccountEmpty(wrapperAccount);
       });
+    });
 
-      // given all accounts are correct
-      // given the earner's yield has been claimed up to the current index
-      // it reverts with an AlreadyClaimed error
-      test('Earner yield already claimed up to current index - reverts', async () => {
-        // Setup the instruction to claim for earner two
-        // earnerTwo was added after the sync, so its lastClaimIndex should equal the current index
-        const { earnerAccount } = await prepClaimFor(earnAuthority, earnerTwo.publicKey, earnManagerOne.publicKey);
-        const balance = await getTokenBalance(await getATA(extMint.publicKey, earnerTwo.publicKey));
+    describe('migrate_ext_global unit tests', () => {
+      // test cases
+      // [X] given the global account is on the current version
+      //   [X] it reverts with an InvalidVersion error
+      // [X] given the global account is on an older version
+      //   [X] given the admin does not sign the transaction
+      //     [X] it reverts with a NotAuthorized error
+      //   [X] given the admin does sign the transaction
+      //     [X] it sets the version to the current version
 
-        // Verify that the earner's last claim index is equal to the current global index
-        await expectEarnerState(earnerAccount, {
-          lastClaimIndex: newIndex,
-          lastClaimTimestamp: currentTime(),
-        });
+      beforeEach(async () => {
+        // Initialize the program
+        await initializeExt(earnAuthority.publicKey);
+      });
+
+      // given the global account is on the current version
+      // it reverts with an InvalidVersion error
+      test('current version - reverts', async () => {
+        // Setup the instruction
+        prepMigrateExtGlobal(admin);
 
         // Attempt to send the transaction
-        // Expect an AlreadyClaimed error
+        // Expect an InvalidVersion error
         await expectAnchorError(
           extEarn.methods
-            .claimFor(balance)
+            .migrateExtGlobal()
             .accounts({ ...accounts })
-            .signers([earnAuthority])
+            .signers([admin])
             .rpc(),
-          'AlreadyClaimed',
+          'InvalidVersion',
         );
       });
 
-      // given all the accounts are correct
-      // given the M vault doesn't have enough M collateral to mint the yield against
-      // it reverts with an InsufficientCollateral error
-      test('Insufficient M collateral to mint yield - reverts', async () => {
-        // Setup the instruction to claim for earner one
-        const { earnerAccount } = await prepClaimFor(earnAuthority, earnerOne.publicKey, earnManagerOne.publicKey);
-
-        // Get the actual balance of the earner's token account
-        const actualBalance = await getTokenBalance(await getATA(extMint.publicKey, earnerOne.publicKey));
+      // given the global account is on an older version
+      // given the admin does not sign the transaction
+      // it reverts with a NotAuthorized error
+      test('admin does not sign the transaction - reverts', async () => {
+        // Downgrade the global account
+        await setExtAccountState(getExtGlobalAccount(), 'ExtGlobal', { version: 1 });
 
-        // Use a balance 4x greater than the actual balance to try and mint more ext tokens than M available
-        const inflatedBalance = actualBalance.mul(new BN(4));
+        // Setup the instruction
+        prepMigrateExtGlobal(nonAdmin);
 
-        // Attempt to send the transaction with the inflated balance
-        // Expect an InsufficientCollateral error
+        // Attempt to send the transaction
+        // Expect a NotAuthorized error
         await expectAnchorError(
           extEarn.methods
-            .claimFor(inflatedBalance)
+            .migrateExtGlobal()
             .accounts({ ...accounts })
-            .signers([earnAuthority])
+            .signers([nonAdmin])
             .rpc(),
-          'InsufficientCollateral',
+          'NotAuthorized',
         );
       });
 
-      // given all the accounts are correct
-      // given the earn manager has zero fee
-      // it mints all the yield to the earner's recipient account
-      test('Earn manager fee is zero - success', async () => {
-        // Setup the instruction to claim for earner one
-        const { earnerAccount, userTokenAccount, earnManagerTokenAccount } = await prepClaimFor(
-          earnAuthority,
-          earnerOne.publicKey,
-          earnManagerOne.publicKey,
-        );
-
-        // Get the current balance of the earner's token account
-        const earnerStartBalance = await getTokenBalance(userTokenAccount);
-
-        // Get the current balance of the earn manager's token account
-        const earnManagerStartBalance = await getTokenBalance(earnManagerTokenAccount);
-
-        // Confirm the earn manager fee is zero
-        await expectEarnManagerState(getEarnManagerAccount(earnManagerOne.publicKey), {
-          feeBps: new BN(0),
-        });
+      // given the global account is on an older version
+      // given the admin does sign the transaction
+      // it sets the version to the current version
+      test('migrate_ext_global - success', async () => {
+        // Downgrade the global account
+        await setExtAccountState(getExtGlobalAccount(), 'ExtGlobal', { version: 1 });
 
-        // Confirm the starting earner account state
-        await expectEarnerState(earnerAccount, {
-          lastClaimIndex: initialIndex,
-          lastClaimTimestamp: startTime,
-        });
+        // Setup the instruction
+        prepMigrateExtGlobal(admin);
 
         // Send the transaction
         await extEarn.methods
-          .claimFor(earnerStartBalance)
+          .migrateExtGlobal()
           .accounts({ ...accounts })
-          .signers([earnAuthority])
+          .signers([admin])
           .rpc();
 
-        // Calculate expected rewards (balance * (global_index / last_claim_index) - balance)
-        const expectedRewards = earnerStartBalance.mul(newIndex).div(initialIndex).sub(earnerStartBalance);
+        // Check the version
+        const global = await extEarn.account.extGlobal.fetch(getExtGlobalAccount());
+        expect(global.version).toEqual(2);
+      });
+    });
 
-        // Verify the expected token balance changes
+    describe('migrate_legacy_global unit tests', () => {
+      // test cases
+      // [X] given the admin does not sign the transaction
+      //   [X] it reverts with a NotAuthorized error
+      // [X] given the admin does sign the transaction
+      //   [X] given the legacy vault has not claimed up to the current M index
+      //     [X] it reverts with an InvalidAccount error
+      //   [X] given only one of the roles accounts is provided
+      //     [X] it reverts with an InvalidAccount error
+      //   [X] given all accounts are correct
+      //     [X] it creates the global account keyed by the ext mint and flags it as legacy
+      //     [X] it records the number of legacy earners left to migrate
+      //     [X] it moves the collateral to the vault of the ext mint
+      //     [X] it hands the mint authority to the mint authority of the ext mint
+      //     [X] it closes the legacy global account
+
+      const collateral = new BN(50_000_000);
+
+      beforeEach(async () => {
+        // Initialize the program and move the global account to its legacy address
+        await initializeExt(earnAuthority.publicKey);
+
+        const [legacyMVault, mVaultBump] = PublicKey.findProgramAddressSync(
+          [Buffer.from('m_vault')],
+          extEarn.programId,
+        );
+        const [legacyExtMintAuthority, extMintAuthorityBump] = PublicKey.findProgramAddressSync(
+          [Buffer.from('mint_authority')],
+          extEarn.programId,
+        );
+
+        await moveToLegacyAddress(getExtGlobalAccount(), 'ExtGlobal', [Buffer.from('global')], {
+          mVaultBump,
+          extMintAuthorityBump,
+        });
+
+        // The legacy mint authority could mint the ext token
+        const mintInfo = svm.getAccount(extMint.publicKey)!;
+        mintInfo.data.set(legacyExtMintAuthority.toBuffer(), 4);
+        svm.setAccount(extMint.publicKey, mintInfo);
+
+        // The legacy vault is an M earner holding the collateral
+        const earnerMerkleTree = new MerkleTree([admin.publicKey, getMVault(), legacyMVault]);
+        await propagateIndex(initialIndex, earnerMerkleTree.getRoot());
+
+        const { proof } = earnerMerkleTree.getInclusionProof(legacyMVault);
+        await addRegistrarEarner(legacyMVault, proof);
+
+        await mintM(legacyMVault, collateral);
+      });
+
+      // given the admin does not sign the transaction
+      // it reverts with a NotAuthorized error
+      test('admin does not sign the transaction - reverts', async () => {
+        // Setup the instruction
+        prepMigrateLegacyGlobal(nonAdmin);
+
+        // Attempt to send the transaction
+        // Expect a NotAuthorized error
+        await expectAnchorError(
+          extEarn.methods
+            .migrateLegacyGlobal(new BN(0))
+            .accounts({ ...accounts })
+            .signers([nonAdmin])
+            .rpc(),
+          'NotAuthorized',
+        );
+      });
+
+      // given the admin does sign the transaction
+      // given the legacy vault has not claimed up to the current M index
+      // it reverts with an InvalidAccount error
+      test('legacy vault has unclaimed yield - reverts', async () => {
+        // Move the M index past the last claim of the legacy vault
+        warp(new BN(3600), true);
+        await propagateIndex(new BN(1_100_000_000_000));
+
+        // Setup the instruction
+        prepMigrateLegacyGlobal(admin);
+
+        // Attempt to send the transaction
+        // Expect an InvalidAccount error
+        await expectAnchorError(
+          extEarn.methods
+            .migrateLegacyGlobal(new BN(0))
+            .accounts({ ...accounts })
+            .signers([admin])
+            .rpc(),
+          'InvalidAccount',
+        );
+      });
+
+      // given the admin does sign the transaction
+      // given only one of the roles accounts is provided
+      // it reverts with an InvalidAccount error
+      test('only the new roles account - reverts', async () => {
+        // Setup the instruction with a new roles account but no legacy one
+        prepMigrateLegacyGlobal(admin);
+        accounts.rolesAccount = getExtRolesAccount();
+
+        // Attempt to send the transaction
+        // Expect an InvalidAccount error
+        await expectAnchorError(
+          extEarn.methods
+            .migrateLegacyGlobal(new BN(0))
+            .accounts({ ...accounts })
+            .signers([admin])
+            .rpc(),
+          'InvalidAccount',
+        );
+      });
+
+      // given the admin does sign the transaction
+      // given all accounts are correct
+      // it creates the global account keyed by the ext mint and flags it as legacy
+      // it records the number of legacy earners left to migrate
+      // it moves the collateral to the vault of the ext mint
+      // it hands the mint authority to the mint authority of the ext mint
+      // it closes the legacy global account
+      test('migrate_legacy_global - success', async () => {
+        // Setup the instruction
+        const { legacyGlobalAccount, legacyVaultMTokenAccount, vaultMTokenAccount } = prepMigrateLegacyGlobal(admin);
+        const vaultBalance = await getTokenBalance(vaultMTokenAccount);
+
+        // Send the transaction
+        await extEarn.methods
+          .migrateLegacyGlobal(new BN(2))
+          .accounts({ ...accounts })
+          .signers([admin])
+          .rpc();
+
+        // Check the global state
+        await expectExtGlobalState(getExtGlobalAccount(), {
+          admin: admin.publicKey,
+          earnAuthority: earnAuthority.publicKey,
+          extMint: extMint.publicKey,
+          mMint: mMint.publicKey,
+        });
+        const global = await extEarn.account.extGlobal.fetch(getExtGlobalAccount());
+        expect(global.legacy).toBe(true);
+        expect(global.version).toEqual(2);
+        expect(global.legacyEarners.toString()).toEqual('2');
+
+        // Check the collateral moved
+        await expectTokenBalance(legacyVaultMTokenAccount, new BN(0));
+        await expectTokenBalance(vaultMTokenAccount, vaultBalance.add(collateral));
+
+        // Check the mint authority
+        const mint = await getMint(provider.connection, extMint.publicKey, undefined, TOKEN_2022_PROGRAM_ID);
+        expect(mint.mintAuthority).toEqual(getExtMintAuthority());
+
+        // Check the legacy global account is closed
+        expectAccountEmpty(legacyGlobalAccount);
+      });
+    });
+  });
+
+  describe('earn_authority instruction tests', () => {
+    const newIndex = new BN(1_100_000_000_000); // 1.1
+    let startTime: BN;
+    const mintAmount = new BN(100_000_000);
+
+    beforeEach(async () => {
+      // Initialize the program
+      await initializeExt(earnAuthority.publicKey);
+
+      // Add an earn manager to create earner accounts
+      // Set the fee to zero initially
+      await addEarnManager(earnManagerOne.publicKey, new BN(0));
+
+      // Mint M tokens to the earners and then wrap it to Ext tokens
+      await mintM(earnerOne.publicKey, mintAmount);
+      await mintM(earnerTwo.publicKey, mintAmount);
+
+      // Propagate the initial index again to update the max M supply during the interval so max yield is sufficient
+      // Under normal operation, this happens on any bridge transaction and when yield is distributed
+      // However, we are minting tokens here for testing so it is not reflected, therefore, we have to pretend this is a bridge.
+      await propagateIndex(initialIndex);
+
+      // Add earner one as an ext earner so there is outstanding yield once it is synced
+      await addEarner(earnManagerOne, earnerOne.publicKey);
+
+      // Wrap the M tokens to Ext tokens to deposit them in the M vault
+      await wrap(earnerOne, mintAmount);
+      await wrap(earnerTwo, mintAmount);
+
+      startTime = currentTime();
+
+      // Warp time forward an hour
+      warp(new BN(3600), true);
+
+      // Update the index on the Earn program to start a claim cycle
+      await propagateIndex(newIndex);
+    });
+
+    describe('sync unit tests', () => {
+      // test cases
+      // [X] given the earn authority does not sign the transaction
+      //   [X] it reverts with a NotAuthorized error
+      // [X] given the earn authority does sign the transaction
+      //   [X] given the m_earn_global_account does not match the stored key
+      //     [X] it reverts with an InvalidAccount error
+      //   [X] given all accounts are correct
+      //     [X] it updates the ExtGlobal index and timestamp to the current index and timestamp on the M Earn Global account
+
+      // given the earn authority does not sign the transaction
+      // it reverts with a NotAuthorized error
+      test('earn_authority does not sign - reverts', async () => {
+        // Setup the instruction
+        prepSync(nonAdmin);
+
+        // Attempt to send the transaction
+        // Expect it to revert with a NotAuthorized error
+        await expectAnchorError(
+          extEarn.methods
+            .sync()
+            .accounts({ ...accounts })
+            .signers([nonAdmin])
+            .rpc(),
+          'NotAuthorized',
+        );
+      });
+
+      // given the earn authority does sign the transaction
+      // given the m_earn_global_account does not match the stored key
+      // it reverts with a variety of errors (AccountNotInitialized, AccountOwnedByWrongProgram, InvalidAccount)
+      test('m_earn_global_account is invalid - reverts', async () => {
+        // Setup the instruction
+        prepSync(earnAuthority);
+
+        // Use an incorrect account
+        const wrongAccount = PublicKey.unique();
+        if (accounts.mEarnGlobalAccount == wrongAccount) return;
+        accounts.mEarnGlobalAccount = wrongAccount;
+
+        // Attempt to send the transaction
+        // Expect it to revert with an error
+        await expectSystemError(
+          extEarn.methods
+            .sync()
+            .accounts({ ...accounts })
+            .signers([earnAuthority])
+            .rpc(),
+        );
+      });
+
+      // given the earn authority does sign the transaction
+      // given all the accounts are correct
+      // it updates the index and timestamp of the ExtGlobal account
+      test('sync - success', async () => {
+        // Setup the instruction
+        const { globalAccount } = prepSync(earnAuthority);
+
+        // Confirm the state of the ExtGlobal account before the sync
+        await expectExtGlobalState(globalAccount, {
+          index: initialIndex,
+          timestamp: startTime,
+        });
+
+        // Confirm the state of the EarnGlobal account before the sync
+        await expectEarnGlobalState(getEarnGlobalAccount(), {
+          index: newIndex,
+          timestamp: currentTime(),
+        });
+
+        // Send the transaction
+        await extEarn.methods
+          .sync()
+          .accounts({ ...accounts })
+          .signers([earnAuthority])
+          .rpc();
+
+        // Expect the ExtGlobal state to be updated
+        await expectExtGlobalState(globalAccount, {
+          index: newIndex,
+          timestamp: currentTime(),
+        });
+      });
+    });
+
+    describe('claim_for unit tests', () => {
+      // test cases
+      // [X] given the earn authority does not sign the transaction
+      //   [X] it reverts with a NotAuthorized error
+      // [X] given the earn authority does sign the transaction
+      //   [X] given the wrong ext_mint account is provided
+      //     [X] it reverts with an error (could be various depending on the account)
+      //   [X] given the wrong earn manager account is provided for an earner
+      //     [X] it reverts with a ConstraintSeeds error
+      //   [X] given the wrong token account is provided for the M vault
+      //     [X] it reverts with a ConstraintAssociated error
+      //   [X] given the earn manager token account does not match the token account stored on the earn manager account
+      //     [X] it reverts with an InvalidAccount error
+      //   [X] given the earner does not have a recipient token account defined
+      //     [X] given the user token account does not match the one defined on the earner account
+      //       [X] it reverts with an InvalidAccount error
+      //     [X] given the user token account matches the one defined on the earner account
+      //       [X] it mints yield to the user token account
+      //   [X] given the earner does have a recipient token account defined
+      //     [X] given the user token account does not match the recipient token account
+      //       [X] it reverts with an InvalidAccount error
+      //     [X] given the user token account matches the recipient token account
+      //       [X] it mints yield to the recipient token account
+      //   [X] given the accounts are all correct
+      //     [X] given the earner's last claim index is greater than or equal to the current global index
+      //       [X] it reverts with an AlreadyClaimed error
+      //     [X] given the M vault does not have enough M to mint the rewards against
+      //        (e.g. the yield for the vault hasn't been claimed yet or the provided balance is too high)
+      //       [X] it reverts with an InsufficientCollateral error
+      //     [X] given the earn manager has zero fee
+      //       [X] it mints all of the rewards to the earner's token account
+      //     [X] given the earn manager is not active and has a non-zero fee
+      //       [X] it mints all of the rewards to the earner's token account
+      //     [X] given the earn manager is active and has a non-zero fee
+      //       [ ] given the earn manager's fee token account is closed
+      //         [X] it mints all of the rewards to the earner's token account
+      //       [X] given the fee on the current yield rounds to zero
+      //         [X] it mints all of the rewards to the earner's token account
+      //       [X] given the fee does not round to zero
+      //         [X] it mints the fee to the earn manager's token account and the remaining rewards
+      //             to the earner's token account
+
+      beforeEach(async () => {
+        // Push the M yield to the M vault ATA
+        await mClaimFor(getMVault());
+
+        // Sync the latest index from the M earn program to have yield to claim
+        await sync();
+
+        // Add earner two as an earner after the sync so it does not have any yield to claim
+        await addEarner(earnManagerOne, earnerTwo.publicKey);
+      });
+
+      // given the earn authority does not sign the transaction
+      // it reverts with a NotAuthorized error
+      test('Earn authority does not sign the transaction - reverts', async () => {
+        // Setup the instruction to claim for earner one
+        await prepClaimFor(nonAdmin, earnerOne.publicKey, earnManagerOne.publicKey);
+
+        const balance = await getTokenBalance(await getATA(extMint.publicKey, earnerOne.publicKey));
+
+        // Attempt to send the transaction
+        // Expect a NotAuthorized error
+        await expectAnchorError(
+          extEarn.methods
+            .claimFor(balance)
+            .accounts({ ...accounts })
+            .signers([nonAdmin])
+            .rpc(),
+          'NotAuthorized',
+        );
+      });
+
+      // given the wrong ext mint account is provided
+      // it reverts with a InvalidAccount error
+      test('Ext mint account is invalid - reverts', async () => {
+        // Setup the instruction
+        await prepClaimFor(earnAuthority, earnerOne.publicKey, earnManagerOne.publicKey);
+
+        // Change the mint account
+        accounts.extMint = mMint.publicKey;
+
+        // Attempt to send the transaction
+        // Expect revert
+        await expectAnchorError(
+          extEarn.methods
+            .claimFor(await getTokenBalance(await getATA(extMint.publicKey, earnerOne.publicKey)))
+            .accounts({ ...accounts })
+            .signers([earnAuthority])
+            .rpc(),
+          'InvalidAccount',
+        );
+      });
+
+      // given the wrong earn manager account is provided for an earner
+      // it reverts with a ConstraintSeeds error
+      test('Earn manager account is invalid - reverts', async () => {
+        // Add another earn manager
+        await addEarnManager(earnManagerTwo.publicKey, new BN(0));
+
+        // Setup the instruction
+        await prepClaimFor(earnAuthority, earnerOne.publicKey, earnManagerTwo.publicKey);
+
+        // Attempt send the transaction
+        // Expect revert
+        await expectAnchorError(
+          extEarn.methods
+            .claimFor(await getTokenBalance(await getATA(extMint.publicKey, earnerOne.publicKey)))
+            .accounts({ ...accounts })
+            .signers([earnAuthority])
+            .rpc(),
+          'ConstraintSeeds',
+        );
+      });
+
+      // given the M vault token account is not the M vault's ATA
+      // it reverts with a ConstraintAssociated error
+      test('M Vault token account is invalid - reverts', async () => {
+        // Setup the instruction
+        await prepClaimFor(earnAuthority, earnerOne.publicKey, earnManagerOne.publicKey);
+
+        // Create a non-ATA token account for the M vault
+        const { tokenAccount: invalidMVaultTokenAccount } = await createTokenAccount(mMint.publicKey, getMVault());
+
+        // Replace the M vault token account with the invalid one
+        accounts.vaultMTokenAccount = invalidMVaultTokenAccount;
+
+        // Attempt to send the transaction
+        // Expect revert with a ConstraintAssociated error
+        await expectAnchorError(
+          extEarn.methods
+            .claimFor(await getTokenBalance(await getATA(extMint.publicKey, earnerOne.publicKey)))
+            .accounts({ ...accounts })
+            .signers([earnAuthority])
+            .rpc(),
+          'ConstraintAssociated',
+        );
+      });
+
+      // given the earn manager token account does not match token account stored on the earn manager account
+      // it reverts with an InvalidAccount error
+      test('Earn manager token account is invalid - reverts', async () => {
+        // Create a new token account for the earn manager that doesn't match the one stored
+        const { tokenAccount: invalidEarnManagerTokenAccount } = await createTokenAccount(
+          extMint.publicKey,
+          earnManagerOne.publicKey,
+        );
+
+        // Setup the instruction
+        await prepClaimFor(
+          earnAuthority,
+          earnerOne.publicKey,
+          earnManagerOne.publicKey,
+          undefined,
+          invalidEarnManagerTokenAccount,
+        );
+
+        // Attempt to send the transaction
+        // Expect revert with an InvalidAccount error
+        await expectAnchorError(
+          extEarn.methods
+            .claimFor(await getTokenBalance(await getATA(extMint.publicKey, earnerOne.publicKey)))
+            .accounts({ ...accounts })
+            .signers([earnAuthority])
+            .rpc(),
+          'InvalidAccount',
+        );
+      });
+
+      // given the earner does not have a recipient token account defined
+      // given the user token account does not match the user token account on the earner account
+      // it reverts with an InvalidAccount error
+      test("Earner has no recipient account, token account doesn't match - reverts", async () => {
+        // Create a new token account for the earner that doesn't match the one stored
+        const { tokenAccount: invalidUserTokenAccount } = await createTokenAccount(
+          extMint.publicKey,
+          earnerOne.publicKey,
+        );
+
+        // Setup the instruction with the invalid user token account
+        await prepClaimFor(earnAuthority, earnerOne.publicKey, earnManagerOne.publicKey, invalidUserTokenAccount);
+
+        // Attempt to send the transaction
+        // Expect revert with an InvalidAccount error
+        await expectAnchorError(
+          extEarn.methods
+            .claimFor(await getTokenBalance(invalidUserTokenAccount))
+            .accounts({ ...accounts })
+            .signers([earnAuthority])
+            .rpc(),
+          'InvalidAccount',
+        );
+      });
+
+      // given the earner does not have a recipient token account defined
+      // given the user token account matches
+      // it mints the yield to the user token account
+      test('Earner has no recipient account, token account matches - success', async () => {
+        // Setup the instruction
+        const { earnerAccount } = await prepClaimFor(earnAuthority, earnerOne.publicKey, earnManagerOne.publicKey);
+        const earnerATA = await getATA(extMint.publicKey, earnerOne.publicKey);
+
+        // Check that the last claim index and the last claim timestamp are the initial values
+        await expectEarnerState(earnerAccount, {
+          lastClaimIndex: initialIndex,
+          lastClaimTimestamp: startTime,
+        });
+
+        // Get the initial balance for the earner ata
+        const initialBalance = await getTokenBalance(earnerATA);
+
+        // Calculate the expected new balance
+        // Note: earn manager fee is 0, so it all goes to the earner
+        const expectedBalance = initialBalance.mul(newIndex).div(initialIndex);
+
+        // Send the instruction
+        await extEarn.methods
+          .claimFor(initialBalance)
+          .accounts({ ...accounts })
+          .signers([earnAuthority])
+          .rpc();
+
+        // Check the new balance matches the expected balance
+        await expectTokenBalance(earnerATA, expectedBalance);
+
+        // Check the earner account is updated
+        await expectEarnerState(earnerAccount, {
+          lastClaimIndex: newIndex,
+          lastClaimTimestamp: currentTime(),
+        });
+      });
+
+      test('Earner has recipient account, token account does not match - reverts', async () => {
+        // Set the earner's recipient account to the yield recipients ATA
+        const yieldRecipientATA = await getATA(extMint.publicKey, yieldRecipient.publicKey);
+        await setRecipient(earnerOne, yieldRecipientATA);
+
+        // Setup the instruction with the earner's ATA as the user token account
+        const earnerATA = await getATA(extMint.publicKey, earnerOne.publicKey);
+        await prepClaimFor(earnAuthority, earnerOne.publicKey, earnManagerOne.publicKey, earnerATA);
+
+        // Attempt to send the transaction
+        // Expect revert with an InvalidAccount error
+        await expectAnchorError(
+          extEarn.methods
+            .claimFor(await getTokenBalance(earnerATA))
+            .accounts({ ...accounts })
+            .signers([earnAuthority])
+            .rpc(),
+          'InvalidAccount',
+        );
+      });
+
+      test('Earner has recipient account, token account matches - success', async () => {
+        // Set the earner's recipient account to the yield recipients ATA
+        const yieldRecipientATA = await getATA(extMint.publicKey, yieldRecipient.publicKey);
+        await setRecipient(earnerOne, yieldRecipientATA);
+
+        // Setup the instruction
+        const { earnerAccount } = await prepClaimFor(
+          earnAuthority,
+          earnerOne.publicKey,
+          earnManagerOne.publicKey,
+          yieldRecipientATA,
+        );
+        const earnerATA = await getATA(extMint.publicKey, earnerOne.publicKey);
+
+        // Check that the last claim index and the last claim timestamp are the initial values
+        await expectEarnerState(earnerAccount, {
+          lastClaimIndex: initialIndex,
+          lastClaimTimestamp: startTime,
+        });
+
+        // Get the initial balance for the earner ata
+        const initialBalance = await getTokenBalance(earnerATA);
+
+        // Calculate the expected yield
+        // Note: earn manager fee is 0, so it all goes to the yield recipient
+        const expectedYield = initialBalance.mul(newIndex).div(initialIndex).sub(initialBalance);
+
+        // Send the instruction
+        await extEarn.methods
+          .claimFor(initialBalance)
+          .accounts({ ...accounts })
+          .signers([earnAuthority])
+          .rpc();
+
+        // Check the ata balance didn't change but the yield recipient received the yield
+        await expectTokenBalance(earnerATA, initialBalance);
+        await expectTokenBalance(yieldRecipientATA, expectedYield);
+
+        // Check the earner account is updated
+        await expectEarnerState(earnerAccount, {
+          lastClaimIndex: newIndex,
+          lastClaimTimestamp: currentTime(),
+        });
+      });
+
+      // given all accounts are correct
+      // given the earner's yield has been claimed up to the current index
+      // it reverts with an AlreadyClaimed error
+      test('Earner yield already claimed up to current index - reverts', async () => {
+        // Setup the instruction to claim for earner two
+        // earnerTwo was added after the sync, so its lastClaimIndex should equal the current index
+        const { earnerAccount } = await prepClaimFor(earnAuthority, earnerTwo.publicKey, earnManagerOne.publicKey);
+        const balance = await getTokenBalance(await getATA(extMint.publicKey, earnerTwo.publicKey));
+
+        // Verify that the earner's last claim index is equal to the current global index
+        await expectEarnerState(earnerAccount, {
+          lastClaimIndex: newIndex,
+          lastClaimTimestamp: currentTime(),
+        });
+
+        // Attempt to send the transaction
+        // Expect an AlreadyClaimed error
+        await expectAnchorError(
+          extEarn.methods
+            .claimFor(balance)
+            .accounts({ ...accounts })
+            .signers([earnAuthority])
+            .rpc(),
+          'AlreadyClaimed',
+        );
+      });
+
+      // given all the accounts are correct
+      // given the M vault doesn't have enough M collateral to mint the yield against
+      // it reverts with an InsufficientCollateral error
+      test('Insufficient M collateral to mint yield - reverts', async () => {
+        // Setup the instruction to claim for earner one
+        const { earnerAccount } = await prepClaimFor(earnAuthority, earnerOne.publicKey, earnManagerOne.publicKey);
+
+        // Get the actual balance of the earner's token account
+        const actualBalance = await getTokenBalance(await getATA(extMint.publicKey, earnerOne.publicKey));
+
+        // Use a balance 4x greater than the actual balance to try and mint more ext tokens than M available
+        const inflatedBalance = actualBalance.mul(new BN(4));
+
+        // Attempt to send the transaction with the inflated balance
+        // Expect an InsufficientCollateral error
+        await expectAnchorError(
+          extEarn.methods
+            .claimFor(inflatedBalance)
+            .accounts({ ...accounts })
+            .signers([earnAuthority])
+            .rpc(),
+          'InsufficientCollateral',
+        );
+      });
+
+      // given all the accounts are correct
+      // given the earn manager has zero fee
+      // it mints all the yield to the earner's recipient account
+      test('Earn manager fee is zero - success', async () => {
+        // Setup the instruction to claim for earner one
+        const { earnerAccount, userTokenAccount, earnManagerTokenAccount } = await prepClaimFor(
+          earnAuthority,
+          earnerOne.publicKey,
+          earnManagerOne.publicKey,
+        );
+
+        // Get the current balance of the earner's token account
+        const earnerStartBalance = await getTokenBalance(userTokenAccount);
+
+        // Get the current balance of the earn manager's token account
+        const earnManagerStartBalance = await getTokenBalance(earnManagerTokenAccount);
+
+        // Confirm the earn manager fee is zero
+        await expectEarnManagerState(getEarnManagerAccount(earnManagerOne.publicKey), {
+          feeBps: new BN(0),
+        });
+
+        // Confirm the starting earner account state
+        await expectEarnerState(earnerAccount, {
+          lastClaimIndex: initialIndex,
+          lastClaimTimestamp: startTime,
+        });
+
+        // Send the transaction
+        await extEarn.methods
+          .claimFor(earnerStartBalance)
+          .accounts({ ...accounts })
+          .signers([earnAuthority])
+          .rpc();
+
+        // Calculate expected rewards (balance * (global_index / last_claim_index) - balance)
+        const expectedRewards = earnerStartBalance.mul(newIndex).div(initialIndex).sub(earnerStartBalance);
+
+        // Verify the expected token balance changes
+        await expectTokenBalance(userTokenAccount, earnerStartBalance.add(expectedRewards));
+        await expectTokenBalance(earnManagerTokenAccount, earnManagerStartBalance);
+
+        // Verify the earner account was updated with the new claim index and claim timestamp
+        await expectEarnerState(earnerAccount, {
+          lastClaimIndex: newIndex,
+          lastClaimTimestamp: currentTime(),
+        });
+      });
+
+      // given all the accounts are correct
+      // given the earn manager fee is not zero and earn manager is not active
+      // it mints all the yield to the earner's recipient account
+      test('Earn manager fee is non-zero, earn manager inactive - success', async () => {
+        // Set the earn manager fee to a non-zero value
+        await configureEarnManager(earnManagerOne, new BN(1000));
+
+        // Remove the earn manager
+        await removeEarnManager(earnManagerOne.publicKey);
+
+        // Setup the instruction to claim for earner one
+        const { earnerAccount, userTokenAccount, earnManagerTokenAccount } = await prepClaimFor(
+          earnAuthority,
+          earnerOne.publicKey,
+          earnManagerOne.publicKey,
+        );
+
+        // Get the current balance of the earner's token account
+        const earnerStartBalance = await getTokenBalance(userTokenAccount);
+
+        // Get the current balance of the earn manager's token account
+        const earnManagerStartBalance = await getTokenBalance(earnManagerTokenAccount);
+
+        // Confirm the earn manager fee is non-zero and inactive
+        await expectEarnManagerState(getEarnManagerAccount(earnManagerOne.publicKey), {
+          feeBps: new BN(1000),
+          isActive: false,
+        });
+
+        // Confirm the starting earner account state
+        await expectEarnerState(earnerAccount, {
+          lastClaimIndex: initialIndex,
+          lastClaimTimestamp: startTime,
+        });
+
+        // Send the transaction
+        await extEarn.methods
+          .claimFor(earnerStartBalance)
+          .accounts({ ...accounts })
+          .signers([earnAuthority])
+          .rpc();
+
+        // Calculate expected rewards (balance * (global_index / last_claim_index) - balance)
+        const expectedRewards = earnerStartBalance.mul(newIndex).div(initialIndex).sub(earnerStartBalance);
+
+        // Verify the expected token balance changes
+        await expectTokenBalance(userTokenAccount, earnerStartBalance.add(expectedRewards));
+        await expectTokenBalance(earnManagerTokenAccount, earnManagerStartBalance);
+
+        // Verify the earner account was updated with the new claim index and claim timestamp
+        await expectEarnerState(earnerAccount, {
+          lastClaimIndex: newIndex,
+          lastClaimTimestamp: currentTime(),
+        });
+      });
+
+      // given all the accounts are correct
+      // given the earn manager fee is not zero and earn manager is active
+      // given the earn manager token account is closed
+      // it mints all the yield to the earner's recipient account
+      test('Earn manager fee is non-zero, earn manager active, earn manager token account closed - success', async () => {
+        // Set the earn manager fee to a non-zero value
+        await configureEarnManager(earnManagerOne, new BN(1000));
+
+        // Setup the instruction to claim for earner one
+        const { earnerAccount, userTokenAccount, earnManagerTokenAccount } = await prepClaimFor(
+          earnAuthority,
+          earnerOne.publicKey,
+          earnManagerOne.publicKey,
+        );
+
+        // Close the earn manager token account
+        await closeTokenAccount(earnManagerOne, earnManagerTokenAccount);
+
+        // Get the current balance of the earner's token account
+        const earnerStartBalance = await getTokenBalance(userTokenAccount);
+
+        // Confirm the earn manager fee is non-zero and inactive
+        await expectEarnManagerState(getEarnManagerAccount(earnManagerOne.publicKey), {
+          feeBps: new BN(1000),
+          isActive: true,
+        });
+
+        // Confirm the starting earner account state
+        await expectEarnerState(earnerAccount, {
+          lastClaimIndex: initialIndex,
+          lastClaimTimestamp: startTime,
+        });
+
+        // Send the transaction
+        await extEarn.methods
+          .claimFor(earnerStartBalance)
+          .accounts({ ...accounts })
+          .signers([earnAuthority])
+          .rpc();
+
+        // Calculate expected rewards (balance * (global_index / last_claim_index) - balance)
+        const expectedRewards = earnerStartBalance.mul(newIndex).div(initialIndex).sub(earnerStartBalance);
+
+        // Verify the expected token balance changes
+        await expectTokenBalance(userTokenAccount, earnerStartBalance.add(expectedRewards));
+
+        // Verify the earner account was updated with the new claim index and claim timestamp
+        await expectEarnerState(earnerAccount, {
+          lastClaimIndex: newIndex,
+          lastClaimTimestamp: currentTime(),
+        });
+      });
+
+      // given all the accounts are correct
+      // given the earn manager fee is not zero and earn manager is active
+      // given the fee amount rounds to zero
+      // it mints all the yield to the earner's recipient account
+      test('Earn manager fee is non-zero, earn manager active, fee amount rounds to zero - success', async () => {
+        // Set a very small fee (1 bps = 0.01%)
+        await configureEarnManager(earnManagerOne, new BN(1));
+
+        // Setup the accounts
+        const { earnerAccount, userTokenAccount, earnManagerTokenAccount } = await prepClaimFor(
+          earnAuthority,
+          earnerOne.publicKey,
+          earnManagerOne.publicKey,
+        );
+
+        // Get the earner starting balance (this is used to compare later)
+        const earnerStartBalance = await getTokenBalance(userTokenAccount);
+
+        // Get the earn manager token account starting balance
+        const earnManagerStartBalance = await getTokenBalance(earnManagerTokenAccount);
+
+        // Confirm the earn manager fee is non-zero and active
+        await expectEarnManagerState(getEarnManagerAccount(earnManagerOne.publicKey), {
+          feeBps: new BN(1),
+          isActive: true,
+        });
+
+        // Confirm the starting earner account state
+        await expectEarnerState(earnerAccount, {
+          lastClaimIndex: initialIndex,
+          lastClaimTimestamp: startTime,
+        });
+
+        // Send the transaction
+        // We use a smaller balance for the yield calculation here to make the fee round to zero
+        const snapshotBalance = new BN(10000);
+        await extEarn.methods
+          .claimFor(snapshotBalance)
+          .accounts({ ...accounts })
+          .signers([earnAuthority])
+          .rpc();
+
+        // Calculate expected rewards (balance * (global_index / last_claim_index) - balance)
+        const expectedRewards = snapshotBalance.mul(newIndex).div(initialIndex).sub(snapshotBalance);
+
+        // Verify the expected token balance changes
+        // Since fee rounds to zero, all rewards go to the earner
         await expectTokenBalance(userTokenAccount, earnerStartBalance.add(expectedRewards));
         await expectTokenBalance(earnManagerTokenAccount, earnManagerStartBalance);
 
-        // Verify the earner account was updated with the new claim index and claim timestamp
-        await expectEarnerState(earnerAccount, {
-          lastClaimIndex: newIndex,
-          lastClaimTimestamp: currentTime(),
-        });
+        // Verify the earner account was updated with the new claim index and claim timestamp
+        await expectEarnerState(earnerAccount, {
+          lastClaimIndex: newIndex,
+          lastClaimTimestamp: currentTime(),
+        });
+      });
+
+      // given all the accounts are correct
+      // given the earn manager fee is not zero and earn manager is active
+      // given the fee amount is not zero
+      // it mints the fee amount to the earn manager token account
+      // it mints the yield minus the fee amount to the earner's recipient account
+      test('Earn manager fee is non-zero, earn manager active, fee amount not zero - success', async () => {
+        // Configure the earn manager account with a 1% fee
+        const feeBps = new BN(100);
+        await configureEarnManager(earnManagerOne, feeBps);
+
+        // Setup the instruction to claim for earner one
+        const { earnerAccount, userTokenAccount, earnManagerTokenAccount } = await prepClaimFor(
+          earnAuthority,
+          earnerOne.publicKey,
+          earnManagerOne.publicKey,
+        );
+
+        // Get the current balance of the earner's token account
+        const earnerStartBalance = await getTokenBalance(userTokenAccount);
+
+        // Get the current balance of the earn manager's token account
+        const earnManagerStartBalance = await getTokenBalance(earnManagerTokenAccount);
+
+        // Confirm the earn manager fee is 1%
+        await expectEarnManagerState(getEarnManagerAccount(earnManagerOne.publicKey), {
+          feeBps,
+          isActive: true,
+        });
+
+        // Confirm the starting earner account state
+        await expectEarnerState(earnerAccount, {
+          lastClaimIndex: initialIndex,
+          lastClaimTimestamp: startTime,
+        });
+
+        // Send the transaction
+        const snapshotBalance = await getTokenBalance(await getATA(extMint.publicKey, earnerOne.publicKey));
+        await extEarn.methods
+          .claimFor(snapshotBalance)
+          .accounts({ ...accounts })
+          .signers([earnAuthority])
+          .rpc();
+
+        // Calculate expected rewards (balance * (global_index / last_claim_index) - balance)
+        const expectedRewards = snapshotBalance.mul(newIndex).div(initialIndex).sub(snapshotBalance);
+
+        // Calculate the fee amount (1% of rewards)
+        const feeAmount = expectedRewards.mul(feeBps).div(new BN(10000));
+
+        // Calculate the amount that should go to the earner
+        const earnerAmount = expectedRewards.sub(feeAmount);
+
+        // Verify the expected token balance changes
+        await expectTokenBalance(userTokenAccount, earnerStartBalance.add(earnerAmount));
+        await expectTokenBalance(earnManagerTokenAccount, earnManagerStartBalance.add(feeAmount));
+
+        // Verify the earner account was updated with the new claim index and claim timestamp
+        await expectEarnerState(earnerAccount, {
+          lastClaimIndex: newIndex,
+          lastClaimTimestamp: currentTime(),
+        });
+      });
+    });
+
+    describe('claim_for_batch unit tests', () => {
+      // test cases
+      // [X] given the earn authority does not sign the transaction
+      //   [X] it reverts with a NotAuthorized error
+      // [X] given the earn authority does sign the transaction
+      //   [X] given the remaining accounts do not match the snapshot balances
+      //     [X] it reverts with an InvalidParam error
+      //   [X] given the earn manager account does not belong to the earner
+      //     [X] it reverts with an InvalidAccount error
+      //   [X] given an earner is included twice
+      //     [X] it reverts with an AlreadyClaimed error
+      //   [X] given earners with different earn managers
+      //     [X] it mints the rewards to each earner and the fees to each earn manager
+
+      const feeBps = new BN(100); // 1%
+
+      beforeEach(async () => {
+        // Add a second earn manager with a fee and move earner two under it
+        await addEarnManager(earnManagerTwo.publicKey, feeBps);
+        await addEarner(earnManagerTwo, earnerTwo.publicKey);
+
+        // Push the M yield to the M vault ATA
+        await mClaimFor(getMVault());
+
+        // Sync the latest index from the M earn program to have yield to claim
+        await sync();
+      });
+
+      // given the earn authority does not sign the transaction
+      // it reverts with a NotAuthorized error
+      test('Earn authority does not sign the transaction - reverts', async () => {
+        // Setup the instruction
+        const { remainingAccounts } = await prepClaimForBatch(nonAdmin, [
+          { earner: earnerOne.publicKey, earnManager: earnManagerOne.publicKey },
+        ]);
+
+        // Attempt to send the transaction
+        await expectAnchorError(
+          extEarn.methods
+            .claimForBatch([mintAmount])
+            .accounts({ ...accounts })
+            .remainingAccounts(remainingAccounts)
+            .signers([nonAdmin])
+            .rpc(),
+          'NotAuthorized',
+        );
+      });
+
+      // given the remaining accounts do not match the snapshot balances
+      // it reverts with an InvalidParam error
+      test('Remaining accounts do not match snapshot balances - reverts', async () => {
+        // Setup the instruction with one claim
+        const { remainingAccounts } = await prepClaimForBatch(earnAuthority, [
+          { earner: earnerOne.publicKey, earnManager: earnManagerOne.publicKey },
+        ]);
+
+        // Attempt to send two snapshot balances
+        await expectAnchorError(
+          extEarn.methods
+            .claimForBatch([mintAmount, mintAmount])
+            .accounts({ ...accounts })
+            .remainingAccounts(remainingAccounts)
+            .signers([earnAuthority])
+            .rpc(),
+          'InvalidParam',
+        );
+      });
+
+      // given the earn manager account does not belong to the earner
+      // it reverts with an InvalidAccount error
+      test('Earn manager does not match the earner - reverts', async () => {
+        // Setup the instruction with earner one under the wrong earn manager
+        const { remainingAccounts } = await prepClaimForBatch(earnAuthority, [
+          { earner: earnerOne.publicKey, earnManager: earnManagerTwo.publicKey },
+        ]);
+
+        // Attempt to send the transaction
+        await expectAnchorError(
+          extEarn.methods
+            .claimForBatch([mintAmount])
+            .accounts({ ...accounts })
+            .remainingAccounts(remainingAccounts)
+            .signers([earnAuthority])
+            .rpc(),
+          'InvalidAccount',
+        );
+      });
+
+      // given an earner is included twice
+      // it reverts with an AlreadyClaimed error
+      test('Duplicate earner in batch - reverts', async () => {
+        // Setup the instruction with earner one twice
+        const { remainingAccounts } = await prepClaimForBatch(earnAuthority, [
+          { earner: earnerOne.publicKey, earnManager: earnManagerOne.publicKey },
+          { earner: earnerOne.publicKey, earnManager: earnManagerOne.publicKey },
+        ]);
+
+        // Attempt to send the transaction
+        await expectAnchorError(
+          extEarn.methods
+            .claimForBatch([mintAmount, mintAmount])
+            .accounts({ ...accounts })
+            .remainingAccounts(remainingAccounts)
+            .signers([earnAuthority])
+            .rpc(),
+          'AlreadyClaimed',
+        );
+      });
+
+      // given earners with different earn managers
+      // it mints the rewards to each earner and the fees to each earn manager
+      test('Claim for batch with different earn managers - success', async () => {
+        const earnerOneATA = await getATA(extMint.publicKey, earnerOne.publicKey);
+        const earnerTwoATA = await getATA(extMint.publicKey, earnerTwo.publicKey);
+        const earnManagerTwoATA = await getATA(extMint.publicKey, earnManagerTwo.publicKey);
+
+        // Setup the instruction
+        const { remainingAccounts } = await prepClaimForBatch(earnAuthority, [
+          { earner: earnerOne.publicKey, earnManager: earnManagerOne.publicKey },
+          { earner: earnerTwo.publicKey, earnManager: earnManagerTwo.publicKey },
+        ]);
+
+        // Send the transaction
+        await extEarn.methods
+          .claimForBatch([mintAmount, mintAmount])
+          .accounts({ ...accounts })
+          .remainingAccounts(remainingAccounts)
+          .signers([earnAuthority])
+          .rpc();
+
+        // Calculate expected rewards (balance * (global_index / last_claim_index) - balance)
+        const expectedRewards = mintAmount.mul(newIndex).div(initialIndex).sub(mintAmount);
+        const feeAmount = expectedRewards.mul(feeBps).div(new BN(10000));
+
+        // Earner one's earn manager takes no fee, earner two's takes 1%
+        await expectTokenBalance(earnerOneATA, mintAmount.add(expectedRewards));
+        await expectTokenBalance(earnerTwoATA, mintAmount.add(expectedRewards).sub(feeAmount));
+        await expectTokenBalance(earnManagerTwoATA, feeAmount);
+
+        // Verify both earners were advanced to the new index
+        await expectEarnerState(getExtEarnerAccount(earnerOneATA), { lastClaimIndex: newIndex });
+        await expectEarnerState(getExtEarnerAccount(earnerTwoATA), { lastClaimIndex: newIndex });
+      });
+    });
+
+    describe('claim_excess unit tests', () => {
+      // test cases
+      // [X] given the earn authority does not sign the transaction
+      //   [X] it reverts with a NotAuthorized error
+      // [X] given the earn authority does sign the transaction
+      //   [X] given the ext index is behind the M earn index
+      //     [X] it reverts with an IndexNotSynced error
+      //   [X] given an earner has not been claimed for the current index
+      //     [X] given the claims timeout has not passed
+      //       [X] it reverts with a ClaimsPending error
+      //     [X] given the claims timeout has passed
+      //       [X] it transfers the excess M to the yield recipient
+      //   [X] given legacy earners have not been migrated
+      //     [X] it reverts with a ClaimsPending error
+      //   [X] given all earners have been claimed for the current index
+      //     [X] it transfers the excess M to the yield recipient
+
+      beforeEach(async () => {
+        // Send the excess to the M token account of the yield recipient
+        await setYieldRecipient(new BN(0));
+
+        // Push the M yield to the M vault ATA
+        await mClaimFor(getMVault());
+      });
+
+      // given the earn authority does not sign the transaction
+      // it reverts with a NotAuthorized error
+      test('earn authority does not sign - reverts', async () => {
+        // Sync the index so that only the signer is invalid
+        await sync();
+
+        // Setup the instruction
+        await prepClaimExcess(nonAdmin);
+
+        // Attempt to send the transaction
+        // Expect a NotAuthorized error
+        await expectAnchorError(
+          extEarn.methods
+            .claimExcess()
+            .accounts({ ...accounts })
+            .signers([nonAdmin])
+            .rpc(),
+          'NotAuthorized',
+        );
+      });
+
+      // given the earn authority does sign the transaction
+      // given the ext index is behind the M earn index
+      // it reverts with an IndexNotSynced error
+      test('index not synced - reverts', async () => {
+        // Setup the instruction
+        await prepClaimExcess(earnAuthority);
+
+        // Attempt to send the transaction
+        // Expect an IndexNotSynced error
+        await expectAnchorError(
+          extEarn.methods
+            .claimExcess()
+            .accounts({ ...accounts })
+            .signers([earnAuthority])
+            .rpc(),
+          'IndexNotSynced',
+        );
+      });
+
+      // given the earn authority does sign the transaction
+      // given an earner has not been claimed for the current index
+      // given the claims timeout has not passed
+      // it reverts with a ClaimsPending error
+      test('earner not claimed for the current index - reverts', async () => {
+        // Sync the index, earner one is owed yield for it
+        await sync();
+
+        // Confirm earner one is counted as not claimed
+        await expectExtGlobalState(getExtGlobalAccount(), {
+          earners: new BN(1),
+          claimedEarners: new BN(0),
+        });
+
+        // Setup the instruction
+        await prepClaimExcess(earnAuthority);
+
+        // Attempt to send the transaction
+        // Expect a ClaimsPending error
+        await expectAnchorError(
+          extEarn.methods
+            .claimExcess()
+            .accounts({ ...accounts })
+            .signers([earnAuthority])
+            .rpc(),
+          'ClaimsPending',
+        );
+      });
+
+      // given the earn authority does sign the transaction
+      // given an earner has not been claimed for the current index
+      // given the claims timeout has passed
+      // it transfers the excess M to the yield recipient
+      test('claims timeout passed - success', async () => {
+        // Unclaimed earners only block claim_excess for an hour after the index update
+        const claimsTimeout = new BN(3600);
+        await setYieldRecipient(new BN(0), undefined, claimsTimeout);
+
+        // Sync the index, earner one is owed yield for it
+        await sync();
+
+        // Move past the claims timeout
+        const { timestamp } = await extEarn.account.extGlobal.fetch(getExtGlobalAccount());
+        warp(timestamp.add(claimsTimeout), false);
+
+        // Setup the instruction
+        const { yieldRecipientTokenAccount } = await prepClaimExcess(earnAuthority);
+
+        // Send the transaction
+        await extEarn.methods
+          .claimExcess()
+          .accounts({ ...accounts })
+          .signers([earnAuthority])
+          .rpc();
+
+        // Check the excess was sent even though earner one has not been claimed for
+        const excess = await getTokenBalance(yieldRecipientTokenAccount);
+        expect(excess.gtn(0)).toBe(true);
+      });
+
+      // given the earn authority does sign the transaction
+      // given legacy earners have not been migrated
+      // it reverts with a ClaimsPending error
+      test('legacy earners not migrated - reverts', async () => {
+        // Sync the index and claim for earner one
+        await sync();
+        await claimFor(earnerOne.publicKey, earnManagerOne.publicKey);
+
+        // An earner of the legacy extension has not been migrated and is not counted yet
+        await setExtAccountState(getExtGlobalAccount(), 'ExtGlobal', { legacy: true, legacyEarners: new BN(1) });
+
+        // Setup the instruction
+        await prepClaimExcess(earnAuthority);
+
+        // Attempt to send the transaction
+        // Expect a ClaimsPending error
+        await expectAnchorError(
+          extEarn.methods
+            .claimExcess()
+            .accounts({ ...accounts })
+            .signers([earnAuthority])
+            .rpc(),
+          'ClaimsPending',
+        );
+      });
+
+      // given the earn authority does sign the transaction
+      // given all earners have been claimed for the current index
+      // it transfers the excess M to the yield recipient
+      test('claim_excess - success', async () => {
+        // Sync the index and claim for earner one
+        await sync();
+        await claimFor(earnerOne.publicKey, earnManagerOne.publicKey);
+
+        // Earners added after the sync have nothing to claim
+        await addEarner(earnManagerOne, earnerTwo.publicKey);
+
+        await expectExtGlobalState(getExtGlobalAccount(), {
+          earners: new BN(2),
+          claimedEarners: new BN(2),
+        });
+
+        // Setup the instruction
+        const { vaultMTokenAccount, yieldRecipientTokenAccount } = await prepClaimExcess(earnAuthority);
+
+        // The excess is the vault balance above the ext supply
+        const collateral = await getTokenBalance(vaultMTokenAccount!);
+        const { supply } = await getMint(provider.connection, extMint.publicKey, undefined, TOKEN_2022_PROGRAM_ID);
+        const expectedExcess = collateral.sub(new BN(supply.toString()));
+
+        // Send the transaction
+        await extEarn.methods
+          .claimExcess()
+          .accounts({ ...accounts })
+          .signers([earnAuthority])
+          .rpc();
+
+        // Check the excess was sent to the yield recipient and the vault is fully collateralized
+        await expectTokenBalance(yieldRecipientTokenAccount, expectedExcess);
+        await expectTokenBalance(vaultMTokenAccount!, new BN(supply.toString()));
+      });
+    });
+
+    describe('preview_claim unit tests', () => {
+      // test cases
+      // [X] given the earner has already claimed for the current index
+      //   [X] it reports an AlreadyClaimed failure
+      // [X] given the rewards would exceed the collateral in the M vault
+      //   [X] it reports an InsufficientCollateral failure
+      // [X] given the earner has yield to claim
+      //   [X] it reports the rewards without a failure
+
+      beforeEach(async () => {
+        // Push the M yield to the M vault ATA
+        await mClaimFor(getMVault());
+
+        // Sync the latest index from the M earn program to have yield to claim
+        await sync();
+      });
+
+      // given the earner has already claimed for the current index
+      // it reports an AlreadyClaimed failure
+      test('earner already claimed - reports AlreadyClaimed', async () => {
+        // Claim for earner one
+        await claimFor(earnerOne.publicKey, earnManagerOne.publicKey);
+
+        // Preview the claim
+        await prepPreviewClaim(earnerOne.publicKey);
+
+        const preview = await extEarn.methods
+          .previewClaim(mintAmount)
+          .accounts({ ...accounts })
+          .view();
+
+        expect(preview.failure).toEqual({ alreadyClaimed: {} });
+        expect(preview.amount.toString()).toEqual('0');
+      });
+
+      // given the rewards would exceed the collateral in the M vault
+      // it reports an InsufficientCollateral failure
+      test('rewards exceed the collateral - reports InsufficientCollateral', async () => {
+        // Preview the claim with a snapshot balance far above the collateral
+        await prepPreviewClaim(earnerOne.publicKey);
+
+        const preview = await extEarn.methods
+          .previewClaim(mintAmount.muln(10))
+          .accounts({ ...accounts })
+          .view();
+
+        expect(preview.failure).toEqual({ insufficientCollateral: {} });
+      });
+
+      // given the earner has yield to claim
+      // it reports the rewards without a failure
+      test('earner has yield - reports rewards', async () => {
+        // Preview the claim
+        await prepPreviewClaim(earnerOne.publicKey);
+
+        const preview = await extEarn.methods
+          .previewClaim(mintAmount)
+          .accounts({ ...accounts })
+          .view();
+
+        // 10% of the snapshot balance, the earn manager takes no fee
+        expect(preview.failure).toBeNull();
+        expect(preview.rewards.toString()).toEqual('10000000');
+        expect(preview.fee.toString()).toEqual('0');
+        expect(preview.amount.toString()).toEqual('10000000');
+      });
+    });
+  });
+
+  describe('earn_manager instruction tests', () => {
+    beforeEach(async () => {
+      // Initialize the program
+      await initializeExt(earnAuthority.publicKey);
+
+      // Add an earn manager
+      await addEarnManager(earnManagerOne.publicKey, new BN(0));
+
+      // Add an earner
+      await addEarner(earnManagerOne, earnerOne.publicKey);
+    });
+
+    describe('add_earner unit tests', () => {
+      // test cases
+      // [X] given signer does not have an earn manager account initialized
+      //   [X] it reverts with an account not initialized error
+      // [X] given signer has an earn manager account initialized
+      //   [X] given earn manager account is not active
+      //     [X] it reverts with a NotAuthorized error
+      //   [X] given earn manager account is active
+      //     [X] given the earner already has an earner account
+      //       [X] it reverts with an account already initialized error
+      //     [X] given the earner does not already have an earner account
+      //       [X] given user token account is for the wrong token mint
+      //         [X] it reverts with an address constraint error
+      //       [X] given user token account authority does not match the user pubkey
+      //         [X] it reverts with an address constraint error
+      //       [X] given the user token account is for the correct token mint and the authority is the user pubkey
+      //         [X] it creates the earner account
+      //         [X] it sets the user to the provided pubkey
+      //         [X] it sets the user_token_account to the provided token account
+      //         [X] it sets the earner is_active flag to true
+      //         [X] it sets the earn_manager to the provided earn manager pubkey
+      //         [X] it sets the last_claim_index to the current index
+      //         [X] it sets the last_claim_timestamp to the current timestamp
+
+      // given signer does not have an earn manager account initialized
+      // it reverts with an account not initialized error
+      test('Signer earn manager account not initialized - reverts', async () => {
+        // Get the ATA for earner two
+        const earnerTwoATA = await getATA(extMint.publicKey, earnerTwo.publicKey);
+
+        // Setup the instruction
+        prepAddEarner(nonEarnManagerOne, nonEarnManagerOne.publicKey, earnerTwoATA);
+
+        // Attempt to add earner without an initialized earn manager account
+        await expectAnchorError(
+          extEarn.methods
+            .addEarner(nonEarnerOne.publicKey)
+            .accounts({ ...accounts })
+            .signers([nonEarnManagerOne])
+            .rpc(),
+          'AccountNotInitialized',
+        );
+      });
+
+      // given signer has an earn manager account initialized
+      // given earn manager account is not active
+      // it reverts with a NotActive error
+      test("Signer's earn manager account not active - reverts", async () => {
+        // Get the ATA for earner two
+        const earnerTwoATA = await getATA(extMint.publicKey, earnerTwo.publicKey);
+
+        // Remove the earn manager one's account (set it to inactive)
+        await removeEarnManager(earnManagerOne.publicKey);
+
+        // Setup the instruction
+        prepAddEarner(earnManagerOne, earnManagerOne.publicKey, earnerTwoATA);
+
+        // Attempt to add earner with an inactive earn manager account
+        await expectAnchorError(
+          extEarn.methods
+            .addEarner(earnerTwo.publicKey)
+            .accounts({ ...accounts })
+            .signers([earnManagerOne])
+            .rpc(),
+          'NotActive',
+        );
+      });
+
+      // given signer has an earn manager account initialized
+      // given earn manager account is active
+      // given earner already has an earner account
+      // it reverts with an account already initialized error
+      test('Earner account already initialized - reverts', async () => {
+        // Get the ATA for earner one
+        const earnerOneATA = await getATA(extMint.publicKey, earnerOne.publicKey);
+
+        // Setup the instruction
+        prepAddEarner(earnManagerOne, earnManagerOne.publicKey, earnerOneATA);
+
+        // Attempt to add earner with an already initialized earner account
+        await expectSystemError(
+          extEarn.methods
+            .addEarner(earnerOne.publicKey)
+            .accounts({ ...accounts })
+            .signers([earnManagerOne])
+            .rpc(),
+        );
+      });
+
+      // given signer has an earn manager account initialized
+      // given earn manager account is active
+      // given the earner does not already have an earner account
+      // given user token account is for the wrong token mint
+      // it reverts with an token mint constraint error
+      test('User token account is for the wrong token mint - reverts', async () => {
+        // Create a new mint for the user token account
+        const wrongMint = new Keypair();
+        await createMint(wrongMint, nonAdmin.publicKey);
+
+        // Get the ATA for earner two on the wrong mint
+        const earnerTwoATA = await getATA(wrongMint.publicKey, earnerTwo.publicKey);
+
+        // Setup the instruction
+        prepAddEarner(earnManagerOne, earnManagerOne.publicKey, earnerTwoATA);
+
+        // Attempt to add earner with user token account for wrong token mint
+        await expectAnchorError(
+          extEarn.methods
+            .addEarner(earnerTwo.publicKey)
+            .accounts({ ...accounts })
+            .signers([earnManagerOne])
+            .rpc(),
+          'ConstraintTokenMint',
+        );
+      });
+
+      // given signer has an earn manager account initialized
+      // given earn manager account is active
+      // given the earner does not already have an earner account
+      // given user token account authority does not match the user pubkey
+      // it reverts with an address constraint error
+      test('User token account authority does not match user pubkey - reverts', async () => {
+        // Get the ATA for random user (not the same as the user)
+        const randomATA = await getATA(extMint.publicKey, nonAdmin.publicKey);
+
+        // Setup the instruction
+        prepAddEarner(earnManagerOne, earnManagerOne.publicKey, randomATA);
+
+        // Attempt to add earner with user token account for wrong token mint
+        await expectAnchorError(
+          extEarn.methods
+            .addEarner(earnerTwo.publicKey)
+            .accounts({ ...accounts })
+            .signers([earnManagerOne])
+            .rpc(),
+          'ConstraintTokenOwner',
+        );
+      });
+
+      test('Add earner - success', async () => {
+        const tokenAccountKeypair = Keypair.generate();
+        const tokenAccountLen = getAccountLen([ExtensionType.ImmutableOwner]);
+        const lamports = await provider.connection.getMinimumBalanceForRentExemption(tokenAccountLen);
+
+        // Create token account with the immutable owner extension
+        const transaction = new Transaction().add(
+          SystemProgram.createAccount({
+            fromPubkey: earnManagerOne.publicKey,
+            newAccountPubkey: tokenAccountKeypair.publicKey,
+            space: tokenAccountLen,
+            lamports,
+            programId: TOKEN_2022_PROGRAM_ID,
+          }),
+          createInitializeImmutableOwnerInstruction(tokenAccountKeypair.publicKey, TOKEN_2022_PROGRAM_ID),
+          createInitializeAccountInstruction(
+            tokenAccountKeypair.publicKey,
+            extMint.publicKey,
+            earnerTwo.publicKey,
+            TOKEN_2022_PROGRAM_ID,
+          ),
+        );
+
+        await provider.send!(transaction, [earnManagerOne, tokenAccountKeypair]);
+
+        // Setup the instruction
+        const { earnerAccount } = prepAddEarner(
+          earnManagerOne,
+          earnManagerOne.publicKey,
+          tokenAccountKeypair.publicKey,
+        );
+
+        // Add earner two to the earn manager's list
+        await extEarn.methods
+          .addEarner(earnerTwo.publicKey)
+          .accounts({ ...accounts })
+          .signers([earnManagerOne])
+          .rpc();
+
+        // Verify the earner account was initialized correctly
+        await expectEarnerState(earnerAccount, {
+          earnManager: earnManagerOne.publicKey,
+          lastClaimIndex: initialIndex,
+          lastClaimTimestamp: currentTime(),
+          user: earnerTwo.publicKey,
+          userTokenAccount: tokenAccountKeypair.publicKey,
+        });
+      });
+
+      // given signer has an earn manager account initialized
+      // given earn manager account is active
+      // given the earner does not already have an earner account
+      // given user token account is for the correct token mint and the authority is the signer
+      // it creates the earner account
+      // it sets the earner is_active flag to true
+      // it sets the earn_manager to the provided earn manager pubkey
+      // it sets the last_claim_index to the current index
+      // it sets the last_claim_timestamp to the current time
+      test('Add earner ata - success', async () => {
+        // Get the ATA for earner two
+        const earnerTwoATA = await getATA(extMint.publicKey, earnerTwo.publicKey);
+
+        // Setup the instruction
+        const { earnerAccount } = prepAddEarner(earnManagerOne, earnManagerOne.publicKey, earnerTwoATA);
+
+        // Add earner one to the earn manager's list
+        await extEarn.methods
+          .addEarner(earnerTwo.publicKey)
+          .accounts({ ...accounts })
+          .signers([earnManagerOne])
+          .rpc();
+
+        // Verify the earner account was initialized correctly
+        await expectEarnerState(earnerAccount, {
+          earnManager: earnManagerOne.publicKey,
+          lastClaimIndex: initialIndex,
+          lastClaimTimestamp: currentTime(),
+          user: earnerTwo.publicKey,
+          userTokenAccount: earnerTwoATA,
+        });
+      });
+    });
+
+    describe('configure_earn_manager unit tests', () => {});
+
+    describe('remove_earner unit tests', () => {
+      // test cases
+      // [X] given signer does not have an earn manager account initialized
+      //   [X] it reverts with an account not initialized error
+      // [X] given signer has an earn manager account initialized
+      //   [X] given earn manager account is not active
+      //     [X] it reverts with a NotAuthorized error
+      //   [X] given earn manager account is active
+      //     [X] given the earner account does not have an earn manager
+      //       [X] it reverts with a NotAuthorized error
+      //     [X] given the earner account has an earn manager
+      //       [X] given the earner's earn manager is not the signer
+      //         [X] it reverts with a NotAuthorized error
+      //       [X] given the earner's earn manager is the signer
+      //         [X] the earner account is closed and the signer refunded the rent
+
+      // given signer does not have an earn manager account initialized
+      // it reverts with an account not initialized error
+      test('Signer earn manager account not initialized - reverts', async () => {
+        // Get the ATA for earner one
+        const earnerOneATA = await getATA(extMint.publicKey, earnerOne.publicKey);
+
+        // Setup the instruction
+        prepRemoveEarner(nonEarnManagerOne, nonEarnManagerOne.publicKey, earnerOneATA);
+
+        // Attempt to remove earner without an initialized earn manager account
+        await expectAnchorError(
+          extEarn.methods
+            .removeEarner()
+            .accounts({ ...accounts })
+            .signers([nonEarnManagerOne])
+            .rpc(),
+          'AccountNotInitialized',
+        );
+      });
+
+      // given signer has an earn manager account initialized
+      // given earn manager account is not active
+      // it reverts with a NotActive error
+      test("Signer's earn manager account not active - reverts", async () => {
+        // Get the ATA for earner one
+        const earnerOneATA = await getATA(extMint.publicKey, earnerOne.publicKey);
+
+        // Remove the earn manager account (set it to inactive)
+        await removeEarnManager(earnManagerOne.publicKey);
+
+        // Setup the instruction
+        prepRemoveEarner(earnManagerOne, earnManagerOne.publicKey, earnerOneATA);
+
+        // Attempt to remove earner with an inactive earn manager account
+        await expectAnchorError(
+          extEarn.methods
+            .removeEarner()
+            .accounts({ ...accounts })
+            .signers([earnManagerOne])
+            .rpc(),
+          'NotActive',
+        );
+      });
+
+      // given signer has an earn manager account initialized
+      // given earn manager account is active
+      // given the earner account has an earn manager
+      // given the earner's earn manager is not the signer
+      // it reverts with a NotAuthorized error
+      test("Earner's earn manager is not signer - reverts", async () => {
+        // Add earner manager two
+        await addEarnManager(earnManagerTwo.publicKey, new BN(100));
+
+        // Get the ATA for earner one
+        const earnerOneATA = await getATA(extMint.publicKey, earnerOne.publicKey);
+
+        // Setup the instruction
+        prepRemoveEarner(earnManagerTwo, earnManagerTwo.publicKey, earnerOneATA);
+
+        // Attempt to remove earner with the wrong earn manager
+        await expectAnchorError(
+          extEarn.methods
+            .removeEarner()
+            .accounts({ ...accounts })
+            .signers([earnManagerTwo])
+            .rpc(),
+          'NotAuthorized',
+        );
+      });
+
+      // given signer has an earn manager account initialized
+      // given earn manager account is active
+      // given the earner account has an earn manager
+      // given the earner's earn manager is the signer
+      // it closes the earner account and refunds the rent
+      test("Earner's earn manager is signer - success", async () => {
+        // Get the ATA for earner one
+        const earnerOneATA = await getATA(extMint.publicKey, earnerOne.publicKey);
+
+        // Setup the instruction
+        const { earnerAccount } = prepRemoveEarner(earnManagerOne, earnManagerOne.publicKey, earnerOneATA);
+
+        // Remove the earner account
+        await extEarn.methods
+          .removeEarner()
+          .accounts({ ...accounts })
+          .signers([earnManagerOne])
+          .rpc();
+
+        // Verify the earner account was closed
+        expectAccountEmpty(earnerAccount);
+      });
+    });
+
+    describe('transfer_earner unit tests', () => {
+      // test cases
+      // [X] given the earner does not have an account initialized
+      //   [X] it reverts with an AccountNotInitialized error
+      // [X] given the to earn manager does not have an account initialized
+      //   [X] it reverts with an AccountNotInitialized error
+      // [X] given the from earn manager does not sign the transaction
+      //   [X] it reverts with a NotAuthorized error
+      // [X] given the from earn manager does sign the transaction
+      //   [X] given the from earn manager is not active
+      //     [X] it reverts with a NotActive error
+      //   [X] given the to earn manager is not active
+      //     [X] it reverts with a NotActive error
+      //   [X] given all the accounts are correct and earn managers are active
+      //     [X] it updates the earner's earn manager to the "to earn manager"
+
+      beforeEach(async () => {
+        // Add second earn manager to have someone to transfer to
+        await addEarnManager(earnManagerTwo.publicKey, new BN(100));
+      });
+
+      // given the earner does not have an account initialized
+      // it reverts with an AccounbtNotInitialized error
+      test('earner account not initialized - reverts', async () => {
+        const nonEarnerOneATA = await getATA(extMint.publicKey, nonEarnerOne.publicKey);
+
+        // Setup the instruction
+        prepTransferEarner(earnManagerOne, earnManagerOne.publicKey, earnManagerTwo.publicKey, nonEarnerOneATA);
+
+        // Attempt to transfer earner without an initialized account
+        await expectAnchorError(
+          extEarn.methods
+            .transferEarner(earnManagerTwo.publicKey)
+            .accounts({ ...accounts })
+            .signers([earnManagerOne])
+            .rpc(),
+          'AccountNotInitialized',
+        );
+      });
+
+      // given the to earn manager does not have an account initialized
+      // it reverts with an AccountNotInitialized error
+      test('to_earn_manager account not initialized - reverts', async () => {
+        const earnerOneATA = await getATA(extMint.publicKey, earnerOne.publicKey);
+
+        // Setup the instruction
+        prepTransferEarner(earnManagerOne, earnManagerOne.publicKey, nonEarnManagerOne.publicKey, earnerOneATA);
+
+        // Attempt to transfer earner to a non-initialized earn manager account
+        await expectAnchorError(
+          extEarn.methods
+            .transferEarner(nonEarnManagerOne.publicKey)
+            .accounts({ ...accounts })
+            .signers([earnManagerOne])
+            .rpc(),
+          'AccountNotInitialized',
+        );
+      });
+
+      // given the from earn manager does not sign the transaction
+      // it reverts with a NotAuthorized error
+      test('from earn manager does not sign transaction - reverts', async () => {
+        const earnerOneATA = await getATA(extMint.publicKey, earnerOne.publicKey);
+
+        // Setup the instruction
+        prepTransferEarner(nonAdmin, earnManagerOne.publicKey, earnManagerTwo.publicKey, earnerOneATA);
+
+        // Attempt to transfer earner with a non-authorized signer
+        await expectAnchorError(
+          extEarn.methods
+            .transferEarner(earnManagerTwo.publicKey)
+            .accounts({ ...accounts })
+            .signers([nonAdmin])
+            .rpc(),
+          'NotAuthorized',
+        );
+      });
+
+      // given the from earn manager does sign the transaction
+      // given the from earn manager is not active
+      // it reverts with a NotActive
+      test('from earn manager is not active - reverts', async () => {
+        const earnerOneATA = await getATA(extMint.publicKey, earnerOne.publicKey);
+
+        // Remove the earn manager account (set it to inactive)
+        await removeEarnManager(earnManagerOne.publicKey);
+
+        // Setup the instruction
+        prepTransferEarner(earnManagerOne, earnManagerOne.publicKey, earnManagerTwo.publicKey, earnerOneATA);
+
+        // Attempt to transfer earner with an inactive earn manager account
+        await expectAnchorError(
+          extEarn.methods
+            .transferEarner(earnManagerTwo.publicKey)
+            .accounts({ ...accounts })
+            .signers([earnManagerOne])
+            .rpc(),
+          'NotActive',
+        );
+      });
+
+      // given the from earn manager signs the transaction
+      // given the to earn manager is not active
+      // it reverts with a NotActive error
+      test('to earn manager is not active - reverts', async () => {
+        const earnerOneATA = await getATA(extMint.publicKey, earnerOne.publicKey);
+
+        // Remove the to earn manager account (set it to inactive)
+        await removeEarnManager(earnManagerTwo.publicKey);
+
+        // Setup the instruction
+        prepTransferEarner(earnManagerOne, earnManagerOne.publicKey, earnManagerTwo.publicKey, earnerOneATA);
+
+        // Attempt to transfer earner with an inactive to earn manager account
+        await expectAnchorError(
+          extEarn.methods
+            .transferEarner(earnManagerTwo.publicKey)
+            .accounts({ ...accounts })
+            .signers([earnManagerOne])
+            .rpc(),
+          'NotActive',
+        );
+      });
+
+      // given the from earn manager signs the transaction
+      // given the accounts are correct and the earn managers are active
+      // it updates the earner's earn manager to the "to earn manager"
+      test('transfer_earner - success', async () => {
+        const earnerOneATA = await getATA(extMint.publicKey, earnerOne.publicKey);
+
+        // Setup the instruction
+        const { earnerAccount } = prepTransferEarner(
+          earnManagerOne,
+          earnManagerOne.publicKey,
+          earnManagerTwo.publicKey,
+          earnerOneATA,
+        );
+
+        // Confirm the earner's earn manager is currently earnManagerOne
+        await expectEarnerState(earnerAccount, {
+          earnManager: earnManagerOne.publicKey,
+        });
+
+        // Transfer the earner from earn manager one to earn manager two
+        await extEarn.methods
+          .transferEarner(earnManagerTwo.publicKey)
+          .accounts({ ...accounts })
+          .signers([earnManagerOne])
+          .rpc();
+
+        // Verify the earner account was updated
+        await expectEarnerState(earnerAccount, {
+          earnManager: earnManagerTwo.publicKey,
+        });
+      });
+    });
+
+    describe('configure_earn_manager unit tests', () => {
+      // test cases
+      // [X] given the earn manager account does not match the signer
+      //   [X] it reverts with an address constraint error
+      // [X] given the earn manager account matches the signer
+      //   [X] given the fee basis points is greater than 100_00
+      //     [X] it reverts with an InvalidParam error
+      //   [X] given the fee basis points is less than or equal to 100_00
+      //     [X] given the fee_token_account is for the wrong token mint
+      //       [X] it reverts with an address constraint error
+      //     [X] given the fee_token_account is for the correct token mint
+      //       [X] given the earn manager account has not been initialized
+      //         [X] it reverts with an AccountNotInitialized error
+      //       [X] given the earn manager account has been initialized
+      //         [X] given the fee_bps is null and the fee_token_account is null
+      //           [X] nothing is updated
+      //         [X] given the fee_bps is null and the fee_token_account is not null
+      //           [X] it updates the fee_token_account to the provided token account
+      //         [X] given the fee_bps is not null and the fee_token_account is null
+      //           [X] it updates the fee_bps to the provided value
+      //         [X] given the fee_bps is not null and the fee_token_account is not null
+      //           [X] it updates the fee_bps to the provided value
+      //           [X] it updates the fee_token_account to the provided token account
+
+      // given the earn manager account does not match the signer
+      // it reverts with a seeds constraint error
+      test('Earn manager account does not match signer - reverts', async () => {
+        // Get the ATA for earn manager one
+        const earnManagerOneATA = await getATA(extMint.publicKey, earnManagerOne.publicKey);
+
+        // Setup the instruction
+        await prepConfigureEarnManager(nonEarnManagerOne, earnManagerOne.publicKey, earnManagerOneATA);
+
+        // Attempt to configure earn manager with non-matching account
+        await expectAnchorError(
+          extEarn.methods
+            .configureEarnManager(new BN(100))
+            .accounts({ ...accounts })
+            .signers([nonEarnManagerOne])
+            .rpc(),
+          'ConstraintSeeds',
+        );
+      });
+
+      // given the earn manager account matches the signer
+      // given the earn manager account is not initialized
+      // it reverts with an AccountNotInitialized error
+      test('Earn manager account not initialized - reverts', async () => {
+        // Get the ATA for earn manager two
+        const earnManagerTwoATA = await getATA(extMint.publicKey, earnManagerTwo.publicKey);
+
+        // Setup the instruction
+        await prepConfigureEarnManager(earnManagerTwo, earnManagerTwo.publicKey, earnManagerTwoATA);
+
+        // Attempt to configure earn manager that hasn't been initialized
+        await expectAnchorError(
+          extEarn.methods
+            .configureEarnManager(new BN(0))
+            .accounts({ ...accounts })
+            .signers([earnManagerTwo])
+            .rpc(),
+          'AccountNotInitialized',
+        );
       });
 
-      // given all the accounts are correct
-      // given the earn manager fee is not zero and earn manager is not active
-      // it mints all the yield to the earner's recipient account
-      test('Earn manager fee is non-zero, earn manager inactive - success', async () => {
-        // Set the earn manager fee to a non-zero value
-        await configureEarnManager(earnManagerOne, new BN(1000));
+      // given the earn manager account matches the signer
+      // given the fee basis points is greater than 100_00
+      // it reverts with an InvalidParam error
+      test('Fee basis points > 10000 - reverts', async () => {
+        // Get the ATA for earn manager one
+        const earnManagerOneATA = await getATA(extMint.publicKey, earnManagerOne.publicKey);
 
-        // Remove the earn manager
-        await removeEarnManager(earnManagerOne.publicKey);
+        // Setup the instruction
+        await prepConfigureEarnManager(earnManagerOne, earnManagerOne.publicKey, earnManagerOneATA);
 
-        // Setup the instruction to claim for earner one
-        const { earnerAccount, userTokenAccount, earnManagerTokenAccount } = await prepClaimFor(
-          earnAuthority,
-          earnerOne.publicKey,
-          earnManagerOne.publicKey,
+        const feeBps = new BN(randomInt(10001, 2 ** 48 - 1));
+
+        // Attempt to configure earn manager with invalid fee basis points
+        await expectAnchorError(
+          extEarn.methods
+            .configureEarnManager(feeBps)
+            .accounts({ ...accounts })
+            .signers([earnManagerOne])
+            .rpc(),
+          'InvalidParam',
         );
+      });
 
-        // Get the current balance of the earner's token account
-        const earnerStartBalance = await getTokenBalance(userTokenAccount);
+      // given the earn manager account matches the signer
+      // given the provided merkle proof for the signer is valid
+      // given the fee basis points is less than or equal to 100_00
+      // given the fee_token_account is for the wrong token mint
+      // it reverts with a constraint token mint error
+      test('Fee token account for wrong mint - reverts', async () => {
+        // Create a new token mint
+        const wrongMint = new Keypair();
+        await createMint(wrongMint, nonAdmin.publicKey);
 
-        // Get the current balance of the earn manager's token account
-        const earnManagerStartBalance = await getTokenBalance(earnManagerTokenAccount);
+        // Get the ATA for earn manager one with the wrong mint
+        const wrongATA = await getATA(wrongMint.publicKey, earnManagerOne.publicKey);
 
-        // Confirm the earn manager fee is non-zero and inactive
-        await expectEarnManagerState(getEarnManagerAccount(earnManagerOne.publicKey), {
-          feeBps: new BN(1000),
-          isActive: false,
-        });
+        // Setup the instruction
+        await prepConfigureEarnManager(earnManagerOne, earnManagerOne.publicKey, wrongATA);
 
-        // Confirm the starting earner account state
-        await expectEarnerState(earnerAccount, {
-          lastClaimIndex: initialIndex,
-          lastClaimTimestamp: startTime,
+        // Attempt to configure earn manager with invalid fee token account
+        await expectAnchorError(
+          extEarn.methods
+            .configureEarnManager(new BN(100))
+            .accounts({ ...accounts })
+            .signers([earnManagerOne])
+            .rpc(),
+          'ConstraintTokenMint',
+        );
+      });
+
+      // given the earn manager account matches the signer
+      // given the earn manager account already exists
+      // given both the fee_bps and fee_token_account are null
+      // nothing is updated
+      test('Both fee bps and fee token account are null - success', async () => {
+        // Get the ATA for earn manager one
+        const earnManagerOneATA = await getATA(extMint.publicKey, earnManagerOne.publicKey);
+
+        // Setup the instruction
+        const { earnManagerAccount } = await prepConfigureEarnManager(earnManagerOne, earnManagerOne.publicKey);
+
+        // Confirm the earn manager account has already been created
+        await expectEarnManagerState(earnManagerAccount, {
+          isActive: true,
+          feeBps: new BN(0),
+          feeTokenAccount: earnManagerOneATA,
         });
 
-        // Send the transaction
+        // Send the instruction
         await extEarn.methods
-          .claimFor(earnerStartBalance)
+          .configureEarnManager(null)
           .accounts({ ...accounts })
-          .signers([earnAuthority])
+          .signers([earnManagerOne])
           .rpc();
 
-        // Calculate expected rewards (balance * (global_index / last_claim_index) - balance)
-        const expectedRewards = earnerStartBalance.mul(newIndex).div(initialIndex).sub(earnerStartBalance);
-
-        // Verify the expected token balance changes
-        await expectTokenBalance(userTokenAccount, earnerStartBalance.add(expectedRewards));
-        await expectTokenBalance(earnManagerTokenAccount, earnManagerStartBalance);
-
-        // Verify the earner account was updated with the new claim index and claim timestamp
-        await expectEarnerState(earnerAccount, {
-          lastClaimIndex: newIndex,
-          lastClaimTimestamp: currentTime(),
+        // Verify the earn manager account is created and updated
+        await expectEarnManagerState(earnManagerAccount, {
+          isActive: true,
+          feeBps: new BN(0),
+          feeTokenAccount: earnManagerOneATA,
         });
       });
 
-      // given all the accounts are correct
-      // given the earn manager fee is not zero and earn manager is active
-      // given the earn manager token account is closed
-      // it mints all the yield to the earner's recipient account
-      test('Earn manager fee is non-zero, earn manager active, earn manager token account closed - success', async () => {
-        // Set the earn manager fee to a non-zero value
-        await configureEarnManager(earnManagerOne, new BN(1000));
-
-        // Setup the instruction to claim for earner one
-        const { earnerAccount, userTokenAccount, earnManagerTokenAccount } = await prepClaimFor(
-          earnAuthority,
-          earnerOne.publicKey,
-          earnManagerOne.publicKey,
-        );
-
-        // Close the earn manager token account
-        await closeTokenAccount(earnManagerOne, earnManagerTokenAccount);
+      // given the earn manager account matches the signer
+      // given the earn manager account already exists
+      // given the fee_bps is not null and the fee_token_account is null
+      // it updates the fee_bps to the provided value
+      test('Fee bps not null, fee token account null - success', async () => {
+        // Get the ATA for earn manager one
+        const earnManagerOneATA = await getATA(extMint.publicKey, earnManagerOne.publicKey);
 
-        // Get the current balance of the earner's token account
-        const earnerStartBalance = await getTokenBalance(userTokenAccount);
+        // Setup the instruction
+        const { earnManagerAccount } = await prepConfigureEarnManager(earnManagerOne, earnManagerOne.publicKey);
 
-        // Confirm the earn manager fee is non-zero and inactive
-        await expectEarnManagerState(getEarnManagerAccount(earnManagerOne.publicKey), {
-          feeBps: new BN(1000),
+        // Confirm the earn manager account has already been created
+        await expectEarnManagerState(earnManagerAccount, {
           isActive: true,
+          feeBps: new BN(0),
+          feeTokenAccount: earnManagerOneATA,
         });
 
-        // Confirm the starting earner account state
-        await expectEarnerState(earnerAccount, {
-          lastClaimIndex: initialIndex,
-          lastClaimTimestamp: startTime,
-        });
+        const newFee = new BN(randomInt(0, 10000));
 
-        // Send the transaction
+        // Send the instruction
         await extEarn.methods
-          .claimFor(earnerStartBalance)
+          .configureEarnManager(newFee)
           .accounts({ ...accounts })
-          .signers([earnAuthority])
+          .signers([earnManagerOne])
           .rpc();
 
-        // Calculate expected rewards (balance * (global_index / last_claim_index) - balance)
-        const expectedRewards = earnerStartBalance.mul(newIndex).div(initialIndex).sub(earnerStartBalance);
-
-        // Verify the expected token balance changes
-        await expectTokenBalance(userTokenAccount, earnerStartBalance.add(expectedRewards));
-
-        // Verify the earner account was updated with the new claim index and claim timestamp
-        await expectEarnerState(earnerAccount, {
-          lastClaimIndex: newIndex,
-          lastClaimTimestamp: currentTime(),
+        // Verify the earn manager account is created and updated
+        await expectEarnManagerState(earnManagerAccount, {
+          isActive: true,
+          feeBps: newFee,
+          feeTokenAccount: earnManagerOneATA,
         });
       });
 
-      // given all the accounts are correct
-      // given the earn manager fee is not zero and earn manager is active
-      // given the fee amount rounds to zero
-      // it mints all the yield to the earner's recipient account
-      test('Earn manager fee is non-zero, earn manager active, fee amount rounds to zero - success', async () => {
-        // Set a very small fee (1 bps = 0.01%)
-        await configureEarnManager(earnManagerOne, new BN(1));
+      // given the earn manager account matches the signer
+      // given the earn manager account already exists
+      // given the fee_bps is null and the fee_token_account is not null
+      // it updates the fee_token_account to the provided token account
+      test('Fee bps null, fee token account not null - success', async () => {
+        // Get the ATA for earn manager one
+        const earnManagerOneATA = await getATA(extMint.publicKey, earnManagerOne.publicKey);
 
-        // Setup the accounts
-        const { earnerAccount, userTokenAccount, earnManagerTokenAccount } = await prepClaimFor(
-          earnAuthority,
-          earnerOne.publicKey,
+        // Use the ATA for a different address to change the fee token account to
+        // it's easier than creating a manual token account
+        const newFeeTokenAccount = await getATA(extMint.publicKey, nonEarnManagerOne.publicKey);
+
+        // Setup the instruction
+        const { earnManagerAccount } = await prepConfigureEarnManager(
+          earnManagerOne,
           earnManagerOne.publicKey,
+          newFeeTokenAccount,
         );
 
-        // Get the earner starting balance (this is used to compare later)
-        const earnerStartBalance = await getTokenBalance(userTokenAccount);
-
-        // Get the earn manager token account starting balance
-        const earnManagerStartBalance = await getTokenBalance(earnManagerTokenAccount);
-
-        // Confirm the earn manager fee is non-zero and active
-        await expectEarnManagerState(getEarnManagerAccount(earnManagerOne.publicKey), {
-          feeBps: new BN(1),
+        // Confirm the earn manager account has already been created
+        await expectEarnManagerState(earnManagerAccount, {
           isActive: true,
+          feeBps: new BN(0),
+          feeTokenAccount: earnManagerOneATA,
         });
 
-        // Confirm the starting earner account state
-        await expectEarnerState(earnerAccount, {
-          lastClaimIndex: initialIndex,
-          lastClaimTimestamp: startTime,
-        });
-
-        // Send the transaction
-        // We use a smaller balance for the yield calculation here to make the fee round to zero
-        const snapshotBalance = new BN(10000);
+        // Send the instruction
         await extEarn.methods
-          .claimFor(snapshotBalance)
+          .configureEarnManager(null)
           .accounts({ ...accounts })
-          .signers([earnAuthority])
+          .signers([earnManagerOne])
           .rpc();
 
-        // Calculate expected rewards (balance * (global_index / last_claim_index) - balance)
-        const expectedRewards = snapshotBalance.mul(newIndex).div(initialIndex).sub(snapshotBalance);
-
-        // Verify the expected token balance changes
-        // Since fee rounds to zero, all rewards go to the earner
-        await expectTokenBalance(userTokenAccount, earnerStartBalance.add(expectedRewards));
-        await expectTokenBalance(earnManagerTokenAccount, earnManagerStartBalance);
-
-        // Verify the earner account was updated with the new claim index and claim timestamp
-        await expectEarnerState(earnerAccount, {
-          lastClaimIndex: newIndex,
-          lastClaimTimestamp: currentTime(),
+        // Verify the earn manager account is created and updated
+        await expectEarnManagerState(earnManagerAccount, {
+          isActive: true,
+          feeBps: new BN(0),
+          feeTokenAccount: newFeeTokenAccount,
         });
       });
 
-      // given all the accounts are correct
-      // given the earn manager fee is not zero and earn manager is active
-      // given the fee amount is not zero
-      // it mints the fee amount to the earn manager token account
-      // it mints the yield minus the fee amount to the earner's recipient account
-      test('Earn manager fee is non-zero, earn manager active, fee amount not zero - success', async () => {
-        // Configure the earn manager account with a 1% fee
-        const feeBps = new BN(100);
-        await configureEarnManager(earnManagerOne, feeBps);
+      // given the earn manager account matches the signer
+      // given the earn manager account already exists
+      // given both the fee_bps and fee_token_account are not null
+      // it updates the fee_bps to the provided value
+      // it updates the fee_token_account to the provided token account
+      test('Both fee bps and fee token account are not null - success', async () => {
+        // Get the ATA for earn manager one
+        const earnManagerOneATA = await getATA(extMint.publicKey, earnManagerOne.publicKey);
 
-        // Setup the instruction to claim for earner one
-        const { earnerAccount, userTokenAccount, earnManagerTokenAccount } = await prepClaimFor(
-          earnAuthority,
-          earnerOne.publicKey,
+        // Use the ATA for a different address to change the fee token account to
+        // it's easier than creating a manual token account
+        const newFeeTokenAccount = await getATA(extMint.publicKey, nonEarnManagerOne.publicKey);
+
+        // Setup the instruction
+        const { earnManagerAccount } = await prepConfigureEarnManager(
+          earnManagerOne,
           earnManagerOne.publicKey,
+          newFeeTokenAccount,
         );
 
-        // Get the current balance of the earner's token account
-        const earnerStartBalance = await getTokenBalance(userTokenAccount);
-
-        // Get the current balance of the earn manager's token account
-        const earnManagerStartBalance = await getTokenBalance(earnManagerTokenAccount);
-
-        // Confirm the earn manager fee is 1%
-        await expectEarnManagerState(getEarnManagerAccount(earnManagerOne.publicKey), {
-          feeBps,
+        // Confirm the earn manager account has already been created
+        await expectEarnManagerState(earnManagerAccount, {
           isActive: true,
+          feeBps: new BN(0),
+          feeTokenAccount: earnManagerOneATA,
         });
 
-        // Confirm the starting earner account state
-        await expectEarnerState(earnerAccount, {
-          lastClaimIndex: initialIndex,
-          lastClaimTimestamp: startTime,
-        });
+        const newFee = new BN(randomInt(0, 10000));
 
-        // Send the transaction
-        const snapshotBalance = await getTokenBalance(await getATA(extMint.publicKey, earnerOne.publicKey));
+        // Send the instruction
         await extEarn.methods
-          .claimFor(snapshotBalance)
+          .configureEarnManager(newFee)
           .accounts({ ...accounts })
-          .signers([earnAuthority])
+          .signers([earnManagerOne])
           .rpc();
 
-        // Calculate expected rewards (balance * (global_index / last_claim_index) - balance)
-        const expectedRewards = snapshotBalance.mul(newIndex).div(initialIndex).sub(snapshotBalance);
-
-        // Calculate the fee amount (1% of rewards)
-        const feeAmount = expectedRewards.mul(feeBps).div(new BN(10000));
-
-        // Calculate the amount that should go to the earner
-        const earnerAmount = expectedRewards.sub(feeAmount);
-
-        // Verify the expected token balance changes
-        await expectTokenBalance(userTokenAccount, earnerStartBalance.add(earnerAmount));
-        await expectTokenBalance(earnManagerTokenAccount, earnManagerStartBalance.add(feeAmount));
-
-        // Verify the earner account was updated with the new claim index and claim timestamp
-        await expectEarnerState(earnerAccount, {
-          lastClaimIndex: newIndex,
-          lastClaimTimestamp: currentTime(),
+        // Verify the earn manager account is created and updated
+        await expectEarnManagerState(earnManagerAccount, {
+          isActive: true,
+          feeBps: newFee,
+          feeTokenAccount: newFeeTokenAccount,
         });
       });
     });
   });
 
-  describe('earn_manager instruction tests', () => {
+  describe('earner instruction tests', () => {
+    let startRecipientAccount: PublicKey;
+
     beforeEach(async () => {
       // Initialize the program
       await initializeExt(earnAuthority.publicKey);
@@ -2325,1621 +4781,1842 @@ describe('ExtEarn unit tests', () => {
       // Add an earn manager
       await addEarnManager(earnManagerOne.publicKey, new BN(0));
 
-      // Add an earner
+      // Add an earner under the earn manager
       await addEarner(earnManagerOne, earnerOne.publicKey);
+
+      // Set the earners recipient token account initially to the non earner one ATA
+      // This way we can tell when we set it back to None
+      startRecipientAccount = await getATA(extMint.publicKey, nonEarnerOne.publicKey);
+      await setRecipient(earnerOne, startRecipientAccount);
     });
 
-    describe('add_earner unit tests', () => {
+    describe('set_recipient unit tests', () => {
       // test cases
-      // [X] given signer does not have an earn manager account initialized
-      //   [X] it reverts with an account not initialized error
-      // [X] given signer has an earn manager account initialized
-      //   [X] given earn manager account is not active
-      //     [X] it reverts with a NotAuthorized error
-      //   [X] given earn manager account is active
-      //     [X] given the earner already has an earner account
-      //       [X] it reverts with an account already initialized error
-      //     [X] given the earner does not already have an earner account
-      //       [X] given user token account is for the wrong token mint
-      //         [X] it reverts with an address constraint error
-      //       [X] given user token account authority does not match the user pubkey
-      //         [X] it reverts with an address constraint error
-      //       [X] given the user token account is for the correct token mint and the authority is the user pubkey
-      //         [X] it creates the earner account
-      //         [X] it sets the user to the provided pubkey
-      //         [X] it sets the user_token_account to the provided token account
-      //         [X] it sets the earner is_active flag to true
-      //         [X] it sets the earn_manager to the provided earn manager pubkey
-      //         [X] it sets the last_claim_index to the current index
-      //         [X] it sets the last_claim_timestamp to the current timestamp
+      // [X] given neither the earner or earn manager signs the transaction
+      //   [X] it reverts with a NotAuthorized error
+      // [X] given the recipient token account is None
+      //   [X] given the earner signs the transaction
+      //     [X] it updates the earner's recipient token account to None (defaults to user token account)
+      //   [X] given the earn manager signs the transaction
+      //     [X] it updates the earner's recipient token account to None (defaults to user token account)
+      // [X] given a recipient token account is provided
+      //   [X] given the recipient token account is for the wrong mint
+      //     [X] it reverts with a ConstraintTokenMint error
+      //   [X] given the recipient token account is valid
+      //     [X] given the earner signs the transaction
+      //       [X] it updates the earner's recipient token account to the provided value
+      //     [X] given the earn manager signs the transaction
+      //       [X] it updates the earner's recipient token account to the provided value
 
-      // given signer does not have an earn manager account initialized
-      // it reverts with an account not initialized error
-      test('Signer earn manager account not initialized - reverts', async () => {
-        // Get the ATA for earner two
-        const earnerTwoATA = await getATA(extMint.publicKey, earnerTwo.publicKey);
+      // given the new recipient token account is None
+      // given the earner signers the transaction
+      // it updates the recipient account to None
+      test('Earner signs, new recipient token account is None (default) - success', async () => {
+        // Setup the instruction
+        const { earnerAccount } = await prepSetRecipient(earnerOne, earnerOne.publicKey, null);
+
+        // Check the starting value
+        await expectEarnerState(earnerAccount, {
+          recipientTokenAccount: startRecipientAccount,
+        });
+
+        // Send the instruction
+        await extEarn.methods
+          .setRecipient()
+          .accounts({ ...accounts })
+          .signers([earnerOne])
+          .rpc();
+
+        // Check that the recipient token account was updated
+        await expectEarnerState(earnerAccount, {
+          recipientTokenAccount: undefined,
+        });
+      });
 
+      // given the new recipient token account is None
+      // given the earn manager signs the transaction
+      // it updates the recipient account to None
+      test('Earn manager signs, new recipient tokenaccount is None (default) - success', async () => {
         // Setup the instruction
-        prepAddEarner(nonEarnManagerOne, nonEarnManagerOne.publicKey, earnerTwoATA);
+        const { earnerAccount } = await prepSetRecipient(earnManagerOne, earnerOne.publicKey, null);
 
-        // Attempt to add earner without an initialized earn manager account
+        // Check the starting value
+        await expectEarnerState(earnerAccount, {
+          recipientTokenAccount: startRecipientAccount,
+        });
+
+        // Send the instruction
+        await extEarn.methods
+          .setRecipient()
+          .accounts({ ...accounts })
+          .signers([earnManagerOne])
+          .rpc();
+
+        // Check that the recipient token account was updated
+        await expectEarnerState(earnerAccount, {
+          recipientTokenAccount: undefined,
+        });
+      });
+
+      // given the transaction is not signed by the earner or the earn manager
+      // it reverts with a NotAuthorized error
+      test('Neither earner nor earn manager signs the transaction - reverts', async () => {
+        // Setup the instruction to change the recipient account back to the default
+        await prepSetRecipient(nonAdmin, earnerOne.publicKey, null);
+
+        // Send the instruction
+        // Expect revert with a NotAuthorized error
         await expectAnchorError(
           extEarn.methods
-            .addEarner(nonEarnerOne.publicKey)
+            .setRecipient()
             .accounts({ ...accounts })
-            .signers([nonEarnManagerOne])
+            .signers([nonAdmin])
             .rpc(),
-          'AccountNotInitialized',
+          'NotAuthorized',
         );
       });
 
-      // given signer has an earn manager account initialized
-      // given earn manager account is not active
-      // it reverts with a NotActive error
-      test("Signer's earn manager account not active - reverts", async () => {
-        // Get the ATA for earner two
-        const earnerTwoATA = await getATA(extMint.publicKey, earnerTwo.publicKey);
-
-        // Remove the earn manager one's account (set it to inactive)
-        await removeEarnManager(earnManagerOne.publicKey);
+      // given a recipient token account is provided
+      // given the recipient token account is for the wrong mint
+      // it reverts with a ConstraintTokenMint error
+      test('Recipient token account is for the wrong mint - reverts', async () => {
+        // Create an ATA for the wrong mint (M mint instead of EXT mint)
+        const wrongMintATA = await getATA(mMint.publicKey, earnerOne.publicKey);
 
         // Setup the instruction
-        prepAddEarner(earnManagerOne, earnManagerOne.publicKey, earnerTwoATA);
+        await prepSetRecipient(earnerOne, earnerOne.publicKey, wrongMintATA);
 
-        // Attempt to add earner with an inactive earn manager account
+        // Attempt to send the transaction
+        // Expect a ConstraintTokenMint error
         await expectAnchorError(
           extEarn.methods
-            .addEarner(earnerTwo.publicKey)
+            .setRecipient()
             .accounts({ ...accounts })
-            .signers([earnManagerOne])
+            .signers([earnerOne])
             .rpc(),
-          'NotActive',
+          'ConstraintTokenMint',
         );
       });
 
-      // given signer has an earn manager account initialized
-      // given earn manager account is active
-      // given earner already has an earner account
-      // it reverts with an account already initialized error
-      test('Earner account already initialized - reverts', async () => {
-        // Get the ATA for earner one
-        const earnerOneATA = await getATA(extMint.publicKey, earnerOne.publicKey);
+      // given a recipient token account is provided
+      // given the recipient token account is valid
+      // given the earner signs the transaction
+      // it updates the earner's recipient token account to the provided token account
+      test('Earner signs, new recipient token account provided - success', async () => {
+        // Get the ATA for the recipient (use yieldRecipient as the recipient)
+        const recipientATA = await getATA(extMint.publicKey, yieldRecipient.publicKey);
+
+        // Setup the instruction
+        const { earnerAccount } = await prepSetRecipient(earnerOne, earnerOne.publicKey, recipientATA);
+
+        // Send the instruction
+        await extEarn.methods
+          .setRecipient()
+          .accounts({ ...accounts })
+          .signers([earnerOne])
+          .rpc();
+
+        // Check that the recipient token account was updated correctly
+        await expectEarnerState(earnerAccount, {
+          recipientTokenAccount: recipientATA,
+        });
+      });
+
+      // given a recipient token account is provided
+      // given the recipient token account is validr
+      // given the earn manager signs the transaction
+      // it updates the earner's recipient token account to the provided token account
+      test('Earn manager signs, new recipient token account provided - success', async () => {
+        // Get the ATA for the recipient (using yieldRecipient as the recipient)
+        const recipientATA = await getATA(extMint.publicKey, yieldRecipient.publicKey);
 
         // Setup the instruction
-        prepAddEarner(earnManagerOne, earnManagerOne.publicKey, earnerOneATA);
+        const { earnerAccount } = await prepSetRecipient(earnManagerOne, earnerOne.publicKey, recipientATA);
 
-        // Attempt to add earner with an already initialized earner account
-        await expectSystemError(
-          extEarn.methods
-            .addEarner(earnerOne.publicKey)
-            .accounts({ ...accounts })
-            .signers([earnManagerOne])
-            .rpc(),
-        );
+        // Send the instruction
+        await extEarn.methods
+          .setRecipient()
+          .accounts({ ...accounts })
+          .signers([earnManagerOne])
+          .rpc();
+
+        // Check that the recipient token account was updated correctly
+        await expectEarnerState(earnerAccount, {
+          recipientTokenAccount: recipientATA,
+        });
       });
+    });
 
-      // given signer has an earn manager account initialized
-      // given earn manager account is active
-      // given the earner does not already have an earner account
-      // given user token account is for the wrong token mint
-      // it reverts with an token mint constraint error
-      test('User token account is for the wrong token mint - reverts', async () => {
-        // Create a new mint for the user token account
-        const wrongMint = new Keypair();
-        await createMint(wrongMint, nonAdmin.publicKey);
+    describe('migrate_earner unit tests', () => {
+      // test cases
+      // [X] given the new token account does not have the immutable owner extension
+      //   [X] it reverts with a MutableOwner error
+      // [X] given the rent payer is not the payer of the earner account
+      //   [X] it reverts with an InvalidAccount error
+      // [X] given all accounts are correct
+      //   [X] it creates the earner account for the new token account
+      //   [X] it carries over the earn manager and recipient
+      //   [X] it closes the old earner account
 
-        // Get the ATA for earner two on the wrong mint
-        const earnerTwoATA = await getATA(wrongMint.publicKey, earnerTwo.publicKey);
+      let earnerATA: PublicKey;
+
+      beforeEach(async () => {
+        // The earner pays for the new earner account
+        svm.airdrop(earnerOne.publicKey, BigInt(10 * LAMPORTS_PER_SOL));
+
+        earnerATA = await getATA(extMint.publicKey, earnerOne.publicKey);
+      });
+
+      // given the new token account does not have the immutable owner extension
+      // it reverts with a MutableOwner error
+      test('new token account has a mutable owner - reverts', async () => {
+        // Create a token account without the immutable owner extension
+        const { tokenAccount } = await createTokenAccount(extMint.publicKey, earnerOne.publicKey);
 
         // Setup the instruction
-        prepAddEarner(earnManagerOne, earnManagerOne.publicKey, earnerTwoATA);
+        prepMigrateEarner(earnerOne, earnerATA, tokenAccount, earnManagerOne.publicKey);
 
-        // Attempt to add earner with user token account for wrong token mint
+        // Attempt to send the transaction
+        // Expect a MutableOwner error
         await expectAnchorError(
           extEarn.methods
-            .addEarner(earnerTwo.publicKey)
+            .migrateEarner()
             .accounts({ ...accounts })
-            .signers([earnManagerOne])
+            .signers([earnerOne])
             .rpc(),
-          'ConstraintTokenMint',
+          'MutableOwner',
         );
       });
 
-      // given signer has an earn manager account initialized
-      // given earn manager account is active
-      // given the earner does not already have an earner account
-      // given user token account authority does not match the user pubkey
-      // it reverts with an address constraint error
-      test('User token account authority does not match user pubkey - reverts', async () => {
-        // Get the ATA for random user (not the same as the user)
-        const randomATA = await getATA(extMint.publicKey, nonAdmin.publicKey);
+      // given the rent payer is not the payer of the earner account
+      // it reverts with an InvalidAccount error
+      test('wrong rent payer - reverts', async () => {
+        // Create a token account with the immutable owner extension
+        const { tokenAccount } = await createImmutableTokenAccount(extMint.publicKey, earnerOne.publicKey);
 
-        // Setup the instruction
-        prepAddEarner(earnManagerOne, earnManagerOne.publicKey, randomATA);
+        // Setup the instruction with the wrong rent payer
+        prepMigrateEarner(earnerOne, earnerATA, tokenAccount, nonAdmin.publicKey);
 
-        // Attempt to add earner with user token account for wrong token mint
+        // Attempt to send the transaction
+        // Expect an InvalidAccount error
         await expectAnchorError(
           extEarn.methods
-            .addEarner(earnerTwo.publicKey)
+            .migrateEarner()
             .accounts({ ...accounts })
-            .signers([earnManagerOne])
+            .signers([earnerOne])
             .rpc(),
-          'ConstraintTokenOwner',
+          'InvalidAccount',
         );
       });
 
-      test('Add earner - success', async () => {
-        const tokenAccountKeypair = Keypair.generate();
-        const tokenAccountLen = getAccountLen([ExtensionType.ImmutableOwner]);
-        const lamports = await provider.connection.getMinimumBalanceForRentExemption(tokenAccountLen);
-
-        // Create token account with the immutable owner extension
-        const transaction = new Transaction().add(
-          SystemProgram.createAccount({
-            fromPubkey: earnManagerOne.publicKey,
-            newAccountPubkey: tokenAccountKeypair.publicKey,
-            space: tokenAccountLen,
-            lamports,
-            programId: TOKEN_2022_PROGRAM_ID,
-          }),
-          createInitializeImmutableOwnerInstruction(tokenAccountKeypair.publicKey, TOKEN_2022_PROGRAM_ID),
-          createInitializeAccountInstruction(
-            tokenAccountKeypair.publicKey,
-            extMint.publicKey,
-            earnerTwo.publicKey,
-            TOKEN_2022_PROGRAM_ID,
-          ),
-        );
-
-        await provider.send!(transaction, [earnManagerOne, tokenAccountKeypair]);
+      // given all accounts are correct
+      // it creates the earner account for the new token account
+      // it carries over the earn manager and recipient
+      // it closes the old earner account
+      test('migrate_earner - success', async () => {
+        // Create a token account with the immutable owner extension
+        const { tokenAccount } = await createImmutableTokenAccount(extMint.publicKey, earnerOne.publicKey);
 
         // Setup the instruction
-        const { earnerAccount } = prepAddEarner(
-          earnManagerOne,
-          earnManagerOne.publicKey,
-          tokenAccountKeypair.publicKey,
-        );
+        const { newEarnerAccount } = prepMigrateEarner(earnerOne, earnerATA, tokenAccount, earnManagerOne.publicKey);
 
-        // Add earner two to the earn manager's list
+        // Send the transaction
         await extEarn.methods
-          .addEarner(earnerTwo.publicKey)
+          .migrateEarner()
           .accounts({ ...accounts })
-          .signers([earnManagerOne])
+          .signers([earnerOne])
           .rpc();
 
-        // Verify the earner account was initialized correctly
-        await expectEarnerState(earnerAccount, {
+        // Check the new earner account
+        await expectEarnerState(newEarnerAccount, {
+          user: earnerOne.publicKey,
+          userTokenAccount: tokenAccount,
           earnManager: earnManagerOne.publicKey,
-          lastClaimIndex: initialIndex,
-          lastClaimTimestamp: currentTime(),
-          user: earnerTwo.publicKey,
-          userTokenAccount: tokenAccountKeypair.publicKey,
+          recipientTokenAccount: startRecipientAccount,
         });
+
+        // Check the old earner account is closed
+        expectAccountEmpty(getExtEarnerAccount(earnerATA));
       });
+    });
+  });
 
-      // given signer has an earn manager account initialized
-      // given earn manager account is active
-      // given the earner does not already have an earner account
-      // given user token account is for the correct token mint and the authority is the signer
-      // it creates the earner account
-      // it sets the earner is_active flag to true
-      // it sets the earn_manager to the provided earn manager pubkey
-      // it sets the last_claim_index to the current index
-      // it sets the last_claim_timestamp to the current time
-      test('Add earner ata - success', async () => {
-        // Get the ATA for earner two
-        const earnerTwoATA = await getATA(extMint.publicKey, earnerTwo.publicKey);
+  describe('open instruction tests', () => {
+    const mintAmount = new BN(100_000_000);
 
-        // Setup the instruction
-        const { earnerAccount } = prepAddEarner(earnManagerOne, earnManagerOne.publicKey, earnerTwoATA);
+    // Setup accounts with M tokens so we can test wrapping and unwrapping
+    beforeEach(async () => {
+      // Initialize the extension program
+      await initializeExt(earnAuthority.publicKey);
 
-        // Add earner one to the earn manager's list
-        await extEarn.methods
-          .addEarner(earnerTwo.publicKey)
-          .accounts({ ...accounts })
-          .signers([earnManagerOne])
-          .rpc();
+      // Add an earn manager on the extension
+      await addEarnManager(earnManagerOne.publicKey, new BN(0));
 
-        // Verify the earner account was initialized correctly
-        await expectEarnerState(earnerAccount, {
-          earnManager: earnManagerOne.publicKey,
-          lastClaimIndex: initialIndex,
-          lastClaimTimestamp: currentTime(),
-          user: earnerTwo.publicKey,
-          userTokenAccount: earnerTwoATA,
-        });
-      });
-    });
+      // Add an earner on the extension under the earn manager
+      await addEarner(earnManagerOne, earnerOne.publicKey);
 
-    describe('configure_earn_manager unit tests', () => {});
+      // Mint M tokens to the extension earner and a non-earner
+      await mintM(earnerOne.publicKey, mintAmount);
+      await mintM(nonEarnerOne.publicKey, mintAmount);
+    });
 
-    describe('remove_earner unit tests', () => {
+    describe('wrap unit tests', () => {
       // test cases
-      // [X] given signer does not have an earn manager account initialized
-      //   [X] it reverts with an account not initialized error
-      // [X] given signer has an earn manager account initialized
-      //   [X] given earn manager account is not active
-      //     [X] it reverts with a NotAuthorized error
-      //   [X] given earn manager account is active
-      //     [X] given the earner account does not have an earn manager
-      //       [X] it reverts with a NotAuthorized error
-      //     [X] given the earner account has an earn manager
-      //       [X] given the earner's earn manager is not the signer
-      //         [X] it reverts with a NotAuthorized error
-      //       [X] given the earner's earn manager is the signer
-      //         [X] the earner account is closed and the signer refunded the rent
-
-      // given signer does not have an earn manager account initialized
-      // it reverts with an account not initialized error
-      test('Signer earn manager account not initialized - reverts', async () => {
-        // Get the ATA for earner one
-        const earnerOneATA = await getATA(extMint.publicKey, earnerOne.publicKey);
+      // [X] given the m mint account does not match the one stored in the global account
+      //   [X] it reverts with an InvalidAccount error
+      // [X] given the ext mint account does not match the one stored in the global account
+      //   [X] it reverts with an InvalidAccount error
+      // [X] given the signer is not the authority on the from m token account
+      //   [X] it reverts with a ConstraintTokenOwner error
+      // [X] given the vault M token account is not the M Vaults ATA for the M token mint
+      //   [X] it reverts with a ConstraintAssociated error
+      // [X] given the from m token account is for the wrong mint
+      //   [X] it reverts with a ConstraintTokenMint error
+      // [X] given the to ext token account is for the wrong mint
+      //   [X] it reverts with a ConstraintTokenMint error
+      // [X] given all the accounts are correct
+      //   [X] given the user does not have enough M tokens
+      //     [X] it reverts with a ? error
+      //   [X] given the user has enough M tokens
+      //     [X] it transfers the amount of M tokens from the user's M token account to the M vault token account
+      //     [X] it mints the amount of wM tokens to the user's wM token account
+      // TODO there are a couple other account constraints that can be tested
 
+      // given the m mint account does not match the one stored in the global account
+      // it reverts with an InvalidAccount error
+      test('M mint account does not match global account - reverts', async () => {
         // Setup the instruction
-        prepRemoveEarner(nonEarnManagerOne, nonEarnManagerOne.publicKey, earnerOneATA);
+        await prepWrap(earnerOne);
 
-        // Attempt to remove earner without an initialized earn manager account
+        // Change the m mint account
+        accounts.mMint = extMint.publicKey;
+
+        // Attempt to send the transaction
+        // Expect an invalid account error
         await expectAnchorError(
           extEarn.methods
-            .removeEarner()
+            .wrap(mintAmount, null)
             .accounts({ ...accounts })
-            .signers([nonEarnManagerOne])
+            .signers([earnerOne])
             .rpc(),
-          'AccountNotInitialized',
+          'InvalidAccount',
         );
       });
 
-      // given signer has an earn manager account initialized
-      // given earn manager account is not active
-      // it reverts with a NotActive error
-      test("Signer's earn manager account not active - reverts", async () => {
-        // Get the ATA for earner one
-        const earnerOneATA = await getATA(extMint.publicKey, earnerOne.publicKey);
-
-        // Remove the earn manager account (set it to inactive)
-        await removeEarnManager(earnManagerOne.publicKey);
-
+      // given the ext mint account does not match the one stored in the global account
+      // it reverts with an InvalidAccount error
+      test('Ext mint account does not match global account - reverts', async () => {
         // Setup the instruction
-        prepRemoveEarner(earnManagerOne, earnManagerOne.publicKey, earnerOneATA);
+        await prepWrap(earnerOne);
 
-        // Attempt to remove earner with an inactive earn manager account
+        // Change the ext mint account
+        accounts.extMint = mMint.publicKey;
+
+        // Attempt to send the transaction
+        // Expect an invalid account error
         await expectAnchorError(
           extEarn.methods
-            .removeEarner()
+            .wrap(mintAmount, null)
             .accounts({ ...accounts })
-            .signers([earnManagerOne])
+            .signers([earnerOne])
             .rpc(),
-          'NotActive',
+          'InvalidAccount',
         );
       });
 
-      // given signer has an earn manager account initialized
-      // given earn manager account is active
-      // given the earner account has an earn manager
-      // given the earner's earn manager is not the signer
-      // it reverts with a NotAuthorized error
-      test("Earner's earn manager is not signer - reverts", async () => {
-        // Add earner manager two
-        await addEarnManager(earnManagerTwo.publicKey, new BN(100));
-
-        // Get the ATA for earner one
-        const earnerOneATA = await getATA(extMint.publicKey, earnerOne.publicKey);
+      // given the signer is not the authority on the user M token account
+      // it reverts with a ConstraintTokenOwner error
+      test('Signer is not the authority on the from M token account - reverts', async () => {
+        // Get the ATA for another user
+        const wrongATA = await getATA(mMint.publicKey, nonEarnerOne.publicKey);
 
-        // Setup the instruction
-        prepRemoveEarner(earnManagerTwo, earnManagerTwo.publicKey, earnerOneATA);
+        // Setup the instruction with the wrong user M token account
+        await prepWrap(earnerOne, wrongATA);
 
-        // Attempt to remove earner with the wrong earn manager
+        // Attempt to send the transaction
+        // Expect revert with TokenOwner error
         await expectAnchorError(
           extEarn.methods
-            .removeEarner()
+            .wrap(mintAmount, null)
             .accounts({ ...accounts })
-            .signers([earnManagerTwo])
+            .signers([earnerOne])
             .rpc(),
-          'NotAuthorized',
+          'ConstraintTokenOwner',
         );
       });
 
-      // given signer has an earn manager account initialized
-      // given earn manager account is active
-      // given the earner account has an earn manager
-      // given the earner's earn manager is the signer
-      // it closes the earner account and refunds the rent
-      test("Earner's earn manager is signer - success", async () => {
-        // Get the ATA for earner one
-        const earnerOneATA = await getATA(extMint.publicKey, earnerOne.publicKey);
+      // given the M vault token account is not the M vault PDA's ATA
+      // it reverts with a ConstraintAssociated error
+      test("M Vault Token account is the the M Vault PDA's ATA (other token account) - reverts", async () => {
+        // Create a token account for the M vault that is not the ATA
+        const tokenAccountKeypair = Keypair.generate();
+        const tokenAccountLen = getAccountLen([ExtensionType.ImmutableOwner]);
+        const lamports = await provider.connection.getMinimumBalanceForRentExemption(tokenAccountLen);
 
-        // Setup the instruction
-        const { earnerAccount } = prepRemoveEarner(earnManagerOne, earnManagerOne.publicKey, earnerOneATA);
+        const mVault = getMVault();
 
-        // Remove the earner account
-        await extEarn.methods
-          .removeEarner()
-          .accounts({ ...accounts })
-          .signers([earnManagerOne])
-          .rpc();
+        // Create token account with the immutable owner extension
+        const transaction = new Transaction().add(
+          SystemProgram.createAccount({
+            fromPubkey: admin.publicKey,
+            newAccountPubkey: tokenAccountKeypair.publicKey,
+            space: tokenAccountLen,
+            lamports,
+            programId: TOKEN_2022_PROGRAM_ID,
+          }),
+          createInitializeImmutableOwnerInstruction(tokenAccountKeypair.publicKey, TOKEN_2022_PROGRAM_ID),
+          createInitializeAccountInstruction(
+            tokenAccountKeypair.publicKey,
+            mMint.publicKey,
+            mVault,
+            TOKEN_2022_PROGRAM_ID,
+          ),
+        );
 
-        // Verify the earner account was closed
-        expectAccountEmpty(earnerAccount);
-      });
-    });
+        await provider.send!(transaction, [admin, tokenAccountKeypair]);
 
-    describe('transfer_earner unit tests', () => {
-      // test cases
-      // [X] given the earner does not have an account initialized
-      //   [X] it reverts with an AccountNotInitialized error
-      // [X] given the to earn manager does not have an account initialized
-      //   [X] it reverts with an AccountNotInitialized error
-      // [X] given the from earn manager does not sign the transaction
-      //   [X] it reverts with a NotAuthorized error
-      // [X] given the from earn manager does sign the transaction
-      //   [X] given the from earn manager is not active
-      //     [X] it reverts with a NotActive error
-      //   [X] given the to earn manager is not active
-      //     [X] it reverts with a NotActive error
-      //   [X] given all the accounts are correct and earn managers are active
-      //     [X] it updates the earner's earn manager to the "to earn manager"
+        // Setup the instruction with the non-ATA vault m token account
+        await prepWrap(earnerOne, undefined, undefined, tokenAccountKeypair.publicKey);
 
-      beforeEach(async () => {
-        // Add second earn manager to have someone to transfer to
-        await addEarnManager(earnManagerTwo.publicKey, new BN(100));
+        // Attempt to send the transaction
+        // Expect revert with a ConstraintAssociated error
+        await expectAnchorError(
+          extEarn.methods
+            .wrap(mintAmount, null)
+            .accounts({ ...accounts })
+            .signers([earnerOne])
+            .rpc(),
+          'ConstraintAssociated',
+        );
       });
 
-      // given the earner does not have an account initialized
-      // it reverts with an AccounbtNotInitialized error
-      test('earner account not initialized - reverts', async () => {
-        const nonEarnerOneATA = await getATA(extMint.publicKey, nonEarnerOne.publicKey);
+      // given the from m token account is for the wrong mint
+      // it reverts with a ConstraintTokenMint error
+      test('From M token account is for wrong mint - reverts', async () => {
+        // Get the user's ATA for the ext mint and pass it as the user M token account
+        const wrongUserATA = await getATA(extMint.publicKey, earnerOne.publicKey);
 
         // Setup the instruction
-        prepTransferEarner(earnManagerOne, earnManagerOne.publicKey, earnManagerTwo.publicKey, nonEarnerOneATA);
+        await prepWrap(earnerOne, wrongUserATA);
 
-        // Attempt to transfer earner without an initialized account
+        // Attempt to send the transaction
+        // Expect revert with a ConstraintTokenMint error
         await expectAnchorError(
           extEarn.methods
-            .transferEarner(earnManagerTwo.publicKey)
+            .wrap(mintAmount, null)
             .accounts({ ...accounts })
-            .signers([earnManagerOne])
+            .signers([earnerOne])
             .rpc(),
-          'AccountNotInitialized',
+          'ConstraintTokenMint',
         );
       });
 
-      // given the to earn manager does not have an account initialized
-      // it reverts with an AccountNotInitialized error
-      test('to_earn_manager account not initialized - reverts', async () => {
-        const earnerOneATA = await getATA(extMint.publicKey, earnerOne.publicKey);
+      // given the to ext token account is for the wrong mint
+      // it reverts with a ConstraintTokenMint error
+      test('To Ext token account is for the wrong mint - reverts', async () => {
+        // Get the user's ATA for the m mint and pass it as the user ext token account
+        const wrongUserATA = await getATA(mMint.publicKey, earnerOne.publicKey);
 
         // Setup the instruction
-        prepTransferEarner(earnManagerOne, earnManagerOne.publicKey, nonEarnManagerOne.publicKey, earnerOneATA);
+        await prepWrap(earnerOne, undefined, wrongUserATA);
 
-        // Attempt to transfer earner to a non-initialized earn manager account
+        // Attempt to send the transaction
+        // Expect revert with a ConstraintTokenMint error
         await expectAnchorError(
           extEarn.methods
-            .transferEarner(nonEarnManagerOne.publicKey)
+            .wrap(mintAmount, null)
             .accounts({ ...accounts })
-            .signers([earnManagerOne])
+            .signers([earnerOne])
             .rpc(),
-          'AccountNotInitialized',
+          'ConstraintTokenMint',
         );
       });
 
-      // given the from earn manager does not sign the transaction
-      // it reverts with a NotAuthorized error
-      test('from earn manager does not sign transaction - reverts', async () => {
-        const earnerOneATA = await getATA(extMint.publicKey, earnerOne.publicKey);
-
+      // given all accounts are correct
+      // give the user does not have enough M tokens
+      // it reverts
+      test('Not enough M - reverts', async () => {
         // Setup the instruction
-        prepTransferEarner(nonAdmin, earnManagerOne.publicKey, earnManagerTwo.publicKey, earnerOneATA);
+        await prepWrap(earnerOne);
 
-        // Attempt to transfer earner with a non-authorized signer
-        await expectAnchorError(
+        const wrapAmount = new BN(randomInt(mintAmount.toNumber() + 1, 2 ** 48 - 1));
+
+        // Attempt to send the transaction
+        // Expect an error
+        await expectSystemError(
           extEarn.methods
-            .transferEarner(earnManagerTwo.publicKey)
+            .wrap(wrapAmount, null)
             .accounts({ ...accounts })
-            .signers([nonAdmin])
+            .signers([earnerOne])
             .rpc(),
-          'NotAuthorized',
         );
       });
 
-      // given the from earn manager does sign the transaction
-      // given the from earn manager is not active
-      // it reverts with a NotActive
-      test('from earn manager is not active - reverts', async () => {
-        const earnerOneATA = await getATA(extMint.publicKey, earnerOne.publicKey);
+      // given all accounts are correct
+      // given the user has enough M tokens
+      // it transfers the amount of M tokens from the user's M token account to the M vault token account
+      // it mints the amount of wM tokens to the user's wM token account
+      test('Wrap as wM earner - success', async () => {
+        // Setup the instruction
+        const { vaultMTokenAccount, fromMTokenAccount, toExtTokenAccount } = await prepWrap(earnerOne);
 
-        // Remove the earn manager account (set it to inactive)
-        await removeEarnManager(earnManagerOne.publicKey);
+        // Confirm initial balances
+        await expectTokenBalance(fromMTokenAccount, mintAmount);
+        await expectTokenBalance(vaultMTokenAccount, new BN(0));
+        await expectTokenBalance(toExtTokenAccount, new BN(0));
+
+        const wrapAmount = new BN(randomInt(1, mintAmount.toNumber()));
+
+        // Send the instruction
+        await extEarn.methods
+          .wrap(wrapAmount, null)
+          .accounts({ ...accounts })
+          .signers([earnerOne])
+          .rpc();
+
+        // Confirm updated balances
+        await expectTokenBalance(fromMTokenAccount, mintAmount.sub(wrapAmount));
+        await expectTokenBalance(vaultMTokenAccount, wrapAmount);
+        await expectTokenBalance(toExtTokenAccount, wrapAmount);
+      });
 
+      // given all accounts are correct
+      // given the user has enough M tokens
+      // it transfers the amount of M tokens from the user's M token account to the M vault token account
+      // it mints the amount of wM tokens to the user's wM token account
+      test('Wrap as non-earner - success', async () => {
         // Setup the instruction
-        prepTransferEarner(earnManagerOne, earnManagerOne.publicKey, earnManagerTwo.publicKey, earnerOneATA);
+        const { vaultMTokenAccount, fromMTokenAccount, toExtTokenAccount } = await prepWrap(nonEarnerOne);
 
-        // Attempt to transfer earner with an inactive earn manager account
-        await expectAnchorError(
-          extEarn.methods
-            .transferEarner(earnManagerTwo.publicKey)
-            .accounts({ ...accounts })
-            .signers([earnManagerOne])
-            .rpc(),
-          'NotActive',
-        );
+        // Confirm initial balances
+        await expectTokenBalance(fromMTokenAccount, mintAmount);
+        await expectTokenBalance(vaultMTokenAccount, new BN(0));
+        await expectTokenBalance(toExtTokenAccount, new BN(0));
+
+        const wrapAmount = new BN(randomInt(1, mintAmount.toNumber()));
+
+        // Send the instruction
+        await extEarn.methods
+          .wrap(wrapAmount, null)
+          .accounts({ ...accounts })
+          .signers([nonEarnerOne])
+          .rpc();
+
+        // Confirm updated balances
+        await expectTokenBalance(fromMTokenAccount, mintAmount.sub(wrapAmount));
+        await expectTokenBalance(vaultMTokenAccount, wrapAmount);
+        await expectTokenBalance(toExtTokenAccount, wrapAmount);
       });
+    });
 
-      // given the from earn manager signs the transaction
-      // given the to earn manager is not active
-      // it reverts with a NotActive error
-      test('to earn manager is not active - reverts', async () => {
-        const earnerOneATA = await getATA(extMint.publicKey, earnerOne.publicKey);
+    describe('unwrap unit tests', () => {
+      const wrappedAmount = new BN(50_000_000);
+      beforeEach(async () => {
+        // Wrap tokens for the users so we can test unwrapping
+        await wrap(earnerOne, wrappedAmount);
+        await wrap(nonEarnerOne, wrappedAmount);
+      });
 
-        // Remove the to earn manager account (set it to inactive)
-        await removeEarnManager(earnManagerTwo.publicKey);
+      // test cases
+      // [X] given the m mint account does not match the one stored in the global account
+      //   [X] it reverts with an InvalidAccount error
+      // [X] given the ext mint account does not match the one stored in the global account
+      //   [X] it reverts with an InvalidAccount error
+      // [X] given the signer is not the authority on the from ext token account
+      //   [X] it reverts with a ConstraintTokenOwner error
+      // [X] given the vault M token account is not the M Vaults ATA for the M token mint
+      //   [X] it reverts with a ConstraintAssociated error
+      // [X] given the to m token account is for the wrong mint
+      //   [X] it reverts with a ConstraintTokenMint error
+      // [X] given the from ext token account is for the wrong mint
+      //   [X] it reverts with a ConstraintTokenMint error
+      // [X] given all the accounts are correct
+      //   [X] given the user does not have enough ext tokens
+      //     [X] it reverts
+      //   [X] given the user has enough ext tokens
+      //     [X] it transfers the amount of M tokens from the M vault token account to the user's M token account
+      //     [X] it burns the amount of ext tokens from the user's ext token account
+      // TODO there are a couple other account constraints that can be tested
 
+      // given the m mint account does not match the one stored in the global account
+      // it reverts with an InvalidAccount error
+      test('M mint account does not match global account - reverts', async () => {
         // Setup the instruction
-        prepTransferEarner(earnManagerOne, earnManagerOne.publicKey, earnManagerTwo.publicKey, earnerOneATA);
+        await prepUnwrap(earnerOne);
 
-        // Attempt to transfer earner with an inactive to earn manager account
+        // Change the m mint account
+        accounts.mMint = extMint.publicKey;
+
+        // Attempt to send the transaction
+        // Expect an invalid account error
         await expectAnchorError(
           extEarn.methods
-            .transferEarner(earnManagerTwo.publicKey)
+            .unwrap(wrappedAmount, null)
             .accounts({ ...accounts })
-            .signers([earnManagerOne])
+            .signers([earnerOne])
             .rpc(),
-          'NotActive',
+          'InvalidAccount',
         );
       });
 
-      // given the from earn manager signs the transaction
-      // given the accounts are correct and the earn managers are active
-      // it updates the earner's earn manager to the "to earn manager"
-      test('transfer_earner - success', async () => {
-        const earnerOneATA = await getATA(extMint.publicKey, earnerOne.publicKey);
-
+      // given the ext mint account does not match the one stored in the global account
+      // it reverts with an InvalidAccount error
+      test('Ext mint account does not match global account - reverts', async () => {
         // Setup the instruction
-        const { earnerAccount } = prepTransferEarner(
-          earnManagerOne,
-          earnManagerOne.publicKey,
-          earnManagerTwo.publicKey,
-          earnerOneATA,
-        );
-
-        // Confirm the earner's earn manager is currently earnManagerOne
-        await expectEarnerState(earnerAccount, {
-          earnManager: earnManagerOne.publicKey,
-        });
+        await prepUnwrap(earnerOne);
 
-        // Transfer the earner from earn manager one to earn manager two
-        await extEarn.methods
-          .transferEarner(earnManagerTwo.publicKey)
-          .accounts({ ...accounts })
-          .signers([earnManagerOne])
-          .rpc();
+        // Change the ext mint account
+        accounts.extMint = mMint.publicKey;
 
-        // Verify the earner account was updated
-        await expectEarnerState(earnerAccount, {
-          earnManager: earnManagerTwo.publicKey,
-        });
+        // Attempt to send the transaction
+        // Expect an invalid account error
+        await expectAnchorError(
+          extEarn.methods
+            .unwrap(wrappedAmount, null)
+            .accounts({ ...accounts })
+            .signers([earnerOne])
+            .rpc(),
+          'InvalidAccount',
+        );
       });
-    });
-
-    describe('configure_earn_manager unit tests', () => {
-      // test cases
-      // [X] given the earn manager account does not match the signer
-      //   [X] it reverts with an address constraint error
-      // [X] given the earn manager account matches the signer
-      //   [X] given the fee basis points is greater than 100_00
-      //     [X] it reverts with an InvalidParam error
-      //   [X] given the fee basis points is less than or equal to 100_00
-      //     [X] given the fee_token_account is for the wrong token mint
-      //       [X] it reverts with an address constraint error
-      //     [X] given the fee_token_account is for the correct token mint
-      //       [X] given the earn manager account has not been initialized
-      //         [X] it reverts with an AccountNotInitialized error
-      //       [X] given the earn manager account has been initialized
-      //         [X] given the fee_bps is null and the fee_token_account is null
-      //           [X] nothing is updated
-      //         [X] given the fee_bps is null and the fee_token_account is not null
-      //           [X] it updates the fee_token_account to the provided token account
-      //         [X] given the fee_bps is not null and the fee_token_account is null
-      //           [X] it updates the fee_bps to the provided value
-      //         [X] given the fee_bps is not null and the fee_token_account is not null
-      //           [X] it updates the fee_bps to the provided value
-      //           [X] it updates the fee_token_account to the provided token account
 
-      // given the earn manager account does not match the signer
-      // it reverts with a seeds constraint error
-      test('Earn manager account does not match signer - reverts', async () => {
-        // Get the ATA for earn manager one
-        const earnManagerOneATA = await getATA(extMint.publicKey, earnManagerOne.publicKey);
+      // given the signer is not the authority on the user M token account
+      // it reverts with a ConstraintTokenOwner error
+      test('Signer is not the authority on the from Ext token account - reverts', async () => {
+        // Get the ATA for another user
+        const mATA = await getATA(mMint.publicKey, earnerOne.publicKey);
+        const wrongExtATA = await getATA(extMint.publicKey, nonEarnerOne.publicKey);
 
-        // Setup the instruction
-        await prepConfigureEarnManager(nonEarnManagerOne, earnManagerOne.publicKey, earnManagerOneATA);
+        // Setup the instruction with the wrong user M token account
+        await prepUnwrap(earnerOne, mATA, wrongExtATA);
 
-        // Attempt to configure earn manager with non-matching account
+        // Attempt to send the transaction
+        // Expect revert with TokenOwner error
         await expectAnchorError(
           extEarn.methods
-            .configureEarnManager(new BN(100))
+            .unwrap(wrappedAmount, null)
             .accounts({ ...accounts })
-            .signers([nonEarnManagerOne])
+            .signers([earnerOne])
             .rpc(),
-          'ConstraintSeeds',
+          'ConstraintTokenOwner',
         );
       });
 
-      // given the earn manager account matches the signer
-      // given the earn manager account is not initialized
-      // it reverts with an AccountNotInitialized error
-      test('Earn manager account not initialized - reverts', async () => {
-        // Get the ATA for earn manager two
-        const earnManagerTwoATA = await getATA(extMint.publicKey, earnManagerTwo.publicKey);
+      // given the M vault token account is not the M vault PDA's ATA
+      // it reverts with a ConstraintAssociated error
+      test("M Vault Token account is the the M Vault PDA's ATA (other token account) - reverts", async () => {
+        // Create a token account for the M vault that is not the ATA
+        const tokenAccountKeypair = Keypair.generate();
+        const tokenAccountLen = getAccountLen([ExtensionType.ImmutableOwner]);
+        const lamports = await provider.connection.getMinimumBalanceForRentExemption(tokenAccountLen);
 
-        // Setup the instruction
-        await prepConfigureEarnManager(earnManagerTwo, earnManagerTwo.publicKey, earnManagerTwoATA);
+        const mVault = getMVault();
 
-        // Attempt to configure earn manager that hasn't been initialized
+        // Create token account with the immutable owner extension
+        const transaction = new Transaction().add(
+          SystemProgram.createAccount({
+            fromPubkey: admin.publicKey,
+            newAccountPubkey: tokenAccountKeypair.publicKey,
+            space: tokenAccountLen,
+            lamports,
+            programId: TOKEN_2022_PROGRAM_ID,
+          }),
+          createInitializeImmutableOwnerInstruction(tokenAccountKeypair.publicKey, TOKEN_2022_PROGRAM_ID),
+          createInitializeAccountInstruction(
+            tokenAccountKeypair.publicKey,
+            mMint.publicKey,
+            mVault,
+            TOKEN_2022_PROGRAM_ID,
+          ),
+        );
+
+        await provider.send!(transaction, [admin, tokenAccountKeypair]);
+
+        // Setup the instruction with the non-ATA vault m token account
+        await prepUnwrap(earnerOne, undefined, undefined, tokenAccountKeypair.publicKey);
+
+        // Attempt to send the transaction
+        // Expect revert with a ConstraintAssociated error
         await expectAnchorError(
           extEarn.methods
-            .configureEarnManager(new BN(0))
+            .unwrap(wrappedAmount, null)
             .accounts({ ...accounts })
-            .signers([earnManagerTwo])
+            .signers([earnerOne])
             .rpc(),
-          'AccountNotInitialized',
+          'ConstraintAssociated',
         );
       });
 
-      // given the earn manager account matches the signer
-      // given the fee basis points is greater than 100_00
-      // it reverts with an InvalidParam error
-      test('Fee basis points > 10000 - reverts', async () => {
-        // Get the ATA for earn manager one
-        const earnManagerOneATA = await getATA(extMint.publicKey, earnManagerOne.publicKey);
+      // given the user m token account is for the wrong mint
+      // it reverts with a ConstraintTokenMint error
+      test('To M token account is for wrong mint - reverts', async () => {
+        // Get the user's ATA for the ext mint and pass it as the user M token account
+        const wrongUserATA = await getATA(extMint.publicKey, earnerOne.publicKey);
 
         // Setup the instruction
-        await prepConfigureEarnManager(earnManagerOne, earnManagerOne.publicKey, earnManagerOneATA);
-
-        const feeBps = new BN(randomInt(10001, 2 ** 48 - 1));
+        await prepUnwrap(earnerOne, wrongUserATA);
 
-        // Attempt to configure earn manager with invalid fee basis points
+        // Attempt to send the transaction
+        // Expect revert with a ConstraintTokenMint error
         await expectAnchorError(
           extEarn.methods
-            .configureEarnManager(feeBps)
+            .unwrap(wrappedAmount, null)
             .accounts({ ...accounts })
-            .signers([earnManagerOne])
+            .signers([earnerOne])
             .rpc(),
-          'InvalidParam',
+          'ConstraintTokenMint',
         );
       });
 
-      // given the earn manager account matches the signer
-      // given the provided merkle proof for the signer is valid
-      // given the fee basis points is less than or equal to 100_00
-      // given the fee_token_account is for the wrong token mint
-      // it reverts with a constraint token mint error
-      test('Fee token account for wrong mint - reverts', async () => {
-        // Create a new token mint
-        const wrongMint = new Keypair();
-        await createMint(wrongMint, nonAdmin.publicKey);
-
-        // Get the ATA for earn manager one with the wrong mint
-        const wrongATA = await getATA(wrongMint.publicKey, earnManagerOne.publicKey);
+      // given the user ext token account is for the wrong mint
+      // it reverts with a ConstraintTokenMint error
+      test('From Ext token account is for the wrong mint - reverts', async () => {
+        // Get the user's ATA for the m mint and pass it as the user ext token account
+        const wrongUserATA = await getATA(mMint.publicKey, earnerOne.publicKey);
 
         // Setup the instruction
-        await prepConfigureEarnManager(earnManagerOne, earnManagerOne.publicKey, wrongATA);
+        await prepUnwrap(earnerOne, undefined, wrongUserATA);
 
-        // Attempt to configure earn manager with invalid fee token account
+        // Attempt to send the transaction
+        // Expect revert with a ConstraintTokenMint error
         await expectAnchorError(
           extEarn.methods
-            .configureEarnManager(new BN(100))
+            .unwrap(wrappedAmount, null)
             .accounts({ ...accounts })
-            .signers([earnManagerOne])
+            .signers([earnerOne])
             .rpc(),
           'ConstraintTokenMint',
         );
       });
 
-      // given the earn manager account matches the signer
-      // given the earn manager account already exists
-      // given both the fee_bps and fee_token_account are null
-      // nothing is updated
-      test('Both fee bps and fee token account are null - success', async () => {
-        // Get the ATA for earn manager one
-        const earnManagerOneATA = await getATA(extMint.publicKey, earnManagerOne.publicKey);
-
+      // given all accounts are correct
+      // give the user does not have enough ext tokens
+      // it reverts
+      test('Not enough ext tokens - reverts', async () => {
         // Setup the instruction
-        const { earnManagerAccount } = await prepConfigureEarnManager(earnManagerOne, earnManagerOne.publicKey);
-
-        // Confirm the earn manager account has already been created
-        await expectEarnManagerState(earnManagerAccount, {
-          isActive: true,
-          feeBps: new BN(0),
-          feeTokenAccount: earnManagerOneATA,
-        });
+        await prepUnwrap(earnerOne);
 
-        // Send the instruction
-        await extEarn.methods
-          .configureEarnManager(null)
-          .accounts({ ...accounts })
-          .signers([earnManagerOne])
-          .rpc();
+        const unwrapAmount = new BN(randomInt(wrappedAmount.toNumber() + 1, 2 ** 48 - 1));
 
-        // Verify the earn manager account is created and updated
-        await expectEarnManagerState(earnManagerAccount, {
-          isActive: true,
-          feeBps: new BN(0),
-          feeTokenAccount: earnManagerOneATA,
-        });
+        // Attempt to send the transaction
+        // Expect an error
+        await expectSystemError(
+          extEarn.methods
+            .unwrap(unwrapAmount, null)
+            .accounts({ ...accounts })
+            .signers([earnerOne])
+            .rpc(),
+        );
       });
 
-      // given the earn manager account matches the signer
-      // given the earn manager account already exists
-      // given the fee_bps is not null and the fee_token_account is null
-      // it updates the fee_bps to the provided value
-      test('Fee bps not null, fee token account null - success', async () => {
-        // Get the ATA for earn manager one
-        const earnManagerOneATA = await getATA(extMint.publicKey, earnManagerOne.publicKey);
-
+      // given all accounts are correct
+      // given the user has enough ext tokens
+      // it transfers the amount of M tokens from the M vault token account to the user's M token account
+      // it burns the amount of ext tokens from the user's ext token account
+      test('Unwrap as ext earner - success', async () => {
         // Setup the instruction
-        const { earnManagerAccount } = await prepConfigureEarnManager(earnManagerOne, earnManagerOne.publicKey);
-
-        // Confirm the earn manager account has already been created
-        await expectEarnManagerState(earnManagerAccount, {
-          isActive: true,
-          feeBps: new BN(0),
-          feeTokenAccount: earnManagerOneATA,
-        });
+        const { vaultMTokenAccount, toMTokenAccount, fromExtTokenAccount } = await prepUnwrap(earnerOne);
 
-        const newFee = new BN(randomInt(0, 10000));
+        // Confirm initial balances
+        await expectTokenBalance(toMTokenAccount, mintAmount.sub(wrappedAmount));
+        await expectTokenBalance(vaultMTokenAccount, wrappedAmount.add(wrappedAmount));
+        await expectTokenBalance(fromExtTokenAccount, wrappedAmount);
+
+        const unwrapAmount = new BN(randomInt(1, wrappedAmount.toNumber()));
 
         // Send the instruction
         await extEarn.methods
-          .configureEarnManager(newFee)
+          .unwrap(unwrapAmount, null)
           .accounts({ ...accounts })
-          .signers([earnManagerOne])
+          .signers([earnerOne])
           .rpc();
 
-        // Verify the earn manager account is created and updated
-        await expectEarnManagerState(earnManagerAccount, {
-          isActive: true,
-          feeBps: newFee,
-          feeTokenAccount: earnManagerOneATA,
-        });
+        // Confirm updated balances
+        await expectTokenBalance(toMTokenAccount, mintAmount.sub(wrappedAmount).add(unwrapAmount));
+        await expectTokenBalance(vaultMTokenAccount, wrappedAmount.add(wrappedAmount).sub(unwrapAmount));
+        await expectTokenBalance(fromExtTokenAccount, wrappedAmount.sub(unwrapAmount));
       });
 
-      // given the earn manager account matches the signer
-      // given the earn manager account already exists
-      // given the fee_bps is null and the fee_token_account is not null
-      // it updates the fee_token_account to the provided token account
-      test('Fee bps null, fee token account not null - success', async () => {
-        // Get the ATA for earn manager one
-        const earnManagerOneATA = await getATA(extMint.publicKey, earnManagerOne.publicKey);
-
-        // Use the ATA for a different address to change the fee token account to
-        // it's easier than creating a manual token account
-        const newFeeTokenAccount = await getATA(extMint.publicKey, nonEarnManagerOne.publicKey);
-
+      // given all accounts are correct
+      // given the user has enough ext tokens
+      // it transfers the amount of M tokens from the M vault token account to the user's M token account
+      // it burns the amount of ext tokens from the user's ext token account
+      test('Unwrap as non-earner - success', async () => {
         // Setup the instruction
-        const { earnManagerAccount } = await prepConfigureEarnManager(
-          earnManagerOne,
-          earnManagerOne.publicKey,
-          newFeeTokenAccount,
-        );
+        const { vaultMTokenAccount, toMTokenAccount, fromExtTokenAccount } = await prepUnwrap(nonEarnerOne);
 
-        // Confirm the earn manager account has already been created
-        await expectEarnManagerState(earnManagerAccount, {
-          isActive: true,
-          feeBps: new BN(0),
-          feeTokenAccount: earnManagerOneATA,
-        });
+        // Confirm initial balances
+        await expectTokenBalance(toMTokenAccount, mintAmount.sub(wrappedAmount));
+        await expectTokenBalance(vaultMTokenAccount, wrappedAmount.add(wrappedAmount));
+        await expectTokenBalance(fromExtTokenAccount, wrappedAmount);
+
+        const unwrapAmount = new BN(randomInt(1, wrappedAmount.toNumber()));
 
         // Send the instruction
         await extEarn.methods
-          .configureEarnManager(null)
+          .unwrap(unwrapAmount, null)
           .accounts({ ...accounts })
-          .signers([earnManagerOne])
+          .signers([nonEarnerOne])
           .rpc();
 
-        // Verify the earn manager account is created and updated
-        await expectEarnManagerState(earnManagerAccount, {
-          isActive: true,
-          feeBps: new BN(0),
-          feeTokenAccount: newFeeTokenAccount,
-        });
+        // Confirm updated balances
+        await expectTokenBalance(toMTokenAccount, mintAmount.sub(wrappedAmount).add(unwrapAmount));
+        await expectTokenBalance(vaultMTokenAccount, wrappedAmount.add(wrappedAmount).sub(unwrapAmount));
+        await expectTokenBalance(fromExtTokenAccount, wrappedAmount.sub(unwrapAmount));
       });
+    });
 
-      // given the earn manager account matches the signer
-      // given the earn manager account already exists
-      // given both the fee_bps and fee_token_account are not null
-      // it updates the fee_bps to the provided value
-      // it updates the fee_token_account to the provided token account
-      test('Both fee bps and fee token account are not null - success', async () => {
-        // Get the ATA for earn manager one
-        const earnManagerOneATA = await getATA(extMint.publicKey, earnManagerOne.publicKey);
+    describe('wrap fee unit tests', () => {
+      // test cases
+      // [X] given a wrap fee is set
+      //   [X] given the fee token account is not provided
+      //     [X] it reverts with an InvalidAccount error
+      //   [X] given the fee token account is provided
+      //     [X] it transfers the fee to the fee token account, rounded down
+      //     [X] it mints ext tokens for the amount after the fee
+      // [X] given an unwrap fee is set
+      //   [X] it transfers the fee on the unwrapped M to the fee token account
+      //   [X] it transfers the rest of the M to the user
+
+      // given a wrap fee is set
+      // given the fee token account is not provided
+      // it reverts with an InvalidAccount error
+      test('wrap fee without fee token account - reverts', async () => {
+        // Set a 0.25% wrap fee
+        await setWrapFees(new BN(25), new BN(0));
 
-        // Use the ATA for a different address to change the fee token account to
-        // it's easier than creating a manual token account
-        const newFeeTokenAccount = await getATA(extMint.publicKey, nonEarnManagerOne.publicKey);
+        // Setup the instruction without the fee token account
+        await prepWrap(earnerOne);
 
-        // Setup the instruction
-        const { earnManagerAccount } = await prepConfigureEarnManager(
-          earnManagerOne,
-          earnManagerOne.publicKey,
-          newFeeTokenAccount,
+        // Attempt to send the transaction
+        // Expect an InvalidAccount error
+        await expectAnchorError(
+          extEarn.methods
+            .wrap(mintAmount, null)
+            .accounts({ ...accounts })
+            .signers([earnerOne])
+            .rpc(),
+          'InvalidAccount',
         );
+      });
 
-        // Confirm the earn manager account has already been created
-        await expectEarnManagerState(earnManagerAccount, {
-          isActive: true,
-          feeBps: new BN(0),
-          feeTokenAccount: earnManagerOneATA,
-        });
+      // given a wrap fee is set
+      // given the fee token account is provided
+      // it transfers the fee and mints ext tokens for the rest
+      test('wrap with fee - success', async () => {
+        // Set a 0.25% wrap fee
+        const { feeTokenAccount } = await setWrapFees(new BN(25), new BN(0));
 
-        const newFee = new BN(randomInt(0, 10000));
+        // Setup the instruction with the fee token account
+        const { vaultMTokenAccount, toExtTokenAccount } = await prepWrap(earnerOne);
+        accounts.feeTokenAccount = feeTokenAccount;
 
-        // Send the instruction
+        // Wrap an amount where the fee is rounded down
+        const wrapAmount = new BN(1_000_399);
+        const fee = new BN(2_500);
+
+        // Send the transaction
         await extEarn.methods
-          .configureEarnManager(newFee)
+          .wrap(wrapAmount, null)
           .accounts({ ...accounts })
-          .signers([earnManagerOne])
+          .signers([earnerOne])
           .rpc();
 
-        // Verify the earn manager account is created and updated
-        await expectEarnManagerState(earnManagerAccount, {
-          isActive: true,
-          feeBps: newFee,
-          feeTokenAccount: newFeeTokenAccount,
-        });
+        // Verify the fee was taken and the rest was wrapped
+        await expectTokenBalance(feeTokenAccount, fee);
+        await expectTokenBalance(vaultMTokenAccount, wrapAmount.sub(fee));
+        await expectTokenBalance(toExtTokenAccount, wrapAmount.sub(fee));
       });
-    });
-  });
-
-  describe('earner instruction tests', () => {
-    let startRecipientAccount: PublicKey;
-
-    beforeEach(async () => {
-      // Initialize the program
-      await initializeExt(earnAuthority.publicKey);
-
-      // Add an earn manager
-      await addEarnManager(earnManagerOne.publicKey, new BN(0));
 
-      // Add an earner under the earn manager
-      await addEarner(earnManagerOne, earnerOne.publicKey);
-
-      // Set the earners recipient token account initially to the non earner one ATA
-      // This way we can tell when we set it back to None
-      startRecipientAccount = await getATA(extMint.publicKey, nonEarnerOne.publicKey);
-      await setRecipient(earnerOne, startRecipientAccount);
-    });
+      // given an unwrap fee is set
+      // it transfers the fee and the rest of the M to the user
+      test('unwrap with fee - success', async () => {
+        // Wrap the tokens before the fee is set
+        await wrap(earnerOne, mintAmount);
 
-    describe('set_recipient unit tests', () => {
-      // test cases
-      // [X] given neither the earner or earn manager signs the transaction
-      //   [X] it reverts with a NotAuthorized error
-      // [X] given the recipient token account is None
-      //   [X] given the earner signs the transaction
-      //     [X] it updates the earner's recipient token account to None (defaults to user token account)
-      //   [X] given the earn manager signs the transaction
-      //     [X] it updates the earner's recipient token account to None (defaults to user token account)
-      // [X] given a recipient token account is provided
-      //   [X] given the recipient token account is for the wrong mint
-      //     [X] it reverts with a ConstraintTokenMint error
-      //   [X] given the recipient token account is valid
-      //     [X] given the earner signs the transaction
-      //       [X] it updates the earner's recipient token account to the provided value
-      //     [X] given the earn manager signs the transaction
-      //       [X] it updates the earner's recipient token account to the provided value
+        // Set a 0.5% unwrap fee
+        const { feeTokenAccount } = await setWrapFees(new BN(0), new BN(50));
 
-      // given the new recipient token account is None
-      // given the earner signers the transaction
-      // it updates the recipient account to None
-      test('Earner signs, new recipient token account is None (default) - success', async () => {
-        // Setup the instruction
-        const { earnerAccount } = await prepSetRecipient(earnerOne, earnerOne.publicKey, null);
+        // Setup the instruction with the fee token account
+        const { vaultMTokenAccount, toMTokenAccount, fromExtTokenAccount } = await prepUnwrap(earnerOne);
+        accounts.feeTokenAccount = feeTokenAccount;
 
-        // Check the starting value
-        await expectEarnerState(earnerAccount, {
-          recipientTokenAccount: startRecipientAccount,
-        });
+        const fee = mintAmount.muln(50).divn(10_000);
 
-        // Send the instruction
+        // Send the transaction
         await extEarn.methods
-          .setRecipient()
+          .unwrap(mintAmount, null)
           .accounts({ ...accounts })
           .signers([earnerOne])
           .rpc();
 
-        // Check that the recipient token account was updated
-        await expectEarnerState(earnerAccount, {
-          recipientTokenAccount: undefined,
-        });
+        // Verify the fee was taken from the unwrapped M
+        await expectTokenBalance(feeTokenAccount, fee);
+        await expectTokenBalance(toMTokenAccount, mintAmount.sub(fee));
+        await expectTokenBalance(fromExtTokenAccount, new BN(0));
+        await expectTokenBalance(vaultMTokenAccount, new BN(0));
       });
+    });
 
-      // given the new recipient token account is None
-      // given the earn manager signs the transaction
-      // it updates the recipient account to None
-      test('Earn manager signs, new recipient tokenaccount is None (default) - success', async () => {
-        // Setup the instruction
-        const { earnerAccount } = await prepSetRecipient(earnManagerOne, earnerOne.publicKey, null);
-
-        // Check the starting value
-        await expectEarnerState(earnerAccount, {
-          recipientTokenAccount: startRecipientAccount,
+    describe('wrapper allowlist unit tests', () => {
+      // test cases
+      // [X] given the allowlist uses wrapper accounts
+      //   [X] given the signer has no wrapper account
+      //     [X] it reverts with a NotAuthorized error
+      //   [X] given the wrapper account of another user is provided
+      //     [X] it reverts with a ConstraintSeeds error
+      //   [X] given the signer has a wrapper account
+      //     [X] it wraps and unwraps
+      // [X] given the allowlist uses a merkle root
+      //   [X] given no proof is provided
+      //     [X] it reverts with a NotAuthorized error
+      //   [X] given the proof is for another wrapper
+      //     [X] it reverts with an InvalidProof error
+      //   [X] given a valid proof is provided
+      //     [X] it wraps and unwraps
+
+      describe('wrapper account allowlist', () => {
+        beforeEach(async () => {
+          // Only allow wrappers with a wrapper account
+          await setAllowlist({ pda: {} });
+
+          // Add earner one as a wrapper
+          await addWrapper(earnerOne.publicKey);
         });
 
-        // Send the instruction
-        await extEarn.methods
-          .setRecipient()
-          .accounts({ ...accounts })
-          .signers([earnManagerOne])
-          .rpc();
+        // given the allowlist uses wrapper accounts
+        // given the signer has no wrapper account
+        // it reverts with a NotAuthorized error
+        test('no wrapper account - reverts', async () => {
+          // Setup the instruction without a wrapper account
+          await prepWrap(nonEarnerOne);
+
+          // Attempt to send the transaction
+          // Expect a NotAuthorized error
+          await expectAnchorError(
+            extEarn.methods
+              .wrap(mintAmount, null)
+              .accounts({ ...accounts })
+              .signers([nonEarnerOne])
+              .rpc(),
+            'NotAuthorized',
+          );
+        });
 
-        // Check that the recipient token account was updated
-        await expectEarnerState(earnerAccount, {
-          recipientTokenAccount: undefined,
+        // given the allowlist uses wrapper accounts
+        // given the wrapper account of another user is provided
+        // it reverts with a ConstraintSeeds error
+        test('wrapper account of another user - reverts', async () => {
+          // Setup the instruction with the wrapper account of earner one
+          await prepWrap(nonEarnerOne);
+          accounts.wrapperAccount = getWrapperAccount(earnerOne.publicKey);
+
+          // Attempt to send the transaction
+          // Expect a ConstraintSeeds error
+          await expectAnchorError(
+            extEarn.methods
+              .wrap(mintAmount, null)
+              .accounts({ ...accounts })
+              .signers([nonEarnerOne])
+              .rpc(),
+            'ConstraintSeeds',
+          );
         });
-      });
 
-      // given the transaction is not signed by the earner or the earn manager
-      // it reverts with a NotAuthorized error
-      test('Neither earner nor earn manager signs the transaction - reverts', async () => {
-        // Setup the instruction to change the recipient account back to the default
-        await prepSetRecipient(nonAdmin, earnerOne.publicKey, null);
+        // given the allowlist uses wrapper accounts
+        // given the signer has a wrapper account
+        // it wraps and unwraps
+        test('wrapper account - success', async () => {
+          // Wrap with the wrapper account
+          const { toExtTokenAccount } = await prepWrap(earnerOne);
+          accounts.wrapperAccount = getWrapperAccount(earnerOne.publicKey);
 
-        // Send the instruction
-        // Expect revert with a NotAuthorized error
-        await expectAnchorError(
-          extEarn.methods
-            .setRecipient()
+          await extEarn.methods
+            .wrap(mintAmount, null)
             .accounts({ ...accounts })
-            .signers([nonAdmin])
-            .rpc(),
-          'NotAuthorized',
-        );
-      });
+            .signers([earnerOne])
+            .rpc();
 
-      // given a recipient token account is provided
-      // given the recipient token account is for the wrong mint
-      // it reverts with a ConstraintTokenMint error
-      test('Recipient token account is for the wrong mint - reverts', async () => {
-        // Create an ATA for the wrong mint (M mint instead of EXT mint)
-        const wrongMintATA = await getATA(mMint.publicKey, earnerOne.publicKey);
+          await expectTokenBalance(toExtTokenAccount, mintAmount);
 
-        // Setup the instruction
-        await prepSetRecipient(earnerOne, earnerOne.publicKey, wrongMintATA);
+          // Unwrap with the wrapper account
+          const { toMTokenAccount } = await prepUnwrap(earnerOne);
+          accounts.wrapperAccount = getWrapperAccount(earnerOne.publicKey);
 
-        // Attempt to send the transaction
-        // Expect a ConstraintTokenMint error
-        await expectAnchorError(
-          extEarn.methods
-            .setRecipient()
+          await extEarn.methods
+            .unwrap(mintAmount, null)
             .accounts({ ...accounts })
             .signers([earnerOne])
-            .rpc(),
-          'ConstraintTokenMint',
-        );
+            .rpc();
+
+          await expectTokenBalance(toExtTokenAccount, new BN(0));
+          await expectTokenBalance(toMTokenAccount, mintAmount);
+        });
       });
 
-      // given a recipient token account is provided
-      // given the recipient token account is valid
-      // given the earner signs the transaction
-      // it updates the earner's recipient token account to the provided token account
-      test('Earner signs, new recipient token account provided - success', async () => {
-        // Get the ATA for the recipient (use yieldRecipient as the recipient)
-        const recipientATA = await getATA(extMint.publicKey, yieldRecipient.publicKey);
+      describe('merkle root allowlist', () => {
+        const wrapperMerkleTree = new MerkleTree([earnerOne.publicKey, earnerTwo.publicKey]);
 
-        // Setup the instruction
-        const { earnerAccount } = await prepSetRecipient(earnerOne, earnerOne.publicKey, recipientATA);
+        beforeEach(async () => {
+          // Only allow wrappers in the merkle tree
+          await setAllowlist({ merkleRoot: {} }, wrapperMerkleTree.getRoot());
+        });
 
-        // Send the instruction
-        await extEarn.methods
-          .setRecipient()
-          .accounts({ ...accounts })
-          .signers([earnerOne])
-          .rpc();
+        // given the allowlist uses a merkle root
+        // given no proof is provided
+        // it reverts with a NotAuthorized error
+        test('no proof - reverts', async () => {
+          // Setup the instruction
+          await prepWrap(earnerOne);
+
+          // Attempt to send the transaction without a proof
+          // Expect a NotAuthorized error
+          await expectAnchorError(
+            extEarn.methods
+              .wrap(mintAmount, null)
+              .accounts({ ...accounts })
+              .signers([earnerOne])
+              .rpc(),
+            'NotAuthorized',
+          );
+        });
 
-        // Check that the recipient token account was updated correctly
-        await expectEarnerState(earnerAccount, {
-          recipientTokenAccount: recipientATA,
+        // given the allowlist uses a merkle root
+        // given the proof is for another wrapper
+        // it reverts with an InvalidProof error
+        test('proof of another wrapper - reverts', async () => {
+          // Setup the instruction
+          await prepWrap(nonEarnerOne);
+
+          // Use the proof of earner one
+          const { proof } = wrapperMerkleTree.getInclusionProof(earnerOne.publicKey);
+
+          // Attempt to send the transaction
+          // Expect an InvalidProof error
+          await expectAnchorError(
+            extEarn.methods
+              .wrap(mintAmount, proof)
+              .accounts({ ...accounts })
+              .signers([nonEarnerOne])
+              .rpc(),
+            'InvalidProof',
+          );
         });
-      });
 
-      // given a recipient token account is provided
-      // given the recipient token account is validr
-      // given the earn manager signs the transaction
-      // it updates the earner's recipient token account to the provided token account
-      test('Earn manager signs, new recipient token account provided - success', async () => {
-        // Get the ATA for the recipient (using yieldRecipient as the recipient)
-        const recipientATA = await getATA(extMint.publicKey, yieldRecipient.publicKey);
+        // given the allowlist uses a merkle root
+        // given a valid proof is provided
+        // it wraps and unwraps
+        test('valid proof - success', async () => {
+          const { proof } = wrapperMerkleTree.getInclusionProof(earnerOne.publicKey);
+
+          // Wrap with the proof
+          const { toExtTokenAccount } = await prepWrap(earnerOne);
+
+          await extEarn.methods
+            .wrap(mintAmount, proof)
+            .accounts({ ...accounts })
+            .signers([earnerOne])
+            .rpc();
+
+          await expectTokenBalance(toExtTokenAccount, mintAmount);
 
-        // Setup the instruction
-        const { earnerAccount } = await prepSetRecipient(earnManagerOne, earnerOne.publicKey, recipientATA);
+          // Unwrap with the proof
+          const { toMTokenAccount } = await prepUnwrap(earnerOne);
 
-        // Send the instruction
-        await extEarn.methods
-          .setRecipient()
-          .accounts({ ...accounts })
-          .signers([earnManagerOne])
-          .rpc();
+          await extEarn.methods
+            .unwrap(mintAmount, proof)
+            .accounts({ ...accounts })
+            .signers([earnerOne])
+            .rpc();
 
-        // Check that the recipient token account was updated correctly
-        await expectEarnerState(earnerAccount, {
-          recipientTokenAccount: recipientATA,
+          await expectTokenBalance(toExtTokenAccount, new BN(0));
+          await expectTokenBalance(toMTokenAccount, mintAmount);
         });
       });
     });
-  });
-
-  describe('open instruction tests', () => {
-    const mintAmount = new BN(100_000_000);
 
-    // Setup accounts with M tokens so we can test wrapping and unwrapping
-    beforeEach(async () => {
-      // Initialize the extension program
-      await initializeExt(earnAuthority.publicKey);
+    describe('remove_orphaned_earner unit tests', () => {
+      // test cases
+      // [X] given the earner account is not initialized
+      //   [X] it reverts with an account not initialized error
+      // [X] given the earn manager account is not initialized
+      //   [X] it reverts with an account not initialized error
+      // [X] given the earn manager account does not match the one on the earner account
+      //   [X] it reverts with a ConstraintSeeds error
+      // [X] given all the accounts are valid
+      //   [X] given the earner has an earn manager
+      //     [X] given the earn manager account is active
+      //       [X] it reverts with a Active error
+      //     [X] given the earn manager account is not active
+      //       [X] it closes the earner account and refunds the rent to the signer
 
-      // Add an earn manager on the extension
-      await addEarnManager(earnManagerOne.publicKey, new BN(0));
+      beforeEach(async () => {
+        // Add another earn manager
+        await addEarnManager(earnManagerTwo.publicKey, new BN(0));
 
-      // Add an earner on the extension under the earn manager
-      await addEarner(earnManagerOne, earnerOne.publicKey);
+        // Add an earner under the new earn manager
+        await addEarner(earnManagerTwo, earnerTwo.publicKey);
 
-      // Mint M tokens to the extension earner and a non-earner
-      await mintM(earnerOne.publicKey, mintAmount);
-      await mintM(nonEarnerOne.publicKey, mintAmount);
-    });
+        // Remove earn manager two so that earner two is orphaned
+        await removeEarnManager(earnManagerTwo.publicKey);
+      });
 
-    describe('wrap unit tests', () => {
-      // test cases
-      // [X] given the m mint account does not match the one stored in the global account
-      //   [X] it reverts with an InvalidAccount error
-      // [X] given the ext mint account does not match the one stored in the global account
-      //   [X] it reverts with an InvalidAccount error
-      // [X] given the signer is not the authority on the from m token account
-      //   [X] it reverts with a ConstraintTokenOwner error
-      // [X] given the vault M token account is not the M Vaults ATA for the M token mint
-      //   [X] it reverts with a ConstraintAssociated error
-      // [X] given the from m token account is for the wrong mint
-      //   [X] it reverts with a ConstraintTokenMint error
-      // [X] given the to ext token account is for the wrong mint
-      //   [X] it reverts with a ConstraintTokenMint error
-      // [X] given all the accounts are correct
-      //   [X] given the user does not have enough M tokens
-      //     [X] it reverts with a ? error
-      //   [X] given the user has enough M tokens
-      //     [X] it transfers the amount of M tokens from the user's M token account to the M vault token account
-      //     [X] it mints the amount of wM tokens to the user's wM token account
-      // TODO there are a couple other account constraints that can be tested
+      // given the earner account is not initialized
+      // it reverts with an account not initialized error
+      test('Earner account is not initialized - reverts', async () => {
+        // Calculate the ATA for non earner one, but don't create it
+        const nonInitATA = getAssociatedTokenAddressSync(
+          extMint.publicKey,
+          nonEarnerOne.publicKey,
+          true,
+          TOKEN_2022_PROGRAM_ID,
+          ASSOCIATED_TOKEN_PROGRAM_ID,
+        );
 
-      // given the m mint account does not match the one stored in the global account
-      // it reverts with an InvalidAccount error
-      test('M mint account does not match global account - reverts', async () => {
         // Setup the instruction
-        await prepWrap(earnerOne);
-
-        // Change the m mint account
-        accounts.mMint = extMint.publicKey;
+        prepRemoveOrphanedEarner(nonAdmin, nonInitATA, earnManagerOne.publicKey);
 
-        // Attempt to send the transaction
-        // Expect an invalid account error
+        // Attempt to remove orphaned earner with uninitialized token account
         await expectAnchorError(
           extEarn.methods
-            .wrap(mintAmount)
+            .removeOrphanedEarner()
             .accounts({ ...accounts })
-            .signers([earnerOne])
+            .signers([nonAdmin])
             .rpc(),
-          'InvalidAccount',
+          'AccountNotInitialized',
         );
       });
 
-      // given the ext mint account does not match the one stored in the global account
-      // it reverts with an InvalidAccount error
-      test('Ext mint account does not match global account - reverts', async () => {
-        // Setup the instruction
-        await prepWrap(earnerOne);
+      // given the earn manager account is not initialized
+      // it reverts with an account not initialized error
+      test('Earn manager account is not initialized - reverts', async () => {
+        // Get the ATA for earner two
+        const earnerTwoATA = await getATA(extMint.publicKey, earnerTwo.publicKey);
 
-        // Change the ext mint account
-        accounts.extMint = mMint.publicKey;
+        // Prepare the instruction
+        prepRemoveOrphanedEarner(nonAdmin, earnerTwoATA, nonEarnManagerOne.publicKey);
 
-        // Attempt to send the transaction
-        // Expect an invalid account error
+        // Attempt to remove orphaned earner with uninitialized earn manager account
         await expectAnchorError(
           extEarn.methods
-            .wrap(mintAmount)
+            .removeOrphanedEarner()
             .accounts({ ...accounts })
-            .signers([earnerOne])
+            .signers([nonAdmin])
             .rpc(),
-          'InvalidAccount',
+          'AccountNotInitialized',
         );
       });
 
-      // given the signer is not the authority on the user M token account
-      // it reverts with a ConstraintTokenOwner error
-      test('Signer is not the authority on the from M token account - reverts', async () => {
-        // Get the ATA for another user
-        const wrongATA = await getATA(mMint.publicKey, nonEarnerOne.publicKey);
+      // given all the accounts are valid
+      // given the earner has an earn manager
+      // given the earn manager account is active
+      // it reverts with an Active error
+      test('Earn manager account is active - reverts', async () => {
+        // Get the ATA for earner one
+        const earnerOneATA = await getATA(extMint.publicKey, earnerOne.publicKey);
 
-        // Setup the instruction with the wrong user M token account
-        await prepWrap(earnerOne, wrongATA);
+        // Setup the instruction
+        prepRemoveOrphanedEarner(nonAdmin, earnerOneATA, earnManagerOne.publicKey);
 
-        // Attempt to send the transaction
-        // Expect revert with TokenOwner error
+        // Attempt to remove orphaned earner with an active earn manager
         await expectAnchorError(
           extEarn.methods
-            .wrap(mintAmount)
+            .removeOrphanedEarner()
             .accounts({ ...accounts })
-            .signers([earnerOne])
+            .signers([nonAdmin])
             .rpc(),
-          'ConstraintTokenOwner',
+          'Active',
         );
       });
 
-      // given the M vault token account is not the M vault PDA's ATA
-      // it reverts with a ConstraintAssociated error
-      test("M Vault Token account is the the M Vault PDA's ATA (other token account) - reverts", async () => {
-        // Create a token account for the M vault that is not the ATA
-        const tokenAccountKeypair = Keypair.generate();
-        const tokenAccountLen = getAccountLen([ExtensionType.ImmutableOwner]);
-        const lamports = await provider.connection.getMinimumBalanceForRentExemption(tokenAccountLen);
+      // given the earn manager account does not match the earner's earn manager
+      // it reverts with a ConstraintSeeds error
+      test('Invalid earn manager account - reverts', async () => {
+        const earnerTwoATA = await getATA(extMint.publicKey, earnerTwo.publicKey);
 
-        const mVault = getMVault();
+        // Setup the instruction
+        await prepRemoveOrphanedEarner(nonAdmin, earnerTwoATA, earnManagerOne.publicKey);
 
-        // Create token account with the immutable owner extension
-        const transaction = new Transaction().add(
-          SystemProgram.createAccount({
-            fromPubkey: admin.publicKey,
-            newAccountPubkey: tokenAccountKeypair.publicKey,
-            space: tokenAccountLen,
-            lamports,
-            programId: TOKEN_2022_PROGRAM_ID,
-          }),
-          createInitializeImmutableOwnerInstruction(tokenAccountKeypair.publicKey, TOKEN_2022_PROGRAM_ID),
-          createInitializeAccountInstruction(
-            tokenAccountKeypair.publicKey,
-            mMint.publicKey,
-            mVault,
-            TOKEN_2022_PROGRAM_ID,
-          ),
+        // Attempt to remove orphaned earner with the wrong earn manager account
+        // expect revert with ConstraintSeeds error
+        await expectAnchorError(
+          extEarn.methods
+            .removeOrphanedEarner()
+            .accounts({ ...accounts })
+            .signers([nonAdmin])
+            .rpc(),
+          'ConstraintSeeds',
         );
+      });
 
-        await provider.send!(transaction, [admin, tokenAccountKeypair]);
+      // given all the accounts are valid
+      // given the earner has an earn manager
+      // given the earn manager account is not active
+      // it closes the earner account and refunds the rent to the signer
+      test('Remove orphaned earner - success', async () => {
+        // Get the ATA for earnerTwo
+        const earnerTwoATA = await getATA(extMint.publicKey, earnerTwo.publicKey);
 
-        // Setup the instruction with the non-ATA vault m token account
-        await prepWrap(earnerOne, undefined, undefined, tokenAccountKeypair.publicKey);
+        // Setup the instruction
+        const { earnerAccount, earnManagerAccount } = prepRemoveOrphanedEarner(
+          nonAdmin,
+          earnerTwoATA,
+          earnManagerTwo.publicKey,
+        );
+
+        // Confirm that the account is active and has the correct earn manager
+        await expectEarnerState(earnerAccount, {
+          earnManager: earnManagerTwo.publicKey,
+        });
+
+        // Confirm that the earn manager account is not active
+        await expectEarnManagerState(earnManagerAccount, {
+          isActive: false,
+        });
+
+        // Remove the orphaned earner
+        await extEarn.methods
+          .removeOrphanedEarner()
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc();
+
+        // Verify the earner account was closed
+        expectAccountEmpty(earnerAccount);
+      });
+    });
+
+    describe('migrate_earner_account unit tests', () => {
+      // test cases
+      // [X] given the earner account is on the current version
+      //   [X] it reverts with an InvalidVersion error
+      // [X] given the earner account is on an older version
+      //   [X] it sets the version to the current version
+
+      let earnerAccount: PublicKey;
+
+      beforeEach(async () => {
+        earnerAccount = getExtEarnerAccount(await getATA(extMint.publicKey, earnerOne.publicKey));
+      });
+
+      // given the earner account is on the current version
+      // it reverts with an InvalidVersion error
+      test('current version - reverts', async () => {
+        // Setup the instruction
+        prepMigrateEarnerAccount(nonAdmin, earnerAccount);
 
         // Attempt to send the transaction
-        // Expect revert with a ConstraintAssociated error
+        // Expect an InvalidVersion error
         await expectAnchorError(
           extEarn.methods
-            .wrap(mintAmount)
+            .migrateEarnerAccount()
             .accounts({ ...accounts })
-            .signers([earnerOne])
+            .signers([nonAdmin])
             .rpc(),
-          'ConstraintAssociated',
+          'InvalidVersion',
         );
       });
 
-      // given the from m token account is for the wrong mint
-      // it reverts with a ConstraintTokenMint error
-      test('From M token account is for wrong mint - reverts', async () => {
-        // Get the user's ATA for the ext mint and pass it as the user M token account
-        const wrongUserATA = await getATA(extMint.publicKey, earnerOne.publicKey);
+      // given the earner account is on an older version
+      // it sets the version to the current version
+      test('migrate_earner_account - success', async () => {
+        // Downgrade the earner account
+        await setExtAccountState(earnerAccount, 'Earner', { version: 0 });
+
+        // Setup the instruction
+        prepMigrateEarnerAccount(nonAdmin, earnerAccount);
+
+        // Send the transaction
+        await extEarn.methods
+          .migrateEarnerAccount()
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc();
+
+        // Check the version
+        const earner = await extEarn.account.earner.fetch(earnerAccount);
+        expect(earner.version).toEqual(1);
+      });
+    });
 
+    describe('migrate_earn_manager unit tests', () => {
+      // test cases
+      // [X] given the earn manager account is on the current version
+      //   [X] it reverts with an InvalidVersion error
+      // [X] given the earn manager account is on an older version
+      //   [X] it sets the version to the current version
+
+      // given the earn manager account is on the current version
+      // it reverts with an InvalidVersion error
+      test('current version - reverts', async () => {
         // Setup the instruction
-        await prepWrap(earnerOne, wrongUserATA);
+        prepMigrateEarnManager(nonAdmin, getEarnManagerAccount(earnManagerOne.publicKey));
 
         // Attempt to send the transaction
-        // Expect revert with a ConstraintTokenMint error
+        // Expect an InvalidVersion error
         await expectAnchorError(
           extEarn.methods
-            .wrap(mintAmount)
+            .migrateEarnManager()
             .accounts({ ...accounts })
-            .signers([earnerOne])
+            .signers([nonAdmin])
             .rpc(),
-          'ConstraintTokenMint',
+          'InvalidVersion',
         );
       });
 
-      // given the to ext token account is for the wrong mint
-      // it reverts with a ConstraintTokenMint error
-      test('To Ext token account is for the wrong mint - reverts', async () => {
-        // Get the user's ATA for the m mint and pass it as the user ext token account
-        const wrongUserATA = await getATA(mMint.publicKey, earnerOne.publicKey);
+      // given the earn manager account is on an older version
+      // it sets the version to the current version
+      test('migrate_earn_manager - success', async () => {
+        // Downgrade the earn manager account
+        const earnManagerAccount = getEarnManagerAccount(earnManagerOne.publicKey);
+        await setExtAccountState(earnManagerAccount, 'EarnManager', { version: 0 });
 
         // Setup the instruction
-        await prepWrap(earnerOne, undefined, wrongUserATA);
+        prepMigrateEarnManager(nonAdmin, earnManagerAccount);
+
+        // Send the transaction
+        await extEarn.methods
+          .migrateEarnManager()
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc();
+
+        // Check the version
+        const earnManager = await extEarn.account.earnManager.fetch(earnManagerAccount);
+        expect(earnManager.version).toEqual(1);
+      });
+    });
+
+    describe('migrate_legacy_earner unit tests', () => {
+      // test cases
+      // [X] given the global account is not a migrated legacy global account
+      //   [X] it reverts with an InvalidAccount error
+      // [X] given the global account is a migrated legacy global account
+      //   [X] given the rent payer is not the payer of the legacy earner account
+      //     [X] it reverts with an InvalidAccount error
+      //   [X] given all accounts are correct
+      //     [X] it creates the earner account keyed by the ext mint
+      //     [X] it counts the earner on the global account
+      //     [X] it no longer counts the earner as left to migrate
+      //     [X] it closes the legacy earner account
+
+      let earnerATA: PublicKey;
+      let legacyEarnerAccount: PublicKey;
+
+      beforeEach(async () => {
+        // Move the earner account to its legacy address
+        earnerATA = await getATA(extMint.publicKey, earnerOne.publicKey);
+        ({ legacyAccount: legacyEarnerAccount } = await moveToLegacyAddress(
+          getExtEarnerAccount(earnerATA),
+          'Earner',
+          [Buffer.from('earner'), earnerATA.toBuffer()],
+        ));
+      });
+
+      // given the global account is not a migrated legacy global account
+      // it reverts with an InvalidAccount error
+      test('global account is not legacy - reverts', async () => {
+        // Setup the instruction
+        prepMigrateLegacyEarner(nonAdmin, legacyEarnerAccount, earnerATA, earnManagerOne.publicKey);
 
         // Attempt to send the transaction
-        // Expect revert with a ConstraintTokenMint error
+        // Expect an InvalidAccount error
         await expectAnchorError(
           extEarn.methods
-            .wrap(mintAmount)
+            .migrateLegacyEarner()
             .accounts({ ...accounts })
-            .signers([earnerOne])
+            .signers([nonAdmin])
             .rpc(),
-          'ConstraintTokenMint',
+          'InvalidAccount',
         );
       });
 
-      // given all accounts are correct
-      // give the user does not have enough M tokens
-      // it reverts
-      test('Not enough M - reverts', async () => {
-        // Setup the instruction
-        await prepWrap(earnerOne);
+      // given the global account is a migrated legacy global account
+      // given the rent payer is not the payer of the legacy earner account
+      // it reverts with an InvalidAccount error
+      test('wrong rent payer - reverts', async () => {
+        // Flag the global account as migrated from the legacy layout
+        await setExtAccountState(getExtGlobalAccount(), 'ExtGlobal', { legacy: true });
 
-        const wrapAmount = new BN(randomInt(mintAmount.toNumber() + 1, 2 ** 48 - 1));
+        // Setup the instruction with the wrong rent payer
+        prepMigrateLegacyEarner(nonAdmin, legacyEarnerAccount, earnerATA, earnManagerTwo.publicKey);
 
         // Attempt to send the transaction
-        // Expect an error
-        await expectSystemError(
+        // Expect an InvalidAccount error
+        await expectAnchorError(
           extEarn.methods
-            .wrap(wrapAmount)
+            .migrateLegacyEarner()
             .accounts({ ...accounts })
-            .signers([earnerOne])
+            .signers([nonAdmin])
             .rpc(),
+          'InvalidAccount',
         );
       });
 
+      // given the global account is a migrated legacy global account
       // given all accounts are correct
-      // given the user has enough M tokens
-      // it transfers the amount of M tokens from the user's M token account to the M vault token account
-      // it mints the amount of wM tokens to the user's wM token account
-      test('Wrap as wM earner - success', async () => {
-        // Setup the instruction
-        const { vaultMTokenAccount, fromMTokenAccount, toExtTokenAccount } = await prepWrap(earnerOne);
-
-        // Confirm initial balances
-        await expectTokenBalance(fromMTokenAccount, mintAmount);
-        await expectTokenBalance(vaultMTokenAccount, new BN(0));
-        await expectTokenBalance(toExtTokenAccount, new BN(0));
+      // it creates the earner account keyed by the ext mint
+      // it counts the earner on the global account
+      // it no longer counts the earner as left to migrate
+      // it closes the legacy earner account
+      test('migrate_legacy_earner - success', async () => {
+        // Flag the global account as migrated from the legacy layout with two earners left to migrate
+        await setExtAccountState(getExtGlobalAccount(), 'ExtGlobal', { legacy: true, legacyEarners: new BN(2) });
+        const { earners } = await extEarn.account.extGlobal.fetch(getExtGlobalAccount());
 
-        const wrapAmount = new BN(randomInt(1, mintAmount.toNumber()));
+        // Setup the instruction
+        const { earnerAccount } = prepMigrateLegacyEarner(
+          nonAdmin,
+          legacyEarnerAccount,
+          earnerATA,
+          earnManagerOne.publicKey,
+        );
 
-        // Send the instruction
+        // Send the transaction
         await extEarn.methods
-          .wrap(wrapAmount)
+          .migrateLegacyEarner()
           .accounts({ ...accounts })
-          .signers([earnerOne])
+          .signers([nonAdmin])
           .rpc();
 
-        // Confirm updated balances
-        await expectTokenBalance(fromMTokenAccount, mintAmount.sub(wrapAmount));
-        await expectTokenBalance(vaultMTokenAccount, wrapAmount);
-        await expectTokenBalance(toExtTokenAccount, wrapAmount);
-      });
-
-      // given all accounts are correct
-      // given the user has enough M tokens
-      // it transfers the amount of M tokens from the user's M token account to the M vault token account
-      // it mints the amount of wM tokens to the user's wM token account
-      test('Wrap as non-earner - success', async () => {
-        // Setup the instruction
-        const { vaultMTokenAccount, fromMTokenAccount, toExtTokenAccount } = await prepWrap(nonEarnerOne);
-
-        // Confirm initial balances
-        await expectTokenBalance(fromMTokenAccount, mintAmount);
-        await expectTokenBalance(vaultMTokenAccount, new BN(0));
-        await expectTokenBalance(toExtTokenAccount, new BN(0));
-
-        const wrapAmount = new BN(randomInt(1, mintAmount.toNumber()));
+        // Check the earner account
+        await expectEarnerState(earnerAccount, {
+          user: earnerOne.publicKey,
+          userTokenAccount: earnerATA,
+          earnManager: earnManagerOne.publicKey,
+        });
 
-        // Send the instruction
-        await extEarn.methods
-          .wrap(wrapAmount)
-          .accounts({ ...accounts })
-          .signers([nonEarnerOne])
-          .rpc();
+        // Check the earner is counted
+        await expectExtGlobalState(getExtGlobalAccount(), {
+          earners: earners.addn(1),
+          legacyEarners: new BN(1),
+        });
 
-        // Confirm updated balances
-        await expectTokenBalance(fromMTokenAccount, mintAmount.sub(wrapAmount));
-        await expectTokenBalance(vaultMTokenAccount, wrapAmount);
-        await expectTokenBalance(toExtTokenAccount, wrapAmount);
+        // Check the legacy earner account is closed
+        expectAccountEmpty(legacyEarnerAccount);
       });
     });
 
-    describe('unwrap unit tests', () => {
-      const wrappedAmount = new BN(50_000_000);
-      beforeEach(async () => {
-        // Wrap tokens for the users so we can test unwrapping
-        await wrap(earnerOne, wrappedAmount);
-        await wrap(nonEarnerOne, wrappedAmount);
-      });
-
+    describe('migrate_legacy_earn_manager unit tests', () => {
       // test cases
-      // [X] given the m mint account does not match the one stored in the global account
-      //   [X] it reverts with an InvalidAccount error
-      // [X] given the ext mint account does not match the one stored in the global account
+      // [X] given the global account is not a migrated legacy global account
       //   [X] it reverts with an InvalidAccount error
-      // [X] given the signer is not the authority on the from ext token account
-      //   [X] it reverts with a ConstraintTokenOwner error
-      // [X] given the vault M token account is not the M Vaults ATA for the M token mint
-      //   [X] it reverts with a ConstraintAssociated error
-      // [X] given the to m token account is for the wrong mint
-      //   [X] it reverts with a ConstraintTokenMint error
-      // [X] given the from ext token account is for the wrong mint
-      //   [X] it reverts with a ConstraintTokenMint error
-      // [X] given all the accounts are correct
-      //   [X] given the user does not have enough ext tokens
-      //     [X] it reverts
-      //   [X] given the user has enough ext tokens
-      //     [X] it transfers the amount of M tokens from the M vault token account to the user's M token account
-      //     [X] it burns the amount of ext tokens from the user's ext token account
-      // TODO there are a couple other account constraints that can be tested
+      // [X] given the global account is a migrated legacy global account
+      //   [X] given the rent payer is not the payer of the legacy earn manager account
+      //     [X] it reverts with an InvalidAccount error
+      //   [X] given all accounts are correct
+      //     [X] it creates the earn manager account keyed by the ext mint
+      //     [X] it closes the legacy earn manager account
 
-      // given the m mint account does not match the one stored in the global account
+      let legacyEarnManagerAccount: PublicKey;
+
+      beforeEach(async () => {
+        // Move the earn manager account to its legacy address
+        ({ legacyAccount: legacyEarnManagerAccount } = await moveToLegacyAddress(
+          getEarnManagerAccount(earnManagerOne.publicKey),
+          'EarnManager',
+          [Buffer.from('earn_manager'), earnManagerOne.publicKey.toBuffer()],
+        ));
+      });
+
+      // given the global account is not a migrated legacy global account
       // it reverts with an InvalidAccount error
-      test('M mint account does not match global account - reverts', async () => {
+      test('global account is not legacy - reverts', async () => {
         // Setup the instruction
-        await prepUnwrap(earnerOne);
-
-        // Change the m mint account
-        accounts.mMint = extMint.publicKey;
+        prepMigrateLegacyEarnManager(nonAdmin, legacyEarnManagerAccount, earnManagerOne.publicKey, admin.publicKey);
 
         // Attempt to send the transaction
-        // Expect an invalid account error
+        // Expect an InvalidAccount error
         await expectAnchorError(
           extEarn.methods
-            .unwrap(wrappedAmount)
+            .migrateLegacyEarnManager()
             .accounts({ ...accounts })
-            .signers([earnerOne])
+            .signers([nonAdmin])
             .rpc(),
           'InvalidAccount',
         );
       });
 
-      // given the ext mint account does not match the one stored in the global account
+      // given the global account is a migrated legacy global account
+      // given the rent payer is not the payer of the legacy earn manager account
       // it reverts with an InvalidAccount error
-      test('Ext mint account does not match global account - reverts', async () => {
-        // Setup the instruction
-        await prepUnwrap(earnerOne);
+      test('wrong rent payer - reverts', async () => {
+        // Flag the global account as migrated from the legacy layout
+        await setExtAccountState(getExtGlobalAccount(), 'ExtGlobal', { legacy: true });
 
-        // Change the ext mint account
-        accounts.extMint = mMint.publicKey;
+        // Setup the instruction with the wrong rent payer
+        prepMigrateLegacyEarnManager(nonAdmin, legacyEarnManagerAccount, earnManagerOne.publicKey, nonAdmin.publicKey);
 
         // Attempt to send the transaction
-        // Expect an invalid account error
+        // Expect an InvalidAccount error
         await expectAnchorError(
           extEarn.methods
-            .unwrap(wrappedAmount)
+            .migrateLegacyEarnManager()
             .accounts({ ...accounts })
-            .signers([earnerOne])
+            .signers([nonAdmin])
             .rpc(),
           'InvalidAccount',
         );
       });
 
-      // given the signer is not the authority on the user M token account
-      // it reverts with a ConstraintTokenOwner error
-      test('Signer is not the authority on the from Ext token account - reverts', async () => {
-        // Get the ATA for another user
-        const mATA = await getATA(mMint.publicKey, earnerOne.publicKey);
-        const wrongExtATA = await getATA(extMint.publicKey, nonEarnerOne.publicKey);
-
-        // Setup the instruction with the wrong user M token account
-        await prepUnwrap(earnerOne, mATA, wrongExtATA);
+      // given the global account is a migrated legacy global account
+      // given all accounts are correct
+      // it creates the earn manager account keyed by the ext mint
+      // it closes the legacy earn manager account
+      test('migrate_legacy_earn_manager - success', async () => {
+        // Flag the global account as migrated from the legacy layout
+        await setExtAccountState(getExtGlobalAccount(), 'ExtGlobal', { legacy: true });
 
-        // Attempt to send the transaction
-        // Expect revert with TokenOwner error
-        await expectAnchorError(
-          extEarn.methods
-            .unwrap(wrappedAmount)
-            .accounts({ ...accounts })
-            .signers([earnerOne])
-            .rpc(),
-          'ConstraintTokenOwner',
+        // Setup the instruction
+        const { earnManagerAccount } = prepMigrateLegacyEarnManager(
+          nonAdmin,
+          legacyEarnManagerAccount,
+          earnManagerOne.publicKey,
+          admin.publicKey,
         );
+
+        // Send the transaction
+        await extEarn.methods
+          .migrateLegacyEarnManager()
+          .accounts({ ...accounts })
+          .signers([nonAdmin])
+          .rpc();
+
+        // Check the earn manager account
+        await expectEarnManagerState(earnManagerAccount, {
+          earnManager: earnManagerOne.publicKey,
+          isActive: true,
+        });
+
+        // Check the legacy earn manager account is closed
+        expectAccountEmpty(legacyEarnManagerAccount);
       });
+    });
+  });
 
-      // given the M vault token account is not the M vault PDA's ATA
-      // it reverts with a ConstraintAssociated error
-      test("M Vault Token account is the the M Vault PDA's ATA (other token account) - reverts", async () => {
-        // Create a token account for the M vault that is not the ATA
-        const tokenAccountKeypair = Keypair.generate();
-        const tokenAccountLen = getAccountLen([ExtensionType.ImmutableOwner]);
-        const lamports = await provider.connection.getMinimumBalanceForRentExemption(tokenAccountLen);
+  describe('rebasing instruction tests', () => {
+    const newIndex = new BN(1_100_000_000_000); // 1.1
+    const mintAmount = new BN(100_000_000);
 
-        const mVault = getMVault();
+    beforeEach(async () => {
+      // Initialize the extension program in rebasing mode
+      await initializeExt(earnAuthority.publicKey, { rebasing: {} });
 
-        // Create token account with the immutable owner extension
-        const transaction = new Transaction().add(
-          SystemProgram.createAccount({
-            fromPubkey: admin.publicKey,
-            newAccountPubkey: tokenAccountKeypair.publicKey,
-            space: tokenAccountLen,
-            lamports,
-            programId: TOKEN_2022_PROGRAM_ID,
-          }),
-          createInitializeImmutableOwnerInstruction(tokenAccountKeypair.publicKey, TOKEN_2022_PROGRAM_ID),
-          createInitializeAccountInstruction(
-            tokenAccountKeypair.publicKey,
-            mMint.publicKey,
-            mVault,
-            TOKEN_2022_PROGRAM_ID,
-          ),
-        );
+      // Mint M tokens to a holder that wraps before the index update and one that wraps after it
+      await mintM(earnerOne.publicKey, mintAmount);
+      await mintM(nonEarnerOne.publicKey, mintAmount);
 
-        await provider.send!(transaction, [admin, tokenAccountKeypair]);
+      // Propagate the initial index again to update the max M supply so max yield is sufficient
+      await propagateIndex(initialIndex);
 
-        // Setup the instruction with the non-ATA vault m token account
-        await prepUnwrap(earnerOne, undefined, undefined, tokenAccountKeypair.publicKey);
+      // Wrap the M tokens of earner one at the initial index
+      await wrap(earnerOne, mintAmount);
 
-        // Attempt to send the transaction
-        // Expect revert with a ConstraintAssociated error
-        await expectAnchorError(
-          extEarn.methods
-            .unwrap(wrappedAmount)
-            .accounts({ ...accounts })
-            .signers([earnerOne])
-            .rpc(),
-          'ConstraintAssociated',
-        );
+      // Warp time forward an hour
+      warp(new BN(3600), true);
+
+      // Update the index on the Earn program to start a claim cycle
+      await propagateIndex(newIndex);
+    });
+
+    describe('sync unit tests', () => {
+      // test cases
+      // [X] given the vault has not received the M yield for the new index
+      //   [X] it keeps the ext index and records the M index as synced
+      // [X] given the vault has received part of the M yield for the new index
+      //   [X] it sets the ext index to the highest index the vault can back
+      // [X] given the vault has received all of the M yield for the new index
+      //   [X] it sets the ext index to the M index
+      //   [X] it sets the multiplier of the ext mint to the index over the base index
+
+      // given the vault has not received the M yield for the new index
+      // it keeps the ext index and records the M index as synced
+      test('vault yield not received - keeps the index', async () => {
+        // Sync before the M yield is claimed for the vault
+        await sync();
+
+        // The index can't be backed yet, but the M index is synced
+        const global = await extEarn.account.extGlobal.fetch(getExtGlobalAccount());
+        expect(global.index.toString()).toEqual(initialIndex.toString());
+        expect(global.mIndex.toString()).toEqual(newIndex.toString());
+      });
+
+      // given the vault has received part of the M yield for the new index
+      // it sets the ext index to the highest index the vault can back
+      test('vault yield partially received - caps the index', async () => {
+        // Half of the 10% yield reaches the vault, e.g. when M was wrapped during the cycle
+        await mintM(getMVault(), mintAmount.divn(20));
+
+        await sync();
+
+        // The vault backs 105% of the supply
+        await expectExtGlobalState(getExtGlobalAccount(), {
+          index: new BN(1_050_000_000_000),
+        });
       });
 
-      // given the user m token account is for the wrong mint
-      // it reverts with a ConstraintTokenMint error
-      test('To M token account is for wrong mint - reverts', async () => {
-        // Get the user's ATA for the ext mint and pass it as the user M token account
-        const wrongUserATA = await getATA(extMint.publicKey, earnerOne.publicKey);
+      // given the vault has received all of the M yield for the new index
+      // it sets the ext index to the M index
+      // it sets the multiplier of the ext mint to the index over the base index
+      test('vault yield received - success', async () => {
+        // Claim the M yield for the vault and sync the new index
+        await mClaimFor(getMVault());
+        await sync();
 
-        // Setup the instruction
-        await prepUnwrap(earnerOne, wrongUserATA);
+        // Check the index
+        await expectExtGlobalState(getExtGlobalAccount(), {
+          index: newIndex,
+        });
 
-        // Attempt to send the transaction
-        // Expect revert with a ConstraintTokenMint error
-        await expectAnchorError(
-          extEarn.methods
-            .unwrap(wrappedAmount)
-            .accounts({ ...accounts })
-            .signers([earnerOne])
-            .rpc(),
-          'ConstraintTokenMint',
-        );
+        // Check the multiplier
+        const mint = await getMint(provider.connection, extMint.publicKey, undefined, TOKEN_2022_PROGRAM_ID);
+        expect(getScaledUiAmountConfig(mint)!.multiplier).toEqual(1.1);
       });
+    });
 
-      // given the user ext token account is for the wrong mint
-      // it reverts with a ConstraintTokenMint error
-      test('From Ext token account is for the wrong mint - reverts', async () => {
-        // Get the user's ATA for the m mint and pass it as the user ext token account
-        const wrongUserATA = await getATA(mMint.publicKey, earnerOne.publicKey);
+    describe('settle_fee unit tests', () => {
+      // test cases
+      // [X] given the earn authority does not sign the transaction
+      //   [X] it reverts with a NotAuthorized error
+      // [X] given the earn authority does sign the transaction
+      //   [X] given the earner has already been settled for the current index
+      //     [X] it reverts with an AlreadyClaimed error
+      //   [X] given the earner has yield since its last settlement
+      //     [X] it transfers the earn manager fee out of the earner's balance
+      //     [X] it advances the earner to the current index
+
+      const feeBps = new BN(1_000); // 10%
+
+      beforeEach(async () => {
+        // Earn managers can take a fee from the yield of their earners in rebasing mode
+        await addEarnManager(earnManagerOne.publicKey, feeBps);
+
+        // Add earner one while the ext index is still the initial index
+        await addEarner(earnManagerOne, earnerOne.publicKey);
+
+        // Claim the M yield for the vault and sync the new index
+        await mClaimFor(getMVault());
+        await sync();
+      });
 
+      // given the earn authority does not sign the transaction
+      // it reverts with a NotAuthorized error
+      test('earn authority does not sign - reverts', async () => {
         // Setup the instruction
-        await prepUnwrap(earnerOne, undefined, wrongUserATA);
+        await prepSettleFee(nonAdmin, earnerOne.publicKey, earnManagerOne.publicKey);
 
         // Attempt to send the transaction
-        // Expect revert with a ConstraintTokenMint error
+        // Expect a NotAuthorized error
         await expectAnchorError(
           extEarn.methods
-            .unwrap(wrappedAmount)
+            .settleFee(mintAmount)
             .accounts({ ...accounts })
-            .signers([earnerOne])
+            .signers([nonAdmin])
             .rpc(),
-          'ConstraintTokenMint',
+          'NotAuthorized',
         );
       });
 
-      // given all accounts are correct
-      // give the user does not have enough ext tokens
-      // it reverts
-      test('Not enough ext tokens - reverts', async () => {
-        // Setup the instruction
-        await prepUnwrap(earnerOne);
-
-        const unwrapAmount = new BN(randomInt(wrappedAmount.toNumber() + 1, 2 ** 48 - 1));
+      // given the earn authority does sign the transaction
+      // given the earner has already been settled for the current index
+      // it reverts with an AlreadyClaimed error
+      test('already settled - reverts', async () => {
+        // Settle the fee once
+        await prepSettleFee(earnAuthority, earnerOne.publicKey, earnManagerOne.publicKey);
+        await extEarn.methods
+          .settleFee(mintAmount)
+          .accounts({ ...accounts })
+          .signers([earnAuthority])
+          .rpc();
 
-        // Attempt to send the transaction
-        // Expect an error
-        await expectSystemError(
+        // Attempt to settle it again
+        // Expect an AlreadyClaimed error
+        svm.expireBlockhash();
+        await expectAnchorError(
           extEarn.methods
-            .unwrap(unwrapAmount)
+            .settleFee(mintAmount)
             .accounts({ ...accounts })
-            .signers([earnerOne])
+            .signers([earnAuthority])
             .rpc(),
+          'AlreadyClaimed',
         );
       });
 
-      // given all accounts are correct
-      // given the user has enough ext tokens
-      // it transfers the amount of M tokens from the M vault token account to the user's M token account
-      // it burns the amount of ext tokens from the user's ext token account
-      test('Unwrap as ext earner - success', async () => {
+      // given the earn authority does sign the transaction
+      // given the earner has yield since its last settlement
+      // it transfers the earn manager fee out of the earner's balance
+      // it advances the earner to the current index
+      test('settle_fee - success', async () => {
         // Setup the instruction
-        const { vaultMTokenAccount, toMTokenAccount, fromExtTokenAccount } = await prepUnwrap(earnerOne);
-
-        // Confirm initial balances
-        await expectTokenBalance(toMTokenAccount, mintAmount.sub(wrappedAmount));
-        await expectTokenBalance(vaultMTokenAccount, wrappedAmount.add(wrappedAmount));
-        await expectTokenBalance(fromExtTokenAccount, wrappedAmount);
-
-        const unwrapAmount = new BN(randomInt(1, wrappedAmount.toNumber()));
+        const { earnerAccount, userTokenAccount, earnManagerTokenAccount } = await prepSettleFee(
+          earnAuthority,
+          earnerOne.publicKey,
+          earnManagerOne.publicKey,
+        );
 
-        // Send the instruction
+        // Send the transaction
         await extEarn.methods
-          .unwrap(unwrapAmount)
+          .settleFee(mintAmount)
           .accounts({ ...accounts })
-          .signers([earnerOne])
+          .signers([earnAuthority])
           .rpc();
 
-        // Confirm updated balances
-        await expectTokenBalance(toMTokenAccount, mintAmount.sub(wrappedAmount).add(unwrapAmount));
-        await expectTokenBalance(vaultMTokenAccount, wrappedAmount.add(wrappedAmount).sub(unwrapAmount));
-        await expectTokenBalance(fromExtTokenAccount, wrappedAmount.sub(unwrapAmount));
+        // The 10% M yield is worth (1.1 - 1) / 1.1 of the balance in ext tokens at the new index
+        const rewards = mintAmount.mul(newIndex.sub(initialIndex)).div(newIndex);
+        const fee = rewards.mul(feeBps).divn(10_000);
+
+        // Check the fee was moved to the earn manager
+        await expectTokenBalance(userTokenAccount, mintAmount.sub(fee));
+        await expectTokenBalance(earnManagerTokenAccount, fee);
+
+        // Check the earner was advanced
+        await expectEarnerState(earnerAccount, { lastClaimIndex: newIndex });
       });
+    });
 
-      // given all accounts are correct
-      // given the user has enough ext tokens
-      // it transfers the amount of M tokens from the M vault token account to the user's M token account
-      // it burns the amount of ext tokens from the user's ext token account
-      test('Unwrap as non-earner - success', async () => {
-        // Setup the instruction
-        const { vaultMTokenAccount, toMTokenAccount, fromExtTokenAccount } = await prepUnwrap(nonEarnerOne);
+    describe('earn manager fee unit tests', () => {
+      // test cases
+      // [X] given the yield mode is rebasing
+      //   [X] given an earn manager is added with a non-zero fee
+      //     [X] it creates the earn manager account with the fee
+      //   [X] given an earn manager sets a non-zero fee
+      //     [X] it updates the fee
+
+      // given the yield mode is rebasing
+      // given an earn manager is added with a non-zero fee
+      // it creates the earn manager account with the fee
+      test('add_earn_manager with fee - success', async () => {
+        // Add the earn manager with a fee
+        const { earnManagerAccount } = await addEarnManager(earnManagerOne.publicKey, new BN(100));
+
+        // Verify the earn manager account
+        await expectEarnManagerState(earnManagerAccount, {
+          isActive: true,
+          feeBps: new BN(100),
+        });
+      });
 
-        // Confirm initial balances
-        await expectTokenBalance(toMTokenAccount, mintAmount.sub(wrappedAmount));
-        await expectTokenBalance(vaultMTokenAccount, wrappedAmount.add(wrappedAmount));
-        await expectTokenBalance(fromExtTokenAccount, wrappedAmount);
+      // given the yield mode is rebasing
+      // given an earn manager sets a non-zero fee
+      // it updates the fee
+      test('configure_earn_manager with fee - success', async () => {
+        // Add the earn manager without a fee
+        const { earnManagerAccount } = await addEarnManager(earnManagerOne.publicKey, new BN(0));
 
-        const unwrapAmount = new BN(randomInt(1, wrappedAmount.toNumber()));
+        // Setup the instruction
+        await prepConfigureEarnManager(earnManagerOne, earnManagerOne.publicKey);
 
-        // Send the instruction
+        // Send the transaction
         await extEarn.methods
-          .unwrap(unwrapAmount)
+          .configureEarnManager(new BN(100))
           .accounts({ ...accounts })
-          .signers([nonEarnerOne])
+          .signers([earnManagerOne])
           .rpc();
 
-        // Confirm updated balances
-        await expectTokenBalance(toMTokenAccount, mintAmount.sub(wrappedAmount).add(unwrapAmount));
-        await expectTokenBalance(vaultMTokenAccount, wrappedAmount.add(wrappedAmount).sub(unwrapAmount));
-        await expectTokenBalance(fromExtTokenAccount, wrappedAmount.sub(unwrapAmount));
+        // Verify the fee was updated
+        await expectEarnManagerState(earnManagerAccount, {
+          feeBps: new BN(100),
+        });
       });
     });
 
-    describe('remove_orphaned_earner unit tests', () => {
+    describe('wrap and unwrap index unit tests', () => {
       // test cases
-      // [X] given the earner account is not initialized
-      //   [X] it reverts with an account not initialized error
-      // [X] given the earn manager account is not initialized
-      //   [X] it reverts with an account not initialized error
-      // [X] given the earn manager account does not match the one on the earner account
-      //   [X] it reverts with a ConstraintSeeds error
-      // [X] given all the accounts are valid
-      //   [X] given the earner has an earn manager
-      //     [X] given the earn manager account is active
-      //       [X] it reverts with a Active error
-      //     [X] given the earn manager account is not active
-      //       [X] it closes the earner account and refunds the rent to the signer
-
-      beforeEach(async () => {
-        // Add another earn manager
-        await addEarnManager(earnManagerTwo.publicKey, new BN(0));
-
-        // Add an earner under the new earn manager
-        await addEarner(earnManagerTwo, earnerTwo.publicKey);
-
-        // Remove earn manager two so that earner two is orphaned
-        await removeEarnManager(earnManagerTwo.publicKey);
-      });
-
-      // given the earner account is not initialized
-      // it reverts with an account not initialized error
-      test('Earner account is not initialized - reverts', async () => {
-        // Calculate the ATA for non earner one, but don't create it
-        const nonInitATA = getAssociatedTokenAddressSync(
-          extMint.publicKey,
-          nonEarnerOne.publicKey,
-          true,
-          TOKEN_2022_PROGRAM_ID,
-          ASSOCIATED_TOKEN_PROGRAM_ID,
-        );
-
+      // [X] given the M earn index is ahead of the ext index
+      //   [X] given a user wraps
+      //     [X] it reverts with an IndexNotSynced error
+      //   [X] given a user unwraps
+      //     [X] it reverts with an IndexNotSynced error
+      // [X] given the M earn index has been synced before the vault received the yield
+      //   [X] given a user wraps
+      //     [X] it mints ext tokens at the ext index
+      // [X] given the ext index has been synced with the M earn index
+      //   [X] given a user wraps
+      //     [X] it mints ext tokens at the new index
+      //   [X] given a user unwraps
+      //     [X] it transfers M tokens at the new index
+
+      // given the M earn index is ahead of the ext index
+      // given a user wraps
+      // it reverts with an IndexNotSynced error
+      test('wrap before sync - reverts', async () => {
         // Setup the instruction
-        prepRemoveOrphanedEarner(nonAdmin, nonInitATA, earnManagerOne.publicKey);
-
-        // Attempt to remove orphaned earner with uninitialized token account
-        await expectAnchorError(
-          extEarn.methods
-            .removeOrphanedEarner()
-            .accounts({ ...accounts })
-            .signers([nonAdmin])
-            .rpc(),
-          'AccountNotInitialized',
-        );
-      });
-
-      // given the earn manager account is not initialized
-      // it reverts with an account not initialized error
-      test('Earn manager account is not initialized - reverts', async () => {
-        // Get the ATA for earner two
-        const earnerTwoATA = await getATA(extMint.publicKey, earnerTwo.publicKey);
-
-        // Prepare the instruction
-        prepRemoveOrphanedEarner(nonAdmin, earnerTwoATA, nonEarnManagerOne.publicKey);
+        await prepWrap(nonEarnerOne);
 
-        // Attempt to remove orphaned earner with uninitialized earn manager account
+        // Attempt to send the transaction
+        // Expect an IndexNotSynced error
         await expectAnchorError(
           extEarn.methods
-            .removeOrphanedEarner()
+            .wrap(mintAmount, null)
             .accounts({ ...accounts })
-            .signers([nonAdmin])
+            .signers([nonEarnerOne])
             .rpc(),
-          'AccountNotInitialized',
+          'IndexNotSynced',
         );
       });
 
-      // given all the accounts are valid
-      // given the earner has an earn manager
-      // given the earn manager account is active
-      // it reverts with an Active error
-      test('Earn manager account is active - reverts', async () => {
-        // Get the ATA for earner one
-        const earnerOneATA = await getATA(extMint.publicKey, earnerOne.publicKey);
-
+      // given the M earn index is ahead of the ext index
+      // given a user unwraps
+      // it reverts with an IndexNotSynced error
+      test('unwrap before sync - reverts', async () => {
         // Setup the instruction
-        prepRemoveOrphanedEarner(nonAdmin, earnerOneATA, earnManagerOne.publicKey);
+        await prepUnwrap(earnerOne);
 
-        // Attempt to remove orphaned earner with an active earn manager
+        // Attempt to send the transaction
+        // Expect an IndexNotSynced error
         await expectAnchorError(
           extEarn.methods
-            .removeOrphanedEarner()
+            .unwrap(mintAmount, null)
             .accounts({ ...accounts })
-            .signers([nonAdmin])
+            .signers([earnerOne])
             .rpc(),
-          'Active',
+          'IndexNotSynced',
         );
       });
 
-      // given the earn manager account does not match the earner's earn manager
-      // it reverts with a ConstraintSeeds error
-      test('Invalid earn manager account - reverts', async () => {
-        const earnerTwoATA = await getATA(extMint.publicKey, earnerTwo.publicKey);
+      // given the M earn index has been synced before the vault received the yield
+      // given a user wraps
+      // it mints ext tokens at the ext index
+      test('wrap after sync without the vault yield - success', async () => {
+        // Sync before the M yield is claimed for the vault
+        await sync();
 
-        // Setup the instruction
-        await prepRemoveOrphanedEarner(nonAdmin, earnerTwoATA, earnManagerOne.publicKey);
+        // Wrap the M tokens of the non-earner at the initial index
+        const { toExtTokenAccount } = await wrap(nonEarnerOne, mintAmount);
 
-        // Attempt to remove orphaned earner with the wrong earn manager account
-        // expect revert with ConstraintSeeds error
-        await expectAnchorError(
-          extEarn.methods
-            .removeOrphanedEarner()
-            .accounts({ ...accounts })
-            .signers([nonAdmin])
-            .rpc(),
-          'ConstraintSeeds',
-        );
+        // Verify the ext tokens were minted at the initial index
+        await expectTokenBalance(toExtTokenAccount, mintAmount);
       });
 
-      // given all the accounts are valid
-      // given the earner has an earn manager
-      // given the earn manager account is not active
-      // it closes the earner account and refunds the rent to the signer
-      test('Remove orphaned earner - success', async () => {
-        // Get the ATA for earnerTwo
-        const earnerTwoATA = await getATA(extMint.publicKey, earnerTwo.publicKey);
+      // given the ext index has been synced with the M earn index
+      // given a user wraps
+      // it mints ext tokens at the new index
+      test('wrap after sync - success', async () => {
+        // Claim the M yield for the vault and sync the new index
+        await mClaimFor(getMVault());
+        await sync();
 
-        // Setup the instruction
-        const { earnerAccount, earnManagerAccount } = prepRemoveOrphanedEarner(
-          nonAdmin,
-          earnerTwoATA,
-          earnManagerTwo.publicKey,
-        );
+        // Wrap the M tokens of the non-earner at the new index
+        const { toExtTokenAccount } = await wrap(nonEarnerOne, mintAmount);
 
-        // Confirm that the account is active and has the correct earn manager
-        await expectEarnerState(earnerAccount, {
-          earnManager: earnManagerTwo.publicKey,
-        });
+        // Verify the ext tokens were minted at the new index
+        await expectTokenBalance(toExtTokenAccount, mintAmount.mul(initialIndex).div(newIndex));
+      });
 
-        // Confirm that the earn manager account is not active
-        await expectEarnManagerState(earnManagerAccount, {
-          isActive: false,
-        });
+      // given the ext index has been synced with the M earn index
+      // given a user unwraps
+      // it transfers M tokens at the new index
+      test('unwrap after sync - success', async () => {
+        // Claim the M yield for the vault and sync the new index
+        await mClaimFor(getMVault());
+        await sync();
 
-        // Remove the orphaned earner
-        await extEarn.methods
-          .removeOrphanedEarner()
-          .accounts({ ...accounts })
-          .signers([nonAdmin])
-          .rpc();
+        // Unwrap the ext tokens of earner one at the new index
+        const { toMTokenAccount, fromExtTokenAccount } = await unwrap(earnerOne, mintAmount);
 
-        // Verify the earner account was closed
-        expectAccountEmpty(earnerAccount);
+        // Verify the M tokens were transferred at the new index, including the yield
+        await expectTokenBalance(toMTokenAccount, mintAmount.mul(newIndex).div(initialIndex));
+        await expectTokenBalance(fromExtTokenAccount, new BN(0));
       });
     });
   });
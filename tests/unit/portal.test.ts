@@ -473,6 +473,94 @@ describe('Portal unit tests', () => {
       const logs = await fetchTransactionLogs(provider, txIds[txIds.length - 1].txid);
       expect(logs).toContain('Program log: Index update: 123456 | root update: true');
     });
+
+    it('tokens with merkle roots and root timestamp', async () => {
+      const additionalPayload = utils.encodePacked(
+        // index
+        { type: 'uint64', value: 123457 },
+        {
+          // destination
+          type: 'bytes32',
+          value: '0x866A2BF4E572CbcF37D5071A7a58503Bfb36be1b',
+        },
+        {
+          // earner root
+          type: 'bytes32',
+          value: '0x2222222222222222222222222222222222222222',
+        },
+        // earner root timestamp
+        { type: 'uint64', value: 1_700_000_000 },
+      );
+
+      const getRedeemTxns = redeem(
+        [
+          {
+            pubkey: config.EARN_PROGRAM,
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: config.EARN_GLOBAL_ACCOUNT,
+            isSigner: false,
+            isWritable: true,
+          },
+        ],
+        additionalPayload,
+      );
+
+      const txIds = await ssw(ctx, getRedeemTxns(), signer);
+      const logs = await fetchTransactionLogs(provider, txIds[txIds.length - 1].txid);
+      expect(logs).toContain('Program log: Index update: 123457 | root update: true');
+
+      // verify the root timestamp was stored on the inbox item and propagated
+      const item = await ntt.program.account.inboxItem.fetch(inboxItem);
+      expect(JSON.stringify(item.releaseStatus.released)).toBeDefined();
+
+      const global = await earn.account.global.fetch(config.EARN_GLOBAL_ACCOUNT);
+      expect(global.earnerMerkleRootTimestamp.toString()).toBe('1700000000');
+    });
+
+    it('tokens with merkle roots without timestamp after a timestamped root', async () => {
+      const additionalPayload = utils.encodePacked(
+        // index
+        { type: 'uint64', value: 123458 },
+        {
+          // destination
+          type: 'bytes32',
+          value: '0x866A2BF4E572CbcF37D5071A7a58503Bfb36be1b',
+        },
+        {
+          // earner root
+          type: 'bytes32',
+          value: '0x3333333333333333333333333333333333333333',
+        },
+      );
+
+      const getRedeemTxns = redeem(
+        [
+          {
+            pubkey: config.EARN_PROGRAM,
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: config.EARN_GLOBAL_ACCOUNT,
+            isSigner: false,
+            isWritable: true,
+          },
+        ],
+        additionalPayload,
+      );
+
+      // The root is dropped with an EarnerRootDropped event and the index still propagated
+      const txIds = await ssw(ctx, getRedeemTxns(), signer);
+      const logs = await fetchTransactionLogs(provider, txIds[txIds.length - 1].txid);
+      expect(logs).toContain('Program log: Dropping stale earner root');
+      expect(logs).toContain('Program log: Index update: 123458 | root update: false');
+
+      const global = await earn.account.global.fetch(config.EARN_GLOBAL_ACCOUNT);
+      expect(global.earnerMerkleRootTimestamp.toString()).toBe('1700000000');
+    });
   });
 
   describe('Mint', () => {
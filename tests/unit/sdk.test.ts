@@ -33,6 +33,7 @@ const EXT_EARN_IDL = require('@m0-foundation/solana-m-sdk/src/idl/ext_earn.json'
 describe('SDK unit tests', () => {
   const signer = loadKeypair('keys/user.json');
   const mints = [loadKeypair('keys/mint.json'), Keypair.generate()];
+  const extMint = mints[1].publicKey;
   const multisig = Keypair.generate();
   const earnerA = Keypair.fromSecretKey(
     Buffer.from(
@@ -58,9 +59,15 @@ describe('SDK unit tests', () => {
   const extEarn = new Program<ExtEarn>(EXT_EARN_IDL, EXT_PROGRAM_ID, provider);
 
   const [globalAccount] = PublicKey.findProgramAddressSync([Buffer.from('global')], earn.programId);
-  const [extGlobalAccount] = PublicKey.findProgramAddressSync([Buffer.from('global')], extEarn.programId);
+  const [extGlobalAccount] = PublicKey.findProgramAddressSync(
+    [Buffer.from('global'), extMint.toBuffer()],
+    extEarn.programId,
+  );
   const [tokenAuth] = PublicKey.findProgramAddressSync([Buffer.from('token_authority')], earn.programId);
-  const [extMintAuth] = PublicKey.findProgramAddressSync([Buffer.from('mint_authority')], extEarn.programId);
+  const [extMintAuth] = PublicKey.findProgramAddressSync(
+    [Buffer.from('mint_authority'), extMint.toBuffer()],
+    extEarn.programId,
+  );
 
   // use local EVM testnet (anvil)
   const evmClient = createPublicClient({ transport: http('http://localhost:8545') });
@@ -248,7 +255,7 @@ describe('SDK unit tests', () => {
     const earnerMerkleTree = new MerkleTree([earnerA.publicKey]);
 
     await earn.methods
-      .propagateIndex(new BN(1_000_000_000_000), earnerMerkleTree.getRoot())
+      .propagateIndex(new BN(1_000_000_000_000), earnerMerkleTree.getRoot(), new BN(0))
       .accounts({
         signer: signer.publicKey,
         globalAccount,
@@ -262,7 +269,7 @@ describe('SDK unit tests', () => {
       earn.programId,
     )[0];
     earnerAccountB = PublicKey.findProgramAddressSync(
-      [Buffer.from('earner'), mintATAs[1][1].toBytes()],
+      [Buffer.from('earner'), extMint.toBytes(), mintATAs[1][1].toBytes()],
       extEarn.programId,
     )[0];
 
@@ -279,7 +286,7 @@ describe('SDK unit tests', () => {
 
     // add manager
     const [earnManagerAccount] = PublicKey.findProgramAddressSync(
-      [Buffer.from('earn_manager'), signer.publicKey.toBytes()],
+      [Buffer.from('earn_manager'), extMint.toBytes(), signer.publicKey.toBytes()],
       extEarn.programId,
     );
 
@@ -305,7 +312,7 @@ describe('SDK unit tests', () => {
       .rpc();
 
     await earn.methods
-      .propagateIndex(new BN(1_010_000_000_000), earnerMerkleTree.getRoot())
+      .propagateIndex(new BN(1_010_000_000_000), earnerMerkleTree.getRoot(), new BN(0))
       .accounts({
         signer: signer.publicKey,
         globalAccount,
@@ -328,7 +335,8 @@ describe('SDK unit tests', () => {
   describe('rpc', () => {
     test('get all earners', async () => {
       for (const [index, earner] of [earnerA, earnerB].entries()) {
-        const auth = await EarnAuthority.load(connection, evmClient, index === 0 ? EARN_PROGRAM : EXT_PROGRAM_ID);
+        const program = index === 0 ? EARN_PROGRAM : EXT_PROGRAM_ID;
+        const auth = await EarnAuthority.load(connection, evmClient, program, undefined, extMint);
         const earners = await auth.getAllEarners();
         expect(earners).toHaveLength(1);
         expect(earners[0].data.user.toBase58()).toEqual(earner.publicKey.toBase58());
@@ -336,12 +344,12 @@ describe('SDK unit tests', () => {
     });
 
     test('get earn manager', async () => {
-      const manager = await EarnManager.fromManagerAddress(connection, evmClient, signer.publicKey);
+      const manager = await EarnManager.fromManagerAddress(connection, evmClient, signer.publicKey, extMint);
       expect(manager.data.feeBps.toNumber()).toEqual(10);
     });
 
     test('manager earners', async () => {
-      const manager = await EarnManager.fromManagerAddress(connection, evmClient, signer.publicKey);
+      const manager = await EarnManager.fromManagerAddress(connection, evmClient, signer.publicKey, extMint);
       const earners = await manager.getEarners();
       expect(earners).toHaveLength(1);
       expect(earners[0].data.user.toBase58()).toEqual(earnerB.publicKey.toBase58());
@@ -491,7 +499,7 @@ describe('SDK unit tests', () => {
 
   describe('earn manager', () => {
     test('configure', async () => {
-      const manager = await EarnManager.fromManagerAddress(connection, evmClient, signer.publicKey);
+      const manager = await EarnManager.fromManagerAddress(connection, evmClient, signer.publicKey, extMint);
 
       const dummyATA = spl.getAssociatedTokenAddressSync(
         mints[1].publicKey,
@@ -508,7 +516,7 @@ describe('SDK unit tests', () => {
     });
 
     test('add earner', async () => {
-      const manager = await EarnManager.fromManagerAddress(connection, evmClient, signer.publicKey);
+      const manager = await EarnManager.fromManagerAddress(connection, evmClient, signer.publicKey, extMint);
 
       const earnerATA = spl.getAssociatedTokenAddressSync(
         mints[1].publicKey,
@@ -520,7 +528,7 @@ describe('SDK unit tests', () => {
       const ixs = await manager.buildAddEarnerInstruction(earnerC.publicKey, earnerATA);
       await sendAndConfirmTransaction(connection, new Transaction().add(...ixs), [signer]);
 
-      const earner = await Earner.fromTokenAccount(connection, evmClient, earnerATA);
+      const earner = await Earner.fromTokenAccount(connection, evmClient, earnerATA, EXT_PROGRAM_ID, extMint);
       expect(earner.data.earnManager?.toBase58()).toEqual(manager.manager.toBase58());
     });
   });
@@ -548,7 +556,7 @@ describe('SDK unit tests', () => {
           spl.TOKEN_2022_PROGRAM_ID,
         );
 
-        const earner = await Earner.fromTokenAccount(connection, evmClient, earnerATA, EXT_PROGRAM_ID);
+        const earner = await Earner.fromTokenAccount(connection, evmClient, earnerATA, EXT_PROGRAM_ID, extMint);
         const claimed = await earner.getClaimedYield();
         expect(claimed.toString()).toEqual('5000000');
       });
@@ -585,7 +593,7 @@ describe('SDK unit tests', () => {
           spl.TOKEN_2022_PROGRAM_ID,
         );
 
-        const earner = await Earner.fromTokenAccount(connection, evmClient, earnerATA, EXT_PROGRAM_ID);
+        const earner = await Earner.fromTokenAccount(connection, evmClient, earnerATA, EXT_PROGRAM_ID, extMint);
         const pending = await earner.getPendingYield();
 
         // Earners's weighted balance over the period is 2,000,000
@@ -598,7 +606,7 @@ describe('SDK unit tests', () => {
 
       test('ext earn program - no manager fee', async () => {
         // Set the earn manager to 0% fee
-        const manager = await EarnManager.fromManagerAddress(connection, evmClient, signer.publicKey);
+        const manager = await EarnManager.fromManagerAddress(connection, evmClient, signer.publicKey, extMint);
 
         const dummyATA = spl.getAssociatedTokenAddressSync(
           mints[1].publicKey,
@@ -618,7 +626,7 @@ describe('SDK unit tests', () => {
           spl.TOKEN_2022_PROGRAM_ID,
         );
 
-        const earner = await Earner.fromTokenAccount(connection, evmClient, earnerATA, EXT_PROGRAM_ID);
+        const earner = await Earner.fromTokenAccount(connection, evmClient, earnerATA, EXT_PROGRAM_ID, extMint);
         const pending = await earner.getPendingYield();
 
         // Earner's weighted balance over the period is 2,000,000 M